@@ -28,7 +28,9 @@ use snarkvm_utilities::{
     ToBytes,
 };
 
-use crate::srs::{UniversalProver, UniversalVerifier};
+#[cfg(feature = "prover")]
+use crate::srs::UniversalProver;
+use crate::srs::UniversalVerifier;
 use anyhow::Result;
 use core::ops::{Add, AddAssign};
 use parking_lot::RwLock;
@@ -91,6 +93,7 @@ impl<E: PairingEngine> UniversalParams<E> {
         self.powers.read().max_num_powers() - 1
     }
 
+    #[cfg(feature = "prover")]
     pub fn to_universal_prover(&self) -> Result<UniversalProver<E>> {
         Ok(UniversalProver::<E> { max_degree: self.max_degree(), _unused: None })
     }
@@ -19,26 +19,32 @@
 //! proposed by Kate, Zaverucha, and Goldberg ([KZG11](http://cacr.uwaterloo.ca/techreports/2010/cacr2010-10.pdf)).
 //! This construction achieves extractability in the algebraic group model (AGM).
 
-use crate::{
-    fft::{DensePolynomial, Polynomial},
-    msm::VariableBase,
-    polycommit::PCError,
-};
-use anyhow::anyhow;
+use crate::polycommit::PCError;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, PrimeField, Zero};
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, rand::Uniform, BitIteratorBE};
+use snarkvm_utilities::rand::Uniform;
 
 use core::{marker::PhantomData, ops::Mul};
 use itertools::Itertools;
 use rand_core::RngCore;
 
-#[cfg(not(feature = "serial"))]
+#[cfg(feature = "prover")]
+use crate::{
+    fft::{DensePolynomial, Polynomial},
+    msm::VariableBase,
+};
+#[cfg(feature = "prover")]
+use anyhow::anyhow;
+#[cfg(feature = "prover")]
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut, BitIteratorBE};
+
+#[cfg(all(feature = "prover", not(feature = "serial")))]
 use rayon::prelude::*;
 
 mod data_structures;
 pub use data_structures::*;
 
+#[cfg(feature = "prover")]
 use super::sonic_pc::LabeledPolynomialWithBasis;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -94,6 +100,7 @@ impl<E: PairingEngine> KZG10<E> {
     }
 
     /// Outputs a commitment to `polynomial`.
+    #[cfg(feature = "prover")]
     pub fn commit(
         powers: &Powers<E>,
         polynomial: &Polynomial<'_, E::Fr>,
@@ -155,6 +162,7 @@ impl<E: PairingEngine> KZG10<E> {
     }
 
     /// Outputs a commitment to `polynomial`.
+    #[cfg(feature = "prover")]
     pub fn commit_lagrange(
         lagrange_basis: &LagrangeBasis<E>,
         evaluations: &[E::Fr],
@@ -209,6 +217,7 @@ impl<E: PairingEngine> KZG10<E> {
     /// The witness polynomial w(x) the quotient of the division (p(x) - p(z)) / (x - z)
     /// Observe that this quotient does not change with z because
     /// p(z) is the remainder term. We can therefore omit p(z) when computing the quotient.
+    #[cfg(feature = "prover")]
     pub fn compute_witness_polynomial(
         polynomial: &DensePolynomial<E::Fr>,
         point: E::Fr,
@@ -234,6 +243,7 @@ impl<E: PairingEngine> KZG10<E> {
         Ok((witness_polynomial, random_witness_polynomial))
     }
 
+    #[cfg(feature = "prover")]
     pub(crate) fn open_with_witness_polynomial(
         powers: &Powers<E>,
         point: E::Fr,
@@ -270,6 +280,7 @@ impl<E: PairingEngine> KZG10<E> {
 
     /// On input a polynomial `p` in Lagrange basis, and a point `point`,
     /// outputs an evaluation proof for the same.
+    #[cfg(feature = "prover")]
     pub fn open_lagrange(
         lagrange_basis: &LagrangeBasis<E>,
         domain_elements: &[E::Fr],
@@ -299,6 +310,7 @@ impl<E: PairingEngine> KZG10<E> {
     }
 
     /// On input a polynomial `p` and a point `point`, outputs a proof for the same.
+    #[cfg(feature = "prover")]
     pub fn open(
         powers: &Powers<E>,
         polynomial: &DensePolynomial<E::Fr>,
@@ -399,6 +411,7 @@ impl<E: PairingEngine> KZG10<E> {
         Ok(result)
     }
 
+    #[cfg(feature = "prover")]
     pub(crate) fn check_degree_is_too_large(degree: usize, num_powers: usize) -> Result<(), PCError> {
         let num_coefficients = degree + 1;
         if num_coefficients > num_powers {
@@ -408,6 +421,7 @@ impl<E: PairingEngine> KZG10<E> {
         }
     }
 
+    #[cfg(feature = "prover")]
     pub(crate) fn check_hiding_bound(hiding_poly_degree: usize, num_powers: usize) -> Result<(), PCError> {
         if hiding_poly_degree == 0 {
             Err(PCError::HidingBoundIsZero)
@@ -420,6 +434,7 @@ impl<E: PairingEngine> KZG10<E> {
         }
     }
 
+    #[cfg(feature = "prover")]
     pub(crate) fn check_degrees_and_bounds<'a>(
         max_degree: usize,
         enforced_degree_bounds: Option<&[usize]>,
@@ -447,6 +462,7 @@ impl<E: PairingEngine> KZG10<E> {
     }
 }
 
+#[cfg(feature = "prover")]
 fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &DensePolynomial<F>) -> (usize, Vec<F::BigInteger>) {
     if p.coeffs.is_empty() {
         (0, vec![])
@@ -460,6 +476,7 @@ fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &DensePolynomial<
     }
 }
 
+#[cfg(feature = "prover")]
 fn convert_to_bigints<F: PrimeField>(p: &[F]) -> Vec<F::BigInteger> {
     let to_bigint_time = start_timer!(|| "Converting polynomial coeffs to bigints");
     let coeffs = cfg_iter!(p).map(|s| s.to_bigint()).collect::<Vec<_>>();
@@ -15,17 +15,23 @@
 use crate::{
     fft::DensePolynomial,
     msm::variable_base::VariableBase,
-    polycommit::{kzg10, optional_rng::OptionalRng, PCError},
-    srs::{UniversalProver, UniversalVerifier},
+    polycommit::{kzg10, PCError},
+    srs::UniversalVerifier,
     AlgebraicSponge,
 };
+#[cfg(feature = "prover")]
+use crate::{polycommit::optional_rng::OptionalRng, srs::UniversalProver};
+#[cfg(feature = "prover")]
 use hashbrown::HashMap;
 use itertools::Itertools;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
 
-use anyhow::{bail, Result};
+use anyhow::Result;
 use core::{convert::TryInto, marker::PhantomData, ops::Mul};
+#[cfg(feature = "prover")]
+use anyhow::bail;
+#[cfg(feature = "prover")]
 use rand_core::{RngCore, SeedableRng};
 use std::{
     borrow::Borrow,
@@ -58,6 +64,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         kzg10::KZG10::load_srs(max_degree).map_err(Into::into)
     }
 
+    #[cfg(feature = "prover")]
     pub fn trim(
         pp: &UniversalParams<E>,
         supported_degree: usize,
@@ -171,6 +178,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     ///
     /// If for some `i`, `polynomials[i].degree_bound().is_some()`, then that
     /// polynomial will have the corresponding degree bound enforced.
+    #[cfg(feature = "prover")]
     #[allow(clippy::format_push_string)]
     pub fn commit<'b>(
         universal_prover: &UniversalProver<E>,
@@ -263,6 +271,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Ok((labeled_comms, randomness))
     }
 
+    #[cfg(feature = "prover")]
     pub fn combine_for_open<'a>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -286,6 +295,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
     /// On input a list of labeled polynomials and a query set, `open` outputs a proof of evaluation
     /// of the polynomials at the points in the query set.
+    #[cfg(feature = "prover")]
     pub fn batch_open<'a>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -410,6 +420,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         result
     }
 
+    #[cfg(feature = "prover")]
     pub fn open_combinations<'a>(
         universal_prover: &UniversalProver<E>,
         ck: &CommitterUnionKey<E>,
@@ -545,6 +556,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 }
 
 impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
+    #[cfg(feature = "prover")]
     fn combine_polynomials<'a, B: Borrow<DensePolynomial<E::Fr>>>(
         coeffs_polys_rands: impl IntoIterator<Item = (E::Fr, B, &'a Randomness<E>)>,
     ) -> (DensePolynomial<E::Fr>, Randomness<E>) {
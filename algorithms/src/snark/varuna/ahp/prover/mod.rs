@@ -20,10 +20,15 @@ pub(crate) use constraint_system::*;
 mod message;
 pub(crate) use message::*;
 
+#[cfg(feature = "prover")]
 mod oracles;
+#[cfg(feature = "prover")]
 pub(crate) use oracles::*;
 
+#[cfg(feature = "prover")]
 mod round_functions;
 
+#[cfg(feature = "prover")]
 mod state;
+#[cfg(feature = "prover")]
 pub(self) use state::*;
@@ -17,7 +17,9 @@ pub(super) mod certificate;
 pub use certificate::*;
 
 /// The Varuna circuit proving key.
+#[cfg(feature = "prover")]
 pub(super) mod circuit_proving_key;
+#[cfg(feature = "prover")]
 pub use circuit_proving_key::*;
 
 /// The Varuna circuit verifying key.
@@ -28,6 +30,10 @@ pub use circuit_verifying_key::*;
 pub(super) mod proof;
 pub use proof::*;
 
+/// A container of Varuna zkSNARK proofs, in a packed layout.
+pub(super) mod packed_proofs;
+pub use packed_proofs::*;
+
 /// A test circuit.
 #[cfg(any(test, feature = "test"))]
 pub(super) mod test_circuit;
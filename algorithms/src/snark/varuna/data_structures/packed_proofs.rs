@@ -0,0 +1,375 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    polycommit::sonic_pc,
+    snark::varuna::{
+        ahp::prover::{FourthMessage, ThirdMessage},
+        Commitments,
+        Evaluations,
+        Proof,
+        WitnessCommitments,
+    },
+};
+
+use snarkvm_curves::PairingEngine;
+use snarkvm_utilities::{
+    error,
+    io::{self, Read, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
+
+/// A container of zkSNARK proofs, serialized in a columnar layout.
+///
+/// The batch sizes, commitments, evaluations, prover messages, and evaluation proofs of all proofs
+/// are stored one column at a time, with the batch sizes stored as `u32`s. A commitment which repeats
+/// an earlier commitment, in the same or in another proof, is stored as an index into the distinct commitments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PackedProofs<E: PairingEngine> {
+    /// The proofs.
+    proofs: Vec<Proof<E>>,
+}
+
+impl<E: PairingEngine> PackedProofs<E> {
+    /// Initializes a new container of packed proofs.
+    pub fn new(proofs: Vec<Proof<E>>) -> Self {
+        Self { proofs }
+    }
+
+    /// Returns the proofs.
+    pub fn proofs(&self) -> &[Proof<E>] {
+        &self.proofs
+    }
+
+    /// Returns the proofs, consuming the container.
+    pub fn into_proofs(self) -> Vec<Proof<E>> {
+        self.proofs
+    }
+
+    /// Returns the commitments of all proofs, as a bitmap marking the commitments already seen in an earlier slot,
+    /// the table of distinct commitments, and the table index of each repeated commitment.
+    fn to_commitment_table(&self) -> Result<(Vec<bool>, Vec<sonic_pc::Commitment<E>>, Vec<u16>), SerializationError> {
+        let mut is_repeated = Vec::new();
+        let mut table: Vec<sonic_pc::Commitment<E>> = Vec::new();
+        let mut indices = Vec::new();
+        for proof in &self.proofs {
+            for commitment in commitments_of(&proof.commitments) {
+                match table.iter().position(|c| c == commitment) {
+                    Some(index) => {
+                        is_repeated.push(true);
+                        indices.push(u16::try_from(index)?);
+                    }
+                    None => {
+                        is_repeated.push(false);
+                        table.push(*commitment);
+                    }
+                }
+            }
+        }
+        Ok((is_repeated, table, indices))
+    }
+}
+
+/// Returns the commitments of a proof, in serialization order.
+fn commitments_of<E: PairingEngine>(commitments: &Commitments<E>) -> impl Iterator<Item = &sonic_pc::Commitment<E>> {
+    commitments
+        .witness_commitments
+        .iter()
+        .map(|c| &c.w)
+        .chain(commitments.mask_poly.iter())
+        .chain([&commitments.h_0, &commitments.g_1, &commitments.h_1])
+        .chain(commitments.g_a_commitments.iter())
+        .chain(commitments.g_b_commitments.iter())
+        .chain(commitments.g_c_commitments.iter())
+        .chain([&commitments.h_2])
+}
+
+/// Returns the commitments of a proof, in serialization order, from the given iterator.
+fn commitments_from<E: PairingEngine>(
+    batch_sizes: &[usize],
+    has_mask_poly: bool,
+    commitments: &mut impl Iterator<Item = sonic_pc::Commitment<E>>,
+) -> Result<Commitments<E>, SerializationError> {
+    let mut next = || commitments.next().ok_or(SerializationError::InvalidData);
+    let num_circuits = batch_sizes.len();
+    Ok(Commitments {
+        witness_commitments: (0..batch_sizes.iter().sum::<usize>())
+            .map(|_| Ok(WitnessCommitments { w: next()? }))
+            .collect::<Result<_, SerializationError>>()?,
+        mask_poly: if has_mask_poly { Some(next()?) } else { None },
+        h_0: next()?,
+        g_1: next()?,
+        h_1: next()?,
+        g_a_commitments: (0..num_circuits).map(|_| next()).collect::<Result<_, _>>()?,
+        g_b_commitments: (0..num_circuits).map(|_| next()).collect::<Result<_, _>>()?,
+        g_c_commitments: (0..num_circuits).map(|_| next()).collect::<Result<_, _>>()?,
+        h_2: next()?,
+    })
+}
+
+/// Returns the number of commitments in a proof with the given batch sizes.
+fn num_commitments(batch_sizes: &[usize], has_mask_poly: bool) -> usize {
+    batch_sizes.iter().sum::<usize>() + usize::from(has_mask_poly) + 4 + 3 * batch_sizes.len()
+}
+
+/// Returns the given bits, packed into bytes.
+fn to_bitmap(bits: &[bool]) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|chunk| chunk.iter().enumerate().fold(0u8, |byte, (i, bit)| byte | (u8::from(*bit) << i)))
+        .collect()
+}
+
+/// Returns the given number of bits, unpacked from the given bytes.
+fn from_bitmap(bytes: &[u8], num_bits: usize) -> Vec<bool> {
+    (0..num_bits).map(|i| (bytes[i / 8] >> (i % 8)) & 1 == 1).collect()
+}
+
+impl<E: PairingEngine> CanonicalSerialize for PackedProofs<E> {
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        // Write the number of proofs.
+        CanonicalSerialize::serialize_with_mode(&u32::try_from(self.proofs.len())?, &mut writer, compress)?;
+        // Write the batch sizes column.
+        for proof in &self.proofs {
+            let batch_sizes = proof.batch_sizes.iter().map(|x| u32::try_from(*x)).collect::<Result<Vec<_>, _>>()?;
+            CanonicalSerialize::serialize_with_mode(&u32::try_from(batch_sizes.len())?, &mut writer, compress)?;
+            serialize_vec_without_len(batch_sizes.iter(), &mut writer, compress)?;
+        }
+        // Write the masking polynomial column.
+        let has_mask_polys = self.proofs.iter().map(|proof| proof.commitments.mask_poly.is_some()).collect::<Vec<_>>();
+        serialize_vec_without_len(to_bitmap(&has_mask_polys).iter(), &mut writer, compress)?;
+        // Write the commitments column, as the bitmap of repeated commitments, the distinct commitments,
+        // and the table indices of the repeated commitments.
+        let (is_repeated, table, indices) = self.to_commitment_table()?;
+        serialize_vec_without_len(to_bitmap(&is_repeated).iter(), &mut writer, compress)?;
+        serialize_vec_without_len(table.iter(), &mut writer, compress)?;
+        serialize_vec_without_len(indices.iter(), &mut writer, compress)?;
+        // Write the evaluations column.
+        for proof in &self.proofs {
+            Evaluations::serialize_with_mode(&proof.evaluations, &mut writer, compress)?;
+        }
+        // Write the prover messages column.
+        for proof in &self.proofs {
+            for third_sums in proof.third_msg.sums.iter() {
+                serialize_vec_without_len(third_sums.iter(), &mut writer, compress)?;
+            }
+            serialize_vec_without_len(proof.fourth_msg.sums.iter(), &mut writer, compress)?;
+        }
+        // Write the evaluation proofs column.
+        for proof in &self.proofs {
+            CanonicalSerialize::serialize_with_mode(&proof.pc_proof, &mut writer, compress)?;
+        }
+        Ok(())
+    }
+
+    fn serialized_size(&self, mode: Compress) -> usize {
+        let mut size = 4 + (self.proofs.len() + 7) / 8;
+        for proof in &self.proofs {
+            size += 4 + 4 * proof.batch_sizes.len();
+            size += Evaluations::serialized_size(&proof.evaluations, mode);
+            for third_sums in proof.third_msg.sums.iter() {
+                size += serialized_vec_size_without_len(third_sums, mode);
+            }
+            size += serialized_vec_size_without_len(&proof.fourth_msg.sums, mode);
+            size += CanonicalSerialize::serialized_size(&proof.pc_proof, mode);
+        }
+        if let Ok((is_repeated, table, indices)) = self.to_commitment_table() {
+            size += (is_repeated.len() + 7) / 8;
+            size += serialized_vec_size_without_len(&table, mode);
+            size += serialized_vec_size_without_len(&indices, mode);
+        }
+        size
+    }
+}
+
+impl<E: PairingEngine> Valid for PackedProofs<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.proofs.iter().try_for_each(Valid::check)
+    }
+}
+
+impl<E: PairingEngine> CanonicalDeserialize for PackedProofs<E> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        // Read the number of proofs.
+        let num_proofs: u32 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        // Read the batch sizes column.
+        let batch_sizes = (0..num_proofs)
+            .map(|_| {
+                let num_circuits: u32 = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+                (0..num_circuits)
+                    .map(|_| Ok(usize::try_from(u32::deserialize_with_mode(&mut reader, compress, validate)?)?))
+                    .collect::<Result<Vec<_>, SerializationError>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // Read the masking polynomial column.
+        let bitmap: Vec<u8> =
+            deserialize_vec_without_len(&mut reader, compress, validate, (batch_sizes.len() + 7) / 8)?;
+        let has_mask_polys = from_bitmap(&bitmap, batch_sizes.len());
+        // Read the commitments column.
+        let num_slots =
+            batch_sizes.iter().zip(&has_mask_polys).map(|(sizes, mask)| num_commitments(sizes, *mask)).sum();
+        let bitmap: Vec<u8> = deserialize_vec_without_len(&mut reader, compress, validate, (num_slots + 7) / 8)?;
+        let is_repeated = from_bitmap(&bitmap, num_slots);
+        let num_repeated = is_repeated.iter().filter(|is_repeated| **is_repeated).count();
+        let table: Vec<sonic_pc::Commitment<E>> =
+            deserialize_vec_without_len(&mut reader, compress, validate, num_slots - num_repeated)?;
+        let indices: Vec<u16> = deserialize_vec_without_len(&mut reader, compress, validate, num_repeated)?;
+        let (mut table_iter, mut indices_iter) = (table.iter(), indices.iter());
+        let mut slots = is_repeated.into_iter().map_while(|is_repeated| match is_repeated {
+            true => indices_iter.next().and_then(|index| table.get(usize::from(*index))).copied(),
+            false => table_iter.next().copied(),
+        });
+        let commitments = batch_sizes
+            .iter()
+            .zip(&has_mask_polys)
+            .map(|(sizes, has_mask_poly)| commitments_from(sizes, *has_mask_poly, &mut slots))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Read the evaluations column.
+        let evaluations = batch_sizes
+            .iter()
+            .map(|sizes| Evaluations::deserialize_with_mode(sizes, &mut reader, compress, validate))
+            .collect::<Result<Vec<_>, _>>()?;
+        // Read the prover messages column.
+        let mut messages = Vec::with_capacity(batch_sizes.len());
+        for sizes in &batch_sizes {
+            let third_msg_sums = sizes
+                .iter()
+                .map(|&batch_size| deserialize_vec_without_len(&mut reader, compress, validate, batch_size))
+                .collect::<Result<Vec<_>, _>>()?;
+            let fourth_msg_sums = deserialize_vec_without_len(&mut reader, compress, validate, sizes.len())?;
+            messages.push((ThirdMessage { sums: third_msg_sums }, FourthMessage { sums: fourth_msg_sums }));
+        }
+        // Read the evaluation proofs column, and assemble the proofs.
+        let mut proofs = Vec::with_capacity(batch_sizes.len());
+        for (((batch_sizes, commitments), evaluations), (third_msg, fourth_msg)) in
+            batch_sizes.into_iter().zip(commitments).zip(evaluations).zip(messages)
+        {
+            let pc_proof = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+            proofs.push(Proof { batch_sizes, commitments, evaluations, third_msg, fourth_msg, pc_proof });
+        }
+        Ok(Self { proofs })
+    }
+}
+
+impl<E: PairingEngine> ToBytes for PackedProofs<E> {
+    fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
+        Self::serialize_compressed(self, &mut w).map_err(|_| error("could not serialize PackedProofs"))
+    }
+}
+
+impl<E: PairingEngine> FromBytes for PackedProofs<E> {
+    fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
+        Self::deserialize_compressed(&mut r).map_err(|_| error("could not deserialize PackedProofs"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        polycommit::{kzg10::KZGProof, sonic_pc::BatchProof},
+        snark::varuna::prover::MatrixSums,
+    };
+    use snarkvm_curves::{
+        bls12_377::{Bls12_377, Fr, G1Affine, G1Projective},
+        AffineCurve,
+        ProjectiveCurve,
+    };
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    fn sample_proof(
+        rng: &mut TestRng,
+        num_circuits: usize,
+        batch_size: usize,
+        shared: &[sonic_pc::Commitment<Bls12_377>],
+    ) -> Proof<Bls12_377> {
+        let mut sample_commitment = |i: usize| match shared.get(i) {
+            Some(commitment) => *commitment,
+            None => sonic_pc::Commitment::<Bls12_377>(G1Projective::rand(rng).to_affine()),
+        };
+        let commitments = Commitments {
+            witness_commitments: (0..num_circuits * batch_size)
+                .map(|i| WitnessCommitments { w: sample_commitment(i) })
+                .collect(),
+            mask_poly: if batch_size % 2 == 0 { Some(sample_commitment(0)) } else { None },
+            h_0: sample_commitment(1),
+            g_1: sample_commitment(2),
+            h_1: sample_commitment(3),
+            g_a_commitments: (0..num_circuits).map(&mut sample_commitment).collect(),
+            g_b_commitments: (0..num_circuits).map(&mut sample_commitment).collect(),
+            g_c_commitments: (0..num_circuits).map(&mut sample_commitment).collect(),
+            h_2: sample_commitment(4),
+        };
+        let evaluations = Evaluations {
+            g_1_eval: Fr::rand(rng),
+            g_a_evals: (0..num_circuits).map(|_| Fr::rand(rng)).collect(),
+            g_b_evals: (0..num_circuits).map(|_| Fr::rand(rng)).collect(),
+            g_c_evals: (0..num_circuits).map(|_| Fr::rand(rng)).collect(),
+        };
+        let mut rand_sums = || MatrixSums::<Fr> { sum_a: Fr::rand(rng), sum_b: Fr::rand(rng), sum_c: Fr::rand(rng) };
+        let third_msg = ThirdMessage::<Fr> {
+            sums: (0..num_circuits).map(|_| (0..batch_size).map(|_| rand_sums()).collect()).collect(),
+        };
+        let fourth_msg = FourthMessage::<Fr> { sums: (0..num_circuits).map(|_| rand_sums()).collect() };
+        let pc_proof = sonic_pc::BatchLCProof {
+            proof: BatchProof(vec![
+                KZGProof { w: G1Affine::prime_subgroup_generator(), random_v: Some(Fr::rand(rng)) };
+                3
+            ]),
+        };
+        Proof { batch_sizes: vec![batch_size; num_circuits], commitments, evaluations, third_msg, fourth_msg, pc_proof }
+    }
+
+    #[test]
+    fn test_serializing_packed_proofs() {
+        let rng = &mut TestRng::default();
+
+        for num_proofs in 0..4 {
+            for (num_circuits, batch_size) in [(1, 1), (2, 3), (3, 2)] {
+                let proofs = (0..num_proofs).map(|_| sample_proof(rng, num_circuits, batch_size, &[])).collect();
+                let packed = PackedProofs::new(proofs);
+                for compress in [Compress::Yes, Compress::No] {
+                    let size = packed.serialized_size(compress);
+                    let mut serialized = vec![0; size];
+                    packed.serialize_with_mode(&mut serialized[..], compress).unwrap();
+                    let candidate =
+                        PackedProofs::deserialize_with_mode(&serialized[..], compress, Validate::Yes).unwrap();
+                    assert_eq!(packed, candidate);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_packed_proofs_deduplicate_commitments() {
+        let rng = &mut TestRng::default();
+
+        // Sample proofs which share some of their commitments.
+        let shared =
+            (0..5).map(|_| sonic_pc::Commitment::<Bls12_377>(G1Projective::rand(rng).to_affine())).collect::<Vec<_>>();
+        let proofs = (0..4).map(|_| sample_proof(rng, 2, 3, &shared)).collect::<Vec<_>>();
+
+        // Ensure the packed proofs are smaller than the proofs serialized individually.
+        let individual_size = proofs.iter().map(|proof| proof.compressed_size()).sum::<usize>();
+        let packed = PackedProofs::new(proofs);
+        let packed_bytes = packed.to_bytes_le().unwrap();
+        assert!(packed_bytes.len() < individual_size);
+        assert_eq!(packed, PackedProofs::read_le(&packed_bytes[..]).unwrap());
+    }
+}
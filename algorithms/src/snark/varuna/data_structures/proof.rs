@@ -53,7 +53,7 @@ pub struct Commitments<E: PairingEngine> {
 }
 
 impl<E: PairingEngine> Commitments<E> {
-    fn serialize_with_mode<W: snarkvm_utilities::Write>(
+    pub(super) fn serialize_with_mode<W: snarkvm_utilities::Write>(
         &self,
         mut writer: W,
         compress: Compress,
@@ -70,7 +70,7 @@ impl<E: PairingEngine> Commitments<E> {
         Ok(())
     }
 
-    fn serialized_size(&self, compress: Compress) -> usize {
+    pub(super) fn serialized_size(&self, compress: Compress) -> usize {
         serialized_vec_size_without_len(&self.witness_commitments, compress)
             .saturating_add(CanonicalSerialize::serialized_size(&self.mask_poly, compress))
             .saturating_add(CanonicalSerialize::serialized_size(&self.h_0, compress))
@@ -82,7 +82,7 @@ impl<E: PairingEngine> Commitments<E> {
             .saturating_add(CanonicalSerialize::serialized_size(&self.h_2, compress))
     }
 
-    fn deserialize_with_mode<R: snarkvm_utilities::Read>(
+    pub(super) fn deserialize_with_mode<R: snarkvm_utilities::Read>(
         batch_sizes: &[usize],
         mut reader: R,
         compress: Compress,
@@ -125,7 +125,7 @@ pub struct Evaluations<F: PrimeField> {
 }
 
 impl<F: PrimeField> Evaluations<F> {
-    fn serialize_with_mode<W: snarkvm_utilities::Write>(
+    pub(super) fn serialize_with_mode<W: snarkvm_utilities::Write>(
         &self,
         mut writer: W,
         compress: Compress,
@@ -137,14 +137,14 @@ impl<F: PrimeField> Evaluations<F> {
         Ok(())
     }
 
-    fn serialized_size(&self, compress: Compress) -> usize {
+    pub(super) fn serialized_size(&self, compress: Compress) -> usize {
         CanonicalSerialize::serialized_size(&self.g_1_eval, compress)
             .saturating_add(serialized_vec_size_without_len(&self.g_a_evals, compress))
             .saturating_add(serialized_vec_size_without_len(&self.g_b_evals, compress))
             .saturating_add(serialized_vec_size_without_len(&self.g_c_evals, compress))
     }
 
-    fn deserialize_with_mode<R: snarkvm_utilities::Read>(
+    pub(super) fn deserialize_with_mode<R: snarkvm_utilities::Read>(
         batch_sizes: &[usize],
         mut reader: R,
         compress: Compress,
@@ -223,7 +223,7 @@ impl<F: PrimeField> Valid for Evaluations<F> {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Proof<E: PairingEngine> {
     /// The number of instances being proven in this proof.
-    batch_sizes: Vec<usize>,
+    pub(super) batch_sizes: Vec<usize>,
 
     /// Commitments to prover polynomials.
     pub commitments: Commitments<E>,
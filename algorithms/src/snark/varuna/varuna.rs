@@ -15,22 +15,12 @@
 use super::Certificate;
 use crate::{
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{
-        Commitment,
-        CommitterUnionKey,
-        Evaluations,
-        LabeledCommitment,
-        QuerySet,
-        Randomness,
-        SonicKZG10,
-    },
+    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, QuerySet, SonicKZG10},
     r1cs::{ConstraintSynthesizer, SynthesisError},
     snark::varuna::{
-        ahp::{AHPError, AHPForR1CS, CircuitId, EvaluationsProvider},
-        proof,
+        ahp::{AHPError, AHPForR1CS, CircuitId},
         prover,
         witness_label,
-        CircuitProvingKey,
         CircuitVerifyingKey,
         Proof,
         SNARKMode,
@@ -41,18 +31,27 @@ use crate::{
     SNARKError,
     SNARK,
 };
-use rand::RngCore;
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{to_bytes_le, ToBytes};
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use core::marker::PhantomData;
 use itertools::Itertools;
-use rand::{CryptoRng, Rng};
-use std::{borrow::Borrow, collections::BTreeMap, ops::Deref, sync::Arc};
+use std::{borrow::Borrow, collections::BTreeMap};
 
-use crate::srs::UniversalProver;
+#[cfg(feature = "prover")]
+use crate::{
+    polycommit::sonic_pc::{CommitterUnionKey, Randomness},
+    snark::varuna::{ahp::EvaluationsProvider, proof, CircuitProvingKey},
+    srs::UniversalProver,
+};
+#[cfg(feature = "prover")]
+use anyhow::anyhow;
+#[cfg(feature = "prover")]
+use rand::{CryptoRng, Rng, RngCore};
+#[cfg(feature = "prover")]
+use std::{ops::Deref, sync::Arc};
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
 
@@ -69,6 +68,7 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, SM: SNARKMode> VarunaSNARK
 
     // TODO: implement optimizations resulting from batching
     //       (e.g. computing a common set of Lagrange powers, FFT precomputations, etc)
+    #[cfg(feature = "prover")]
     pub fn batch_circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
         universal_srs: &UniversalSRS<E>,
         circuits: &[&C],
@@ -206,8 +206,10 @@ where
     type FSParameters = FS::Parameters;
     type FiatShamirRng = FS;
     type Proof = Proof<E>;
+    #[cfg(feature = "prover")]
     type ProvingKey = CircuitProvingKey<E, SM>;
     type ScalarField = E::Fr;
+    #[cfg(feature = "prover")]
     type UniversalProver = UniversalProver<E>;
     type UniversalSRS = UniversalSRS<E>;
     type UniversalVerifier = UniversalVerifier<E>;
@@ -223,6 +225,7 @@ where
 
     /// Generates the circuit proving and verifying keys.
     /// This is a deterministic algorithm that anyone can rerun.
+    #[cfg(feature = "prover")]
     fn circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
         universal_srs: &Self::UniversalSRS,
         circuit: &C,
@@ -233,6 +236,7 @@ where
     }
 
     /// Prove that the verifying key commitments commit to the indexed circuit's polynomials
+    #[cfg(feature = "prover")]
     fn prove_vk(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -339,6 +343,7 @@ where
     /// This is the main entrypoint for creating proofs.
     /// You can find a specification of the prover algorithm in:
     /// https://github.com/AleoHQ/protocol-docs/tree/main/snark/varuna
+    #[cfg(feature = "prover")]
     fn prove_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "prover")]
 pub mod universal_prover;
+#[cfg(feature = "prover")]
 pub use universal_prover::*;
 
 pub mod universal_verifier;
@@ -17,6 +17,7 @@ use snarkvm_fields::PrimeField;
 use snarkvm_utilities::{CanonicalDeserialize, CanonicalSerialize, FromBytes, ToBytes};
 
 use anyhow::Result;
+#[cfg(feature = "prover")]
 use rand::{CryptoRng, Rng};
 use std::{borrow::Borrow, collections::BTreeMap, fmt::Debug};
 
@@ -42,9 +43,11 @@ pub trait SNARK {
         + Send
         + Sync;
     type Proof: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Send + Sync;
+    #[cfg(feature = "prover")]
     type ProvingKey: Clone + ToBytes + FromBytes + Send + Sync + Ord;
 
     type UniversalSRS: Clone;
+    #[cfg(feature = "prover")]
     type UniversalProver;
     type UniversalVerifier;
 
@@ -56,11 +59,13 @@ pub trait SNARK {
 
     fn universal_setup(config: usize) -> Result<Self::UniversalSRS, SNARKError>;
 
+    #[cfg(feature = "prover")]
     fn circuit_setup<C: ConstraintSynthesizer<Self::ScalarField>>(
         srs: &Self::UniversalSRS,
         circuit: &C,
     ) -> Result<(Self::ProvingKey, Self::VerifyingKey)>;
 
+    #[cfg(feature = "prover")]
     fn prove_vk(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -68,6 +73,7 @@ pub trait SNARK {
         proving_key: &Self::ProvingKey,
     ) -> Result<Self::Certificate, SNARKError>;
 
+    #[cfg(feature = "prover")]
     fn prove<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -80,6 +86,7 @@ pub trait SNARK {
         Self::prove_batch(universal_prover, fs_parameters, &keys_to_constraints, rng)
     }
 
+    #[cfg(feature = "prover")]
     fn prove_batch<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
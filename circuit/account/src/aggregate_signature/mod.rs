@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod verify;
+
+use crate::ComputeKey;
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, Boolean, Field, Group, Scalar};
+
+/// An aggregate of account signatures on the same message, from a known set of signers.
+#[derive(Clone)]
+pub struct AggregateSignature<A: Aleo> {
+    /// The nonce commitments of the signatures, in the order of the signers.
+    nonces: Vec<Group<A>>,
+    /// The aggregate prover response.
+    response: Scalar<A>,
+}
+
+#[cfg(console)]
+impl<A: Aleo> Inject for AggregateSignature<A> {
+    type Primitive = console::AggregateSignature<A::Network>;
+
+    /// Initializes an aggregate signature from the given mode and native aggregate signature.
+    fn new(mode: Mode, signature: Self::Primitive) -> AggregateSignature<A> {
+        Self {
+            nonces: signature.nonces().iter().map(|nonce| Group::new(mode, *nonce)).collect(),
+            response: Scalar::new(mode, signature.response()),
+        }
+    }
+}
+
+impl<A: Aleo> AggregateSignature<A> {
+    /// Returns the nonce commitments, in the order of the signers.
+    pub fn nonces(&self) -> &[Group<A>] {
+        &self.nonces
+    }
+
+    /// Returns the aggregate response.
+    pub const fn response(&self) -> &Scalar<A> {
+        &self.response
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Eject for AggregateSignature<A> {
+    type Primitive = console::AggregateSignature<A::Network>;
+
+    /// Ejects the mode of the aggregate signature.
+    fn eject_mode(&self) -> Mode {
+        (&self.nonces, &self.response).eject_mode()
+    }
+
+    /// Ejects the aggregate signature.
+    fn eject_value(&self) -> Self::Primitive {
+        Self::Primitive::from((&self.nonces, &self.response).eject_value())
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<A: Aleo> AggregateSignature<A> {
+    /// Returns `true` if the aggregate signature is valid for the given signers and message.
+    pub fn verify(&self, compute_keys: &[ComputeKey<A>], message: &[Field<A>]) -> Boolean<A> {
+        // Ensure there is one signer for each nonce commitment.
+        if self.nonces.is_empty() || self.nonces.len() != compute_keys.len() {
+            return Boolean::constant(false);
+        }
+
+        // Construct the hash input for the weights as (nonces, pk_sigs, message).
+        let mut preimage = Vec::with_capacity(self.nonces.len() + compute_keys.len() + message.len());
+        preimage.extend(self.nonces.iter().map(|nonce| nonce.to_x_coordinate()));
+        preimage.extend(compute_keys.iter().map(|compute_key| compute_key.pk_sig().to_x_coordinate()));
+        preimage.extend_from_slice(message);
+        // Compute the seed of the weights.
+        let seed = A::hash_psd8(&preimage);
+
+        // Compute the expected commitment as sum_i weight_i * (nonce_i - challenge_i * pk_sig_i).
+        let mut expected = Group::zero();
+        let mut index = Field::zero();
+        for (nonce, compute_key) in self.nonces.iter().zip_eq(compute_keys) {
+            // Compute the weight of the signature from the seed and its index.
+            let weight = A::hash_to_scalar_psd2(&[seed.clone(), index.clone()]);
+            index += Field::one();
+
+            // Retrieve pk_sig.
+            let pk_sig = compute_key.pk_sig();
+            // Retrieve pr_sig.
+            let pr_sig = compute_key.pr_sig();
+
+            // Construct the hash input as (nonce, pk_sig, pr_sig, address, message).
+            let mut preimage = Vec::with_capacity(4 + message.len());
+            preimage.extend([nonce, pk_sig, pr_sig].map(|point| point.to_x_coordinate()));
+            preimage.push(compute_key.to_address().to_field());
+            preimage.extend_from_slice(message);
+            // Compute the verifier challenge.
+            let challenge = A::hash_to_scalar_psd8(&preimage);
+
+            expected += (nonce - &(pk_sig * &challenge)) * weight;
+        }
+
+        // Return `true` if the aggregate response opens the expected commitment.
+        A::g_scalar_multiply(&self.response).is_equal(&expected)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: usize = 3;
+
+    fn check_verify(mode: Mode) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for num_signers in 1..=ITERATIONS {
+            // Sign a message with each signer, and aggregate the signatures.
+            let message = [Field::new(mode, Uniform::rand(rng)), Field::new(mode, Uniform::rand(rng))];
+            let signatures = (0..num_signers)
+                .map(|_| console::Signature::sign(&console::PrivateKey::new(rng)?, &message.eject_value(), rng))
+                .collect::<Result<Vec<_>>>()?;
+            let expected = console::AggregateSignature::aggregate(&signatures, &message.eject_value())?;
+
+            // Initialize the aggregate signature and the compute keys of the signers.
+            let aggregate = AggregateSignature::<Circuit>::new(mode, expected.clone());
+            assert_eq!(expected, aggregate.eject_value());
+            let compute_keys =
+                signatures.iter().map(|signature| ComputeKey::new(mode, signature.compute_key())).collect::<Vec<_>>();
+
+            Circuit::scope(&format!("{mode} {num_signers}"), || {
+                let candidate = aggregate.verify(&compute_keys, &message);
+                assert!(candidate.eject_value());
+                assert!(Circuit::is_satisfied_in_scope());
+            });
+            Circuit::reset();
+
+            // Ensure the aggregate signature is invalid for an incorrect message.
+            let failure_message = [Field::new(mode, Uniform::rand(rng)), Field::new(mode, Uniform::rand(rng))];
+            let candidate = aggregate.verify(&compute_keys, &failure_message);
+            assert!(!candidate.eject_value());
+            // Ensure the aggregate signature is invalid for a missing signer.
+            let candidate = aggregate.verify(&compute_keys[1..], &message);
+            assert!(!candidate.eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_constant() -> Result<()> {
+        check_verify(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_public() -> Result<()> {
+        check_verify(Mode::Public)
+    }
+
+    #[test]
+    fn test_verify_private() -> Result<()> {
+        check_verify(Mode::Private)
+    }
+}
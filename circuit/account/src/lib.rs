@@ -18,6 +18,9 @@
 #[cfg(test)]
 use snarkvm_circuit_network::AleoV0 as Circuit;
 
+pub mod aggregate_signature;
+pub use aggregate_signature::*;
+
 pub mod compute_key;
 pub use compute_key::*;
 
@@ -19,7 +19,13 @@ pub mod v0;
 pub use v0::*;
 
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
-use snarkvm_circuit_types::{environment::Environment, Boolean, Field, Group, Scalar};
+use snarkvm_circuit_types::{
+    environment::{Eject, Environment},
+    Boolean,
+    Field,
+    Group,
+    Scalar,
+};
 
 pub trait Aleo: Environment {
     /// The maximum number of field elements in data (must not exceed u16::MAX).
@@ -163,6 +169,24 @@ pub trait Aleo: Environment {
     /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
     fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Scalar<Self>;
 
+    /// Returns the Poseidon hash with an input rate of 2, for the given domain and input.
+    /// The domain must be a constant, and should match the domain used in `console::Network`.
+    fn hash_to_field_with_domain(domain: &Field<Self>, input: &[Field<Self>]) -> Field<Self> {
+        Self::hash_psd2(&prepend_domain::<Self>(domain, input))
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field, for the given domain and input.
+    /// The domain must be a constant, and should match the domain used in `console::Network`.
+    fn hash_to_scalar_with_domain(domain: &Field<Self>, input: &[Field<Self>]) -> Scalar<Self> {
+        Self::hash_to_scalar_psd2(&prepend_domain::<Self>(domain, input))
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the affine curve, for the given domain and input.
+    /// The domain must be a constant, and should match the domain used in `console::Network`.
+    fn hash_to_group_with_domain(domain: &Field<Self>, input: &[Field<Self>]) -> Group<Self> {
+        Self::hash_to_group_psd2(&prepend_domain::<Self>(domain, input))
+    }
+
     /// Returns `true` if the given Merkle path is valid for the given root and leaf.
     #[allow(clippy::ptr_arg)]
     fn verify_merkle_path_bhp<const DEPTH: u8>(
@@ -179,3 +203,13 @@ pub trait Aleo: Environment {
         leaf: &Vec<Field<Self>>,
     ) -> Boolean<Self>;
 }
+
+/// Returns the given input, prefixed by the given domain.
+/// Halts if the domain is not a constant, as it would not be bound to the circuit.
+fn prepend_domain<A: Aleo>(domain: &Field<A>, input: &[Field<A>]) -> Vec<Field<A>> {
+    // Ensure the domain is a constant.
+    if !domain.is_constant() {
+        A::halt("The domain separator must be a constant")
+    }
+    core::iter::once(domain.clone()).chain(input.iter().cloned()).collect()
+}
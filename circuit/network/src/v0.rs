@@ -497,9 +497,11 @@ impl Display for AleoV0 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use console::Network;
     use snarkvm_circuit_types::Field;
 
     type CurrentAleo = AleoV0;
+    type CurrentNetwork = <AleoV0 as Environment>::Network;
 
     /// Compute 2^EXPONENT - 1, in a purposefully constraint-inefficient manner for testing.
     fn create_example_circuit<E: Environment>() -> Field<E> {
@@ -533,6 +535,31 @@ mod tests {
         println!("{output}");
     }
 
+    #[test]
+    fn test_hash_with_domain() {
+        let rng = &mut TestRng::default();
+
+        // Sample the input, and prepare the domain.
+        let console_input = vec![Uniform::rand(rng), Uniform::rand(rng)];
+        let console_domain = snarkvm_console_types::Field::new_domain_separator("AleoTestDomain0");
+
+        // Compute the expected outputs.
+        let expected_field = CurrentNetwork::hash_to_field_with_domain(&console_domain, &console_input).unwrap();
+        let expected_scalar = CurrentNetwork::hash_to_scalar_with_domain(&console_domain, &console_input).unwrap();
+        let expected_group = CurrentNetwork::hash_to_group_with_domain(&console_domain, &console_input).unwrap();
+
+        // Inject the domain and input.
+        let domain = Field::<CurrentAleo>::constant(console_domain);
+        let input = Vec::<Field<CurrentAleo>>::new(Mode::Private, console_input);
+
+        // Ensure the circuit outputs match the console outputs.
+        assert_eq!(expected_field, CurrentAleo::hash_to_field_with_domain(&domain, &input).eject_value());
+        assert_eq!(expected_scalar, CurrentAleo::hash_to_scalar_with_domain(&domain, &input).eject_value());
+        assert_eq!(expected_group, CurrentAleo::hash_to_group_with_domain(&domain, &input).eject_value());
+        assert!(CurrentAleo::is_satisfied());
+        CurrentAleo::reset();
+    }
+
     #[test]
     fn test_circuit_scope() {
         CurrentAleo::scope("test_circuit_scope", || {
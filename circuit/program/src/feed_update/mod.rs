@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_circuit_account::Signature;
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, Address, Boolean, Field, U128, U64};
+
+/// A signed update of an off-chain data feed, verified against the feed signer.
+pub struct FeedUpdate<A: Aleo> {
+    /// The feed ID.
+    feed_id: Field<A>,
+    /// The value of the feed.
+    value: U128<A>,
+    /// The timestamp of the value.
+    timestamp: U64<A>,
+    /// The signature of the feed signer.
+    signature: Signature<A>,
+}
+
+impl<A: Aleo> From<(Field<A>, U128<A>, U64<A>, Signature<A>)> for FeedUpdate<A> {
+    /// Initializes a feed update from its parts.
+    fn from((feed_id, value, timestamp, signature): (Field<A>, U128<A>, U64<A>, Signature<A>)) -> Self {
+        Self { feed_id, value, timestamp, signature }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Inject for FeedUpdate<A> {
+    type Primitive = console::FeedUpdate<A::Network>;
+
+    /// Initializes a feed update from the given mode and console feed update.
+    fn new(mode: Mode, feed_update: Self::Primitive) -> Self {
+        Self {
+            feed_id: Field::new(mode, *feed_update.feed_id()),
+            value: U128::new(mode, *feed_update.value()),
+            timestamp: U64::new(mode, *feed_update.timestamp()),
+            signature: Signature::new(mode, *feed_update.signature()),
+        }
+    }
+}
+
+impl<A: Aleo> FeedUpdate<A> {
+    /// Returns `true` if the feed update is signed by the given signer.
+    pub fn verify(&self, signer: &Address<A>) -> Boolean<A> {
+        // Construct the message as `(domain, feed_id, value, timestamp)`.
+        let message = [
+            Field::constant(console::Field::new_domain_separator(console::FEED_UPDATE_DOMAIN)),
+            self.feed_id.clone(),
+            self.value.to_field(),
+            self.timestamp.to_field(),
+        ];
+        // Verify the signature.
+        self.signature.verify(signer, &message)
+    }
+
+    /// Returns the feed ID.
+    pub const fn feed_id(&self) -> &Field<A> {
+        &self.feed_id
+    }
+
+    /// Returns the value of the feed.
+    pub const fn value(&self) -> &U128<A> {
+        &self.value
+    }
+
+    /// Returns the timestamp of the value.
+    pub const fn timestamp(&self) -> &U64<A> {
+        &self.timestamp
+    }
+
+    /// Returns the signature of the feed signer.
+    pub const fn signature(&self) -> &Signature<A> {
+        &self.signature
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Eject for FeedUpdate<A> {
+    type Primitive = console::FeedUpdate<A::Network>;
+
+    /// Ejects the mode of the feed update.
+    fn eject_mode(&self) -> Mode {
+        (&self.feed_id, &self.value, &self.timestamp, &self.signature).eject_mode()
+    }
+
+    /// Ejects the feed update.
+    fn eject_value(&self) -> Self::Primitive {
+        Self::Primitive::from((
+            self.feed_id.eject_value(),
+            self.value.eject_value(),
+            self.timestamp.eject_value(),
+            self.signature.eject_value(),
+        ))
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+    use rand::Rng;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_verify(mode: Mode) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a feed update.
+            let private_key = snarkvm_console_account::PrivateKey::new(rng)?;
+            let signer = snarkvm_console_account::Address::try_from(&private_key)?;
+            let feed_update = console::FeedUpdate::sign(&private_key, Uniform::rand(rng), rng.gen(), rng.gen(), rng)?;
+
+            Circuit::scope(format!("FeedUpdate {i}"), || {
+                let candidate = FeedUpdate::<Circuit>::new(mode, feed_update.clone());
+                assert_eq!(feed_update, candidate.eject_value());
+
+                // Ensure the feed update verifies against its signer.
+                let signer = Address::new(mode, signer);
+                assert!(candidate.verify(&signer).eject_value());
+
+                // Ensure the feed update does not verify against another signer.
+                let other = Address::new(mode, console::Address::new(Uniform::rand(rng)));
+                assert!(!candidate.verify(&other).eject_value());
+            });
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_constant() -> Result<()> {
+        check_verify(Mode::Constant)
+    }
+
+    #[test]
+    fn test_verify_public() -> Result<()> {
+        check_verify(Mode::Public)
+    }
+
+    #[test]
+    fn test_verify_private() -> Result<()> {
+        check_verify(Mode::Private)
+    }
+}
@@ -22,6 +22,9 @@ use snarkvm_circuit_network::AleoV0 as Circuit;
 mod data;
 pub use data::*;
 
+mod feed_update;
+pub use feed_update::*;
+
 mod id;
 pub use id::*;
 
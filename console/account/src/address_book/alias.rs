@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The maximum number of characters in an alias.
+pub const MAX_ALIAS_LENGTH: usize = 64;
+/// The reserved prefix of an address, which an alias must not start with.
+const RESERVED_PREFIX: &str = "aleo1";
+
+/// A recipient, given either as an address or as an alias to be resolved.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AddressOrAlias<N: Network> {
+    /// An address.
+    Address(Address<N>),
+    /// An alias, to be resolved into an address.
+    Alias(String),
+}
+
+impl<N: Network> From<Address<N>> for AddressOrAlias<N> {
+    /// Initializes the recipient from an address.
+    fn from(address: Address<N>) -> Self {
+        Self::Address(address)
+    }
+}
+
+impl<N: Network> FromStr for AddressOrAlias<N> {
+    type Err = Error;
+
+    /// Parses the given string as an address, or otherwise as an alias.
+    fn from_str(string: &str) -> Result<Self> {
+        match Address::from_str(string) {
+            Ok(address) => Ok(Self::Address(address)),
+            Err(_) => {
+                check_alias(string)?;
+                Ok(Self::Alias(string.to_string()))
+            }
+        }
+    }
+}
+
+impl<N: Network> Display for AddressOrAlias<N> {
+    /// Prints the recipient as an address or alias.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Address(address) => Display::fmt(address, f),
+            Self::Alias(alias) => Display::fmt(alias, f),
+        }
+    }
+}
+
+/// Ensures the given alias is well-formed.
+/// An alias is a non-empty string of lowercase letters, digits, `-`, `_`, and `.`,
+/// which does not start with the address prefix.
+pub(super) fn check_alias(alias: &str) -> Result<()> {
+    ensure!(!alias.is_empty(), "The alias must not be empty");
+    ensure!(alias.len() <= MAX_ALIAS_LENGTH, "The alias must not exceed {MAX_ALIAS_LENGTH} characters");
+    ensure!(
+        alias.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_' | '.')),
+        "The alias '{alias}' must only contain lowercase letters, digits, '-', '_', or '.'"
+    );
+    ensure!(!alias.starts_with(RESERVED_PREFIX), "The alias '{alias}' must not start with '{RESERVED_PREFIX}'");
+    Ok(())
+}
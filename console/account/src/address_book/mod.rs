@@ -0,0 +1,196 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod alias;
+pub use alias::*;
+
+mod resolver;
+pub use resolver::*;
+
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Address;
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+/// A local address book, mapping human-readable aliases to addresses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressBook<N: Network> {
+    /// The mapping of aliases to addresses.
+    entries: BTreeMap<String, Address<N>>,
+}
+
+impl<N: Network> AddressBook<N> {
+    /// Initializes a new, empty address book.
+    pub fn new() -> Self {
+        Self { entries: BTreeMap::new() }
+    }
+
+    /// Opens the address book from the given JSON file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        // Ensure the file exists.
+        ensure!(path.exists(), "The address book does not exist at '{}'", path.display());
+        // Read the address book.
+        let entries: BTreeMap<String, Address<N>> = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+        // Ensure every alias is well-formed.
+        for alias in entries.keys() {
+            check_alias(alias)?;
+        }
+        Ok(Self { entries })
+    }
+
+    /// Writes the address book to the given JSON file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        Ok(serde_json::to_writer_pretty(BufWriter::new(File::create(path)?), &self.entries)?)
+    }
+
+    /// Returns the number of entries in the address book.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the address book is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the address for the given alias, if it exists.
+    pub fn get(&self, alias: &str) -> Option<&Address<N>> {
+        self.entries.get(alias)
+    }
+
+    /// Returns the aliases and addresses in the address book.
+    pub fn iter(&self) -> impl '_ + Iterator<Item = (&String, &Address<N>)> {
+        self.entries.iter()
+    }
+
+    /// Inserts the given alias and address into the address book.
+    /// Returns the previous address of the alias, if it existed.
+    pub fn insert(&mut self, alias: &str, address: Address<N>) -> Result<Option<Address<N>>> {
+        // Ensure the alias is well-formed.
+        check_alias(alias)?;
+        Ok(self.entries.insert(alias.to_string(), address))
+    }
+
+    /// Removes the given alias from the address book, returning its address if it existed.
+    pub fn remove(&mut self, alias: &str) -> Option<Address<N>> {
+        self.entries.remove(alias)
+    }
+
+    /// Resolves the given address or alias into an address.
+    /// Aliases are looked up in the address book, followed by each of the given resolvers, in order.
+    pub fn resolve(&self, recipient: &AddressOrAlias<N>, resolvers: &[&dyn AddressResolver<N>]) -> Result<Address<N>> {
+        match recipient {
+            AddressOrAlias::Address(address) => Ok(*address),
+            AddressOrAlias::Alias(alias) => {
+                // Check the address book.
+                if let Some(address) = self.get(alias) {
+                    return Ok(*address);
+                }
+                // Check the resolvers.
+                for resolver in resolvers {
+                    if let Some(address) = resolver.resolve(alias)? {
+                        return Ok(address);
+                    }
+                }
+                bail!("Failed to resolve the alias '{alias}' into an address")
+            }
+        }
+    }
+}
+
+impl<N: Network> Default for AddressBook<N> {
+    /// Initializes a new, empty address book.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// A resolver that resolves every alias to the given address.
+    struct SampleResolver(Address<CurrentNetwork>);
+
+    impl AddressResolver<CurrentNetwork> for SampleResolver {
+        fn resolve(&self, _alias: &str) -> Result<Option<Address<CurrentNetwork>>> {
+            Ok(Some(self.0))
+        }
+    }
+
+    fn sample_address(rng: &mut TestRng) -> Address<CurrentNetwork> {
+        Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_insert_and_resolve() {
+        let rng = &mut TestRng::default();
+
+        let (alice, bob) = (sample_address(rng), sample_address(rng));
+
+        let mut address_book = AddressBook::<CurrentNetwork>::new();
+        assert!(address_book.insert("alice", alice).unwrap().is_none());
+        assert_eq!(address_book.insert("alice", bob).unwrap(), Some(alice));
+        assert_eq!(address_book.insert("alice", alice).unwrap(), Some(bob));
+        assert_eq!(address_book.len(), 1);
+
+        // Ensure malformed aliases are rejected.
+        assert!(address_book.insert("", bob).is_err());
+        assert!(address_book.insert("Bob", bob).is_err());
+        assert!(address_book.insert(&alice.to_string(), bob).is_err());
+
+        // Ensure addresses and aliases resolve.
+        let by_address = AddressOrAlias::from_str(&bob.to_string()).unwrap();
+        assert_eq!(address_book.resolve(&by_address, &[]).unwrap(), bob);
+        let by_alias = AddressOrAlias::from_str("alice").unwrap();
+        assert_eq!(address_book.resolve(&by_alias, &[]).unwrap(), alice);
+
+        // Ensure unknown aliases fall through to the resolvers.
+        let unknown = AddressOrAlias::from_str("bob.aleo").unwrap();
+        assert!(address_book.resolve(&unknown, &[]).is_err());
+        assert_eq!(address_book.resolve(&unknown, &[&SampleResolver(bob)]).unwrap(), bob);
+
+        // Ensure the address book takes precedence over the resolvers.
+        assert_eq!(address_book.resolve(&by_alias, &[&SampleResolver(bob)]).unwrap(), alice);
+    }
+
+    #[test]
+    fn test_save_and_open() {
+        let rng = &mut TestRng::default();
+
+        let mut address_book = AddressBook::<CurrentNetwork>::new();
+        address_book.insert("alice", sample_address(rng)).unwrap();
+        address_book.insert("bob", sample_address(rng)).unwrap();
+
+        // Write the address book to a file.
+        let path = std::env::temp_dir().join(format!("address_book_{}.json", rng.gen::<u64>()));
+        address_book.save(&path).unwrap();
+
+        // Ensure the address book is read back.
+        let candidate = AddressBook::<CurrentNetwork>::open(&path).unwrap();
+        assert_eq!(address_book, candidate);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,28 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A resolver of aliases into addresses, i.e. a remote name service.
+pub trait AddressResolver<N: Network> {
+    /// Returns the address for the given alias, or `None` if the alias is not registered.
+    fn resolve(&self, alias: &str) -> Result<Option<Address<N>>>;
+}
+
+impl<N: Network> AddressResolver<N> for AddressBook<N> {
+    /// Returns the address for the given alias, or `None` if the alias is not in the address book.
+    fn resolve(&self, alias: &str) -> Result<Option<Address<N>>> {
+        Ok(self.get(alias).copied())
+    }
+}
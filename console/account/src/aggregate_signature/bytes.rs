@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for AggregateSignature<N> {
+    /// Reads an aggregate signature from a buffer.
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the number of nonce commitments.
+        let num_nonces = u16::read_le(&mut reader)?;
+        // Ensure the number of nonce commitments is nonzero.
+        if num_nonces == 0 {
+            return Err(error("An aggregate signature must contain at least one signature"));
+        }
+        // Read the nonce commitments.
+        let nonces = (0..num_nonces).map(|_| Group::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the aggregate response.
+        let response = Scalar::read_le(&mut reader)?;
+        Ok(Self { nonces, response })
+    }
+}
+
+impl<N: Network> ToBytes for AggregateSignature<N> {
+    /// Writes an aggregate signature to a buffer.
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        u16::try_from(self.nonces.len()).map_err(error)?.write_le(&mut writer)?;
+        self.nonces.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for num_signers in 1..5 {
+            // Sample a new aggregate signature.
+            let (signatures, message) = super::super::test_helpers::sample_signatures(num_signers, rng);
+            let aggregate = AggregateSignature::aggregate(&signatures, &message)?;
+
+            // Check the byte representation.
+            let aggregate_bytes = aggregate.to_bytes_le()?;
+            assert_eq!(aggregate, AggregateSignature::read_le(&aggregate_bytes[..])?);
+            assert!(AggregateSignature::<CurrentNetwork>::read_le(&aggregate_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
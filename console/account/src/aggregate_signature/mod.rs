@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod verify;
+
+use crate::{ComputeKey, Signature};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+/// An aggregate of account signatures on the same message, from a known set of signers.
+///
+/// The aggregate is a half-aggregation of Schnorr signatures `(nonces, response)`, where:
+///     nonces := [G^r_1, ..., G^r_n]
+///     response := sum_i weight_i * response_i
+///     weight_i := HashToScalar(HashPSD8(nonces, pk_sigs, message), i)
+/// Unlike a multi-signature, the signers do not interact, so any party holding the signatures can aggregate them.
+#[derive(Clone, PartialEq, Eq)]
+pub struct AggregateSignature<N: Network> {
+    /// The nonce commitments of the signatures, in the order of the signers.
+    nonces: Vec<Group<N>>,
+    /// The aggregate prover response.
+    response: Scalar<N>,
+}
+
+impl<N: Network> From<(Vec<Group<N>>, Scalar<N>)> for AggregateSignature<N> {
+    /// Derives the aggregate signature from a tuple `(nonces, response)`.
+    fn from((nonces, response): (Vec<Group<N>>, Scalar<N>)) -> Self {
+        Self { nonces, response }
+    }
+}
+
+impl<N: Network> AggregateSignature<N> {
+    /// The maximum number of signatures in an aggregate signature.
+    pub const MAX_SIGNATURES: usize = u16::MAX as usize;
+
+    /// Aggregates the given signatures on the given message, in the order of the signers.
+    pub fn aggregate(signatures: &[Signature<N>], message: &[Field<N>]) -> Result<Self> {
+        // Ensure the number of signatures is within bounds.
+        ensure!(!signatures.is_empty(), "Cannot aggregate zero signatures");
+        ensure!(
+            signatures.len() <= Self::MAX_SIGNATURES,
+            "Cannot aggregate more than {} signatures",
+            Self::MAX_SIGNATURES
+        );
+        // Ensure each signature is valid for the message.
+        for signature in signatures {
+            ensure!(
+                signature.verify(&signature.to_address(), message),
+                "Cannot aggregate an invalid signature from '{}'",
+                signature.to_address()
+            );
+        }
+
+        // Recover each nonce commitment as `g_r` := (response * G) + (challenge * pk_sig).
+        let nonces = signatures
+            .iter()
+            .map(|signature| {
+                N::g_scalar_multiply(&signature.response()) + (signature.compute_key().pk_sig() * signature.challenge())
+            })
+            .collect::<Vec<_>>();
+        // Retrieve the compute keys of the signers.
+        let compute_keys = signatures.iter().map(|signature| signature.compute_key()).collect::<Vec<_>>();
+
+        // Compute the aggregate response as the weighted sum of the responses.
+        let weights = Self::weights(&nonces, &compute_keys, message)?;
+        let response =
+            signatures.iter().zip_eq(&weights).map(|(signature, weight)| signature.response() * weight).sum();
+
+        Ok(Self { nonces, response })
+    }
+
+    /// Returns the nonce commitments, in the order of the signers.
+    pub fn nonces(&self) -> &[Group<N>] {
+        &self.nonces
+    }
+
+    /// Returns the aggregate prover response.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+
+    /// Returns the number of aggregated signatures.
+    pub fn len(&self) -> usize {
+        self.nonces.len()
+    }
+
+    /// Returns `true` if there are no aggregated signatures.
+    pub fn is_empty(&self) -> bool {
+        self.nonces.is_empty()
+    }
+
+    /// Returns the weight of each signature, which binds the signature to its position in the aggregate.
+    fn weights(nonces: &[Group<N>], compute_keys: &[ComputeKey<N>], message: &[Field<N>]) -> Result<Vec<Scalar<N>>> {
+        // Construct the hash input as (nonces, pk_sigs, message).
+        let mut preimage = Vec::with_capacity(nonces.len() + compute_keys.len() + message.len());
+        preimage.extend(nonces.iter().map(|nonce| nonce.to_x_coordinate()));
+        preimage.extend(compute_keys.iter().map(|compute_key| compute_key.pk_sig().to_x_coordinate()));
+        preimage.extend(message);
+        // Compute the seed of the weights.
+        let seed = N::hash_psd8(&preimage)?;
+        // Compute the weight of each signature from the seed and its index.
+        (0..u16::try_from(nonces.len())?).map(|index| N::hash_to_scalar_psd2(&[seed, Field::from_u16(index)])).collect()
+    }
+}
+
+impl<N: Network> Debug for AggregateSignature<N> {
+    /// Prints the aggregate signature as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("AggregateSignature").field("nonces", &self.nonces).field("response", &self.response).finish()
+    }
+}
+
+#[cfg(test)]
+mod test_helpers {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples the signatures of the given number of signers on a random message, and returns the message.
+    pub(super) fn sample_signatures(
+        num_signers: usize,
+        rng: &mut TestRng,
+    ) -> (Vec<Signature<CurrentNetwork>>, Vec<Field<CurrentNetwork>>) {
+        let message: Vec<_> = (0..4).map(|_| Uniform::rand(rng)).collect();
+        let signatures =
+            (0..num_signers).map(|_| Signature::sign(&PrivateKey::new(rng).unwrap(), &message, rng).unwrap()).collect();
+        (signatures, message)
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> AggregateSignature<N> {
+    /// Returns `true` if the aggregate signature is valid for the given signers and message, where:
+    ///     challenge_i := HashToScalar(nonce_i, pk_sig_i, pr_sig_i, address_i, message)
+    ///     G^response == sum_i weight_i * (nonce_i - challenge_i * pk_sig_i)
+    pub fn verify(&self, compute_keys: &[ComputeKey<N>], message: &[Field<N>]) -> bool {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
+            eprintln!("Cannot verify the aggregate signature: the signed message exceeds maximum allowed size");
+            return false;
+        }
+        // Ensure there is one signer for each nonce commitment.
+        if self.nonces.is_empty() || self.nonces.len() != compute_keys.len() {
+            return false;
+        }
+
+        // Compute the weight of each signature, and return `false` if this operation fails.
+        let weights = match Self::weights(&self.nonces, compute_keys, message) {
+            Ok(weights) => weights,
+            Err(_) => return false,
+        };
+
+        // Compute the expected commitment as sum_i weight_i * (nonce_i - challenge_i * pk_sig_i).
+        let mut expected = Group::<N>::zero();
+        for ((nonce, compute_key), weight) in self.nonces.iter().zip_eq(compute_keys).zip_eq(weights) {
+            // Retrieve pk_sig.
+            let pk_sig = compute_key.pk_sig();
+            // Retrieve pr_sig.
+            let pr_sig = compute_key.pr_sig();
+            // Derive the address from the compute key.
+            let address = compute_key.to_address();
+
+            // Construct the hash input as (nonce, pk_sig, pr_sig, address, message).
+            let mut preimage = Vec::with_capacity(4 + message.len());
+            preimage.extend([*nonce, pk_sig, pr_sig, *address].map(|point| point.to_x_coordinate()));
+            preimage.extend(message);
+            // Hash to derive the verifier challenge, and return `false` if this operation fails.
+            let challenge = match N::hash_to_scalar_psd8(&preimage) {
+                Ok(challenge) => challenge,
+                Err(_) => return false,
+            };
+
+            expected += (*nonce - pk_sig * challenge) * weight;
+        }
+
+        // Return `true` if the aggregate response opens the expected commitment.
+        N::g_scalar_multiply(&self.response) == expected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_aggregate_and_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for num_signers in 1..=ITERATIONS {
+            // Sample the signatures, and aggregate them.
+            let (signatures, message) = super::super::test_helpers::sample_signatures(num_signers, rng);
+            let compute_keys = signatures.iter().map(|signature| signature.compute_key()).collect::<Vec<_>>();
+            let aggregate = AggregateSignature::aggregate(&signatures, &message)?;
+            assert_eq!(aggregate.len(), num_signers);
+
+            // Check that the aggregate signature is valid for the signers and message.
+            assert!(aggregate.verify(&compute_keys, &message));
+
+            // Check that the aggregate signature is invalid for an incorrect message.
+            let failure_message: Vec<_> = (0..message.len()).map(|_| Uniform::rand(rng)).collect();
+            assert!(!aggregate.verify(&compute_keys, &failure_message));
+
+            // Check that the aggregate signature is invalid for an incorrect set of signers.
+            let mut failure_keys = compute_keys.clone();
+            failure_keys[0] = ComputeKey::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!aggregate.verify(&failure_keys, &message));
+            assert!(!aggregate.verify(&compute_keys[1..], &message));
+
+            // Check that the aggregate signature is invalid for reordered signers.
+            if num_signers > 1 {
+                let mut reordered_keys = compute_keys.clone();
+                reordered_keys.swap(0, 1);
+                assert!(!aggregate.verify(&reordered_keys, &message));
+            }
+
+            // Check that the aggregate signature is invalid for an incorrect response.
+            let failure = AggregateSignature { response: Uniform::rand(rng), ..aggregate.clone() };
+            assert!(!failure.verify(&compute_keys, &message));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_aggregate_fails() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure zero signatures cannot be aggregated.
+        assert!(AggregateSignature::<CurrentNetwork>::aggregate(&[], &[]).is_err());
+
+        // Ensure a signature on another message cannot be aggregated.
+        let (mut signatures, message) = super::super::test_helpers::sample_signatures(3, rng);
+        let other_message: Vec<_> = (0..message.len()).map(|_| Uniform::rand(rng)).collect();
+        signatures.push(Signature::sign(&PrivateKey::new(rng)?, &other_message, rng)?);
+        assert!(AggregateSignature::aggregate(&signatures, &message).is_err());
+        Ok(())
+    }
+}
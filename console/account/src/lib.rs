@@ -21,6 +21,16 @@ pub use snarkvm_console_types::{environment::prelude::*, Address, Field, Group,
 
 mod address;
 
+#[cfg(feature = "address_book")]
+pub mod address_book;
+#[cfg(feature = "address_book")]
+pub use address_book::*;
+
+#[cfg(feature = "aggregate_signature")]
+pub mod aggregate_signature;
+#[cfg(feature = "aggregate_signature")]
+pub use aggregate_signature::*;
+
 #[cfg(feature = "compute_key")]
 pub mod compute_key;
 #[cfg(feature = "compute_key")]
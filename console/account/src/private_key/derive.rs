@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static ACCOUNT_CHILD_SEED_DOMAIN: &str = "AleoAccountChildSeed0";
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the child private key at the given index, derived from the account seed.
+    ///
+    /// The child seed is computed as `Hash(domain, seed, index)`, so every child is deterministic
+    /// given the parent private key, and no child reveals the parent or its siblings.
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        // Construct the child seed domain separator.
+        let domain = Field::<N>::new_domain_separator(ACCOUNT_CHILD_SEED_DOMAIN);
+        // Derive the child private key from the child seed.
+        Self::try_from(N::hash_psd4(&[domain, self.seed, Field::from_u32(index)])?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_derive_child() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+            // Check that the derivation is deterministic.
+            let index = rng.gen();
+            assert_eq!(private_key.derive_child(index)?, private_key.derive_child(index)?);
+            // Check that distinct indices derive distinct children.
+            assert_ne!(private_key.derive_child(index)?, private_key.derive_child(index.wrapping_add(1))?);
+            assert_ne!(private_key, private_key.derive_child(index)?);
+        }
+        Ok(())
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod bytes;
+mod derive;
 mod serialize;
 mod string;
 mod try_from;
@@ -18,10 +18,21 @@ impl<N: Network> FromBytes for Signature<N> {
     /// Reads an account signature from a buffer.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let challenge = Scalar::new(FromBytes::read_le(&mut reader)?);
-        let response = Scalar::new(FromBytes::read_le(&mut reader)?);
-        let compute_key = ComputeKey::read_le(&mut reader)?;
-        Ok(Self { challenge, response, compute_key })
+        // Read the challenge bytes, where the most significant bits of the last byte are the variant tag.
+        let mut challenge = vec![0u8; Scalar::<N>::SIZE_IN_BYTES];
+        reader.read_exact(&mut challenge)?;
+        let last = challenge.last_mut().ok_or_else(|| error("Invalid signature challenge"))?;
+        let tag = *last >> VARIANT_TAG_SHIFT;
+        *last &= (1u8 << VARIANT_TAG_SHIFT) - 1;
+        // Match the variant.
+        match SignatureVariant::from_tag(tag).map_err(|_| error("Unsupported signature variant"))? {
+            SignatureVariant::Schnorr => {
+                let challenge = Scalar::new(FromBytes::read_le(&challenge[..])?);
+                let response = Scalar::new(FromBytes::read_le(&mut reader)?);
+                let compute_key = ComputeKey::read_le(&mut reader)?;
+                Ok(Self { challenge, response, compute_key })
+            }
+        }
     }
 }
 
@@ -29,6 +40,7 @@ impl<N: Network> ToBytes for Signature<N> {
     /// Writes an account signature to a buffer.
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the challenge, which is tagged as a Schnorr signature, as its variant tag is 0.
         self.challenge.write_le(&mut writer)?;
         self.response.write_le(&mut writer)?;
         self.compute_key.write_le(&mut writer)
@@ -56,6 +68,11 @@ mod tests {
             let signature_bytes = signature.to_bytes_le()?;
             assert_eq!(signature, Signature::read_le(&signature_bytes[..])?);
             assert!(Signature::<CurrentNetwork>::read_le(&signature_bytes[1..]).is_err());
+
+            // Check that an unsupported variant tag is rejected.
+            let mut tagged_bytes = signature_bytes.clone();
+            tagged_bytes[Scalar::<CurrentNetwork>::SIZE_IN_BYTES - 1] |= 1 << VARIANT_TAG_SHIFT;
+            assert!(Signature::<CurrentNetwork>::read_le(&tagged_bytes[..]).is_err());
         }
         Ok(())
     }
@@ -16,6 +16,7 @@ mod bitwise;
 mod bytes;
 mod from_bits;
 mod parse;
+mod scheme;
 mod serialize;
 mod size_in_bits;
 mod to_bits;
@@ -25,6 +26,8 @@ mod verify;
 #[cfg(feature = "private_key")]
 mod sign;
 
+pub use scheme::*;
+
 #[cfg(feature = "compute_key")]
 use crate::ComputeKey;
 #[cfg(feature = "private_key")]
@@ -78,6 +81,11 @@ impl<N: Network> Signature<N> {
     pub fn to_address(&self) -> Address<N> {
         self.compute_key.to_address()
     }
+
+    /// Returns the variant of the signature scheme.
+    pub const fn variant(&self) -> SignatureVariant {
+        <Self as SignatureScheme<N>>::VARIANT
+    }
 }
 
 impl<N: Network> TypeName for Signature<N> {
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of bits the variant tag is shifted by, in the last byte of a serialized signature challenge.
+///
+/// A canonical challenge is less than the scalar field modulus, so the most significant bits of its last byte
+/// are always zero. The variant tag is stored in these bits, so historical Schnorr signatures read as variant 0.
+pub(super) const VARIANT_TAG_SHIFT: u8 = 4;
+
+/// The variant of an account signature scheme, which is tagged in the serialized signature.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SignatureVariant {
+    /// A Schnorr signature over the embedded curve.
+    Schnorr,
+}
+
+impl SignatureVariant {
+    /// Returns the type tag of the variant.
+    pub const fn to_tag(self) -> u8 {
+        match self {
+            Self::Schnorr => 0,
+        }
+    }
+
+    /// Returns the variant for the given type tag.
+    pub fn from_tag(tag: u8) -> Result<Self> {
+        match tag {
+            0 => Ok(Self::Schnorr),
+            _ => bail!("Unsupported signature variant ({tag})"),
+        }
+    }
+}
+
+/// A scheme for account signatures.
+///
+/// Note: `Signature` is the only scheme, and parsing only accepts the Schnorr variant tag. The tag reserves
+/// the bits for a future variant, so historical signatures stay readable, but introducing a new scheme also
+/// requires a signature type that dispatches on the tag when parsing, as the layouts of the schemes differ.
+pub trait SignatureScheme<N: Network>: Sized {
+    /// The variant of the signature scheme.
+    const VARIANT: SignatureVariant;
+
+    /// Returns a signature for the given message, using the given private key.
+    #[cfg(feature = "private_key")]
+    fn sign<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, message: &[Field<N>], rng: &mut R) -> Result<Self>;
+
+    /// Returns `true` if the signature is valid for the given address and message.
+    fn verify(&self, address: &Address<N>, message: &[Field<N>]) -> bool;
+
+    /// Returns the address of the signer.
+    fn signer(&self) -> Address<N>;
+}
+
+impl<N: Network> SignatureScheme<N> for Signature<N> {
+    /// The Schnorr signature is the default account signature.
+    const VARIANT: SignatureVariant = SignatureVariant::Schnorr;
+
+    /// Returns a signature for the given message, using the given private key.
+    #[cfg(feature = "private_key")]
+    fn sign<R: Rng + CryptoRng>(private_key: &PrivateKey<N>, message: &[Field<N>], rng: &mut R) -> Result<Self> {
+        Signature::sign(private_key, message, rng)
+    }
+
+    /// Returns `true` if the signature is valid for the given address and message.
+    fn verify(&self, address: &Address<N>, message: &[Field<N>]) -> bool {
+        Signature::verify(self, address, message)
+    }
+
+    /// Returns the address of the signer.
+    fn signer(&self) -> Address<N> {
+        self.to_address()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns `true` if the given signature is valid, for any signature scheme.
+    fn check_scheme<S: SignatureScheme<CurrentNetwork>>(
+        private_key: &PrivateKey<CurrentNetwork>,
+        message: &[Field<CurrentNetwork>],
+        rng: &mut TestRng,
+    ) -> bool {
+        let signature = S::sign(private_key, message, rng).unwrap();
+        signature.verify(&signature.signer(), message) && signature.signer() == Address::try_from(private_key).unwrap()
+    }
+
+    #[test]
+    fn test_scheme() {
+        let mut rng = TestRng::default();
+
+        // Ensure the Schnorr signature is usable through the scheme.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng).unwrap();
+        let message = (0..4).map(|_| Uniform::rand(&mut rng)).collect::<Vec<_>>();
+        assert!(check_scheme::<Signature<CurrentNetwork>>(&private_key, &message, &mut rng));
+
+        // Ensure the variant tags round trip, and unknown tags are rejected.
+        assert_eq!(
+            SignatureVariant::from_tag(Signature::<CurrentNetwork>::VARIANT.to_tag()).unwrap(),
+            SignatureVariant::Schnorr
+        );
+        assert!(SignatureVariant::from_tag(1).is_err());
+    }
+}
@@ -31,6 +31,11 @@ pub use elligator2::Elligator2;
 mod keccak;
 pub use keccak::*;
 
+#[cfg(feature = "password")]
+mod password;
+#[cfg(feature = "password")]
+pub use password::PasswordCipher;
+
 mod pedersen;
 pub use pedersen::{Pedersen, Pedersen128, Pedersen64};
 
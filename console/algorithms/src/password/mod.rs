@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::prelude::*;
+
+use argon2::Argon2;
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    XChaCha20Poly1305,
+    XNonce,
+};
+
+/// A password-based authenticated encryption, for data at rest such as wallet stores and record backups.
+///
+/// The key is derived from the password and a salt with Argon2id, where the password is encoded as
+/// `length || bytes`, so distinct passwords never derive the same key. Each message is encrypted with
+/// XChaCha20-Poly1305 under a random nonce, and is laid out as `nonce || ciphertext || tag`.
+#[derive(Clone)]
+pub struct PasswordCipher {
+    /// The cipher, keyed with the derived key.
+    cipher: XChaCha20Poly1305,
+}
+
+impl PasswordCipher {
+    /// The number of bytes in a nonce.
+    pub const NONCE_SIZE: usize = 24;
+    /// The number of bytes in a salt.
+    pub const SALT_SIZE: usize = 16;
+    /// The number of bytes in an authentication tag.
+    pub const TAG_SIZE: usize = 16;
+
+    /// Initializes the cipher, with the key derived from the given password and salt.
+    pub fn new(password: &str, salt: &[u8; Self::SALT_SIZE]) -> Result<Self> {
+        // Encode the password with its length.
+        let mut encoded = Vec::with_capacity(8 + password.len());
+        encoded.extend_from_slice(&(password.len() as u64).to_le_bytes());
+        encoded.extend_from_slice(password.as_bytes());
+        // Derive the key.
+        let mut key = [0u8; 32];
+        Argon2::default()
+            .hash_password_into(&encoded, salt, &mut key)
+            .map_err(|error| anyhow!("Failed to derive the key from the password: {error}"))?;
+        Ok(Self { cipher: XChaCha20Poly1305::new(&key.into()) })
+    }
+
+    /// Returns a new salt, sampled from the given RNG.
+    pub fn sample_salt<R: Rng + CryptoRng>(rng: &mut R) -> [u8; Self::SALT_SIZE] {
+        rng.gen()
+    }
+
+    /// Returns the given plaintext, encrypted and authenticated together with the associated data.
+    pub fn encrypt<R: Rng + CryptoRng>(
+        &self,
+        plaintext: &[u8],
+        associated_data: &[u8],
+        rng: &mut R,
+    ) -> Result<Vec<u8>> {
+        // Sample the nonce.
+        let nonce: [u8; Self::NONCE_SIZE] = rng.gen();
+        // Encrypt the plaintext.
+        let ciphertext = self
+            .cipher
+            .encrypt(XNonce::from_slice(&nonce), Payload { msg: plaintext, aad: associated_data })
+            .map_err(|_| anyhow!("Failed to encrypt the message"))?;
+        Ok([&nonce[..], &ciphertext].concat())
+    }
+
+    /// Returns the plaintext of the given ciphertext, which fails if the password, the ciphertext,
+    /// or the associated data does not match.
+    pub fn decrypt(&self, ciphertext: &[u8], associated_data: &[u8]) -> Result<Vec<u8>> {
+        ensure!(ciphertext.len() >= Self::NONCE_SIZE + Self::TAG_SIZE, "The ciphertext is too short");
+        // Split the nonce from the ciphertext.
+        let (nonce, ciphertext) = ciphertext.split_at(Self::NONCE_SIZE);
+        // Decrypt the ciphertext.
+        self.cipher
+            .decrypt(XNonce::from_slice(nonce), Payload { msg: ciphertext, aad: associated_data })
+            .map_err(|_| anyhow!("Failed to decrypt the message: incorrect password or corrupted ciphertext"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let rng = &mut TestRng::default();
+
+        let salt = PasswordCipher::sample_salt(rng);
+        let cipher = PasswordCipher::new("correct horse battery staple", &salt).unwrap();
+
+        let ciphertext = cipher.encrypt(b"message", b"context", rng).unwrap();
+        assert_eq!(ciphertext.len(), PasswordCipher::NONCE_SIZE + 7 + PasswordCipher::TAG_SIZE);
+        assert_eq!(cipher.decrypt(&ciphertext, b"context").unwrap(), b"message");
+
+        // Ensure the associated data is authenticated.
+        assert!(cipher.decrypt(&ciphertext, b"another context").is_err());
+        // Ensure a modified ciphertext is rejected.
+        let mut modified = ciphertext.clone();
+        modified[PasswordCipher::NONCE_SIZE] ^= 1;
+        assert!(cipher.decrypt(&modified, b"context").is_err());
+        // Ensure another password or salt is rejected.
+        let other = PasswordCipher::new("incorrect password", &salt).unwrap();
+        assert!(other.decrypt(&ciphertext, b"context").is_err());
+        let other = PasswordCipher::new("correct horse battery staple", &PasswordCipher::sample_salt(rng)).unwrap();
+        assert!(other.decrypt(&ciphertext, b"context").is_err());
+    }
+
+    #[test]
+    fn test_password_encoding() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a password is not confused with its extension by a null byte.
+        let salt = PasswordCipher::sample_salt(rng);
+        let ciphertext = PasswordCipher::new("a", &salt).unwrap().encrypt(b"message", &[], rng).unwrap();
+        assert!(PasswordCipher::new("a\0", &salt).unwrap().decrypt(&ciphertext, &[]).is_err());
+        assert!(PasswordCipher::new("a", &salt).unwrap().decrypt(&ciphertext, &[]).is_ok());
+    }
+}
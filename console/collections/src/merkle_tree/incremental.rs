@@ -0,0 +1,258 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A Merkle tree that is updated incrementally as leaves are appended or removed,
+/// with the same root and paths as a [`MerkleTree`] over the same leaves.
+///
+/// The tree caches the hash of every complete subtree, along with the frontier,
+/// the hashes of the partially-filled nodes on its right edge. Appending `k` leaves
+/// to a tree with `n` leaves costs `O(k + log n)` hashes, instead of rehashing the tree.
+#[derive(Clone)]
+pub struct IncrementalMerkleTree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+> {
+    /// The leaf hasher for the Merkle tree.
+    leaf_hasher: LH,
+    /// The path hasher for the Merkle tree.
+    path_hasher: PH,
+    /// The computed root of the full Merkle tree.
+    root: PH::Hash,
+    /// The hashes of the complete subtrees, from the hashed leaves to the root level.
+    levels: Vec<Vec<PH::Hash>>,
+    /// The hashes of the partially-filled nodes on the right edge, for each level.
+    frontier: Vec<PH::Hash>,
+    /// The hashes of the empty subtrees, for each level.
+    empty_hashes: Vec<PH::Hash>,
+    /// The number of hashed leaves in the tree.
+    number_of_leaves: usize,
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    IncrementalMerkleTree<E, LH, PH, DEPTH>
+{
+    #[inline]
+    /// Initializes a new incremental Merkle tree with the given leaves.
+    pub fn new(leaf_hasher: &LH, path_hasher: &PH, leaves: &[LH::Leaf]) -> Result<Self> {
+        // Ensure the Merkle tree depth is greater than 0.
+        ensure!(DEPTH > 0, "Merkle tree depth must be greater than 0");
+        // Ensure the Merkle tree depth is less than or equal to 64.
+        ensure!(DEPTH <= 64u8, "Merkle tree depth must be less than or equal to 64");
+
+        // Compute the hash of the empty subtree at each level.
+        let mut empty_hashes = Vec::with_capacity(DEPTH as usize + 1);
+        empty_hashes.push(path_hasher.hash_empty()?);
+        for level in 0..DEPTH as usize {
+            empty_hashes.push(path_hasher.hash_children(&empty_hashes[level], &empty_hashes[level])?);
+        }
+
+        // Initialize the empty Merkle tree.
+        let mut tree = Self {
+            leaf_hasher: leaf_hasher.clone(),
+            path_hasher: path_hasher.clone(),
+            root: empty_hashes[0],
+            levels: vec![vec![]],
+            frontier: vec![],
+            empty_hashes,
+            number_of_leaves: 0,
+        };
+        // Append the leaves to the Merkle tree.
+        tree.append(leaves)?;
+        Ok(tree)
+    }
+
+    #[inline]
+    /// Updates the Merkle tree with the given new leaves appended to it.
+    pub fn append(&mut self, new_leaves: &[LH::Leaf]) -> Result<()> {
+        let timer = timer!("IncrementalMerkleTree::append");
+
+        // Ensure the updated tree does not exceed the depth bound, before altering the tree.
+        let number_of_leaves = match self.number_of_leaves.checked_add(new_leaves.len()) {
+            Some(number_of_leaves) => number_of_leaves,
+            None => bail!("Integer overflow when computing the number of leaves in the Merkle tree"),
+        };
+        Self::root_level(number_of_leaves)?;
+
+        // Hash the new leaves.
+        let leaf_hashes = self.leaf_hasher.hash_leaves(new_leaves)?;
+        lap!(timer, "Hashed {} new leaves", new_leaves.len());
+
+        self.levels[0].extend(leaf_hashes);
+        self.number_of_leaves = number_of_leaves;
+
+        // Hash the newly-completed subtrees, iterating from the leaves to the root level.
+        let mut level = 0;
+        while self.levels[level].len() >= 2 {
+            if self.levels.len() == level + 1 {
+                self.levels.push(vec![]);
+            }
+            // Construct the children for each new parent node in the next level.
+            let tuples = (self.levels[level + 1].len()..self.levels[level].len() / 2)
+                .map(|i| (self.levels[level][2 * i], self.levels[level][2 * i + 1]))
+                .collect::<Vec<_>>();
+            // Compute and store the hashes for each new parent node.
+            let hashes = self.path_hasher.hash_all_children(&tuples)?;
+            self.levels[level + 1].extend(hashes);
+            level += 1;
+        }
+        lap!(timer, "Hashed {} levels", level);
+
+        // Update the frontier and the root.
+        self.update_frontier()?;
+
+        finish!(timer);
+        Ok(())
+    }
+
+    #[inline]
+    /// Updates the Merkle tree with the last 'n' leaves removed from it.
+    pub fn remove_last_n(&mut self, n: usize) -> Result<()> {
+        ensure!(n > 0, "Cannot remove zero leaves from the Merkle tree");
+
+        // Determine the updated number of leaves, after removing the last 'n' leaves.
+        let updated_number_of_leaves = self.number_of_leaves.checked_sub(n).ok_or_else(|| {
+            anyhow!("Failed to remove '{n}' leaves from the Merkle tree, as it only contains {}", self.number_of_leaves)
+        })?;
+
+        // Discard the subtrees that contain a removed leaf.
+        for (level, hashes) in self.levels.iter_mut().enumerate() {
+            hashes.truncate(updated_number_of_leaves >> level);
+        }
+        self.number_of_leaves = updated_number_of_leaves;
+
+        // Update the frontier and the root.
+        self.update_frontier()
+    }
+
+    #[inline]
+    /// Returns the Merkle path for the given leaf index and leaf.
+    pub fn prove(&self, leaf_index: usize, leaf: &LH::Leaf) -> Result<MerklePath<E, DEPTH>> {
+        // Ensure the leaf index is valid.
+        ensure!(leaf_index < self.number_of_leaves, "The given Merkle leaf index is out of bounds");
+        // Ensure the leaf hash matches the one in the tree.
+        ensure!(
+            self.levels[0][leaf_index] == self.leaf_hasher.hash_leaf(leaf)?,
+            "The given Merkle leaf does not match the one in the Merkle tree"
+        );
+
+        // Collect the sibling hashes along the path, from the leaf to the root level.
+        let mut path = (0..Self::root_level(self.number_of_leaves)?)
+            .map(|level| self.node(level, (leaf_index >> level) ^ 1))
+            .collect::<Vec<_>>();
+
+        // If the Merkle path length is not equal to `DEPTH`, pad the path with the empty hash.
+        path.resize(DEPTH as usize, self.empty_hashes[0]);
+
+        // Return the Merkle path.
+        MerklePath::try_from((U64::new(leaf_index as u64), path))
+    }
+
+    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+    pub fn verify(&self, path: &MerklePath<E, DEPTH>, root: &PH::Hash, leaf: &LH::Leaf) -> bool {
+        path.verify(&self.leaf_hasher, &self.path_hasher, root, leaf)
+    }
+
+    /// Returns the Merkle root of the tree.
+    pub const fn root(&self) -> &PH::Hash {
+        &self.root
+    }
+
+    /// Returns the empty hash.
+    pub fn empty_hash(&self) -> &PH::Hash {
+        &self.empty_hashes[0]
+    }
+
+    /// Returns the leaf hashes from the Merkle tree.
+    pub fn leaf_hashes(&self) -> &[LH::Hash] {
+        &self.levels[0]
+    }
+
+    /// Returns the number of leaves in the Merkle tree.
+    pub const fn number_of_leaves(&self) -> usize {
+        self.number_of_leaves
+    }
+
+    /// Returns the level of the root of the (unpadded) tree, for the given number of leaves.
+    fn root_level(number_of_leaves: usize) -> Result<usize> {
+        // Compute the maximum number of leaves.
+        let max_leaves = match number_of_leaves.checked_next_power_of_two() {
+            Some(num_leaves) => num_leaves,
+            None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
+        };
+        // Compute the number of levels in the Merkle tree, ensuring it is within the depth bound.
+        Ok(tree_depth::<DEPTH>(max_leaves + (max_leaves - 1))? as usize)
+    }
+
+    /// Returns the hash of the node at the given level and index.
+    fn node(&self, level: usize, index: usize) -> PH::Hash {
+        // Compute the number of complete subtrees in the level.
+        let num_complete = self.number_of_leaves >> level;
+        if index < num_complete {
+            self.levels[level][index]
+        } else if index == num_complete && self.number_of_leaves & ((1 << level) - 1) != 0 {
+            self.frontier[level]
+        } else {
+            self.empty_hashes[level]
+        }
+    }
+
+    /// Recomputes the hashes of the partially-filled nodes and the root, from the cached subtrees.
+    fn update_frontier(&mut self) -> Result<()> {
+        let root_level = Self::root_level(self.number_of_leaves)?;
+
+        // Compute the partially-filled node for each level, iterating from the leaves to the root level.
+        self.frontier = vec![self.empty_hashes[0]];
+        for level in 1..=root_level {
+            let hash = match self.number_of_leaves & ((1 << level) - 1) != 0 {
+                true => {
+                    let index = self.number_of_leaves >> level;
+                    let left = self.node(level - 1, 2 * index);
+                    let right = self.node(level - 1, 2 * index + 1);
+                    self.path_hasher.hash_children(&left, &right)?
+                }
+                false => self.empty_hashes[level],
+            };
+            self.frontier.push(hash);
+        }
+
+        // Compute the root hash, by iterating from the root level up to `DEPTH`.
+        let mut root_hash = self.node(root_level, 0);
+        for _ in root_level..DEPTH as usize {
+            // Update the root hash, by hashing the current root hash with the empty hash.
+            root_hash = self.path_hasher.hash_children(&root_hash, &self.empty_hashes[0])?;
+        }
+        self.root = root_hash;
+        Ok(())
+    }
+}
+
+impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>>, const DEPTH: u8>
+    TryFrom<&MerkleTree<E, LH, PH, DEPTH>> for IncrementalMerkleTree<E, LH, PH, DEPTH>
+{
+    type Error = Error;
+
+    /// Initializes an incremental Merkle tree with the hashers and leaves of the given Merkle tree.
+    fn try_from(tree: &MerkleTree<E, LH, PH, DEPTH>) -> Result<Self> {
+        let mut incremental = Self::new(&tree.leaf_hasher, &tree.path_hasher, &[])?;
+        // Insert the existing leaf hashes, and hash the subtrees.
+        incremental.levels[0] = tree.leaf_hashes()?.to_vec();
+        incremental.number_of_leaves = tree.number_of_leaves;
+        incremental.append(&[])?;
+        Ok(incremental)
+    }
+}
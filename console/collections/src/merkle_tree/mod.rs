@@ -15,6 +15,9 @@
 mod helpers;
 pub use helpers::*;
 
+mod incremental;
+pub use incremental::*;
+
 mod path;
 pub use path::*;
 
@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::{Poseidon, BHP1024, BHP512};
+use snarkvm_console_types::prelude::Console;
+
+type CurrentEnvironment = Console;
+
+const ITERATIONS: usize = 10;
+
+/// Checks that the incremental Merkle tree matches a Merkle tree recomputed from the given leaves.
+fn check_matches_full_tree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    incremental: &IncrementalMerkleTree<E, LH, PH, DEPTH>,
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+) -> Result<()> {
+    // Recompute the Merkle tree from scratch.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+
+    assert_eq!(incremental.number_of_leaves(), merkle_tree.number_of_leaves());
+    assert_eq!(incremental.root(), merkle_tree.root());
+    assert_eq!(incremental.leaf_hashes(), merkle_tree.leaf_hashes()?);
+
+    // Ensure the Merkle path of each leaf matches, and is valid.
+    for (leaf_index, leaf) in leaves.iter().enumerate() {
+        let path = incremental.prove(leaf_index, leaf)?;
+        assert_eq!(path, merkle_tree.prove(leaf_index, leaf)?);
+        assert!(incremental.verify(&path, incremental.root(), leaf));
+    }
+    // Ensure a Merkle path for an out-of-bounds leaf fails.
+    if let Some(leaf) = leaves.first() {
+        assert!(incremental.prove(leaves.len(), leaf).is_err());
+    }
+    Ok(())
+}
+
+/// Runs the following test:
+/// 1. Construct the incremental Merkle tree for the leaves.
+/// 2. Append each batch of additional leaves, checking against a recomputed Merkle tree.
+/// 3. Remove the batches in reverse order, checking against a recomputed Merkle tree.
+fn check_incremental_merkle_tree<
+    E: Environment,
+    LH: LeafHash<Hash = PH::Hash>,
+    PH: PathHash<Hash = Field<E>>,
+    const DEPTH: u8,
+>(
+    leaf_hasher: &LH,
+    path_hasher: &PH,
+    leaves: &[LH::Leaf],
+    batches: &[Vec<LH::Leaf>],
+) -> Result<()> {
+    let mut incremental = IncrementalMerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    let mut all_leaves = leaves.to_vec();
+    check_matches_full_tree(&incremental, leaf_hasher, path_hasher, &all_leaves)?;
+
+    // Ensure the conversion from a Merkle tree matches.
+    let merkle_tree = MerkleTree::<E, LH, PH, DEPTH>::new(leaf_hasher, path_hasher, leaves)?;
+    check_matches_full_tree(&IncrementalMerkleTree::try_from(&merkle_tree)?, leaf_hasher, path_hasher, &all_leaves)?;
+
+    // Append each batch.
+    for batch in batches {
+        incremental.append(batch)?;
+        all_leaves.extend_from_slice(batch);
+        check_matches_full_tree(&incremental, leaf_hasher, path_hasher, &all_leaves)?;
+    }
+
+    // Remove each batch.
+    for batch in batches.iter().rev().filter(|batch| !batch.is_empty()) {
+        incremental.remove_last_n(batch.len())?;
+        all_leaves.truncate(all_leaves.len() - batch.len());
+        check_matches_full_tree(&incremental, leaf_hasher, path_hasher, &all_leaves)?;
+    }
+
+    // Ensure removing more leaves than the tree contains fails.
+    assert!(incremental.remove_last_n(leaves.len() + 1).is_err());
+    check_matches_full_tree(&incremental, leaf_hasher, path_hasher, leaves)
+}
+
+#[test]
+fn test_incremental_merkle_tree_bhp() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = BHP1024<CurrentEnvironment>;
+        type PH = BHP512<CurrentEnvironment>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let max_leaves = 1usize << core::cmp::min(DEPTH, 5);
+        let mut sample = |num_leaves: usize| {
+            (0..num_leaves).map(|_| Field::<CurrentEnvironment>::rand(rng).to_bits_le()).collect::<Vec<_>>()
+        };
+
+        for i in 0..ITERATIONS {
+            // Determine the leaves and the batches of additional leaves.
+            let num_leaves = core::cmp::min(max_leaves, i);
+            let leaves = sample(num_leaves);
+            let mut remaining = max_leaves - num_leaves;
+            let batches = (0..3)
+                .map(|j| {
+                    let num_additional_leaves = core::cmp::min(remaining, (i + j) % 4);
+                    remaining -= num_additional_leaves;
+                    sample(num_additional_leaves)
+                })
+                .collect::<Vec<_>>();
+
+            check_incremental_merkle_tree::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &leaves,
+                &batches,
+            )?;
+        }
+
+        // Ensure appending beyond the depth bound fails, and leaves the tree unchanged.
+        let mut incremental =
+            IncrementalMerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &[])?;
+        if DEPTH <= 5 {
+            let leaves = sample(max_leaves);
+            incremental.append(&leaves)?;
+            assert!(incremental.append(&sample(1)).is_err());
+            check_matches_full_tree(&incremental, &leaf_hasher, &path_hasher, &leaves)?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Spot check important depths.
+    assert!(run_test::<1>(&mut rng).is_ok());
+    assert!(run_test::<2>(&mut rng).is_ok());
+    assert!(run_test::<3>(&mut rng).is_ok());
+    assert!(run_test::<4>(&mut rng).is_ok());
+    assert!(run_test::<5>(&mut rng).is_ok());
+    assert!(run_test::<16>(&mut rng).is_ok());
+    assert!(run_test::<32>(&mut rng).is_ok());
+    assert!(run_test::<64>(&mut rng).is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_incremental_merkle_tree_poseidon() -> Result<()> {
+    fn run_test<const DEPTH: u8>(rng: &mut TestRng) -> Result<()> {
+        type LH = Poseidon<CurrentEnvironment, 4>;
+        type PH = Poseidon<CurrentEnvironment, 2>;
+
+        let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+        let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+        let max_leaves = 1usize << core::cmp::min(DEPTH, 6);
+        let mut sample = |num_leaves: usize| (0..num_leaves).map(|_| vec![Uniform::rand(rng)]).collect::<Vec<_>>();
+
+        for i in 0..ITERATIONS {
+            // Determine the leaves and the batches of additional leaves.
+            let num_leaves = core::cmp::min(max_leaves, i * 3);
+            let leaves = sample(num_leaves);
+            let mut remaining = max_leaves - num_leaves;
+            let batches = (0..4)
+                .map(|j| {
+                    let num_additional_leaves = core::cmp::min(remaining, (i * j) % 9);
+                    remaining -= num_additional_leaves;
+                    sample(num_additional_leaves)
+                })
+                .collect::<Vec<_>>();
+
+            check_incremental_merkle_tree::<CurrentEnvironment, LH, PH, DEPTH>(
+                &leaf_hasher,
+                &path_hasher,
+                &leaves,
+                &batches,
+            )?;
+        }
+        Ok(())
+    }
+
+    let mut rng = TestRng::default();
+
+    // Ensure DEPTH = 0 fails.
+    assert!(run_test::<0>(&mut rng).is_err());
+    // Spot check important depths.
+    assert!(run_test::<1>(&mut rng).is_ok());
+    assert!(run_test::<2>(&mut rng).is_ok());
+    assert!(run_test::<3>(&mut rng).is_ok());
+    assert!(run_test::<6>(&mut rng).is_ok());
+    assert!(run_test::<7>(&mut rng).is_ok());
+    assert!(run_test::<32>(&mut rng).is_ok());
+    Ok(())
+}
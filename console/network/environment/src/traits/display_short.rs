@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The marker for the omitted characters in an abbreviated string.
+pub const ELLIPSIS: char = '…';
+
+pub trait DisplayShort {
+    ///
+    /// Returns the abbreviated string of the object. (i.e. "aleo1abcd…uvwxyz")
+    ///
+    /// The human-readable prefix and the checksum (or type suffix) are always retained,
+    /// so that an abbreviated string can be checked against the full string it came from.
+    ///
+    fn to_short_string(&self) -> String;
+}
+
+///
+/// Returns the given string, retaining the first `num_leading` and the last `num_trailing` characters.
+/// If the string is not longer than the retained characters and the ellipsis, it is returned unchanged.
+///
+pub fn abbreviate(string: &str, num_leading: usize, num_trailing: usize) -> String {
+    let num_characters = string.chars().count();
+    match num_characters > num_leading + num_trailing + 1 {
+        true => {
+            let leading = string.chars().take(num_leading);
+            let trailing = string.chars().skip(num_characters - num_trailing);
+            leading.chain(core::iter::once(ELLIPSIS)).chain(trailing).collect()
+        }
+        false => string.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abbreviate() {
+        assert_eq!("abc…xyz", abbreviate("abcdefghijklmnopqrstuvwxyz", 3, 3));
+        assert_eq!("…", abbreviate("abcdef", 0, 0));
+        // Ensure short strings are unchanged.
+        assert_eq!("abcdefg", abbreviate("abcdefg", 3, 3));
+        assert_eq!("abcdefgh", abbreviate("abcdefgh", 4, 4));
+        assert_eq!("", abbreviate("", 3, 3));
+    }
+}
@@ -21,6 +21,9 @@ pub use arithmetic::*;
 pub mod bitwise;
 pub use bitwise::*;
 
+pub mod display_short;
+pub use display_short::*;
+
 pub mod from_bits;
 pub use from_bits::*;
 
@@ -153,6 +153,19 @@ impl<F: FieldTrait, const PREFIX: u16> Display for AleoID<F, PREFIX> {
     }
 }
 
+impl<F: FieldTrait, const PREFIX: u16> DisplayShort for AleoID<F, PREFIX> {
+    /// Returns the abbreviated ID, retaining the prefix and the checksum. (i.e. "at1abcd…uvwxyz")
+    #[inline]
+    fn to_short_string(&self) -> String {
+        /// The number of retained characters, after the prefix and separator.
+        const NUM_LEADING_DATA_CHARACTERS: usize = 4;
+        /// The number of characters in a Bech32m checksum.
+        const NUM_CHECKSUM_CHARACTERS: usize = 6;
+
+        abbreviate(&self.to_string(), Self::prefix().len() + 1 + NUM_LEADING_DATA_CHARACTERS, NUM_CHECKSUM_CHARACTERS)
+    }
+}
+
 impl<F: FieldTrait, const PREFIX: u16> Debug for AleoID<F, PREFIX> {
     #[inline]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
@@ -210,3 +223,30 @@ impl<F: FieldTrait, const PREFIX: u16> Distribution<AleoID<F, PREFIX>> for Stand
         AleoID::<F, PREFIX>(Uniform::rand(rng))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Network, Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_to_short_string() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new transaction ID.
+            let id: <CurrentNetwork as Network>::TransactionID = Uniform::rand(&mut rng);
+            let string = id.to_string();
+
+            let candidate = id.to_short_string();
+            assert_eq!(candidate.chars().count(), 7 + 1 + 6);
+            assert!(string.starts_with(candidate.split(ELLIPSIS).next().unwrap()));
+            assert!(string.ends_with(candidate.split(ELLIPSIS).nth(1).unwrap()));
+            assert!(candidate.starts_with("at1"));
+        }
+    }
+}
@@ -35,12 +35,14 @@ pub mod prelude {
 use crate::environment::prelude::*;
 use snarkvm_algorithms::{
     crypto_hash::PoseidonSponge,
-    snark::varuna::{CircuitProvingKey, CircuitVerifyingKey, VarunaHidingMode},
-    srs::{UniversalProver, UniversalVerifier},
+    snark::varuna::{CircuitVerifyingKey, VarunaHidingMode},
+    srs::UniversalVerifier,
     AlgebraicSponge,
 };
+#[cfg(feature = "prover")]
+use snarkvm_algorithms::{snark::varuna::CircuitProvingKey, srs::UniversalProver};
 use snarkvm_console_algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512};
-use snarkvm_console_collections::merkle_tree::{MerklePath, MerkleTree};
+use snarkvm_console_collections::merkle_tree::{IncrementalMerkleTree, MerklePath, MerkleTree};
 use snarkvm_console_types::{Field, Group, Scalar};
 use snarkvm_curves::PairingEngine;
 
@@ -50,6 +52,8 @@ use std::sync::Arc;
 
 /// A helper type for the BHP Merkle tree.
 pub type BHPMerkleTree<N, const DEPTH: u8> = MerkleTree<N, BHP1024<N>, BHP512<N>, DEPTH>;
+/// A helper type for the incremental BHP Merkle tree.
+pub type BHPIncrementalMerkleTree<N, const DEPTH: u8> = IncrementalMerkleTree<N, BHP1024<N>, BHP512<N>, DEPTH>;
 /// A helper type for the Poseidon Merkle tree.
 pub type PoseidonMerkleTree<N, const DEPTH: u8> = MerkleTree<N, Poseidon4<N>, Poseidon2<N>, DEPTH>;
 
@@ -59,6 +63,7 @@ pub type FiatShamir<N> = PoseidonSponge<Fq<N>, 2, 1>;
 pub type FiatShamirParameters<N> = <FiatShamir<N> as AlgebraicSponge<Fq<N>, 2>>::Parameters;
 
 /// Helper types for the Varuna proving and verifying key.
+#[cfg(feature = "prover")]
 pub(crate) type VarunaProvingKey<N> = CircuitProvingKey<<N as Environment>::PairingCurve, VarunaHidingMode>;
 pub(crate) type VarunaVerifyingKey<N> = CircuitVerifyingKey<<N as Environment>::PairingCurve>;
 
@@ -146,6 +151,8 @@ pub trait Network:
     const MAX_OPERANDS: usize = Self::MAX_INPUTS;
     /// The maximum number of instructions in a closure or function.
     const MAX_INSTRUCTIONS: usize = u16::MAX as usize;
+    /// The maximum number of registers (inputs and destinations) in a closure or function.
+    const MAX_REGISTERS: usize = u16::MAX as usize;
     /// The maximum number of commands in finalize.
     const MAX_COMMANDS: usize = u16::MAX as usize;
     /// The maximum number of write commands in finalize.
@@ -156,6 +163,18 @@ pub trait Network:
     /// The maximum number of outputs per transition.
     const MAX_OUTPUTS: usize = 16;
 
+    /// The maximum number of transitions in a transaction, including the fee transition.
+    /// This matches the capacity of a transaction Merkle tree (`2^TRANSACTION_DEPTH`).
+    const MAX_TRANSITIONS: usize = 32;
+    /// The maximum number of bytes in a serialized execution or fee proof.
+    const MAX_PROOF_SIZE_IN_BYTES: usize = 256 * 1024;
+    /// The maximum number of public inputs used to verify a single transition proof.
+    /// A transition commits to its own inputs and outputs, its caller, and every external call it makes.
+    const MAX_PUBLIC_INPUTS: usize = 7
+        + 2 * Self::MAX_INPUTS
+        + 2 * Self::MAX_OUTPUTS
+        + (Self::MAX_TRANSITIONS - 2) * (1 + 2 * Self::MAX_INPUTS + Self::MAX_OUTPUTS);
+
     /// The state root type.
     type StateRoot: Bech32ID<Field<Self>>;
     /// The block hash type.
@@ -171,12 +190,14 @@ pub trait Network:
     fn genesis_bytes() -> &'static [u8];
 
     /// Returns the proving key for the given function name in `credits.aleo`.
+    #[cfg(feature = "prover")]
     fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>>;
 
     /// Returns the verifying key for the given function name in `credits.aleo`.
     fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>>;
 
     /// Returns the `proving key` for the inclusion circuit.
+    #[cfg(feature = "prover")]
     fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>>;
 
     /// Returns the `verifying key` for the inclusion circuit.
@@ -189,6 +210,7 @@ pub trait Network:
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self>;
 
     /// Returns the Varuna universal prover.
+    #[cfg(feature = "prover")]
     fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve>;
 
     /// Returns the Varuna universal verifier.
@@ -332,6 +354,30 @@ pub trait Network:
     /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
     fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>>;
 
+    /// Returns the Poseidon hash with an input rate of 2, for the given domain and input.
+    ///
+    /// The domain must be unique to the application, and should be constructed
+    /// using `Field::new_domain_separator`, i.e. `Field::new_domain_separator("MyAppDomain")`.
+    fn hash_to_field_with_domain(domain: &Field<Self>, input: &[Field<Self>]) -> Result<Field<Self>> {
+        Self::hash_psd2(&[&[*domain], input].concat())
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the scalar field, for the given domain and input.
+    ///
+    /// The domain must be unique to the application, and should be constructed
+    /// using `Field::new_domain_separator`, i.e. `Field::new_domain_separator("MyAppDomain")`.
+    fn hash_to_scalar_with_domain(domain: &Field<Self>, input: &[Field<Self>]) -> Result<Scalar<Self>> {
+        Self::hash_to_scalar_psd2(&[&[*domain], input].concat())
+    }
+
+    /// Returns the Poseidon hash with an input rate of 2 on the affine curve, for the given domain and input.
+    ///
+    /// The domain must be unique to the application, and should be constructed
+    /// using `Field::new_domain_separator`, i.e. `Field::new_domain_separator("MyAppDomain")`.
+    fn hash_to_group_with_domain(domain: &Field<Self>, input: &[Field<Self>]) -> Result<Group<Self>> {
+        Self::hash_to_group_psd2(&[&[*domain], input].concat())
+    }
+
     /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
     fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>>;
 
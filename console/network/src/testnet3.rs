@@ -67,14 +67,18 @@ lazy_static! {
     /// The Poseidon hash function, using a rate of 8.
     pub static ref POSEIDON_8: Poseidon8<Testnet3> = Poseidon8::<Testnet3>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
 
-    pub static ref CREDITS_PROVING_KEYS: IndexMap<String, Arc<VarunaProvingKey<Console>>> = {
+    pub static ref CREDITS_VERIFYING_KEYS: IndexMap<String, Arc<VarunaVerifyingKey<Console>>> = {
         let mut map = IndexMap::new();
-        snarkvm_parameters::insert_credit_keys!(map, VarunaProvingKey<Console>, Prover);
+        snarkvm_parameters::insert_credit_keys!(map, VarunaVerifyingKey<Console>, Verifier);
         map
     };
-    pub static ref CREDITS_VERIFYING_KEYS: IndexMap<String, Arc<VarunaVerifyingKey<Console>>> = {
+}
+
+#[cfg(feature = "prover")]
+lazy_static! {
+    pub static ref CREDITS_PROVING_KEYS: IndexMap<String, Arc<VarunaProvingKey<Console>>> = {
         let mut map = IndexMap::new();
-        snarkvm_parameters::insert_credit_keys!(map, VarunaVerifyingKey<Console>, Verifier);
+        snarkvm_parameters::insert_credit_keys!(map, VarunaProvingKey<Console>, Prover);
         map
     };
 }
@@ -132,7 +136,7 @@ impl Network for Testnet3 {
     type TransitionID = AleoID<Field<Self>, { hrp2!("au") }>;
 
     /// The network edition.
-    const EDITION: u16 = 0;
+    const EDITION: u16 = 1;
     /// The network ID.
     const ID: u16 = 3;
     /// The function name for the inclusion circuit.
@@ -146,6 +150,7 @@ impl Network for Testnet3 {
     }
 
     /// Returns the proving key for the given function name in `credits.aleo`.
+    #[cfg(feature = "prover")]
     fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>> {
         CREDITS_PROVING_KEYS
             .get(&function_name)
@@ -160,6 +165,7 @@ impl Network for Testnet3 {
     }
 
     /// Returns the `proving key` for the inclusion circuit.
+    #[cfg(feature = "prover")]
     fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>> {
         static INSTANCE: OnceCell<Arc<VarunaProvingKey<Console>>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
@@ -201,6 +207,7 @@ impl Network for Testnet3 {
     }
 
     /// Returns the Varuna universal prover.
+    #[cfg(feature = "prover")]
     fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
         static INSTANCE: OnceCell<UniversalProver<<Console as Environment>::PairingCurve>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
@@ -494,4 +501,43 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    fn test_hash_with_domain() {
+        let rng = &mut TestRng::default();
+
+        // Sample the input, and prepare two distinct domains.
+        let input = vec![Field::rand(rng), Field::rand(rng)];
+        let domain = Field::new_domain_separator("AleoTestDomain0");
+        let other_domain = Field::new_domain_separator("AleoTestDomain1");
+
+        // Ensure the domain is prepended to the input.
+        let expected = [vec![domain], input.clone()].concat();
+        assert_eq!(
+            CurrentNetwork::hash_to_field_with_domain(&domain, &input).unwrap(),
+            CurrentNetwork::hash_psd2(&expected).unwrap()
+        );
+        assert_eq!(
+            CurrentNetwork::hash_to_scalar_with_domain(&domain, &input).unwrap(),
+            CurrentNetwork::hash_to_scalar_psd2(&expected).unwrap()
+        );
+        assert_eq!(
+            CurrentNetwork::hash_to_group_with_domain(&domain, &input).unwrap(),
+            CurrentNetwork::hash_to_group_psd2(&expected).unwrap()
+        );
+
+        // Ensure distinct domains produce distinct outputs.
+        assert_ne!(
+            CurrentNetwork::hash_to_field_with_domain(&domain, &input).unwrap(),
+            CurrentNetwork::hash_to_field_with_domain(&other_domain, &input).unwrap()
+        );
+        assert_ne!(
+            CurrentNetwork::hash_to_scalar_with_domain(&domain, &input).unwrap(),
+            CurrentNetwork::hash_to_scalar_with_domain(&other_domain, &input).unwrap()
+        );
+        assert_ne!(
+            CurrentNetwork::hash_to_group_with_domain(&domain, &input).unwrap(),
+            CurrentNetwork::hash_to_group_with_domain(&other_domain, &input).unwrap()
+        );
+    }
 }
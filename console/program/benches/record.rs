@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_console_account::{Address, PrivateKey, ViewKey};
+use snarkvm_console_network::{environment::prelude::*, Network, Testnet3};
+use snarkvm_console_program::{Ciphertext, Entry, Identifier, Literal, Owner, Plaintext, Record};
+use snarkvm_console_types::{Field, Scalar};
+
+use criterion::{Criterion, Throughput};
+use indexmap::IndexMap;
+
+type CurrentNetwork = Testnet3;
+
+const NUM_RECORDS: usize = 1_000;
+
+/// Samples the given number of encrypted records, where every other record is owned by the given address.
+fn sample_records(
+    address: Address<CurrentNetwork>,
+    num_records: usize,
+    rng: &mut TestRng,
+) -> Vec<Record<CurrentNetwork, Ciphertext<CurrentNetwork>>> {
+    // Sample an unrelated address.
+    let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+    (0..num_records)
+        .map(|i| {
+            // Prepare the owner.
+            let owner = match i % 2 == 0 {
+                true => Owner::Private(Plaintext::from(Literal::Address(address))),
+                false => Owner::Private(Plaintext::from(Literal::Address(other_address))),
+            };
+            // Prepare the record.
+            let randomizer = Scalar::rand(rng);
+            let data = IndexMap::from_iter(vec![(
+                Identifier::from_str("amount").unwrap(),
+                Entry::Private(Plaintext::from(Literal::Field(Field::rand(rng)))),
+            )]);
+            let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+            let record =
+                Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(owner, data, nonce).unwrap();
+            // Encrypt the record.
+            record.encrypt(randomizer).unwrap()
+        })
+        .collect()
+}
+
+fn record_decrypt(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    // Sample the view key and address.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let view_key = ViewKey::try_from(&private_key).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+    // Sample the records.
+    let records = sample_records(address, NUM_RECORDS, rng);
+
+    let mut group = c.benchmark_group("record_decrypt");
+    group.throughput(Throughput::Elements(NUM_RECORDS as u64));
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            let _records = records
+                .iter()
+                .filter(|record| record.is_owner(&view_key))
+                .map(|record| record.decrypt(&view_key).unwrap())
+                .collect::<Vec<_>>();
+        })
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| {
+            let _records = Record::decrypt_batch(&view_key, &records);
+        })
+    });
+    group.finish();
+}
+
+criterion_group! {
+    name = record;
+    config = Criterion::default().sample_size(10);
+    targets = record_decrypt
+}
+
+criterion_main!(record);
@@ -104,6 +104,8 @@ fn cast_field_to_type<N: Network>(input: &Field<N>, to_type: LiteralType) -> Res
 }
 
 /// Casts a group literal to the given literal type.
+/// Casts to types other than an address or group go through the x-coordinate, so a cast to a scalar
+/// fails if the x-coordinate exceeds the scalar field modulus, rather than silently truncating it.
 fn cast_group_to_type<N: Network>(input: &Group<N>, to_type: LiteralType) -> Result<Literal<N>> {
     match to_type {
         LiteralType::Address => Ok(Literal::Address(Address::new(*input))),
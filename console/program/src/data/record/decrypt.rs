@@ -41,7 +41,7 @@ impl<N: Network> Record<N, Ciphertext<N>> {
     }
 
     /// Decrypts `self` into plaintext using the given randomizers.
-    fn decrypt_with_randomizers(&self, randomizers: &[Field<N>]) -> Result<Record<N, Plaintext<N>>> {
+    pub(super) fn decrypt_with_randomizers(&self, randomizers: &[Field<N>]) -> Result<Record<N, Plaintext<N>>> {
         // Initialize an index to keep track of the randomizer index.
         let mut index: usize = 0;
 
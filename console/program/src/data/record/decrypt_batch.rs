@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A trait to decrypt a batch of records with a view key.
+pub trait DecryptBatch<N: Network> {
+    /// Decrypts the given records, in parallel.
+    /// Returns the decrypted record for each record owned by the view key, and `None` otherwise.
+    fn decrypt_batch(&self, records: &[Record<N, Ciphertext<N>>]) -> Vec<Option<Record<N, Plaintext<N>>>>;
+}
+
+impl<N: Network> DecryptBatch<N> for ViewKey<N> {
+    /// Decrypts the given records, in parallel.
+    /// Returns the decrypted record for each record owned by the view key, and `None` otherwise.
+    fn decrypt_batch(&self, records: &[Record<N, Ciphertext<N>>]) -> Vec<Option<Record<N, Plaintext<N>>>> {
+        Record::decrypt_batch(self, records)
+    }
+}
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Decrypts the given records using the given view key, in parallel.
+    /// Returns the decrypted record for each record owned by the view key, and `None` otherwise.
+    ///
+    /// Unlike calling `Self::is_owner` followed by `Self::decrypt`, the record view key is computed
+    /// once per record, and the remaining entries are only decrypted if the owner matches.
+    pub fn decrypt_batch(view_key: &ViewKey<N>, records: &[Self]) -> Vec<Option<Record<N, Plaintext<N>>>> {
+        // Compute the address, once for all records.
+        let address = view_key.to_address();
+        // Compute the x-coordinate of the address.
+        let address_x_coordinate = address.to_x_coordinate();
+        // Decrypt the records that belong to the view key.
+        cfg_iter!(records).map(|record| record.decrypt_if_owner(view_key, &address, &address_x_coordinate)).collect()
+    }
+
+    /// Decrypts `self` into plaintext, if the record is owned by the given view key.
+    fn decrypt_if_owner(
+        &self,
+        view_key: &ViewKey<N>,
+        address: &Address<N>,
+        address_x_coordinate: &Field<N>,
+    ) -> Option<Record<N, Plaintext<N>>> {
+        // Compute the record view key.
+        let record_view_key = (self.nonce * **view_key).to_x_coordinate();
+
+        // Check the owner, before computing the remaining randomizers.
+        let is_owner = match &self.owner {
+            // If the owner is public, check if the address is the owner.
+            Owner::Public(owner) => &owner.to_x_coordinate() == address_x_coordinate,
+            // If the owner is private, decrypt the owner using the 0th randomizer.
+            Owner::Private(ciphertext) => {
+                let randomizer = N::hash_many_psd8(&[N::encryption_domain(), record_view_key], 1);
+                &(ciphertext[0] - randomizer[0]) == address_x_coordinate
+            }
+        };
+        if !is_owner {
+            return None;
+        }
+
+        // Determine the number of randomizers needed to decrypt the record.
+        let num_randomizers = self.num_randomizers().ok()?;
+        // Prepare a randomizer for each field element.
+        let randomizers = N::hash_many_psd8(&[N::encryption_domain(), record_view_key], num_randomizers);
+        // Decrypt the record.
+        let record = self.decrypt_with_randomizers(&randomizers).ok()?;
+        // Ensure the record owner matches the address.
+        match **record.owner() == *address {
+            true => Some(record),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Literal;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    /// Samples an encrypted record, owned by the given address.
+    fn sample_record(
+        address: Address<CurrentNetwork>,
+        is_public: bool,
+        rng: &mut TestRng,
+    ) -> Result<Record<CurrentNetwork, Ciphertext<CurrentNetwork>>> {
+        // Prepare the owner.
+        let owner = match is_public {
+            true => Owner::Public(address),
+            false => Owner::Private(Plaintext::from(Literal::Address(address))),
+        };
+        // Prepare the record.
+        let randomizer = Scalar::rand(rng);
+        let record = Record {
+            owner,
+            data: IndexMap::from_iter(vec![
+                (Identifier::from_str("a")?, Entry::Private(Plaintext::from(Literal::Field(Field::rand(rng))))),
+                (Identifier::from_str("b")?, Entry::Public(Plaintext::from(Literal::Scalar(Scalar::rand(rng))))),
+            ]),
+            nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+        };
+        // Encrypt the record.
+        record.encrypt(randomizer)
+    }
+
+    #[test]
+    fn test_decrypt_batch() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the view key and address of the owner.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+        // Sample an unrelated address.
+        let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+
+        // Sample records owned by a mix of the two addresses.
+        let records = (0..ITERATIONS)
+            .map(|i| match i % 3 {
+                0 => sample_record(other_address, i % 2 == 0, &mut rng),
+                _ => sample_record(address, i % 2 == 0, &mut rng),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Decrypt the records in a batch.
+        let candidates = Record::decrypt_batch(&view_key, &records);
+        assert_eq!(candidates.len(), records.len());
+        // Ensure the view key decrypts the same batch.
+        assert_eq!(view_key.decrypt_batch(&records), candidates);
+
+        // Ensure the batch matches decrypting each record individually.
+        for (record, candidate) in records.iter().zip_eq(candidates) {
+            match record.is_owner(&view_key) {
+                true => assert_eq!(Some(record.decrypt(&view_key)?), candidate),
+                false => assert!(candidate.is_none()),
+            }
+        }
+        Ok(())
+    }
+}
@@ -124,4 +124,45 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_is_owner_public_record() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a view key and address.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+
+        // Prepare a record with a public owner and public entries.
+        let randomizer = Scalar::rand(&mut rng);
+        let record = Record {
+            owner: Owner::Public(address),
+            data: IndexMap::from_iter(vec![(
+                Identifier::from_str("amount")?,
+                Entry::Public(Plaintext::from(Literal::Field(Field::rand(&mut rng)))),
+            )]),
+            nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+        };
+        assert!(record.is_public());
+
+        // Ensure the encrypted record remains public, and belongs to the owner.
+        let ciphertext = record.encrypt(randomizer)?;
+        assert!(ciphertext.is_public());
+        assert!(ciphertext.is_owner(&view_key));
+        assert_eq!(ciphertext.decrypt(&view_key)?, record);
+
+        // Ensure a record with a private entry is not public.
+        let record = Record {
+            owner: Owner::Public(address),
+            data: IndexMap::from_iter(vec![(
+                Identifier::from_str("amount")?,
+                Entry::Private(Plaintext::from(Literal::Field(Field::rand(&mut rng)))),
+            )]),
+            nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+        };
+        assert!(!record.is_public());
+        assert!(!record.encrypt(randomizer)?.is_public());
+        Ok(())
+    }
 }
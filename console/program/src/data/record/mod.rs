@@ -20,6 +20,8 @@ pub use helpers::Owner;
 
 mod bytes;
 mod decrypt;
+mod decrypt_batch;
+pub use decrypt_batch::DecryptBatch;
 mod encrypt;
 mod equal;
 mod find;
@@ -27,6 +29,7 @@ mod is_owner;
 mod num_randomizers;
 mod parse_ciphertext;
 mod parse_plaintext;
+mod predicates;
 mod serial_number;
 mod serialize;
 mod tag;
@@ -35,7 +38,7 @@ mod to_commitment;
 mod to_fields;
 
 use crate::{Access, Ciphertext, Identifier, Literal, Plaintext, ProgramID};
-use snarkvm_console_account::{Address, PrivateKey, ViewKey};
+use snarkvm_console_account::{Address, ComputeKey, PrivateKey, Signature, ViewKey};
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Boolean, Field, Group, Scalar};
 
@@ -99,6 +102,12 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
     pub const fn nonce(&self) -> &Group<N> {
         &self.nonce
     }
+
+    /// Returns `true` if the owner and all entries of the program record are stored in plaintext.
+    /// A public record is scanned by comparing its owner to an address, without decrypting it.
+    pub fn is_public(&self) -> bool {
+        self.owner.is_public() && self.data.values().all(|entry| !matches!(entry, Entry::Private(..)))
+    }
 }
 
 impl<N: Network, Private: Visibility> Record<N, Private> {
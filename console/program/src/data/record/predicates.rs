@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{RecordPredicate, RecordType, Value};
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Returns the inputs that satisfy the spending predicates of the record, given its record type and
+    /// the preimage of its hash lock, if any. These inputs must immediately follow the record in a function call.
+    pub fn to_predicate_inputs(
+        &self,
+        record_type: &RecordType<N>,
+        preimage: Option<Field<N>>,
+    ) -> Result<Vec<Value<N>>> {
+        record_type
+            .predicates()?
+            .into_iter()
+            .map(|predicate| {
+                // Retrieve the predicate entry.
+                let entry = match self.data.get(&Identifier::from_str(predicate.entry_name())?) {
+                    Some(Entry::Public(Plaintext::Literal(literal, _))) => literal,
+                    _ => bail!("The record is missing its public '{}' entry", predicate.entry_name()),
+                };
+                match (predicate, entry) {
+                    (RecordPredicate::HashLock, Literal::Field(hash_lock)) => {
+                        // Ensure the preimage opens the hash lock.
+                        let Some(preimage) = preimage else { bail!("The record requires a preimage to be spent") };
+                        ensure!(
+                            RecordPredicate::hash_lock(&preimage)? == *hash_lock,
+                            "Incorrect preimage for the hash lock"
+                        );
+                        Ok(Value::from(Literal::Field(preimage)))
+                    }
+                    (RecordPredicate::SpendAfter, Literal::U32(spend_after)) => {
+                        Ok(Value::from(Literal::U32(*spend_after)))
+                    }
+                    _ => bail!("The '{}' entry of the record has the wrong type", predicate.entry_name()),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_predicate_inputs() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a record with both predicates.
+        let preimage = Field::<CurrentNetwork>::rand(rng);
+        let hash_lock = RecordPredicate::hash_lock(&preimage)?;
+        let record_type = RecordType::<CurrentNetwork>::from_str(
+            "record lock: owner as address.private; hash_lock as field.public; spend_after as u32.public;",
+        )?;
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {}.private, hash_lock: {hash_lock}.public, spend_after: 10u32.public, _nonce: 0group.public }}",
+            Address::<CurrentNetwork>::rand(rng)
+        ))?;
+
+        // Ensure the inputs reveal the preimage and the height lock, in order.
+        let inputs = record.to_predicate_inputs(&record_type, Some(preimage))?;
+        assert_eq!(inputs, vec![Value::from_str(&preimage.to_string())?, Value::from_str("10u32")?]);
+
+        // Ensure a missing or incorrect preimage is rejected.
+        assert!(record.to_predicate_inputs(&record_type, None).is_err());
+        assert!(record.to_predicate_inputs(&record_type, Some(Field::rand(rng))).is_err());
+        Ok(())
+    }
+}
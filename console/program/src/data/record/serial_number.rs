@@ -32,4 +32,121 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         // Compute `serial_number` as `Commit(commitment, sn_nonce)`.
         N::commit_bhp512(&(N::serial_number_domain(), commitment).to_bits_le(), &sn_nonce)
     }
+
+    /// Returns the serial number and `gamma` for the given private key and commitment, along with a proof
+    /// that the serial number was derived from the private key of the signer, for `verify_serial_number`.
+    ///
+    /// The proof shows that `log_G(pk_sig) == log_H(gamma)` where `H := HashToGroup(commitment)`, as in a request:
+    ///     challenge := HashToScalar(r * G, r * H, pk_sig, pr_sig, address, gamma, commitment)
+    ///     response := r - challenge * sk_sig
+    pub fn prove_serial_number<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        commitment: Field<N>,
+        rng: &mut R,
+    ) -> Result<(Field<N>, Group<N>, Signature<N>)> {
+        // Retrieve `sk_sig`.
+        let sk_sig = private_key.sk_sig();
+        // Compute the compute key.
+        let compute_key = ComputeKey::try_from(private_key)?;
+        // Compute the address.
+        let address = compute_key.to_address();
+
+        // Compute the generator `H` as `HashToGroup(commitment)`.
+        let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
+        // Compute `gamma` as `sk_sig * H`.
+        let gamma = h * sk_sig;
+        // Compute the serial number from `gamma`.
+        let serial_number = Self::serial_number_from_gamma(&gamma, commitment)?;
+
+        // Sample a random nonce.
+        let r = Scalar::rand(rng);
+        // Compute `challenge` as `HashToScalar(r * G, r * H, pk_sig, pr_sig, address, gamma, commitment)`.
+        let mut preimage =
+            [N::g_scalar_multiply(&r), h * r, compute_key.pk_sig(), compute_key.pr_sig(), *address, gamma]
+                .map(|point| point.to_x_coordinate())
+                .to_vec();
+        preimage.push(commitment);
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+        // Compute `response` as `r - challenge * sk_sig`.
+        let response = r - challenge * sk_sig;
+
+        Ok((serial_number, gamma, Signature::from((challenge, response, compute_key))))
+    }
+
+    /// Returns `true` if the given serial number was derived from the given commitment by the given address,
+    /// as proven by `prove_serial_number`. This lets a view-only service check a claimed serial number
+    /// without the private key, and without constructing an input circuit.
+    pub fn verify_serial_number(
+        address: &Address<N>,
+        commitment: Field<N>,
+        serial_number: Field<N>,
+        gamma: &Group<N>,
+        proof: &Signature<N>,
+    ) -> bool {
+        // Retrieve the compute key.
+        let compute_key = proof.compute_key();
+        // Ensure the compute key belongs to the address.
+        if compute_key.to_address() != *address {
+            return false;
+        }
+        // Ensure the serial number is derived from `gamma`.
+        match Self::serial_number_from_gamma(gamma, commitment) {
+            Ok(candidate_serial_number) if candidate_serial_number == serial_number => (),
+            _ => return false,
+        }
+
+        // Compute the generator `H` as `HashToGroup(commitment)`.
+        let h = match N::hash_to_group_psd2(&[N::serial_number_domain(), commitment]) {
+            Ok(h) => h,
+            Err(_) => return false,
+        };
+        // Compute `g_r` as `(response * G) + (challenge * pk_sig)`, equivalent to `r * G`.
+        let g_r = N::g_scalar_multiply(&proof.response()) + (compute_key.pk_sig() * proof.challenge());
+        // Compute `h_r` as `(response * H) + (challenge * gamma)`, equivalent to `r * H`.
+        let h_r = (h * proof.response()) + (*gamma * proof.challenge());
+
+        // Compute the candidate challenge.
+        let mut preimage = [g_r, h_r, compute_key.pk_sig(), compute_key.pr_sig(), **address, *gamma]
+            .map(|point| point.to_x_coordinate())
+            .to_vec();
+        preimage.push(commitment);
+        match N::hash_to_scalar_psd8(&preimage) {
+            Ok(candidate_challenge) => candidate_challenge == proof.challenge(),
+            Err(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verify_serial_number() -> Result<()> {
+        type CurrentRecord = Record<CurrentNetwork, Plaintext<CurrentNetwork>>;
+
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let commitment = Field::rand(rng);
+
+        // Ensure the proven serial number matches the serial number of the private key.
+        let (serial_number, gamma, proof) = CurrentRecord::prove_serial_number(&private_key, commitment, rng)?;
+        assert_eq!(serial_number, CurrentRecord::serial_number(private_key, commitment)?);
+
+        // Ensure the proof verifies.
+        assert!(CurrentRecord::verify_serial_number(&address, commitment, serial_number, &gamma, &proof));
+
+        // Ensure the proof fails for a different address, commitment, serial number, or gamma.
+        let other_address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        assert!(!CurrentRecord::verify_serial_number(&other_address, commitment, serial_number, &gamma, &proof));
+        assert!(!CurrentRecord::verify_serial_number(&address, Field::rand(rng), serial_number, &gamma, &proof));
+        assert!(!CurrentRecord::verify_serial_number(&address, commitment, Field::rand(rng), &gamma, &proof));
+        assert!(!CurrentRecord::verify_serial_number(&address, commitment, serial_number, &Group::rand(rng), &proof));
+        Ok(())
+    }
 }
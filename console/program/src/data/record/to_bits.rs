@@ -81,3 +81,64 @@ impl<N: Network> ToBits for Record<N, Ciphertext<N>> {
         self.nonce.write_bits_be(vec);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_record() -> Record<CurrentNetwork, Plaintext<CurrentNetwork>> {
+        Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            r"{
+    owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private,
+    a: true.private,
+    b: 123456789field.public,
+    c: {
+        d: 0group.private
+    },
+    _nonce: 0group.public
+}",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_to_bits_le() {
+        let record = sample_record();
+
+        // The bits are the owner, the number of data bits as a u32, each identifier and entry in order, and the nonce.
+        let mut data_bits_le = vec![];
+        for (identifier, entry) in record.data() {
+            data_bits_le.extend(identifier.to_bits_le());
+            data_bits_le.extend(entry.to_bits_le());
+        }
+        let mut expected = record.owner().to_bits_le();
+        expected.extend(u32::try_from(data_bits_le.len()).unwrap().to_bits_le());
+        expected.extend(data_bits_le);
+        expected.extend(record.nonce().to_bits_le());
+        assert_eq!(record.to_bits_le(), expected);
+
+        // Ensure the entries are ordered as declared, so reordering them changes the bits.
+        let mut reordered = record.clone();
+        reordered.data.swap_indices(0, 1);
+        assert_ne!(record.to_bits_le(), reordered.to_bits_le());
+    }
+
+    #[test]
+    fn test_to_bits_be() {
+        let record = sample_record();
+
+        let mut data_bits_be = vec![];
+        for (identifier, entry) in record.data() {
+            data_bits_be.extend(identifier.to_bits_be());
+            data_bits_be.extend(entry.to_bits_be());
+        }
+        let mut expected = record.owner().to_bits_be();
+        expected.extend(u32::try_from(data_bits_be.len()).unwrap().to_bits_be());
+        expected.extend(data_bits_be);
+        expected.extend(record.nonce().to_bits_be());
+        assert_eq!(record.to_bits_be(), expected);
+    }
+}
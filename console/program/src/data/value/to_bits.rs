@@ -35,3 +35,28 @@ impl<N: Network> ToBits for Value<N> {
         };
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_bits() {
+        // Ensure a value has the bits of the plaintext or record it holds, without a variant tag.
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: 1u8, b: true }").unwrap();
+        let value = Value::Plaintext(plaintext.clone());
+        assert_eq!(value.to_bits_le(), plaintext.to_bits_le());
+        assert_eq!(value.to_bits_be(), plaintext.to_bits_be());
+
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(
+            "{ owner: aleo14tlamssdmg3d0p5zmljma573jghe2q9n6wz29qf36re2glcedcpqfg4add.private, _nonce: 0group.public }",
+        )
+        .unwrap();
+        let value = Value::Record(record.clone());
+        assert_eq!(value.to_bits_le(), record.to_bits_le());
+        assert_eq!(value.to_bits_be(), record.to_bits_be());
+    }
+}
@@ -25,7 +25,7 @@ mod plaintext_type;
 pub use plaintext_type::PlaintextType;
 
 mod record_type;
-pub use record_type::{EntryType, RecordType};
+pub use record_type::{EntryType, RecordPredicate, RecordType};
 
 mod register_type;
 pub use register_type::RegisterType;
@@ -18,6 +18,9 @@ pub use entry_type::EntryType;
 mod helpers;
 use helpers::PublicOrPrivate;
 
+mod predicate;
+pub use predicate::RecordPredicate;
+
 mod bytes;
 mod parse;
 mod serialize;
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{LiteralType, PlaintextType};
+use snarkvm_console_types::Field;
+
+/// A spending predicate of a record, which is declared as a reserved public entry of its record type.
+///
+/// A function that consumes a record with predicates must declare one public input for each predicate,
+/// immediately after the record input, in the order below:
+///   - `hash_lock as field.public` requires the preimage of the hash lock, as a `field.public` input.
+///   - `spend_after as u32.public` requires the height lock, as a `u32.public` input, which finalize
+///     checks against the block height.
+///
+/// The predicates apply to programs deployed from network edition `RecordPredicate::EDITION`,
+/// so the records of programs deployed earlier keep their meaning.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RecordPredicate {
+    /// The record may only be spent by revealing the preimage of its hash lock.
+    HashLock,
+    /// The record may only be spent at or after its height lock.
+    SpendAfter,
+}
+
+impl RecordPredicate {
+    /// The record predicates, in the order of their inputs.
+    pub const ALL: [Self; 2] = [Self::HashLock, Self::SpendAfter];
+    /// The network edition from which the record predicates apply.
+    pub const EDITION: u16 = 1;
+
+    /// Returns the reserved entry name of the predicate.
+    pub const fn entry_name(&self) -> &'static str {
+        match self {
+            Self::HashLock => "hash_lock",
+            Self::SpendAfter => "spend_after",
+        }
+    }
+
+    /// Returns the literal type of the predicate entry, and of its input.
+    pub const fn literal_type(&self) -> LiteralType {
+        match self {
+            Self::HashLock => LiteralType::Field,
+            Self::SpendAfter => LiteralType::U32,
+        }
+    }
+
+    /// Returns the hash lock for the given preimage, as its BHP-256 hash.
+    pub fn hash_lock<N: Network>(preimage: &Field<N>) -> Result<Field<N>> {
+        N::hash_bhp256(&preimage.to_bits_le())
+    }
+}
+
+impl<N: Network> RecordType<N> {
+    /// Returns the spending predicates of the record type, in the order of their inputs.
+    pub fn predicates(&self) -> Result<Vec<RecordPredicate>> {
+        let mut predicates = Vec::new();
+        for predicate in RecordPredicate::ALL {
+            if let Some(entry_type) = self.entries.get(&Identifier::from_str(predicate.entry_name())?) {
+                // Ensure the reserved entry is declared with the predicate type.
+                let literal_type = predicate.literal_type();
+                ensure!(
+                    entry_type == &EntryType::Public(PlaintextType::Literal(literal_type)),
+                    "The '{}' entry of record '{}' must be declared as '{literal_type}.public'",
+                    predicate.entry_name(),
+                    self.name
+                );
+                predicates.push(predicate);
+            }
+        }
+        Ok(predicates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_predicates() -> Result<()> {
+        // Ensure a record without reserved entries has no predicates.
+        let record =
+            RecordType::<CurrentNetwork>::from_str("record token: owner as address.private; amount as u64.private;")?;
+        assert!(record.predicates()?.is_empty());
+
+        // Ensure the predicates are returned in the order of their inputs.
+        let record = RecordType::<CurrentNetwork>::from_str(
+            "record lock: owner as address.private; spend_after as u32.public; hash_lock as field.public;",
+        )?;
+        assert_eq!(record.predicates()?, RecordPredicate::ALL.to_vec());
+
+        // Ensure a reserved entry must be public, and of the predicate type.
+        let record = RecordType::<CurrentNetwork>::from_str(
+            "record lock: owner as address.private; hash_lock as field.private;",
+        )?;
+        assert!(record.predicates().is_err());
+        let record = RecordType::<CurrentNetwork>::from_str(
+            "record lock: owner as address.private; spend_after as u64.public;",
+        )?;
+        assert!(record.predicates().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for FeedUpdate<N> {
+    /// Reads the feed update from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid feed update version"));
+        }
+        // Read the feed ID.
+        let feed_id = FromBytes::read_le(&mut reader)?;
+        // Read the value.
+        let value = FromBytes::read_le(&mut reader)?;
+        // Read the timestamp.
+        let timestamp = FromBytes::read_le(&mut reader)?;
+        // Read the signature.
+        let signature = FromBytes::read_le(&mut reader)?;
+        // Return the feed update.
+        Ok(Self::from((feed_id, value, timestamp, signature)))
+    }
+}
+
+impl<N: Network> ToBytes for FeedUpdate<N> {
+    /// Writes the feed update to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the feed ID.
+        self.feed_id.write_le(&mut writer)?;
+        // Write the value.
+        self.value.write_le(&mut writer)?;
+        // Write the timestamp.
+        self.timestamp.write_le(&mut writer)?;
+        // Write the signature.
+        self.signature.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        let rng = &mut TestRng::default();
+
+        let expected = test_helpers::sample_feed_update(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected, FeedUpdate::read_le(&expected_bytes[..]).unwrap());
+    }
+}
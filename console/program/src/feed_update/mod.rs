@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::{Literal, Plaintext, Value};
+use snarkvm_console_account::{Address, PrivateKey, Signature};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, U128, U64};
+
+/// The domain separator for the messages of feed updates.
+pub const FEED_UPDATE_DOMAIN: &str = "AleoFeedUpdate0";
+
+/// A signed update of an off-chain data feed (i.e. a price oracle), for programs to ingest with `feed.verify`.
+///
+/// The feed signer signs the message `(domain, feed_id, value, timestamp)`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FeedUpdate<N: Network> {
+    /// The feed ID.
+    feed_id: Field<N>,
+    /// The value of the feed.
+    value: U128<N>,
+    /// The timestamp of the value, in seconds since the Unix epoch.
+    timestamp: U64<N>,
+    /// The signature of the feed signer.
+    signature: Signature<N>,
+}
+
+impl<N: Network> From<(Field<N>, U128<N>, U64<N>, Signature<N>)> for FeedUpdate<N> {
+    /// Initializes a feed update from its parts.
+    fn from((feed_id, value, timestamp, signature): (Field<N>, U128<N>, U64<N>, Signature<N>)) -> Self {
+        Self { feed_id, value, timestamp, signature }
+    }
+}
+
+impl<N: Network> FeedUpdate<N> {
+    /// Returns a feed update for the given feed ID, value, and timestamp, signed by the given private key.
+    pub fn sign<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        feed_id: Field<N>,
+        value: u128,
+        timestamp: u64,
+        rng: &mut R,
+    ) -> Result<Self> {
+        let (value, timestamp) = (U128::new(value), U64::new(timestamp));
+        // Sign the message of the feed update.
+        let signature = Signature::sign(private_key, &Self::to_message(feed_id, value, timestamp), rng)?;
+        Ok(Self { feed_id, value, timestamp, signature })
+    }
+
+    /// Returns `true` if the feed update is signed by the given signer.
+    pub fn verify(&self, signer: &Address<N>) -> bool {
+        self.signature.verify(signer, &Self::to_message(self.feed_id, self.value, self.timestamp))
+    }
+
+    /// Returns the message signed by the feed signer, as `(domain, feed_id, value, timestamp)`.
+    pub fn to_message(feed_id: Field<N>, value: U128<N>, timestamp: U64<N>) -> [Field<N>; 4] {
+        [
+            Field::new_domain_separator(FEED_UPDATE_DOMAIN),
+            feed_id,
+            Field::from_u128(*value),
+            Field::from_u64(*timestamp),
+        ]
+    }
+
+    /// Returns the feed ID.
+    pub const fn feed_id(&self) -> &Field<N> {
+        &self.feed_id
+    }
+
+    /// Returns the value of the feed.
+    pub const fn value(&self) -> &U128<N> {
+        &self.value
+    }
+
+    /// Returns the timestamp of the value.
+    pub const fn timestamp(&self) -> &U64<N> {
+        &self.timestamp
+    }
+
+    /// Returns the signature of the feed signer.
+    pub const fn signature(&self) -> &Signature<N> {
+        &self.signature
+    }
+
+    /// Returns the signer of the feed update.
+    pub fn signer(&self) -> Address<N> {
+        self.signature.to_address()
+    }
+
+    /// Returns the function inputs to ingest the feed update, as `(signature, signer, feed_id, value, timestamp)`.
+    pub fn to_inputs(&self) -> Vec<Value<N>> {
+        vec![
+            Value::Plaintext(Plaintext::from(Literal::Signature(Box::new(self.signature)))),
+            Value::Plaintext(Plaintext::from(Literal::Address(self.signer()))),
+            Value::Plaintext(Plaintext::from(Literal::Field(self.feed_id))),
+            Value::Plaintext(Plaintext::from(Literal::U128(self.value))),
+            Value::Plaintext(Plaintext::from(Literal::U64(self.timestamp))),
+        ]
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a random feed update.
+    pub(crate) fn sample_feed_update(rng: &mut TestRng) -> FeedUpdate<CurrentNetwork> {
+        let private_key = PrivateKey::new(rng).unwrap();
+        FeedUpdate::sign(&private_key, Uniform::rand(rng), rng.gen(), rng.gen(), rng).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+            let signer = Address::try_from(&private_key).unwrap();
+
+            let feed_update = FeedUpdate::sign(&private_key, Uniform::rand(rng), rng.gen(), rng.gen(), rng).unwrap();
+            assert_eq!(feed_update.signer(), signer);
+            assert!(feed_update.verify(&signer));
+
+            // Ensure a different signer fails.
+            let other = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+            assert!(!feed_update.verify(&other));
+
+            // Ensure a tampered value fails.
+            let tampered = FeedUpdate::from((
+                *feed_update.feed_id(),
+                U128::new(feed_update.value().wrapping_add(1)),
+                *feed_update.timestamp(),
+                *feed_update.signature(),
+            ));
+            assert!(!tampered.verify(&signer));
+        }
+    }
+
+    #[test]
+    fn test_to_inputs() {
+        let rng = &mut TestRng::default();
+
+        let feed_update = test_helpers::sample_feed_update(rng);
+        let inputs = feed_update.to_inputs();
+        assert_eq!(inputs.len(), 5);
+        assert_eq!(inputs[1], Value::from_str(&feed_update.signer().to_string()).unwrap());
+        assert_eq!(inputs[3], Value::from_str(&feed_update.value().to_string()).unwrap());
+        assert_eq!(inputs[4], Value::from_str(&feed_update.timestamp().to_string()).unwrap());
+    }
+}
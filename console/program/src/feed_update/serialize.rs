@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for FeedUpdate<N> {
+    /// Serializes the feed update into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut feed_update = serializer.serialize_struct("FeedUpdate", 4)?;
+                feed_update.serialize_field("feed_id", &self.feed_id)?;
+                feed_update.serialize_field("value", &self.value)?;
+                feed_update.serialize_field("timestamp", &self.timestamp)?;
+                feed_update.serialize_field("signature", &self.signature)?;
+                feed_update.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for FeedUpdate<N> {
+    /// Deserializes the feed update from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the feed update from a string into a value.
+                let mut feed_update = serde_json::Value::deserialize(deserializer)?;
+                // Recover the feed update.
+                Ok(Self::from((
+                    // Retrieve the feed ID.
+                    DeserializeExt::take_from_value::<D>(&mut feed_update, "feed_id")?,
+                    // Retrieve the value.
+                    DeserializeExt::take_from_value::<D>(&mut feed_update, "value")?,
+                    // Retrieve the timestamp.
+                    DeserializeExt::take_from_value::<D>(&mut feed_update, "timestamp")?,
+                    // Retrieve the signature.
+                    DeserializeExt::take_from_value::<D>(&mut feed_update, "signature")?,
+                )))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "feed update"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        let rng = &mut TestRng::default();
+
+        let expected = test_helpers::sample_feed_update(rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected).unwrap();
+        assert_eq!(expected_string, &candidate_string);
+
+        // Deserialize
+        assert_eq!(expected, FeedUpdate::from_str(expected_string).unwrap());
+        assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+    }
+
+    #[test]
+    fn test_bincode() {
+        let rng = &mut TestRng::default();
+
+        let expected = test_helpers::sample_feed_update(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, FeedUpdate::read_le(&expected_bytes[..]).unwrap());
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+    }
+}
@@ -30,6 +30,9 @@ pub use data::*;
 mod data_types;
 pub use data_types::*;
 
+mod feed_update;
+pub use feed_update::*;
+
 mod id;
 pub use id::*;
 
@@ -39,6 +42,12 @@ pub use locator::*;
 mod owner;
 pub use owner::*;
 
+mod payment_request;
+pub use payment_request::*;
+
+mod record_bundle;
+pub use record_bundle::*;
+
 mod request;
 pub use request::*;
 
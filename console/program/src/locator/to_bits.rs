@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> ToBits for Locator<N> {
+    /// Returns the little-endian bits of the locator, as the program ID bits followed by the resource bits.
+    fn write_bits_le(&self, vec: &mut Vec<bool>) {
+        self.id.write_bits_le(vec);
+        self.resource.write_bits_le(vec);
+    }
+
+    /// Returns the big-endian bits of the locator, as the program ID bits followed by the resource bits.
+    fn write_bits_be(&self, vec: &mut Vec<bool>) {
+        self.id.write_bits_be(vec);
+        self.resource.write_bits_be(vec);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_bits() -> Result<()> {
+        let locator = Locator::<CurrentNetwork>::from_str("foo.aleo/bar")?;
+
+        // Ensure the bits are the program ID bits followed by the resource bits.
+        let mut expected = ProgramID::<CurrentNetwork>::from_str("foo.aleo")?.to_bits_le();
+        expected.extend(Identifier::<CurrentNetwork>::from_str("bar")?.to_bits_le());
+        assert_eq!(expected, locator.to_bits_le());
+
+        let mut expected = ProgramID::<CurrentNetwork>::from_str("foo.aleo")?.to_bits_be();
+        expected.extend(Identifier::<CurrentNetwork>::from_str("bar")?.to_bits_be());
+        assert_eq!(expected, locator.to_bits_be());
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod serialize;
+mod string;
+
+use crate::{Identifier, Literal, Plaintext, ProgramID, Value};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, U64};
+
+/// The URI scheme of a payment request.
+pub const PAYMENT_REQUEST_SCHEME: &str = "aleo";
+/// The maximum number of bytes in the memo of a payment request.
+pub const MAX_MEMO_BYTES: usize = 128;
+
+/// A payment request, encoded as a compact URI (i.e. for QR codes), of the form
+/// `aleo:{recipient}?amount={amount}&program={program_id}&function={function_name}&memo={memo}`.
+///
+/// The program and function default to `credits.aleo/transfer_public`, and are omitted from the URI if so.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PaymentRequest<N: Network> {
+    /// The recipient address.
+    recipient: Address<N>,
+    /// The amount, in microcredits.
+    amount: u64,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The memo, for the recipient to identify the payment.
+    memo: Option<String>,
+}
+
+impl<N: Network> PaymentRequest<N> {
+    /// Initializes a new payment request for `credits.aleo/transfer_public`.
+    pub fn new(recipient: Address<N>, amount: u64) -> Result<Self> {
+        Ok(Self {
+            recipient,
+            amount,
+            program_id: ProgramID::from_str("credits.aleo")?,
+            function_name: Identifier::from_str("transfer_public")?,
+            memo: None,
+        })
+    }
+
+    /// Returns the payment request, with the given program and function.
+    pub fn with_function(mut self, program_id: ProgramID<N>, function_name: Identifier<N>) -> Self {
+        self.program_id = program_id;
+        self.function_name = function_name;
+        self
+    }
+
+    /// Returns the payment request, with the given memo.
+    pub fn with_memo(mut self, memo: &str) -> Result<Self> {
+        ensure!(memo.len() <= MAX_MEMO_BYTES, "The memo must not exceed {MAX_MEMO_BYTES} bytes");
+        self.memo = Some(memo.to_string());
+        Ok(self)
+    }
+
+    /// Returns the recipient address.
+    pub const fn recipient(&self) -> &Address<N> {
+        &self.recipient
+    }
+
+    /// Returns the amount, in microcredits.
+    pub const fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the memo, if one is set.
+    pub fn memo(&self) -> Option<&str> {
+        self.memo.as_deref()
+    }
+
+    /// Returns the function inputs to pay the request, as `(recipient, amount)`.
+    pub fn to_inputs(&self) -> Vec<Value<N>> {
+        vec![
+            Value::Plaintext(Plaintext::from(Literal::Address(self.recipient))),
+            Value::Plaintext(Plaintext::from(Literal::U64(U64::new(self.amount)))),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_inputs() {
+        let rng = &mut TestRng::default();
+
+        let recipient = Address::<CurrentNetwork>::new(Uniform::rand(rng));
+        let request = PaymentRequest::new(recipient, 1_000_000).unwrap();
+        assert_eq!(request.program_id().to_string(), "credits.aleo");
+        assert_eq!(request.function_name().to_string(), "transfer_public");
+
+        let expected = vec![Value::from_str(&recipient.to_string()).unwrap(), Value::from_str("1000000u64").unwrap()];
+        assert_eq!(request.to_inputs(), expected);
+    }
+
+    #[test]
+    fn test_memo_too_long() {
+        let rng = &mut TestRng::default();
+
+        let recipient = Address::<CurrentNetwork>::new(Uniform::rand(rng));
+        let request = PaymentRequest::new(recipient, 1).unwrap();
+        assert!(request.clone().with_memo(&"a".repeat(MAX_MEMO_BYTES)).is_ok());
+        assert!(request.with_memo(&"a".repeat(MAX_MEMO_BYTES + 1)).is_err());
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromStr for PaymentRequest<N> {
+    type Err = Error;
+
+    /// Parses a payment request from a URI.
+    fn from_str(uri: &str) -> Result<Self> {
+        // Strip the scheme.
+        let Some(uri) = uri.strip_prefix(PAYMENT_REQUEST_SCHEME).and_then(|uri| uri.strip_prefix(':')) else {
+            bail!("A payment request must start with '{PAYMENT_REQUEST_SCHEME}:'")
+        };
+        // Split the recipient from the query.
+        let (recipient, query) = uri.split_once('?').unwrap_or((uri, ""));
+
+        // Parse the recipient.
+        let mut request = Self::new(Address::from_str(recipient)?, 0)?;
+
+        // Parse the query parameters.
+        let mut amount = None;
+        let (mut program_id, mut function_name, mut memo) = (None, None, None);
+        for parameter in query.split('&').filter(|parameter| !parameter.is_empty()) {
+            let Some((key, value)) = parameter.split_once('=') else {
+                bail!("Malformed parameter '{parameter}' in payment request")
+            };
+            let slot = match key {
+                "amount" => &mut amount,
+                "program" => &mut program_id,
+                "function" => &mut function_name,
+                "memo" => &mut memo,
+                _ => bail!("Unknown parameter '{key}' in payment request"),
+            };
+            ensure!(slot.is_none(), "Duplicate parameter '{key}' in payment request");
+            *slot = Some(value);
+        }
+
+        // Parse the amount.
+        let Some(amount) = amount else { bail!("A payment request must specify an amount") };
+        ensure!(amount.chars().all(|c| c.is_ascii_digit()), "Invalid amount '{amount}' in payment request");
+        request.amount = amount.parse()?;
+
+        // Parse the program and function.
+        match (program_id, function_name) {
+            (Some(program_id), Some(function_name)) => {
+                request = request.with_function(ProgramID::from_str(program_id)?, Identifier::from_str(function_name)?)
+            }
+            (None, None) => (),
+            _ => bail!("A payment request must specify both the program and function, or neither"),
+        }
+
+        // Parse the memo.
+        if let Some(memo) = memo {
+            request = request.with_memo(&percent_decode(memo)?)?;
+        }
+        Ok(request)
+    }
+}
+
+impl<N: Network> Debug for PaymentRequest<N> {
+    /// Prints the payment request as a URI.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for PaymentRequest<N> {
+    /// Prints the payment request as a URI.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{PAYMENT_REQUEST_SCHEME}:{}?amount={}", self.recipient, self.amount)?;
+        // Print the program and function, if they are not the default.
+        let default = Self::new(self.recipient, self.amount).map_err(|_| fmt::Error)?;
+        if self.program_id != default.program_id || self.function_name != default.function_name {
+            write!(f, "&program={}&function={}", self.program_id, self.function_name)?;
+        }
+        // Print the memo, if one is set.
+        if let Some(memo) = &self.memo {
+            write!(f, "&memo={}", percent_encode(memo))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the given string, with every byte except for unreserved URI characters percent-encoded.
+fn percent_encode(string: &str) -> String {
+    let mut output = String::with_capacity(string.len());
+    for byte in string.bytes() {
+        match byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            true => output.push(byte as char),
+            false => output.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    output
+}
+
+/// Returns the given percent-encoded string, decoded as in RFC 3986.
+/// Every other character must be allowed in a URI query component, and is kept as is (i.e. '+' is not a space).
+fn percent_decode(string: &str) -> Result<String> {
+    let mut bytes = Vec::with_capacity(string.len());
+    let mut iter = string.bytes();
+    while let Some(byte) = iter.next() {
+        match byte {
+            b'%' => {
+                let hex = [iter.next(), iter.next()];
+                let [Some(high), Some(low)] = hex else { bail!("Truncated percent-encoding in '{string}'") };
+                // Ensure both digits are hexadecimal, as `from_str_radix` also accepts a leading sign.
+                ensure!(
+                    high.is_ascii_hexdigit() && low.is_ascii_hexdigit(),
+                    "Invalid percent-encoding '%{}{}'",
+                    high as char,
+                    low as char
+                );
+                let hex = std::str::from_utf8(&[high, low])?.to_string();
+                bytes.push(u8::from_str_radix(&hex, 16)?);
+            }
+            // The unreserved characters, the sub-delimiters except '&', and ':', '@', '/', '?'.
+            byte if byte.is_ascii_alphanumeric() || b"-._~!$'()*+,;=:@/?".contains(&byte) => bytes.push(byte),
+            _ => bail!("Invalid character '{}' in a percent-encoded string", byte as char),
+        }
+    }
+    Ok(String::from_utf8(bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_string() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            let recipient = Address::<CurrentNetwork>::new(Uniform::rand(rng));
+            let amount = rng.gen::<u64>();
+
+            // Check the default program and function.
+            let expected = PaymentRequest::new(recipient, amount).unwrap();
+            let expected_string = format!("aleo:{recipient}?amount={amount}");
+            assert_eq!(expected_string, expected.to_string());
+            assert_eq!(expected, PaymentRequest::from_str(&expected_string).unwrap());
+
+            // Check a custom program, function, and memo.
+            let expected = expected
+                .with_function(ProgramID::from_str("token.aleo").unwrap(), Identifier::from_str("pay").unwrap())
+                .with_memo("Invoice #42 – café")
+                .unwrap();
+            let expected_string = expected.to_string();
+            assert!(
+                expected_string
+                    .ends_with("&program=token.aleo&function=pay&memo=Invoice%20%2342%20%E2%80%93%20caf%C3%A9")
+            );
+            assert_eq!(expected, PaymentRequest::from_str(&expected_string).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        // Ensure '+' is kept as is, rather than decoded into a space.
+        assert_eq!(percent_decode("a+b").unwrap(), "a+b");
+        assert_eq!(percent_decode("a%2Bb%20c").unwrap(), "a+b c");
+        assert_eq!(percent_decode("a%2bb").unwrap(), "a+b");
+        // Ensure the encoding round-trips.
+        let string = "1 + 1 = 2 & café";
+        assert_eq!(percent_decode(&percent_encode(string)).unwrap(), string);
+    }
+
+    #[test]
+    fn test_invalid_string() {
+        let rng = &mut TestRng::default();
+        let recipient = Address::<CurrentNetwork>::new(Uniform::rand(rng));
+
+        let invalid = [
+            format!("{recipient}?amount=1"),
+            format!("bitcoin:{recipient}?amount=1"),
+            format!("aleo:{recipient}"),
+            format!("aleo:{recipient}?amount=-1"),
+            format!("aleo:{recipient}?amount=1&amount=2"),
+            format!("aleo:{recipient}?amount=1&foo=bar"),
+            format!("aleo:{recipient}?amount=1&program=token.aleo"),
+            format!("aleo:{recipient}?amount=1&memo=%E2%80"),
+            format!("aleo:{recipient}?amount=1&memo=%4"),
+            format!("aleo:{recipient}?amount=1&memo=%+1"),
+            format!("aleo:{recipient}?amount=1&memo=a b"),
+            "aleo:aleo1abc?amount=1".to_string(),
+        ];
+        for string in invalid {
+            assert!(PaymentRequest::<CurrentNetwork>::from_str(&string).is_err(), "{string}");
+        }
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for RecordBundle<N> {
+    /// Reads the record bundle from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != RECORD_BUNDLE_VERSION {
+            return Err(error(format!("Invalid record bundle version ({version})")));
+        }
+        // Read the sync height.
+        let sync_height = u32::read_le(&mut reader)?;
+        // Read the number of entries.
+        let num_entries = u32::read_le(&mut reader)?;
+        // Read the entries.
+        let mut bundle = Self::new(sync_height);
+        for _ in 0..num_entries {
+            // Read the commitment.
+            let commitment = Field::read_le(&mut reader)?;
+            // Read the record.
+            let record = Record::read_le(&mut reader)?;
+            // Read the spent status.
+            let is_spent = bool::read_le(&mut reader)?;
+            // Insert the entry.
+            bundle.insert(commitment, record, is_spent).map_err(|e| error(e.to_string()))?;
+        }
+        Ok(bundle)
+    }
+}
+
+impl<N: Network> ToBytes for RecordBundle<N> {
+    /// Writes the record bundle to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        RECORD_BUNDLE_VERSION.write_le(&mut writer)?;
+        // Write the sync height.
+        self.sync_height.write_le(&mut writer)?;
+        // Write the number of entries.
+        u32::try_from(self.entries.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the entries.
+        for (commitment, record, is_spent) in &self.entries {
+            commitment.write_le(&mut writer)?;
+            record.write_le(&mut writer)?;
+            is_spent.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        let rng = &mut TestRng::default();
+
+        for num_records in [0, 1, 4] {
+            let expected = test_helpers::sample_record_bundle(num_records, rng);
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, RecordBundle::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> RecordBundle<N> {
+    /// Returns the record bundle, encrypted with the given password.
+    ///
+    /// The encrypted bundle is laid out as `version || salt || ciphertext`, where the ciphertext is the
+    /// bundle bytes, encrypted with a `PasswordCipher` and authenticated together with the version.
+    pub fn export_records<R: Rng + CryptoRng>(&self, password: &str, rng: &mut R) -> Result<Vec<u8>> {
+        // Sample the salt, and derive the cipher.
+        let salt = PasswordCipher::sample_salt(rng);
+        let cipher = PasswordCipher::new(password, &salt)?;
+        // Encrypt the bundle bytes.
+        let ciphertext = cipher.encrypt(&self.to_bytes_le()?, &[RECORD_BUNDLE_VERSION], rng)?;
+
+        // Write the encrypted bundle.
+        let mut bytes = Vec::new();
+        RECORD_BUNDLE_VERSION.write_le(&mut bytes)?;
+        salt.write_le(&mut bytes)?;
+        bytes.extend(ciphertext);
+        Ok(bytes)
+    }
+
+    /// Returns the record bundle, decrypted from the given encrypted bundle with the given password.
+    pub fn import_records(bytes: &[u8], password: &str) -> Result<Self> {
+        let mut reader = bytes;
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        ensure!(version == RECORD_BUNDLE_VERSION, "Unsupported record bundle version ({version})");
+        // Read the salt, and derive the cipher.
+        let salt = <[u8; PasswordCipher::SALT_SIZE]>::read_le(&mut reader)?;
+        let cipher = PasswordCipher::new(password, &salt)?;
+
+        // Decrypt the ciphertext, which fails if the password is incorrect or the bundle was modified.
+        let plaintext = cipher
+            .decrypt(reader, &[version])
+            .map_err(|_| anyhow!("Failed to decrypt the record bundle: incorrect password or corrupted bundle"))?;
+        // Read the bundle from the bytes.
+        Self::from_bytes_le(&plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_export_import_records() {
+        let rng = &mut TestRng::default();
+
+        for num_records in [0, 1, 4] {
+            let bundle = test_helpers::sample_record_bundle(num_records, rng);
+
+            // Ensure the bundle is recovered with the password.
+            let encrypted = bundle.export_records("correct horse battery staple", rng).unwrap();
+            let candidate = RecordBundle::<CurrentNetwork>::import_records(&encrypted, "correct horse battery staple");
+            assert_eq!(bundle, candidate.unwrap());
+
+            // Ensure the bundle is not recovered with an incorrect password.
+            assert!(RecordBundle::<CurrentNetwork>::import_records(&encrypted, "incorrect password").is_err());
+
+            // Ensure a modified bundle is rejected.
+            let mut modified = encrypted.clone();
+            let index = modified.len() - 1;
+            modified[index] ^= 1;
+            assert!(RecordBundle::<CurrentNetwork>::import_records(&modified, "correct horse battery staple").is_err());
+        }
+    }
+
+    #[test]
+    fn test_password_encoding() {
+        let rng = &mut TestRng::default();
+
+        // Ensure a password is not confused with its extension by a null byte.
+        let bundle = test_helpers::sample_record_bundle(1, rng);
+        let encrypted = bundle.export_records("a", rng).unwrap();
+        assert!(RecordBundle::<CurrentNetwork>::import_records(&encrypted, "a\0").is_err());
+        assert_eq!(bundle, RecordBundle::<CurrentNetwork>::import_records(&encrypted, "a").unwrap());
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+#[cfg(feature = "password")]
+mod encrypt;
+
+use crate::{Plaintext, Record};
+#[cfg(feature = "password")]
+use snarkvm_console_algorithms::PasswordCipher;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+/// The version of the record bundle format.
+pub const RECORD_BUNDLE_VERSION: u8 = 2;
+
+/// A wallet's records, with their commitments and spent status, as of a sync height.
+///
+/// A record bundle is exported with `export_records` as a password-encrypted backup,
+/// which any wallet built on snarkVM can restore with `import_records`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordBundle<N: Network> {
+    /// The block height, up to which the wallet has synced.
+    sync_height: u32,
+    /// The entries, as (commitment, record, is_spent) tuples.
+    entries: Vec<(Field<N>, Record<N, Plaintext<N>>, bool)>,
+}
+
+impl<N: Network> RecordBundle<N> {
+    /// Initializes a new, empty record bundle at the given sync height.
+    pub const fn new(sync_height: u32) -> Self {
+        Self { sync_height, entries: Vec::new() }
+    }
+
+    /// Returns the block height, up to which the wallet has synced.
+    pub const fn sync_height(&self) -> u32 {
+        self.sync_height
+    }
+
+    /// Sets the block height, up to which the wallet has synced.
+    pub fn set_sync_height(&mut self, sync_height: u32) {
+        self.sync_height = sync_height;
+    }
+
+    /// Returns the entries, as (commitment, record, is_spent) tuples.
+    pub fn entries(&self) -> &[(Field<N>, Record<N, Plaintext<N>>, bool)] {
+        &self.entries
+    }
+
+    /// Returns the number of records.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no records.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Inserts the record with the given commitment and spent status.
+    pub fn insert(&mut self, commitment: Field<N>, record: Record<N, Plaintext<N>>, is_spent: bool) -> Result<()> {
+        // Ensure the record is not already in the bundle.
+        ensure!(
+            !self.entries.iter().any(|(candidate, ..)| *candidate == commitment),
+            "The record '{commitment}' is already in the bundle"
+        );
+        self.entries.push((commitment, record, is_spent));
+        Ok(())
+    }
+
+    /// Marks the record with the given commitment as spent.
+    pub fn mark_spent(&mut self, commitment: &Field<N>) -> Result<()> {
+        match self.entries.iter_mut().find(|(candidate, ..)| candidate == commitment) {
+            Some((_, _, is_spent)) => {
+                *is_spent = true;
+                Ok(())
+            }
+            None => bail!("The record '{commitment}' is not in the bundle"),
+        }
+    }
+
+    /// Returns an iterator over the unspent records.
+    pub fn unspent(&self) -> impl '_ + Iterator<Item = (&Field<N>, &Record<N, Plaintext<N>>)> {
+        self.entries.iter().filter(|(.., is_spent)| !is_spent).map(|(commitment, record, _)| (commitment, record))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a record bundle with the given number of records.
+    pub(crate) fn sample_record_bundle(num_records: usize, rng: &mut TestRng) -> RecordBundle<CurrentNetwork> {
+        let mut bundle = RecordBundle::new(rng.gen());
+        for i in 0..num_records {
+            let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+                "{{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, microcredits: {i}u64.private, _nonce: 0group.public }}"
+            ))
+            .unwrap();
+            bundle.insert(Field::rand(rng), record, i % 2 == 0).unwrap();
+        }
+        bundle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_bundle() {
+        let rng = &mut TestRng::default();
+
+        let mut bundle = test_helpers::sample_record_bundle(4, rng);
+        assert_eq!(bundle.len(), 4);
+        assert_eq!(bundle.unspent().count(), 2);
+
+        // Ensure a duplicate record is rejected.
+        let (commitment, record, _) = bundle.entries()[1].clone();
+        assert!(bundle.insert(commitment, record, false).is_err());
+
+        // Ensure a record can be marked as spent.
+        bundle.mark_spent(&commitment).unwrap();
+        assert_eq!(bundle.unspent().count(), 1);
+        assert!(bundle.mark_spent(&Field::rand(rng)).is_err());
+    }
+}
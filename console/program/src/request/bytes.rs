@@ -20,7 +20,7 @@ impl<N: Network> FromBytes for Request<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid request version"));
         }
 
@@ -48,16 +48,27 @@ impl<N: Network> FromBytes for Request<N> {
         let tvk = FromBytes::read_le(&mut reader)?;
         // Read the transition commitment.
         let tcm = FromBytes::read_le(&mut reader)?;
-
-        Ok(Self::from((signer, network_id, program_id, function_name, input_ids, inputs, signature, sk_tag, tvk, tcm)))
+        // Read the expiry, which is included from version 2.
+        let expiry = match version {
+            1 => None,
+            _ => Some(FromBytes::read_le(&mut reader)?),
+        };
+
+        let mut request =
+            Self::from((signer, network_id, program_id, function_name, input_ids, inputs, signature, sk_tag, tvk, tcm));
+        request.expiry = expiry;
+        Ok(request)
     }
 }
 
 impl<N: Network> ToBytes for Request<N> {
     /// Writes the request to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Write the version, where version 2 includes the expiry.
+        match self.expiry {
+            None => 1u8.write_le(&mut writer)?,
+            Some(_) => 2u8.write_le(&mut writer)?,
+        }
 
         // Write the signer.
         self.signer.write_le(&mut writer)?;
@@ -93,7 +104,12 @@ impl<N: Network> ToBytes for Request<N> {
         // Write the transition view key.
         self.tvk.write_le(&mut writer)?;
         // Write the transition commitment.
-        self.tcm.write_le(&mut writer)
+        self.tcm.write_le(&mut writer)?;
+        // Write the expiry, if there is one.
+        if let Some(expiry) = &self.expiry {
+            expiry.write_le(&mut writer)?;
+        }
+        Ok(())
     }
 }
 
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+
+use snarkvm_console_network::Network;
+use snarkvm_console_types::prelude::*;
+
+/// The maximum block height of a request, after which its transition cannot be included in a block.
+///
+/// The caller proves knowledge of the transition secret key `r` for `tpk = r * G`, over `tcm` and the
+/// maximum height. The proof is verified against the transition public key, so it does not reveal the
+/// caller, and it cannot be forged by a delegated prover, which never learns `r`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Expiry<N: Network> {
+    /// The maximum block height.
+    max_height: u32,
+    /// The challenge of the proof.
+    challenge: Scalar<N>,
+    /// The response of the proof.
+    response: Scalar<N>,
+}
+
+impl<N: Network> Expiry<N> {
+    /// Proves the given maximum height, for the transition secret key `r` and the transition commitment.
+    pub(super) fn prove<R: Rng + CryptoRng>(
+        r: &Scalar<N>,
+        tcm: Field<N>,
+        max_height: u32,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Sample a random nonce, and compute the challenge.
+        let nonce = Scalar::rand(rng);
+        let challenge = Self::to_challenge(N::g_scalar_multiply(r), tcm, max_height, N::g_scalar_multiply(&nonce))?;
+        // Compute the response.
+        let response = nonce - challenge * *r;
+
+        Ok(Self { max_height, challenge, response })
+    }
+
+    /// Returns `true` if the maximum height was proven for the given transition public key and commitment.
+    pub fn verify(&self, tpk: &Group<N>, tcm: &Field<N>) -> bool {
+        // Recompute the nonce commitment, as `G * response + tpk * challenge`.
+        let g_nonce = N::g_scalar_multiply(&self.response) + *tpk * self.challenge;
+        // Ensure the challenge matches.
+        match Self::to_challenge(*tpk, *tcm, self.max_height, g_nonce) {
+            Ok(challenge) => challenge == self.challenge,
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the maximum block height.
+    pub const fn max_height(&self) -> u32 {
+        self.max_height
+    }
+
+    /// Returns the challenge, as a hash of the statement and the nonce commitment.
+    fn to_challenge(tpk: Group<N>, tcm: Field<N>, max_height: u32, g_nonce: Group<N>) -> Result<Scalar<N>> {
+        N::hash_to_scalar_psd4(&[tpk.to_x_coordinate(), tcm, Field::from_u32(max_height), g_nonce.to_x_coordinate()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_prove_verify() {
+        let rng = &mut TestRng::default();
+
+        let r = Scalar::<CurrentNetwork>::rand(rng);
+        let tpk = CurrentNetwork::g_scalar_multiply(&r);
+        let tcm = Field::rand(rng);
+
+        // Ensure the expiry verifies for the transition.
+        let expiry = Expiry::prove(&r, tcm, 10, rng).unwrap();
+        assert_eq!(expiry.max_height(), 10);
+        assert!(expiry.verify(&tpk, &tcm));
+
+        // Ensure the expiry does not verify for another transition or height.
+        assert!(!expiry.verify(&CurrentNetwork::g_scalar_multiply(&Scalar::rand(rng)), &tcm));
+        assert!(!expiry.verify(&tpk, &Field::rand(rng)));
+        let extended = Expiry { max_height: 11, ..expiry };
+        assert!(!extended.verify(&tpk, &tcm));
+    }
+}
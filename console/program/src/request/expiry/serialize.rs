@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for Expiry<N> {
+    /// Serializes the expiry into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut expiry = serializer.serialize_struct("Expiry", 3)?;
+                expiry.serialize_field("max_height", &self.max_height)?;
+                expiry.serialize_field("challenge", &self.challenge)?;
+                expiry.serialize_field("response", &self.response)?;
+                expiry.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Expiry<N> {
+    /// Deserializes the expiry from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut expiry = serde_json::Value::deserialize(deserializer)?;
+                Ok(Self {
+                    max_height: DeserializeExt::take_from_value::<D>(&mut expiry, "max_height")?,
+                    challenge: DeserializeExt::take_from_value::<D>(&mut expiry, "challenge")?,
+                    response: DeserializeExt::take_from_value::<D>(&mut expiry, "response")?,
+                })
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "expiry"),
+        }
+    }
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod expiry;
+pub use expiry::Expiry;
+
 mod input_id;
 pub use input_id::InputID;
 
@@ -48,6 +51,8 @@ pub struct Request<N: Network> {
     tvk: Field<N>,
     /// The transition commitment.
     tcm: Field<N>,
+    /// The maximum block height, if the caller bound the request to one.
+    expiry: Option<Expiry<N>>,
 }
 
 impl<N: Network>
@@ -83,7 +88,19 @@ impl<N: Network>
         if *network_id != N::ID {
             N::halt(format!("Invalid network ID. Expected {}, found {}", N::ID, *network_id))
         } else {
-            Self { signer, network_id, program_id, function_name, input_ids, inputs, signature, sk_tag, tvk, tcm }
+            Self {
+                signer,
+                network_id,
+                program_id,
+                function_name,
+                input_ids,
+                inputs,
+                signature,
+                sk_tag,
+                tvk,
+                tcm,
+                expiry: None,
+            }
         }
     }
 }
@@ -150,6 +167,11 @@ impl<N: Network> Request<N> {
     pub const fn tcm(&self) -> &Field<N> {
         &self.tcm
     }
+
+    /// Returns the maximum block height of the request, if the caller bound the request to one.
+    pub const fn expiry(&self) -> Option<&Expiry<N>> {
+        self.expiry.as_ref()
+    }
 }
 
 #[cfg(test)]
@@ -193,9 +215,19 @@ mod test_helpers {
                     ValueType::from_str("token.aleo/token.record").unwrap(),
                 ];
 
-                // Compute the signed request.
-                let request =
-                    Request::sign(&private_key, program_id, function_name, inputs.into_iter(), &input_types, rng).unwrap();
+                // Compute the signed request, with a maximum height for every other request.
+                let max_height = (i % 2 == 0).then_some(i as u32);
+                let request = Request::sign_with_max_height(
+                    &private_key,
+                    program_id,
+                    function_name,
+                    inputs.into_iter(),
+                    &input_types,
+                    max_height,
+                    rng,
+                )
+                .unwrap();
+                assert_eq!(request.expiry().map(Expiry::max_height), max_height);
                 assert!(request.verify(&input_types));
                 request
             })
@@ -21,7 +21,7 @@ impl<N: Network> Serialize for Request<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut transition = serializer.serialize_struct("Request", 9)?;
+                let mut transition = serializer.serialize_struct("Request", 10 + self.expiry.is_some() as usize)?;
                 transition.serialize_field("signer", &self.signer)?;
                 transition.serialize_field("network", &self.network_id)?;
                 transition.serialize_field("program", &self.program_id)?;
@@ -32,6 +32,9 @@ impl<N: Network> Serialize for Request<N> {
                 transition.serialize_field("sk_tag", &self.sk_tag)?;
                 transition.serialize_field("tvk", &self.tvk)?;
                 transition.serialize_field("tcm", &self.tcm)?;
+                if let Some(expiry) = &self.expiry {
+                    transition.serialize_field("expiry", expiry)?;
+                }
                 transition.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -46,8 +49,12 @@ impl<'de, N: Network> Deserialize<'de> for Request<N> {
             true => {
                 // Parse the request from a string into a value.
                 let mut request = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the expiry.
+                let expiry =
+                    serde_json::from_value(request.get_mut("expiry").unwrap_or(&mut serde_json::Value::Null).take())
+                        .map_err(de::Error::custom)?;
                 // Recover the request.
-                Ok(Self::from((
+                let mut request = Self::from((
                     // Retrieve the signer.
                     DeserializeExt::take_from_value::<D>(&mut request, "signer")?,
                     // Retrieve the network ID.
@@ -68,7 +75,9 @@ impl<'de, N: Network> Deserialize<'de> for Request<N> {
                     DeserializeExt::take_from_value::<D>(&mut request, "tvk")?,
                     // Retrieve the `tcm`.
                     DeserializeExt::take_from_value::<D>(&mut request, "tcm")?,
-                )))
+                ));
+                request.expiry = expiry;
+                Ok(request)
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "request"),
         }
@@ -25,6 +25,21 @@ impl<N: Network> Request<N> {
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
         input_types: &[ValueType<N>],
         rng: &mut R,
+    ) -> Result<Self> {
+        Self::sign_with_max_height(private_key, program_id, function_name, inputs, input_types, None, rng)
+    }
+
+    /// Returns the request for a given private key, program ID, function name, inputs, input types, and RNG,
+    /// which cannot be included in a block after the given maximum height, if one is given.
+    /// See `Expiry` for how the maximum height is bound to the transition.
+    pub fn sign_with_max_height<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        input_types: &[ValueType<N>],
+        max_height: Option<u32>,
+        rng: &mut R,
     ) -> Result<Self> {
         // Ensure the number of inputs matches the number of input types.
         if input_types.len() != inputs.len() {
@@ -220,6 +235,9 @@ impl<N: Network> Request<N> {
         // Compute `response` as `r - challenge * sk_sig`.
         let response = r - challenge * sk_sig;
 
+        // Prove the maximum height, if one is given.
+        let expiry = max_height.map(|max_height| Expiry::prove(&r, tcm, max_height, rng)).transpose()?;
+
         Ok(Self {
             signer,
             network_id: U16::new(N::ID),
@@ -231,6 +249,7 @@ impl<N: Network> Request<N> {
             sk_tag,
             tvk,
             tcm,
+            expiry,
         })
     }
 }
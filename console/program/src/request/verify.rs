@@ -36,6 +36,13 @@ impl<N: Network> Request<N> {
                     return false;
                 }
             }
+            // Ensure the maximum height (if any) is proven for the transition.
+            if let Some(expiry) = &self.expiry {
+                if !expiry.verify(&self.to_tpk(), &self.tcm) {
+                    eprintln!("Invalid maximum height in request.");
+                    return false;
+                }
+            }
         }
 
         // Retrieve the challenge from the signature.
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use snarkvm_console_collections::merkle_tree::MerklePath;
-use snarkvm_console_network::BHPMerkleTree;
+use snarkvm_console_network::{BHPIncrementalMerkleTree, BHPMerkleTree};
 
 /// The depth of the Merkle tree for the blocks.
 pub const BLOCKS_DEPTH: u8 = 32;
@@ -31,9 +31,13 @@ pub const TRANSACTIONS_DEPTH: u8 = 16;
 pub const TRANSACTION_DEPTH: u8 = 5;
 /// The depth of the Merkle tree for the transition.
 pub const TRANSITION_DEPTH: u8 = 5;
+/// The depth of the Merkle tree for the serial numbers (i.e. the nullifier set).
+pub const SERIAL_NUMBERS_DEPTH: u8 = 32;
 
 /// The Merkle tree for the block state.
 pub type BlockTree<N> = BHPMerkleTree<N, BLOCKS_DEPTH>;
+/// The incremental Merkle tree for the block state.
+pub type IncrementalBlockTree<N> = BHPIncrementalMerkleTree<N, BLOCKS_DEPTH>;
 /// The Merkle path for the state tree blocks.
 pub type BlockPath<N> = MerklePath<N, BLOCKS_DEPTH>;
 
@@ -62,6 +66,11 @@ pub type TransitionTree<N> = BHPMerkleTree<N, TRANSITION_DEPTH>;
 /// The Merkle path for an input or output ID in the transition.
 pub type TransitionPath<N> = MerklePath<N, TRANSITION_DEPTH>;
 
+/// The Merkle tree for the serial numbers.
+pub type SerialNumbersTree<N> = BHPMerkleTree<N, SERIAL_NUMBERS_DEPTH>;
+/// The Merkle path for a serial number leaf in the serial numbers tree.
+pub type SerialNumbersPath<N> = MerklePath<N, SERIAL_NUMBERS_DEPTH>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
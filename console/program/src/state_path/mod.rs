@@ -18,6 +18,12 @@ pub use configuration::*;
 mod header_leaf;
 pub use header_leaf::*;
 
+mod non_membership_path;
+pub use non_membership_path::*;
+
+mod serial_number_leaf;
+pub use serial_number_leaf::*;
+
 mod transaction_leaf;
 pub use transaction_leaf::*;
 
@@ -59,7 +65,7 @@ pub struct StatePath<N: Network> {
     transaction_leaf: TransactionLeaf<N>,
     /// The transition root.
     transition_root: Field<N>,
-    /// The transition commitment.
+    /// The transition commitment, as bound into the transition ID.
     tcm: Field<N>,
     /// The Merkle path for the transition leaf.
     transition_path: TransitionPath<N>,
@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for NonMembershipPath<N> {
+    /// Reads the non-membership path from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the Merkle path.
+        let path = FromBytes::read_le(&mut reader)?;
+        // Read the low leaf.
+        let leaf = FromBytes::read_le(&mut reader)?;
+        // Return the non-membership path.
+        Ok(Self::new(path, leaf))
+    }
+}
+
+impl<N: Network> ToBytes for NonMembershipPath<N> {
+    /// Writes the non-membership path to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the Merkle path.
+        self.path.write_le(&mut writer)?;
+        // Write the low leaf.
+        self.leaf.write_le(&mut writer)
+    }
+}
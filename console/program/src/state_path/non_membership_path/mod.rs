@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::{SerialNumberLeaf, SerialNumbersPath};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+/// The non-membership path proves a serial number is *not* contained in the serial numbers tree
+/// at a given root, by opening the leaf whose serial number immediately precedes it.
+#[derive(Clone, PartialEq, Eq)]
+pub struct NonMembershipPath<N: Network> {
+    /// The Merkle path for the low leaf.
+    path: SerialNumbersPath<N>,
+    /// The low leaf, whose range `(serial_number, next_serial_number)` covers the serial number.
+    leaf: SerialNumberLeaf<N>,
+}
+
+impl<N: Network> NonMembershipPath<N> {
+    /// Initializes a new instance of `NonMembershipPath`.
+    pub const fn new(path: SerialNumbersPath<N>, leaf: SerialNumberLeaf<N>) -> Self {
+        Self { path, leaf }
+    }
+
+    /// Returns the Merkle path for the low leaf.
+    pub const fn path(&self) -> &SerialNumbersPath<N> {
+        &self.path
+    }
+
+    /// Returns the low leaf.
+    pub const fn leaf(&self) -> &SerialNumberLeaf<N> {
+        &self.leaf
+    }
+
+    /// Checks that the given serial number is not contained in the serial numbers tree with the given root.
+    pub fn verify(&self, serial_numbers_root: &Field<N>, serial_number: &Field<N>) -> Result<()> {
+        // Ensure the serial number falls strictly within the range of the low leaf.
+        ensure!(
+            self.leaf.is_low_leaf_for(serial_number),
+            "Serial number '{serial_number}' is not covered by the low leaf '{}'",
+            self.leaf
+        );
+        // Ensure the low leaf belongs to the serial numbers tree.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.path, serial_numbers_root, &self.leaf.to_bits_le()),
+            "'{}' (a serial number leaf) does not belong to the serial numbers root '{serial_numbers_root}'",
+            self.leaf
+        );
+        Ok(())
+    }
+}
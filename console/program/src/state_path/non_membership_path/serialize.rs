@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use snarkvm_utilities::DeserializeExt;
+
+impl<N: Network> Serialize for NonMembershipPath<N> {
+    /// Serializes the non-membership path into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut path = serializer.serialize_struct("NonMembershipPath", 2)?;
+                path.serialize_field("path", &self.path)?;
+                path.serialize_field("leaf", &self.leaf)?;
+                path.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for NonMembershipPath<N> {
+    /// Deserializes the non-membership path from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the non-membership path from a string into a value.
+                let mut path = serde_json::Value::deserialize(deserializer)?;
+                // Recover the non-membership path.
+                Ok(Self::new(
+                    // Retrieve the Merkle path.
+                    DeserializeExt::take_from_value::<D>(&mut path, "path")?,
+                    // Retrieve the low leaf.
+                    DeserializeExt::take_from_value::<D>(&mut path, "leaf")?,
+                ))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "non-membership path"),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for SerialNumberLeaf<N> {
+    /// Reads the serial number leaf from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = FromBytes::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != SERIAL_NUMBER_LEAF_VERSION {
+            return Err(error("Invalid serial number leaf version"));
+        }
+        // Read the serial number.
+        let serial_number = FromBytes::read_le(&mut reader)?;
+        // Read the next serial number.
+        let next_serial_number = FromBytes::read_le(&mut reader)?;
+        // Return the serial number leaf.
+        Ok(Self::from(version, serial_number, next_serial_number))
+    }
+}
+
+impl<N: Network> ToBytes for SerialNumberLeaf<N> {
+    /// Writes the serial number leaf to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        self.version.write_le(&mut writer)?;
+        // Write the serial number.
+        self.serial_number.write_le(&mut writer)?;
+        // Write the next serial number.
+        self.next_serial_number.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample the leaf.
+            let expected = test_helpers::sample_leaf(&mut rng);
+
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le()?;
+            assert_eq!(expected, SerialNumberLeaf::read_le(&expected_bytes[..])?);
+            assert!(SerialNumberLeaf::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+        Ok(())
+    }
+}
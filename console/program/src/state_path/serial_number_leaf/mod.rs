@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+mod to_bits;
+
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+/// The serial number leaf version.
+const SERIAL_NUMBER_LEAF_VERSION: u8 = 1u8;
+
+/// The Merkle leaf for a serial number in the serial numbers tree.
+///
+/// Each leaf stores a serial number along with the next-largest serial number in the set,
+/// forming a sorted linked list over the leaves. The last leaf in the list points to zero.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct SerialNumberLeaf<N: Network> {
+    /// The version of the Merkle leaf.
+    version: u8,
+    /// The serial number.
+    serial_number: Field<N>,
+    /// The next-largest serial number, or zero if there is none.
+    next_serial_number: Field<N>,
+}
+
+impl<N: Network> SerialNumberLeaf<N> {
+    /// Initializes a new instance of `SerialNumberLeaf`.
+    pub const fn new_with_version(serial_number: Field<N>, next_serial_number: Field<N>) -> Self {
+        Self { version: SERIAL_NUMBER_LEAF_VERSION, serial_number, next_serial_number }
+    }
+
+    /// Initializes a new instance of `SerialNumberLeaf`.
+    pub const fn from(version: u8, serial_number: Field<N>, next_serial_number: Field<N>) -> Self {
+        Self { version, serial_number, next_serial_number }
+    }
+
+    /// Returns the version of the Merkle leaf.
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the serial number in the Merkle leaf.
+    pub const fn serial_number(&self) -> Field<N> {
+        self.serial_number
+    }
+
+    /// Returns the next-largest serial number in the Merkle leaf.
+    pub const fn next_serial_number(&self) -> Field<N> {
+        self.next_serial_number
+    }
+
+    /// Returns `true` if the given serial number falls strictly between this leaf and its successor.
+    pub fn is_low_leaf_for(&self, serial_number: &Field<N>) -> bool {
+        self.serial_number < *serial_number
+            && (self.next_serial_number.is_zero() || *serial_number < self.next_serial_number)
+    }
+}
+
+#[cfg(test)]
+mod test_helpers {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    pub(super) fn sample_leaf(rng: &mut TestRng) -> SerialNumberLeaf<CurrentNetwork> {
+        // Construct a new leaf.
+        SerialNumberLeaf::new_with_version(Uniform::rand(rng), Uniform::rand(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_is_low_leaf_for() {
+        let one = Field::<CurrentNetwork>::one();
+        let two = one + one;
+        let three = two + one;
+
+        // A leaf (1, 3) covers 2, but not 1 or 3.
+        let leaf = SerialNumberLeaf::new_with_version(one, three);
+        assert!(leaf.is_low_leaf_for(&two));
+        assert!(!leaf.is_low_leaf_for(&one));
+        assert!(!leaf.is_low_leaf_for(&three));
+
+        // A leaf (1, 0) covers every serial number larger than 1.
+        let leaf = SerialNumberLeaf::new_with_version(one, Field::zero());
+        assert!(leaf.is_low_leaf_for(&two));
+        assert!(leaf.is_low_leaf_for(&three));
+        assert!(!leaf.is_low_leaf_for(&one));
+    }
+}
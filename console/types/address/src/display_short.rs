@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of retained characters, after the `aleo1` prefix.
+const NUM_LEADING_DATA_CHARACTERS: usize = 4;
+/// The number of characters in a Bech32m checksum.
+const NUM_CHECKSUM_CHARACTERS: usize = 6;
+
+impl<E: Environment> DisplayShort for Address<E> {
+    /// Returns the abbreviated address, retaining the prefix and the checksum. (i.e. "aleo1abcd…uvwxyz")
+    fn to_short_string(&self) -> String {
+        abbreviate(&self.to_string(), "aleo1".len() + NUM_LEADING_DATA_CHARACTERS, NUM_CHECKSUM_CHARACTERS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_to_short_string() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new address.
+            let address = Address::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+            let string = address.to_string();
+
+            let candidate = address.to_short_string();
+            assert_eq!(candidate.chars().count(), 9 + 1 + 6);
+            assert!(string.starts_with(candidate.split(ELLIPSIS).next().unwrap()));
+            assert!(string.ends_with(candidate.split(ELLIPSIS).nth(1).unwrap()));
+            assert!(candidate.starts_with("aleo1"));
+        }
+    }
+}
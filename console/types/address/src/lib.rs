@@ -17,6 +17,7 @@
 
 mod bitwise;
 mod bytes;
+mod display_short;
 mod from_bits;
 mod from_field;
 mod from_fields;
@@ -0,0 +1,57 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The number of retained leading digits.
+const NUM_LEADING_DIGITS: usize = 6;
+/// The number of retained trailing digits, before the type suffix.
+const NUM_TRAILING_DIGITS: usize = 4;
+
+impl<E: Environment> DisplayShort for Field<E> {
+    /// Returns the abbreviated field element, retaining the type suffix. (i.e. "123456…7890field")
+    fn to_short_string(&self) -> String {
+        abbreviate(&self.to_string(), NUM_LEADING_DIGITS, NUM_TRAILING_DIGITS + Self::type_name().len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_to_short_string() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new field.
+            let field = Field::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+            let string = field.to_string();
+
+            let candidate = field.to_short_string();
+            assert!(string.starts_with(candidate.split(ELLIPSIS).next().unwrap()));
+            assert!(string.ends_with(candidate.split(ELLIPSIS).last().unwrap()));
+            assert!(candidate.ends_with("field"));
+        }
+
+        // Ensure small field elements are unchanged.
+        let field = Field::<CurrentEnvironment>::from_u64(123);
+        assert_eq!("123field", field.to_short_string());
+    }
+}
@@ -19,6 +19,7 @@ mod arithmetic;
 mod bitwise;
 mod bytes;
 mod compare;
+mod display_short;
 mod from_bits;
 mod one;
 mod parse;
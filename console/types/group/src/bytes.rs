@@ -15,7 +15,10 @@
 use super::*;
 
 impl<E: Environment> FromBytes for Group<E> {
-    /// Reads the group from a buffer.
+    /// Reads the group from a buffer, in compressed form.
+    ///
+    /// The point is recovered from its x-coordinate, and is enforced to be on the curve
+    /// and in the prime-order subgroup, which uniquely determines the y-coordinate.
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         Self::from_x_coordinate(FromBytes::read_le(&mut reader)?).map_err(|e| error(e.to_string()))
@@ -23,7 +26,7 @@ impl<E: Environment> FromBytes for Group<E> {
 }
 
 impl<E: Environment> ToBytes for Group<E> {
-    /// Writes the group to a buffer.
+    /// Writes the group to a buffer, in compressed form (as its x-coordinate).
     #[inline]
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         self.to_x_coordinate().write_le(&mut writer)
@@ -51,6 +54,28 @@ mod tests {
             let expected_bytes = expected.to_bytes_le()?;
             assert_eq!(expected, Group::read_le(&expected_bytes[..])?);
             assert!(Group::<CurrentEnvironment>::read_le(&expected_bytes[1..]).is_err());
+
+            // Check the byte representation is compressed.
+            assert_eq!(expected_bytes.len(), Group::<CurrentEnvironment>::size_in_bytes());
+            assert_eq!(expected_bytes.len(), Field::<CurrentEnvironment>::SIZE_IN_BYTES);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_rejects_invalid_points() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random x-coordinate.
+            let x_coordinate = Field::<CurrentEnvironment>::new(Uniform::rand(&mut rng));
+
+            // Check that an x-coordinate is only accepted if it recovers a point in the subgroup.
+            let x_coordinate_bytes = x_coordinate.to_bytes_le()?;
+            match Group::<CurrentEnvironment>::from_x_coordinate(x_coordinate) {
+                Ok(expected) => assert_eq!(expected, Group::read_le(&x_coordinate_bytes[..])?),
+                Err(_) => assert!(Group::<CurrentEnvironment>::read_le(&x_coordinate_bytes[..]).is_err()),
+            }
         }
         Ok(())
     }
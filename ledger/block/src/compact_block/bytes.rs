@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for CompactBlock<N> {
+    /// Reads the compact block from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid compact block version"));
+        }
+
+        // Read the block hash.
+        let block_hash = FromBytes::read_le(&mut reader)?;
+        // Read the previous block hash.
+        let previous_hash = FromBytes::read_le(&mut reader)?;
+        // Read the header.
+        let header = FromBytes::read_le(&mut reader)?;
+        // Read the authority.
+        let authority = FromBytes::read_le(&mut reader)?;
+        // Read the ratifications.
+        let ratifications = FromBytes::read_le(&mut reader)?;
+
+        // Read the solutions.
+        let solutions_variant = u8::read_le(&mut reader)?;
+        let solutions = match solutions_variant {
+            0 => None,
+            1 => Some(FromBytes::read_le(&mut reader)?),
+            _ => return Err(error("Invalid solutions variant in the compact block")),
+        };
+
+        // Read the number of compact transactions.
+        let num_transactions = u32::read_le(&mut reader)?;
+        // Ensure the number of compact transactions is within bounds (this is an early safety check).
+        if num_transactions as usize > Transactions::<N>::MAX_TRANSACTIONS {
+            return Err(error("Invalid number of transactions in the compact block"));
+        }
+        // Read the compact transactions.
+        let transactions =
+            (0..num_transactions).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Read the number of aborted transaction IDs.
+        let num_aborted = u32::read_le(&mut reader)?;
+        // Ensure the number of aborted transaction IDs is within bounds (this is an early safety check).
+        if num_aborted as usize > Transactions::<N>::MAX_TRANSACTIONS {
+            return Err(error("Invalid number of aborted transaction IDs in the compact block"));
+        }
+        // Read the aborted transaction IDs.
+        let aborted_transaction_ids =
+            (0..num_aborted).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+
+        // Return the compact block.
+        Self::new(
+            block_hash,
+            previous_hash,
+            header,
+            authority,
+            ratifications,
+            solutions,
+            transactions,
+            aborted_transaction_ids,
+        )
+        .map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for CompactBlock<N> {
+    /// Writes the compact block to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the block hash.
+        self.block_hash.write_le(&mut writer)?;
+        // Write the previous block hash.
+        self.previous_hash.write_le(&mut writer)?;
+        // Write the header.
+        self.header.write_le(&mut writer)?;
+        // Write the authority.
+        self.authority.write_le(&mut writer)?;
+        // Write the ratifications.
+        self.ratifications.write_le(&mut writer)?;
+
+        // Write the solutions.
+        match self.solutions {
+            None => 0u8.write_le(&mut writer)?,
+            Some(ref solutions) => {
+                1u8.write_le(&mut writer)?;
+                solutions.write_le(&mut writer)?;
+            }
+        }
+
+        // Write the compact transactions.
+        (u32::try_from(self.transactions.len()).map_err(error))?.write_le(&mut writer)?;
+        self.transactions.write_le(&mut writer)?;
+
+        // Write the aborted transaction IDs.
+        (u32::try_from(self.aborted_transaction_ids.len()).map_err(error))?.write_le(&mut writer)?;
+        self.aborted_transaction_ids.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() {
+        let rng = &mut TestRng::default();
+
+        // Sample the compact block.
+        let expected = test_helpers::sample_compact_block(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected, CompactBlock::read_le(&expected_bytes[..]).unwrap());
+        assert!(CompactBlock::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Ensure the compact block is smaller than the block.
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        assert!(expected_bytes.len() < block.to_bytes_le().unwrap().len());
+    }
+}
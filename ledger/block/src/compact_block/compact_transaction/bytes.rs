@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for CompactTransaction<N> {
+    /// Reads the compact transaction from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let variant = u8::read_le(&mut reader)?;
+        match variant {
+            0 => {
+                // Read the index.
+                let index = u32::read_le(&mut reader)?;
+                // Read the short ID.
+                let short_id = u64::read_le(&mut reader)?;
+                // Read the number of finalize operations.
+                let num_finalize = NumFinalizeSize::read_le(&mut reader)?;
+                // Read the finalize operations.
+                let finalize =
+                    (0..num_finalize).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+                // Return the short transaction.
+                Ok(Self::Short(index, short_id, finalize))
+            }
+            1 => {
+                // Read the confirmed transaction.
+                let confirmed = ConfirmedTransaction::read_le(&mut reader)?;
+                // Return the prefilled transaction.
+                Ok(Self::Prefilled(confirmed))
+            }
+            2.. => Err(error(format!("Failed to decode compact transaction variant {variant}"))),
+        }
+    }
+}
+
+impl<N: Network> ToBytes for CompactTransaction<N> {
+    /// Writes the compact transaction to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Short(index, short_id, finalize) => {
+                // Write the variant.
+                0u8.write_le(&mut writer)?;
+                // Write the index.
+                index.write_le(&mut writer)?;
+                // Write the short ID.
+                short_id.write_le(&mut writer)?;
+                // Write the number of finalize operations.
+                NumFinalizeSize::try_from(finalize.len()).map_err(error)?.write_le(&mut writer)?;
+                // Write the finalize operations.
+                finalize.iter().try_for_each(|finalize| finalize.write_le(&mut writer))
+            }
+            Self::Prefilled(confirmed) => {
+                // Write the variant.
+                1u8.write_le(&mut writer)?;
+                // Write the confirmed transaction.
+                confirmed.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        let rng = &mut TestRng::default();
+
+        // Sample the compact transactions, and a prefilled (rejected) transaction.
+        let compact = crate::compact_block::test_helpers::sample_compact_block(rng);
+        let prefilled = crate::transactions::confirmed::test_helpers::sample_rejected_execute(0, true, rng);
+
+        for expected in compact.transactions().iter().cloned().chain([CompactTransaction::Prefilled(prefilled)]) {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, CompactTransaction::read_le(&expected_bytes[..]).unwrap());
+        }
+    }
+}
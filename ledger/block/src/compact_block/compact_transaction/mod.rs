@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::{ConfirmedTransaction, NumFinalizeSize, Transaction};
+use console::network::prelude::*;
+use synthesizer_program::FinalizeOperation;
+
+/// A transaction in a compact block.
+#[derive(Clone, PartialEq, Eq)]
+pub enum CompactTransaction<N: Network> {
+    /// The accepted transaction is composed of `(index, short_id, finalize_operations)`.
+    Short(u32, u64, Vec<FinalizeOperation<N>>),
+    /// The prefilled transaction is composed of `(confirmed_transaction)`.
+    Prefilled(ConfirmedTransaction<N>),
+}
+
+impl<N: Network> CompactTransaction<N> {
+    /// Returns `true` if the compact transaction is a short ID.
+    pub const fn is_short(&self) -> bool {
+        matches!(self, Self::Short(..))
+    }
+
+    /// Returns `true` if the compact transaction is prefilled.
+    pub const fn is_prefilled(&self) -> bool {
+        matches!(self, Self::Prefilled(..))
+    }
+
+    /// Returns the index of the confirmed transaction.
+    pub const fn index(&self) -> u32 {
+        match self {
+            Self::Short(index, ..) => *index,
+            Self::Prefilled(confirmed) => confirmed.index(),
+        }
+    }
+
+    /// Returns the confirmed transaction, using the given transaction for a short ID.
+    pub fn confirm(&self, transaction: Transaction<N>) -> Result<ConfirmedTransaction<N>> {
+        match self {
+            Self::Short(index, _, finalize_operations) => match transaction.is_deploy() {
+                true => ConfirmedTransaction::accepted_deploy(*index, transaction, finalize_operations.clone()),
+                false => ConfirmedTransaction::accepted_execute(*index, transaction, finalize_operations.clone()),
+            },
+            Self::Prefilled(confirmed) => Ok(confirmed.clone()),
+        }
+    }
+}
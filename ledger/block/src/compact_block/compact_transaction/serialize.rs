@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for CompactTransaction<N> {
+    /// Serializes the compact transaction into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => match self {
+                Self::Short(index, short_id, finalize_operations) => {
+                    let mut object = serializer.serialize_struct("CompactTransaction", 4)?;
+                    object.serialize_field("type", "short")?;
+                    object.serialize_field("index", index)?;
+                    object.serialize_field("short_id", short_id)?;
+                    object.serialize_field("finalize", finalize_operations)?;
+                    object.end()
+                }
+                Self::Prefilled(confirmed) => {
+                    let mut object = serializer.serialize_struct("CompactTransaction", 2)?;
+                    object.serialize_field("type", "prefilled")?;
+                    object.serialize_field("transaction", confirmed)?;
+                    object.end()
+                }
+            },
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for CompactTransaction<N> {
+    /// Deserializes the compact transaction from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the compact transaction from a string into a value.
+                let mut object = serde_json::Value::deserialize(deserializer)?;
+
+                // Recover the compact transaction.
+                match object.get("type").and_then(|t| t.as_str()) {
+                    Some("short") => Ok(Self::Short(
+                        DeserializeExt::take_from_value::<D>(&mut object, "index")?,
+                        DeserializeExt::take_from_value::<D>(&mut object, "short_id")?,
+                        DeserializeExt::take_from_value::<D>(&mut object, "finalize")?,
+                    )),
+                    Some("prefilled") => {
+                        Ok(Self::Prefilled(DeserializeExt::take_from_value::<D>(&mut object, "transaction")?))
+                    }
+                    _ => Err(de::Error::custom("Invalid compact transaction type")),
+                }
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "compact transaction"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        let rng = &mut TestRng::default();
+
+        // Sample the compact transactions, and a prefilled (rejected) transaction.
+        let compact = crate::compact_block::test_helpers::sample_compact_block(rng);
+        let prefilled = crate::transactions::confirmed::test_helpers::sample_rejected_execute(0, true, rng);
+
+        for expected in compact.transactions().iter().cloned().chain([CompactTransaction::Prefilled(prefilled)]) {
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+
+            // Deserialize
+            assert_eq!(expected, CompactTransaction::from_str(expected_string).unwrap());
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+        }
+    }
+}
@@ -0,0 +1,342 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod compact_transaction;
+pub use compact_transaction::*;
+
+mod bytes;
+mod serialize;
+mod string;
+
+use super::*;
+
+use std::collections::HashMap;
+
+/// A compact encoding of a block for relay, which replaces each accepted transaction with its short ID.
+/// A peer reconstructs the block from the transactions in its mempool, and fetches the missing ones.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CompactBlock<N: Network> {
+    /// The hash of the block.
+    block_hash: N::BlockHash,
+    /// The hash of the previous block.
+    previous_hash: N::BlockHash,
+    /// The header of the block.
+    header: Header<N>,
+    /// The authority for the block.
+    authority: Authority<N>,
+    /// The ratifications in the block.
+    ratifications: Ratifications<N>,
+    /// The solutions in the block.
+    solutions: Option<CoinbaseSolution<N>>,
+    /// The compact transactions in the block.
+    transactions: Vec<CompactTransaction<N>>,
+    /// The aborted transaction IDs in the block.
+    aborted_transaction_ids: Vec<N::TransactionID>,
+}
+
+impl<N: Network> CompactBlock<N> {
+    /// Initializes a new compact block.
+    pub fn new(
+        block_hash: N::BlockHash,
+        previous_hash: N::BlockHash,
+        header: Header<N>,
+        authority: Authority<N>,
+        ratifications: Ratifications<N>,
+        solutions: Option<CoinbaseSolution<N>>,
+        transactions: Vec<CompactTransaction<N>>,
+        aborted_transaction_ids: Vec<N::TransactionID>,
+    ) -> Result<Self> {
+        // Ensure the block contains transactions.
+        ensure!(!transactions.is_empty(), "Cannot create a compact block with zero transactions");
+        // Ensure the number of transactions is within the allowed range.
+        if transactions.len() + aborted_transaction_ids.len() > Transactions::<N>::MAX_TRANSACTIONS {
+            bail!(
+                "Cannot initialize a compact block with {} transactions (w/ aborted)",
+                Transactions::<N>::MAX_TRANSACTIONS
+            );
+        }
+        Ok(Self {
+            block_hash,
+            previous_hash,
+            header,
+            authority,
+            ratifications,
+            solutions,
+            transactions,
+            aborted_transaction_ids,
+        })
+    }
+
+    /// Returns the hash of the block.
+    pub const fn hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the hash of the previous block.
+    pub const fn previous_hash(&self) -> N::BlockHash {
+        self.previous_hash
+    }
+
+    /// Returns the header of the block.
+    pub const fn header(&self) -> &Header<N> {
+        &self.header
+    }
+
+    /// Returns the authority for the block.
+    pub const fn authority(&self) -> &Authority<N> {
+        &self.authority
+    }
+
+    /// Returns the ratifications in the block.
+    pub const fn ratifications(&self) -> &Ratifications<N> {
+        &self.ratifications
+    }
+
+    /// Returns the solutions in the block.
+    pub const fn solutions(&self) -> Option<&CoinbaseSolution<N>> {
+        self.solutions.as_ref()
+    }
+
+    /// Returns the compact transactions in the block.
+    pub fn transactions(&self) -> &[CompactTransaction<N>] {
+        &self.transactions
+    }
+
+    /// Returns the aborted transaction IDs in the block.
+    pub fn aborted_transaction_ids(&self) -> &[N::TransactionID] {
+        &self.aborted_transaction_ids
+    }
+
+    /// Returns the short ID of the given transaction ID, for this block.
+    pub fn short_id(&self, transaction_id: &N::TransactionID) -> Result<u64> {
+        to_short_id(self.block_hash, transaction_id)
+    }
+
+    /// Returns the position of each compact transaction that is not found in the given mempool.
+    /// These are the transactions to fetch from the peer that relayed the block.
+    pub fn missing_transactions<'a>(&self, mempool: impl IntoIterator<Item = &'a Transaction<N>>) -> Result<Vec<u32>> {
+        // Index the mempool by short ID.
+        let mempool = self.index_by_short_id(mempool)?;
+        // Collect the position of each short ID that is not uniquely found in the mempool.
+        let mut missing = Vec::new();
+        for (position, transaction) in self.transactions.iter().enumerate() {
+            if let CompactTransaction::Short(_, short_id, _) = transaction {
+                if mempool.get(short_id).copied().flatten().is_none() {
+                    missing.push(u32::try_from(position)?);
+                }
+            }
+        }
+        Ok(missing)
+    }
+
+    /// Reconstructs the block from the given mempool, and the missing transactions,
+    /// which are given in the order of `CompactBlock::missing_transactions`.
+    pub fn into_block<'a>(
+        self,
+        mempool: impl IntoIterator<Item = &'a Transaction<N>>,
+        missing: Vec<Transaction<N>>,
+    ) -> Result<Block<N>> {
+        // Index the mempool by short ID.
+        let mempool = self.index_by_short_id(mempool)?;
+
+        // Confirm each compact transaction.
+        let mut missing = missing.into_iter();
+        let mut transactions = Vec::with_capacity(self.transactions.len());
+        for compact in &self.transactions {
+            let confirmed = match compact {
+                CompactTransaction::Short(_, short_id, _) => {
+                    let transaction = match mempool.get(short_id).copied().flatten() {
+                        Some(transaction) => transaction.clone(),
+                        None => match missing.next() {
+                            Some(transaction) => transaction,
+                            None => bail!("Missing the transaction for short ID '{short_id}'"),
+                        },
+                    };
+                    // Ensure the transaction matches the short ID.
+                    ensure!(
+                        self.short_id(&transaction.id())? == *short_id,
+                        "Transaction '{}' does not match the short ID '{short_id}'",
+                        transaction.id()
+                    );
+                    compact.confirm(transaction)?
+                }
+                CompactTransaction::Prefilled(confirmed) => confirmed.clone(),
+            };
+            transactions.push(confirmed);
+        }
+        // Ensure every missing transaction was used.
+        ensure!(missing.next().is_none(), "Received more missing transactions than the compact block needs");
+
+        // Construct the block.
+        let block = Block::from(
+            self.previous_hash,
+            self.header,
+            self.authority,
+            self.ratifications,
+            self.solutions,
+            Transactions::from(&transactions),
+            self.aborted_transaction_ids,
+        )?;
+        // Ensure the block hash matches.
+        ensure!(block.hash() == self.block_hash, "Reconstructed block does not match '{}'", self.block_hash);
+        Ok(block)
+    }
+
+    /// Returns a map of short IDs to transactions, where a short ID shared by several transactions maps to `None`.
+    fn index_by_short_id<'a>(
+        &self,
+        mempool: impl IntoIterator<Item = &'a Transaction<N>>,
+    ) -> Result<HashMap<u64, Option<&'a Transaction<N>>>> {
+        let mut index: HashMap<u64, Option<&'a Transaction<N>>> = HashMap::new();
+        for transaction in mempool {
+            let short_id = self.short_id(&transaction.id())?;
+            index
+                .entry(short_id)
+                .and_modify(|entry| {
+                    // Discard colliding transactions, so they are fetched in full.
+                    if entry.map(|entry| entry.id()) != Some(transaction.id()) {
+                        *entry = None;
+                    }
+                })
+                .or_insert(Some(transaction));
+        }
+        Ok(index)
+    }
+}
+
+impl<N: Network> Block<N> {
+    /// Returns the compact block, which replaces each accepted transaction with its short ID.
+    /// Rejected transactions are included in full, as they differ from their unconfirmed transaction.
+    pub fn to_compact(&self) -> Result<CompactBlock<N>> {
+        let transactions = self
+            .transactions
+            .iter()
+            .map(|confirmed| match confirmed {
+                ConfirmedTransaction::AcceptedDeploy(index, transaction, finalize_operations)
+                | ConfirmedTransaction::AcceptedExecute(index, transaction, finalize_operations) => {
+                    Ok(CompactTransaction::Short(
+                        *index,
+                        to_short_id(self.block_hash, &transaction.id())?,
+                        finalize_operations.clone(),
+                    ))
+                }
+                ConfirmedTransaction::RejectedDeploy(..) | ConfirmedTransaction::RejectedExecute(..) => {
+                    Ok(CompactTransaction::Prefilled(confirmed.clone()))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        CompactBlock::new(
+            self.block_hash,
+            self.previous_hash,
+            self.header,
+            self.authority.clone(),
+            self.ratifications.clone(),
+            self.solutions.clone(),
+            transactions,
+            self.aborted_transaction_ids.clone(),
+        )
+    }
+
+    /// Returns the transactions at the given positions of the compact block,
+    /// which are the transactions a peer found missing from its mempool.
+    pub fn get_compact_transactions(&self, positions: &[u32]) -> Result<Vec<Transaction<N>>> {
+        positions
+            .iter()
+            .map(|position| match self.transactions.iter().nth(*position as usize) {
+                Some(confirmed) => Ok(confirmed.transaction().clone()),
+                None => bail!("Block '{}' has no transaction at position {position}", self.block_hash),
+            })
+            .collect()
+    }
+}
+
+/// Returns the short ID of the given transaction ID, keyed by the given block hash.
+fn to_short_id<N: Network>(block_hash: N::BlockHash, transaction_id: &N::TransactionID) -> Result<u64> {
+    // Hash the transaction ID, keyed by the block hash.
+    let hash = N::hash_psd2(&[*block_hash, **transaction_id])?;
+    // Truncate the hash to 8 bytes.
+    let bytes = hash.to_bytes_le()?;
+    Ok(u64::from_le_bytes(bytes[..8].try_into()?))
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples the compact block of the genesis block.
+    pub(crate) fn sample_compact_block(rng: &mut TestRng) -> CompactBlock<CurrentNetwork> {
+        crate::test_helpers::sample_genesis_block(rng).to_compact().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_block() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block and its compact block.
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        let compact = block.to_compact().unwrap();
+        assert_eq!(compact.hash(), block.hash());
+        assert_eq!(compact.transactions().len(), block.transactions().len());
+
+        // Retrieve the transactions.
+        let transactions =
+            block.transactions().iter().map(|confirmed| confirmed.transaction().clone()).collect::<Vec<_>>();
+
+        // Ensure the block is reconstructed from a full mempool.
+        assert!(compact.missing_transactions(&transactions).unwrap().is_empty());
+        assert_eq!(compact.clone().into_block(&transactions, vec![]).unwrap(), block);
+
+        // Ensure every transaction is missing from an empty mempool.
+        let missing = compact.missing_transactions(std::iter::empty()).unwrap();
+        assert_eq!(missing.len(), transactions.len());
+        // Ensure the block is reconstructed from the fetched transactions.
+        let fetched = block.get_compact_transactions(&missing).unwrap();
+        assert_eq!(compact.clone().into_block(std::iter::empty(), fetched).unwrap(), block);
+
+        // Ensure the reconstruction fails without the missing transactions.
+        assert!(compact.clone().into_block(std::iter::empty(), vec![]).is_err());
+        // Ensure the reconstruction fails with extra transactions.
+        assert!(compact.clone().into_block(&transactions, transactions.clone()).is_err());
+        // Ensure the block has no transaction past its last position.
+        assert!(block.get_compact_transactions(&[transactions.len() as u32]).is_err());
+    }
+
+    #[test]
+    fn test_short_id() {
+        let rng = &mut TestRng::default();
+
+        // Sample the compact block.
+        let compact = test_helpers::sample_compact_block(rng);
+        for transaction in compact.transactions() {
+            if let CompactTransaction::Short(_, short_id, _) = transaction {
+                // Ensure no other transaction in the block shares the short ID.
+                let num_matches = compact
+                    .transactions()
+                    .iter()
+                    .filter(|other| matches!(other, CompactTransaction::Short(_, other_id, _) if other_id == short_id))
+                    .count();
+                assert_eq!(num_matches, 1);
+            }
+        }
+    }
+}
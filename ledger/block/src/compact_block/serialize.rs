@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for CompactBlock<N> {
+    /// Serializes the compact block to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut block = serializer.serialize_struct("CompactBlock", 8)?;
+                block.serialize_field("block_hash", &self.block_hash)?;
+                block.serialize_field("previous_hash", &self.previous_hash)?;
+                block.serialize_field("header", &self.header)?;
+                block.serialize_field("authority", &self.authority)?;
+                block.serialize_field("ratifications", &self.ratifications)?;
+
+                if let Some(solutions) = &self.solutions {
+                    block.serialize_field("solutions", solutions)?;
+                }
+
+                block.serialize_field("transactions", &self.transactions)?;
+                block.serialize_field("aborted_transaction_ids", &self.aborted_transaction_ids)?;
+                block.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for CompactBlock<N> {
+    /// Deserializes the compact block from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut block = serde_json::Value::deserialize(deserializer)?;
+
+                // Retrieve the solutions.
+                let solutions = block.get_mut("solutions").unwrap_or(&mut serde_json::Value::Null).take();
+
+                // Recover the compact block.
+                Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut block, "block_hash")?,
+                    DeserializeExt::take_from_value::<D>(&mut block, "previous_hash")?,
+                    DeserializeExt::take_from_value::<D>(&mut block, "header")?,
+                    DeserializeExt::take_from_value::<D>(&mut block, "authority")?,
+                    DeserializeExt::take_from_value::<D>(&mut block, "ratifications")?,
+                    serde_json::from_value(solutions).map_err(de::Error::custom)?,
+                    DeserializeExt::take_from_value::<D>(&mut block, "transactions")?,
+                    DeserializeExt::take_from_value::<D>(&mut block, "aborted_transaction_ids")?,
+                )
+                .map_err(de::Error::custom)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "compact block"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        let rng = &mut TestRng::default();
+
+        // Sample the compact block.
+        let expected = test_helpers::sample_compact_block(rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected).unwrap();
+
+        // Deserialize
+        assert_eq!(expected, CompactBlock::from_str(expected_string).unwrap());
+        assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+    }
+
+    #[test]
+    fn test_bincode() {
+        let rng = &mut TestRng::default();
+
+        // Sample the compact block.
+        let expected = test_helpers::sample_compact_block(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, CompactBlock::read_le(&expected_bytes[..]).unwrap());
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::prelude::*;
+
+/// A labeled tree of values, which describes an object for people (e.g. in explorers and debuggers).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisplayTree {
+    /// The label.
+    label: String,
+    /// The value, if one exists.
+    value: Option<String>,
+    /// The children.
+    children: Vec<DisplayTree>,
+}
+
+impl DisplayTree {
+    /// Initializes a new node with the given label, and no value.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self { label: label.into(), value: None, children: Vec::new() }
+    }
+
+    /// Initializes a new node with the given label and value.
+    pub fn leaf(label: impl Into<String>, value: impl Display) -> Self {
+        Self { label: label.into(), value: Some(value.to_string()), children: Vec::new() }
+    }
+
+    /// Sets the value of the node.
+    pub fn with_value(mut self, value: impl Display) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    /// Appends the given child to the node.
+    pub fn with_child(mut self, child: DisplayTree) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Appends the given children to the node.
+    pub fn with_children(mut self, children: impl IntoIterator<Item = DisplayTree>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// Returns the label.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the value, if one exists.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+
+    /// Returns the children.
+    pub fn children(&self) -> &[DisplayTree] {
+        &self.children
+    }
+
+    /// Returns the tree as JSON.
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut object = serde_json::Map::new();
+        object.insert("label".to_string(), self.label.clone().into());
+        if let Some(value) = &self.value {
+            object.insert("value".to_string(), value.clone().into());
+        }
+        if !self.children.is_empty() {
+            object.insert("children".to_string(), self.children.iter().map(Self::to_json).collect());
+        }
+        serde_json::Value::Object(object)
+    }
+
+    /// Writes the children of the node, where each line starts with the given prefix.
+    fn fmt_children(&self, f: &mut Formatter, prefix: &str) -> fmt::Result {
+        for (i, child) in self.children.iter().enumerate() {
+            // Determine the connectors, based on whether this is the last child.
+            let is_last = i + 1 == self.children.len();
+            let (connector, indent) = if is_last { ("└── ", "    ") } else { ("├── ", "│   ") };
+            // Write the child, and then its children.
+            writeln!(f, "{prefix}{connector}{}", child.line())?;
+            child.fmt_children(f, &format!("{prefix}{indent}"))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the line for the node.
+    fn line(&self) -> String {
+        match &self.value {
+            Some(value) => format!("{}: {value}", self.label),
+            None => self.label.clone(),
+        }
+    }
+}
+
+impl Serialize for DisplayTree {
+    /// Serializes the tree as JSON.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json().serialize(serializer)
+    }
+}
+
+impl Display for DisplayTree {
+    /// Displays the tree as indented text.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.line())?;
+        self.fmt_children(f, "")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_tree() {
+        let tree = DisplayTree::leaf("transaction", "at1")
+            .with_child(DisplayTree::leaf("type", "execute"))
+            .with_child(DisplayTree::new("transition").with_child(DisplayTree::leaf("program", "credits.aleo")));
+
+        // Ensure the text renderer draws the tree.
+        let expected = "transaction: at1\n├── type: execute\n└── transition\n    └── program: credits.aleo\n";
+        assert_eq!(tree.to_string(), expected);
+
+        // Ensure the JSON renderer preserves the tree.
+        let expected = serde_json::json!({
+            "label": "transaction",
+            "value": "at1",
+            "children": [
+                { "label": "type", "value": "execute" },
+                { "label": "transition", "children": [{ "label": "program", "value": "credits.aleo" }] },
+            ],
+        });
+        assert_eq!(tree.to_json(), expected);
+        assert_eq!(serde_json::to_value(&tree).unwrap(), expected);
+    }
+}
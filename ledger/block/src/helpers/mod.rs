@@ -12,5 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod display_tree;
+pub use display_tree::*;
+
 mod target;
 pub use target::*;
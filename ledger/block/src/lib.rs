@@ -17,6 +17,9 @@
 // #![warn(clippy::cast_possible_truncation)]
 #![cfg_attr(test, allow(clippy::single_element_loop))]
 
+pub mod compact_block;
+pub use compact_block::*;
+
 pub mod header;
 pub use header::*;
 
@@ -29,9 +32,15 @@ pub use ratifications::*;
 pub mod ratify;
 pub use ratify::*;
 
+pub mod swap;
+pub use swap::*;
+
 pub mod transaction;
 pub use transaction::*;
 
+pub mod transaction_proof;
+pub use transaction_proof::*;
+
 pub mod transactions;
 pub use transactions::*;
 
@@ -56,7 +56,25 @@ impl<N: Network> FromBytes for Ratify<N> {
                 // Return the ratify object.
                 Self::PuzzleReward(amount)
             }
-            3.. => return Err(error(format!("Failed to decode ratify object variant {variant}"))),
+            3 => {
+                // Read the commitment.
+                let commitment: Field<N> = FromBytes::read_le(&mut reader)?;
+                // Read the signature.
+                let signature: Signature<N> = FromBytes::read_le(&mut reader)?;
+                // Return the ratify object.
+                Self::BeaconCommit(commitment, signature)
+            }
+            4 => {
+                // Read the address.
+                let address: Address<N> = FromBytes::read_le(&mut reader)?;
+                // Read the value.
+                let value: Field<N> = FromBytes::read_le(&mut reader)?;
+                // Read the randomizer.
+                let randomizer: Scalar<N> = FromBytes::read_le(&mut reader)?;
+                // Return the ratify object.
+                Self::BeaconReveal(address, value, randomizer)
+            }
+            5.. => return Err(error(format!("Failed to decode ratify object variant {variant}"))),
         };
         Ok(ratify)
     }
@@ -87,6 +105,17 @@ impl<N: Network> ToBytes for Ratify<N> {
                 (2 as Variant).write_le(&mut writer)?;
                 amount.write_le(&mut writer)
             }
+            Self::BeaconCommit(commitment, signature) => {
+                (3 as Variant).write_le(&mut writer)?;
+                commitment.write_le(&mut writer)?;
+                signature.write_le(&mut writer)
+            }
+            Self::BeaconReveal(address, value, randomizer) => {
+                (4 as Variant).write_le(&mut writer)?;
+                address.write_le(&mut writer)?;
+                value.write_le(&mut writer)?;
+                randomizer.write_le(&mut writer)
+            }
         }
     }
 }
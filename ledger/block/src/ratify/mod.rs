@@ -16,7 +16,11 @@ mod bytes;
 mod serialize;
 mod string;
 
-use console::{network::prelude::*, types::Address};
+use console::{
+    account::Signature,
+    network::prelude::*,
+    types::{Address, Field, Scalar},
+};
 use ledger_committee::Committee;
 
 use indexmap::IndexMap;
@@ -33,6 +37,10 @@ pub enum Ratify<N: Network> {
     BlockReward(u64),
     /// The puzzle reward.
     PuzzleReward(u64),
+    /// The commitment of a committee member to the randomness beacon, signed over `(epoch, commitment)`.
+    BeaconCommit(Field<N>, Signature<N>),
+    /// The revealed value and randomizer of a commitment to the randomness beacon, from the previous epoch.
+    BeaconReveal(Address<N>, Field<N>, Scalar<N>),
 }
 
 impl<N: Network> Ratify<N> {
@@ -56,10 +64,16 @@ pub(crate) mod test_helpers {
             public_balances.insert(*address, rng.gen());
         }
 
+        let private_key = console::account::PrivateKey::new(rng).unwrap();
+        let commitment: Field<CurrentNetwork> = Uniform::rand(rng);
+        let signature = Signature::sign(&private_key, &[Field::from_u32(rng.gen()), commitment], rng).unwrap();
+
         vec![
             Ratify::Genesis(committee, public_balances),
             Ratify::BlockReward(rng.gen()),
             Ratify::PuzzleReward(rng.gen()),
+            Ratify::BeaconCommit(commitment, signature),
+            Ratify::BeaconReveal(Address::try_from(private_key).unwrap(), Uniform::rand(rng), Uniform::rand(rng)),
         ]
     }
 }
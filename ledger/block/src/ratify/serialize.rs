@@ -38,6 +38,21 @@ impl<N: Network> Serialize for Ratify<N> {
                     input.serialize_field("amount", &amount)?;
                     input.end()
                 }
+                Self::BeaconCommit(commitment, signature) => {
+                    let mut input = serializer.serialize_struct("Ratify", 3)?;
+                    input.serialize_field("type", "beacon_commit")?;
+                    input.serialize_field("commitment", &commitment)?;
+                    input.serialize_field("signature", &signature)?;
+                    input.end()
+                }
+                Self::BeaconReveal(address, value, randomizer) => {
+                    let mut input = serializer.serialize_struct("Ratify", 4)?;
+                    input.serialize_field("type", "beacon_reveal")?;
+                    input.serialize_field("address", &address)?;
+                    input.serialize_field("value", &value)?;
+                    input.serialize_field("randomizer", &randomizer)?;
+                    input.end()
+                }
             },
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
         }
@@ -75,6 +90,24 @@ impl<'de, N: Network> Deserialize<'de> for Ratify<N> {
                         // Construct the ratify object.
                         Ratify::PuzzleReward(amount)
                     }
+                    Some("beacon_commit") => {
+                        // Retrieve the commitment.
+                        let commitment: Field<N> = DeserializeExt::take_from_value::<D>(&mut object, "commitment")?;
+                        // Retrieve the signature.
+                        let signature: Signature<N> = DeserializeExt::take_from_value::<D>(&mut object, "signature")?;
+                        // Construct the ratify object.
+                        Ratify::BeaconCommit(commitment, signature)
+                    }
+                    Some("beacon_reveal") => {
+                        // Retrieve the address.
+                        let address: Address<N> = DeserializeExt::take_from_value::<D>(&mut object, "address")?;
+                        // Retrieve the value.
+                        let value: Field<N> = DeserializeExt::take_from_value::<D>(&mut object, "value")?;
+                        // Retrieve the randomizer.
+                        let randomizer: Scalar<N> = DeserializeExt::take_from_value::<D>(&mut object, "randomizer")?;
+                        // Construct the ratify object.
+                        Ratify::BeaconReveal(address, value, randomizer)
+                    }
                     _ => return Err(de::Error::custom("Invalid ratify object type")),
                 };
                 // Return the ratify object.
@@ -0,0 +1,109 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for PartiallySignedSwap<N> {
+    /// Reads the partially-signed swap from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid partially-signed swap version"));
+        }
+
+        // Read the terms.
+        let mut terms = Vec::with_capacity(2);
+        for _ in 0..2 {
+            terms.push((Address::read_le(&mut reader)?, Locator::read_le(&mut reader)?));
+        }
+        let terms: [_; 2] = terms.try_into().map_err(|_| error("Failed to read the swap terms"))?;
+
+        // Initialize the swap.
+        let mut swap = Self::new(terms).map_err(error)?;
+
+        // Read the transactions, proofs, and signatures of each leg.
+        for index in 0..2 {
+            match u8::read_le(&mut reader)? {
+                0 => (),
+                1 => {
+                    let transaction = Transaction::read_le(&mut reader)?;
+                    let proof = LegProof::read_le(&mut reader)?;
+                    swap.add_leg(&terms[index].0, transaction, proof).map_err(error)?;
+                }
+                variant => return Err(error(format!("Invalid swap transaction variant '{variant}'"))),
+            }
+            swap.signatures[index] = match u8::read_le(&mut reader)? {
+                0 => None,
+                1 => Some(Signature::read_le(&mut reader)?),
+                variant => return Err(error(format!("Invalid swap signature variant '{variant}'"))),
+            };
+        }
+        Ok(swap)
+    }
+}
+
+impl<N: Network> ToBytes for PartiallySignedSwap<N> {
+    /// Writes the partially-signed swap to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the terms.
+        for (signer, locator) in &self.terms {
+            signer.write_le(&mut writer)?;
+            locator.write_le(&mut writer)?;
+        }
+
+        // Write the transactions, proofs, and signatures of each leg.
+        for ((transaction, proof), signature) in self.transactions.iter().zip_eq(&self.proofs).zip_eq(&self.signatures)
+        {
+            match (transaction, proof) {
+                (Some(transaction), Some(proof)) => {
+                    1u8.write_le(&mut writer)?;
+                    transaction.write_le(&mut writer)?;
+                    proof.write_le(&mut writer)?;
+                }
+                _ => 0u8.write_le(&mut writer)?,
+            }
+            match signature {
+                None => 0u8.write_le(&mut writer)?,
+                Some(signature) => {
+                    1u8.write_le(&mut writer)?;
+                    signature.write_le(&mut writer)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let ([first, _], mut expected) = crate::swap::test_helpers::sample_swap(rng);
+        expected.sign(&first, rng)?;
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, PartiallySignedSwap::read_le(&expected_bytes[..])?);
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A proof that a swap leg was signed by a given party.
+///
+/// The root transition of a leg commits to its transition view key `tvk = (r * G) * view_key`,
+/// where `r * G` is the transition public key. The proof reveals `tvk` as a group element, and
+/// shows that it shares a discrete logarithm (the view key) with the party's address.
+/// Note that revealing `tvk` lets the counterparty decrypt the private values of the leg.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct LegProof<N: Network> {
+    /// The transition view key, as a group element.
+    tvk: Group<N>,
+    /// The challenge of the proof.
+    challenge: Scalar<N>,
+    /// The response of the proof.
+    response: Scalar<N>,
+}
+
+impl<N: Network> LegProof<N> {
+    /// Proves that the given transition was signed by the owner of the given view key.
+    pub fn prove<R: Rng + CryptoRng>(view_key: &ViewKey<N>, transition: &Transition<N>, rng: &mut R) -> Result<Self> {
+        // Compute the transition view key.
+        let tvk = *transition.tpk() * **view_key;
+        // Ensure the transition view key matches the transition commitment.
+        ensure!(N::hash_psd2(&[tvk.to_x_coordinate()])? == *transition.tcm(), "The view key did not sign the leg");
+
+        // Sample a random nonce, and compute the challenge.
+        let nonce = Scalar::rand(rng);
+        let challenge = Self::to_challenge(
+            &view_key.to_address(),
+            transition,
+            &tvk,
+            N::g_scalar_multiply(&nonce),
+            *transition.tpk() * nonce,
+        )?;
+        // Compute the response.
+        let response = nonce - challenge * **view_key;
+
+        Ok(Self { tvk, challenge, response })
+    }
+
+    /// Returns `true` if the given transition was signed by the given address.
+    pub fn verify(&self, signer: &Address<N>, transition: &Transition<N>) -> bool {
+        // Ensure the transition view key matches the transition commitment.
+        match N::hash_psd2(&[self.tvk.to_x_coordinate()]) {
+            Ok(tcm) if tcm == *transition.tcm() => (),
+            _ => return false,
+        }
+        // Recompute the nonce commitments, as `G * response + address * challenge`
+        // and `tpk * response + tvk * challenge`.
+        let g_r = N::g_scalar_multiply(&self.response) + **signer * self.challenge;
+        let tpk_r = *transition.tpk() * self.response + self.tvk * self.challenge;
+        // Ensure the challenge matches.
+        match Self::to_challenge(signer, transition, &self.tvk, g_r, tpk_r) {
+            Ok(challenge) => challenge == self.challenge,
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the challenge, as a hash of the statement and the nonce commitments.
+    fn to_challenge(
+        signer: &Address<N>,
+        transition: &Transition<N>,
+        tvk: &Group<N>,
+        g_r: Group<N>,
+        tpk_r: Group<N>,
+    ) -> Result<Scalar<N>> {
+        N::hash_to_scalar_psd8(&[
+            signer.to_x_coordinate(),
+            transition.tpk().to_x_coordinate(),
+            *transition.tcm(),
+            tvk.to_x_coordinate(),
+            g_r.to_x_coordinate(),
+            tpk_r.to_x_coordinate(),
+        ])
+    }
+}
+
+impl<N: Network> FromBytes for LegProof<N> {
+    /// Reads the leg proof from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let tvk = Group::read_le(&mut reader)?;
+        let challenge = Scalar::read_le(&mut reader)?;
+        let response = Scalar::read_le(&mut reader)?;
+        Ok(Self { tvk, challenge, response })
+    }
+}
+
+impl<N: Network> ToBytes for LegProof<N> {
+    /// Writes the leg proof to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.tvk.write_le(&mut writer)?;
+        self.challenge.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> Serialize for LegProof<N> {
+    /// Serializes the leg proof into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut proof = serializer.serialize_struct("LegProof", 3)?;
+                proof.serialize_field("tvk", &self.tvk)?;
+                proof.serialize_field("challenge", &self.challenge)?;
+                proof.serialize_field("response", &self.response)?;
+                proof.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for LegProof<N> {
+    /// Deserializes the leg proof from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut proof = serde_json::Value::deserialize(deserializer)?;
+                Ok(Self {
+                    tvk: DeserializeExt::take_from_value::<D>(&mut proof, "tvk")?,
+                    challenge: DeserializeExt::take_from_value::<D>(&mut proof, "challenge")?,
+                    response: DeserializeExt::take_from_value::<D>(&mut proof, "response")?,
+                })
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "leg proof"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leg_proof() {
+        let rng = &mut TestRng::default();
+
+        let (private_keys, swap) = crate::swap::test_helpers::sample_swap(rng);
+        let [first, second] = private_keys.map(|private_key| ViewKey::try_from(private_key).unwrap());
+        let [first_leg, second_leg] = swap.transactions().clone().map(Option::unwrap);
+        let first_transition = first_leg.execution().unwrap().peek().unwrap();
+        let second_transition = second_leg.execution().unwrap().peek().unwrap();
+
+        // Ensure the proof verifies for the signer of the leg.
+        let proof = LegProof::prove(&first, first_transition, rng).unwrap();
+        assert!(proof.verify(&first.to_address(), first_transition));
+        // Ensure the proof does not verify for another address or transition.
+        assert!(!proof.verify(&second.to_address(), first_transition));
+        assert!(!proof.verify(&first.to_address(), second_transition));
+
+        // Ensure a party cannot prove a leg it did not sign.
+        assert!(LegProof::prove(&first, second_transition, rng).is_err());
+
+        // Check the byte representation.
+        assert_eq!(proof, LegProof::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap());
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod leg_proof;
+pub use leg_proof::*;
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::{Transaction, Transition};
+use console::{
+    account::{Address, PrivateKey, Signature, ViewKey},
+    network::prelude::*,
+    program::Locator,
+    types::{Field, Group, Scalar},
+};
+
+/// A partially-signed swap between two parties.
+///
+/// Each party contributes one leg of the swap, as an execute transaction of the agreed function,
+/// with a proof that they signed its root transition. Once both legs are present, each party signs
+/// the swap ID, which commits to the terms and both transactions.
+///
+/// The signatures record that both parties agreed to the complete swap, but do not make it atomic:
+/// each leg is a valid transaction on its own, and may be broadcast without the other.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PartiallySignedSwap<N: Network> {
+    /// The signer and function of each leg.
+    terms: [(Address<N>, Locator<N>); 2],
+    /// The transaction of each leg, if it has been contributed.
+    transactions: [Option<Transaction<N>>; 2],
+    /// The proof that the signer of each leg signed its transaction, if it has been contributed.
+    proofs: [Option<LegProof<N>>; 2],
+    /// The signature of each party on the swap ID, if it has been signed.
+    signatures: [Option<Signature<N>>; 2],
+}
+
+impl<N: Network> PartiallySignedSwap<N> {
+    /// Initializes a new swap with the given terms, as the signer and function of each leg.
+    pub fn new(terms: [(Address<N>, Locator<N>); 2]) -> Result<Self> {
+        // Ensure the legs are signed by distinct parties.
+        ensure!(terms[0].0 != terms[1].0, "The legs of a swap must be signed by distinct parties");
+        Ok(Self { terms, transactions: [None, None], proofs: [None, None], signatures: [None, None] })
+    }
+
+    /// Returns the signer and function of each leg.
+    pub const fn terms(&self) -> &[(Address<N>, Locator<N>); 2] {
+        &self.terms
+    }
+
+    /// Returns the transaction of each leg, if it has been contributed.
+    pub const fn transactions(&self) -> &[Option<Transaction<N>>; 2] {
+        &self.transactions
+    }
+
+    /// Returns the proof for each leg, if it has been contributed.
+    pub const fn proofs(&self) -> &[Option<LegProof<N>>; 2] {
+        &self.proofs
+    }
+
+    /// Returns the signature of each party, if it has been signed.
+    pub const fn signatures(&self) -> &[Option<Signature<N>>; 2] {
+        &self.signatures
+    }
+
+    /// Returns `true` if both legs are present, and both parties have signed.
+    pub fn is_complete(&self) -> bool {
+        self.finalize().is_ok()
+    }
+
+    /// Adds the transaction for the leg of the given signer, with a proof that they signed it.
+    pub fn add_leg(&mut self, signer: &Address<N>, transaction: Transaction<N>, proof: LegProof<N>) -> Result<()> {
+        // Retrieve the leg of the signer.
+        let index = self.leg_index(signer)?;
+        // Ensure the leg has not been contributed.
+        ensure!(self.transactions[index].is_none(), "The leg for '{signer}' has already been contributed");
+
+        // Ensure the transaction executes the agreed function.
+        let Some(execution) = transaction.execution() else { bail!("A swap leg must be an execute transaction") };
+        let transition = execution.peek()?;
+        let locator = Locator::new(*transition.program_id(), *transition.function_name());
+        ensure!(
+            locator == self.terms[index].1,
+            "Expected the swap leg to execute '{}', found '{locator}'",
+            self.terms[index].1
+        );
+        // Ensure the root transition was signed by the signer.
+        ensure!(proof.verify(signer, transition), "The swap leg was not signed by '{signer}'");
+
+        // Ensure the transaction differs from the counterparty leg.
+        if let Some(other) = &self.transactions[1 - index] {
+            ensure!(other.id() != transaction.id(), "The legs of a swap must be distinct transactions");
+        }
+
+        self.transactions[index] = Some(transaction);
+        self.proofs[index] = Some(proof);
+        Ok(())
+    }
+
+    /// Returns the swap ID, as a hash of the terms and both transaction IDs.
+    pub fn to_swap_id(&self) -> Result<Field<N>> {
+        let mut preimage = Vec::new();
+        for ((signer, locator), transaction) in self.terms.iter().zip_eq(&self.transactions) {
+            let Some(transaction) = transaction else { bail!("The swap leg for '{signer}' is missing") };
+            preimage.push(signer.to_field()?);
+            preimage.extend(locator.to_fields()?);
+            preimage.push(*transaction.id());
+        }
+        N::hash_psd8(&preimage)
+    }
+
+    /// Signs the swap ID with the given private key, which must be one of the two parties.
+    pub fn sign<R: Rng + CryptoRng>(&mut self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<()> {
+        // Retrieve the leg of the signer.
+        let index = self.leg_index(&Address::try_from(private_key)?)?;
+        // Sign the swap ID, which requires both legs to be present.
+        self.signatures[index] = Some(Signature::sign(private_key, &[self.to_swap_id()?], rng)?);
+        Ok(())
+    }
+
+    /// Returns the transactions of both legs, if both parties have signed the complete swap.
+    pub fn finalize(&self) -> Result<[Transaction<N>; 2]> {
+        // Compute the swap ID, which requires both legs to be present.
+        let swap_id = self.to_swap_id()?;
+        // Ensure both parties have signed the swap ID.
+        for ((signer, _), signature) in self.terms.iter().zip_eq(&self.signatures) {
+            match signature {
+                Some(signature) => {
+                    ensure!(signature.verify(signer, &[swap_id]), "Invalid swap signature from '{signer}'")
+                }
+                None => bail!("The swap has not been signed by '{signer}'"),
+            }
+        }
+        match &self.transactions {
+            [Some(first), Some(second)] => Ok([first.clone(), second.clone()]),
+            _ => bail!("The swap is missing a leg"),
+        }
+    }
+
+    /// Returns the index of the leg signed by the given address.
+    fn leg_index(&self, signer: &Address<N>) -> Result<usize> {
+        match self.terms.iter().position(|(address, _)| address == signer) {
+            Some(index) => Ok(index),
+            None => bail!("'{signer}' is not a party to the swap"),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test_helpers {
+    use super::*;
+    use crate::Execution;
+    use console::network::Testnet3;
+    use synthesizer_process::Process;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    /// Samples an unproven leg that transfers public credits from the given private key.
+    pub(crate) fn sample_leg(
+        private_key: &PrivateKey<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Transaction<CurrentNetwork> {
+        // Prepare the function inputs.
+        let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let inputs = [recipient.to_string(), "1_u64".to_string()];
+
+        // Authorize the function.
+        let process = Process::load().unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(private_key, "credits.aleo", "transfer_public", inputs.iter(), rng)
+            .unwrap();
+        // Convert the transitions.
+        // Note: This is a testing-only hack to adhere to Rust's dependency cycle rules.
+        let transitions =
+            authorization.transitions().into_values().map(|transition| Transition::from_str(&transition.to_string()));
+        let execution = Execution::from(transitions.map(Result::unwrap), Default::default(), None).unwrap();
+
+        // Construct the transaction.
+        Transaction::from_execution(execution, None).unwrap()
+    }
+
+    /// Samples the private keys of both parties, and their partially-signed swap with both legs contributed.
+    pub(crate) fn sample_swap(
+        rng: &mut TestRng,
+    ) -> ([PrivateKey<CurrentNetwork>; 2], PartiallySignedSwap<CurrentNetwork>) {
+        let private_keys = [PrivateKey::new(rng).unwrap(), PrivateKey::new(rng).unwrap()];
+        let addresses = private_keys.map(|private_key| Address::try_from(private_key).unwrap());
+
+        // Sample the transactions of both legs.
+        let locator = Locator::from_str("credits.aleo/transfer_public").unwrap();
+        let transactions = [sample_leg(&private_keys[0], rng), sample_leg(&private_keys[1], rng)];
+
+        // Construct the swap.
+        let mut swap = PartiallySignedSwap::new([(addresses[0], locator), (addresses[1], locator)]).unwrap();
+        for ((private_key, address), transaction) in private_keys.iter().zip_eq(&addresses).zip_eq(transactions) {
+            let view_key = ViewKey::try_from(private_key).unwrap();
+            let proof = LegProof::prove(&view_key, transaction.execution().unwrap().peek().unwrap(), rng).unwrap();
+            swap.add_leg(address, transaction, proof).unwrap();
+        }
+        (private_keys, swap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap() {
+        let rng = &mut TestRng::default();
+
+        let ([first, second], mut swap) = test_helpers::sample_swap(rng);
+
+        // Ensure the swap cannot be finalized until both parties sign.
+        assert!(!swap.is_complete());
+        swap.sign(&first, rng).unwrap();
+        assert!(swap.finalize().is_err());
+        swap.sign(&second, rng).unwrap();
+        assert!(swap.is_complete());
+
+        // Ensure the finalized transactions match the legs.
+        let [a, b] = swap.finalize().unwrap();
+        assert_eq!(Some(a), swap.transactions()[0]);
+        assert_eq!(Some(b), swap.transactions()[1]);
+
+        // Ensure a third party cannot sign.
+        let outsider = PrivateKey::new(rng).unwrap();
+        assert!(swap.clone().sign(&outsider, rng).is_err());
+
+        // Ensure a signature on different terms is rejected.
+        let mut tampered = swap.clone();
+        tampered.signatures.swap(0, 1);
+        assert!(tampered.finalize().is_err());
+    }
+
+    #[test]
+    fn test_swap_legs() {
+        let rng = &mut TestRng::default();
+
+        let ([first, _], swap) = test_helpers::sample_swap(rng);
+        let [first_address, second_address] = swap.terms().map(|(address, _)| address);
+        let [first_transaction, second_transaction] = swap.transactions().clone().map(Option::unwrap);
+        let [first_proof, second_proof] = swap.proofs().map(Option::unwrap);
+
+        // Ensure the parties must be distinct.
+        let locator = swap.terms()[0].1;
+        assert!(PartiallySignedSwap::new([(first_address, locator), (first_address, locator)]).is_err());
+
+        // Ensure a swap cannot be signed until both legs are present.
+        let mut partial = PartiallySignedSwap::new(*swap.terms()).unwrap();
+        partial.add_leg(&first_address, first_transaction.clone(), first_proof).unwrap();
+        assert!(partial.to_swap_id().is_err());
+        assert!(partial.clone().sign(&first, rng).is_err());
+
+        // Ensure a leg cannot be contributed twice, or as the wrong party.
+        assert!(partial.add_leg(&first_address, first_transaction.clone(), first_proof).is_err());
+        assert!(partial.add_leg(&second_address, first_transaction, first_proof).is_err());
+        // Ensure a leg cannot be contributed with the proof of another leg.
+        assert!(partial.add_leg(&second_address, second_transaction.clone(), first_proof).is_err());
+        partial.add_leg(&second_address, second_transaction, second_proof).unwrap();
+    }
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for PartiallySignedSwap<N> {
+    /// Serializes the partially-signed swap into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut swap = serializer.serialize_struct("PartiallySignedSwap", 4)?;
+                swap.serialize_field("terms", &self.terms)?;
+                swap.serialize_field("transactions", &self.transactions)?;
+                swap.serialize_field("proofs", &self.proofs)?;
+                swap.serialize_field("signatures", &self.signatures)?;
+                swap.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for PartiallySignedSwap<N> {
+    /// Deserializes the partially-signed swap from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the swap from a string into a value.
+                let mut swap = serde_json::Value::deserialize(deserializer)?;
+                // Retrieve the terms.
+                let terms: [(Address<N>, Locator<N>); 2] = DeserializeExt::take_from_value::<D>(&mut swap, "terms")?;
+                // Initialize the swap.
+                let mut partially_signed_swap = Self::new(terms).map_err(de::Error::custom)?;
+                // Retrieve the transactions and proofs, and add each contributed leg.
+                let transactions: [Option<Transaction<N>>; 2] =
+                    DeserializeExt::take_from_value::<D>(&mut swap, "transactions")?;
+                let proofs: [Option<LegProof<N>>; 2] = DeserializeExt::take_from_value::<D>(&mut swap, "proofs")?;
+                for (((signer, _), transaction), proof) in terms.iter().zip_eq(transactions).zip_eq(proofs) {
+                    match (transaction, proof) {
+                        (Some(transaction), Some(proof)) => {
+                            partially_signed_swap.add_leg(signer, transaction, proof).map_err(de::Error::custom)?
+                        }
+                        (None, None) => (),
+                        _ => return Err(de::Error::custom("Each swap leg must have both a transaction and a proof")),
+                    }
+                }
+                // Retrieve the signatures.
+                partially_signed_swap.signatures = DeserializeExt::take_from_value::<D>(&mut swap, "signatures")?;
+                Ok(partially_signed_swap)
+            }
+            false => {
+                FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "partially-signed swap")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let ([first, _], mut expected) = crate::swap::test_helpers::sample_swap(rng);
+        expected.sign(&first, rng)?;
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected)?;
+
+        // Deserialize
+        assert_eq!(expected, PartiallySignedSwap::from_str(expected_string)?);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let (_, expected) = crate::swap::test_helpers::sample_swap(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, PartiallySignedSwap::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+        Ok(())
+    }
+}
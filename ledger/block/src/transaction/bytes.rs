@@ -20,6 +20,10 @@ impl<N: Network> FromBytes for Transaction<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
+        // Read the packed representation.
+        if version == 2 {
+            return Self::read_packed_le(&mut reader);
+        }
         // Ensure the version is valid.
         if version != 1 {
             return Err(error("Invalid transaction version"));
@@ -20,12 +20,20 @@ impl<N: Network> FromBytes for Deployment<N> {
         // Read the version.
         let version = u8::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 1 {
+        if version != 1 && version != 2 {
             return Err(error("Invalid deployment version"));
         }
 
         // Read the edition.
         let edition = u16::read_le(&mut reader)?;
+        // Read the program edition, which is only written for upgrades.
+        let program_edition = match version {
+            1 => 0,
+            _ => match u16::read_le(&mut reader)? {
+                0 => return Err(error("Invalid program edition for an upgrade")),
+                program_edition => program_edition,
+            },
+        };
         // Read the program.
         let program = Program::read_le(&mut reader)?;
 
@@ -45,17 +53,25 @@ impl<N: Network> FromBytes for Deployment<N> {
         }
 
         // Return the deployment.
-        Self::new(edition, program, verifying_keys).map_err(|err| error(format!("{err}")))
+        Self::new_with_program_edition(edition, program_edition, program, verifying_keys)
+            .map_err(|err| error(format!("{err}")))
     }
 }
 
 impl<N: Network> ToBytes for Deployment<N> {
     /// Writes the deployment to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        // Write the version.
-        1u8.write_le(&mut writer)?;
+        // Write the version, which is 2 for upgrades, so initial deployments keep their encoding.
+        match self.is_upgrade() {
+            true => 2u8.write_le(&mut writer)?,
+            false => 1u8.write_le(&mut writer)?,
+        }
         // Write the edition.
         self.edition.write_le(&mut writer)?;
+        // Write the program edition, if the deployment is an upgrade.
+        if self.is_upgrade() {
+            self.program_edition.write_le(&mut writer)?;
+        }
         // Write the program.
         self.program.write_le(&mut writer)?;
         // Write the number of entries in the bundle.
@@ -91,6 +107,20 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Deployment::read_le(&expected_bytes[..])?);
         assert!(Deployment::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+        // Check the byte representation of an upgrade.
+        let expected = Deployment::new_with_program_edition(
+            expected.edition(),
+            3,
+            expected.program().clone(),
+            expected.verifying_keys().clone(),
+        )?;
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Deployment::read_le(&expected_bytes[..])?);
+        // Ensure an upgrade to program edition 0 is rejected.
+        let mut candidate_bytes = expected_bytes;
+        candidate_bytes[3..5].copy_from_slice(&0u16.to_le_bytes());
+        assert!(Deployment::<CurrentNetwork>::read_le(&candidate_bytes[..]).is_err());
         Ok(())
     }
 }
@@ -31,6 +31,8 @@ use synthesizer_snark::{Certificate, VerifyingKey};
 pub struct Deployment<N: Network> {
     /// The edition.
     edition: u16,
+    /// The program edition, which is 0 for the initial deployment and is bumped by each upgrade.
+    program_edition: u16,
     /// The program.
     program: Program<N>,
     /// The mapping of function names to their verifying key and certificate.
@@ -43,9 +45,19 @@ impl<N: Network> Deployment<N> {
         edition: u16,
         program: Program<N>,
         verifying_keys: Vec<(Identifier<N>, (VerifyingKey<N>, Certificate<N>))>,
+    ) -> Result<Self> {
+        Self::new_with_program_edition(edition, 0, program, verifying_keys)
+    }
+
+    /// Initializes a new deployment of the given program edition, which upgrades the deployed program if it is nonzero.
+    pub fn new_with_program_edition(
+        edition: u16,
+        program_edition: u16,
+        program: Program<N>,
+        verifying_keys: Vec<(Identifier<N>, (VerifyingKey<N>, Certificate<N>))>,
     ) -> Result<Self> {
         // Construct the deployment.
-        let deployment = Self { edition, program, verifying_keys };
+        let deployment = Self { edition, program_edition, program, verifying_keys };
         // Ensure the deployment is ordered.
         deployment.check_is_ordered()?;
         // Return the deployment.
@@ -56,13 +68,20 @@ impl<N: Network> Deployment<N> {
     pub fn check_is_ordered(&self) -> Result<()> {
         let program_id = self.program.id();
 
-        // Ensure the edition matches.
+        // Ensure the edition is supported by the network.
         ensure!(
-            self.edition == N::EDITION,
-            "Deployed the wrong edition (expected '{}', found '{}').",
+            self.edition <= N::EDITION,
+            "Deployed an unsupported edition (expected at most '{}', found '{}').",
             N::EDITION,
             self.edition
         );
+        // Ensure the program only uses instructions and commands that are available in the deployment edition.
+        let required_edition = self.program.required_edition();
+        ensure!(
+            required_edition <= self.edition,
+            "Program '{program_id}' requires network edition {required_edition}, but was deployed for edition {}",
+            self.edition
+        );
         // Ensure the program contains functions.
         ensure!(
             !self.program.functions().is_empty(),
@@ -104,11 +123,47 @@ impl<N: Network> Deployment<N> {
         Ok(u64::try_from(self.to_bytes_le()?.len())?)
     }
 
+    /// Returns the *minimum* cost in microcredits to publish the deployment (total cost, (storage cost, namespace cost)).
+    pub fn cost(&self) -> Result<(u64, (u64, u64))> {
+        // Determine the number of bytes in the deployment.
+        let size_in_bytes = self.size_in_bytes()?;
+        // Determine the number of characters in the program ID.
+        let num_characters = u32::try_from(self.program_id().name().to_string().len())?;
+
+        // Compute the storage cost in microcredits.
+        let storage_cost = size_in_bytes
+            .checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER)
+            .ok_or(anyhow!("The storage cost computation overflowed for a deployment"))?;
+
+        // Compute the namespace cost in credits: 10^(10 - num_characters).
+        let namespace_cost = 10u64
+            .checked_pow(10u32.saturating_sub(num_characters))
+            .ok_or(anyhow!("The namespace cost computation overflowed for a deployment"))?
+            .saturating_mul(1_000_000); // 1 microcredit = 1e-6 credits.
+
+        // Compute the total cost in microcredits.
+        let total_cost = storage_cost
+            .checked_add(namespace_cost)
+            .ok_or(anyhow!("The total cost computation overflowed for a deployment"))?;
+
+        Ok((total_cost, (storage_cost, namespace_cost)))
+    }
+
     /// Returns the edition.
     pub const fn edition(&self) -> u16 {
         self.edition
     }
 
+    /// Returns the program edition.
+    pub const fn program_edition(&self) -> u16 {
+        self.program_edition
+    }
+
+    /// Returns `true` if the deployment upgrades a deployed program.
+    pub const fn is_upgrade(&self) -> bool {
+        self.program_edition > 0
+    }
+
     /// Returns the program.
     pub const fn program(&self) -> &Program<N> {
         &self.program
@@ -173,3 +228,70 @@ function compute:
             .clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_check_is_ordered_required_edition() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a deployment, to reuse the verifying key of its 'compute' function.
+        let deployment = test_helpers::sample_deployment(rng);
+
+        // Initialize a program that reads the randomness beacon, which is not available in the initial edition.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program testing.aleo;
+
+mapping store:
+    key as u8.public;
+    value as field.public;
+
+function compute:
+    input r0 as u8.public;
+    async compute r0 into r1;
+    output r1 as testing.aleo/compute.future;
+
+finalize compute:
+    input r0 as u8.public;
+    set beacon.output into store[r0];",
+        )?;
+        let required_edition = program.required_edition();
+        assert!(required_edition > 0);
+
+        // Ensure the deployment is rejected for an edition before the one the program requires.
+        let verifying_keys = deployment.verifying_keys().clone();
+        assert!(Deployment::new(required_edition - 1, program.clone(), verifying_keys.clone()).is_err());
+        // Ensure the deployment is ordered for the edition the program requires.
+        assert!(Deployment::new(required_edition, program, verifying_keys).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_upgrade_deployment_id() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a deployment.
+        let deployment = test_helpers::sample_deployment(rng);
+        assert!(!deployment.is_upgrade());
+
+        // Construct an upgrade of the same program.
+        let upgrade = Deployment::<CurrentNetwork>::new_with_program_edition(
+            deployment.edition(),
+            1,
+            deployment.program().clone(),
+            deployment.verifying_keys().clone(),
+        )?;
+        assert!(upgrade.is_upgrade());
+        assert_eq!(upgrade.program_edition(), 1);
+
+        // Ensure the program edition is committed to in the deployment ID.
+        assert_ne!(deployment.to_deployment_id()?, upgrade.to_deployment_id()?);
+        Ok(())
+    }
+}
@@ -19,8 +19,12 @@ impl<N: Network> Serialize for Deployment<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut deployment = serializer.serialize_struct("Deployment", 3)?;
+                let mut deployment = serializer.serialize_struct("Deployment", 3 + self.is_upgrade() as usize)?;
                 deployment.serialize_field("edition", &self.edition)?;
+                // Serialize the program edition, if the deployment is an upgrade.
+                if self.is_upgrade() {
+                    deployment.serialize_field("program_edition", &self.program_edition)?;
+                }
                 deployment.serialize_field("program", &self.program)?;
                 deployment.serialize_field("verifying_keys", &self.verifying_keys)?;
                 deployment.end()
@@ -39,9 +43,15 @@ impl<'de, N: Network> Deserialize<'de> for Deployment<N> {
                 let mut deployment = serde_json::Value::deserialize(deserializer)?;
 
                 // Recover the deployment.
-                let deployment = Self::new(
+                let deployment = Self::new_with_program_edition(
                     // Retrieve the edition.
                     DeserializeExt::take_from_value::<D>(&mut deployment, "edition")?,
+                    // Retrieve the program edition, which is only present for upgrades.
+                    serde_json::from_value::<Option<u16>>(
+                        deployment.get_mut("program_edition").unwrap_or(&mut serde_json::Value::Null).take(),
+                    )
+                    .map_err(de::Error::custom)?
+                    .unwrap_or_default(),
                     // Retrieve the program.
                     DeserializeExt::take_from_value::<D>(&mut deployment, "program")?,
                     // Retrieve the verifying keys.
@@ -76,6 +86,17 @@ mod tests {
         assert_eq!(expected, Deployment::from_str(expected_string)?);
         assert_eq!(expected, serde_json::from_str(&candidate_string)?);
 
+        // Ensure an upgrade round-trips with its program edition.
+        let expected = Deployment::new_with_program_edition(
+            expected.edition(),
+            1,
+            expected.program().clone(),
+            expected.verifying_keys().clone(),
+        )?;
+        let candidate_string = serde_json::to_string(&expected)?;
+        assert!(candidate_string.contains("\"program_edition\":1"));
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
         Ok(())
     }
 
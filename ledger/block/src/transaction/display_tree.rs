@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{DisplayTree, Input, Output};
+use synthesizer_snark::Proof;
+
+impl<N: Network> Transaction<N> {
+    /// Returns a structured, human-oriented breakdown of the transaction.
+    /// The tree can be rendered as text with `Display`, or as JSON with `DisplayTree::to_json`.
+    pub fn to_display_tree(&self) -> Result<DisplayTree> {
+        let tree = DisplayTree::leaf("transaction", self.id());
+        match self {
+            Self::Deploy(_, owner, deployment, fee) => Ok(tree
+                .with_child(DisplayTree::leaf("type", "deploy"))
+                .with_child(DisplayTree::leaf("owner", owner.address()))
+                .with_child(
+                    DisplayTree::leaf("deployment", deployment.program_id())
+                        .with_child(DisplayTree::leaf("edition", deployment.edition()))
+                        .with_children(
+                            deployment
+                                .is_upgrade()
+                                .then(|| DisplayTree::leaf("program edition", deployment.program_edition())),
+                        )
+                        .with_child(DisplayTree::leaf("functions", deployment.verifying_keys().len())),
+                )
+                .with_child(fee_tree(fee)?)),
+            Self::Execute(_, execution, fee) => {
+                let tree = tree.with_child(DisplayTree::leaf("type", "execute")).with_child(
+                    DisplayTree::new("execution")
+                        .with_child(DisplayTree::leaf("global state root", execution.global_state_root()))
+                        .with_child(proof_size_tree(execution.proof())?)
+                        .with_children(execution.transitions().map(transition_tree)),
+                );
+                match fee {
+                    Some(fee) => Ok(tree.with_child(fee_tree(fee)?)),
+                    None => Ok(tree),
+                }
+            }
+            Self::Fee(_, fee) => Ok(tree.with_child(DisplayTree::leaf("type", "fee")).with_child(fee_tree(fee)?)),
+        }
+    }
+}
+
+/// Returns the tree for the given fee.
+fn fee_tree<N: Network>(fee: &Fee<N>) -> Result<DisplayTree> {
+    let tree = DisplayTree::leaf("fee", format!("{} microcredits", *fee.amount()?))
+        .with_child(DisplayTree::leaf("base", format!("{} microcredits", *fee.base_amount()?)))
+        .with_child(DisplayTree::leaf("priority", format!("{} microcredits", *fee.priority_amount()?)));
+    let tree = match fee.payer() {
+        Some(payer) => tree.with_child(DisplayTree::leaf("payer", payer)),
+        None => tree,
+    };
+    Ok(tree
+        .with_child(DisplayTree::leaf("global state root", fee.global_state_root()))
+        .with_child(proof_size_tree(fee.proof())?)
+        .with_child(transition_tree(fee.transition())))
+}
+
+/// Returns the tree for the size of the given proof.
+fn proof_size_tree<N: Network>(proof: Option<&Proof<N>>) -> Result<DisplayTree> {
+    match proof {
+        Some(proof) => Ok(DisplayTree::leaf("proof size", format!("{} bytes", proof.to_bytes_le()?.len()))),
+        None => Ok(DisplayTree::leaf("proof size", "none")),
+    }
+}
+
+/// Returns the tree for the given transition.
+fn transition_tree<N: Network>(transition: &Transition<N>) -> DisplayTree {
+    DisplayTree::leaf("transition", transition.id())
+        .with_child(DisplayTree::leaf("program", transition.program_id()))
+        .with_child(DisplayTree::leaf("function", transition.function_name()))
+        .with_child(DisplayTree::new("inputs").with_children(transition.inputs().iter().map(input_tree)))
+        .with_child(DisplayTree::new("outputs").with_children(transition.outputs().iter().map(output_tree)))
+}
+
+/// Returns the tree for the given input, labeled by its visibility.
+fn input_tree<N: Network>(input: &Input<N>) -> DisplayTree {
+    match input {
+        Input::Constant(id, plaintext) => value_tree("constant", id, plaintext.as_ref()),
+        Input::Public(id, plaintext) => value_tree("public", id, plaintext.as_ref()),
+        Input::Private(id, ciphertext) => value_tree("private", id, ciphertext.as_ref().map(|_| "(encrypted)")),
+        Input::Record(serial_number, tag) => DisplayTree::leaf("record", serial_number)
+            .with_child(DisplayTree::leaf("serial number", serial_number))
+            .with_child(DisplayTree::leaf("tag", tag)),
+        Input::ExternalRecord(id) => DisplayTree::leaf("external record", id),
+    }
+}
+
+/// Returns the tree for the given output, labeled by its visibility.
+fn output_tree<N: Network>(output: &Output<N>) -> DisplayTree {
+    match output {
+        Output::Constant(id, plaintext) => value_tree("constant", id, plaintext.as_ref()),
+        Output::Public(id, plaintext) => value_tree("public", id, plaintext.as_ref()),
+        Output::Private(id, ciphertext) => value_tree("private", id, ciphertext.as_ref().map(|_| "(encrypted)")),
+        Output::Record(commitment, checksum, _) => DisplayTree::leaf("record", commitment)
+            .with_child(DisplayTree::leaf("commitment", commitment))
+            .with_child(DisplayTree::leaf("checksum", checksum)),
+        Output::ExternalRecord(id) => DisplayTree::leaf("external record", id),
+        Output::Future(id, future) => value_tree("future", id, future.as_ref()),
+    }
+}
+
+/// Returns the tree for a value with the given visibility and ID, which shows the value if it is present.
+fn value_tree(visibility: &str, id: impl Display, value: Option<impl Display>) -> DisplayTree {
+    let tree = DisplayTree::leaf(visibility, &id).with_child(DisplayTree::leaf("id", id));
+    match value {
+        Some(value) => tree.with_child(DisplayTree::leaf("value", value)),
+        None => tree,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_to_display_tree() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let tree = transaction.to_display_tree().unwrap();
+            assert_eq!(tree.value(), Some(transaction.id().to_string().as_str()));
+
+            // Ensure each transition is shown.
+            let text = tree.to_string();
+            for transition in transaction.transitions() {
+                assert!(text.contains(&transition.id().to_string()));
+                assert!(text.contains(&format!("function: {}", transition.function_name())));
+            }
+
+            // Ensure the JSON renderer preserves the root.
+            let json = tree.to_json();
+            assert_eq!(json["label"], "transaction");
+            assert_eq!(json["value"], transaction.id().to_string());
+        }
+
+        // Ensure the transaction type is as expected.
+        let transaction: Transaction<CurrentNetwork> =
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng);
+        let tree = transaction.to_display_tree().unwrap();
+        assert_eq!(tree.children()[0], DisplayTree::leaf("type", "fee"));
+    }
+}
@@ -29,6 +29,10 @@ impl<N: Network> FromBytes for Execution<N> {
         if num_transitions == 0 {
             return Err(error("Execution (from 'read_le') has no transitions"));
         }
+        // Ensure the number of transitions is within bounds (holding back 1 for the fee).
+        if num_transitions as usize >= N::MAX_TRANSITIONS {
+            return Err(error(format!("Execution (from 'read_le') has too many transitions ({num_transitions})")));
+        }
         // Read the transitions.
         let transitions =
             (0..num_transitions).map(|_| Transition::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
@@ -39,7 +43,7 @@ impl<N: Network> FromBytes for Execution<N> {
         // Read the proof.
         let proof = match proof_variant {
             0 => None,
-            1 => Some(Proof::read_le(&mut reader)?),
+            1 => Some(Proof::read_le((&mut reader).take(N::MAX_PROOF_SIZE_IN_BYTES as u64))?),
             _ => return Err(error(format!("Invalid proof variant '{proof_variant}'"))),
         };
         // Return the new `Execution` instance.
@@ -92,4 +96,17 @@ mod tests {
         assert!(Execution::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
         Ok(())
     }
+
+    #[test]
+    fn test_bytes_too_many_transitions() {
+        // Ensure the network limit matches the capacity of the transaction tree.
+        assert_eq!(CurrentNetwork::MAX_TRANSITIONS, Transaction::<CurrentNetwork>::MAX_TRANSITIONS);
+
+        // Construct the bytes of an execution that claims too many transitions.
+        let num_transitions = u8::try_from(CurrentNetwork::MAX_TRANSITIONS).unwrap();
+        let bytes = [1u8, num_transitions];
+        // Ensure the execution is rejected before any transitions are read.
+        let error = Execution::<CurrentNetwork>::read_le(&bytes[..]).unwrap_err();
+        assert!(error.to_string().contains("too many transitions"));
+    }
 }
@@ -48,6 +48,12 @@ impl<N: Network> Execution<N> {
         let execution = Self { transitions: transitions.map(|t| (*t.id(), t)).collect(), global_state_root, proof };
         // Ensure the transitions are not empty.
         ensure!(!execution.transitions.is_empty(), "Execution cannot initialize from empty list of transitions");
+        // Ensure the number of transitions is within bounds (holding back 1 for the fee).
+        ensure!(
+            execution.transitions.len() < N::MAX_TRANSITIONS,
+            "Execution cannot initialize from more than {} transitions",
+            N::MAX_TRANSITIONS - 1
+        );
         // Return the new `Execution` instance.
         Ok(execution)
     }
@@ -137,10 +143,32 @@ impl<N: Network> Execution<N> {
         self.transitions.values()
     }
 
+    /// Returns an iterator over the serial numbers, for the input records.
+    pub fn serial_numbers(&self) -> impl '_ + Iterator<Item = &Field<N>> {
+        self.transitions.values().flat_map(Transition::serial_numbers)
+    }
+
     /// Returns an iterator over the commitments.
     pub fn commitments(&self) -> impl '_ + Iterator<Item = &Field<N>> {
         self.transitions.values().flat_map(Transition::commitments)
     }
+
+    /// Returns the idempotency key, which is the hash of the serial numbers of the input records.
+    ///
+    /// Executions that spend the same records share an idempotency key, even if they were proven separately.
+    /// Only one of them can be confirmed, so a service rebroadcasting an execution can use the key to detect
+    /// that it was already submitted, instead of waiting for a double-spend rejection.
+    ///
+    /// Returns `None` if the execution does not spend any records, as it is not idempotent.
+    pub fn idempotency_key(&self) -> Result<Option<Field<N>>> {
+        // Retrieve the serial numbers.
+        let serial_numbers = self.serial_numbers().copied().collect::<Vec<_>>();
+        // Hash the serial numbers, if there are any.
+        match serial_numbers.is_empty() {
+            true => Ok(None),
+            false => Ok(Some(N::hash_psd8(&serial_numbers)?)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -159,3 +187,36 @@ pub mod test_helpers {
         if let Transaction::Execute(_, execution, _) = transaction { execution } else { unreachable!() }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_idempotency_key() {
+        let rng = &mut TestRng::default();
+
+        // Ensure an execution without input records has no idempotency key.
+        let execution = test_helpers::sample_execution(rng);
+        assert!(execution.serial_numbers().next().is_none());
+        assert!(execution.idempotency_key().unwrap().is_none());
+
+        // Construct an execution, which spends a record.
+        let fee = crate::transaction::fee::test_helpers::sample_fee_private_hardcoded(rng);
+        let execution =
+            Execution::from(std::iter::once(fee.transition().clone()), execution.global_state_root(), None).unwrap();
+        let serial_numbers = execution.serial_numbers().copied().collect::<Vec<_>>();
+        assert!(!serial_numbers.is_empty());
+
+        // Ensure the idempotency key is the hash of the serial numbers.
+        let idempotency_key = execution.idempotency_key().unwrap();
+        assert_eq!(idempotency_key, Some(CurrentNetwork::hash_psd8(&serial_numbers).unwrap()));
+
+        // Ensure the idempotency key does not depend on the proof or global state root.
+        let rebroadcast =
+            Execution::from(execution.transitions().cloned(), Default::default(), execution.proof().cloned()).unwrap();
+        assert_eq!(idempotency_key, rebroadcast.idempotency_key().unwrap());
+    }
+}
@@ -32,7 +32,7 @@ impl<N: Network> FromBytes for Fee<N> {
         // Read the proof.
         let proof = match proof_variant {
             0 => None,
-            1 => Some(Proof::read_le(&mut reader)?),
+            1 => Some(Proof::read_le((&mut reader).take(N::MAX_PROOF_SIZE_IN_BYTES as u64))?),
             _ => return Err(error(format!("Invalid proof variant '{proof_variant}'"))),
         };
         // Return the new `Fee` instance.
@@ -87,6 +87,11 @@ impl<N: Network> Fee<N> {
     }
 
     /// Returns the amount (in microcredits).
+    ///
+    /// The base and priority fees are public inputs of `credits.aleo/fee_private` and `fee_public`,
+    /// as validators must check the base fee against the deterministic cost of the transaction and
+    /// order transactions by their priority fee. The base fee is therefore derivable from the
+    /// transaction itself, so only the priority fee reveals a choice made by the sender.
     pub fn amount(&self) -> Result<U64<N>> {
         // Retrieve the base fee amount.
         let base_fee_amount = self.base_amount()?;
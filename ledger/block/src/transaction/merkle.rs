@@ -109,12 +109,15 @@ impl<N: Network> Transaction<N> {
         let program = deployment.program();
         // Prepare the leaves.
         let leaves = program.functions().values().enumerate().map(|(index, function)| {
+            // Hash the function, committing to the program edition for upgrades, so initial deployments keep their ID.
+            let function_hash = match deployment.is_upgrade() {
+                true => {
+                    N::hash_bhp1024(&to_bits_le![program.id(), deployment.program_edition(), function.to_bytes_le()?])?
+                }
+                false => N::hash_bhp1024(&to_bits_le![program.id(), function.to_bytes_le()?])?,
+            };
             // Construct the transaction leaf.
-            Ok(TransactionLeaf::new_deployment(
-                u16::try_from(index)?,
-                N::hash_bhp1024(&to_bits_le![program.id(), function.to_bytes_le()?])?,
-            )
-            .to_bits_le())
+            Ok(TransactionLeaf::new_deployment(u16::try_from(index)?, function_hash).to_bits_le())
         });
         // If the fee is present, add it to the leaves.
         let leaves = match fee {
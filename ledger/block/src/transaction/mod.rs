@@ -22,7 +22,10 @@ mod fee;
 pub use fee::*;
 
 mod bytes;
+mod display_tree;
 mod merkle;
+mod non_membership;
+mod packed;
 mod serialize;
 mod string;
 
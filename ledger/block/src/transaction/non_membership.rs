@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::NonMembershipPath;
+
+impl<N: Network> Transaction<N> {
+    /// Checks that none of the serial numbers in the transaction are contained in the serial numbers tree
+    /// with the given root, using one non-membership path per serial number (in order).
+    ///
+    /// This allows a validator to check the transaction does not double spend, without access to the ledger.
+    pub fn verify_non_membership(&self, serial_numbers_root: &Field<N>, paths: &[NonMembershipPath<N>]) -> Result<()> {
+        // Retrieve the serial numbers.
+        let serial_numbers = self.serial_numbers().collect::<Vec<_>>();
+        // Ensure there is one path per serial number.
+        ensure!(
+            serial_numbers.len() == paths.len(),
+            "Transaction '{}' contains {} serial numbers, but found {} non-membership paths",
+            self.id(),
+            serial_numbers.len(),
+            paths.len()
+        );
+        // Ensure each serial number is not in the serial numbers tree.
+        for (serial_number, path) in serial_numbers.into_iter().zip_eq(paths) {
+            path.verify(serial_numbers_root, serial_number)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use synthesizer_snark::Proof;
+
+impl<N: Network> Transaction<N> {
+    /// Returns the packed byte representation of the transaction.
+    ///
+    /// In the packed representation, the proofs of an execute transaction are stored together in a
+    /// columnar layout, which shares identical commitments between the proofs. Other transactions
+    /// are written in their standard byte representation. Both representations are accepted by `read_le`.
+    pub fn to_packed_bytes_le(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write_packed_le(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Writes the transaction to the buffer, in the packed representation.
+    pub fn write_packed_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Execute(id, execution, fee) => {
+                // Write the version.
+                2u8.write_le(&mut writer)?;
+                // Write the variant.
+                1u8.write_le(&mut writer)?;
+                // Write the ID.
+                id.write_le(&mut writer)?;
+
+                // Write the number of transitions.
+                (u8::try_from(execution.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+                // Write the transitions.
+                for transition in execution.transitions() {
+                    transition.write_le(&mut writer)?;
+                }
+                // Write the global state root.
+                execution.global_state_root().write_le(&mut writer)?;
+
+                // Write the fee, without its proof.
+                match fee {
+                    None => 0u8.write_le(&mut writer)?,
+                    Some(fee) => {
+                        1u8.write_le(&mut writer)?;
+                        fee.transition().write_le(&mut writer)?;
+                        fee.global_state_root().write_le(&mut writer)?;
+                    }
+                }
+
+                // Write the proof variant, where the first bit is set if there is an execution proof,
+                // and the second bit is set if there is a fee proof.
+                let execution_proof = execution.proof();
+                let fee_proof = fee.as_ref().and_then(|fee| fee.proof());
+                let proof_variant = u8::from(execution_proof.is_some()) | (u8::from(fee_proof.is_some()) << 1);
+                proof_variant.write_le(&mut writer)?;
+                // Write the proofs.
+                let proofs = execution_proof.into_iter().chain(fee_proof).collect::<Vec<_>>();
+                Proof::write_packed_le(&proofs, &mut writer)
+            }
+            _ => self.write_le(&mut writer),
+        }
+    }
+
+    /// Reads an execute transaction from the buffer, in the packed representation.
+    /// This method assumes the version has already been read.
+    pub(super) fn read_packed_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the variant.
+        let variant = u8::read_le(&mut reader)?;
+        // Ensure the variant is an execute transaction.
+        if variant != 1 {
+            return Err(error("Invalid packed transaction variant"));
+        }
+        // Read the ID.
+        let id = N::TransactionID::read_le(&mut reader)?;
+
+        // Read the number of transitions.
+        let num_transitions = u8::read_le(&mut reader)?;
+        // Read the transitions.
+        let transitions =
+            (0..num_transitions).map(|_| Transition::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the global state root.
+        let global_state_root = N::StateRoot::read_le(&mut reader)?;
+
+        // Read the fee variant.
+        let fee_variant = u8::read_le(&mut reader)?;
+        // Read the fee, without its proof.
+        let fee = match fee_variant {
+            0u8 => None,
+            1u8 => Some((Transition::read_le(&mut reader)?, N::StateRoot::read_le(&mut reader)?)),
+            _ => return Err(error("Invalid fee variant")),
+        };
+
+        // Read the proof variant.
+        let proof_variant = u8::read_le(&mut reader)?;
+        // Ensure the proof variant is valid.
+        if proof_variant > 3 || (fee.is_none() && proof_variant & 2 != 0) {
+            return Err(error(format!("Invalid proof variant '{proof_variant}'")));
+        }
+        // Read the proofs.
+        let mut proofs = Proof::read_packed_le(&mut reader)?.into_iter();
+        // Ensure the number of proofs matches the proof variant.
+        if proofs.len() != proof_variant.count_ones() as usize {
+            return Err(error("Mismatching number of packed proofs"));
+        }
+        let execution_proof = if proof_variant & 1 != 0 { proofs.next() } else { None };
+        let fee_proof = if proof_variant & 2 != 0 { proofs.next() } else { None };
+
+        // Initialize the execution.
+        let execution = Execution::from(transitions.into_iter(), global_state_root, execution_proof)
+            .map_err(|e| error(e.to_string()))?;
+        // Initialize the fee.
+        let fee = match fee {
+            Some((transition, global_state_root)) => {
+                Some(Fee::from(transition, global_state_root, fee_proof).map_err(|e| error(e.to_string()))?)
+            }
+            None => None,
+        };
+        // Initialize the transaction.
+        let transaction = Self::from_execution(execution, fee).map_err(|e| error(e.to_string()))?;
+
+        // Ensure the transaction ID matches.
+        match transaction.id() == id {
+            // Return the transaction.
+            true => Ok(transaction),
+            false => Err(error("Transaction ID mismatch")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_packed_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+        ]
+        .into_iter()
+        {
+            // Check the packed byte representation.
+            let expected_bytes = expected.to_packed_bytes_le()?;
+            assert_eq!(expected, Transaction::read_le(&expected_bytes[..])?);
+            assert!(Transaction::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
+            // Ensure the packed byte representation is no larger than the standard one.
+            assert!(expected_bytes.len() <= expected.to_bytes_le()?.len());
+        }
+        Ok(())
+    }
+}
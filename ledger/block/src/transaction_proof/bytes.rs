@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for TransactionProof<N> {
+    /// Reads the transaction proof from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid transaction proof version"));
+        }
+
+        // Read the transaction proof.
+        let block_hash = N::BlockHash::read_le(&mut reader)?;
+        let previous_block_hash = N::BlockHash::read_le(&mut reader)?;
+        let header_root = Field::read_le(&mut reader)?;
+        let header_path = HeaderPath::read_le(&mut reader)?;
+        let header_leaf = HeaderLeaf::read_le(&mut reader)?;
+        let transactions_path = TransactionsPath::read_le(&mut reader)?;
+        let transaction_id = N::TransactionID::read_le(&mut reader)?;
+
+        // Return the transaction proof.
+        Ok(Self::new(
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction_id,
+        ))
+    }
+}
+
+impl<N: Network> ToBytes for TransactionProof<N> {
+    /// Writes the transaction proof to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the transaction proof.
+        self.block_hash.write_le(&mut writer)?;
+        self.previous_block_hash.write_le(&mut writer)?;
+        self.header_root.write_le(&mut writer)?;
+        self.header_path.write_le(&mut writer)?;
+        self.header_leaf.write_le(&mut writer)?;
+        self.transactions_path.write_le(&mut writer)?;
+        self.transaction_id.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transaction proof.
+        let expected = test_helpers::sample_transaction_proof(rng);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected, TransactionProof::read_le(&expected_bytes[..]).unwrap());
+    }
+}
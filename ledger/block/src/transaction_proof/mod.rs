@@ -0,0 +1,221 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use super::*;
+
+use console::program::{HeaderLeaf, HeaderPath, TransactionsPath};
+
+/// A proof that a transaction is included in a block, which is verified against the block hash
+/// without the block body.
+///
+/// # Diagram
+/// ```ignore
+///     block_hash := Hash( previous_block_hash || header_root )
+///                                                     |
+///                                                header_path
+///                                                    |
+///                                               header_leaf := (1, transactions_root)
+///                                                   |
+///                                            transactions_path
+///                                                  |
+///                                           transaction_id
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct TransactionProof<N: Network> {
+    /// The hash of the block.
+    block_hash: N::BlockHash,
+    /// The hash of the previous block.
+    previous_block_hash: N::BlockHash,
+    /// The root of the block header.
+    header_root: Field<N>,
+    /// The Merkle path from the header leaf to the header root.
+    header_path: HeaderPath<N>,
+    /// The header leaf of the transactions root.
+    header_leaf: HeaderLeaf<N>,
+    /// The Merkle path from the transaction ID to the transactions root.
+    transactions_path: TransactionsPath<N>,
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+}
+
+impl<N: Network> TransactionProof<N> {
+    /// Initializes a new transaction proof.
+    pub const fn new(
+        block_hash: N::BlockHash,
+        previous_block_hash: N::BlockHash,
+        header_root: Field<N>,
+        header_path: HeaderPath<N>,
+        header_leaf: HeaderLeaf<N>,
+        transactions_path: TransactionsPath<N>,
+        transaction_id: N::TransactionID,
+    ) -> Self {
+        Self {
+            block_hash,
+            previous_block_hash,
+            header_root,
+            header_path,
+            header_leaf,
+            transactions_path,
+            transaction_id,
+        }
+    }
+
+    /// Returns the hash of the block.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the hash of the previous block.
+    pub const fn previous_block_hash(&self) -> N::BlockHash {
+        self.previous_block_hash
+    }
+
+    /// Returns the root of the block header.
+    pub const fn header_root(&self) -> &Field<N> {
+        &self.header_root
+    }
+
+    /// Returns the Merkle path from the header leaf to the header root.
+    pub const fn header_path(&self) -> &HeaderPath<N> {
+        &self.header_path
+    }
+
+    /// Returns the header leaf of the transactions root.
+    pub const fn header_leaf(&self) -> &HeaderLeaf<N> {
+        &self.header_leaf
+    }
+
+    /// Returns the Merkle path from the transaction ID to the transactions root.
+    pub const fn transactions_path(&self) -> &TransactionsPath<N> {
+        &self.transactions_path
+    }
+
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> N::TransactionID {
+        self.transaction_id
+    }
+
+    /// Checks the transaction is included in the block with the given block hash.
+    pub fn verify(&self, block_hash: N::BlockHash) -> Result<()> {
+        // Ensure the proof is for the given block.
+        ensure!(self.block_hash == block_hash, "The proof is for block '{}', not '{block_hash}'", self.block_hash);
+        // Ensure the header leaf index is 1 (Header::transactions_root).
+        ensure!(self.header_leaf.index() == 1, "Header leaf index must be 1 (Header::transactions_root)");
+        // Ensure the transactions path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(
+                &self.transactions_path,
+                &self.header_leaf.id(),
+                &self.transaction_id.to_bits_le()
+            ),
+            "Transaction '{}' does not belong to '{}' (a header leaf)",
+            self.transaction_id,
+            self.header_leaf
+        );
+        // Ensure the header path is valid.
+        ensure!(
+            N::verify_merkle_path_bhp(&self.header_path, &self.header_root, &self.header_leaf.to_bits_le()),
+            "'{}' (a header leaf) does not belong to '{}' (a block header)",
+            self.header_leaf,
+            self.block_hash
+        );
+        // Ensure the block hash is correct.
+        ensure!(
+            *self.block_hash == N::hash_bhp1024(&to_bits_le![(*self.previous_block_hash), self.header_root])?,
+            "Block hash '{}' is incorrect. Double-check the previous block hash and block header root.",
+            self.block_hash
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> Block<N> {
+    /// Returns a proof that the given transaction is included in this block.
+    pub fn prove_transaction(&self, transaction_id: &N::TransactionID) -> Result<TransactionProof<N>> {
+        // Compute the Merkle path of the transaction in the transactions tree.
+        let transactions_path = self.transactions.to_path(*transaction_id)?;
+        // Compute the header leaf of the transactions root, and its Merkle path in the header tree.
+        let header_leaf = HeaderLeaf::new(1, self.header.transactions_root());
+        let header_path = self.header.to_path(&header_leaf)?;
+        // Return the transaction proof.
+        Ok(TransactionProof::new(
+            self.block_hash,
+            self.previous_hash,
+            self.header.to_root()?,
+            header_path,
+            header_leaf,
+            transactions_path,
+            *transaction_id,
+        ))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a transaction proof for the transaction in the genesis block.
+    pub(crate) fn sample_transaction_proof(rng: &mut TestRng) -> TransactionProof<CurrentNetwork> {
+        let (block, transaction) = crate::test_helpers::sample_genesis_block_and_transaction(rng);
+        block.prove_transaction(&transaction.id()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_prove_transaction() {
+        let rng = &mut TestRng::default();
+
+        // Prove each transaction in the genesis block.
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        for transaction_id in block.transaction_ids() {
+            let proof = block.prove_transaction(transaction_id).unwrap();
+            assert_eq!(proof.transaction_id(), *transaction_id);
+            // Ensure the proof verifies against the block hash.
+            proof.verify(block.hash()).unwrap();
+            // Ensure the proof does not verify against another block hash.
+            assert!(proof.verify(block.previous_hash()).is_err());
+        }
+
+        // Ensure a transaction that is not in the block cannot be proven.
+        let transaction_id = <CurrentNetwork as Network>::TransactionID::from(Field::rand(rng));
+        assert!(block.prove_transaction(&transaction_id).is_err());
+
+        // Ensure a proof for a different transaction ID does not verify.
+        let proof = test_helpers::sample_transaction_proof(rng);
+        let forged = TransactionProof::new(
+            proof.block_hash(),
+            proof.previous_block_hash(),
+            *proof.header_root(),
+            proof.header_path().clone(),
+            *proof.header_leaf(),
+            proof.transactions_path().clone(),
+            transaction_id,
+        );
+        assert!(forged.verify(proof.block_hash()).is_err());
+    }
+}
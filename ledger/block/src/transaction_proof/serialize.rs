@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Serialize for TransactionProof<N> {
+    /// Serializes the transaction proof into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut proof = serializer.serialize_struct("TransactionProof", 7)?;
+                proof.serialize_field("block_hash", &self.block_hash)?;
+                proof.serialize_field("previous_block_hash", &self.previous_block_hash)?;
+                proof.serialize_field("header_root", &self.header_root)?;
+                proof.serialize_field("header_path", &self.header_path)?;
+                proof.serialize_field("header_leaf", &self.header_leaf)?;
+                proof.serialize_field("transactions_path", &self.transactions_path)?;
+                proof.serialize_field("transaction_id", &self.transaction_id)?;
+                proof.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for TransactionProof<N> {
+    /// Deserializes the transaction proof from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                // Parse the transaction proof from a string into a value.
+                let mut proof = serde_json::Value::deserialize(deserializer)?;
+                // Recover the transaction proof.
+                Ok(Self::new(
+                    DeserializeExt::take_from_value::<D>(&mut proof, "block_hash")?,
+                    DeserializeExt::take_from_value::<D>(&mut proof, "previous_block_hash")?,
+                    DeserializeExt::take_from_value::<D>(&mut proof, "header_root")?,
+                    DeserializeExt::take_from_value::<D>(&mut proof, "header_path")?,
+                    DeserializeExt::take_from_value::<D>(&mut proof, "header_leaf")?,
+                    DeserializeExt::take_from_value::<D>(&mut proof, "transactions_path")?,
+                    DeserializeExt::take_from_value::<D>(&mut proof, "transaction_id")?,
+                ))
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "transaction proof"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transaction proof.
+        let expected = test_helpers::sample_transaction_proof(rng);
+
+        // Serialize
+        let expected_string = &expected.to_string();
+        let candidate_string = serde_json::to_string(&expected).unwrap();
+        assert_eq!(expected_string, &candidate_string);
+
+        // Deserialize
+        assert_eq!(expected, TransactionProof::from_str(expected_string).unwrap());
+        assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+    }
+
+    #[test]
+    fn test_bincode() {
+        let rng = &mut TestRng::default();
+
+        // Sample the transaction proof.
+        let expected = test_helpers::sample_transaction_proof(rng);
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected).unwrap();
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, TransactionProof::read_le(&expected_bytes[..]).unwrap());
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..]).unwrap());
+    }
+}
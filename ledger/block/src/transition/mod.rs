@@ -27,6 +27,7 @@ use console::{
     network::prelude::*,
     program::{
         Ciphertext,
+        Expiry,
         Identifier,
         InputID,
         OutputID,
@@ -61,6 +62,8 @@ pub struct Transition<N: Network> {
     tpk: Group<N>,
     /// The transition commitment.
     tcm: Field<N>,
+    /// The maximum block height, if the caller bound the transition to one.
+    expiry: Option<Expiry<N>>,
 }
 
 impl<N: Network> Transition<N> {
@@ -73,12 +76,38 @@ impl<N: Network> Transition<N> {
         outputs: Vec<Output<N>>,
         tpk: Group<N>,
         tcm: Field<N>,
+        expiry: Option<Expiry<N>>,
     ) -> Result<Self> {
+        // Ensure the maximum height (if any) is proven for the transition.
+        if let Some(expiry) = &expiry {
+            ensure!(expiry.verify(&tpk, &tcm), "Invalid maximum height for the transition");
+        }
         // Compute the transition ID.
         let function_tree = Self::function_tree(&inputs, &outputs)?;
-        let id = N::hash_bhp512(&(*function_tree.root(), tcm).to_bits_le())?;
+        let id = Self::compute_id(*function_tree.root(), tcm, expiry.as_ref())?;
         // Return the transition.
-        Ok(Self { id: id.into(), program_id, function_name, inputs, outputs, tpk, tcm })
+        Ok(Self { id, program_id, function_name, inputs, outputs, tpk, tcm, expiry })
+    }
+
+    /// Returns the transition ID for the given transition root, transition commitment, and maximum height (if any),
+    /// as `Hash(transition_root || bound_tcm)`, where the maximum height is bound to `tcm` (see `to_bound_tcm`).
+    pub fn compute_id(transition_root: Field<N>, tcm: Field<N>, expiry: Option<&Expiry<N>>) -> Result<N::TransitionID> {
+        let bound_tcm = Self::bind_tcm(tcm, expiry)?;
+        Ok(N::hash_bhp512(&(transition_root, bound_tcm).to_bits_le())?.into())
+    }
+
+    /// Returns the transition commitment that is hashed into the transition ID, which is `Hash(tcm || max_height)`
+    /// if the transition has a maximum height, and `tcm` otherwise. State paths to the outputs use this commitment.
+    pub fn to_bound_tcm(&self) -> Result<Field<N>> {
+        Self::bind_tcm(self.tcm, self.expiry.as_ref())
+    }
+
+    /// Binds the maximum height (if any) to the given transition commitment.
+    fn bind_tcm(tcm: Field<N>, expiry: Option<&Expiry<N>>) -> Result<Field<N>> {
+        match expiry {
+            Some(expiry) => N::hash_psd2(&[tcm, Field::from_u32(expiry.max_height())]),
+            None => Ok(tcm),
+        }
     }
 
     /// Initializes a new transition from a request and response.
@@ -256,7 +285,7 @@ impl<N: Network> Transition<N> {
         // Retrieve the `tcm`.
         let tcm = *request.tcm();
         // Return the transition.
-        Self::new(program_id, function_name, inputs, outputs, tpk, tcm)
+        Self::new(program_id, function_name, inputs, outputs, tpk, tcm, request.expiry().copied())
     }
 }
 
@@ -295,6 +324,11 @@ impl<N: Network> Transition<N> {
     pub const fn tcm(&self) -> &Field<N> {
         &self.tcm
     }
+
+    /// Returns the maximum block height, if the caller bound the transition to one.
+    pub const fn expiry(&self) -> Option<&Expiry<N>> {
+        self.expiry.as_ref()
+    }
 }
 
 impl<N: Network> Transition<N> {
@@ -19,7 +19,7 @@ impl<N: Network> Serialize for Transition<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut transition = serializer.serialize_struct("Transition", 7)?;
+                let mut transition = serializer.serialize_struct("Transition", 7 + self.expiry.is_some() as usize)?;
                 transition.serialize_field("id", &self.id)?;
                 transition.serialize_field("program", &self.program_id)?;
                 transition.serialize_field("function", &self.function_name)?;
@@ -27,6 +27,9 @@ impl<N: Network> Serialize for Transition<N> {
                 transition.serialize_field("outputs", &self.outputs)?;
                 transition.serialize_field("tpk", &self.tpk)?;
                 transition.serialize_field("tcm", &self.tcm)?;
+                if let Some(expiry) = &self.expiry {
+                    transition.serialize_field("expiry", expiry)?;
+                }
                 transition.end()
             }
             false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
@@ -43,6 +46,10 @@ impl<'de, N: Network> Deserialize<'de> for Transition<N> {
                 let mut transition = serde_json::Value::deserialize(deserializer)?;
                 // Retrieve the ID.
                 let id: N::TransitionID = DeserializeExt::take_from_value::<D>(&mut transition, "id")?;
+                // Retrieve the expiry.
+                let expiry =
+                    serde_json::from_value(transition.get_mut("expiry").unwrap_or(&mut serde_json::Value::Null).take())
+                        .map_err(de::Error::custom)?;
 
                 // Recover the transition.
                 let transition = Self::new(
@@ -58,6 +65,8 @@ impl<'de, N: Network> Deserialize<'de> for Transition<N> {
                     DeserializeExt::take_from_value::<D>(&mut transition, "tpk")?,
                     // Retrieve the `tcm`.
                     DeserializeExt::take_from_value::<D>(&mut transition, "tcm")?,
+                    // Retrieve the expiry.
+                    expiry,
                 )
                 .map_err(de::Error::custom)?;
 
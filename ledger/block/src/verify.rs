@@ -97,6 +97,35 @@ impl<N: Network> Block<N> {
 }
 
 impl<N: Network> Block<N> {
+    /// Ensures the block body matches the roots in the block header.
+    /// Note: The finalize root is not checked, as it requires the finalize operations of the ratifications.
+    pub fn verify_body(&self) -> Result<()> {
+        // Retrieve the block height.
+        let height = self.height();
+
+        // Ensure the block transactions are correct.
+        self.verify_transactions()?;
+
+        // Ensure the transactions root is correct.
+        ensure!(
+            self.header.transactions_root() == self.compute_transactions_root()?,
+            "Transactions root is incorrect in block {height}"
+        );
+        // Ensure the ratifications root is correct.
+        ensure!(
+            self.header.ratifications_root() == self.compute_ratifications_root()?,
+            "Ratifications root is incorrect in block {height}"
+        );
+        // Ensure the solutions root is correct.
+        ensure!(
+            self.header.solutions_root() == self.compute_solutions_root()?,
+            "Solutions root is incorrect in block {height}"
+        );
+        // Ensure the subdag root is correct.
+        ensure!(self.header.subdag_root() == self.compute_subdag_root()?, "Subdag root is incorrect in block {height}");
+        Ok(())
+    }
+
     /// Ensures the block hash is correct.
     fn verify_hash(&self, previous_height: u32, previous_hash: N::BlockHash) -> Result<(), Error> {
         // Determine the expected height.
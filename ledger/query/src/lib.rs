@@ -18,10 +18,30 @@
 #[cfg_attr(feature = "async", macro_use)]
 extern crate async_trait;
 
+#[cfg(feature = "query")]
+mod http;
+#[cfg(feature = "query")]
+pub use http::*;
+
 #[cfg(feature = "query")]
 mod query;
 #[cfg(feature = "query")]
 pub use query::*;
 
+#[cfg(feature = "query")]
+mod subscription;
+#[cfg(feature = "query")]
+pub use subscription::*;
+
+#[cfg(feature = "query")]
+mod wallet;
+#[cfg(feature = "query")]
+pub use wallet::*;
+
+#[cfg(feature = "query")]
+mod watch_only;
+#[cfg(feature = "query")]
+pub use watch_only::*;
+
 mod traits;
 pub use traits::*;
@@ -18,6 +18,7 @@ use console::{
     program::{ProgramID, StatePath},
     types::Field,
 };
+use ledger_block::Block;
 use ledger_store::{BlockStorage, BlockStore};
 use synthesizer_program::Program;
 
@@ -138,9 +139,38 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
         }
     }
 
+    /// Returns the latest block height.
+    pub fn get_latest_height(&self) -> Result<u32> {
+        match self {
+            Self::VM(block_store) => {
+                block_store.current_block_height().ok_or_else(|| anyhow!("No blocks found in storage"))
+            }
+            Self::REST(url) => match N::ID {
+                3 => Ok(Self::get_request(&format!("{url}/testnet3/latest/height"))?.into_json()?),
+                _ => bail!("Unsupported network ID in inclusion query"),
+            },
+        }
+    }
+
+    /// Returns the block for the given block height.
+    pub fn get_block(&self, height: u32) -> Result<Block<N>> {
+        match self {
+            Self::VM(block_store) => {
+                let block_hash = block_store
+                    .get_block_hash(height)?
+                    .ok_or_else(|| anyhow!("Block {height} not found in storage"))?;
+                block_store.get_block(&block_hash)?.ok_or_else(|| anyhow!("Block {height} not found in storage"))
+            }
+            Self::REST(url) => match N::ID {
+                3 => Ok(Self::get_request(&format!("{url}/testnet3/block/{height}"))?.into_json()?),
+                _ => bail!("Unsupported network ID in inclusion query"),
+            },
+        }
+    }
+
     /// Performs a GET request to the given URL.
     fn get_request(url: &str) -> Result<ureq::Response> {
-        let response = ureq::get(url).call()?;
+        let response = crate::http_agent().get(url).call()?;
         if response.status() == 200 { Ok(response) } else { bail!("Failed to fetch from {url}") }
     }
 
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Query;
+use console::{
+    account::ViewKey,
+    network::prelude::*,
+    program::{Plaintext, Record},
+    types::Field,
+};
+use ledger_block::{Block, ConfirmedTransaction};
+use ledger_store::BlockStorage;
+
+use std::time::Duration;
+
+/// A subscriber to ledger events, which are delivered by a [`Subscription`].
+///
+/// Each event has a no-op default, so a subscriber only implements the events it is interested in.
+/// An error returned by a subscriber stops the poll, and the block is delivered again on the next poll.
+pub trait Subscriber<N: Network> {
+    /// Handles a new block.
+    fn on_block(&mut self, _block: &Block<N>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Handles a confirmed transaction, in the block at the given height.
+    fn on_transaction_confirmed(&mut self, _height: u32, _transaction: &ConfirmedTransaction<N>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Handles a record owned by one of the subscribed view keys, in the block at the given height.
+    fn on_record(&mut self, _height: u32, _commitment: &Field<N>, _record: &Record<N, Plaintext<N>>) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A polling subscription to ledger events, from a given block height.
+pub struct Subscription<N: Network, B: BlockStorage<N>> {
+    /// The query.
+    query: Query<N, B>,
+    /// The view keys, for which records are delivered.
+    view_keys: Vec<ViewKey<N>>,
+    /// The height of the next block to deliver.
+    next_height: u32,
+}
+
+impl<N: Network, B: BlockStorage<N>> Subscription<N, B> {
+    /// Initializes a new subscription, starting from the given block height.
+    pub fn new(query: impl Into<Query<N, B>>, start_height: u32) -> Self {
+        Self { query: query.into(), view_keys: Vec::new(), next_height: start_height }
+    }
+
+    /// Adds the given view key, for which records are delivered.
+    pub fn with_view_key(mut self, view_key: ViewKey<N>) -> Self {
+        self.view_keys.push(view_key);
+        self
+    }
+
+    /// Returns the height of the next block to deliver.
+    pub const fn next_height(&self) -> u32 {
+        self.next_height
+    }
+
+    /// Delivers the events for all blocks since the last poll, and returns the number of blocks delivered.
+    pub fn poll(&mut self, subscriber: &mut impl Subscriber<N>) -> Result<u32> {
+        // Retrieve the latest block height.
+        let latest_height = self.query.get_latest_height()?;
+        // Deliver each new block.
+        let start_height = self.next_height;
+        while self.next_height <= latest_height {
+            let block = self.query.get_block(self.next_height)?;
+            self.deliver(&block, subscriber)?;
+            self.next_height = self.next_height.saturating_add(1);
+        }
+        Ok(self.next_height.saturating_sub(start_height))
+    }
+
+    /// Polls for new blocks at the given interval, until `should_stop` returns `true` or an error occurs.
+    pub fn run(
+        &mut self,
+        subscriber: &mut impl Subscriber<N>,
+        interval: Duration,
+        should_stop: impl Fn() -> bool,
+    ) -> Result<()> {
+        while !should_stop() {
+            self.poll(subscriber)?;
+            std::thread::sleep(interval);
+        }
+        Ok(())
+    }
+
+    /// Delivers the events for the given block.
+    fn deliver(&self, block: &Block<N>, subscriber: &mut impl Subscriber<N>) -> Result<()> {
+        subscriber.on_block(block)?;
+        for transaction in block.transactions().iter() {
+            subscriber.on_transaction_confirmed(block.height(), transaction)?;
+            // Deliver each record to the first view key that owns it.
+            for (commitment, record) in transaction.records() {
+                if let Some(view_key) = self.view_keys.iter().find(|view_key| record.is_owner(view_key)) {
+                    subscriber.on_record(block.height(), commitment, &record.decrypt(view_key)?)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
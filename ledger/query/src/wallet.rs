@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Subscriber, Transfer, WalletBalance, WatchOnlyWallet};
+use console::{
+    account::{Address, PrivateKey, ViewKey},
+    network::prelude::*,
+};
+use ledger_block::{Block, Transaction};
+
+use std::collections::HashMap;
+
+/// A wallet of accounts derived by index from a master private key.
+///
+/// Each account is scanned by its own [`WatchOnlyWallet`], so the wallet can hand out a fresh
+/// deposit address per user, and attribute every received record and transfer to its account.
+pub struct Wallet<N: Network> {
+    /// The master private key, from which the accounts are derived.
+    master: PrivateKey<N>,
+    /// The watch-only wallet of each derived account, in order of index.
+    accounts: Vec<WatchOnlyWallet<N>>,
+    /// A map of account addresses to their index.
+    indices: HashMap<Address<N>, u32>,
+}
+
+impl<N: Network> Wallet<N> {
+    /// Initializes a new wallet, with no derived accounts.
+    pub fn new(master: PrivateKey<N>) -> Self {
+        Self { master, accounts: Vec::new(), indices: HashMap::new() }
+    }
+
+    /// Returns the number of derived accounts.
+    pub fn num_accounts(&self) -> u32 {
+        u32::try_from(self.accounts.len()).unwrap_or(u32::MAX)
+    }
+
+    /// Derives the account at the next index, and returns its index and address.
+    /// The account starts scanning at the next height of the wallet, as a freshly derived account holds no records.
+    pub fn derive_account(&mut self) -> Result<(u32, Address<N>)> {
+        let index = u32::try_from(self.accounts.len())?;
+        let view_key = ViewKey::try_from(self.master.derive_child(index)?)?;
+        let account = WatchOnlyWallet::new_at_height(view_key, self.next_height())?;
+        let address = account.address();
+        self.indices.insert(address, index);
+        self.accounts.push(account);
+        Ok((index, address))
+    }
+
+    /// Derives accounts until the wallet has the given number of accounts.
+    pub fn derive_accounts(&mut self, num_accounts: u32) -> Result<()> {
+        while self.num_accounts() < num_accounts {
+            self.derive_account()?;
+        }
+        Ok(())
+    }
+
+    /// Returns the private key of the account at the given index.
+    pub fn private_key(&self, index: u32) -> Result<PrivateKey<N>> {
+        ensure!(index < self.num_accounts(), "The account at index {index} has not been derived");
+        self.master.derive_child(index)
+    }
+
+    /// Returns the watch-only wallet of the account at the given index, if it has been derived.
+    pub fn account(&self, index: u32) -> Option<&WatchOnlyWallet<N>> {
+        self.accounts.get(index as usize)
+    }
+
+    /// Returns an iterator over the watch-only wallets of the derived accounts, in order of index.
+    pub fn accounts(&self) -> impl '_ + ExactSizeIterator<Item = &WatchOnlyWallet<N>> {
+        self.accounts.iter()
+    }
+
+    /// Returns the index of the account with the given address, if it has been derived.
+    pub fn index_of(&self, address: &Address<N>) -> Option<u32> {
+        self.indices.get(address).copied()
+    }
+
+    /// Returns the height of the next block to scan, which is the lowest across the derived accounts.
+    pub fn next_height(&self) -> u32 {
+        self.accounts.iter().map(WatchOnlyWallet::next_height).min().unwrap_or_default()
+    }
+
+    /// Returns the combined balance of the derived accounts.
+    pub fn balance(&self) -> Result<WalletBalance<N>> {
+        let mut balance = WalletBalance::default();
+        for account in &self.accounts {
+            balance.merge(&account.balance()?)?;
+        }
+        Ok(balance)
+    }
+
+    /// Returns the transfers of the derived accounts, with the index of the account of each transfer.
+    pub fn history(&self) -> Result<Vec<(u32, Transfer<N>)>> {
+        let mut history = Vec::new();
+        for (index, account) in (0u32..).zip(&self.accounts) {
+            history.extend(account.history()?.into_iter().map(|transfer| (index, transfer)));
+        }
+        Ok(history)
+    }
+
+    /// Adds the given unconfirmed transaction to each derived account.
+    pub fn add_unconfirmed(&mut self, transaction: Transaction<N>) {
+        for account in &mut self.accounts {
+            account.add_unconfirmed(transaction.clone());
+        }
+    }
+
+    /// Scans the given block for the records received and spent by each derived account.
+    pub fn scan_block(&mut self, block: &Block<N>) -> Result<()> {
+        self.accounts.iter_mut().try_for_each(|account| account.scan_block(block))
+    }
+}
+
+impl<N: Network> Subscriber<N> for Wallet<N> {
+    /// Scans the new block for the records received and spent by each derived account.
+    fn on_block(&mut self, block: &Block<N>) -> Result<()> {
+        self.scan_block(block)
+    }
+}
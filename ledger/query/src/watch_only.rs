@@ -0,0 +1,602 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Subscriber;
+use console::{
+    account::{Address, GraphKey, ViewKey},
+    network::prelude::*,
+    program::{Argument, Entry, Identifier, Literal, Plaintext, ProgramID, Record, RecordBundle},
+    types::{Field, U16},
+};
+use ledger_block::{Block, Input, Output, Transaction, Transition};
+use ledger_store::helpers::KeyValueStore;
+
+use std::collections::HashMap;
+
+/// The key prefix of the wallet state in a key-value store.
+///
+/// The wallet state includes decrypted records, so embedders should encrypt this column family at rest,
+/// for example with `EncryptedKeyValueStore::with_column_families`.
+pub const WALLET_COLUMN_FAMILY: &[u8] = b"wallet/";
+
+/// A record owned by a watch-only wallet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WatchedRecord<N: Network> {
+    /// The program ID of the transition that created the record.
+    program_id: ProgramID<N>,
+    /// The commitment of the record.
+    commitment: Field<N>,
+    /// The tag of the record, which is published when the record is spent.
+    tag: Field<N>,
+    /// The decrypted record.
+    record: Record<N, Plaintext<N>>,
+    /// The height of the block in which the record was created.
+    created_height: u32,
+    /// The height of the block in which the record was spent, if it has been spent.
+    spent_height: Option<u32>,
+}
+
+impl<N: Network> WatchedRecord<N> {
+    /// Returns the program ID of the transition that created the record.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the commitment of the record.
+    pub const fn commitment(&self) -> &Field<N> {
+        &self.commitment
+    }
+
+    /// Returns the tag of the record.
+    pub const fn tag(&self) -> &Field<N> {
+        &self.tag
+    }
+
+    /// Returns the decrypted record.
+    pub const fn record(&self) -> &Record<N, Plaintext<N>> {
+        &self.record
+    }
+
+    /// Returns the height of the block in which the record was created.
+    pub const fn created_height(&self) -> u32 {
+        self.created_height
+    }
+
+    /// Returns the height of the block in which the record was spent, if it has been spent.
+    pub const fn spent_height(&self) -> Option<u32> {
+        self.spent_height
+    }
+
+    /// Returns `true` if the record has been spent.
+    pub const fn is_spent(&self) -> bool {
+        self.spent_height.is_some()
+    }
+
+    /// Returns the amount held by the record, if it has one.
+    pub fn amount(&self) -> Result<Option<u64>> {
+        record_amount(&self.record)
+    }
+}
+
+/// The confirmed and unconfirmed balance of a program.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Balance {
+    /// The balance of the unspent records in confirmed blocks.
+    confirmed: u64,
+    /// The balance once the unconfirmed transactions are confirmed.
+    unconfirmed: u64,
+}
+
+impl Balance {
+    /// Returns the balance of the unspent records in confirmed blocks.
+    pub const fn confirmed(&self) -> u64 {
+        self.confirmed
+    }
+
+    /// Returns the balance once the unconfirmed transactions are confirmed.
+    pub const fn unconfirmed(&self) -> u64 {
+        self.unconfirmed
+    }
+}
+
+/// The balances of a watch-only wallet, for each program with records that hold an amount.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WalletBalance<N: Network> {
+    /// The balance of each program.
+    programs: HashMap<ProgramID<N>, Balance>,
+}
+
+impl<N: Network> Default for WalletBalance<N> {
+    /// Returns an empty wallet balance.
+    fn default() -> Self {
+        Self { programs: HashMap::new() }
+    }
+}
+
+impl<N: Network> WalletBalance<N> {
+    /// Returns the balance of the given program.
+    pub fn get(&self, program_id: &ProgramID<N>) -> Balance {
+        self.programs.get(program_id).copied().unwrap_or_default()
+    }
+
+    /// Returns the balance of `credits.aleo`, in microcredits.
+    pub fn credits(&self) -> Result<Balance> {
+        Ok(self.get(&ProgramID::from_str("credits.aleo")?))
+    }
+
+    /// Returns an iterator over the balance of each program.
+    pub fn iter(&self) -> impl '_ + Iterator<Item = (&ProgramID<N>, &Balance)> {
+        self.programs.iter()
+    }
+
+    /// Adds the given wallet balance to this wallet balance.
+    pub fn merge(&mut self, other: &Self) -> Result<()> {
+        for (program_id, other) in other.iter() {
+            let balance = self.programs.entry(*program_id).or_default();
+            balance.confirmed = checked_add(balance.confirmed, other.confirmed)?;
+            balance.unconfirmed = checked_add(balance.unconfirmed, other.unconfirmed)?;
+        }
+        Ok(())
+    }
+}
+
+/// The direction of a transfer, relative to the account.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TransferKind {
+    /// The account sent the amount.
+    Sent,
+    /// The account received the amount.
+    Received,
+}
+
+/// A transfer in the history of a watch-only wallet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transfer<N: Network> {
+    /// The direction of the transfer.
+    kind: TransferKind,
+    /// The height of the block containing the transfer, or `None` if it is unconfirmed.
+    height: Option<u32>,
+    /// The ID of the transaction containing the transfer.
+    transaction_id: N::TransactionID,
+    /// The program ID of the transfer.
+    program_id: ProgramID<N>,
+    /// The amount of the transfer.
+    amount: u64,
+    /// The other party of the transfer, if it can be derived.
+    counterparty: Option<Address<N>>,
+}
+
+impl<N: Network> Transfer<N> {
+    /// Returns the direction of the transfer.
+    pub const fn kind(&self) -> TransferKind {
+        self.kind
+    }
+
+    /// Returns the height of the block containing the transfer, or `None` if it is unconfirmed.
+    pub const fn height(&self) -> Option<u32> {
+        self.height
+    }
+
+    /// Returns the ID of the transaction containing the transfer.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the program ID of the transfer.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the amount of the transfer.
+    pub const fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns the other party of the transfer, if it can be derived.
+    pub const fn counterparty(&self) -> Option<Address<N>> {
+        self.counterparty
+    }
+}
+
+/// A wallet that tracks the records, balance, and history of an account, using only its view key.
+///
+/// A record is detected as spent when its tag, derived from the view key and the record commitment,
+/// appears in a transition input. The wallet never holds the private key, so it cannot spend records.
+pub struct WatchOnlyWallet<N: Network> {
+    /// The view key of the account.
+    view_key: ViewKey<N>,
+    /// The address of the account.
+    address: Address<N>,
+    /// The graph key of the account, which is used to derive record tags.
+    graph_key: GraphKey<N>,
+    /// The records owned by the account, in the order they were received.
+    records: Vec<WatchedRecord<N>>,
+    /// A map of record tags to their index in `records`.
+    tags: HashMap<Field<N>, usize>,
+    /// The confirmed transfers of the account.
+    transfers: Vec<Transfer<N>>,
+    /// The unconfirmed transactions, in the order they were added.
+    unconfirmed: Vec<Transaction<N>>,
+    /// The height of the next block to scan.
+    next_height: u32,
+}
+
+impl<N: Network> WatchOnlyWallet<N> {
+    /// Initializes a new watch-only wallet for the given view key.
+    pub fn new(view_key: ViewKey<N>) -> Result<Self> {
+        Self::new_at_height(view_key, 0)
+    }
+
+    /// Initializes a new watch-only wallet for the given view key, which starts scanning at the given height.
+    /// Note: The account must not have received records below the given height, as they are not scanned.
+    pub fn new_at_height(view_key: ViewKey<N>, next_height: u32) -> Result<Self> {
+        let address = view_key.to_address();
+        let graph_key = GraphKey::try_from(view_key)?;
+        Ok(Self {
+            view_key,
+            address,
+            graph_key,
+            records: Vec::new(),
+            tags: HashMap::new(),
+            transfers: Vec::new(),
+            unconfirmed: Vec::new(),
+            next_height,
+        })
+    }
+
+    /// Returns the view key of the account.
+    pub const fn view_key(&self) -> &ViewKey<N> {
+        &self.view_key
+    }
+
+    /// Returns the address of the account.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the height of the next block to scan.
+    pub const fn next_height(&self) -> u32 {
+        self.next_height
+    }
+
+    /// Returns the records owned by the account, including spent records.
+    pub fn records(&self) -> &[WatchedRecord<N>] {
+        &self.records
+    }
+
+    /// Returns an iterator over the unspent records owned by the account.
+    pub fn unspent(&self) -> impl '_ + Iterator<Item = &WatchedRecord<N>> {
+        self.records.iter().filter(|record| !record.is_spent())
+    }
+
+    /// Returns the unconfirmed transactions.
+    pub fn unconfirmed(&self) -> &[Transaction<N>] {
+        &self.unconfirmed
+    }
+
+    /// Returns the balance of each program, from the unspent records owned by the account.
+    ///
+    /// The unconfirmed balance adds the records received, and removes the records spent, by the unconfirmed transactions.
+    pub fn balance(&self) -> Result<WalletBalance<N>> {
+        let mut programs = HashMap::<ProgramID<N>, Balance>::new();
+        // Add the confirmed records.
+        for record in self.unspent() {
+            if let Some(amount) = record.amount()? {
+                let balance = programs.entry(record.program_id).or_default();
+                balance.confirmed = checked_add(balance.confirmed, amount)?;
+                balance.unconfirmed = checked_add(balance.unconfirmed, amount)?;
+            }
+        }
+        for transaction in &self.unconfirmed {
+            // Add the records received by the unconfirmed transaction.
+            for transition in transaction.transitions() {
+                for (_, record) in self.owned_records(transition)? {
+                    if let Some(amount) = record_amount(&record)? {
+                        let balance = programs.entry(*transition.program_id()).or_default();
+                        balance.unconfirmed = checked_add(balance.unconfirmed, amount)?;
+                    }
+                }
+            }
+            // Remove the records spent by the unconfirmed transaction.
+            for tag in transaction.tags() {
+                if let Some(record) = self.tags.get(tag).and_then(|index| self.records.get(*index)) {
+                    if let (false, Some(amount)) = (record.is_spent(), record.amount()?) {
+                        let balance = programs.entry(record.program_id).or_default();
+                        balance.unconfirmed = balance.unconfirmed.saturating_sub(amount);
+                    }
+                }
+            }
+        }
+        Ok(WalletBalance { programs })
+    }
+
+    /// Returns the transfers of the account, with the confirmed transfers first, followed by the unconfirmed transfers.
+    pub fn history(&self) -> Result<Vec<Transfer<N>>> {
+        let mut history = self.transfers.clone();
+        for transaction in &self.unconfirmed {
+            history.extend(self.to_transfers(transaction, None)?);
+        }
+        Ok(history)
+    }
+
+    /// Adds the given unconfirmed transaction, which is removed once it is found in a scanned block.
+    pub fn add_unconfirmed(&mut self, transaction: Transaction<N>) {
+        if !self.unconfirmed.iter().any(|unconfirmed| unconfirmed.id() == transaction.id()) {
+            self.unconfirmed.push(transaction);
+        }
+    }
+
+    /// Scans the given block for records received and spent by the account.
+    /// Blocks below the next height to scan have already been scanned, and are ignored.
+    /// Returns an error if the block is above the next height to scan, as the blocks in between would be missed.
+    pub fn scan_block(&mut self, block: &Block<N>) -> Result<()> {
+        let height = block.height();
+        if height < self.next_height {
+            return Ok(());
+        }
+        ensure!(height == self.next_height, "Expected to scan block {}, found block {height}", self.next_height);
+
+        for confirmed in block.transactions().iter() {
+            // Remove the transaction from the unconfirmed transactions.
+            let unconfirmed_id = confirmed.to_unconfirmed_transaction_id()?;
+            self.unconfirmed.retain(|unconfirmed| unconfirmed.id() != unconfirmed_id);
+
+            let transaction = confirmed.transaction();
+            // Detect the records received by the account.
+            for transition in transaction.transitions() {
+                for (commitment, record) in self.owned_records(transition)? {
+                    let tag = Record::<N, Plaintext<N>>::tag(self.graph_key.sk_tag(), commitment)?;
+                    if self.tags.contains_key(&tag) {
+                        continue;
+                    }
+                    self.tags.insert(tag, self.records.len());
+                    self.records.push(WatchedRecord {
+                        program_id: *transition.program_id(),
+                        commitment,
+                        tag,
+                        record,
+                        created_height: height,
+                        spent_height: None,
+                    });
+                }
+            }
+            // Detect the records spent by the account, by matching the published tags.
+            for tag in transaction.tags() {
+                if let Some(record) = self.tags.get(tag).and_then(|index| self.records.get_mut(*index)) {
+                    record.spent_height.get_or_insert(height);
+                }
+            }
+            // Record the transfers of the account.
+            let transfers = self.to_transfers(transaction, Some(height))?;
+            self.transfers.extend(transfers);
+        }
+
+        self.next_height = height.saturating_add(1);
+        Ok(())
+    }
+
+    /// Returns the records owned by the account as a record bundle, for backups.
+    pub fn to_record_bundle(&self) -> Result<RecordBundle<N>> {
+        let mut bundle = RecordBundle::new(self.next_height.saturating_sub(1));
+        for record in &self.records {
+            bundle.insert(record.commitment, record.record.clone(), record.is_spent())?;
+        }
+        Ok(bundle)
+    }
+
+    /// Saves the sync height and decrypted records of the account to the given store, in the wallet column family.
+    pub fn save_record_bundle(&self, store: &impl KeyValueStore) -> Result<()> {
+        store.put(&to_wallet_key(&self.address)?, &self.to_record_bundle()?.to_bytes_le()?)
+    }
+
+    /// Returns the sync height and decrypted records of the given account, if they were saved to the given store.
+    pub fn load_record_bundle(store: &impl KeyValueStore, address: &Address<N>) -> Result<Option<RecordBundle<N>>> {
+        store.get(&to_wallet_key(address)?)?.map(|bytes| RecordBundle::from_bytes_le(&bytes)).transpose()
+    }
+}
+
+impl<N: Network> WatchOnlyWallet<N> {
+    /// Returns the commitments and decrypted records, for the outputs of the given transition owned by the account.
+    fn owned_records(&self, transition: &Transition<N>) -> Result<Vec<(Field<N>, Record<N, Plaintext<N>>)>> {
+        transition
+            .records()
+            .filter(|(_, record)| record.is_owner(&self.view_key))
+            .map(|(commitment, record)| Ok((*commitment, record.decrypt(&self.view_key)?)))
+            .collect()
+    }
+
+    /// Returns the transition view key, if the given transition was signed by the account.
+    fn transition_view_key(&self, transition: &Transition<N>) -> Result<Option<Field<N>>> {
+        // Compute the transition view key as `tpk * view_key`, which equals `r * signer` for the signer.
+        let tvk = (*transition.tpk() * *self.view_key).to_x_coordinate();
+        // The account signed the transition if the transition commitment is `Hash(tvk)`.
+        match N::hash_psd2(&[tvk])? == *transition.tcm() {
+            true => Ok(Some(tvk)),
+            false => Ok(None),
+        }
+    }
+
+    /// Returns the transfers of the account in the given transaction.
+    ///
+    /// Transitions that match a transfer signature are decoded from their inputs, which includes the counterparty
+    /// where it is public or the account signed the transition. Otherwise, the records received by the account
+    /// from another signer are reported as a transfer with an unknown counterparty.
+    fn to_transfers(&self, transaction: &Transaction<N>, height: Option<u32>) -> Result<Vec<Transfer<N>>> {
+        let mut transfers = Vec::new();
+        for transition in transaction.transitions() {
+            let tvk = self.transition_view_key(transition)?;
+            let transfer = |kind, amount, counterparty| Transfer {
+                kind,
+                height,
+                transaction_id: transaction.id(),
+                program_id: *transition.program_id(),
+                amount,
+                counterparty,
+            };
+
+            match decode_transfer(transition, tvk)? {
+                // The account sent credits to another account.
+                Some((recipient, amount, _)) if tvk.is_some() && recipient != self.address => {
+                    transfers.push(transfer(TransferKind::Sent, amount, Some(recipient)))
+                }
+                // The account received credits from another account.
+                Some((recipient, amount, sender)) if tvk.is_none() && recipient == self.address => {
+                    transfers.push(transfer(TransferKind::Received, amount, sender))
+                }
+                // The account received records from another account.
+                None if tvk.is_none() => {
+                    let records = self.owned_records(transition)?;
+                    if !records.is_empty() {
+                        let amount = records.iter().try_fold(0u64, |total, (_, record)| {
+                            checked_add(total, record_amount(record)?.unwrap_or_default())
+                        })?;
+                        transfers.push(transfer(TransferKind::Received, amount, None));
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(transfers)
+    }
+}
+
+impl<N: Network> Subscriber<N> for WatchOnlyWallet<N> {
+    /// Scans the new block for records received and spent by the account.
+    fn on_block(&mut self, block: &Block<N>) -> Result<()> {
+        self.scan_block(block)
+    }
+}
+
+/// An input in the signature of a transfer function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum TransferInput {
+    /// A record, which is spent by the transfer.
+    Record,
+    /// The address of the recipient.
+    Recipient,
+    /// The `u64` amount.
+    Amount,
+}
+
+/// The signatures of the transfer functions, as the function name and its inputs.
+/// A transition of any program is decoded as a transfer if its function name and inputs match a signature,
+/// so token programs that follow the interface of `credits.aleo` are tracked as well.
+const TRANSFER_SIGNATURES: &[(&str, &[TransferInput])] = &[
+    ("transfer_public", &[TransferInput::Recipient, TransferInput::Amount]),
+    ("transfer_private", &[TransferInput::Record, TransferInput::Recipient, TransferInput::Amount]),
+    ("transfer_private_to_public", &[TransferInput::Record, TransferInput::Recipient, TransferInput::Amount]),
+    ("transfer_public_to_private", &[TransferInput::Recipient, TransferInput::Amount]),
+];
+
+/// Returns the recipient, amount, and sender (if public) of the given transfer.
+/// Returns `None` if the transition does not match a transfer signature, or the recipient or amount is private
+/// to another signer.
+fn decode_transfer<N: Network>(
+    transition: &Transition<N>,
+    tvk: Option<Field<N>>,
+) -> Result<Option<(Address<N>, u64, Option<Address<N>>)>> {
+    // Retrieve the signature of the transfer function.
+    let function_name = transition.function_name().to_string();
+    let signature = match TRANSFER_SIGNATURES.iter().find(|(name, _)| *name == function_name) {
+        Some((_, signature)) => *signature,
+        None => return Ok(None),
+    };
+    // Ensure the transition has the same number of inputs as the signature.
+    if transition.inputs().len() != signature.len() {
+        return Ok(None);
+    }
+
+    let (mut recipient, mut amount) = (None, None);
+    for (index, expected) in (0u16..).zip(signature) {
+        match expected {
+            TransferInput::Record => {
+                if !matches!(transition.inputs().get(index as usize), Some(Input::Record(..))) {
+                    return Ok(None);
+                }
+            }
+            TransferInput::Recipient => match input_plaintext(transition, index, tvk)? {
+                Some(Plaintext::Literal(Literal::Address(address), _)) => recipient = Some(address),
+                _ => return Ok(None),
+            },
+            TransferInput::Amount => match input_plaintext(transition, index, tvk)? {
+                Some(Plaintext::Literal(Literal::U64(value), _)) => amount = Some(*value),
+                _ => return Ok(None),
+            },
+        }
+    }
+    let (recipient, amount) = match (recipient, amount) {
+        (Some(recipient), Some(amount)) => (recipient, amount),
+        _ => return Ok(None),
+    };
+    // The caller is public for transfers from a public balance, which do not spend a record.
+    let is_caller_public = !signature.contains(&TransferInput::Record);
+    // The caller is the first argument of the finalize future, for transfers from a public balance.
+    let sender = match is_caller_public {
+        true => {
+            transition.outputs().iter().find_map(Output::future).and_then(|future| match future.arguments().first() {
+                Some(Argument::Plaintext(Plaintext::Literal(Literal::Address(sender), _))) => Some(*sender),
+                _ => None,
+            })
+        }
+        false => None,
+    };
+    Ok(Some((recipient, amount, sender)))
+}
+
+/// Returns the plaintext of the given transition input, decrypting it with the transition view key if it is private.
+fn input_plaintext<N: Network>(
+    transition: &Transition<N>,
+    index: u16,
+    tvk: Option<Field<N>>,
+) -> Result<Option<Plaintext<N>>> {
+    match (transition.inputs().get(index as usize), tvk) {
+        (Some(Input::Constant(_, Some(plaintext)) | Input::Public(_, Some(plaintext))), _) => {
+            Ok(Some(plaintext.clone()))
+        }
+        (Some(Input::Private(_, Some(ciphertext))), Some(tvk)) => {
+            let program_id = transition.program_id();
+            // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+            let function_id = N::hash_bhp1024(
+                &(U16::<N>::new(N::ID), program_id.name(), program_id.network(), transition.function_name())
+                    .to_bits_le(),
+            )?;
+            // Compute the input view key as `Hash(function ID || tvk || index)`.
+            let input_view_key = N::hash_psd4(&[function_id, tvk, Field::from_u16(index)])?;
+            Ok(Some(ciphertext.decrypt_symmetric(input_view_key)?))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Returns the amount held by the given record, as its `microcredits` entry, or an `amount` entry for token programs.
+fn record_amount<N: Network>(record: &Record<N, Plaintext<N>>) -> Result<Option<u64>> {
+    for name in ["microcredits", "amount"] {
+        match record.find(&[Identifier::from_str(name)?]) {
+            Ok(Entry::Private(Plaintext::Literal(Literal::U64(amount), _)))
+            | Ok(Entry::Public(Plaintext::Literal(Literal::U64(amount), _))) => return Ok(Some(*amount)),
+            _ => continue,
+        }
+    }
+    Ok(None)
+}
+
+/// Returns the sum of the given amounts, or an error if it overflows.
+fn checked_add(a: u64, b: u64) -> Result<u64> {
+    a.checked_add(b).ok_or_else(|| anyhow!("The wallet balance overflowed"))
+}
+
+/// Returns the key of the wallet state of the given account, in the wallet column family.
+fn to_wallet_key<N: Network>(address: &Address<N>) -> Result<Vec<u8>> {
+    Ok([WALLET_COLUMN_FAMILY, &address.to_bytes_le()?].concat())
+}
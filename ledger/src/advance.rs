@@ -26,8 +26,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         // Decouple the transmissions into ratifications, solutions, and transactions.
         let (ratifications, solutions, transactions) = decouple_transmissions(transmissions.into_iter())?;
-        // Currently, we do not support ratifications from the memory pool.
-        ensure!(ratifications.is_empty(), "Ratifications are currently unsupported from the memory pool");
+        // Ensure the ratifications from the memory pool are for the randomness beacon.
+        ensure!(
+            ratifications.iter().all(|ratify| matches!(ratify, Ratify::BeaconCommit(..) | Ratify::BeaconReveal(..))),
+            "Only randomness beacon ratifications are supported from the memory pool"
+        );
         // Construct the block template.
         let (header, ratifications, solutions, transactions, aborted_transaction_ids) =
             self.construct_block_template(&previous_block, Some(&subdag), ratifications, solutions, transactions)?;
@@ -53,8 +56,13 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         candidate_transactions: Vec<Transaction<N>>,
         rng: &mut R,
     ) -> Result<Block<N>> {
-        // Currently, we do not support ratifications from the memory pool.
-        ensure!(candidate_ratifications.is_empty(), "Ratifications are currently unsupported from the memory pool");
+        // Ensure the ratifications from the memory pool are for the randomness beacon.
+        ensure!(
+            candidate_ratifications
+                .iter()
+                .all(|ratify| matches!(ratify, Ratify::BeaconCommit(..) | Ratify::BeaconReveal(..))),
+            "Only randomness beacon ratifications are supported from the memory pool"
+        );
 
         // Retrieve the latest block as the previous block (for the next block).
         let previous_block = self.latest_block();
@@ -83,14 +91,83 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
     /// Adds the given block as the next block in the ledger.
     pub fn advance_to_next_block(&self, block: &Block<N>) -> Result<()> {
+        self.add_next_block(block, false)
+    }
+
+    /// Adds the given block as the next block in the ledger, and if `is_unverified`,
+    /// marks the block as unverified (i.e. for a block that is added from a checkpoint).
+    pub(crate) fn add_next_block(&self, block: &Block<N>, is_unverified: bool) -> Result<()> {
         // Acquire the write lock on the current block.
         let mut current_block = self.current_block.write();
-        // Update the VM.
-        self.vm.add_next_block(block)?;
+        // Acquire the write lock on the randomness beacon.
+        let mut beacon = self.beacon.write();
+
+        // Note: The state derived from the block is computed before updating the VM,
+        // so that the ledger is only updated if the block is added.
+
+        // Compute the next total supply, which ensures the block conserves the supply.
+        let total_supply = next_total_supply(self.total_supply(), block)?;
+        // Compute the next unverified heights, which extend to the block if it is unverified.
+        let unverified_heights = match is_unverified {
+            true => {
+                let start = self.unverified_heights.read().as_ref().map_or(block.height(), |heights| *heights.start());
+                Some(start..=block.height())
+            }
+            false => self.unverified_heights(),
+        };
+        // Compute the next randomness beacon, and its new output, if any.
+        let (next_beacon, beacon_output) = self.next_beacon(&beacon, block)?;
+        // Compute the next serial numbers accumulator, and the leaves to store.
+        let (serial_numbers, serial_number_leaves) = {
+            let previous = self.serial_numbers.read();
+            let serial_numbers = previous.extended(block.serial_numbers())?;
+            let serial_number_leaves = serial_numbers.changed_leaves(&previous)?;
+            (serial_numbers, serial_number_leaves)
+        };
+        // Compute the changes to the per-program statistics.
+        let program_stats = ProgramStatsUpdate::new(self.vm.finalize_store().ledger_state_store(), block)?;
+        // Compute the secondary indexes of the block, if the archive mode is enabled.
+        let indexes = match self.vm.finalize_store().ledger_state_store().is_indexed()? {
+            true => {
+                let mut indexes = LedgerIndexes::new();
+                indexes.add_block(block);
+                Some(indexes)
+            }
+            false => None,
+        };
+
+        // Update the VM, and store the state derived from the block in the same atomic batch.
+        self.vm.add_next_block_with(block, || {
+            let ledger_state_store = self.vm.finalize_store().ledger_state_store();
+            if is_unverified {
+                ledger_state_store.set_unverified_heights(unverified_heights.clone())?;
+            }
+            ledger_state_store.insert_serial_number_leaves(&serial_number_leaves)?;
+            ledger_state_store.set_beacon_state(next_beacon.to_state(), beacon_output)?;
+            program_stats.store(ledger_state_store)?;
+            if let Some(indexes) = &indexes {
+                indexes.store(ledger_state_store)?;
+            }
+            ledger_state_store.set_total_supply(total_supply)
+        })?;
+
+        // Note: The block is now added, so the following updates must not fail.
+        // Update the unverified heights.
+        *self.unverified_heights.write() = unverified_heights;
+        // Update the randomness beacon.
+        *beacon = next_beacon;
+        // Drop the write lock on the randomness beacon.
+        drop(beacon);
+        // Update the serial numbers accumulator.
+        *self.serial_numbers.write() = serial_numbers;
+        // Update the total supply.
+        *self.total_supply.write() = total_supply;
         // Update the current block.
         *current_block = block.clone();
         // Drop the write lock on the current block.
         drop(current_block);
+        // Update the latest height in the metrics.
+        self.metrics.set_latest_height(block.height());
 
         // Update the cached committee from storage.
         if let Ok(current_committee) = self.vm.finalize_store().committee_store().current_committee() {
@@ -218,7 +295,13 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             next_cumulative_weight,
             next_cumulative_proof_target,
             previous_block.hash(),
-        )?;
+        )?
+        .with_beacon_output(self.vm.beacon_output()?)?;
+        // Drop the candidate transactions that have expired, as of the next block.
+        let candidate_transactions = candidate_transactions
+            .into_iter()
+            .filter(|transaction| self.vm.check_transaction_expiry(transaction, next_height).is_ok())
+            .collect::<Vec<_>>();
         // Speculate over the ratifications, solutions, and transactions.
         let (ratifications, transactions, aborted_transaction_ids, ratified_finalize_operations) = self.vm.speculate(
             state,
@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns an attestation to the state root after the block at the given height, signed with the given private key.
+    ///
+    /// The signer must be a member of the committee at the given height. Attestations from
+    /// several validators are combined with `StateRootAttestation::add_signature`.
+    pub fn attest_state_root<R: Rng + CryptoRng>(
+        &self,
+        height: u32,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<StateRootAttestation<N>> {
+        // Retrieve the block hash and state root at the given height.
+        let block_hash = self.get_hash(height)?;
+        let Some(state_root) = self.get_state_root(height)? else { bail!("Missing the state root for block {height}") };
+        // Ensure the signer is a member of the committee at the given height.
+        let committee = self.get_committee_at(height)?;
+        let signer = Address::try_from(private_key)?;
+        ensure!(committee.is_committee_member(signer), "'{signer}' is not in the committee at block {height}");
+
+        // Sign the attestation.
+        let mut attestation = StateRootAttestation::new(height, block_hash, state_root);
+        attestation.sign(private_key, rng)?;
+        Ok(attestation)
+    }
+
+    /// Ensures the given attestation matches the ledger, and is signed by a quorum of the committee at its height.
+    pub fn verify_state_root_attestation(&self, attestation: &StateRootAttestation<N>) -> Result<()> {
+        let height = attestation.height();
+        // Ensure the block hash matches the ledger.
+        ensure!(
+            attestation.block_hash() == self.get_hash(height)?,
+            "The attestation has an incorrect block hash for block {height}"
+        );
+        // Ensure the state root matches the ledger.
+        ensure!(
+            Some(attestation.state_root()) == self.get_state_root(height)?,
+            "The attestation has an incorrect state root for block {height}"
+        );
+        // Ensure the attestation is signed by a quorum of the committee.
+        attestation.verify(&self.get_committee_at(height)?)
+    }
+
+    /// Returns the committee at the given height.
+    fn get_committee_at(&self, height: u32) -> Result<Committee<N>> {
+        match self.get_committee(height)? {
+            Some(committee) => Ok(committee),
+            None => bail!("Missing the committee for block {height}"),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the current epoch of the randomness beacon, in which commitments are collected.
+    pub fn beacon_epoch(&self) -> u32 {
+        self.beacon.read().epoch()
+    }
+
+    /// Returns the output of the randomness beacon for the given commit epoch, if the epoch has an output.
+    pub fn beacon_output(&self, epoch: u32) -> Result<Option<Field<N>>> {
+        self.vm.finalize_store().ledger_state_store().beacon_output(epoch)
+    }
+
+    /// Returns the randomness beacon after the given block, and the `(commit epoch, output)` it produced, if any.
+    ///
+    /// The beacon is advanced to the epoch of the block, and then updated with the commitments and reveals
+    /// in the ratifications of the block. Each commitment must be signed over `(epoch, commitment)`
+    /// by a member of the current committee.
+    pub(crate) fn next_beacon(
+        &self,
+        beacon: &RandomnessBeacon<N>,
+        block: &Block<N>,
+    ) -> Result<(RandomnessBeacon<N>, Option<(u32, Field<N>)>)> {
+        let mut beacon = beacon.clone();
+        // Advance the beacon, if the block starts a new epoch.
+        let epoch = block.height() / N::NUM_BLOCKS_PER_EPOCH;
+        let output = match epoch > beacon.epoch() {
+            true => beacon.advance(epoch)?,
+            false => None,
+        };
+        // Apply the commitments and reveals in the block.
+        for ratify in block.ratifications().iter() {
+            match ratify {
+                Ratify::BeaconCommit(commitment, signature) => {
+                    let signer = signature.to_address();
+                    // Ensure the signature is valid for the current epoch.
+                    ensure!(
+                        signature.verify(&signer, &[Field::from_u32(beacon.epoch()), *commitment]),
+                        "Invalid signature for the beacon commitment of '{signer}'"
+                    );
+                    // Ensure the signer is a member of the current committee.
+                    ensure!(
+                        self.latest_committee()?.is_committee_member(signer),
+                        "'{signer}' is not in the current committee"
+                    );
+                    beacon.commit(signer, *commitment)?;
+                }
+                Ratify::BeaconReveal(address, value, randomizer) => beacon.reveal(*address, *value, *randomizer)?,
+                Ratify::Genesis(..) | Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => {}
+            }
+        }
+        Ok((beacon, output))
+    }
+}
@@ -17,6 +17,31 @@ use super::*;
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
     pub fn check_next_block(&self, block: &Block<N>) -> Result<()> {
+        self.check_next_block_with_verified(block, &HashSet::new())
+    }
+
+    /// Checks the given block is valid next block, where the given transactions were already verified
+    /// against an earlier state of the ledger, and only need to be checked for conflicts with the ledger.
+    pub(crate) fn check_next_block_with_verified(
+        &self,
+        block: &Block<N>,
+        verified_transaction_ids: &HashSet<N::TransactionID>,
+    ) -> Result<()> {
+        let start = std::time::Instant::now();
+        let result = self.check_next_block_internal(block, verified_transaction_ids);
+        // Record the verification latency of a valid block.
+        if result.is_ok() {
+            self.metrics.record_verification_latency(start.elapsed());
+        }
+        result
+    }
+
+    /// Checks the given block is valid next block, skipping the full verification of the given transactions.
+    fn check_next_block_internal(
+        &self,
+        block: &Block<N>,
+        verified_transaction_ids: &HashSet<N::TransactionID>,
+    ) -> Result<()> {
         let height = block.height();
 
         // Ensure the block hash does not already exist.
@@ -38,36 +63,25 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             }
         }
 
-        // Ensure each transaction is well-formed and unique.
+        // Ensure each transaction is well-formed, unique, and has not expired as of this block.
         // TODO: this intermediate allocation shouldn't be necessary; this is most likely https://github.com/rust-lang/rust/issues/89418.
         let transactions = block.transactions().iter().collect::<Vec<_>>();
         cfg_iter!(transactions).try_for_each(|transaction| {
-            self.check_transaction_basic(*transaction, transaction.to_rejected_id()?)
+            // Note: Pre-verified transactions may have been verified for an earlier block.
+            self.vm
+                .check_transaction_expiry(transaction, height)
+                .and_then(|()| match verified_transaction_ids.contains(&transaction.id()) {
+                    true => self.vm.check_transaction_is_unique(transaction),
+                    false => self.check_transaction_basic(*transaction, transaction.to_rejected_id()?),
+                })
                 .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
         })?;
 
-        // TODO (howardwu): Remove this after moving the total supply into credits.aleo.
-        {
-            // // Retrieve the latest total supply.
-            // let latest_total_supply = self.latest_total_supply_in_microcredits();
-            // // Retrieve the block reward from the first block ratification.
-            // let block_reward = match block.ratifications()[0] {
-            //     Ratify::BlockReward(block_reward) => block_reward,
-            //     _ => bail!("Block {height} is invalid - the first ratification must be a block reward"),
-            // };
-            // // Retrieve the puzzle reward from the second block ratification.
-            // let puzzle_reward = match block.ratifications()[1] {
-            //     Ratify::PuzzleReward(puzzle_reward) => puzzle_reward,
-            //     _ => bail!("Block {height} is invalid - the second ratification must be a puzzle reward"),
-            // };
-            // // Compute the next total supply in microcredits.
-            // let next_total_supply_in_microcredits =
-            //     update_total_supply(latest_total_supply, block_reward, puzzle_reward, block.transactions())?;
-            // // Ensure the total supply in microcredits is correct.
-            // if next_total_supply_in_microcredits != block.total_supply_in_microcredits() {
-            //     bail!("Invalid total supply in microcredits")
-            // }
-        }
+        // Ensure the block conserves the total supply.
+        next_total_supply(self.total_supply(), block).map_err(|e| anyhow!("Block {height} is invalid - {e}"))?;
+
+        // Ensure the randomness beacon ratifications in the block are valid.
+        self.next_beacon(&self.beacon.read(), block).map_err(|e| anyhow!("Block {height} is invalid - {e}"))?;
 
         // Construct the finalize state.
         let state = FinalizeGlobalState::new::<N>(
@@ -76,7 +90,8 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
-        )?;
+        )?
+        .with_beacon_output(self.vm.beacon_output()?)?;
 
         // Ensure speculation over the unconfirmed transactions is correct.
         let ratified_finalize_operations =
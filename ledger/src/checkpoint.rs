@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Advances the ledger to the given checkpoint, using the given blocks that follow the latest block.
+    ///
+    /// The checkpoint must be signed by one of the trusted signers, and the blocks must form a chain
+    /// from the latest block to the checkpoint, which ends in the checkpointed state root. The blocks
+    /// are added to the ledger **without** verifying their transactions, so the ledger can serve
+    /// requests immediately; the skipped blocks remain unverified until `verify_history` succeeds.
+    pub fn advance_to_checkpoint(
+        &self,
+        checkpoint: &Checkpoint<N>,
+        trusted_signers: &[Address<N>],
+        blocks: &[Block<N>],
+    ) -> Result<()> {
+        // Ensure the checkpoint is signed by a trusted signer.
+        checkpoint.verify(trusted_signers)?;
+
+        // Retrieve the latest height and hash.
+        let latest_height = self.latest_height();
+        let latest_hash = self.latest_hash();
+        // Ensure the checkpoint is ahead of the ledger.
+        ensure!(
+            checkpoint.height() > latest_height,
+            "The checkpoint (at height {}) is not ahead of the ledger (at height {latest_height})",
+            checkpoint.height()
+        );
+        // Ensure the blocks cover the range up to the checkpoint.
+        ensure!(
+            blocks.len() as u64 == u64::from(checkpoint.height() - latest_height),
+            "Expected {} blocks to reach the checkpoint, found {}",
+            checkpoint.height() - latest_height,
+            blocks.len()
+        );
+
+        // Ensure the blocks form a chain from the latest block to the checkpoint, before adding any of them.
+        let mut previous_hash = latest_hash;
+        for (expected_height, block) in (latest_height + 1..).zip(blocks) {
+            // Ensure the block height is correct.
+            ensure!(block.height() == expected_height, "Expected block {expected_height}, found {}", block.height());
+            // Ensure the block links to the previous block.
+            ensure!(
+                block.previous_hash() == previous_hash,
+                "Block {expected_height} does not link to the previous block"
+            );
+            // Ensure the block hash is correct.
+            let block_hash = N::hash_bhp1024(&to_bits_le![block.previous_hash(), block.header().to_root()?])?;
+            ensure!(block.hash() == block_hash.into(), "Block {expected_height} has an incorrect block hash");
+            // Ensure the transactions correspond to the block header.
+            ensure!(
+                block.header().transactions_root() == block.transactions().to_transactions_root()?,
+                "Block {expected_height} has an incorrect transactions root"
+            );
+            previous_hash = block.hash();
+        }
+        // Ensure the chain ends in the checkpointed block.
+        ensure!(previous_hash == checkpoint.block_hash(), "The blocks do not end in the checkpointed block");
+
+        // Add the blocks to the ledger, and mark them as unverified.
+        for block in blocks {
+            self.add_next_block(block, true)?;
+        }
+
+        // Ensure the ledger has reached the checkpointed state root.
+        // Note: This does not undo the added blocks, as the checkpoint is signed by a trusted signer.
+        ensure!(
+            self.latest_state_root() == checkpoint.state_root(),
+            "The ledger does not match the state root of the checkpoint at height {}",
+            checkpoint.height()
+        );
+        Ok(())
+    }
+
+    /// Returns the range of block heights that were added from a checkpoint, and are not yet verified.
+    ///
+    /// Note: This range is stored with the blocks, so a reloaded ledger resumes from the same unverified blocks.
+    pub fn unverified_heights(&self) -> Option<RangeInclusive<u32>> {
+        self.unverified_heights.read().clone()
+    }
+
+    /// Returns `true` if all of the blocks in the ledger are verified.
+    pub fn is_fully_verified(&self) -> bool {
+        self.unverified_heights.read().is_none()
+    }
+
+    /// Verifies the transactions of the unverified blocks, from the latest to the earliest.
+    ///
+    /// The range of unverified heights shrinks as each block is verified, and the ledger
+    /// is fully verified when this returns successfully. On failure, returns an error.
+    pub fn verify_history(&self) -> Result<()> {
+        loop {
+            // Retrieve the highest unverified height.
+            let Some(height) = self.unverified_heights.read().as_ref().map(|heights| *heights.end()) else {
+                break;
+            };
+            // Retrieve the block.
+            let block = self.get_block(height)?;
+            // Verify the transactions in the block.
+            let transactions = block.transactions();
+            let result = cfg_iter!(transactions).try_for_each(|transaction| {
+                self.vm
+                    .check_historical_transaction(transaction, transaction.to_rejected_id()?)
+                    .map_err(|e| anyhow!("Transaction '{}' in block {height} is invalid - {e}", transaction.id()))
+            });
+            if let Err(error) = result {
+                error!("Failed to verify the history of the ledger - {error}");
+                return Err(error);
+            }
+
+            // Acquire the read lock on the current block, so the ledger does not advance while the range is stored.
+            let _current_block = self.current_block.read();
+            // Remove the block from the unverified heights.
+            let mut unverified_heights = self.unverified_heights.write();
+            let next_heights = match unverified_heights.as_ref() {
+                Some(heights) if height > *heights.start() => Some(*heights.start()..=height - 1),
+                _ => None,
+            };
+            self.vm.finalize_store().ledger_state_store().set_unverified_heights(next_heights.clone())?;
+            *unverified_heights = next_heights;
+        }
+        Ok(())
+    }
+
+    /// Spawns a thread that verifies the transactions of the unverified blocks, and returns its handle.
+    pub fn spawn_history_verification(&self) -> std::thread::JoinHandle<Result<()>>
+    where
+        N: 'static,
+        C: 'static,
+    {
+        let ledger = self.clone();
+        std::thread::spawn(move || ledger.verify_history())
+    }
+}
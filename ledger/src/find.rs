@@ -51,6 +51,46 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.transition_store().find_transition_id(id)
     }
 
+    /// Returns the block height that contains the given record `commitment`.
+    ///
+    /// Note: This requires the archive mode, see `Ledger::enable_indexes`.
+    pub fn find_block_height_from_commitment(&self, commitment: &Field<N>) -> Result<Option<u32>> {
+        // Ensure the archive mode is enabled.
+        self.ensure_is_indexed()?;
+        self.vm.finalize_store().ledger_state_store().find_block_height_from_commitment(commitment)
+    }
+
+    /// Returns the transaction ID that spends the given `serial number`.
+    ///
+    /// Note: This requires the archive mode, see `Ledger::enable_indexes`.
+    pub fn find_transaction_id_from_serial_number(&self, serial_number: &Field<N>) -> Result<Option<N::TransactionID>> {
+        // Ensure the archive mode is enabled.
+        self.ensure_is_indexed()?;
+        self.vm.finalize_store().ledger_state_store().find_transaction_id_from_serial_number(serial_number)
+    }
+
+    /// Returns the transaction ID that spends the record of the given `tag`.
+    ///
+    /// Note: This requires the archive mode, see `Ledger::enable_indexes`.
+    pub fn find_transaction_id_from_tag(&self, tag: &Field<N>) -> Result<Option<N::TransactionID>> {
+        // Ensure the archive mode is enabled.
+        self.ensure_is_indexed()?;
+        self.vm.finalize_store().ledger_state_store().find_transaction_id_from_tag(tag)
+    }
+
+    /// Returns the transaction IDs that deploy or call the given `program ID`, in the given range of block heights.
+    ///
+    /// Note: This requires the archive mode, see `Ledger::enable_indexes`.
+    pub fn find_transaction_ids_from_program_id(
+        &self,
+        program_id: &ProgramID<N>,
+        heights: Range<u32>,
+    ) -> Result<Vec<N::TransactionID>> {
+        // Ensure the archive mode is enabled.
+        self.ensure_is_indexed()?;
+        self.vm.finalize_store().ledger_state_store().find_transaction_ids_from_program_id(program_id, heights)
+    }
+
     /// Returns the record ciphertexts that belong to the given view key.
     pub fn find_record_ciphertexts<'a>(
         &'a self,
@@ -142,3 +182,13 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         })
     }
 }
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Ensures the archive mode is enabled, so the secondary indexes are available.
+    fn ensure_is_indexed(&self) -> Result<()> {
+        match self.is_indexed()? {
+            true => Ok(()),
+            false => bail!("The ledger indexes are not enabled (see 'Ledger::enable_indexes')"),
+        }
+    }
+}
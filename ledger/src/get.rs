@@ -47,6 +47,16 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         EpochChallenge::new(epoch_number, epoch_block_hash, N::COINBASE_PUZZLE_DEGREE)
     }
 
+    /// Returns the activity statistics for the given program, or `None` if the program has no activity.
+    pub fn get_program_stats(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramStats>> {
+        self.vm.finalize_store().ledger_state_store().get_program_stats(program_id)
+    }
+
+    /// Returns the activity statistics for every program with activity.
+    pub fn get_all_program_stats(&self) -> IndexMap<ProgramID<N>, ProgramStats> {
+        self.vm.finalize_store().ledger_state_store().program_stats()
+    }
+
     /// Returns the block for the given block height.
     pub fn get_block(&self, height: u32) -> Result<Block<N>> {
         // If the height is 0, return the genesis block.
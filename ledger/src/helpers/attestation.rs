@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::{Address, PrivateKey, Signature},
+    network::prelude::*,
+    types::Field,
+};
+use ledger_committee::Committee;
+
+use std::collections::HashSet;
+
+/// An attestation to the state root after the block at a block height, signed by the validators of a committee.
+///
+/// The attestation is verified standalone against the committee, and is valid once the signers
+/// reach the quorum threshold of the committee stake, so another chain can trust the state root
+/// as far as it trusts the committee.
+#[derive(Clone, PartialEq, Eq)]
+pub struct StateRootAttestation<N: Network> {
+    /// The block height.
+    height: u32,
+    /// The block hash at the block height.
+    block_hash: N::BlockHash,
+    /// The state root after the block at the block height.
+    state_root: N::StateRoot,
+    /// The signatures of the validators, over the network ID, height, block hash, and state root.
+    signatures: Vec<Signature<N>>,
+}
+
+impl<N: Network> StateRootAttestation<N> {
+    /// The version of the attestation.
+    const VERSION: u8 = 1;
+
+    /// Initializes a new attestation without signatures.
+    pub const fn new(height: u32, block_hash: N::BlockHash, state_root: N::StateRoot) -> Self {
+        Self { height, block_hash, state_root, signatures: Vec::new() }
+    }
+
+    /// Returns the block height.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the block hash at the block height.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the state root after the block at the block height.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns the signatures.
+    pub fn signatures(&self) -> &[Signature<N>] {
+        &self.signatures
+    }
+
+    /// Returns the addresses of the signers.
+    pub fn signers(&self) -> impl '_ + Iterator<Item = Address<N>> {
+        self.signatures.iter().map(|signature| signature.to_address())
+    }
+
+    /// Signs the attestation with the given private key.
+    pub fn sign<R: Rng + CryptoRng>(&mut self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<()> {
+        let signature = private_key.sign(&self.to_message(), rng)?;
+        self.add_signature(signature)
+    }
+
+    /// Adds the given signature to the attestation, ensuring it is valid and its signer has not signed yet.
+    pub fn add_signature(&mut self, signature: Signature<N>) -> Result<()> {
+        // Ensure the signer has not signed yet.
+        let signer = signature.to_address();
+        ensure!(
+            !self.signers().any(|address| address == signer),
+            "'{signer}' already signed the attestation at block {}",
+            self.height
+        );
+        // Ensure the signature is valid.
+        ensure!(
+            signature.verify(&signer, &self.to_message()),
+            "Invalid signature from '{signer}' for the attestation at block {}",
+            self.height
+        );
+        self.signatures.push(signature);
+        Ok(())
+    }
+
+    /// Ensures the attestation is signed by members of the given committee, reaching its quorum threshold.
+    pub fn verify(&self, committee: &Committee<N>) -> Result<()> {
+        // Compute the signed message.
+        let message = self.to_message();
+
+        let mut signers = HashSet::with_capacity(self.signatures.len());
+        for signature in &self.signatures {
+            let signer = signature.to_address();
+            // Ensure the signer is unique.
+            ensure!(signers.insert(signer), "'{signer}' signed the attestation at block {} twice", self.height);
+            // Ensure the signer is a committee member.
+            ensure!(
+                committee.is_committee_member(signer),
+                "Attestation at block {} has a signer not in the committee (found '{signer}')",
+                self.height
+            );
+            // Ensure the signature is valid.
+            ensure!(
+                signature.verify(&signer, &message),
+                "Invalid signature from '{signer}' for the attestation at block {}",
+                self.height
+            );
+        }
+        // Ensure the signers reach the quorum threshold.
+        ensure!(
+            committee.is_quorum_threshold_reached(&signers),
+            "Attestation at block {} does not reach the quorum threshold ({} of {} signers)",
+            self.height,
+            signers.len(),
+            committee.num_members()
+        );
+        Ok(())
+    }
+
+    /// Returns the signed message, which is bound to the network.
+    fn to_message(&self) -> [Field<N>; 4] {
+        [Field::from_u16(N::ID), Field::from_u32(self.height), *self.block_hash, *self.state_root]
+    }
+}
+
+impl<N: Network> FromBytes for StateRootAttestation<N> {
+    /// Reads the attestation from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != Self::VERSION {
+            return Err(error("Invalid state root attestation version"));
+        }
+        // Read the attestation.
+        let height = u32::read_le(&mut reader)?;
+        let block_hash = FromBytes::read_le(&mut reader)?;
+        let state_root = FromBytes::read_le(&mut reader)?;
+        // Read the signatures.
+        let num_signatures = u16::read_le(&mut reader)?;
+        let signatures = (0..num_signatures).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { height, block_hash, state_root, signatures })
+    }
+}
+
+impl<N: Network> ToBytes for StateRootAttestation<N> {
+    /// Writes the attestation to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        Self::VERSION.write_le(&mut writer)?;
+        self.height.write_le(&mut writer)?;
+        self.block_hash.write_le(&mut writer)?;
+        self.state_root.write_le(&mut writer)?;
+        u16::try_from(self.signatures.len()).map_err(error)?.write_le(&mut writer)?;
+        self.signatures.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> Debug for StateRootAttestation<N> {
+    /// Prints the attestation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("StateRootAttestation")
+            .field("height", &self.height)
+            .field("block_hash", &self.block_hash)
+            .field("state_root", &self.state_root)
+            .field("signers", &self.signers().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_state_root_attestation() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee of four validators with equal stake.
+        let private_keys = (0..4).map(|_| PrivateKey::<CurrentNetwork>::new(rng).unwrap()).collect::<Vec<_>>();
+        let members = private_keys
+            .iter()
+            .map(|private_key| (Address::try_from(private_key).unwrap(), (ledger_committee::MIN_VALIDATOR_STAKE, true)))
+            .collect();
+        let committee = Committee::new(0, members).unwrap();
+
+        // Sign the attestation with two validators.
+        let mut attestation = StateRootAttestation::new(10, Uniform::rand(rng), Uniform::rand(rng));
+        assert!(attestation.verify(&committee).is_err());
+        for private_key in &private_keys[..2] {
+            attestation.sign(private_key, rng).unwrap();
+        }
+        // Ensure a validator cannot sign twice.
+        assert!(attestation.sign(&private_keys[0], rng).is_err());
+        // Ensure two of four validators do not reach the quorum threshold.
+        assert!(attestation.verify(&committee).is_err());
+
+        // Ensure a signer outside of the committee is rejected.
+        let mut outsider = attestation.clone();
+        outsider.sign(&PrivateKey::new(rng).unwrap(), rng).unwrap();
+        assert!(outsider.verify(&committee).is_err());
+
+        // Ensure three of four validators reach the quorum threshold.
+        attestation.sign(&private_keys[2], rng).unwrap();
+        attestation.verify(&committee).unwrap();
+
+        // Ensure the attestation round-trips through bytes.
+        let bytes = attestation.to_bytes_le().unwrap();
+        assert_eq!(attestation, StateRootAttestation::read_le(&bytes[..]).unwrap());
+
+        // Ensure a tampered attestation fails to verify.
+        let tampered = StateRootAttestation { height: 11, ..attestation.clone() };
+        assert!(tampered.verify(&committee).is_err());
+        // Ensure a signature on a different attestation is rejected.
+        let mut other = StateRootAttestation::new(11, attestation.block_hash(), attestation.state_root());
+        assert!(other.add_signature(attestation.signatures()[0]).is_err());
+    }
+}
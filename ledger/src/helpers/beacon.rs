@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::Address,
+    network::prelude::*,
+    types::{Field, Scalar},
+};
+
+use ledger_store::BeaconState;
+
+use indexmap::IndexMap;
+
+/// The domain separator for the outputs of the randomness beacon.
+const BEACON_DOMAIN: &str = "AleoRandomnessBeacon0";
+
+/// A commit-reveal randomness beacon, which aggregates the values revealed by participants into an output per epoch.
+///
+/// A participant commits to a value during an epoch, and reveals the value during the next epoch. Once the
+/// reveal epoch ends, the revealed values are aggregated into the output for the commit epoch. A participant
+/// that commits but does not reveal is excluded from the output.
+///
+/// The commitments and reveals are carried in the blocks, so the beacon is derived from the blocks alone.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RandomnessBeacon<N: Network> {
+    /// The current epoch, in which commitments are collected.
+    epoch: u32,
+    /// The commitments of the current epoch.
+    commitments: IndexMap<Address<N>, Field<N>>,
+    /// The commitments of the previous epoch, which are revealed in the current epoch.
+    pending: IndexMap<Address<N>, Field<N>>,
+    /// The revealed values of the previous epoch.
+    reveals: IndexMap<Address<N>, Field<N>>,
+}
+
+impl<N: Network> RandomnessBeacon<N> {
+    /// Initializes a new randomness beacon, collecting commitments in the given epoch.
+    pub fn new(epoch: u32) -> Self {
+        Self { epoch, commitments: Default::default(), pending: Default::default(), reveals: Default::default() }
+    }
+
+    /// Initializes a randomness beacon from the given stored state.
+    pub fn from_state((epoch, commitments, pending, reveals): BeaconState<N>) -> Self {
+        Self {
+            epoch,
+            commitments: commitments.into_iter().collect(),
+            pending: pending.into_iter().collect(),
+            reveals: reveals.into_iter().collect(),
+        }
+    }
+
+    /// Returns the state of the randomness beacon, for storage.
+    pub fn to_state(&self) -> BeaconState<N> {
+        let to_vec = |map: &IndexMap<Address<N>, Field<N>>| -> Vec<(Address<N>, Field<N>)> {
+            map.iter().map(|(address, value)| (*address, *value)).collect()
+        };
+        (self.epoch, to_vec(&self.commitments), to_vec(&self.pending), to_vec(&self.reveals))
+    }
+
+    /// Returns the commitment of the given participant to the given value, as `Commit(address || value, randomizer)`.
+    pub fn commitment(address: &Address<N>, value: Field<N>, randomizer: Scalar<N>) -> Result<Field<N>> {
+        N::commit_bhp1024(&[address.to_bits_le(), value.to_bits_le()].concat(), &randomizer)
+    }
+
+    /// Returns the current epoch, in which commitments are collected.
+    pub const fn epoch(&self) -> u32 {
+        self.epoch
+    }
+
+    /// Adds the commitment of the given participant to the current epoch.
+    pub fn commit(&mut self, address: Address<N>, commitment: Field<N>) -> Result<()> {
+        // Ensure the participant has not committed in the current epoch.
+        ensure!(!self.commitments.contains_key(&address), "'{address}' already committed in epoch {}", self.epoch);
+        self.commitments.insert(address, commitment);
+        Ok(())
+    }
+
+    /// Adds the revealed value of the given participant, for its commitment in the previous epoch.
+    pub fn reveal(&mut self, address: Address<N>, value: Field<N>, randomizer: Scalar<N>) -> Result<()> {
+        let commit_epoch = self.epoch.saturating_sub(1);
+        // Retrieve the commitment of the participant.
+        let Some(commitment) = self.pending.get(&address) else {
+            bail!("'{address}' has no commitment to reveal for epoch {commit_epoch}")
+        };
+        // Ensure the participant has not revealed yet.
+        ensure!(!self.reveals.contains_key(&address), "'{address}' already revealed for epoch {commit_epoch}");
+        // Ensure the value and randomizer open the commitment.
+        ensure!(
+            *commitment == Self::commitment(&address, value, randomizer)?,
+            "The reveal of '{address}' does not match its commitment for epoch {commit_epoch}"
+        );
+        self.reveals.insert(address, value);
+        Ok(())
+    }
+
+    /// Advances the beacon to the given epoch, and returns the `(commit epoch, output)` for the previous
+    /// commit epoch, if any.
+    ///
+    /// The commitments of the current epoch are revealed in the given epoch if it is the next epoch,
+    /// and are discarded otherwise.
+    pub fn advance(&mut self, epoch: u32) -> Result<Option<(u32, Field<N>)>> {
+        // Ensure the epoch advances.
+        ensure!(epoch > self.epoch, "The beacon cannot advance from epoch {} to epoch {epoch}", self.epoch);
+
+        // Aggregate the revealed values for the previous commit epoch.
+        let output = match self.reveals.is_empty() {
+            true => None,
+            false => {
+                let commit_epoch = self.epoch.saturating_sub(1);
+                Some((commit_epoch, Self::aggregate(commit_epoch, &self.reveals)?))
+            }
+        };
+
+        // Move the commitments of the current epoch to the reveal phase.
+        self.pending = match epoch == self.epoch.saturating_add(1) {
+            true => std::mem::take(&mut self.commitments),
+            false => Default::default(),
+        };
+        self.commitments.clear();
+        self.reveals.clear();
+        self.epoch = epoch;
+
+        Ok(output)
+    }
+
+    /// Returns the output for the given commit epoch, as `Hash(domain, epoch, Σ Hash(address, value))`.
+    ///
+    /// The sum makes the output independent of the order in which the values were revealed.
+    fn aggregate(epoch: u32, reveals: &IndexMap<Address<N>, Field<N>>) -> Result<Field<N>> {
+        let mut sum = Field::zero();
+        for (address, value) in reveals {
+            sum += N::hash_psd2(&[address.to_x_coordinate(), *value])?;
+        }
+        N::hash_psd2(&[Field::new_domain_separator(BEACON_DOMAIN), Field::from_u32(epoch), sum])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a participant, with a committed value and randomizer.
+    fn sample_participant(
+        rng: &mut TestRng,
+    ) -> (Address<CurrentNetwork>, Field<CurrentNetwork>, Scalar<CurrentNetwork>, Field<CurrentNetwork>) {
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let (value, randomizer) = (Field::rand(rng), Scalar::rand(rng));
+        let commitment = RandomnessBeacon::commitment(&address, value, randomizer).unwrap();
+        (address, value, randomizer, commitment)
+    }
+
+    #[test]
+    fn test_commit_reveal() {
+        let rng = &mut TestRng::default();
+
+        let (alice, alice_value, alice_randomizer, alice_commitment) = sample_participant(rng);
+        let (bob, bob_value, bob_randomizer, bob_commitment) = sample_participant(rng);
+        let (carol, _, _, carol_commitment) = sample_participant(rng);
+
+        // Commit in epoch 1.
+        let mut beacon = RandomnessBeacon::<CurrentNetwork>::new(1);
+        beacon.commit(alice, alice_commitment).unwrap();
+        beacon.commit(bob, bob_commitment).unwrap();
+        beacon.commit(carol, carol_commitment).unwrap();
+        assert!(beacon.commit(alice, alice_commitment).is_err());
+        // Ensure a value cannot be revealed in the commit epoch.
+        assert!(beacon.reveal(alice, alice_value, alice_randomizer).is_err());
+        assert_eq!(beacon.advance(2).unwrap(), None);
+
+        // Reveal in epoch 2, in a different order. Carol does not reveal.
+        beacon.reveal(bob, bob_value, bob_randomizer).unwrap();
+        assert!(beacon.reveal(bob, bob_value, bob_randomizer).is_err());
+        assert!(beacon.reveal(alice, alice_value, bob_randomizer).is_err());
+        beacon.reveal(alice, alice_value, alice_randomizer).unwrap();
+        let (commit_epoch, output) = beacon.advance(3).unwrap().unwrap();
+        assert_eq!(commit_epoch, 1);
+
+        // Ensure the output does not depend on the order of the reveals.
+        let mut reveals = IndexMap::new();
+        reveals.insert(alice, alice_value);
+        reveals.insert(bob, bob_value);
+        assert_eq!(RandomnessBeacon::aggregate(1, &reveals).unwrap(), output);
+
+        // Ensure the beacon does not move backwards.
+        assert!(beacon.advance(3).is_err());
+    }
+
+    #[test]
+    fn test_state() {
+        let rng = &mut TestRng::default();
+
+        let (alice, alice_value, alice_randomizer, alice_commitment) = sample_participant(rng);
+        let (bob, _, _, bob_commitment) = sample_participant(rng);
+
+        // Initialize a beacon with commitments, pending commitments, and reveals.
+        let mut beacon = RandomnessBeacon::<CurrentNetwork>::new(1);
+        beacon.commit(alice, alice_commitment).unwrap();
+        beacon.advance(2).unwrap();
+        beacon.reveal(alice, alice_value, alice_randomizer).unwrap();
+        beacon.commit(bob, bob_commitment).unwrap();
+
+        // Ensure the beacon is restored from its state.
+        let restored = RandomnessBeacon::from_state(beacon.to_state());
+        assert_eq!(beacon, restored);
+        assert_eq!(beacon.advance(3).unwrap(), restored.clone().advance(3).unwrap());
+    }
+
+    #[test]
+    fn test_skipped_epoch() {
+        let rng = &mut TestRng::default();
+
+        let (alice, alice_value, alice_randomizer, alice_commitment) = sample_participant(rng);
+
+        // Ensure commitments are discarded if the reveal epoch is skipped.
+        let mut beacon = RandomnessBeacon::<CurrentNetwork>::new(1);
+        beacon.commit(alice, alice_commitment).unwrap();
+        assert_eq!(beacon.advance(3).unwrap(), None);
+        assert!(beacon.reveal(alice, alice_value, alice_randomizer).is_err());
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::{Address, PrivateKey, Signature},
+    network::prelude::*,
+    types::Field,
+};
+
+/// A signed checkpoint of the ledger, attesting to the block hash and state root at a block height.
+///
+/// A node that trusts the signer can start from the checkpoint, by adding the blocks up to it
+/// without verification, and retroactively verify those blocks in the background.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Checkpoint<N: Network> {
+    /// The block height.
+    height: u32,
+    /// The block hash at the block height.
+    block_hash: N::BlockHash,
+    /// The state root after the block at the block height.
+    state_root: N::StateRoot,
+    /// The signature of the signer, over the height, block hash, and state root.
+    signature: Signature<N>,
+}
+
+impl<N: Network> Checkpoint<N> {
+    /// The version of the checkpoint.
+    const VERSION: u8 = 1;
+
+    /// Initializes a new checkpoint, signed with the given private key.
+    pub fn new<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        height: u32,
+        block_hash: N::BlockHash,
+        state_root: N::StateRoot,
+        rng: &mut R,
+    ) -> Result<Self> {
+        let signature = private_key.sign(&Self::to_message(height, block_hash, state_root), rng)?;
+        Ok(Self { height, block_hash, state_root, signature })
+    }
+
+    /// Returns the block height.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the block hash at the block height.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the state root after the block at the block height.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns the signature.
+    pub const fn signature(&self) -> &Signature<N> {
+        &self.signature
+    }
+
+    /// Returns the address of the signer.
+    pub fn signer(&self) -> Address<N> {
+        self.signature.to_address()
+    }
+
+    /// Ensures the checkpoint is signed by one of the given trusted signers.
+    pub fn verify(&self, trusted_signers: &[Address<N>]) -> Result<()> {
+        // Ensure the signer is trusted.
+        let signer = self.signer();
+        ensure!(
+            trusted_signers.contains(&signer),
+            "Checkpoint at block {} has an untrusted signer '{signer}'",
+            self.height
+        );
+        // Ensure the signature is valid.
+        let message = Self::to_message(self.height, self.block_hash, self.state_root);
+        ensure!(
+            self.signature.verify(&signer, &message),
+            "Invalid signature for the checkpoint at block {}",
+            self.height
+        );
+        Ok(())
+    }
+
+    /// Returns the signed message, for the given height, block hash, and state root.
+    fn to_message(height: u32, block_hash: N::BlockHash, state_root: N::StateRoot) -> [Field<N>; 3] {
+        [Field::from_u32(height), *block_hash, *state_root]
+    }
+}
+
+impl<N: Network> FromBytes for Checkpoint<N> {
+    /// Reads the checkpoint from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != Self::VERSION {
+            return Err(error("Invalid checkpoint version"));
+        }
+        // Read the checkpoint.
+        let height = u32::read_le(&mut reader)?;
+        let block_hash = FromBytes::read_le(&mut reader)?;
+        let state_root = FromBytes::read_le(&mut reader)?;
+        let signature = FromBytes::read_le(&mut reader)?;
+        Ok(Self { height, block_hash, state_root, signature })
+    }
+}
+
+impl<N: Network> ToBytes for Checkpoint<N> {
+    /// Writes the checkpoint to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        Self::VERSION.write_le(&mut writer)?;
+        self.height.write_le(&mut writer)?;
+        self.block_hash.write_le(&mut writer)?;
+        self.state_root.write_le(&mut writer)?;
+        self.signature.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> Debug for Checkpoint<N> {
+    /// Prints the checkpoint as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Checkpoint")
+            .field("height", &self.height)
+            .field("block_hash", &self.block_hash)
+            .field("state_root", &self.state_root)
+            .field("signer", &self.signer())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_checkpoint() {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let checkpoint = Checkpoint::new(&private_key, 10, Uniform::rand(rng), Uniform::rand(rng), rng).unwrap();
+
+        // Ensure the checkpoint verifies for a trusted signer only.
+        checkpoint.verify(&[address]).unwrap();
+        let other = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(checkpoint.verify(&[other]).is_err());
+        assert!(checkpoint.verify(&[]).is_err());
+
+        // Ensure the checkpoint round-trips through bytes.
+        let bytes = checkpoint.to_bytes_le().unwrap();
+        assert_eq!(checkpoint, Checkpoint::read_le(&bytes[..]).unwrap());
+
+        // Ensure a tampered checkpoint fails to verify.
+        let tampered = Checkpoint { height: 11, ..checkpoint.clone() };
+        assert!(tampered.verify(&[address]).is_err());
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{IncrementalBlockTree, BLOCKS_DEPTH},
+};
+use ledger_block::{Block, Header};
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// A chain of block headers, which is validated without the block bodies.
+///
+/// Each header is checked to succeed the previous header, by its height, round, and cumulative weight,
+/// by the previous block hash, by the block hash over the header, and by the previous state root,
+/// which is tracked in a block tree of the block hashes, as in the full ledger.
+///
+/// The body of a block is only checked when the block is given to `HeaderChain::check_block`,
+/// so a body can be fetched and validated on demand.
+///
+/// Note: The headers are unauthenticated, as the block authority is not verified against the committee,
+/// which requires the finalized state of the ledger. The header chain must start from a trusted genesis block,
+/// and the headers must be obtained from a trusted source.
+#[derive(Clone)]
+pub struct HeaderChain<N: Network> {
+    /// The block hash, header, and state root after the block, of each block by height.
+    headers: Arc<RwLock<Vec<(N::BlockHash, Header<N>, N::StateRoot)>>>,
+    /// The block tree of the block hashes.
+    block_tree: Arc<RwLock<IncrementalBlockTree<N>>>,
+}
+
+impl<N: Network> HeaderChain<N> {
+    /// Initializes a new header chain, starting from the given genesis block.
+    pub fn new(genesis_block: &Block<N>) -> Result<Self> {
+        // Ensure the block is a genesis block.
+        ensure!(genesis_block.is_genesis(), "The header chain must start from a genesis block");
+        // Ensure the genesis block body matches its header.
+        genesis_block.verify_body()?;
+
+        // Construct the block tree.
+        let mut block_tree = IncrementalBlockTree::try_from(&N::merkle_tree_bhp::<BLOCKS_DEPTH>(&[])?)?;
+        block_tree.append(&[genesis_block.hash().to_bits_le()])?;
+        let state_root = (*block_tree.root()).into();
+
+        Ok(Self {
+            headers: Arc::new(RwLock::new(vec![(genesis_block.hash(), *genesis_block.header(), state_root)])),
+            block_tree: Arc::new(RwLock::new(block_tree)),
+        })
+    }
+
+    /// Returns the latest block height.
+    pub fn latest_height(&self) -> u32 {
+        self.latest().1.height()
+    }
+
+    /// Returns the latest block hash.
+    pub fn latest_hash(&self) -> N::BlockHash {
+        self.latest().0
+    }
+
+    /// Returns the latest block header.
+    pub fn latest_header(&self) -> Header<N> {
+        self.latest().1
+    }
+
+    /// Returns the latest state root.
+    pub fn latest_state_root(&self) -> N::StateRoot {
+        self.latest().2
+    }
+
+    /// Returns the block hash for the given block height.
+    pub fn get_hash(&self, height: u32) -> Result<N::BlockHash> {
+        self.get(height).map(|(block_hash, ..)| block_hash)
+    }
+
+    /// Returns the block header for the given block height.
+    pub fn get_header(&self, height: u32) -> Result<Header<N>> {
+        self.get(height).map(|(_, header, _)| header)
+    }
+
+    /// Returns the state root after the block at the given block height.
+    pub fn get_state_root(&self, height: u32) -> Result<N::StateRoot> {
+        self.get(height).map(|(.., state_root)| state_root)
+    }
+
+    /// Returns `true` if the given state root is the state root after a block in the chain.
+    pub fn contains_state_root(&self, state_root: &N::StateRoot) -> bool {
+        self.headers.read().iter().any(|(.., candidate)| candidate == state_root)
+    }
+
+    /// Checks the given block header succeeds the latest block header, and adds it to the chain.
+    pub fn add_next_header(
+        &self,
+        block_hash: N::BlockHash,
+        previous_hash: N::BlockHash,
+        header: Header<N>,
+    ) -> Result<()> {
+        // Acquire the write locks, in order.
+        let mut block_tree = self.block_tree.write();
+        let mut headers = self.headers.write();
+
+        // Retrieve the latest block.
+        let (latest_hash, latest_header, latest_state_root) = match headers.last() {
+            Some(latest) => *latest,
+            None => bail!("The header chain is missing the genesis block"),
+        };
+        // Determine the expected height.
+        let height = latest_header.height().saturating_add(1);
+
+        // Ensure the block header is well-formed.
+        ensure!(header.is_valid(), "Header is malformed in block {height}");
+        // Ensure the height is correct.
+        ensure!(header.height() == height, "Expected block {height}, found block {}", header.height());
+        // Ensure the round is after the latest round.
+        ensure!(
+            header.round() > latest_header.round(),
+            "Round is not after the previous round in block {height} (found '{}', expected after '{}')",
+            header.round(),
+            latest_header.round()
+        );
+        // Ensure the cumulative weight does not decrease.
+        ensure!(
+            header.cumulative_weight() >= latest_header.cumulative_weight(),
+            "Cumulative weight decreased in block {height}"
+        );
+        // Ensure the previous block hash matches.
+        ensure!(
+            previous_hash == latest_hash,
+            "Previous block hash is incorrect in block {height} (found '{previous_hash}', expected '{latest_hash}')"
+        );
+        // Ensure the block hash is correct.
+        let candidate_hash: N::BlockHash = N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])?.into();
+        ensure!(
+            block_hash == candidate_hash,
+            "Block hash is incorrect in block {height} (found '{block_hash}', expected '{candidate_hash}')"
+        );
+        // Ensure the previous state root matches.
+        ensure!(
+            header.previous_state_root() == latest_state_root,
+            "Previous state root is incorrect in block {height} (found '{}', expected '{latest_state_root}')",
+            header.previous_state_root()
+        );
+
+        // Append the block hash to the block tree.
+        block_tree.append(&[block_hash.to_bits_le()])?;
+        // Add the block header.
+        headers.push((block_hash, header, (*block_tree.root()).into()));
+        Ok(())
+    }
+
+    /// Checks the header of the given block succeeds the latest block header, and adds it to the chain.
+    pub fn add_next_block_header(&self, block: &Block<N>) -> Result<()> {
+        self.add_next_header(block.hash(), block.previous_hash(), *block.header())
+    }
+
+    /// Checks the given block matches its header in the chain, and its body matches the roots in the header.
+    /// Note: The block authority is not verified.
+    pub fn check_block(&self, block: &Block<N>) -> Result<()> {
+        // Retrieve the block header in the chain.
+        let (block_hash, header, _) = self.get(block.height())?;
+        // Ensure the block hash matches.
+        ensure!(block.hash() == block_hash, "Block {} does not match the header chain", block.height());
+        // Ensure the block header matches.
+        ensure!(block.header() == &header, "Block {} has a different header in the header chain", block.height());
+        // Ensure the block body matches the header.
+        block.verify_body()
+    }
+
+    /// Returns the latest block hash, header, and state root.
+    fn latest(&self) -> (N::BlockHash, Header<N>, N::StateRoot) {
+        // The chain always contains the genesis block.
+        *self.headers.read().last().expect("The header chain is missing the genesis block")
+    }
+
+    /// Returns the block hash, header, and state root for the given block height.
+    fn get(&self, height: u32) -> Result<(N::BlockHash, Header<N>, N::StateRoot)> {
+        match self.headers.read().get(height as usize) {
+            Some(entry) => Ok(*entry),
+            None => bail!("Block {height} is not in the header chain"),
+        }
+    }
+}
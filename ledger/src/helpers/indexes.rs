@@ -0,0 +1,87 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, program::ProgramID, types::Field};
+use ledger_block::{Block, ConfirmedTransaction};
+use ledger_store::{LedgerStateStorage, LedgerStateStore};
+
+use std::collections::HashSet;
+
+/// The secondary indexes of the archive mode for a sequence of blocks, which are stored in the ledger state store.
+pub(crate) struct LedgerIndexes<N: Network> {
+    /// The block height of each record commitment.
+    commitments: Vec<(Field<N>, u32)>,
+    /// The transaction ID that spends each serial number.
+    serial_numbers: Vec<(Field<N>, N::TransactionID)>,
+    /// The transaction ID that spends the record of each tag.
+    tags: Vec<(Field<N>, N::TransactionID)>,
+    /// The block height, transaction index, and transaction ID of each transaction that deploys or calls a program.
+    programs: Vec<(ProgramID<N>, u32, u32, N::TransactionID)>,
+}
+
+impl<N: Network> LedgerIndexes<N> {
+    /// Initializes new indexes, with no blocks.
+    pub(crate) fn new() -> Self {
+        Self {
+            commitments: Default::default(),
+            serial_numbers: Default::default(),
+            tags: Default::default(),
+            programs: Default::default(),
+        }
+    }
+
+    /// Updates the indexes with the confirmed transactions in the given block.
+    pub(crate) fn add_block(&mut self, block: &Block<N>) {
+        let height = block.height();
+        for confirmed in block.transactions().iter() {
+            // Retrieve the transaction, which is the fee transaction of a rejected transaction.
+            let transaction = confirmed.transaction();
+            let transaction_id = transaction.id();
+
+            // Index the records that are created and spent by the transaction.
+            self.commitments.extend(transaction.commitments().map(|commitment| (*commitment, height)));
+            self.serial_numbers
+                .extend(transaction.serial_numbers().map(|serial_number| (*serial_number, transaction_id)));
+            self.tags.extend(transaction.tags().map(|tag| (*tag, transaction_id)));
+
+            // Collect the programs that are deployed or called by the transaction, excluding the fee.
+            let mut program_ids = HashSet::new();
+            match confirmed {
+                ConfirmedTransaction::AcceptedDeploy(_, transaction, _) => {
+                    program_ids.extend(transaction.deployment().map(|deployment| *deployment.program_id()));
+                }
+                ConfirmedTransaction::AcceptedExecute(_, transaction, _) => {
+                    if let Some(execution) = transaction.execution() {
+                        program_ids.extend(execution.transitions().map(|transition| *transition.program_id()));
+                    }
+                }
+                ConfirmedTransaction::RejectedDeploy(_, _, rejected, _)
+                | ConfirmedTransaction::RejectedExecute(_, _, rejected, _) => {
+                    program_ids.extend(rejected.deployment().map(|deployment| *deployment.program_id()));
+                    if let Some(execution) = rejected.execution() {
+                        program_ids.extend(execution.transitions().map(|transition| *transition.program_id()));
+                    }
+                }
+            }
+            // Index the transaction for each program.
+            let index = confirmed.index();
+            self.programs.extend(program_ids.into_iter().map(|program_id| (program_id, height, index, transaction_id)));
+        }
+    }
+
+    /// Stores the indexes in the given store.
+    pub(crate) fn store<S: LedgerStateStorage<N>>(&self, store: &LedgerStateStore<N, S>) -> Result<()> {
+        store.insert_indexes(&self.commitments, &self.serial_numbers, &self.tags, &self.programs)
+    }
+}
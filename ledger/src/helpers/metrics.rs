@@ -0,0 +1,221 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_store::helpers::StorageStats;
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    fmt::Write as _,
+    sync::{
+        atomic::{AtomicU32, AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// The verification latency percentiles, in microseconds.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LatencyPercentiles {
+    /// The number of samples.
+    pub count: u64,
+    /// The median latency.
+    pub p50: u64,
+    /// The 90th percentile latency.
+    pub p90: u64,
+    /// The 99th percentile latency.
+    pub p99: u64,
+    /// The maximum latency.
+    pub max: u64,
+}
+
+impl LatencyPercentiles {
+    /// Computes the percentiles of the given samples, using the nearest-rank method.
+    fn from_samples(samples: impl IntoIterator<Item = u64>) -> Self {
+        let mut samples = samples.into_iter().collect::<Vec<_>>();
+        samples.sort_unstable();
+        // Returns the sample at the given percentile.
+        let percentile = |p: usize| match samples.len() {
+            0 => 0,
+            len => samples[((len * p).saturating_add(99) / 100).clamp(1, len) - 1],
+        };
+        Self {
+            count: samples.len() as u64,
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+            max: samples.last().copied().unwrap_or(0),
+        }
+    }
+}
+
+/// A snapshot of the node metrics, for operators to export (e.g. to Prometheus).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metrics {
+    /// The latest block height.
+    pub latest_height: u32,
+    /// The number of transactions in the mempool.
+    pub mempool_size: u64,
+    /// The number of pending proving requests.
+    pub proving_queue_depth: u64,
+    /// The latency percentiles of the recently verified blocks.
+    pub verification_latency: LatencyPercentiles,
+    /// The number of keys in the storage.
+    pub storage_keys: u64,
+    /// The number of bytes in the storage.
+    pub storage_bytes: u64,
+}
+
+impl Metrics {
+    /// Returns the metrics in the Prometheus text format, with the given metric name prefix (e.g. `snarkvm`).
+    pub fn to_prometheus(&self, prefix: &str) -> String {
+        let mut output = String::new();
+        let mut gauge = |name: &str, help: &str, value: u64| {
+            // Note: Writing to a string does not fail.
+            let _ = writeln!(output, "# HELP {prefix}_{name} {help}");
+            let _ = writeln!(output, "# TYPE {prefix}_{name} gauge");
+            let _ = writeln!(output, "{prefix}_{name} {value}");
+        };
+        gauge("latest_height", "The latest block height.", u64::from(self.latest_height));
+        gauge("mempool_size", "The number of transactions in the mempool.", self.mempool_size);
+        gauge("proving_queue_depth", "The number of pending proving requests.", self.proving_queue_depth);
+        gauge("verification_latency_p50_us", "The median block verification latency.", self.verification_latency.p50);
+        gauge("verification_latency_p90_us", "The p90 block verification latency.", self.verification_latency.p90);
+        gauge("verification_latency_p99_us", "The p99 block verification latency.", self.verification_latency.p99);
+        gauge("verification_latency_max_us", "The maximum block verification latency.", self.verification_latency.max);
+        gauge("storage_keys", "The number of keys in the storage.", self.storage_keys);
+        gauge("storage_bytes", "The number of bytes in the storage.", self.storage_bytes);
+        output
+    }
+}
+
+/// A shared recorder of the node metrics, which is updated by each subsystem, and read as a `Metrics` snapshot.
+///
+/// Cloning the recorder returns a handle to the same metrics.
+#[derive(Clone, Default)]
+pub struct MetricsRecorder {
+    /// The latest block height.
+    latest_height: Arc<AtomicU32>,
+    /// The number of transactions in the mempool.
+    mempool_size: Arc<AtomicU64>,
+    /// The number of pending proving requests.
+    proving_queue_depth: Arc<AtomicU64>,
+    /// The latencies of the recently verified blocks, in microseconds.
+    verification_latencies: Arc<Mutex<VecDeque<u64>>>,
+    /// The number of keys and bytes in the storage.
+    storage: Arc<Mutex<(u64, u64)>>,
+}
+
+impl MetricsRecorder {
+    /// The maximum number of verification latencies used to compute the percentiles.
+    pub const MAX_LATENCY_SAMPLES: usize = 1024;
+
+    /// Initializes a new metrics recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the latest block height.
+    pub fn set_latest_height(&self, height: u32) {
+        self.latest_height.store(height, Ordering::Relaxed);
+    }
+
+    /// Sets the number of transactions in the mempool.
+    pub fn set_mempool_size(&self, size: usize) {
+        self.mempool_size.store(size as u64, Ordering::Relaxed);
+    }
+
+    /// Sets the number of pending proving requests.
+    pub fn set_proving_queue_depth(&self, depth: usize) {
+        self.proving_queue_depth.store(depth as u64, Ordering::Relaxed);
+    }
+
+    /// Records the latency of a block verification.
+    pub fn record_verification_latency(&self, latency: Duration) {
+        let latency = u64::try_from(latency.as_micros()).unwrap_or(u64::MAX);
+        let mut latencies = self.verification_latencies.lock();
+        // Evict the oldest latency, if the window is full.
+        if latencies.len() == Self::MAX_LATENCY_SAMPLES {
+            latencies.pop_front();
+        }
+        latencies.push_back(latency);
+    }
+
+    /// Sets the storage sizes from the given storage statistics.
+    pub fn set_storage_stats(&self, stats: &StorageStats) {
+        *self.storage.lock() = (stats.num_keys(), stats.num_bytes());
+    }
+
+    /// Returns a snapshot of the metrics.
+    pub fn snapshot(&self) -> Metrics {
+        let (storage_keys, storage_bytes) = *self.storage.lock();
+        Metrics {
+            latest_height: self.latest_height.load(Ordering::Relaxed),
+            mempool_size: self.mempool_size.load(Ordering::Relaxed),
+            proving_queue_depth: self.proving_queue_depth.load(Ordering::Relaxed),
+            verification_latency: LatencyPercentiles::from_samples(self.verification_latencies.lock().iter().copied()),
+            storage_keys,
+            storage_bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_percentiles() {
+        assert_eq!(LatencyPercentiles::from_samples([]), LatencyPercentiles::default());
+
+        let percentiles = LatencyPercentiles::from_samples((1..=100).rev());
+        assert_eq!(percentiles, LatencyPercentiles { count: 100, p50: 50, p90: 90, p99: 99, max: 100 });
+
+        let percentiles = LatencyPercentiles::from_samples([7]);
+        assert_eq!(percentiles, LatencyPercentiles { count: 1, p50: 7, p90: 7, p99: 7, max: 7 });
+    }
+
+    #[test]
+    fn test_metrics_recorder() {
+        let recorder = MetricsRecorder::new();
+        // Ensure clones update the same metrics.
+        recorder.clone().set_latest_height(5);
+        recorder.set_mempool_size(3);
+        recorder.set_proving_queue_depth(2);
+        for latency in 0..MetricsRecorder::MAX_LATENCY_SAMPLES + 10 {
+            recorder.record_verification_latency(Duration::from_micros(latency as u64));
+        }
+        let mut stats = StorageStats::new();
+        stats.add_entry(None, 4, 6);
+
+        recorder.set_storage_stats(&stats);
+        let metrics = recorder.snapshot();
+        assert_eq!(metrics.latest_height, 5);
+        assert_eq!(metrics.mempool_size, 3);
+        assert_eq!(metrics.proving_queue_depth, 2);
+        assert_eq!(metrics.verification_latency.count, MetricsRecorder::MAX_LATENCY_SAMPLES as u64);
+        assert_eq!(metrics.verification_latency.max, MetricsRecorder::MAX_LATENCY_SAMPLES as u64 + 9);
+        assert_eq!((metrics.storage_keys, metrics.storage_bytes), (1, 10));
+
+        // Ensure the snapshot round-trips through JSON.
+        let json = serde_json::to_string(&metrics).unwrap();
+        assert_eq!(metrics, serde_json::from_str(&json).unwrap());
+        // Ensure the snapshot is exported in the Prometheus text format.
+        let prometheus = metrics.to_prometheus("snarkvm");
+        assert!(prometheus.contains("# TYPE snarkvm_latest_height gauge\nsnarkvm_latest_height 5\n"));
+        assert!(prometheus.contains("snarkvm_storage_bytes 10\n"));
+    }
+}
@@ -12,8 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod attestation;
+pub use attestation::*;
+
+mod beacon;
+pub use beacon::*;
+
 mod bft;
 pub use bft::*;
 
+mod checkpoint;
+pub use checkpoint::*;
+
+mod header_chain;
+pub use header_chain::*;
+
+mod indexes;
+pub use indexes::*;
+
+mod metrics;
+pub use metrics::*;
+
+mod program_stats;
+pub use program_stats::*;
+
+mod serial_numbers;
+pub use serial_numbers::*;
+
 mod supply;
 pub use supply::*;
+
+mod sync;
+pub use sync::*;
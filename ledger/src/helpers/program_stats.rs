@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, program::ProgramID, types::Address};
+use ledger_block::{Block, ConfirmedTransaction, Execution, Fee, Transaction};
+use ledger_store::{LedgerStateStorage, LedgerStateStore};
+
+pub use ledger_store::ProgramStats;
+
+use indexmap::{map::Entry, IndexMap, IndexSet};
+
+/// The changes of a block to the program statistics, which are computed before the block is added.
+pub(crate) struct ProgramStatsUpdate<N: Network> {
+    /// The updated statistics of each program with activity in the block, in the order the programs were seen.
+    stats: IndexMap<ProgramID<N>, ProgramStats>,
+    /// The callers that called a program for the first time.
+    callers: IndexSet<(ProgramID<N>, Address<N>)>,
+}
+
+impl<N: Network> ProgramStatsUpdate<N> {
+    /// Returns the changes of the given block to the program statistics in the given store.
+    pub(crate) fn new<S: LedgerStateStorage<N>>(store: &LedgerStateStore<N, S>, block: &Block<N>) -> Result<Self> {
+        let mut update = Self { stats: Default::default(), callers: Default::default() };
+        for confirmed in block.transactions().iter() {
+            match confirmed {
+                ConfirmedTransaction::AcceptedDeploy(_, Transaction::Deploy(_, _, deployment, fee), _) => {
+                    let stats = update.stats_mut(store, *deployment.program_id())?;
+                    stats.deployments = stats.deployments.saturating_add(1);
+                    update.record_fee(store, *deployment.program_id(), fee)?;
+                }
+                ConfirmedTransaction::AcceptedExecute(_, Transaction::Execute(_, execution, fee), _) => {
+                    // Count the call to each program in the execution.
+                    for transition in execution.transitions() {
+                        let stats = update.stats_mut(store, *transition.program_id())?;
+                        stats.executions = stats.executions.saturating_add(1);
+                    }
+                    if let Some(fee) = fee {
+                        // Attribute the fee to the program of the root call.
+                        update.record_fee(store, *execution.peek()?.program_id(), fee)?;
+                        // Count the payer of a public fee as a caller of each program in the execution.
+                        if let Some(caller) = fee.payer() {
+                            update.record_caller(store, execution, caller)?;
+                        }
+                    }
+                }
+                ConfirmedTransaction::RejectedExecute(_, Transaction::Fee(_, fee), rejected, _) => {
+                    if let Some(execution) = rejected.execution() {
+                        // Attribute the rejected execution and its fee to the program of the root call.
+                        let program_id = *execution.peek()?.program_id();
+                        let stats = update.stats_mut(store, program_id)?;
+                        stats.rejected_executions = stats.rejected_executions.saturating_add(1);
+                        update.record_fee(store, program_id, fee)?;
+                    }
+                }
+                // A rejected deployment does not add a program, so it is not attributed to one.
+                _ => {}
+            }
+        }
+        Ok(update)
+    }
+
+    /// Stores the changes in the given store.
+    pub(crate) fn store<S: LedgerStateStorage<N>>(&self, store: &LedgerStateStore<N, S>) -> Result<()> {
+        store.insert_program_stats(&self.stats, &self.callers)
+    }
+
+    /// Returns the updated statistics of the given program, starting from its statistics in the given store.
+    fn stats_mut<S: LedgerStateStorage<N>>(
+        &mut self,
+        store: &LedgerStateStore<N, S>,
+        program_id: ProgramID<N>,
+    ) -> Result<&mut ProgramStats> {
+        match self.stats.entry(program_id) {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => Ok(entry.insert(store.get_program_stats(&program_id)?.unwrap_or_default())),
+        }
+    }
+
+    /// Records the given fee, as paid for the given program.
+    fn record_fee<S: LedgerStateStorage<N>>(
+        &mut self,
+        store: &LedgerStateStore<N, S>,
+        program_id: ProgramID<N>,
+        fee: &Fee<N>,
+    ) -> Result<()> {
+        let amount = *fee.amount()?;
+        let stats = self.stats_mut(store, program_id)?;
+        stats.fees_paid = stats.fees_paid.saturating_add(amount);
+        Ok(())
+    }
+
+    /// Records the given caller, as a caller of each program in the given execution.
+    fn record_caller<S: LedgerStateStorage<N>>(
+        &mut self,
+        store: &LedgerStateStore<N, S>,
+        execution: &Execution<N>,
+        caller: Address<N>,
+    ) -> Result<()> {
+        for transition in execution.transitions() {
+            let program_id = *transition.program_id();
+            // Determine if the caller is new for the program.
+            if !store.contains_program_caller(&program_id, &caller)? && self.callers.insert((program_id, caller)) {
+                let stats = self.stats_mut(store, program_id)?;
+                stats.unique_callers = stats.unique_callers.saturating_add(1);
+            }
+        }
+        Ok(())
+    }
+}
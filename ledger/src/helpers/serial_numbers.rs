@@ -0,0 +1,298 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{NonMembershipPath, SerialNumberLeaf, SerialNumbersTree},
+    types::Field,
+};
+
+use std::collections::BTreeMap;
+
+/// An indexed Merkle tree over the spent serial numbers (i.e. the nullifier set).
+///
+/// The leaves form a sorted linked list, where each leaf commits to a serial number and
+/// the next-largest serial number in the set. This allows a prover to show a serial number
+/// is unspent by opening the single leaf whose range covers it (see `NonMembershipPath`).
+/// Leaves are appended in insertion order, so the root is determined by the order in which
+/// serial numbers are added, which for the ledger is the canonical block order.
+#[derive(Clone)]
+pub struct SerialNumberAccumulator<N: Network> {
+    /// The Merkle tree over the serial number leaves.
+    tree: SerialNumbersTree<N>,
+    /// The serial number leaves, in insertion order.
+    leaves: Vec<SerialNumberLeaf<N>>,
+    /// The mapping of each serial number to its leaf index, in ascending order of serial numbers.
+    indices: BTreeMap<Field<N>, usize>,
+}
+
+impl<N: Network> SerialNumberAccumulator<N> {
+    /// Initializes a new accumulator, containing only the sentinel leaf `(0, 0)`.
+    pub fn new() -> Result<Self> {
+        // Initialize the sentinel leaf.
+        let sentinel = SerialNumberLeaf::new_with_version(Field::zero(), Field::zero());
+        // Initialize the Merkle tree.
+        let tree = N::merkle_tree_bhp(&[sentinel.to_bits_le()])?;
+        // Return the accumulator.
+        Ok(Self { tree, leaves: vec![sentinel], indices: [(Field::zero(), 0)].into_iter().collect() })
+    }
+
+    /// Initializes an accumulator from the given serial number leaves, in insertion order.
+    pub fn from_leaves(leaves: Vec<SerialNumberLeaf<N>>) -> Result<Self> {
+        // If there are no leaves, return a new accumulator.
+        if leaves.is_empty() {
+            return Self::new();
+        }
+        // Ensure the first leaf is the sentinel leaf.
+        ensure!(leaves[0].serial_number().is_zero(), "The first serial number leaf is not the sentinel leaf");
+        // Construct the indices, ensuring each serial number is unique.
+        let mut indices = BTreeMap::new();
+        for (index, leaf) in leaves.iter().enumerate() {
+            if indices.insert(leaf.serial_number(), index).is_some() {
+                bail!("Serial number '{}' already exists", leaf.serial_number())
+            }
+        }
+        // Initialize the Merkle tree.
+        let tree = N::merkle_tree_bhp(&leaves.iter().map(|leaf| leaf.to_bits_le()).collect::<Vec<_>>())?;
+        // Return the accumulator.
+        Ok(Self { tree, leaves, indices })
+    }
+
+    /// Returns the root of the accumulator.
+    pub const fn root(&self) -> &Field<N> {
+        self.tree.root()
+    }
+
+    /// Returns the number of serial numbers in the accumulator.
+    pub fn len(&self) -> usize {
+        // Note: The sentinel leaf is not a serial number.
+        self.leaves.len().saturating_sub(1)
+    }
+
+    /// Returns `true` if the accumulator contains no serial numbers.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the given serial number is in the accumulator.
+    pub fn contains(&self, serial_number: &Field<N>) -> bool {
+        !serial_number.is_zero() && self.indices.contains_key(serial_number)
+    }
+
+    /// Adds the given serial numbers to the accumulator.
+    pub fn extend<'a>(&mut self, serial_numbers: impl IntoIterator<Item = &'a Field<N>>) -> Result<()> {
+        // Prepare the updated accumulator, so the accumulator is not altered in case of failure.
+        *self = self.extended(serial_numbers)?;
+        Ok(())
+    }
+
+    /// Returns the accumulator with the given serial numbers added, without altering this accumulator.
+    pub fn extended<'a>(&self, serial_numbers: impl IntoIterator<Item = &'a Field<N>>) -> Result<Self> {
+        // Prepare the updated leaves and indices.
+        let mut leaves = self.leaves.clone();
+        let mut indices = self.indices.clone();
+        // Track the existing leaves that are updated.
+        let mut updates = BTreeMap::new();
+
+        for serial_number in serial_numbers {
+            // Ensure the serial number is nonzero, as zero is reserved for the sentinel leaf.
+            ensure!(!serial_number.is_zero(), "Found a zero serial number");
+            // Ensure the serial number is new.
+            ensure!(!indices.contains_key(serial_number), "Serial number '{serial_number}' already exists");
+
+            // Retrieve the index of the low leaf (the sentinel guarantees it exists).
+            let (_, low_index) = indices
+                .range(..*serial_number)
+                .next_back()
+                .ok_or_else(|| anyhow!("Missing the low leaf for serial number '{serial_number}'"))?;
+            let low_index = *low_index;
+            // Retrieve the low leaf.
+            let low_leaf = leaves[low_index];
+
+            // Insert the new leaf, pointing to the successor of the low leaf.
+            let new_index = leaves.len();
+            leaves.push(SerialNumberLeaf::new_with_version(*serial_number, low_leaf.next_serial_number()));
+            indices.insert(*serial_number, new_index);
+
+            // Update the low leaf to point to the new serial number.
+            leaves[low_index] = SerialNumberLeaf::new_with_version(low_leaf.serial_number(), *serial_number);
+            // If the low leaf was already in the tree, record the update.
+            if low_index < self.leaves.len() {
+                updates.insert(low_index, leaves[low_index].to_bits_le());
+            }
+        }
+
+        // If there are no new leaves, return early.
+        if leaves.len() == self.leaves.len() {
+            return Ok(self.clone());
+        }
+
+        // Prepare the updated tree.
+        let mut tree = self.tree.clone();
+        // Update the existing leaves.
+        if !updates.is_empty() {
+            tree.update_many(&updates)?;
+        }
+        // Append the new leaves.
+        let new_leaves = leaves[self.leaves.len()..].iter().map(|leaf| leaf.to_bits_le()).collect::<Vec<_>>();
+        tree.append(&new_leaves)?;
+
+        // Return the updated accumulator.
+        Ok(Self { tree, leaves, indices })
+    }
+
+    /// Returns the `(leaf index, serial number leaf)` pairs that were added or updated since the `previous`
+    /// accumulator, which are the new leaves and the low leaves that now point to them.
+    pub fn changed_leaves(&self, previous: &Self) -> Result<Vec<(u32, SerialNumberLeaf<N>)>> {
+        let mut changed = BTreeMap::new();
+        for (index, leaf) in self.leaves.iter().enumerate().skip(previous.leaves.len()) {
+            changed.insert(index, *leaf);
+            // Retrieve the index of the low leaf.
+            if let Some((_, low_index)) = self.indices.range(..leaf.serial_number()).next_back() {
+                changed.insert(*low_index, self.leaves[*low_index]);
+            }
+        }
+        changed.into_iter().map(|(index, leaf)| Ok((u32::try_from(index)?, leaf))).collect()
+    }
+
+    /// Returns a non-membership path for the given serial number, at the current root.
+    pub fn prove_non_membership(&self, serial_number: &Field<N>) -> Result<NonMembershipPath<N>> {
+        // Ensure the serial number is not in the accumulator.
+        ensure!(!self.contains(serial_number), "Serial number '{serial_number}' is already spent");
+        // Ensure the serial number is nonzero.
+        ensure!(!serial_number.is_zero(), "Found a zero serial number");
+        // Retrieve the index of the low leaf.
+        let (_, low_index) = self
+            .indices
+            .range(..*serial_number)
+            .next_back()
+            .ok_or_else(|| anyhow!("Missing the low leaf for serial number '{serial_number}'"))?;
+        // Retrieve the low leaf.
+        let low_leaf = self.leaves[*low_index];
+        // Compute the Merkle path for the low leaf.
+        let path = self.tree.prove(*low_index, &low_leaf.to_bits_le())?;
+        // Return the non-membership path.
+        Ok(NonMembershipPath::new(path, low_leaf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_prove_non_membership() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the accumulator.
+        let mut accumulator = SerialNumberAccumulator::<CurrentNetwork>::new().unwrap();
+        assert!(accumulator.is_empty());
+
+        for i in 0..ITERATIONS {
+            // Sample the serial numbers.
+            let spent: Vec<Field<CurrentNetwork>> = (0..i + 1).map(|_| Uniform::rand(rng)).collect();
+            let unspent: Field<CurrentNetwork> = Uniform::rand(rng);
+
+            // Ensure the unspent serial number can be proven before the update.
+            let root = *accumulator.root();
+            let path = accumulator.prove_non_membership(&unspent).unwrap();
+            assert!(path.verify(&root, &unspent).is_ok());
+
+            // Add the spent serial numbers.
+            accumulator.extend(&spent).unwrap();
+            assert_ne!(root, *accumulator.root());
+
+            // Ensure the spent serial numbers cannot be proven.
+            for serial_number in &spent {
+                assert!(accumulator.contains(serial_number));
+                assert!(accumulator.prove_non_membership(serial_number).is_err());
+                // Ensure the stale path does not verify against the new root.
+                assert!(path.verify(accumulator.root(), serial_number).is_err());
+            }
+
+            // Ensure the unspent serial number can be proven at the new root.
+            let path = accumulator.prove_non_membership(&unspent).unwrap();
+            assert!(path.verify(accumulator.root(), &unspent).is_ok());
+            assert!(path.verify(&root, &unspent).is_err());
+        }
+    }
+
+    #[test]
+    fn test_extend_matches_sequential_inserts() {
+        let rng = &mut TestRng::default();
+
+        // Sample the serial numbers.
+        let serial_numbers: Vec<Field<CurrentNetwork>> = (0..ITERATIONS).map(|_| Uniform::rand(rng)).collect();
+
+        // Add the serial numbers in one batch.
+        let mut batched = SerialNumberAccumulator::<CurrentNetwork>::new().unwrap();
+        batched.extend(&serial_numbers).unwrap();
+
+        // Add the serial numbers one at a time.
+        let mut sequential = SerialNumberAccumulator::<CurrentNetwork>::new().unwrap();
+        for serial_number in &serial_numbers {
+            sequential.extend([serial_number]).unwrap();
+        }
+
+        assert_eq!(batched.root(), sequential.root());
+        assert_eq!(batched.len(), ITERATIONS);
+    }
+
+    #[test]
+    fn test_from_changed_leaves() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the accumulator, and the leaves as they would be stored.
+        let mut accumulator = SerialNumberAccumulator::<CurrentNetwork>::new().unwrap();
+        let mut stored = BTreeMap::new();
+
+        for i in 0..ITERATIONS {
+            // Add the serial numbers.
+            let serial_numbers: Vec<Field<CurrentNetwork>> = (0..i + 1).map(|_| Uniform::rand(rng)).collect();
+            let previous = accumulator.clone();
+            accumulator.extend(&serial_numbers).unwrap();
+
+            // Store the changed leaves.
+            stored.extend(accumulator.changed_leaves(&previous).unwrap());
+
+            // Ensure the accumulator is restored from the stored leaves.
+            let restored = SerialNumberAccumulator::from_leaves(stored.values().copied().collect()).unwrap();
+            assert_eq!(accumulator.root(), restored.root());
+            assert_eq!(accumulator.len(), restored.len());
+        }
+    }
+
+    #[test]
+    fn test_extend_rejects_duplicates() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the accumulator.
+        let mut accumulator = SerialNumberAccumulator::<CurrentNetwork>::new().unwrap();
+        let serial_number: Field<CurrentNetwork> = Uniform::rand(rng);
+        accumulator.extend([&serial_number]).unwrap();
+        let root = *accumulator.root();
+
+        // Ensure duplicates are rejected, and the accumulator is unchanged.
+        assert!(accumulator.extend([&serial_number]).is_err());
+        assert!(accumulator.extend([&Uniform::rand(rng), &Uniform::rand(rng), &serial_number]).is_err());
+        assert!(accumulator.extend([&Field::zero()]).is_err());
+        assert_eq!(root, *accumulator.root());
+        assert_eq!(accumulator.len(), 1);
+    }
+}
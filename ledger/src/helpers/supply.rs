@@ -13,9 +13,9 @@
 // limitations under the License.
 
 use console::network::Network;
-use ledger_block::Transactions;
+use ledger_block::{Block, Ratify, Transactions, MAX_COINBASE_REWARD};
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, ensure, Result};
 
 /// Returns the next total supply in microcredits, given the starting total supply and newly-confirmed transactions.
 pub fn update_total_supply<N: Network>(
@@ -27,9 +27,13 @@ pub fn update_total_supply<N: Network>(
     // Initialize the next total supply of microcredits.
     let mut next_total_supply = starting_total_supply_in_microcredits;
     // Add the block reward to the total supply.
-    next_total_supply = next_total_supply.saturating_add(block_reward);
+    next_total_supply = next_total_supply
+        .checked_add(block_reward)
+        .ok_or_else(|| anyhow!("The block reward overflows the total supply of microcredits"))?;
     // Add the puzzle reward to the total supply.
-    next_total_supply = next_total_supply.saturating_add(puzzle_reward);
+    next_total_supply = next_total_supply
+        .checked_add(puzzle_reward)
+        .ok_or_else(|| anyhow!("The puzzle reward overflows the total supply of microcredits"))?;
 
     // Iterate through the transactions to calculate the next total supply of microcredits.
     for confirmed in transactions.iter() {
@@ -53,3 +57,84 @@ pub fn update_total_supply<N: Network>(
     // Return the final total supply in microcredits.
     Ok(next_total_supply)
 }
+
+/// Returns the next total supply in microcredits, after applying the given block to the given total supply.
+///
+/// This method ensures the block conserves the supply: the genesis block must mint exactly the starting supply,
+/// and every later block may only mint the block reward and puzzle reward owed for its coinbase reward and fees.
+/// The rewards are counted in full, so the total supply is an upper bound on the credits in circulation,
+/// as any reward that is not distributed (due to rounding or ineligible stakers) is never minted.
+pub fn next_total_supply<N: Network>(total_supply_in_microcredits: u64, block: &Block<N>) -> Result<u64> {
+    let height = block.height();
+
+    // Retrieve the supply minted by the ratifications.
+    let (mut genesis_supply, mut block_reward, mut puzzle_reward) = (None, None, None);
+    for ratify in block.ratifications().iter() {
+        match ratify {
+            Ratify::Genesis(committee, public_balances) => {
+                // Ensure only the genesis block mints the starting supply.
+                ensure!(height == 0 && genesis_supply.is_none(), "Block {height} mints a genesis supply");
+                // Compute the supply held by the committee and the public balances.
+                let supply = public_balances
+                    .values()
+                    .try_fold(committee.total_stake(), |supply, balance| supply.checked_add(*balance))
+                    .ok_or_else(|| anyhow!("The genesis supply overflows"))?;
+                // Ensure the genesis supply is the starting supply.
+                ensure!(
+                    supply == N::STARTING_SUPPLY,
+                    "The genesis block mints {supply} microcredits, instead of {}",
+                    N::STARTING_SUPPLY
+                );
+                genesis_supply = Some(supply);
+            }
+            Ratify::BlockReward(reward) => {
+                ensure!(block_reward.replace(*reward).is_none(), "Block {height} has more than one block reward")
+            }
+            Ratify::PuzzleReward(reward) => {
+                ensure!(puzzle_reward.replace(*reward).is_none(), "Block {height} has more than one puzzle reward")
+            }
+            // The randomness beacon does not mint any supply.
+            Ratify::BeaconCommit(..) | Ratify::BeaconReveal(..) => {}
+        }
+    }
+
+    // Ensure the rewards match the coinbase reward and fees of the block.
+    match (block_reward, puzzle_reward) {
+        // Only the genesis block mints no rewards, as every later block must ratify its block and puzzle reward.
+        (None, None) => ensure!(height == 0, "Block {height} must have a block reward and a puzzle reward"),
+        (Some(block_reward), Some(puzzle_reward)) => {
+            // Ensure the puzzle reward is within the bound of the coinbase reward.
+            ensure!(
+                puzzle_reward <= MAX_COINBASE_REWARD / 2,
+                "Block {height} mints a puzzle reward of {puzzle_reward} microcredits, exceeding the coinbase reward"
+            );
+            // Compute the priority fees, which are paid to the stakers as part of the block reward.
+            let priority_fees = block.transactions().iter().try_fold(0u64, |fees, confirmed| {
+                fees.checked_add(*confirmed.priority_fee_amount()?)
+                    .ok_or_else(|| anyhow!("The priority fees of block {height} overflow"))
+            })?;
+            // Compute the expected block reward.
+            // Note: Half of the coinbase reward (rounded down) is the puzzle reward, and the other half is in
+            // the block reward, so the block reward is determined by the puzzle reward.
+            let expected_block_reward =
+                ledger_block::block_reward(N::STARTING_SUPPLY, N::BLOCK_TIME, puzzle_reward * 2, priority_fees);
+            ensure!(
+                block_reward == expected_block_reward,
+                "Block {height} mints a block reward of {block_reward} microcredits, instead of {expected_block_reward}"
+            );
+        }
+        _ => bail!("Block {height} must have both a block reward and a puzzle reward"),
+    }
+
+    // Add the genesis supply to the total supply.
+    let total_supply = total_supply_in_microcredits
+        .checked_add(genesis_supply.unwrap_or_default())
+        .ok_or_else(|| anyhow!("The genesis supply overflows the total supply of microcredits"))?;
+    // Add the rewards, and subtract the fees and the amount burned by each split.
+    update_total_supply(
+        total_supply,
+        block_reward.unwrap_or_default(),
+        puzzle_reward.unwrap_or_default(),
+        block.transactions(),
+    )
+}
@@ -0,0 +1,210 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Ledger;
+use console::network::prelude::*;
+use ledger_block::Block;
+use ledger_store::ConsensusStorage;
+
+use parking_lot::Mutex;
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+        Arc,
+    },
+    thread,
+};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A block that was verified ahead of time, with the IDs of its transactions that passed verification.
+type VerifiedBlock<N> = (Block<N>, HashSet<<N as Network>::TransactionID>);
+
+/// A sync engine that applies a stream of blocks to the ledger in three pipelined stages:
+///  1. Download - pulls the blocks from the given source, in order.
+///  2. Verify - verifies the transactions of several blocks in parallel, against the current ledger.
+///  3. Apply - checks and adds each block to the ledger, sequentially and in order.
+///
+/// The stages are connected by bounded queues, and at most `queue_capacity` blocks are in flight
+/// at any time, so a fast download stage waits for the verify and apply stages to catch up.
+///
+/// A transaction that was verified ahead of time is only checked for conflicts with the ledger
+/// when its block is applied. A transaction that failed verification (e.g. as it depends on
+/// a program deployed in an earlier block that was still in flight) is fully verified when applied.
+pub struct BlockSync<N: Network, C: ConsensusStorage<N>> {
+    /// The ledger.
+    ledger: Ledger<N, C>,
+    /// The maximum number of blocks in flight.
+    queue_capacity: usize,
+    /// The number of blocks verified in parallel.
+    num_verifiers: usize,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> BlockSync<N, C> {
+    /// The default maximum number of blocks in flight.
+    pub const DEFAULT_QUEUE_CAPACITY: usize = 32;
+
+    /// Initializes a new sync engine for the given ledger.
+    pub fn new(ledger: Ledger<N, C>) -> Self {
+        // By default, verify as many blocks in parallel as there are cores.
+        let num_verifiers = thread::available_parallelism().map(|n| n.get()).unwrap_or(1);
+        Self { ledger, queue_capacity: Self::DEFAULT_QUEUE_CAPACITY, num_verifiers }
+    }
+
+    /// Sets the maximum number of blocks in flight.
+    pub fn with_queue_capacity(mut self, queue_capacity: usize) -> Self {
+        self.queue_capacity = queue_capacity.max(1);
+        self
+    }
+
+    /// Sets the number of blocks verified in parallel.
+    pub fn with_num_verifiers(mut self, num_verifiers: usize) -> Self {
+        self.num_verifiers = num_verifiers.max(1);
+        self
+    }
+
+    /// Returns the ledger.
+    pub const fn ledger(&self) -> &Ledger<N, C> {
+        &self.ledger
+    }
+
+    /// Returns the maximum number of blocks in flight.
+    pub const fn queue_capacity(&self) -> usize {
+        self.queue_capacity
+    }
+
+    /// Returns the number of blocks verified in parallel.
+    pub const fn num_verifiers(&self) -> usize {
+        self.num_verifiers
+    }
+
+    /// Applies the given blocks to the ledger, in order, and returns the number of blocks applied.
+    ///
+    /// On the first invalid block or source error, the sync stops and returns the error.
+    /// The blocks before it remain applied to the ledger.
+    pub fn run<I>(&self, blocks: I) -> Result<u32>
+    where
+        I: IntoIterator<Item = Result<Block<N>>>,
+        I::IntoIter: Send,
+    {
+        let blocks = blocks.into_iter();
+        // Initialize a flag to stop the download and verify stages, once the apply stage has stopped.
+        let is_stopped = AtomicBool::new(false);
+
+        thread::scope(|scope| {
+            let is_stopped = &is_stopped;
+
+            // Initialize the queue from the download stage to the verify stage.
+            let (download_sender, download_receiver) = mpsc::sync_channel(self.queue_capacity);
+            let download_receiver = Arc::new(Mutex::new(download_receiver));
+            // Initialize the queue from the verify stage to the apply stage.
+            let (verified_sender, verified_receiver) = mpsc::sync_channel(self.queue_capacity);
+            // Initialize the permits, which bound the number of blocks in flight.
+            let (permit_sender, permit_receiver) = mpsc::sync_channel(self.queue_capacity);
+            for _ in 0..self.queue_capacity {
+                permit_sender.send(())?;
+            }
+
+            // Start the download stage.
+            scope.spawn(move || {
+                for (index, block) in blocks.enumerate() {
+                    // Wait until fewer than `queue_capacity` blocks are in flight.
+                    if permit_receiver.recv().is_err() || is_stopped.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    // Note: A source error is passed along, to be returned in order by the apply stage.
+                    let is_err = block.is_err();
+                    if download_sender.send((index, block)).is_err() || is_err {
+                        break;
+                    }
+                }
+            });
+
+            // Start the verify stage.
+            for _ in 0..self.num_verifiers {
+                let download_receiver = download_receiver.clone();
+                let verified_sender = verified_sender.clone();
+                scope.spawn(move || {
+                    loop {
+                        // Retrieve the next block, or stop if the download stage has stopped.
+                        // Note: The lock is released before the block is verified.
+                        let next = download_receiver.lock().recv();
+                        let Ok((index, block)) = next else {
+                            break;
+                        };
+                        if is_stopped.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        let block = block.map(|block| {
+                            let verified_transaction_ids = self.verify_transactions(&block);
+                            (block, verified_transaction_ids)
+                        });
+                        // Pass the block along, or stop if the apply stage has stopped.
+                        if verified_sender.send((index, block)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            drop(download_receiver);
+            drop(verified_sender);
+
+            // Run the apply stage.
+            let result = self.apply_blocks(verified_receiver, permit_sender);
+            is_stopped.store(true, Ordering::Relaxed);
+            result
+        })
+    }
+
+    /// Checks and adds the verified blocks to the ledger, in order, and returns the number of blocks applied.
+    fn apply_blocks(
+        &self,
+        verified_receiver: mpsc::Receiver<(usize, Result<VerifiedBlock<N>>)>,
+        permit_sender: mpsc::SyncSender<()>,
+    ) -> Result<u32> {
+        // Note: The verify stage may finish blocks out of order, so they are buffered until their turn.
+        let mut pending = BTreeMap::new();
+        let mut next_index = 0;
+        let mut num_applied = 0u32;
+        for (index, block) in verified_receiver.iter() {
+            pending.insert(index, block);
+            while let Some(block) = pending.remove(&next_index) {
+                let (block, verified_transaction_ids) = block?;
+                // Check and add the block to the ledger.
+                self.ledger.check_next_block_with_verified(&block, &verified_transaction_ids)?;
+                self.ledger.advance_to_next_block(&block)?;
+                next_index += 1;
+                num_applied += 1;
+                // Release a permit, as the block has left the pipeline.
+                let _ = permit_sender.try_send(());
+            }
+        }
+        Ok(num_applied)
+    }
+
+    /// Returns the IDs of the transactions in the given block that pass verification against the current ledger.
+    fn verify_transactions(&self, block: &Block<N>) -> HashSet<N::TransactionID> {
+        // TODO: this intermediate allocation shouldn't be necessary; this is most likely https://github.com/rust-lang/rust/issues/89418.
+        let transactions = block.transactions().iter().collect::<Vec<_>>();
+        cfg_iter!(transactions)
+            .filter_map(|transaction| {
+                let rejected_id = transaction.to_rejected_id().ok()?;
+                self.ledger.check_transaction_basic(transaction, rejected_id).ok().map(|_| transaction.id())
+            })
+            .collect()
+    }
+}
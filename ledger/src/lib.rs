@@ -35,12 +35,20 @@ mod helpers;
 pub use helpers::*;
 
 mod advance;
+mod attestation;
+mod beacon;
 mod check_next_block;
 mod check_transaction_basic;
+mod checkpoint;
 mod contains;
 mod find;
 mod get;
 mod iterators;
+mod non_membership;
+mod simulate;
+
+#[cfg(any(test, feature = "test-helpers"))]
+pub mod test_helpers;
 
 #[cfg(test)]
 mod tests;
@@ -48,7 +56,7 @@ mod tests;
 use console::{
     account::{Address, GraphKey, PrivateKey, ViewKey},
     network::prelude::*,
-    program::{Ciphertext, Entry, Identifier, Literal, Plaintext, ProgramID, Record, StatePath, Value},
+    program::{Ciphertext, Entry, Identifier, InputID, Literal, Plaintext, ProgramID, Record, StatePath, Value},
     types::{Field, Group},
 };
 use ledger_authority::Authority;
@@ -57,19 +65,20 @@ use ledger_coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, ProverSo
 use ledger_committee::Committee;
 use ledger_narwhal::{BatchCertificate, Subdag, Transmission, TransmissionID};
 use ledger_query::Query;
-use ledger_store::{ConsensusStorage, ConsensusStore};
+use ledger_store::{atomic_batch_scope, ConsensusStorage, ConsensusStore};
 use synthesizer::{
+    process::{Authorization, VmConfig},
     program::{FinalizeGlobalState, Program},
-    vm::VM,
+    vm::{Simulation, VM},
 };
 
 use aleo_std::prelude::{finish, lap, timer};
 use anyhow::Result;
-use core::ops::Range;
+use core::ops::{Range, RangeInclusive};
 use indexmap::IndexMap;
 use parking_lot::RwLock;
 use rand::{prelude::IteratorRandom, rngs::OsRng};
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, collections::HashSet, sync::Arc};
 use time::OffsetDateTime;
 
 #[cfg(not(feature = "serial"))]
@@ -105,9 +114,35 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     current_committee: Arc<RwLock<Option<Committee<N>>>>,
     /// The current block.
     current_block: Arc<RwLock<Block<N>>>,
+    /// The serial numbers accumulator.
+    serial_numbers: Arc<RwLock<SerialNumberAccumulator<N>>>,
+    /// The total supply in microcredits.
+    total_supply: Arc<RwLock<u64>>,
+    /// The range of block heights that were added from a checkpoint, and are not yet verified.
+    unverified_heights: Arc<RwLock<Option<RangeInclusive<u32>>>>,
+    /// The metrics recorder.
+    metrics: MetricsRecorder,
+    /// The commit-reveal randomness beacon.
+    beacon: Arc<RwLock<RandomnessBeacon<N>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Loads the ledger from the storage selected by the given settings.
+    /// Note: This sets the configured data directory as the root of the storage, for the whole process.
+    pub fn load_with_config(genesis_block: Block<N>, config: &VmConfig) -> Result<Self> {
+        // Ensure the ledger network matches the settings.
+        config.ensure_network::<N>()?;
+        // Use the configured data directory for the ledger storage.
+        config.apply_directories();
+        // Load the ledger.
+        let ledger = Self::load(genesis_block, config.dev())?;
+        // If the archive mode is enabled, build the secondary indexes.
+        if config.archive() {
+            ledger.enable_indexes()?;
+        }
+        Ok(ledger)
+    }
+
     /// Loads the ledger from storage.
     pub fn load(genesis_block: Block<N>, dev: Option<u16>) -> Result<Self> {
         let timer = timer!("Ledger::load");
@@ -156,6 +191,18 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         // Retrieve the current committee.
         let current_committee = vm.finalize_store().committee_store().current_committee().ok();
+        // Retrieve the range of blocks that were added from a checkpoint, and are not yet verified.
+        let unverified_heights = vm.finalize_store().ledger_state_store().unverified_heights()?;
+        // Restore the serial numbers accumulator.
+        let serial_numbers =
+            SerialNumberAccumulator::from_leaves(vm.finalize_store().ledger_state_store().serial_number_leaves()?)?;
+        // Retrieve the total supply.
+        let total_supply = vm.finalize_store().ledger_state_store().total_supply()?;
+        // Restore the randomness beacon.
+        let beacon = match vm.finalize_store().ledger_state_store().beacon_state()? {
+            Some(state) => RandomnessBeacon::from_state(state),
+            None => RandomnessBeacon::new(0),
+        };
 
         // Initialize the ledger.
         let mut ledger = Self {
@@ -165,10 +212,16 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             current_epoch_challenge: Default::default(),
             current_committee: Arc::new(RwLock::new(current_committee)),
             current_block: Arc::new(RwLock::new(genesis_block.clone())),
+            serial_numbers: Arc::new(RwLock::new(serial_numbers)),
+            total_supply: Arc::new(RwLock::new(total_supply)),
+            unverified_heights: Arc::new(RwLock::new(unverified_heights)),
+            metrics: MetricsRecorder::new(),
+            beacon: Arc::new(RwLock::new(beacon)),
         };
 
         // If the block store is empty, initialize the genesis block.
-        if ledger.vm.block_store().heights().max().is_none() {
+        let is_new_ledger = ledger.vm.block_store().heights().max().is_none();
+        if is_new_ledger {
             // Add the genesis block.
             ledger.advance_to_next_block(&genesis_block)?;
         }
@@ -184,6 +237,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         // Set the current block.
         ledger.current_block = Arc::new(RwLock::new(block));
+        ledger.metrics.set_latest_height(latest_height);
         // Set the current committee (and ensures the latest committee exists).
         ledger.current_committee = Arc::new(RwLock::new(Some(ledger.latest_committee()?)));
         // Set the current epoch challenge.
@@ -193,11 +247,43 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         Ok(ledger)
     }
 
+    /// Enables the archive mode, by building the secondary indexes over the blocks in the ledger.
+    /// The indexes are then stored as blocks are added to the ledger.
+    pub fn enable_indexes(&self) -> Result<()> {
+        // Acquire the read lock on the current block, so the ledger does not advance while indexing.
+        let current_block = self.current_block.read();
+        // If the indexes are already enabled, return early.
+        let store = self.vm.finalize_store().ledger_state_store();
+        if store.is_indexed()? {
+            return Ok(());
+        }
+        // Index the blocks in block order.
+        let mut indexes = LedgerIndexes::new();
+        for height in 0..=current_block.height() {
+            indexes.add_block(&self.get_block(height)?);
+        }
+        // Store the indexes, and enable the archive mode, in the same atomic batch.
+        atomic_batch_scope!(store, {
+            indexes.store(store)?;
+            store.enable_indexes()
+        })
+    }
+
+    /// Returns `true` if the archive mode is enabled, and the secondary indexes are available.
+    pub fn is_indexed(&self) -> Result<bool> {
+        self.vm.finalize_store().ledger_state_store().is_indexed()
+    }
+
     /// Returns the VM.
     pub const fn vm(&self) -> &VM<N, C> {
         &self.vm
     }
 
+    /// Returns the metrics recorder, which is shared with the subsystems that update the metrics.
+    pub const fn metrics(&self) -> &MetricsRecorder {
+        &self.metrics
+    }
+
     /// Returns the coinbase puzzle.
     pub const fn coinbase_puzzle(&self) -> &CoinbasePuzzle<N> {
         &self.coinbase_puzzle
@@ -216,6 +302,16 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.block_store().current_state_root()
     }
 
+    /// Returns the latest serial numbers root.
+    pub fn latest_serial_numbers_root(&self) -> Field<N> {
+        *self.serial_numbers.read().root()
+    }
+
+    /// Returns the total supply in microcredits, as of the latest block.
+    pub fn total_supply(&self) -> u64 {
+        *self.total_supply.read()
+    }
+
     /// Returns the latest epoch number.
     pub fn latest_epoch_number(&self) -> u32 {
         self.current_block.read().height() / N::NUM_BLOCKS_PER_EPOCH
@@ -382,58 +478,3 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         )
     }
 }
-
-#[cfg(test)]
-pub(crate) mod test_helpers {
-    use crate::Ledger;
-    use console::{
-        account::{Address, PrivateKey, ViewKey},
-        network::Testnet3,
-        prelude::*,
-    };
-    use ledger_block::Block;
-    use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
-    use synthesizer::vm::VM;
-
-    pub(crate) type CurrentNetwork = Testnet3;
-    pub(crate) type CurrentLedger = Ledger<CurrentNetwork, ConsensusMemory<CurrentNetwork>>;
-
-    #[allow(dead_code)]
-    pub(crate) struct TestEnv {
-        pub ledger: CurrentLedger,
-        pub private_key: PrivateKey<CurrentNetwork>,
-        pub view_key: ViewKey<CurrentNetwork>,
-        pub address: Address<CurrentNetwork>,
-    }
-
-    pub(crate) fn sample_test_env(rng: &mut (impl Rng + CryptoRng)) -> TestEnv {
-        // Sample the genesis private key.
-        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
-        let view_key = ViewKey::try_from(&private_key).unwrap();
-        let address = Address::try_from(&private_key).unwrap();
-        // Sample the ledger.
-        let ledger = sample_ledger(private_key, rng);
-        // Return the test environment.
-        TestEnv { ledger, private_key, view_key, address }
-    }
-
-    pub(crate) fn sample_genesis_block() -> Block<CurrentNetwork> {
-        Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap()
-    }
-
-    pub(crate) fn sample_ledger(
-        private_key: PrivateKey<CurrentNetwork>,
-        rng: &mut (impl Rng + CryptoRng),
-    ) -> CurrentLedger {
-        // Initialize the store.
-        let store = ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap();
-        // Create a genesis block.
-        let genesis = VM::from(store).unwrap().genesis_beacon(&private_key, rng).unwrap();
-        // Initialize the ledger with the genesis block.
-        let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
-        // Ensure the genesis block is correct.
-        assert_eq!(genesis, ledger.get_block(0).unwrap());
-        // Return the ledger.
-        ledger
-    }
-}
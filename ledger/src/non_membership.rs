@@ -0,0 +1,35 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::NonMembershipPath;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the latest serial numbers root, along with a non-membership path for each serial number
+    /// in the given transaction (in order), proving the transaction does not spend a spent record.
+    ///
+    /// The paths may be checked statelessly with `Transaction::verify_non_membership`.
+    pub fn prove_non_membership(&self, transaction: &Transaction<N>) -> Result<(Field<N>, Vec<NonMembershipPath<N>>)> {
+        // Acquire the read lock on the serial numbers accumulator.
+        let serial_numbers = self.serial_numbers.read();
+        // Compute the non-membership path for each serial number.
+        let paths = transaction
+            .serial_numbers()
+            .map(|serial_number| serial_numbers.prove_non_membership(serial_number))
+            .collect::<Result<Vec<_>>>()?;
+        // Return the serial numbers root and the paths.
+        Ok((*serial_numbers.root(), paths))
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Simulates the given authorization against the current ledger state, without computing a proof.
+    ///
+    /// Returns the outputs of the call and the finalize operations it would apply, as if the
+    /// execution were included in the next block, or an error if it would fail.
+    pub fn simulate(&self, authorization: &Authorization<N>) -> Result<Simulation<N>> {
+        // Acquire the read lock on the current block, so the ledger does not advance during the simulation.
+        let latest_block = self.current_block.read();
+
+        // Ensure the records spent by the authorization exist, and are unspent.
+        for request in authorization.to_vec_deque() {
+            for input_id in request.input_ids() {
+                if let InputID::Record(commitment, _, serial_number, _) = input_id {
+                    ensure!(
+                        self.contains_commitment(commitment)?,
+                        "Record '{commitment}' does not exist in the ledger"
+                    );
+                    ensure!(!self.contains_serial_number(serial_number)?, "Record '{commitment}' is already spent");
+                }
+            }
+        }
+
+        // Construct the finalize state of the next block.
+        let state = FinalizeGlobalState::new::<N>(
+            latest_block.round().saturating_add(1),
+            latest_block.height().saturating_add(1),
+            latest_block.cumulative_weight(),
+            latest_block.cumulative_proof_target(),
+            latest_block.hash(),
+        )?
+        .with_beacon_output(self.vm.beacon_output()?)?;
+        // Simulate the authorization.
+        self.vm.simulate(state, authorization)
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{CurrentLedger, CurrentNetwork};
+use console::{
+    account::{Address, PrivateKey},
+    prelude::*,
+    program::{ProgramID, Value},
+};
+use ledger_block::{Block, Transaction};
+use ledger_store::ConsensusStore;
+use synthesizer::{program::Program, vm::VM};
+
+/// The program called by the multi-transition executions.
+const CHILD_PROGRAM: &str = r"
+program fixture_child.aleo;
+
+function double:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;";
+
+/// The program of the multi-transition executions, which calls the child program twice.
+const PARENT_PROGRAM: &str = r"
+import fixture_child.aleo;
+
+program fixture_parent.aleo;
+
+function quadruple:
+    input r0 as u64.private;
+    call fixture_child.aleo/double r0 into r1;
+    call fixture_child.aleo/double r1 into r2;
+    output r2 as u64.private;";
+
+/// The number of each kind of transaction in a generated block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TransactionMix {
+    /// The number of program deployments.
+    pub deployments: usize,
+    /// The number of public transfers of credits.
+    pub transfers: usize,
+    /// The number of executions of a function that calls another program, i.e. with three transitions.
+    pub multi_transition_executions: usize,
+}
+
+impl Default for TransactionMix {
+    /// Returns a mix of one transfer per block.
+    fn default() -> Self {
+        Self { deployments: 0, transfers: 1, multi_transition_executions: 0 }
+    }
+}
+
+impl TransactionMix {
+    /// Returns the number of transactions in a block.
+    pub const fn num_transactions(&self) -> usize {
+        self.deployments + self.transfers + self.multi_transition_executions
+    }
+}
+
+/// A chain of valid blocks with a mix of transactions, generated from a seed, for integration tests.
+///
+/// The accounts, programs, and transactions are derived from the seed, so the same seed generates the
+/// same sequence of transactions. Note that the block timestamps are taken from the clock.
+pub struct ChainFixture {
+    /// The private key of the genesis account, which pays for every transaction.
+    private_key: PrivateKey<CurrentNetwork>,
+    /// The genesis block.
+    genesis_block: Block<CurrentNetwork>,
+    /// The blocks after the genesis block, in order.
+    blocks: Vec<Block<CurrentNetwork>>,
+    /// The ledger, with every block added.
+    ledger: CurrentLedger,
+}
+
+impl ChainFixture {
+    /// Generates a chain of the given number of blocks after the genesis block, where each block has
+    /// the given mix of transactions.
+    ///
+    /// If the mix includes multi-transition executions, the chain starts with two more blocks,
+    /// which deploy the programs of the executions.
+    pub fn generate(seed: u64, num_blocks: u32, mix: TransactionMix) -> Result<Self> {
+        ensure!(mix.num_transactions() > 0, "Cannot generate blocks without transactions");
+        let rng = &mut TestRng::fixed(seed);
+
+        // Initialize the genesis account and the ledger.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let genesis_block = VM::from(ConsensusStore::open(None)?)?.genesis_beacon(&private_key, rng)?;
+        let ledger = CurrentLedger::load(genesis_block.clone(), None)?;
+        let mut fixture = Self { private_key, genesis_block, blocks: Vec::new(), ledger };
+
+        // Deploy the programs of the multi-transition executions.
+        if mix.multi_transition_executions > 0 {
+            for program in [CHILD_PROGRAM, PARENT_PROGRAM] {
+                let transaction = fixture.deploy(&Program::from_str(program)?, rng)?;
+                fixture.add_block(vec![transaction], rng)?;
+            }
+        }
+
+        // Generate the blocks.
+        for _ in 0..num_blocks {
+            let height = fixture.ledger.latest_height() + 1;
+            let mut transactions = Vec::with_capacity(mix.num_transactions());
+            for index in 0..mix.deployments {
+                let program = Program::from_str(&format!(
+                    r"
+program fixture_{height}_{index}.aleo;
+
+function main:
+    input r0 as u64.private;
+    add r0 1u64 into r1;
+    output r1 as u64.private;"
+                ))?;
+                transactions.push(fixture.deploy(&program, rng)?);
+            }
+            for _ in 0..mix.transfers {
+                let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+                let inputs = [Value::from_str(&recipient.to_string())?, Value::from_str("1u64")?];
+                transactions.push(fixture.execute("credits.aleo", "transfer_public", inputs, rng)?);
+            }
+            for _ in 0..mix.multi_transition_executions {
+                let inputs = [Value::from_str(&format!("{}u64", rng.gen_range(0..1_000_000u64)))?];
+                transactions.push(fixture.execute("fixture_parent.aleo", "quadruple", inputs, rng)?);
+            }
+            fixture.add_block(transactions, rng)?;
+        }
+        Ok(fixture)
+    }
+
+    /// Returns the private key of the genesis account, which pays for every transaction.
+    pub const fn private_key(&self) -> &PrivateKey<CurrentNetwork> {
+        &self.private_key
+    }
+
+    /// Returns the genesis block.
+    pub const fn genesis_block(&self) -> &Block<CurrentNetwork> {
+        &self.genesis_block
+    }
+
+    /// Returns the blocks after the genesis block, in order.
+    pub fn blocks(&self) -> &[Block<CurrentNetwork>] {
+        &self.blocks
+    }
+
+    /// Returns the ledger, with every block added.
+    pub const fn ledger(&self) -> &CurrentLedger {
+        &self.ledger
+    }
+
+    /// Returns a deployment of the given program, paid for by the genesis account.
+    fn deploy(&self, program: &Program<CurrentNetwork>, rng: &mut TestRng) -> Result<Transaction<CurrentNetwork>> {
+        self.ledger.vm.deploy(&self.private_key, program, None, 0, None, rng)
+    }
+
+    /// Returns an execution of the given function, paid for by the genesis account.
+    fn execute<const NUM_INPUTS: usize>(
+        &self,
+        program_id: &str,
+        function_name: &str,
+        inputs: [Value<CurrentNetwork>; NUM_INPUTS],
+        rng: &mut TestRng,
+    ) -> Result<Transaction<CurrentNetwork>> {
+        let program_id = ProgramID::<CurrentNetwork>::from_str(program_id)?;
+        self.ledger.vm.execute(&self.private_key, (program_id, function_name), inputs.into_iter(), None, 0, None, rng)
+    }
+
+    /// Adds a block with the given transactions to the ledger, and ensures every transaction is accepted.
+    fn add_block(&mut self, transactions: Vec<Transaction<CurrentNetwork>>, rng: &mut TestRng) -> Result<()> {
+        let num_transactions = transactions.len();
+        let block =
+            self.ledger.prepare_advance_to_next_beacon_block(&self.private_key, vec![], vec![], transactions, rng)?;
+        ensure!(
+            block.transactions().num_accepted() == num_transactions,
+            "Block {} did not accept all of its transactions",
+            block.height()
+        );
+        self.ledger.check_next_block(&block)?;
+        self.ledger.advance_to_next_block(&block)?;
+        self.blocks.push(block);
+        Ok(())
+    }
+}
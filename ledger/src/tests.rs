@@ -17,11 +17,12 @@ use crate::{
     RecordsFilter,
 };
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, PrivateKey, Signature},
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
+    types::Field,
 };
-use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
+use ledger_block::{ConfirmedTransaction, Ratify, Rejected, Transaction};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
 use synthesizer::{program::Program, vm::VM};
 
@@ -517,3 +518,499 @@ fn test_bond_and_unbond_validator() {
     let committee = ledger.latest_committee().unwrap();
     assert!(!committee.is_committee_member(new_member_address));
 }
+
+#[test]
+fn test_non_membership() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Fetch an unspent record.
+    let record = ledger.find_unspent_credits_records(&view_key).unwrap().values().next().unwrap().clone();
+
+    // Prepare a `split` execution without a fee.
+    let inputs = [Value::Record(record), Value::from_str("100u64").unwrap()];
+    let authorization = ledger.vm.authorize(&private_key, "credits.aleo", "split", inputs.into_iter(), rng).unwrap();
+    let transaction = ledger.vm.execute_authorization(authorization, None, None, rng).unwrap();
+
+    // Ensure the serial numbers are proven to be unspent at the latest root.
+    let (root, paths) = ledger.prove_non_membership(&transaction).unwrap();
+    assert_eq!(root, ledger.latest_serial_numbers_root());
+    assert_eq!(paths.len(), transaction.serial_numbers().count());
+    transaction.verify_non_membership(&root, &paths).unwrap();
+    // Ensure missing paths are rejected.
+    assert!(transaction.verify_non_membership(&root, &paths[1..]).is_err());
+
+    // Add the transaction to the ledger.
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction.clone()], rng)
+        .unwrap();
+    ledger.check_next_block(&block).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the serial numbers can no longer be proven to be unspent.
+    assert_ne!(root, ledger.latest_serial_numbers_root());
+    assert!(ledger.prove_non_membership(&transaction).is_err());
+    // Ensure the previous paths do not verify against the latest root.
+    assert!(transaction.verify_non_membership(&ledger.latest_serial_numbers_root(), &paths).is_err());
+}
+
+#[test]
+fn test_block_sync() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Deploy a test program, and execute it in later blocks, so the executions depend on an earlier block.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("dummy_program.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+function foo:
+    input r0 as u8.private;
+    async foo r0 into r1;
+    output r1 as {program_id}/foo.future;
+finalize foo:
+    input r0 as u8.public;
+    add r0 r0 into r1;",
+    ))
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    for _ in 0..2 {
+        let inputs = [Value::<CurrentNetwork>::from_str("1u8").unwrap()];
+        let transaction =
+            ledger.vm.execute(&private_key, (program_id, "foo"), inputs.into_iter(), None, 0, None, rng).unwrap();
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    let genesis = ledger.get_block(0).unwrap();
+    let blocks = (1..=3).map(|height| ledger.get_block(height).unwrap()).collect::<Vec<_>>();
+
+    // Sync the blocks into a new ledger.
+    let synced_ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+    let sync = crate::BlockSync::new(synced_ledger.clone()).with_queue_capacity(2).with_num_verifiers(2);
+    assert_eq!(sync.run(blocks.iter().cloned().map(Ok)).unwrap(), 3);
+    assert_eq!(synced_ledger.latest_hash(), ledger.latest_hash());
+    assert_eq!(synced_ledger.latest_state_root(), ledger.latest_state_root());
+    assert_eq!(synced_ledger.get_program(program_id).unwrap(), program);
+    // Ensure the metrics track the synced blocks.
+    let metrics = synced_ledger.metrics().snapshot();
+    assert_eq!(metrics.latest_height, 3);
+    assert_eq!(metrics.verification_latency.count, 3);
+    // Ensure syncing the same blocks again fails.
+    assert!(sync.run(blocks.iter().cloned().map(Ok)).is_err());
+    assert_eq!(synced_ledger.latest_height(), 3);
+
+    // Ensure an out-of-order block stops the sync, and the earlier blocks remain applied.
+    let synced_ledger = CurrentLedger::load(genesis, None).unwrap();
+    let sync = crate::BlockSync::new(synced_ledger.clone());
+    let out_of_order = [&blocks[0], &blocks[2], &blocks[1]];
+    assert!(sync.run(out_of_order.into_iter().cloned().map(Ok)).is_err());
+    assert_eq!(synced_ledger.latest_height(), 1);
+
+    // Ensure a source error stops the sync, and the earlier blocks remain applied.
+    let source = [Ok(blocks[1].clone()), Err(anyhow!("Failed to download the block")), Ok(blocks[2].clone())];
+    assert!(sync.run(source).is_err());
+    assert_eq!(synced_ledger.latest_height(), 2);
+}
+
+#[test]
+fn test_checkpoint_sync() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Add blocks with a deployment and an execution.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("dummy_program.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+function foo:
+    input r0 as u8.private;
+    async foo r0 into r1;
+    output r1 as {program_id}/foo.future;
+finalize foo:
+    input r0 as u8.public;
+    add r0 r0 into r1;",
+    ))
+    .unwrap();
+    let transaction = ledger.vm.deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("1u8").unwrap()];
+    let transaction =
+        ledger.vm.execute(&private_key, (program_id, "foo"), inputs.into_iter(), None, 0, None, rng).unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    let genesis = ledger.get_block(0).unwrap();
+    let blocks = (1..=2).map(|height| ledger.get_block(height).unwrap()).collect::<Vec<_>>();
+
+    // Sign a checkpoint at the latest block.
+    let checkpoint =
+        crate::Checkpoint::new(&private_key, 2, ledger.latest_hash(), ledger.latest_state_root(), rng).unwrap();
+
+    // Ensure a checkpoint from an untrusted signer is rejected.
+    let synced_ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+    let other = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    assert!(synced_ledger.advance_to_checkpoint(&checkpoint, &[other], &blocks).is_err());
+    // Ensure blocks that do not reach the checkpoint are rejected.
+    assert!(synced_ledger.advance_to_checkpoint(&checkpoint, &[address], &blocks[..1]).is_err());
+    let wrong_checkpoint =
+        crate::Checkpoint::new(&private_key, 2, genesis.hash(), ledger.latest_state_root(), rng).unwrap();
+    assert!(synced_ledger.advance_to_checkpoint(&wrong_checkpoint, &[address], &blocks).is_err());
+    assert_eq!(synced_ledger.latest_height(), 0);
+    assert!(synced_ledger.is_fully_verified());
+
+    // Advance to the checkpoint.
+    synced_ledger.advance_to_checkpoint(&checkpoint, &[address], &blocks).unwrap();
+    assert_eq!(synced_ledger.latest_hash(), ledger.latest_hash());
+    assert_eq!(synced_ledger.get_program(program_id).unwrap(), program);
+    assert_eq!(synced_ledger.unverified_heights(), Some(1..=2));
+    assert!(!synced_ledger.is_fully_verified());
+    // Ensure the unverified heights are stored.
+    let ledger_state_store = synced_ledger.vm.finalize_store().ledger_state_store();
+    assert_eq!(ledger_state_store.unverified_heights().unwrap(), Some(1..=2));
+
+    // Verify the history in the background, which includes the deployment.
+    synced_ledger.spawn_history_verification().join().unwrap().unwrap();
+    assert_eq!(synced_ledger.unverified_heights(), None);
+    assert!(synced_ledger.is_fully_verified());
+    assert_eq!(ledger_state_store.unverified_heights().unwrap(), None);
+}
+
+#[test]
+fn test_chain_fixture() {
+    let mix = crate::test_helpers::TransactionMix { deployments: 1, transfers: 1, multi_transition_executions: 1 };
+    let fixture = crate::test_helpers::ChainFixture::generate(7, 1, mix).unwrap();
+
+    // Ensure the chain includes the two blocks that deploy the programs of the multi-transition executions.
+    assert_eq!(fixture.blocks().len(), 3);
+    assert_eq!(fixture.ledger().latest_height(), 3);
+    let block = fixture.blocks().last().unwrap();
+    assert_eq!(block.transactions().num_accepted(), mix.num_transactions());
+    assert!(block.transactions().iter().any(|transaction| transaction.transitions().count() == 3));
+
+    // Ensure the chain is valid in a new ledger.
+    let ledger = CurrentLedger::load(fixture.genesis_block().clone(), None).unwrap();
+    for block in fixture.blocks() {
+        ledger.check_next_block(block).unwrap();
+        ledger.advance_to_next_block(block).unwrap();
+    }
+    assert_eq!(ledger.latest_hash(), fixture.ledger().latest_hash());
+
+    // Ensure the same seed generates the same accounts.
+    let other =
+        crate::test_helpers::ChainFixture::generate(7, 1, crate::test_helpers::TransactionMix::default()).unwrap();
+    assert_eq!(other.private_key(), fixture.private_key());
+}
+
+#[test]
+fn test_simulate() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // A helper function to retrieve the public balance of the caller.
+    let balance = || {
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(address));
+        ledger.vm().finalize_store().get_value_confirmed(program_id, mapping_name, &key).unwrap()
+    };
+    let initial_balance = balance();
+
+    // Authorize a public transfer.
+    let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let authorization =
+        ledger.vm().authorize(&private_key, "credits.aleo", "transfer_public", inputs.iter(), rng).unwrap();
+
+    // Simulate the transfer, and ensure it updates the balances of the caller and the recipient.
+    let simulation = ledger.simulate(&authorization).unwrap();
+    assert_eq!(simulation.outputs().len(), 1);
+    assert_eq!(simulation.finalize_operations().len(), 2);
+    // Ensure the simulation did not change the ledger state.
+    assert_eq!(balance(), initial_balance);
+
+    // Ensure the authorization can still be executed after the simulation.
+    assert!(ledger.vm().execute_authorization(authorization, None, None, rng).is_ok());
+
+    // Ensure a transfer that exceeds the balance of the caller fails the simulation.
+    let inputs =
+        [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str(&format!("{}u64", u64::MAX)).unwrap()];
+    let authorization =
+        ledger.vm().authorize(&private_key, "credits.aleo", "transfer_public", inputs.iter(), rng).unwrap();
+    assert!(ledger.simulate(&authorization).is_err());
+}
+
+#[test]
+fn test_program_stats() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Deploy a test program to the ledger, paying the fee with a record.
+    let program_id = ProgramID::<CurrentNetwork>::from_str("test_program_stats.aleo").unwrap();
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        "
+program {program_id};
+
+function hello:
+    input r0 as u32.public;
+    output r0 as u32.private;"
+    ))
+    .unwrap();
+    assert_eq!(ledger.get_program_stats(&program_id).unwrap(), None);
+
+    let microcredits = Identifier::from_str("microcredits").unwrap();
+    let (_, record) = ledger
+        .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))
+        .unwrap()
+        .find(|(_, record)| match record.data().get(&microcredits) {
+            Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => !amount.is_zero(),
+            _ => false,
+        })
+        .unwrap();
+    let deployment = ledger.vm().deploy(&private_key, &program, Some(record), 0, None, rng).unwrap();
+    let deployment_fee = *deployment.fee_amount().unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![deployment], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the deployment is counted, without a caller, as the fee was private.
+    let stats = ledger.get_program_stats(&program_id).unwrap().unwrap();
+    assert_eq!(stats.deployments, 1);
+    assert_eq!(stats.executions, 0);
+    assert_eq!(stats.unique_callers, 0);
+    assert_eq!(stats.fees_paid, deployment_fee);
+
+    // Execute the program twice, paying the fees from the public balance.
+    let executions = (0..2)
+        .map(|_| {
+            let inputs = [Value::<CurrentNetwork>::from_str("1u32").unwrap()];
+            ledger.vm().execute(&private_key, (program_id, "hello"), inputs.iter(), None, 0, None, rng).unwrap()
+        })
+        .collect::<Vec<_>>();
+    let execution_fees = executions.iter().map(|transaction| *transaction.fee_amount().unwrap()).sum::<u64>();
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], executions, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the executions are counted, with a single caller.
+    let stats = ledger.get_program_stats(&program_id).unwrap().unwrap();
+    assert_eq!(stats.deployments, 1);
+    assert_eq!(stats.executions, 2);
+    assert_eq!(stats.rejected_executions, 0);
+    assert_eq!(stats.unique_callers, 1);
+    assert_eq!(stats.fees_paid, deployment_fee + execution_fees);
+    assert_eq!(ledger.get_all_program_stats().get(&program_id), Some(&stats));
+}
+
+#[test]
+fn test_archive_indexes() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Fetch an unspent record.
+    let (commitment, record) = ledger.find_unspent_credits_records(&view_key).unwrap().into_iter().next().unwrap();
+
+    // Ensure the indexes are unavailable until the archive mode is enabled.
+    assert!(!ledger.is_indexed().unwrap());
+    assert!(ledger.find_block_height_from_commitment(&commitment).is_err());
+    ledger.enable_indexes().unwrap();
+    assert!(ledger.is_indexed().unwrap());
+    assert_eq!(ledger.find_block_height_from_commitment(&commitment).unwrap(), Some(0));
+
+    // Spend the record in the next block.
+    let inputs = [Value::Record(record), Value::from_str("100u64").unwrap()];
+    let authorization = ledger.vm.authorize(&private_key, "credits.aleo", "split", inputs.into_iter(), rng).unwrap();
+    let transaction = ledger.vm.execute_authorization(authorization, None, None, rng).unwrap();
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction.clone()], rng)
+        .unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the spending transaction is indexed by its serial numbers and tags.
+    let serial_number = transaction.serial_numbers().next().unwrap();
+    assert_eq!(ledger.find_transaction_id_from_serial_number(serial_number).unwrap(), Some(transaction.id()));
+    let tag = transaction.tags().next().unwrap();
+    assert_eq!(ledger.find_transaction_id_from_tag(tag).unwrap(), Some(transaction.id()));
+    // Ensure the new records are indexed by their block height.
+    for commitment in transaction.commitments() {
+        assert_eq!(ledger.find_block_height_from_commitment(commitment).unwrap(), Some(1));
+    }
+
+    // Ensure the transaction is indexed by its program, in the range of block heights.
+    let program_id = ProgramID::from_str("credits.aleo").unwrap();
+    assert_eq!(ledger.find_transaction_ids_from_program_id(&program_id, 1..2).unwrap(), vec![transaction.id()]);
+    assert!(!ledger.find_transaction_ids_from_program_id(&program_id, 0..1).unwrap().is_empty());
+    assert!(ledger.find_transaction_ids_from_program_id(&program_id, 2..u32::MAX).unwrap().is_empty());
+}
+
+#[test]
+fn test_total_supply() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Ensure the genesis block mints the starting supply.
+    let genesis = ledger.get_block(0).unwrap();
+    assert_eq!(crate::next_total_supply(0, &genesis).unwrap(), CurrentNetwork::STARTING_SUPPLY);
+
+    // Execute a transfer, paying the fee from the public balance.
+    let total_supply = ledger.total_supply();
+    let inputs = [Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm()
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let fee = *transaction.fee_amount().unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the total supply gains the rewards, and loses the fee.
+    let rewards = block
+        .ratifications()
+        .iter()
+        .map(|ratify| match ratify {
+            ledger_block::Ratify::BlockReward(reward) | ledger_block::Ratify::PuzzleReward(reward) => *reward,
+            ledger_block::Ratify::Genesis(..) => unreachable!("Only the genesis block mints a genesis supply"),
+            ledger_block::Ratify::BeaconCommit(..) | ledger_block::Ratify::BeaconReveal(..) => 0,
+        })
+        .sum::<u64>();
+    assert!(rewards > 0);
+    assert_eq!(ledger.total_supply(), total_supply + rewards - fee);
+}
+
+#[test]
+fn test_header_chain() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Add two blocks to the ledger.
+    for _ in 0..2 {
+        let inputs =
+            [Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let transaction = ledger
+            .vm()
+            .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+            .unwrap();
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    let genesis = ledger.get_block(0).unwrap();
+    let blocks = (1..=2).map(|height| ledger.get_block(height).unwrap()).collect::<Vec<_>>();
+
+    // Initialize the header chain from the genesis block.
+    let chain = crate::HeaderChain::new(&genesis).unwrap();
+    assert_eq!(chain.latest_hash(), genesis.hash());
+    // Ensure a header that does not follow the genesis block is rejected.
+    assert!(chain.add_next_block_header(&blocks[1]).is_err());
+    // Ensure a header with a forged block hash is rejected.
+    assert!(chain.add_next_header(blocks[1].hash(), blocks[0].previous_hash(), *blocks[0].header()).is_err());
+
+    // Add the block headers.
+    for block in &blocks {
+        chain.add_next_block_header(block).unwrap();
+    }
+    // Ensure the header chain tracks the ledger.
+    assert_eq!(chain.latest_height(), ledger.latest_height());
+    assert_eq!(chain.latest_hash(), ledger.latest_hash());
+    assert_eq!(chain.latest_state_root(), ledger.latest_state_root());
+    assert!(chain.contains_state_root(&blocks[1].previous_state_root()));
+    // Ensure a header cannot be added twice.
+    assert!(chain.add_next_block_header(&blocks[1]).is_err());
+
+    // Ensure the block bodies are checked on demand.
+    for block in &blocks {
+        chain.check_block(block).unwrap();
+    }
+    // Ensure a block that is not in the header chain is rejected.
+    let other = crate::test_helpers::sample_genesis_block();
+    assert!(chain.check_block(&other).is_err());
+}
+
+#[test]
+fn test_state_root_attestation() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Attest to the state root of the genesis block.
+    let attestation = ledger.attest_state_root(0, &private_key, rng).unwrap();
+    assert_eq!(attestation.block_hash(), ledger.get_hash(0).unwrap());
+    assert_eq!(Some(attestation.state_root()), ledger.get_state_root(0).unwrap());
+    // Ensure a single validator does not reach the quorum threshold.
+    assert!(ledger.verify_state_root_attestation(&attestation).is_err());
+
+    // Ensure a signer outside of the committee is rejected.
+    assert!(ledger.attest_state_root(0, &PrivateKey::new(rng).unwrap(), rng).is_err());
+    // Ensure a missing block is rejected.
+    assert!(ledger.attest_state_root(1, &private_key, rng).is_err());
+
+    // Ensure an attestation to a different state root is rejected.
+    let mut forged = crate::StateRootAttestation::new(0, attestation.block_hash(), Uniform::rand(rng));
+    forged.sign(&private_key, rng).unwrap();
+    assert!(ledger.verify_state_root_attestation(&forged).is_err());
+}
+
+#[test]
+fn test_beacon_commit() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+    assert_eq!(ledger.beacon_epoch(), 0);
+
+    // Commit to a value in a block, as a member of the committee.
+    let commitment = crate::RandomnessBeacon::commitment(&address, Uniform::rand(rng), Uniform::rand(rng)).unwrap();
+    let signature = Signature::sign(&private_key, &[Field::from_u32(0), commitment], rng).unwrap();
+    let ratify = Ratify::BeaconCommit(commitment, signature);
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![ratify.clone()], vec![], vec![], rng).unwrap();
+    assert!(block.ratifications().iter().any(|r| r == &ratify));
+    ledger.check_next_block(&block).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the participant cannot commit twice in the same epoch.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![ratify], vec![], vec![], rng).unwrap();
+    assert!(ledger.check_next_block(&block).is_err());
+
+    // Ensure a signature for a different epoch is rejected.
+    let signature = Signature::sign(&private_key, &[Field::from_u32(1), commitment], rng).unwrap();
+    let ratify = Ratify::BeaconCommit(commitment, signature);
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![ratify], vec![], vec![], rng).unwrap();
+    assert!(ledger.check_next_block(&block).is_err());
+    // Ensure a signer outside of the committee is rejected.
+    let signature = Signature::sign(&PrivateKey::new(rng).unwrap(), &[Field::from_u32(0), commitment], rng).unwrap();
+    let ratify = Ratify::BeaconCommit(commitment, signature);
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![ratify], vec![], vec![], rng).unwrap();
+    assert!(ledger.check_next_block(&block).is_err());
+
+    // Ensure the commitment is persisted in the beacon state.
+    let (epoch, commitments, ..) = ledger.vm().finalize_store().ledger_state_store().beacon_state().unwrap().unwrap();
+    assert_eq!(epoch, 0);
+    assert_eq!(commitments, vec![(address, commitment)]);
+
+    // Ensure the beacon has no output yet, and the finalize scope reads zero.
+    assert_eq!(ledger.beacon_output(0).unwrap(), None);
+    assert_eq!(ledger.vm().beacon_output().unwrap(), Field::zero());
+}
@@ -24,7 +24,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
+    program::{HeaderLeaf, IncrementalBlockTree, ProgramID, StatePath, BLOCKS_DEPTH},
     types::Field,
 };
 use ledger_authority::Authority;
@@ -650,7 +650,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     }
 
     /// Returns a state path for the given `commitment`.
-    fn get_state_path_for_commitment(&self, commitment: &Field<N>, block_tree: &BlockTree<N>) -> Result<StatePath<N>> {
+    fn get_state_path_for_commitment(
+        &self,
+        commitment: &Field<N>,
+        block_tree: &IncrementalBlockTree<N>,
+    ) -> Result<StatePath<N>> {
         // Ensure the commitment exists.
         if !self.transition_store().contains_commitment(commitment)? {
             bail!("Commitment '{commitment}' does not exist");
@@ -728,7 +732,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             transaction_path,
             transaction_leaf,
             transition_root,
-            *transition.tcm(),
+            transition.to_bound_tcm()?,
             transition_path,
             transition_leaf,
         ))
@@ -987,8 +991,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
 pub struct BlockStore<N: Network, B: BlockStorage<N>> {
     /// The block storage.
     storage: B,
-    /// The block tree.
-    tree: Arc<RwLock<BlockTree<N>>>,
+    /// The block tree, which is updated incrementally as blocks are inserted or removed.
+    tree: Arc<RwLock<IncrementalBlockTree<N>>>,
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -1012,7 +1016,9 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
                 None => vec![],
             };
             // Construct the block tree.
-            Arc::new(RwLock::new(N::merkle_tree_bhp(&hashes)?))
+            let mut tree = IncrementalBlockTree::try_from(&N::merkle_tree_bhp::<BLOCKS_DEPTH>(&[])?)?;
+            tree.append(&hashes)?;
+            Arc::new(RwLock::new(tree))
         };
 
         // Return the block store.
@@ -1023,16 +1029,18 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     pub fn insert(&self, block: &Block<N>) -> Result<()> {
         // Acquire the write lock on the block tree.
         let mut tree = self.tree.write();
-        // Prepare an updated Merkle tree containing the new block hash.
-        let updated_tree = tree.prepare_append(&[block.hash().to_bits_le()])?;
         // Ensure the next block height is correct.
-        if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
+        if block.height() != u32::try_from(tree.number_of_leaves())? {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
+        // Append the new block hash to the block tree.
+        tree.append(&[block.hash().to_bits_le()])?;
         // Insert the (state root, block height) pair.
-        self.storage.insert((*updated_tree.root()).into(), block)?;
-        // Update the block tree.
-        *tree = updated_tree;
+        if let Err(error) = self.storage.insert((*tree.root()).into(), block) {
+            // Restore the block tree.
+            tree.remove_last_n(1)?;
+            return Err(error);
+        }
         // Return success.
         Ok(())
     }
@@ -1070,9 +1078,6 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        // Prepare an updated Merkle tree removing the last 'n' block hashes.
-        let updated_tree = tree.prepare_remove_last_n(usize::try_from(n)?)?;
-
         atomic_batch_scope!(self, {
             // Remove the blocks, in descending order.
             for block_hash in hashes.iter().rev() {
@@ -1081,8 +1086,8 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
             Ok(())
         })?;
 
-        // Update the block tree.
-        *tree = updated_tree;
+        // Remove the last 'n' block hashes from the block tree.
+        tree.remove_last_n(usize::try_from(n)?)?;
         // Return success.
         Ok(())
     }
@@ -1164,6 +1169,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         (*self.tree.read().root()).into()
     }
 
+    /// Returns the current block height, or `None` if the store holds no blocks.
+    pub fn current_block_height(&self) -> Option<u32> {
+        u32::try_from(self.tree.read().number_of_leaves()).ok()?.checked_sub(1)
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.storage.get_state_root(block_height)
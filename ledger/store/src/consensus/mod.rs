@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    helpers::StorageStats,
     BlockStorage,
     BlockStore,
     FinalizeStorage,
@@ -100,6 +101,16 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
         self.finalize_store().finish_atomic()?;
         self.block_store().finish_atomic()
     }
+
+    /// Returns the number of keys and bytes in the storage, in total and for each column.
+    fn stats(&self) -> Result<StorageStats> {
+        bail!("Storage statistics are not supported by this storage")
+    }
+
+    /// Compacts the keys in the given range, where `None` is unbounded, to reclaim space.
+    fn compact(&self, _start: Option<&[u8]>, _end: Option<&[u8]>) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// The consensus store.
@@ -184,4 +195,14 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
+
+    /// Returns the number of keys and bytes in the storage, in total and for each column.
+    pub fn stats(&self) -> Result<StorageStats> {
+        self.storage.stats()
+    }
+
+    /// Compacts the keys in the given range, where `None` is unbounded, to reclaim space.
+    pub fn compact(&self, start: Option<&[u8]>, end: Option<&[u8]>) -> Result<()> {
+        self.storage.compact(start, end)
+    }
 }
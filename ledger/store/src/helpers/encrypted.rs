@@ -0,0 +1,235 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::{BatchOperation, KeyValueStore, WriteBatch};
+use console::{algorithms::PasswordCipher, network::prelude::*};
+
+use rand::rngs::OsRng;
+
+/// The key of the encryption metadata, which is reserved in the underlying store.
+pub const ENCRYPTION_METADATA_KEY: &[u8] = b"\x00snarkvm/encryption";
+
+/// The version of the encryption format.
+const ENCRYPTION_VERSION: u8 = 2;
+
+/// A key-value store that encrypts the values of an underlying store, with a key derived from a passphrase.
+///
+/// Each value is encrypted with a `PasswordCipher` under a fresh nonce, and the storage key is authenticated
+/// as associated data, so a value cannot be read, modified, or moved to another key without the passphrase.
+/// The storage keys are not encrypted, so the key order and prefix iteration are preserved.
+///
+/// By default, every value is encrypted. With `with_column_families`, only the values whose key starts with
+/// one of the given prefixes are encrypted, such as the wallet sync state and decrypted record caches.
+#[derive(Clone)]
+pub struct EncryptedKeyValueStore<S: KeyValueStore> {
+    /// The underlying store.
+    inner: S,
+    /// The cipher, keyed with the key derived from the passphrase.
+    cipher: PasswordCipher,
+    /// The key prefixes of the encrypted values, where no prefixes encrypts every value.
+    column_families: Vec<Vec<u8>>,
+}
+
+impl<S: KeyValueStore> EncryptedKeyValueStore<S> {
+    /// Opens the encrypted store over the given store, with the given passphrase.
+    ///
+    /// If the store has no encryption metadata, a new salt is sampled and stored, and the passphrase is set.
+    /// Otherwise, this fails if the passphrase does not match the one the store was created with.
+    pub fn open<R: Rng + CryptoRng>(inner: S, passphrase: &str, rng: &mut R) -> Result<Self> {
+        let cipher = match inner.get(ENCRYPTION_METADATA_KEY)? {
+            // Check the passphrase against the stored metadata.
+            Some(metadata) => {
+                let mut reader = &metadata[..];
+                // Read the version.
+                let version = u8::read_le(&mut reader)?;
+                ensure!(version == ENCRYPTION_VERSION, "Unsupported storage encryption version ({version})");
+                // Read the salt.
+                let salt = <[u8; PasswordCipher::SALT_SIZE]>::read_le(&mut reader)?;
+                // Derive the key, and ensure it opens the sealed check value.
+                let cipher = PasswordCipher::new(passphrase, &salt)?;
+                cipher
+                    .decrypt(reader, ENCRYPTION_METADATA_KEY)
+                    .map_err(|_| anyhow!("Failed to open the encrypted store: incorrect passphrase"))?;
+                cipher
+            }
+            // Store the metadata for a new passphrase.
+            None => {
+                let salt = PasswordCipher::sample_salt(rng);
+                let cipher = PasswordCipher::new(passphrase, &salt)?;
+                let mut metadata = Vec::new();
+                ENCRYPTION_VERSION.write_le(&mut metadata)?;
+                salt.write_le(&mut metadata)?;
+                metadata.extend(cipher.encrypt(&[], ENCRYPTION_METADATA_KEY, rng)?);
+                inner.put(ENCRYPTION_METADATA_KEY, &metadata)?;
+                cipher
+            }
+        };
+        Ok(Self { inner, cipher, column_families: Vec::new() })
+    }
+
+    /// Restricts the encryption to the values whose key starts with one of the given prefixes.
+    pub fn with_column_families<P: Into<Vec<u8>>>(mut self, prefixes: impl IntoIterator<Item = P>) -> Self {
+        self.column_families = prefixes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns the underlying store.
+    pub const fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Returns `true` if the value of the given key is encrypted.
+    pub fn is_encrypted(&self, key: &[u8]) -> bool {
+        self.column_families.is_empty() || self.column_families.iter().any(|prefix| key.starts_with(prefix))
+    }
+}
+
+impl<S: KeyValueStore> EncryptedKeyValueStore<S> {
+    /// Returns the stored bytes for the given value, which are encrypted if the key is in an encrypted column family.
+    fn seal(&self, key: &[u8], value: &[u8]) -> Result<Vec<u8>> {
+        // Ensure the key is not reserved.
+        ensure!(key != ENCRYPTION_METADATA_KEY, "The storage key is reserved for the encryption metadata");
+        match self.is_encrypted(key) {
+            // Encrypt the value, with the storage key as associated data.
+            true => self.cipher.encrypt(value, key, &mut OsRng),
+            false => Ok(value.to_vec()),
+        }
+    }
+
+    /// Returns the value for the given stored bytes, which are decrypted if the key is in an encrypted column family.
+    fn open_value(&self, key: &[u8], bytes: Vec<u8>) -> Result<Vec<u8>> {
+        match self.is_encrypted(key) {
+            // Decrypt the value, which fails if the value was modified or moved to another key.
+            true => self.cipher.decrypt(&bytes, key),
+            false => Ok(bytes),
+        }
+    }
+}
+
+impl<S: KeyValueStore> KeyValueStore for EncryptedKeyValueStore<S> {
+    /// Returns the value for the given key, if it exists.
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        ensure!(key != ENCRYPTION_METADATA_KEY, "The storage key is reserved for the encryption metadata");
+        self.inner.get(key)?.map(|bytes| self.open_value(key, bytes)).transpose()
+    }
+
+    /// Inserts the value for the given key, replacing any existing value.
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<()> {
+        self.inner.put(key, &self.seal(key, value)?)
+    }
+
+    /// Removes the value for the given key, if it exists.
+    fn delete(&self, key: &[u8]) -> Result<()> {
+        ensure!(key != ENCRYPTION_METADATA_KEY, "The storage key is reserved for the encryption metadata");
+        self.inner.delete(key)
+    }
+
+    /// Returns an iterator over the key-value pairs whose key starts with the given prefix, in key order.
+    fn iter_prefix<'a>(&'a self, prefix: &[u8]) -> Box<dyn 'a + Iterator<Item = Result<(Vec<u8>, Vec<u8>)>>> {
+        Box::new(self.inner.iter_prefix(prefix).filter_map(move |pair| match pair {
+            // Skip the encryption metadata.
+            Ok((key, _)) if key == ENCRYPTION_METADATA_KEY => None,
+            Ok((key, bytes)) => Some(self.open_value(&key, bytes).map(|value| (key, value))),
+            Err(error) => Some(Err(error)),
+        }))
+    }
+
+    /// Applies the operations of the given batch atomically, in order.
+    fn write_batch(&self, batch: WriteBatch) -> Result<()> {
+        let mut sealed = WriteBatch::new();
+        for operation in batch.into_operations() {
+            match operation {
+                BatchOperation::Put(key, value) => {
+                    let bytes = self.seal(&key, &value)?;
+                    sealed.put(key, bytes)
+                }
+                BatchOperation::Delete(key) => {
+                    ensure!(key != ENCRYPTION_METADATA_KEY, "The storage key is reserved for the encryption metadata");
+                    sealed.delete(key)
+                }
+            }
+        }
+        self.inner.write_batch(sealed)
+    }
+
+    /// Returns `true` if the given key exists.
+    fn contains_key(&self, key: &[u8]) -> Result<bool> {
+        ensure!(key != ENCRYPTION_METADATA_KEY, "The storage key is reserved for the encryption metadata");
+        self.inner.contains_key(key)
+    }
+
+    /// Compacts the keys in the given range, where `None` is unbounded, to reclaim space.
+    fn compact(&self, start: Option<&[u8]>, end: Option<&[u8]>) -> Result<()> {
+        self.inner.compact(start, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{memory::MemoryKeyValueStore, test_helpers::key_value};
+
+    /// Returns a new encrypted store over an in-memory store.
+    fn sample_store(rng: &mut TestRng) -> EncryptedKeyValueStore<MemoryKeyValueStore> {
+        EncryptedKeyValueStore::open(MemoryKeyValueStore::new(), "correct horse battery staple", rng).unwrap()
+    }
+
+    #[test]
+    fn test_key_value_store() {
+        let rng = &mut TestRng::default();
+
+        key_value::check_get_put_delete(&sample_store(rng));
+        key_value::check_iter_prefix(&sample_store(rng));
+        key_value::check_write_batch(&sample_store(rng));
+        key_value::check_stats_and_compact(&sample_store(rng));
+    }
+
+    #[test]
+    fn test_encryption() {
+        let rng = &mut TestRng::default();
+
+        let store = sample_store(rng).with_column_families(["wallet/"]);
+        store.put(b"wallet/records", b"secret").unwrap();
+        store.put(b"wallet/height", b"42").unwrap();
+        store.put(b"blocks/0", b"public").unwrap();
+
+        // Ensure only the wallet values are encrypted in the underlying store.
+        let inner = store.inner().clone();
+        assert_ne!(inner.get(b"wallet/records").unwrap().unwrap(), b"secret");
+        assert_eq!(inner.get(b"blocks/0").unwrap().unwrap(), b"public");
+        assert_eq!(store.get(b"wallet/records").unwrap().unwrap(), b"secret");
+
+        // Ensure the store is reopened with the passphrase, and not with another passphrase.
+        let reopened = EncryptedKeyValueStore::open(inner.clone(), "correct horse battery staple", rng)
+            .unwrap()
+            .with_column_families(["wallet/"]);
+        assert_eq!(reopened.get(b"wallet/height").unwrap().unwrap(), b"42");
+        assert!(EncryptedKeyValueStore::open(inner.clone(), "incorrect passphrase", rng).is_err());
+
+        // Ensure a modified value is rejected.
+        let mut modified = inner.get(b"wallet/records").unwrap().unwrap();
+        let index = modified.len() - 1;
+        modified[index] ^= 1;
+        inner.put(b"wallet/records", &modified).unwrap();
+        assert!(store.get(b"wallet/records").is_err());
+
+        // Ensure a value moved to another key is rejected.
+        inner.put(b"wallet/records", &inner.get(b"wallet/height").unwrap().unwrap()).unwrap();
+        assert!(store.get(b"wallet/records").is_err());
+
+        // Ensure the encryption metadata is reserved.
+        assert!(store.put(ENCRYPTION_METADATA_KEY, b"").is_err());
+        assert_eq!(store.iter_prefix(b"").count(), 3);
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::{BatchOperation, KeyValueStore, WriteBatch};
+use console::network::prelude::*;
+
+use parking_lot::RwLock;
+use std::{collections::BTreeMap, sync::Arc};
+
+/// An in-memory key-value store.
+#[derive(Clone, Default)]
+pub struct MemoryKeyValueStore {
+    /// The key-value pairs, in key order.
+    map: Arc<RwLock<BTreeMap<Vec<u8>, Vec<u8>>>>,
+}
+
+impl MemoryKeyValueStore {
+    /// Initializes a new, empty in-memory key-value store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl KeyValueStore for MemoryKeyValueStore {
+    /// Returns the value for the given key, if it exists.
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self.map.read().get(key).cloned())
+    }
+
+    /// Inserts the value for the given key, replacing any existing value.
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<()> {
+        self.map.write().insert(key.to_vec(), value.to_vec());
+        Ok(())
+    }
+
+    /// Removes the value for the given key, if it exists.
+    fn delete(&self, key: &[u8]) -> Result<()> {
+        self.map.write().remove(key);
+        Ok(())
+    }
+
+    /// Returns an iterator over the key-value pairs whose key starts with the given prefix, in key order.
+    fn iter_prefix<'a>(&'a self, prefix: &[u8]) -> Box<dyn 'a + Iterator<Item = Result<(Vec<u8>, Vec<u8>)>>> {
+        // Collect the matching pairs, so the lock is not held by the iterator.
+        let pairs = self
+            .map
+            .read()
+            .range(prefix.to_vec()..)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| Ok((key.clone(), value.clone())))
+            .collect::<Vec<_>>();
+        Box::new(pairs.into_iter())
+    }
+
+    /// Applies the operations of the given batch atomically, in order.
+    fn write_batch(&self, batch: WriteBatch) -> Result<()> {
+        let mut map = self.map.write();
+        for operation in batch.into_operations() {
+            match operation {
+                BatchOperation::Put(key, value) => map.insert(key, value),
+                BatchOperation::Delete(key) => map.remove(&key),
+            };
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_helpers::key_value;
+
+    #[test]
+    fn test_get_put_delete() {
+        key_value::check_get_put_delete(&MemoryKeyValueStore::new());
+    }
+
+    #[test]
+    fn test_iter_prefix() {
+        key_value::check_iter_prefix(&MemoryKeyValueStore::new());
+    }
+
+    #[test]
+    fn test_write_batch() {
+        key_value::check_write_batch(&MemoryKeyValueStore::new());
+    }
+
+    #[test]
+    fn test_stats_and_compact() {
+        key_value::check_stats_and_compact(&MemoryKeyValueStore::new());
+    }
+}
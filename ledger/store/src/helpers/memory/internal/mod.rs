@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod key_value;
+pub use key_value::*;
+
 mod map;
 pub use map::*;
 
@@ -16,16 +16,22 @@
 
 use crate::{
     helpers::memory::{MemoryMap, NestedMemoryMap},
+    BeaconState,
     CommitteeStorage,
     CommitteeStore,
     FinalizeStorage,
+    LedgerStateStorage,
+    LedgerStateStore,
+    ProgramStats,
 };
 use console::{
     prelude::*,
-    program::{Identifier, Plaintext, ProgramID, Value},
+    program::{Identifier, Plaintext, ProgramID, SerialNumberLeaf, Value},
+    types::{Address, Field},
 };
 use ledger_committee::Committee;
 
+use core::marker::PhantomData;
 use indexmap::IndexSet;
 
 /// An in-memory finalize storage.
@@ -33,6 +39,8 @@ use indexmap::IndexSet;
 pub struct FinalizeMemory<N: Network> {
     /// The committee store.
     committee_store: CommitteeStore<N, CommitteeMemory<N>>,
+    /// The ledger state store.
+    ledger_state_store: LedgerStateStore<N, LedgerStateMemory<N>>,
     /// The program ID map.
     program_id_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
@@ -44,6 +52,7 @@ pub struct FinalizeMemory<N: Network> {
 #[rustfmt::skip]
 impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type CommitteeStorage = CommitteeMemory<N>;
+    type LedgerStateStorage = LedgerStateMemory<N>;
     type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
 
@@ -51,9 +60,12 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     fn open(dev: Option<u16>) -> Result<Self> {
         // Initialize the committee store.
         let committee_store = CommitteeStore::<N, CommitteeMemory<N>>::open(dev)?;
+        // Initialize the ledger state store.
+        let ledger_state_store = LedgerStateStore::<N, LedgerStateMemory<N>>::open(dev)?;
         // Return the finalize store.
         Ok(Self {
             committee_store,
+            ledger_state_store,
             program_id_map: MemoryMap::default(),
             key_value_map: NestedMemoryMap::default(),
             dev,
@@ -71,6 +83,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.committee_store
     }
 
+    /// Returns the ledger state store.
+    fn ledger_state_store(&self) -> &LedgerStateStore<N, Self::LedgerStateStorage> {
+        &self.ledger_state_store
+    }
+
     /// Returns the program ID map.
     fn program_id_map(&self) -> &Self::ProgramIDMap {
         &self.program_id_map
@@ -142,3 +159,161 @@ impl<N: Network> CommitteeStorage<N> for CommitteeMemory<N> {
         self.dev
     }
 }
+
+/// An in-memory ledger state storage.
+#[derive(Clone)]
+pub struct LedgerStateMemory<N: Network> {
+    /// The unverified heights map.
+    unverified_heights_map: MemoryMap<u8, (u32, u32)>,
+    /// The serial number leaf map.
+    serial_number_leaf_map: MemoryMap<u32, SerialNumberLeaf<N>>,
+    /// The serial number index map.
+    serial_number_index_map: MemoryMap<Field<N>, u32>,
+    /// The total supply map.
+    total_supply_map: MemoryMap<u8, u64>,
+    /// The program stats map.
+    program_stats_map: MemoryMap<ProgramID<N>, ProgramStats>,
+    /// The program caller map.
+    program_caller_map: MemoryMap<(ProgramID<N>, Address<N>), ()>,
+    /// The indexes enabled map.
+    indexes_enabled_map: MemoryMap<u8, bool>,
+    /// The commitment height map.
+    commitment_height_map: MemoryMap<Field<N>, u32>,
+    /// The serial number transaction map.
+    serial_number_transaction_map: MemoryMap<Field<N>, N::TransactionID>,
+    /// The tag transaction map.
+    tag_transaction_map: MemoryMap<Field<N>, N::TransactionID>,
+    /// The program transactions map.
+    program_transactions_map: NestedMemoryMap<ProgramID<N>, (u32, u32), N::TransactionID>,
+    /// The beacon state map.
+    beacon_state_map: MemoryMap<u8, BeaconState<N>>,
+    /// The beacon output map.
+    beacon_output_map: MemoryMap<u32, Field<N>>,
+    /// The latest beacon output map.
+    latest_beacon_output_map: MemoryMap<u8, Field<N>>,
+    /// The optional development ID.
+    dev: Option<u16>,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> LedgerStateStorage<N> for LedgerStateMemory<N> {
+    type UnverifiedHeightsMap = MemoryMap<u8, (u32, u32)>;
+    type SerialNumberLeafMap = MemoryMap<u32, SerialNumberLeaf<N>>;
+    type SerialNumberIndexMap = MemoryMap<Field<N>, u32>;
+    type TotalSupplyMap = MemoryMap<u8, u64>;
+    type ProgramStatsMap = MemoryMap<ProgramID<N>, ProgramStats>;
+    type ProgramCallerMap = MemoryMap<(ProgramID<N>, Address<N>), ()>;
+    type IndexesEnabledMap = MemoryMap<u8, bool>;
+    type CommitmentHeightMap = MemoryMap<Field<N>, u32>;
+    type SerialNumberTransactionMap = MemoryMap<Field<N>, N::TransactionID>;
+    type TagTransactionMap = MemoryMap<Field<N>, N::TransactionID>;
+    type ProgramTransactionsMap = NestedMemoryMap<ProgramID<N>, (u32, u32), N::TransactionID>;
+    type BeaconStateMap = MemoryMap<u8, BeaconState<N>>;
+    type BeaconOutputMap = MemoryMap<u32, Field<N>>;
+    type LatestBeaconOutputMap = MemoryMap<u8, Field<N>>;
+
+    /// Initializes the ledger state storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self {
+            unverified_heights_map: MemoryMap::default(),
+            serial_number_leaf_map: MemoryMap::default(),
+            serial_number_index_map: MemoryMap::default(),
+            total_supply_map: MemoryMap::default(),
+            program_stats_map: MemoryMap::default(),
+            program_caller_map: MemoryMap::default(),
+            indexes_enabled_map: MemoryMap::default(),
+            commitment_height_map: MemoryMap::default(),
+            serial_number_transaction_map: MemoryMap::default(),
+            tag_transaction_map: MemoryMap::default(),
+            program_transactions_map: NestedMemoryMap::default(),
+            beacon_state_map: MemoryMap::default(),
+            beacon_output_map: MemoryMap::default(),
+            latest_beacon_output_map: MemoryMap::default(),
+            dev,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Initializes the test-variant of the storage.
+    #[cfg(any(test, feature = "test"))]
+    fn open_testing(_: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        Self::open(dev)
+    }
+
+    /// Returns the unverified heights map.
+    fn unverified_heights_map(&self) -> &Self::UnverifiedHeightsMap {
+        &self.unverified_heights_map
+    }
+
+    /// Returns the serial number leaf map.
+    fn serial_number_leaf_map(&self) -> &Self::SerialNumberLeafMap {
+        &self.serial_number_leaf_map
+    }
+
+    /// Returns the serial number index map.
+    fn serial_number_index_map(&self) -> &Self::SerialNumberIndexMap {
+        &self.serial_number_index_map
+    }
+
+    /// Returns the total supply map.
+    fn total_supply_map(&self) -> &Self::TotalSupplyMap {
+        &self.total_supply_map
+    }
+
+    /// Returns the program stats map.
+    fn program_stats_map(&self) -> &Self::ProgramStatsMap {
+        &self.program_stats_map
+    }
+
+    /// Returns the program caller map.
+    fn program_caller_map(&self) -> &Self::ProgramCallerMap {
+        &self.program_caller_map
+    }
+
+    /// Returns the indexes enabled map.
+    fn indexes_enabled_map(&self) -> &Self::IndexesEnabledMap {
+        &self.indexes_enabled_map
+    }
+
+    /// Returns the commitment height map.
+    fn commitment_height_map(&self) -> &Self::CommitmentHeightMap {
+        &self.commitment_height_map
+    }
+
+    /// Returns the serial number transaction map.
+    fn serial_number_transaction_map(&self) -> &Self::SerialNumberTransactionMap {
+        &self.serial_number_transaction_map
+    }
+
+    /// Returns the tag transaction map.
+    fn tag_transaction_map(&self) -> &Self::TagTransactionMap {
+        &self.tag_transaction_map
+    }
+
+    /// Returns the program transactions map.
+    fn program_transactions_map(&self) -> &Self::ProgramTransactionsMap {
+        &self.program_transactions_map
+    }
+
+    /// Returns the beacon state map.
+    fn beacon_state_map(&self) -> &Self::BeaconStateMap {
+        &self.beacon_state_map
+    }
+
+    /// Returns the beacon output map.
+    fn beacon_output_map(&self) -> &Self::BeaconOutputMap {
+        &self.beacon_output_map
+    }
+
+    /// Returns the latest beacon output map.
+    fn latest_beacon_output_map(&self) -> &Self::LatestBeaconOutputMap {
+        &self.latest_beacon_output_map
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+}
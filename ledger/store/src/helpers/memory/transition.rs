@@ -15,7 +15,7 @@
 use crate::{helpers::memory::MemoryMap, InputStorage, InputStore, OutputStorage, OutputStore, TransitionStorage};
 use console::{
     prelude::*,
-    program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
+    program::{Ciphertext, Expiry, Future, Identifier, Plaintext, ProgramID, Record},
     types::{Field, Group},
 };
 
@@ -36,6 +36,8 @@ pub struct TransitionMemory<N: Network> {
     tcm_map: MemoryMap<N::TransitionID, Field<N>>,
     /// The reverse `tcm` map.
     reverse_tcm_map: MemoryMap<Field<N>, N::TransitionID>,
+    /// The transition expiries.
+    expiry_map: MemoryMap<N::TransitionID, Expiry<N>>,
 }
 
 #[rustfmt::skip]
@@ -47,6 +49,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     type ReverseTPKMap = MemoryMap<Group<N>, N::TransitionID>;
     type TCMMap = MemoryMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = MemoryMap<Field<N>, N::TransitionID>;
+    type ExpiryMap = MemoryMap<N::TransitionID, Expiry<N>>;
 
     /// Initializes the transition storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -58,6 +61,7 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
             reverse_tpk_map: MemoryMap::default(),
             tcm_map: MemoryMap::default(),
             reverse_tcm_map: MemoryMap::default(),
+            expiry_map: MemoryMap::default(),
         })
     }
 
@@ -95,6 +99,11 @@ impl<N: Network> TransitionStorage<N> for TransitionMemory<N> {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
         &self.reverse_tcm_map
     }
+
+    /// Returns the transition expiries.
+    fn expiry_map(&self) -> &Self::ExpiryMap {
+        &self.expiry_map
+    }
 }
 
 /// An in-memory transition input storage.
@@ -15,10 +15,20 @@
 pub mod memory;
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
+#[cfg(feature = "sled")]
+pub mod sled;
 
 #[cfg(test)]
 pub(crate) mod test_helpers;
 
+#[cfg(feature = "password")]
+mod encrypted;
+#[cfg(feature = "password")]
+pub use encrypted::*;
+
+mod stats;
+pub use stats::*;
+
 mod traits;
 pub use traits::*;
 
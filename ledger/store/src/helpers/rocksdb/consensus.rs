@@ -13,7 +13,10 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{BlockDB, FinalizeDB, TransactionDB, TransitionDB},
+    helpers::{
+        rocksdb::{BlockDB, Database, FinalizeDB, RocksDB, TransactionDB, TransitionDB},
+        StorageStats,
+    },
     BlockStore,
     ConsensusStorage,
     FinalizeStore,
@@ -58,4 +61,14 @@ impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
     fn block_store(&self) -> &BlockStore<N, Self::BlockStorage> {
         &self.block_store
     }
+
+    /// Returns the number of keys and bytes in the storage, in total and for each map.
+    fn stats(&self) -> Result<StorageStats> {
+        RocksDB::open(N::ID, self.dev())?.stats()
+    }
+
+    /// Compacts the keys in the given range, where `None` is unbounded, to reclaim space.
+    fn compact(&self, start: Option<&[u8]>, end: Option<&[u8]>) -> Result<()> {
+        RocksDB::open(N::ID, self.dev())?.compact(start, end)
+    }
 }
@@ -22,6 +22,7 @@ pub enum MapID {
     Deployment(DeploymentMap),
     Execution(ExecutionMap),
     Fee(FeeMap),
+    LedgerState(LedgerStateMap),
     Transaction(TransactionMap),
     Transition(TransitionMap),
     TransitionInput(TransitionInputMap),
@@ -39,6 +40,7 @@ impl From<MapID> for u16 {
             MapID::Deployment(id) => id as u16,
             MapID::Execution(id) => id as u16,
             MapID::Fee(id) => id as u16,
+            MapID::LedgerState(id) => id as u16,
             MapID::Transaction(id) => id as u16,
             MapID::Transition(id) => id as u16,
             MapID::TransitionInput(id) => id as u16,
@@ -97,6 +99,7 @@ pub enum DeploymentMap {
     Program = DataID::DeploymentProgramMap as u16,
     VerifyingKey = DataID::DeploymentVerifyingKeyMap as u16,
     Certificate = DataID::DeploymentCertificateMap as u16,
+    NetworkEdition = DataID::DeploymentNetworkEditionMap as u16,
 }
 
 /// The RocksDB map prefix for execution-related entries.
@@ -120,6 +123,28 @@ pub enum FeeMap {
     ReverseFee = DataID::FeeReverseFeeMap as u16,
 }
 
+/// The RocksDB map prefix for ledger state entries.
+// Note: the order of these variants can be changed at any point in time,
+// as long as the corresponding DataID values remain the same.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum LedgerStateMap {
+    UnverifiedHeights = DataID::LedgerUnverifiedHeightsMap as u16,
+    SerialNumberLeaf = DataID::LedgerSerialNumberLeafMap as u16,
+    SerialNumberIndex = DataID::LedgerSerialNumberIndexMap as u16,
+    TotalSupply = DataID::LedgerTotalSupplyMap as u16,
+    ProgramStats = DataID::LedgerProgramStatsMap as u16,
+    ProgramCaller = DataID::LedgerProgramCallerMap as u16,
+    IndexesEnabled = DataID::LedgerIndexesEnabledMap as u16,
+    CommitmentHeight = DataID::LedgerCommitmentHeightMap as u16,
+    SerialNumberTransaction = DataID::LedgerSerialNumberTransactionMap as u16,
+    TagTransaction = DataID::LedgerTagTransactionMap as u16,
+    ProgramTransactions = DataID::LedgerProgramTransactionsMap as u16,
+    BeaconState = DataID::LedgerBeaconStateMap as u16,
+    BeaconOutput = DataID::LedgerBeaconOutputMap as u16,
+    LatestBeaconOutput = DataID::LedgerLatestBeaconOutputMap as u16,
+}
+
 /// The RocksDB map prefix for transition input entries.
 // Note: the order of these variants can be changed at any point in time,
 // as long as the corresponding DataID values remain the same.
@@ -173,6 +198,7 @@ pub enum TransitionMap {
     ReverseTPK = DataID::TransitionReverseTPKMap as u16,
     TCM = DataID::TransitionTCMMap as u16,
     ReverseTCM = DataID::TransitionReverseTCMMap as u16,
+    Expiry = DataID::TransitionExpiryMap as u16,
 }
 
 /// The RocksDB map prefix for program-related entries.
@@ -272,6 +298,22 @@ enum DataID {
 
     // TODO (howardwu): For mainnet - Reorder this up above.
     BlockRejectedDeploymentOrExecutionMap,
+    TransitionExpiryMap,
+    LedgerUnverifiedHeightsMap,
+    LedgerSerialNumberLeafMap,
+    LedgerSerialNumberIndexMap,
+    LedgerTotalSupplyMap,
+    LedgerProgramStatsMap,
+    LedgerProgramCallerMap,
+    LedgerIndexesEnabledMap,
+    LedgerCommitmentHeightMap,
+    LedgerSerialNumberTransactionMap,
+    LedgerTagTransactionMap,
+    LedgerProgramTransactionsMap,
+    LedgerBeaconStateMap,
+    LedgerBeaconOutputMap,
+    LedgerLatestBeaconOutputMap,
+    DeploymentNetworkEditionMap,
 
     // Testing
     #[cfg(test)]
@@ -283,3 +325,85 @@ enum DataID {
     #[cfg(test)]
     Test4,
 }
+
+impl DataID {
+    /// The data IDs, excluding the testing IDs.
+    const ALL: &'static [DataID] = &[
+        DataID::BlockStateRootMap,
+        DataID::BlockReverseStateRootMap,
+        DataID::BlockIDMap,
+        DataID::BlockReverseIDMap,
+        DataID::BlockHeaderMap,
+        DataID::BlockAuthorityMap,
+        DataID::BlockCertificateMap,
+        DataID::BlockRatificationsMap,
+        DataID::BlockSolutionsMap,
+        DataID::BlockPuzzleCommitmentsMap,
+        DataID::BlockTransactionsMap,
+        DataID::BlockAbortedTransactionIDsMap,
+        DataID::BlockRejectedOrAbortedTransactionIDMap,
+        DataID::BlockConfirmedTransactionsMap,
+        DataID::CurrentRoundMap,
+        DataID::RoundToHeightMap,
+        DataID::CommitteeMap,
+        DataID::DeploymentIDMap,
+        DataID::DeploymentEditionMap,
+        DataID::DeploymentReverseIDMap,
+        DataID::DeploymentOwnerMap,
+        DataID::DeploymentProgramMap,
+        DataID::DeploymentVerifyingKeyMap,
+        DataID::DeploymentCertificateMap,
+        DataID::ExecutionIDMap,
+        DataID::ExecutionReverseIDMap,
+        DataID::ExecutionInclusionMap,
+        DataID::FeeFeeMap,
+        DataID::FeeReverseFeeMap,
+        DataID::InputIDMap,
+        DataID::InputReverseIDMap,
+        DataID::InputConstantMap,
+        DataID::InputPublicMap,
+        DataID::InputPrivateMap,
+        DataID::InputRecordMap,
+        DataID::InputRecordTagMap,
+        DataID::InputExternalRecordMap,
+        DataID::OutputIDMap,
+        DataID::OutputReverseIDMap,
+        DataID::OutputConstantMap,
+        DataID::OutputPublicMap,
+        DataID::OutputPrivateMap,
+        DataID::OutputRecordMap,
+        DataID::OutputRecordNonceMap,
+        DataID::OutputExternalRecordMap,
+        DataID::OutputFutureMap,
+        DataID::TransactionIDMap,
+        DataID::TransitionLocatorMap,
+        DataID::TransitionTPKMap,
+        DataID::TransitionReverseTPKMap,
+        DataID::TransitionTCMMap,
+        DataID::TransitionReverseTCMMap,
+        DataID::ProgramIDMap,
+        DataID::KeyValueMap,
+        DataID::BlockRejectedDeploymentOrExecutionMap,
+        DataID::TransitionExpiryMap,
+        DataID::LedgerUnverifiedHeightsMap,
+        DataID::LedgerSerialNumberLeafMap,
+        DataID::LedgerSerialNumberIndexMap,
+        DataID::LedgerTotalSupplyMap,
+        DataID::LedgerProgramStatsMap,
+        DataID::LedgerProgramCallerMap,
+        DataID::LedgerIndexesEnabledMap,
+        DataID::LedgerCommitmentHeightMap,
+        DataID::LedgerSerialNumberTransactionMap,
+        DataID::LedgerTagTransactionMap,
+        DataID::LedgerProgramTransactionsMap,
+        DataID::LedgerBeaconStateMap,
+        DataID::LedgerBeaconOutputMap,
+        DataID::LedgerLatestBeaconOutputMap,
+        DataID::DeploymentNetworkEditionMap,
+    ];
+}
+
+/// Returns the map prefix value and name of each data ID, excluding the testing IDs.
+pub(crate) fn data_ids() -> impl Iterator<Item = (u16, String)> {
+    DataID::ALL.iter().map(|data_id| (*data_id as u16, format!("{data_id:?}")))
+}
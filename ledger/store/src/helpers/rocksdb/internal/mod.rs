@@ -24,10 +24,12 @@ pub use nested_map::*;
 #[cfg(test)]
 mod tests;
 
+use crate::helpers::StorageStats;
 use anyhow::{bail, Result};
 use once_cell::sync::OnceCell;
 use parking_lot::Mutex;
 use serde::{de::DeserializeOwned, Serialize};
+use snarkvm_utilities::Directories;
 use std::{
     borrow::Borrow,
     marker::PhantomData,
@@ -94,7 +96,8 @@ impl Deref for RocksDB {
 impl Database for RocksDB {
     /// Opens the database.
     ///
-    /// In production mode, the database opens directory `~/.aleo/storage/ledger-{network}`.
+    /// In production mode, the database opens directory `{root}/storage/ledger-{network}`,
+    /// where `{root}` is the root of [`Directories::current`].
     /// In development mode, the database opens directory `/path/to/repo/.ledger-{network}-{id}`.
     fn open(network_id: u16, dev: Option<u16>) -> Result<Self> {
         static DB: OnceCell<RocksDB> = OnceCell::new();
@@ -110,7 +113,10 @@ impl Database for RocksDB {
                 let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
                 options.set_prefix_extractor(prefix_extractor);
 
-                let primary = aleo_std::aleo_ledger_dir(network_id, dev);
+                let primary = match dev {
+                    Some(_) => aleo_std::aleo_ledger_dir(network_id, dev),
+                    None => Directories::current().ledger_dir(network_id),
+                };
                 let rocksdb = {
                     options.increase_parallelism(2);
                     options.set_max_background_jobs(4);
@@ -188,6 +194,37 @@ impl Database for RocksDB {
     }
 }
 
+impl RocksDB {
+    /// Returns the number of keys and bytes stored for this network, in total and for each map.
+    ///
+    /// Note: The sizes are computed from the uncompressed keys and values, and exclude
+    /// any pending atomic batch, so they may differ from the size of the files on disk.
+    pub fn stats(&self) -> Result<StorageStats> {
+        let mut stats = StorageStats::new();
+        for (map_id, name) in data_ids() {
+            // Construct the context of the map.
+            let mut context = self.network_id.to_le_bytes().to_vec();
+            context.extend_from_slice(&map_id.to_le_bytes());
+
+            for entry in self.rocksdb.prefix_iterator(&context) {
+                let (key, value) = entry?;
+                // Stop once the iterator leaves the map.
+                if !key.starts_with(&context) {
+                    break;
+                }
+                stats.add_entry(Some(&name), key.len(), value.len());
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Compacts the keys in the given range, where `None` is unbounded, to reclaim space.
+    pub fn compact(&self, start: Option<&[u8]>, end: Option<&[u8]>) -> Result<()> {
+        self.rocksdb.compact_range(start, end);
+        Ok(())
+    }
+}
+
 impl RocksDB {
     /// Opens the test database.
     #[cfg(any(test, feature = "test"))]
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::helpers::{
-    rocksdb::{MapID, RocksDB, TestMap as TestMapID},
+    rocksdb::{BlockMap, MapID, RocksDB, TestMap as TestMapID},
     Map,
     MapRead,
 };
@@ -127,6 +127,31 @@ fn test_insert_and_values() {
     assert_eq!(None, values.next());
 }
 
+#[test]
+#[serial]
+fn test_stats_and_compact() {
+    let map =
+        RocksDB::open_map_testing(temp_dir(), None, MapID::Block(BlockMap::Header)).expect("Failed to open data map");
+    let database = map.0.database.clone();
+    assert_eq!(database.stats().expect("Failed to get stats").num_keys(), 0);
+
+    for i in 0u32..10 {
+        map.insert(i, i.to_string()).expect("Failed to insert");
+    }
+
+    let stats = database.stats().expect("Failed to get stats");
+    assert_eq!(stats.num_keys(), 10);
+    assert!(stats.num_bytes() > 0);
+    assert_eq!(stats.columns().len(), 1);
+    assert_eq!(stats.columns()["BlockHeaderMap"].num_keys(), 10);
+    assert_eq!(stats.columns()["BlockHeaderMap"].num_bytes(), stats.num_bytes());
+
+    // Ensure compaction preserves the contents.
+    database.compact(None, None).expect("Failed to compact");
+    assert_eq!(database.stats().expect("Failed to get stats"), stats);
+    assert_eq!(map.get_confirmed(&5).expect("Failed to get").map(|v| v.to_string()), Some("5".to_string()));
+}
+
 // #[test]
 // #[serial]
 // fn test_export_import() {
@@ -15,17 +15,23 @@
 #![allow(clippy::type_complexity)]
 
 use crate::{
-    helpers::rocksdb::{self, CommitteeMap, DataMap, Database, MapID, NestedDataMap, ProgramMap},
+    helpers::rocksdb::{self, CommitteeMap, DataMap, Database, LedgerStateMap, MapID, NestedDataMap, ProgramMap},
+    BeaconState,
     CommitteeStorage,
     CommitteeStore,
     FinalizeStorage,
+    LedgerStateStorage,
+    LedgerStateStore,
+    ProgramStats,
 };
 use console::{
     prelude::*,
-    program::{Identifier, Plaintext, ProgramID, Value},
+    program::{Identifier, Plaintext, ProgramID, SerialNumberLeaf, Value},
+    types::{Address, Field},
 };
 use ledger_committee::Committee;
 
+use core::marker::PhantomData;
 use indexmap::IndexSet;
 
 /// A RocksDB finalize storage.
@@ -33,6 +39,8 @@ use indexmap::IndexSet;
 pub struct FinalizeDB<N: Network> {
     /// The committee store.
     committee_store: CommitteeStore<N, CommitteeDB<N>>,
+    /// The ledger state store.
+    ledger_state_store: LedgerStateStore<N, LedgerStateDB<N>>,
     /// The program ID map.
     program_id_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
@@ -44,6 +52,7 @@ pub struct FinalizeDB<N: Network> {
 #[rustfmt::skip]
 impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type CommitteeStorage = CommitteeDB<N>;
+    type LedgerStateStorage = LedgerStateDB<N>;
     type ProgramIDMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
 
@@ -51,9 +60,12 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     fn open(dev: Option<u16>) -> Result<Self> {
         // Initialize the committee store.
         let committee_store = CommitteeStore::<N, CommitteeDB<N>>::open(dev)?;
+        // Initialize the ledger state store.
+        let ledger_state_store = LedgerStateStore::<N, LedgerStateDB<N>>::open(dev)?;
         // Return the finalize storage.
         Ok(Self {
             committee_store,
+            ledger_state_store,
             program_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map(N::ID, dev, MapID::Program(ProgramMap::KeyValueID))?,
             dev,
@@ -65,9 +77,12 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
         // Initialize the committee store.
         let committee_store = CommitteeStore::<N, CommitteeDB<N>>::open_testing(temp_dir.clone(), dev)?;
+        // Initialize the ledger state store.
+        let ledger_state_store = LedgerStateStore::<N, LedgerStateDB<N>>::open_testing(temp_dir.clone(), dev)?;
         // Return the finalize storage.
         Ok(Self {
             committee_store,
+            ledger_state_store,
             program_id_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueID))?,
             dev,
@@ -79,6 +94,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.committee_store
     }
 
+    /// Returns the ledger state store.
+    fn ledger_state_store(&self) -> &LedgerStateStore<N, Self::LedgerStateStorage> {
+        &self.ledger_state_store
+    }
+
     /// Returns the program ID map.
     fn program_id_map(&self) -> &Self::ProgramIDMap {
         &self.program_id_map
@@ -155,3 +175,178 @@ impl<N: Network> CommitteeStorage<N> for CommitteeDB<N> {
         self.dev
     }
 }
+
+/// A RocksDB ledger state storage.
+#[derive(Clone)]
+pub struct LedgerStateDB<N: Network> {
+    /// The unverified heights map.
+    unverified_heights_map: DataMap<u8, (u32, u32)>,
+    /// The serial number leaf map.
+    serial_number_leaf_map: DataMap<u32, SerialNumberLeaf<N>>,
+    /// The serial number index map.
+    serial_number_index_map: DataMap<Field<N>, u32>,
+    /// The total supply map.
+    total_supply_map: DataMap<u8, u64>,
+    /// The program stats map.
+    program_stats_map: DataMap<ProgramID<N>, ProgramStats>,
+    /// The program caller map.
+    program_caller_map: DataMap<(ProgramID<N>, Address<N>), ()>,
+    /// The indexes enabled map.
+    indexes_enabled_map: DataMap<u8, bool>,
+    /// The commitment height map.
+    commitment_height_map: DataMap<Field<N>, u32>,
+    /// The serial number transaction map.
+    serial_number_transaction_map: DataMap<Field<N>, N::TransactionID>,
+    /// The tag transaction map.
+    tag_transaction_map: DataMap<Field<N>, N::TransactionID>,
+    /// The program transactions map.
+    program_transactions_map: NestedDataMap<ProgramID<N>, (u32, u32), N::TransactionID>,
+    /// The beacon state map.
+    beacon_state_map: DataMap<u8, BeaconState<N>>,
+    /// The beacon output map.
+    beacon_output_map: DataMap<u32, Field<N>>,
+    /// The latest beacon output map.
+    latest_beacon_output_map: DataMap<u8, Field<N>>,
+    /// The optional development ID.
+    dev: Option<u16>,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> LedgerStateStorage<N> for LedgerStateDB<N> {
+    type UnverifiedHeightsMap = DataMap<u8, (u32, u32)>;
+    type SerialNumberLeafMap = DataMap<u32, SerialNumberLeaf<N>>;
+    type SerialNumberIndexMap = DataMap<Field<N>, u32>;
+    type TotalSupplyMap = DataMap<u8, u64>;
+    type ProgramStatsMap = DataMap<ProgramID<N>, ProgramStats>;
+    type ProgramCallerMap = DataMap<(ProgramID<N>, Address<N>), ()>;
+    type IndexesEnabledMap = DataMap<u8, bool>;
+    type CommitmentHeightMap = DataMap<Field<N>, u32>;
+    type SerialNumberTransactionMap = DataMap<Field<N>, N::TransactionID>;
+    type TagTransactionMap = DataMap<Field<N>, N::TransactionID>;
+    type ProgramTransactionsMap = NestedDataMap<ProgramID<N>, (u32, u32), N::TransactionID>;
+    type BeaconStateMap = DataMap<u8, BeaconState<N>>;
+    type BeaconOutputMap = DataMap<u32, Field<N>>;
+    type LatestBeaconOutputMap = DataMap<u8, Field<N>>;
+
+    /// Initializes the ledger state storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self {
+            unverified_heights_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::UnverifiedHeights))?,
+            serial_number_leaf_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::SerialNumberLeaf))?,
+            serial_number_index_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::SerialNumberIndex))?,
+            total_supply_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::TotalSupply))?,
+            program_stats_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::ProgramStats))?,
+            program_caller_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::ProgramCaller))?,
+            indexes_enabled_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::IndexesEnabled))?,
+            commitment_height_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::CommitmentHeight))?,
+            serial_number_transaction_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::SerialNumberTransaction))?,
+            tag_transaction_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::TagTransaction))?,
+            program_transactions_map: rocksdb::RocksDB::open_nested_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::ProgramTransactions))?,
+            beacon_state_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::BeaconState))?,
+            beacon_output_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::BeaconOutput))?,
+            latest_beacon_output_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::LedgerState(LedgerStateMap::LatestBeaconOutput))?,
+            dev,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Initializes the test-variant of the storage.
+    #[cfg(any(test, feature = "test"))]
+    fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        Ok(Self {
+            unverified_heights_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::LedgerState(LedgerStateMap::UnverifiedHeights))?,
+            serial_number_leaf_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::LedgerState(LedgerStateMap::SerialNumberLeaf))?,
+            serial_number_index_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::LedgerState(LedgerStateMap::SerialNumberIndex))?,
+            total_supply_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::LedgerState(LedgerStateMap::TotalSupply))?,
+            program_stats_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::LedgerState(LedgerStateMap::ProgramStats))?,
+            program_caller_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::LedgerState(LedgerStateMap::ProgramCaller))?,
+            indexes_enabled_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::LedgerState(LedgerStateMap::IndexesEnabled))?,
+            commitment_height_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::LedgerState(LedgerStateMap::CommitmentHeight))?,
+            serial_number_transaction_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::LedgerState(LedgerStateMap::SerialNumberTransaction))?,
+            tag_transaction_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::LedgerState(LedgerStateMap::TagTransaction))?,
+            program_transactions_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::LedgerState(LedgerStateMap::ProgramTransactions))?,
+            beacon_state_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::LedgerState(LedgerStateMap::BeaconState))?,
+            beacon_output_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::LedgerState(LedgerStateMap::BeaconOutput))?,
+            latest_beacon_output_map: rocksdb::RocksDB::open_map_testing(temp_dir, dev, MapID::LedgerState(LedgerStateMap::LatestBeaconOutput))?,
+            dev,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the unverified heights map.
+    fn unverified_heights_map(&self) -> &Self::UnverifiedHeightsMap {
+        &self.unverified_heights_map
+    }
+
+    /// Returns the serial number leaf map.
+    fn serial_number_leaf_map(&self) -> &Self::SerialNumberLeafMap {
+        &self.serial_number_leaf_map
+    }
+
+    /// Returns the serial number index map.
+    fn serial_number_index_map(&self) -> &Self::SerialNumberIndexMap {
+        &self.serial_number_index_map
+    }
+
+    /// Returns the total supply map.
+    fn total_supply_map(&self) -> &Self::TotalSupplyMap {
+        &self.total_supply_map
+    }
+
+    /// Returns the program stats map.
+    fn program_stats_map(&self) -> &Self::ProgramStatsMap {
+        &self.program_stats_map
+    }
+
+    /// Returns the program caller map.
+    fn program_caller_map(&self) -> &Self::ProgramCallerMap {
+        &self.program_caller_map
+    }
+
+    /// Returns the indexes enabled map.
+    fn indexes_enabled_map(&self) -> &Self::IndexesEnabledMap {
+        &self.indexes_enabled_map
+    }
+
+    /// Returns the commitment height map.
+    fn commitment_height_map(&self) -> &Self::CommitmentHeightMap {
+        &self.commitment_height_map
+    }
+
+    /// Returns the serial number transaction map.
+    fn serial_number_transaction_map(&self) -> &Self::SerialNumberTransactionMap {
+        &self.serial_number_transaction_map
+    }
+
+    /// Returns the tag transaction map.
+    fn tag_transaction_map(&self) -> &Self::TagTransactionMap {
+        &self.tag_transaction_map
+    }
+
+    /// Returns the program transactions map.
+    fn program_transactions_map(&self) -> &Self::ProgramTransactionsMap {
+        &self.program_transactions_map
+    }
+
+    /// Returns the beacon state map.
+    fn beacon_state_map(&self) -> &Self::BeaconStateMap {
+        &self.beacon_state_map
+    }
+
+    /// Returns the beacon output map.
+    fn beacon_output_map(&self) -> &Self::BeaconOutputMap {
+        &self.beacon_output_map
+    }
+
+    /// Returns the latest beacon output map.
+    fn latest_beacon_output_map(&self) -> &Self::LatestBeaconOutputMap {
+        &self.latest_beacon_output_map
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+}
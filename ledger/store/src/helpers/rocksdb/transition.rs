@@ -22,7 +22,7 @@ use crate::{
 };
 use console::{
     prelude::*,
-    program::{Ciphertext, Future, Identifier, Plaintext, ProgramID, Record},
+    program::{Ciphertext, Expiry, Future, Identifier, Plaintext, ProgramID, Record},
     types::{Field, Group},
 };
 
@@ -43,6 +43,8 @@ pub struct TransitionDB<N: Network> {
     tcm_map: DataMap<N::TransitionID, Field<N>>,
     /// The reverse `tcm` map.
     reverse_tcm_map: DataMap<Field<N>, N::TransitionID>,
+    /// The transition expiries.
+    expiry_map: DataMap<N::TransitionID, Expiry<N>>,
 }
 
 #[rustfmt::skip]
@@ -54,6 +56,7 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     type ReverseTPKMap = DataMap<Group<N>, N::TransitionID>;
     type TCMMap = DataMap<N::TransitionID, Field<N>>;
     type ReverseTCMMap = DataMap<Field<N>, N::TransitionID>;
+    type ExpiryMap = DataMap<N::TransitionID, Expiry<N>>;
 
     /// Initializes the transition storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -65,6 +68,7 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
             reverse_tpk_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transition(TransitionMap::ReverseTPK))?,
             tcm_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transition(TransitionMap::TCM))?,
             reverse_tcm_map: rocksdb::RocksDB::open_map(N::ID, dev,  MapID::Transition(TransitionMap::ReverseTCM))?,
+            expiry_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Transition(TransitionMap::Expiry))?,
         })
     }
 
@@ -102,6 +106,11 @@ impl<N: Network> TransitionStorage<N> for TransitionDB<N> {
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap {
         &self.reverse_tcm_map
     }
+
+    /// Returns the transition expiries.
+    fn expiry_map(&self) -> &Self::ExpiryMap {
+        &self.expiry_map
+    }
 }
 
 /// An database transition input storage.
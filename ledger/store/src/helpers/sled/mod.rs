@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::{BatchOperation, KeyValueStore, WriteBatch};
+use console::network::prelude::*;
+
+use std::path::Path;
+
+/// A key-value store backed by a sled database.
+#[derive(Clone)]
+pub struct SledKeyValueStore {
+    /// The database.
+    db: sled::Db,
+}
+
+impl SledKeyValueStore {
+    /// Opens the key-value store at the given directory, creating it if it does not exist.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(Self { db: sled::open(path)? })
+    }
+
+    /// Opens a temporary key-value store, which is removed when it is dropped.
+    pub fn open_temporary() -> Result<Self> {
+        Ok(Self { db: sled::Config::new().temporary(true).open()? })
+    }
+
+    /// Flushes the pending writes to disk.
+    pub fn flush(&self) -> Result<()> {
+        self.db.flush()?;
+        Ok(())
+    }
+}
+
+impl KeyValueStore for SledKeyValueStore {
+    /// Returns the value for the given key, if it exists.
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>> {
+        Ok(self.db.get(key)?.map(|value| value.to_vec()))
+    }
+
+    /// Inserts the value for the given key, replacing any existing value.
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<()> {
+        self.db.insert(key, value)?;
+        Ok(())
+    }
+
+    /// Removes the value for the given key, if it exists.
+    fn delete(&self, key: &[u8]) -> Result<()> {
+        self.db.remove(key)?;
+        Ok(())
+    }
+
+    /// Returns an iterator over the key-value pairs whose key starts with the given prefix, in key order.
+    fn iter_prefix<'a>(&'a self, prefix: &[u8]) -> Box<dyn 'a + Iterator<Item = Result<(Vec<u8>, Vec<u8>)>>> {
+        Box::new(
+            self.db
+                .scan_prefix(prefix)
+                .map(|pair| pair.map(|(key, value)| (key.to_vec(), value.to_vec())).map_err(Into::into)),
+        )
+    }
+
+    /// Applies the operations of the given batch atomically, in order.
+    fn write_batch(&self, batch: WriteBatch) -> Result<()> {
+        let mut sled_batch = sled::Batch::default();
+        for operation in batch.into_operations() {
+            match operation {
+                BatchOperation::Put(key, value) => sled_batch.insert(key, value),
+                BatchOperation::Delete(key) => sled_batch.remove(key),
+            }
+        }
+        self.db.apply_batch(sled_batch)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::test_helpers::key_value;
+
+    #[test]
+    fn test_get_put_delete() {
+        key_value::check_get_put_delete(&SledKeyValueStore::open_temporary().unwrap());
+    }
+
+    #[test]
+    fn test_iter_prefix() {
+        key_value::check_iter_prefix(&SledKeyValueStore::open_temporary().unwrap());
+    }
+
+    #[test]
+    fn test_write_batch() {
+        key_value::check_write_batch(&SledKeyValueStore::open_temporary().unwrap());
+    }
+
+    #[test]
+    fn test_stats_and_compact() {
+        key_value::check_stats_and_compact(&SledKeyValueStore::open_temporary().unwrap());
+    }
+
+    #[test]
+    fn test_reopen() {
+        let directory = tempfile::tempdir().unwrap();
+        {
+            let store = SledKeyValueStore::open(directory.path()).unwrap();
+            store.put(b"key", b"value").unwrap();
+            store.flush().unwrap();
+        }
+        // Check that the value persists across reopening.
+        let store = SledKeyValueStore::open(directory.path()).unwrap();
+        assert_eq!(store.get(b"key").unwrap(), Some(b"value".to_vec()));
+    }
+}
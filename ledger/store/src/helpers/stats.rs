@@ -0,0 +1,82 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+/// The number of keys and bytes in a column of a storage.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnStats {
+    /// The number of keys.
+    num_keys: u64,
+    /// The number of bytes in the keys and values.
+    num_bytes: u64,
+}
+
+impl ColumnStats {
+    /// Returns the number of keys.
+    pub const fn num_keys(&self) -> u64 {
+        self.num_keys
+    }
+
+    /// Returns the number of bytes in the keys and values.
+    pub const fn num_bytes(&self) -> u64 {
+        self.num_bytes
+    }
+
+    /// Adds an entry with the given key and value lengths.
+    fn add_entry(&mut self, key_len: usize, value_len: usize) {
+        self.num_keys = self.num_keys.saturating_add(1);
+        self.num_bytes = self.num_bytes.saturating_add(key_len.saturating_add(value_len) as u64);
+    }
+}
+
+/// The number of keys and bytes in a storage, in total and for each column.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageStats {
+    /// The totals across all columns.
+    total: ColumnStats,
+    /// The totals for each column, by column name.
+    columns: BTreeMap<String, ColumnStats>,
+}
+
+impl StorageStats {
+    /// Initializes new, empty storage statistics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of keys.
+    pub const fn num_keys(&self) -> u64 {
+        self.total.num_keys
+    }
+
+    /// Returns the number of bytes in the keys and values.
+    pub const fn num_bytes(&self) -> u64 {
+        self.total.num_bytes
+    }
+
+    /// Returns the statistics of each column, by column name.
+    /// Storages without columns return no columns.
+    pub const fn columns(&self) -> &BTreeMap<String, ColumnStats> {
+        &self.columns
+    }
+
+    /// Adds an entry with the given key and value lengths, in the given column, if any.
+    pub fn add_entry(&mut self, column: Option<&str>, key_len: usize, value_len: usize) {
+        self.total.add_entry(key_len, value_len);
+        if let Some(column) = column {
+            self.columns.entry(column.to_string()).or_default().add_entry(key_len, value_len);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::{KeyValueStore, WriteBatch};
+
+/// Returns the key-value pairs whose key starts with the given prefix.
+fn collect_prefix(store: &impl KeyValueStore, prefix: &[u8]) -> Vec<(Vec<u8>, Vec<u8>)> {
+    store.iter_prefix(prefix).collect::<Result<Vec<_>, _>>().unwrap()
+}
+
+pub fn check_get_put_delete(store: &impl KeyValueStore) {
+    // Check that a missing key has no value.
+    assert_eq!(store.get(b"key").unwrap(), None);
+    assert!(!store.contains_key(b"key").unwrap());
+
+    // Insert a value.
+    store.put(b"key", b"value").unwrap();
+    assert_eq!(store.get(b"key").unwrap(), Some(b"value".to_vec()));
+    assert!(store.contains_key(b"key").unwrap());
+
+    // Replace the value.
+    store.put(b"key", b"other").unwrap();
+    assert_eq!(store.get(b"key").unwrap(), Some(b"other".to_vec()));
+
+    // Remove the value, twice.
+    store.delete(b"key").unwrap();
+    assert_eq!(store.get(b"key").unwrap(), None);
+    store.delete(b"key").unwrap();
+    assert!(!store.contains_key(b"key").unwrap());
+
+    // Check that an empty value is distinct from a missing value.
+    store.put(b"empty", b"").unwrap();
+    assert_eq!(store.get(b"empty").unwrap(), Some(Vec::new()));
+}
+
+pub fn check_iter_prefix(store: &impl KeyValueStore) {
+    // Insert keys with and around the prefix, out of order.
+    for key in [&b"b/2"[..], b"a/1", b"b/1", b"b", b"c/1", b"b/10", b"b0"] {
+        store.put(key, key).unwrap();
+    }
+
+    // Check that only the keys with the prefix are returned, in key order.
+    let expected = [&b"b/1"[..], b"b/10", b"b/2"].iter().map(|key| (key.to_vec(), key.to_vec())).collect::<Vec<_>>();
+    assert_eq!(collect_prefix(store, b"b/"), expected);
+
+    // Check the boundaries.
+    assert_eq!(collect_prefix(store, b"d").len(), 0);
+    assert_eq!(collect_prefix(store, b"b").len(), 5);
+    assert_eq!(collect_prefix(store, b"").len(), 7);
+
+    // Check that a removed key is no longer returned.
+    store.delete(b"b/10").unwrap();
+    assert_eq!(collect_prefix(store, b"b/").len(), 2);
+}
+
+pub fn check_write_batch(store: &impl KeyValueStore) {
+    store.put(b"existing", b"value").unwrap();
+
+    // Prepare a batch, which is not applied until it is written.
+    let mut batch = WriteBatch::new();
+    batch.put(b"first".to_vec(), b"1".to_vec());
+    batch.put(b"second".to_vec(), b"2".to_vec());
+    batch.delete(b"existing".to_vec());
+    // Check that later operations take precedence over earlier ones.
+    batch.put(b"first".to_vec(), b"one".to_vec());
+    batch.delete(b"second".to_vec());
+    assert_eq!(batch.len(), 5);
+    assert_eq!(store.get(b"first").unwrap(), None);
+
+    // Write the batch.
+    store.write_batch(batch).unwrap();
+    assert_eq!(store.get(b"first").unwrap(), Some(b"one".to_vec()));
+    assert_eq!(store.get(b"second").unwrap(), None);
+    assert_eq!(store.get(b"existing").unwrap(), None);
+
+    // Check that an empty batch is a no-op.
+    store.write_batch(WriteBatch::new()).unwrap();
+    assert_eq!(collect_prefix(store, b"").len(), 1);
+}
+
+pub fn check_stats_and_compact(store: &impl KeyValueStore) {
+    // Check that an empty store has no keys.
+    assert_eq!(store.stats().unwrap().num_keys(), 0);
+    assert_eq!(store.stats().unwrap().num_bytes(), 0);
+
+    store.put(b"a", b"12").unwrap();
+    store.put(b"bc", b"345").unwrap();
+    let stats = store.stats().unwrap();
+    assert_eq!(stats.num_keys(), 2);
+    assert_eq!(stats.num_bytes(), 8);
+
+    // Check that a removed key is no longer counted.
+    store.delete(b"a").unwrap();
+    assert_eq!(store.stats().unwrap().num_keys(), 1);
+    assert_eq!(store.stats().unwrap().num_bytes(), 5);
+
+    // Check that compaction preserves the contents.
+    store.compact(None, None).unwrap();
+    store.compact(Some(b"a"), Some(b"c")).unwrap();
+    assert_eq!(collect_prefix(store, b""), vec![(b"bc".to_vec(), b"345".to_vec())]);
+}
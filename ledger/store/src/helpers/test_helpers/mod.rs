@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod key_value;
+
 pub mod map;
 
 pub mod nested_map;
@@ -0,0 +1,129 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::helpers::StorageStats;
+use console::network::prelude::Result;
+
+/// An operation in a [`WriteBatch`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BatchOperation {
+    /// Inserts the value for the key.
+    Put(Vec<u8>, Vec<u8>),
+    /// Removes the value for the key.
+    Delete(Vec<u8>),
+}
+
+/// A batch of write operations, which a [`KeyValueStore`] applies atomically and in order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WriteBatch {
+    /// The operations, in order.
+    operations: Vec<BatchOperation>,
+}
+
+impl WriteBatch {
+    /// Initializes a new, empty write batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an insertion of the value for the key.
+    pub fn put(&mut self, key: impl Into<Vec<u8>>, value: impl Into<Vec<u8>>) {
+        self.operations.push(BatchOperation::Put(key.into(), value.into()));
+    }
+
+    /// Adds a removal of the value for the key.
+    pub fn delete(&mut self, key: impl Into<Vec<u8>>) {
+        self.operations.push(BatchOperation::Delete(key.into()));
+    }
+
+    /// Returns the number of operations in the batch.
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns `true` if the batch has no operations.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Returns the operations, in order.
+    pub fn operations(&self) -> &[BatchOperation] {
+        &self.operations
+    }
+
+    /// Returns the operations, in order.
+    pub fn into_operations(self) -> Vec<BatchOperation> {
+        self.operations
+    }
+}
+
+/// A minimal key-value storage backend, for embedders to plug in their own database.
+///
+/// Keys are ordered lexicographically by their bytes, and every operation is durable once it returns,
+/// to the extent supported by the backend. The trait is object-safe, so a backend may be shared as an
+/// `Arc<dyn KeyValueStore>`.
+pub trait KeyValueStore: Send + Sync {
+    ///
+    /// Returns the value for the given key, if it exists.
+    ///
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>>;
+
+    ///
+    /// Inserts the value for the given key, replacing any existing value.
+    ///
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<()>;
+
+    ///
+    /// Removes the value for the given key, if it exists.
+    ///
+    fn delete(&self, key: &[u8]) -> Result<()>;
+
+    ///
+    /// Returns an iterator over the key-value pairs whose key starts with the given prefix, in key order.
+    ///
+    fn iter_prefix<'a>(&'a self, prefix: &[u8]) -> Box<dyn 'a + Iterator<Item = Result<(Vec<u8>, Vec<u8>)>>>;
+
+    ///
+    /// Applies the operations of the given batch atomically, in order.
+    ///
+    fn write_batch(&self, batch: WriteBatch) -> Result<()>;
+
+    ///
+    /// Returns `true` if the given key exists.
+    ///
+    fn contains_key(&self, key: &[u8]) -> Result<bool> {
+        Ok(self.get(key)?.is_some())
+    }
+
+    ///
+    /// Returns the number of keys and bytes in the store.
+    /// By default, this iterates over every key-value pair.
+    ///
+    fn stats(&self) -> Result<StorageStats> {
+        let mut stats = StorageStats::new();
+        for pair in self.iter_prefix(&[]) {
+            let (key, value) = pair?;
+            stats.add_entry(None, key.len(), value.len());
+        }
+        Ok(stats)
+    }
+
+    ///
+    /// Compacts the keys in the given range, where `None` is unbounded, to reclaim space.
+    /// By default, this does nothing, for backends that do not require compaction.
+    ///
+    fn compact(&self, _start: Option<&[u8]>, _end: Option<&[u8]>) -> Result<()> {
+        Ok(())
+    }
+}
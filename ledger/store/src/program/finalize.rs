@@ -17,7 +17,7 @@ use crate::{
     cow_to_cloned,
     cow_to_copied,
     helpers::{Map, MapRead, NestedMap, NestedMapRead},
-    program::{CommitteeStorage, CommitteeStore},
+    program::{CommitteeStorage, CommitteeStore, LedgerStateStorage, LedgerStateStore},
 };
 use console::{
     network::prelude::*,
@@ -72,6 +72,8 @@ fn to_key_id<N: Network>(
 pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The committee storage.
     type CommitteeStorage: CommitteeStorage<N>;
+    /// The ledger state storage.
+    type LedgerStateStorage: LedgerStateStorage<N>;
     /// The mapping of `program ID` to `[mapping name]`.
     type ProgramIDMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
     /// The mapping of `(program ID, mapping name)` to `[(key, value)]`.
@@ -86,6 +88,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
 
     /// Returns the committee storage.
     fn committee_store(&self) -> &CommitteeStore<N, Self::CommitteeStorage>;
+    /// Returns the ledger state storage.
+    fn ledger_state_store(&self) -> &LedgerStateStore<N, Self::LedgerStateStorage>;
     /// Returns the program ID map.
     fn program_id_map(&self) -> &Self::ProgramIDMap;
     /// Returns the key-value map.
@@ -97,6 +101,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.committee_store().start_atomic();
+        self.ledger_state_store().start_atomic();
         self.program_id_map().start_atomic();
         self.key_value_map().start_atomic();
     }
@@ -104,6 +109,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     /// Checks if an atomic batch is in progress.
     fn is_atomic_in_progress(&self) -> bool {
         self.committee_store().is_atomic_in_progress()
+            || self.ledger_state_store().is_atomic_in_progress()
             || self.program_id_map().is_atomic_in_progress()
             || self.key_value_map().is_atomic_in_progress()
     }
@@ -111,6 +117,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     /// Checkpoints the atomic batch.
     fn atomic_checkpoint(&self) {
         self.committee_store().atomic_checkpoint();
+        self.ledger_state_store().atomic_checkpoint();
         self.program_id_map().atomic_checkpoint();
         self.key_value_map().atomic_checkpoint();
     }
@@ -118,6 +125,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     /// Clears the latest atomic batch checkpoint.
     fn clear_latest_checkpoint(&self) {
         self.committee_store().clear_latest_checkpoint();
+        self.ledger_state_store().clear_latest_checkpoint();
         self.program_id_map().clear_latest_checkpoint();
         self.key_value_map().clear_latest_checkpoint();
     }
@@ -125,6 +133,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     /// Rewinds the atomic batch to the previous checkpoint.
     fn atomic_rewind(&self) {
         self.committee_store().atomic_rewind();
+        self.ledger_state_store().atomic_rewind();
         self.program_id_map().atomic_rewind();
         self.key_value_map().atomic_rewind();
     }
@@ -132,6 +141,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     /// Aborts an atomic batch write operation.
     fn abort_atomic(&self) {
         self.committee_store().abort_atomic();
+        self.ledger_state_store().abort_atomic();
         self.program_id_map().abort_atomic();
         self.key_value_map().abort_atomic();
     }
@@ -139,6 +149,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.committee_store().finish_atomic()?;
+        self.ledger_state_store().finish_atomic()?;
         self.program_id_map().finish_atomic()?;
         self.key_value_map().finish_atomic()
     }
@@ -592,6 +603,11 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     pub fn committee_store(&self) -> &CommitteeStore<N, P::CommitteeStorage> {
         self.storage.committee_store()
     }
+
+    /// Returns the ledger state store.
+    pub fn ledger_state_store(&self) -> &LedgerStateStore<N, P::LedgerStateStorage> {
+        self.storage.ledger_state_store()
+    }
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<N, P> {
@@ -0,0 +1,846 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    atomic_batch_scope,
+    cow_to_copied,
+    helpers::{Map, MapRead, NestedMap, NestedMapRead},
+};
+use console::{
+    network::prelude::*,
+    program::{ProgramID, SerialNumberLeaf},
+    types::{Address, Field},
+};
+
+use anyhow::Result;
+use core::{
+    marker::PhantomData,
+    ops::{Range, RangeInclusive},
+};
+use indexmap::{IndexMap, IndexSet};
+use serde::{Deserialize, Serialize};
+
+const UNVERIFIED_HEIGHTS_KEY: u8 = 0;
+const TOTAL_SUPPLY_KEY: u8 = 0;
+const INDEXES_ENABLED_KEY: u8 = 0;
+const BEACON_STATE_KEY: u8 = 0;
+const LATEST_BEACON_OUTPUT_KEY: u8 = 0;
+
+/// The state of the randomness beacon, as `(epoch, commitments, pending commitments, reveals)`,
+/// where the commitments and reveals are `(address, value)` pairs in the order they were added.
+pub type BeaconState<N> = (u32, Vec<(Address<N>, Field<N>)>, Vec<(Address<N>, Field<N>)>, Vec<(Address<N>, Field<N>)>);
+
+/// The activity statistics of a program, as of the latest block.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramStats {
+    /// The number of accepted deployments of the program.
+    pub deployments: u64,
+    /// The number of accepted calls to the program, either as the root call of an execution or as a nested call.
+    pub executions: u64,
+    /// The number of rejected executions, with the program as the root call.
+    pub rejected_executions: u64,
+    /// The number of unique callers, which are the distinct addresses that called the program in an execution
+    /// with a public fee. As the caller of an execution with a private fee is private, it is not counted.
+    pub unique_callers: u64,
+    /// The fees paid in microcredits, for the deployments and the executions with the program as the root call.
+    pub fees_paid: u64,
+}
+
+/// A trait for the storage of the ledger state that is derived from the blocks.
+pub trait LedgerStateStorage<N: Network>: 'static + Clone + Send + Sync {
+    /// The mapping of `()` to `(start height, end height)` of the blocks that are not yet verified.
+    type UnverifiedHeightsMap: for<'a> Map<'a, u8, (u32, u32)>;
+    /// The mapping of `leaf index` to `serial number leaf` of the serial numbers accumulator.
+    type SerialNumberLeafMap: for<'a> Map<'a, u32, SerialNumberLeaf<N>>;
+    /// The mapping of `serial number` to `leaf index` of the serial numbers accumulator.
+    type SerialNumberIndexMap: for<'a> Map<'a, Field<N>, u32>;
+    /// The mapping of `()` to `total supply` (in microcredits) after the latest block.
+    type TotalSupplyMap: for<'a> Map<'a, u8, u64>;
+    /// The mapping of `program ID` to `program stats` of the programs with activity.
+    type ProgramStatsMap: for<'a> Map<'a, ProgramID<N>, ProgramStats>;
+    /// The mapping of `(program ID, caller)` to `()` of the callers that are counted as unique callers.
+    type ProgramCallerMap: for<'a> Map<'a, (ProgramID<N>, Address<N>), ()>;
+    /// The mapping of `()` to `true` if the secondary indexes of the archive mode are enabled.
+    type IndexesEnabledMap: for<'a> Map<'a, u8, bool>;
+    /// The mapping of `commitment` to `block height` of the records, in the archive mode.
+    type CommitmentHeightMap: for<'a> Map<'a, Field<N>, u32>;
+    /// The mapping of `serial number` to `transaction ID` of the spending transactions, in the archive mode.
+    type SerialNumberTransactionMap: for<'a> Map<'a, Field<N>, N::TransactionID>;
+    /// The mapping of `tag` to `transaction ID` of the spending transactions, in the archive mode.
+    type TagTransactionMap: for<'a> Map<'a, Field<N>, N::TransactionID>;
+    /// The mapping of `program ID` to `(block height, transaction index)` to `transaction ID` of the transactions
+    /// that deploy or call the program, in the archive mode.
+    type ProgramTransactionsMap: for<'a> NestedMap<'a, ProgramID<N>, (u32, u32), N::TransactionID>;
+    /// The mapping of `()` to the `state` of the randomness beacon, after the latest block.
+    type BeaconStateMap: for<'a> Map<'a, u8, BeaconState<N>>;
+    /// The mapping of `commit epoch` to `output` of the randomness beacon.
+    type BeaconOutputMap: for<'a> Map<'a, u32, Field<N>>;
+    /// The mapping of `()` to the latest `output` of the randomness beacon.
+    type LatestBeaconOutputMap: for<'a> Map<'a, u8, Field<N>>;
+
+    /// Initializes the ledger state storage.
+    fn open(dev: Option<u16>) -> Result<Self>;
+
+    /// Initializes the test-variant of the storage.
+    #[cfg(any(test, feature = "test"))]
+    fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self>;
+
+    /// Returns the unverified heights map.
+    fn unverified_heights_map(&self) -> &Self::UnverifiedHeightsMap;
+
+    /// Returns the serial number leaf map.
+    fn serial_number_leaf_map(&self) -> &Self::SerialNumberLeafMap;
+
+    /// Returns the serial number index map.
+    fn serial_number_index_map(&self) -> &Self::SerialNumberIndexMap;
+
+    /// Returns the total supply map.
+    fn total_supply_map(&self) -> &Self::TotalSupplyMap;
+
+    /// Returns the program stats map.
+    fn program_stats_map(&self) -> &Self::ProgramStatsMap;
+
+    /// Returns the program caller map.
+    fn program_caller_map(&self) -> &Self::ProgramCallerMap;
+
+    /// Returns the indexes enabled map.
+    fn indexes_enabled_map(&self) -> &Self::IndexesEnabledMap;
+
+    /// Returns the commitment height map.
+    fn commitment_height_map(&self) -> &Self::CommitmentHeightMap;
+
+    /// Returns the serial number transaction map.
+    fn serial_number_transaction_map(&self) -> &Self::SerialNumberTransactionMap;
+
+    /// Returns the tag transaction map.
+    fn tag_transaction_map(&self) -> &Self::TagTransactionMap;
+
+    /// Returns the program transactions map.
+    fn program_transactions_map(&self) -> &Self::ProgramTransactionsMap;
+
+    /// Returns the beacon state map.
+    fn beacon_state_map(&self) -> &Self::BeaconStateMap;
+
+    /// Returns the beacon output map.
+    fn beacon_output_map(&self) -> &Self::BeaconOutputMap;
+
+    /// Returns the latest beacon output map.
+    fn latest_beacon_output_map(&self) -> &Self::LatestBeaconOutputMap;
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16>;
+
+    /// Starts an atomic batch write operation.
+    fn start_atomic(&self) {
+        self.unverified_heights_map().start_atomic();
+        self.serial_number_leaf_map().start_atomic();
+        self.serial_number_index_map().start_atomic();
+        self.total_supply_map().start_atomic();
+        self.program_stats_map().start_atomic();
+        self.program_caller_map().start_atomic();
+        self.indexes_enabled_map().start_atomic();
+        self.commitment_height_map().start_atomic();
+        self.serial_number_transaction_map().start_atomic();
+        self.tag_transaction_map().start_atomic();
+        self.program_transactions_map().start_atomic();
+        self.beacon_state_map().start_atomic();
+        self.beacon_output_map().start_atomic();
+        self.latest_beacon_output_map().start_atomic();
+    }
+
+    /// Checks if an atomic batch is in progress.
+    fn is_atomic_in_progress(&self) -> bool {
+        self.unverified_heights_map().is_atomic_in_progress()
+            || self.serial_number_leaf_map().is_atomic_in_progress()
+            || self.serial_number_index_map().is_atomic_in_progress()
+            || self.total_supply_map().is_atomic_in_progress()
+            || self.program_stats_map().is_atomic_in_progress()
+            || self.program_caller_map().is_atomic_in_progress()
+            || self.indexes_enabled_map().is_atomic_in_progress()
+            || self.commitment_height_map().is_atomic_in_progress()
+            || self.serial_number_transaction_map().is_atomic_in_progress()
+            || self.tag_transaction_map().is_atomic_in_progress()
+            || self.program_transactions_map().is_atomic_in_progress()
+            || self.beacon_state_map().is_atomic_in_progress()
+            || self.beacon_output_map().is_atomic_in_progress()
+            || self.latest_beacon_output_map().is_atomic_in_progress()
+    }
+
+    /// Checkpoints the atomic batch.
+    fn atomic_checkpoint(&self) {
+        self.unverified_heights_map().atomic_checkpoint();
+        self.serial_number_leaf_map().atomic_checkpoint();
+        self.serial_number_index_map().atomic_checkpoint();
+        self.total_supply_map().atomic_checkpoint();
+        self.program_stats_map().atomic_checkpoint();
+        self.program_caller_map().atomic_checkpoint();
+        self.indexes_enabled_map().atomic_checkpoint();
+        self.commitment_height_map().atomic_checkpoint();
+        self.serial_number_transaction_map().atomic_checkpoint();
+        self.tag_transaction_map().atomic_checkpoint();
+        self.program_transactions_map().atomic_checkpoint();
+        self.beacon_state_map().atomic_checkpoint();
+        self.beacon_output_map().atomic_checkpoint();
+        self.latest_beacon_output_map().atomic_checkpoint();
+    }
+
+    /// Clears the latest atomic batch checkpoint.
+    fn clear_latest_checkpoint(&self) {
+        self.unverified_heights_map().clear_latest_checkpoint();
+        self.serial_number_leaf_map().clear_latest_checkpoint();
+        self.serial_number_index_map().clear_latest_checkpoint();
+        self.total_supply_map().clear_latest_checkpoint();
+        self.program_stats_map().clear_latest_checkpoint();
+        self.program_caller_map().clear_latest_checkpoint();
+        self.indexes_enabled_map().clear_latest_checkpoint();
+        self.commitment_height_map().clear_latest_checkpoint();
+        self.serial_number_transaction_map().clear_latest_checkpoint();
+        self.tag_transaction_map().clear_latest_checkpoint();
+        self.program_transactions_map().clear_latest_checkpoint();
+        self.beacon_state_map().clear_latest_checkpoint();
+        self.beacon_output_map().clear_latest_checkpoint();
+        self.latest_beacon_output_map().clear_latest_checkpoint();
+    }
+
+    /// Rewinds the atomic batch to the previous checkpoint.
+    fn atomic_rewind(&self) {
+        self.unverified_heights_map().atomic_rewind();
+        self.serial_number_leaf_map().atomic_rewind();
+        self.serial_number_index_map().atomic_rewind();
+        self.total_supply_map().atomic_rewind();
+        self.program_stats_map().atomic_rewind();
+        self.program_caller_map().atomic_rewind();
+        self.indexes_enabled_map().atomic_rewind();
+        self.commitment_height_map().atomic_rewind();
+        self.serial_number_transaction_map().atomic_rewind();
+        self.tag_transaction_map().atomic_rewind();
+        self.program_transactions_map().atomic_rewind();
+        self.beacon_state_map().atomic_rewind();
+        self.beacon_output_map().atomic_rewind();
+        self.latest_beacon_output_map().atomic_rewind();
+    }
+
+    /// Aborts an atomic batch write operation.
+    fn abort_atomic(&self) {
+        self.unverified_heights_map().abort_atomic();
+        self.serial_number_leaf_map().abort_atomic();
+        self.serial_number_index_map().abort_atomic();
+        self.total_supply_map().abort_atomic();
+        self.program_stats_map().abort_atomic();
+        self.program_caller_map().abort_atomic();
+        self.indexes_enabled_map().abort_atomic();
+        self.commitment_height_map().abort_atomic();
+        self.serial_number_transaction_map().abort_atomic();
+        self.tag_transaction_map().abort_atomic();
+        self.program_transactions_map().abort_atomic();
+        self.beacon_state_map().abort_atomic();
+        self.beacon_output_map().abort_atomic();
+        self.latest_beacon_output_map().abort_atomic();
+    }
+
+    /// Finishes an atomic batch write operation.
+    fn finish_atomic(&self) -> Result<()> {
+        self.unverified_heights_map().finish_atomic()?;
+        self.serial_number_leaf_map().finish_atomic()?;
+        self.serial_number_index_map().finish_atomic()?;
+        self.total_supply_map().finish_atomic()?;
+        self.program_stats_map().finish_atomic()?;
+        self.program_caller_map().finish_atomic()?;
+        self.indexes_enabled_map().finish_atomic()?;
+        self.commitment_height_map().finish_atomic()?;
+        self.serial_number_transaction_map().finish_atomic()?;
+        self.tag_transaction_map().finish_atomic()?;
+        self.program_transactions_map().finish_atomic()?;
+        self.beacon_state_map().finish_atomic()?;
+        self.beacon_output_map().finish_atomic()?;
+        self.latest_beacon_output_map().finish_atomic()
+    }
+
+    /// Stores the given range of unverified heights, where `None` marks all of the blocks as verified.
+    fn set_unverified_heights(&self, heights: Option<RangeInclusive<u32>>) -> Result<()> {
+        atomic_batch_scope!(self, {
+            match heights {
+                Some(heights) => {
+                    // Ensure the range is not empty.
+                    ensure!(!heights.is_empty(), "The range of unverified heights is empty");
+                    self.unverified_heights_map().insert(UNVERIFIED_HEIGHTS_KEY, (*heights.start(), *heights.end()))
+                }
+                None => self.unverified_heights_map().remove(&UNVERIFIED_HEIGHTS_KEY),
+            }
+        })
+    }
+
+    /// Returns the range of unverified heights, if any blocks are not yet verified.
+    fn unverified_heights(&self) -> Result<Option<RangeInclusive<u32>>> {
+        match self.unverified_heights_map().get_confirmed(&UNVERIFIED_HEIGHTS_KEY)? {
+            Some(heights) => {
+                let (start, end) = cow_to_copied!(heights);
+                Ok(Some(start..=end))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Stores the given `(leaf index, serial number leaf)` pairs of the serial numbers accumulator.
+    fn insert_serial_number_leaves(&self, leaves: &[(u32, SerialNumberLeaf<N>)]) -> Result<()> {
+        atomic_batch_scope!(self, {
+            for (index, leaf) in leaves {
+                // Store the leaf.
+                self.serial_number_leaf_map().insert(*index, *leaf)?;
+                // Store the leaf index of the serial number.
+                self.serial_number_index_map().insert(leaf.serial_number(), *index)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the serial number leaves of the serial numbers accumulator, in insertion order.
+    fn serial_number_leaves(&self) -> Result<Vec<SerialNumberLeaf<N>>> {
+        let mut leaves = Vec::new();
+        // Retrieve the leaves in order, until the first missing index.
+        while let Some(leaf) = self.serial_number_leaf_map().get_confirmed(&u32::try_from(leaves.len())?)? {
+            leaves.push(cow_to_copied!(leaf));
+        }
+        Ok(leaves)
+    }
+
+    /// Returns `true` if the given serial number is in the serial numbers accumulator.
+    fn contains_serial_number(&self, serial_number: &Field<N>) -> Result<bool> {
+        // Note: The zero serial number is reserved for the sentinel leaf.
+        match serial_number.is_zero() {
+            true => Ok(false),
+            false => self.serial_number_index_map().contains_key_confirmed(serial_number),
+        }
+    }
+
+    /// Stores the total supply (in microcredits) after the latest block.
+    fn set_total_supply(&self, total_supply: u64) -> Result<()> {
+        self.total_supply_map().insert(TOTAL_SUPPLY_KEY, total_supply)
+    }
+
+    /// Returns the total supply (in microcredits) after the latest block, which is zero before the genesis block.
+    fn total_supply(&self) -> Result<u64> {
+        match self.total_supply_map().get_confirmed(&TOTAL_SUPPLY_KEY)? {
+            Some(total_supply) => Ok(cow_to_copied!(total_supply)),
+            None => Ok(0),
+        }
+    }
+
+    /// Stores the given program stats, and the given `(program ID, caller)` pairs that are counted as unique callers.
+    fn insert_program_stats(
+        &self,
+        stats: &IndexMap<ProgramID<N>, ProgramStats>,
+        callers: &IndexSet<(ProgramID<N>, Address<N>)>,
+    ) -> Result<()> {
+        atomic_batch_scope!(self, {
+            for (program_id, stats) in stats {
+                self.program_stats_map().insert(*program_id, *stats)?;
+            }
+            for caller in callers {
+                self.program_caller_map().insert(*caller, ())?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the program stats for the given program ID, or `None` if the program has no activity.
+    fn get_program_stats(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramStats>> {
+        match self.program_stats_map().get_confirmed(program_id)? {
+            Some(stats) => Ok(Some(cow_to_copied!(stats))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the program stats of every program with activity.
+    fn program_stats(&self) -> IndexMap<ProgramID<N>, ProgramStats> {
+        self.program_stats_map()
+            .iter_confirmed()
+            .map(|(program_id, stats)| (cow_to_copied!(program_id), cow_to_copied!(stats)))
+            .collect()
+    }
+
+    /// Returns `true` if the given caller is counted as a unique caller of the given program.
+    fn contains_program_caller(&self, program_id: &ProgramID<N>, caller: &Address<N>) -> Result<bool> {
+        self.program_caller_map().contains_key_confirmed(&(*program_id, *caller))
+    }
+
+    /// Enables the secondary indexes of the archive mode.
+    fn enable_indexes(&self) -> Result<()> {
+        self.indexes_enabled_map().insert(INDEXES_ENABLED_KEY, true)
+    }
+
+    /// Returns `true` if the secondary indexes of the archive mode are enabled.
+    fn is_indexed(&self) -> Result<bool> {
+        Ok(self.indexes_enabled_map().get_confirmed(&INDEXES_ENABLED_KEY)?.is_some())
+    }
+
+    /// Stores the given secondary indexes of the archive mode, where the transactions of the programs
+    /// are given as `(program ID, block height, transaction index, transaction ID)`.
+    fn insert_indexes(
+        &self,
+        commitments: &[(Field<N>, u32)],
+        serial_numbers: &[(Field<N>, N::TransactionID)],
+        tags: &[(Field<N>, N::TransactionID)],
+        programs: &[(ProgramID<N>, u32, u32, N::TransactionID)],
+    ) -> Result<()> {
+        atomic_batch_scope!(self, {
+            for (commitment, height) in commitments {
+                self.commitment_height_map().insert(*commitment, *height)?;
+            }
+            for (serial_number, transaction_id) in serial_numbers {
+                self.serial_number_transaction_map().insert(*serial_number, *transaction_id)?;
+            }
+            for (tag, transaction_id) in tags {
+                self.tag_transaction_map().insert(*tag, *transaction_id)?;
+            }
+            for (program_id, height, index, transaction_id) in programs {
+                self.program_transactions_map().insert(*program_id, (*height, *index), *transaction_id)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the block height that contains the given record commitment.
+    fn find_block_height_from_commitment(&self, commitment: &Field<N>) -> Result<Option<u32>> {
+        match self.commitment_height_map().get_confirmed(commitment)? {
+            Some(height) => Ok(Some(cow_to_copied!(height))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the transaction ID that spends the given serial number.
+    fn find_transaction_id_from_serial_number(&self, serial_number: &Field<N>) -> Result<Option<N::TransactionID>> {
+        match self.serial_number_transaction_map().get_confirmed(serial_number)? {
+            Some(transaction_id) => Ok(Some(cow_to_copied!(transaction_id))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the transaction ID that spends the record of the given tag.
+    fn find_transaction_id_from_tag(&self, tag: &Field<N>) -> Result<Option<N::TransactionID>> {
+        match self.tag_transaction_map().get_confirmed(tag)? {
+            Some(transaction_id) => Ok(Some(cow_to_copied!(transaction_id))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the transaction IDs that deploy or call the given program, in the given range of block heights.
+    fn find_transaction_ids_from_program_id(
+        &self,
+        program_id: &ProgramID<N>,
+        heights: Range<u32>,
+    ) -> Result<Vec<N::TransactionID>> {
+        // Retrieve the transactions of the program in the given range of block heights.
+        let mut transactions = self
+            .program_transactions_map()
+            .get_map_confirmed(program_id)?
+            .into_iter()
+            .filter(|((height, _), _)| heights.contains(height))
+            .collect::<Vec<_>>();
+        // Sort the transactions in block order, as the entries of a nested map are not returned in key order.
+        transactions.sort_unstable_by_key(|(key, _)| *key);
+        Ok(transactions.into_iter().map(|(_, transaction_id)| transaction_id).collect())
+    }
+
+    /// Stores the given state of the randomness beacon, and its new `(commit epoch, output)`, if any.
+    fn set_beacon_state(&self, state: BeaconState<N>, output: Option<(u32, Field<N>)>) -> Result<()> {
+        atomic_batch_scope!(self, {
+            self.beacon_state_map().insert(BEACON_STATE_KEY, state)?;
+            if let Some((epoch, output)) = output {
+                self.beacon_output_map().insert(epoch, output)?;
+                self.latest_beacon_output_map().insert(LATEST_BEACON_OUTPUT_KEY, output)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the state of the randomness beacon, or `None` if there are no blocks.
+    fn beacon_state(&self) -> Result<Option<BeaconState<N>>> {
+        match self.beacon_state_map().get_confirmed(&BEACON_STATE_KEY)? {
+            Some(state) => Ok(Some(cow_to_cloned!(state))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the output of the randomness beacon for the given commit epoch, if the epoch has an output.
+    fn beacon_output(&self, epoch: u32) -> Result<Option<Field<N>>> {
+        match self.beacon_output_map().get_confirmed(&epoch)? {
+            Some(output) => Ok(Some(cow_to_copied!(output))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the latest output of the randomness beacon, which is zero until the beacon has an output.
+    fn latest_beacon_output(&self) -> Result<Field<N>> {
+        match self.latest_beacon_output_map().get_confirmed(&LATEST_BEACON_OUTPUT_KEY)? {
+            Some(output) => Ok(cow_to_copied!(output)),
+            None => Ok(Field::zero()),
+        }
+    }
+}
+
+/// The ledger state store.
+#[derive(Clone)]
+pub struct LedgerStateStore<N: Network, S: LedgerStateStorage<N>> {
+    /// The ledger state storage.
+    storage: S,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network, S: LedgerStateStorage<N>> LedgerStateStore<N, S> {
+    /// Initializes the ledger state store.
+    pub fn open(dev: Option<u16>) -> Result<Self> {
+        // Initialize the ledger state storage.
+        let storage = S::open(dev)?;
+        // Return the ledger state store.
+        Ok(Self { storage, _phantom: PhantomData })
+    }
+
+    /// Initializes the test-variant of the storage.
+    #[cfg(any(test, feature = "test"))]
+    pub fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
+        // Initialize the ledger state storage.
+        let storage = S::open_testing(temp_dir, dev)?;
+        // Return the ledger state store.
+        Ok(Self { storage, _phantom: PhantomData })
+    }
+
+    /// Initializes a ledger state store from storage.
+    pub fn from(storage: S) -> Self {
+        Self { storage, _phantom: PhantomData }
+    }
+
+    /// Starts an atomic batch write operation.
+    pub fn start_atomic(&self) {
+        self.storage.start_atomic();
+    }
+
+    /// Checks if an atomic batch is in progress.
+    pub fn is_atomic_in_progress(&self) -> bool {
+        self.storage.is_atomic_in_progress()
+    }
+
+    /// Checkpoints the atomic batch.
+    pub fn atomic_checkpoint(&self) {
+        self.storage.atomic_checkpoint();
+    }
+
+    /// Clears the latest atomic batch checkpoint.
+    pub fn clear_latest_checkpoint(&self) {
+        self.storage.clear_latest_checkpoint();
+    }
+
+    /// Rewinds the atomic batch to the previous checkpoint.
+    pub fn atomic_rewind(&self) {
+        self.storage.atomic_rewind();
+    }
+
+    /// Aborts an atomic batch write operation.
+    pub fn abort_atomic(&self) {
+        self.storage.abort_atomic();
+    }
+
+    /// Finishes an atomic batch write operation.
+    pub fn finish_atomic(&self) -> Result<()> {
+        self.storage.finish_atomic()
+    }
+
+    /// Returns the optional development ID.
+    pub fn dev(&self) -> Option<u16> {
+        self.storage.dev()
+    }
+}
+
+impl<N: Network, S: LedgerStateStorage<N>> LedgerStateStore<N, S> {
+    /// Stores the given range of unverified heights, where `None` marks all of the blocks as verified.
+    pub fn set_unverified_heights(&self, heights: Option<RangeInclusive<u32>>) -> Result<()> {
+        self.storage.set_unverified_heights(heights)
+    }
+
+    /// Returns the range of unverified heights, if any blocks are not yet verified.
+    pub fn unverified_heights(&self) -> Result<Option<RangeInclusive<u32>>> {
+        self.storage.unverified_heights()
+    }
+
+    /// Stores the given `(leaf index, serial number leaf)` pairs of the serial numbers accumulator.
+    pub fn insert_serial_number_leaves(&self, leaves: &[(u32, SerialNumberLeaf<N>)]) -> Result<()> {
+        self.storage.insert_serial_number_leaves(leaves)
+    }
+
+    /// Returns the serial number leaves of the serial numbers accumulator, in insertion order.
+    pub fn serial_number_leaves(&self) -> Result<Vec<SerialNumberLeaf<N>>> {
+        self.storage.serial_number_leaves()
+    }
+
+    /// Returns `true` if the given serial number is in the serial numbers accumulator.
+    pub fn contains_serial_number(&self, serial_number: &Field<N>) -> Result<bool> {
+        self.storage.contains_serial_number(serial_number)
+    }
+
+    /// Stores the total supply (in microcredits) after the latest block.
+    pub fn set_total_supply(&self, total_supply: u64) -> Result<()> {
+        self.storage.set_total_supply(total_supply)
+    }
+
+    /// Returns the total supply (in microcredits) after the latest block, which is zero before the genesis block.
+    pub fn total_supply(&self) -> Result<u64> {
+        self.storage.total_supply()
+    }
+
+    /// Stores the given program stats, and the given `(program ID, caller)` pairs that are counted as unique callers.
+    pub fn insert_program_stats(
+        &self,
+        stats: &IndexMap<ProgramID<N>, ProgramStats>,
+        callers: &IndexSet<(ProgramID<N>, Address<N>)>,
+    ) -> Result<()> {
+        self.storage.insert_program_stats(stats, callers)
+    }
+
+    /// Returns the program stats for the given program ID, or `None` if the program has no activity.
+    pub fn get_program_stats(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramStats>> {
+        self.storage.get_program_stats(program_id)
+    }
+
+    /// Returns the program stats of every program with activity.
+    pub fn program_stats(&self) -> IndexMap<ProgramID<N>, ProgramStats> {
+        self.storage.program_stats()
+    }
+
+    /// Returns `true` if the given caller is counted as a unique caller of the given program.
+    pub fn contains_program_caller(&self, program_id: &ProgramID<N>, caller: &Address<N>) -> Result<bool> {
+        self.storage.contains_program_caller(program_id, caller)
+    }
+
+    /// Enables the secondary indexes of the archive mode.
+    pub fn enable_indexes(&self) -> Result<()> {
+        self.storage.enable_indexes()
+    }
+
+    /// Returns `true` if the secondary indexes of the archive mode are enabled.
+    pub fn is_indexed(&self) -> Result<bool> {
+        self.storage.is_indexed()
+    }
+
+    /// Stores the given secondary indexes of the archive mode, where the transactions of the programs
+    /// are given as `(program ID, block height, transaction index, transaction ID)`.
+    pub fn insert_indexes(
+        &self,
+        commitments: &[(Field<N>, u32)],
+        serial_numbers: &[(Field<N>, N::TransactionID)],
+        tags: &[(Field<N>, N::TransactionID)],
+        programs: &[(ProgramID<N>, u32, u32, N::TransactionID)],
+    ) -> Result<()> {
+        self.storage.insert_indexes(commitments, serial_numbers, tags, programs)
+    }
+
+    /// Returns the block height that contains the given record commitment.
+    pub fn find_block_height_from_commitment(&self, commitment: &Field<N>) -> Result<Option<u32>> {
+        self.storage.find_block_height_from_commitment(commitment)
+    }
+
+    /// Returns the transaction ID that spends the given serial number.
+    pub fn find_transaction_id_from_serial_number(&self, serial_number: &Field<N>) -> Result<Option<N::TransactionID>> {
+        self.storage.find_transaction_id_from_serial_number(serial_number)
+    }
+
+    /// Returns the transaction ID that spends the record of the given tag.
+    pub fn find_transaction_id_from_tag(&self, tag: &Field<N>) -> Result<Option<N::TransactionID>> {
+        self.storage.find_transaction_id_from_tag(tag)
+    }
+
+    /// Returns the transaction IDs that deploy or call the given program, in the given range of block heights.
+    pub fn find_transaction_ids_from_program_id(
+        &self,
+        program_id: &ProgramID<N>,
+        heights: Range<u32>,
+    ) -> Result<Vec<N::TransactionID>> {
+        self.storage.find_transaction_ids_from_program_id(program_id, heights)
+    }
+
+    /// Stores the given state of the randomness beacon, and its new `(commit epoch, output)`, if any.
+    pub fn set_beacon_state(&self, state: BeaconState<N>, output: Option<(u32, Field<N>)>) -> Result<()> {
+        self.storage.set_beacon_state(state, output)
+    }
+
+    /// Returns the state of the randomness beacon, or `None` if there are no blocks.
+    pub fn beacon_state(&self) -> Result<Option<BeaconState<N>>> {
+        self.storage.beacon_state()
+    }
+
+    /// Returns the output of the randomness beacon for the given commit epoch, if the epoch has an output.
+    pub fn beacon_output(&self, epoch: u32) -> Result<Option<Field<N>>> {
+        self.storage.beacon_output(epoch)
+    }
+
+    /// Returns the latest output of the randomness beacon, which is zero until the beacon has an output.
+    pub fn latest_beacon_output(&self) -> Result<Field<N>> {
+        self.storage.latest_beacon_output()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::memory::LedgerStateMemory;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_unverified_heights() {
+        // Initialize a new ledger state store.
+        let store = LedgerStateStore::<CurrentNetwork, LedgerStateMemory<CurrentNetwork>>::open(None).unwrap();
+        assert_eq!(store.unverified_heights().unwrap(), None);
+
+        // Store the unverified heights.
+        store.set_unverified_heights(Some(1..=10)).unwrap();
+        assert_eq!(store.unverified_heights().unwrap(), Some(1..=10));
+        store.set_unverified_heights(Some(1..=5)).unwrap();
+        assert_eq!(store.unverified_heights().unwrap(), Some(1..=5));
+
+        // Ensure an empty range is rejected, and the heights are unchanged.
+        assert!(store.set_unverified_heights(Some(RangeInclusive::new(5, 4))).is_err());
+        assert_eq!(store.unverified_heights().unwrap(), Some(1..=5));
+
+        // Mark all of the blocks as verified.
+        store.set_unverified_heights(None).unwrap();
+        assert_eq!(store.unverified_heights().unwrap(), None);
+    }
+
+    #[test]
+    fn test_serial_number_leaves() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new ledger state store.
+        let store = LedgerStateStore::<CurrentNetwork, LedgerStateMemory<CurrentNetwork>>::open(None).unwrap();
+        assert!(store.serial_number_leaves().unwrap().is_empty());
+
+        // Sample the serial numbers.
+        let first: Field<CurrentNetwork> = Uniform::rand(rng);
+        let second: Field<CurrentNetwork> = Uniform::rand(rng);
+
+        // Store the sentinel leaf and the first leaf.
+        let sentinel = SerialNumberLeaf::new_with_version(Field::zero(), first);
+        let leaf = SerialNumberLeaf::new_with_version(first, Field::zero());
+        store.insert_serial_number_leaves(&[(0, sentinel), (1, leaf)]).unwrap();
+        assert_eq!(store.serial_number_leaves().unwrap(), vec![sentinel, leaf]);
+        assert!(store.contains_serial_number(&first).unwrap());
+        assert!(!store.contains_serial_number(&second).unwrap());
+        assert!(!store.contains_serial_number(&Field::zero()).unwrap());
+
+        // Update the first leaf, and store the second leaf.
+        let updated = SerialNumberLeaf::new_with_version(first, second);
+        let leaf = SerialNumberLeaf::new_with_version(second, Field::zero());
+        store.insert_serial_number_leaves(&[(1, updated), (2, leaf)]).unwrap();
+        assert_eq!(store.serial_number_leaves().unwrap(), vec![sentinel, updated, leaf]);
+        assert!(store.contains_serial_number(&first).unwrap());
+        assert!(store.contains_serial_number(&second).unwrap());
+    }
+
+    #[test]
+    fn test_total_supply() {
+        // Initialize a new ledger state store.
+        let store = LedgerStateStore::<CurrentNetwork, LedgerStateMemory<CurrentNetwork>>::open(None).unwrap();
+        assert_eq!(store.total_supply().unwrap(), 0);
+
+        // Store the total supply.
+        store.set_total_supply(1_500_000_000_000_000).unwrap();
+        assert_eq!(store.total_supply().unwrap(), 1_500_000_000_000_000);
+        store.set_total_supply(1_500_000_000_100_000).unwrap();
+        assert_eq!(store.total_supply().unwrap(), 1_500_000_000_100_000);
+    }
+
+    #[test]
+    fn test_program_stats() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new ledger state store.
+        let store = LedgerStateStore::<CurrentNetwork, LedgerStateMemory<CurrentNetwork>>::open(None).unwrap();
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let caller: Address<CurrentNetwork> = Uniform::rand(rng);
+        assert_eq!(store.get_program_stats(&program_id).unwrap(), None);
+        assert!(store.program_stats().is_empty());
+        assert!(!store.contains_program_caller(&program_id, &caller).unwrap());
+
+        // Store the program stats and the caller.
+        let stats = ProgramStats { executions: 1, unique_callers: 1, fees_paid: 100, ..Default::default() };
+        let callers = [(program_id, caller)].into_iter().collect();
+        store.insert_program_stats(&[(program_id, stats)].into_iter().collect(), &callers).unwrap();
+        assert_eq!(store.get_program_stats(&program_id).unwrap(), Some(stats));
+        assert_eq!(store.program_stats(), [(program_id, stats)].into_iter().collect());
+        assert!(store.contains_program_caller(&program_id, &caller).unwrap());
+    }
+
+    #[test]
+    fn test_indexes() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new ledger state store.
+        let store = LedgerStateStore::<CurrentNetwork, LedgerStateMemory<CurrentNetwork>>::open(None).unwrap();
+        assert!(!store.is_indexed().unwrap());
+        store.enable_indexes().unwrap();
+        assert!(store.is_indexed().unwrap());
+
+        // Sample the indexed values.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let commitment: Field<CurrentNetwork> = Uniform::rand(rng);
+        let serial_number: Field<CurrentNetwork> = Uniform::rand(rng);
+        let tag: Field<CurrentNetwork> = Uniform::rand(rng);
+        let transaction_ids: Vec<<CurrentNetwork as Network>::TransactionID> =
+            (0..3).map(|_| Field::<CurrentNetwork>::rand(rng).into()).collect();
+
+        // Store the indexes of two blocks, in one atomic batch.
+        store.start_atomic();
+        let programs = [(program_id, 1, 0, transaction_ids[0]), (program_id, 1, 2, transaction_ids[1])];
+        store.insert_indexes(&[(commitment, 1)], &[(serial_number, transaction_ids[0])], &[], &programs).unwrap();
+        let programs = [(program_id, 3, 1, transaction_ids[2])];
+        store.insert_indexes(&[], &[], &[(tag, transaction_ids[2])], &programs).unwrap();
+        store.finish_atomic().unwrap();
+
+        assert_eq!(store.find_block_height_from_commitment(&commitment).unwrap(), Some(1));
+        assert_eq!(store.find_block_height_from_commitment(&serial_number).unwrap(), None);
+        assert_eq!(store.find_transaction_id_from_serial_number(&serial_number).unwrap(), Some(transaction_ids[0]));
+        assert_eq!(store.find_transaction_id_from_tag(&tag).unwrap(), Some(transaction_ids[2]));
+        assert_eq!(store.find_transaction_ids_from_program_id(&program_id, 0..u32::MAX).unwrap(), transaction_ids);
+        assert_eq!(store.find_transaction_ids_from_program_id(&program_id, 1..2).unwrap(), transaction_ids[..2]);
+        assert!(store.find_transaction_ids_from_program_id(&program_id, 2..3).unwrap().is_empty());
+        assert_eq!(store.find_transaction_ids_from_program_id(&program_id, 3..4).unwrap(), transaction_ids[2..]);
+    }
+
+    #[test]
+    fn test_beacon_state() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new ledger state store.
+        let store = LedgerStateStore::<CurrentNetwork, LedgerStateMemory<CurrentNetwork>>::open(None).unwrap();
+        assert_eq!(store.beacon_state().unwrap(), None);
+        assert_eq!(store.latest_beacon_output().unwrap(), Field::zero());
+
+        // Store a beacon state, without an output.
+        let address: Address<CurrentNetwork> = Uniform::rand(rng);
+        let state = (1, vec![(address, Uniform::rand(rng))], vec![], vec![]);
+        store.set_beacon_state(state.clone(), None).unwrap();
+        assert_eq!(store.beacon_state().unwrap(), Some(state));
+        assert_eq!(store.beacon_output(0).unwrap(), None);
+        assert_eq!(store.latest_beacon_output().unwrap(), Field::zero());
+
+        // Store a beacon state, with an output.
+        let output: Field<CurrentNetwork> = Uniform::rand(rng);
+        let state = (3, vec![], vec![], vec![]);
+        store.set_beacon_state(state.clone(), Some((1, output))).unwrap();
+        assert_eq!(store.beacon_state().unwrap(), Some(state));
+        assert_eq!(store.beacon_output(1).unwrap(), Some(output));
+        assert_eq!(store.latest_beacon_output().unwrap(), output);
+    }
+}
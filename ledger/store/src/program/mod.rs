@@ -17,3 +17,6 @@ pub use committee::*;
 
 mod finalize;
 pub use finalize::*;
+
+mod ledger_state;
+pub use ledger_state::*;
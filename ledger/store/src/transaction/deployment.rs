@@ -36,18 +36,20 @@ use std::borrow::Cow;
 pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     /// The mapping of `transaction ID` to `program ID`.
     type IDMap: for<'a> Map<'a, N::TransactionID, ProgramID<N>>;
-    /// The mapping of `program ID` to `edition`.
+    /// The mapping of `program ID` to the latest `program edition`.
     type EditionMap: for<'a> Map<'a, ProgramID<N>, u16>;
-    /// The mapping of `(program ID, edition)` to `transaction ID`.
+    /// The mapping of `(program ID, program edition)` to `transaction ID`.
     type ReverseIDMap: for<'a> Map<'a, (ProgramID<N>, u16), N::TransactionID>;
-    /// The mapping of `(program ID, edition)` to `ProgramOwner`.
+    /// The mapping of `(program ID, program edition)` to `ProgramOwner`.
     type OwnerMap: for<'a> Map<'a, (ProgramID<N>, u16), ProgramOwner<N>>;
-    /// The mapping of `(program ID, edition)` to `program`.
+    /// The mapping of `(program ID, program edition)` to `program`.
     type ProgramMap: for<'a> Map<'a, (ProgramID<N>, u16), Program<N>>;
-    /// The mapping of `(program ID, function name, edition)` to `verifying key`.
+    /// The mapping of `(program ID, function name, program edition)` to `verifying key`.
     type VerifyingKeyMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), VerifyingKey<N>>;
-    /// The mapping of `(program ID, function name, edition)` to `certificate`.
+    /// The mapping of `(program ID, function name, program edition)` to `certificate`.
     type CertificateMap: for<'a> Map<'a, (ProgramID<N>, Identifier<N>, u16), Certificate<N>>;
+    /// The mapping of `(program ID, program edition)` to the network `edition` of the deployment.
+    type NetworkEditionMap: for<'a> Map<'a, (ProgramID<N>, u16), u16>;
     /// The fee storage.
     type FeeStorage: FeeStorage<N>;
 
@@ -68,6 +70,8 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
     fn verifying_key_map(&self) -> &Self::VerifyingKeyMap;
     /// Returns the certificate map.
     fn certificate_map(&self) -> &Self::CertificateMap;
+    /// Returns the network edition map.
+    fn network_edition_map(&self) -> &Self::NetworkEditionMap;
     /// Returns the fee storage.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage>;
 
@@ -85,6 +89,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().start_atomic();
         self.verifying_key_map().start_atomic();
         self.certificate_map().start_atomic();
+        self.network_edition_map().start_atomic();
         self.fee_store().start_atomic();
     }
 
@@ -97,6 +102,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             || self.program_map().is_atomic_in_progress()
             || self.verifying_key_map().is_atomic_in_progress()
             || self.certificate_map().is_atomic_in_progress()
+            || self.network_edition_map().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
     }
 
@@ -109,6 +115,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().atomic_checkpoint();
         self.verifying_key_map().atomic_checkpoint();
         self.certificate_map().atomic_checkpoint();
+        self.network_edition_map().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
     }
 
@@ -121,6 +128,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().clear_latest_checkpoint();
         self.verifying_key_map().clear_latest_checkpoint();
         self.certificate_map().clear_latest_checkpoint();
+        self.network_edition_map().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
     }
 
@@ -133,6 +141,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().atomic_rewind();
         self.verifying_key_map().atomic_rewind();
         self.certificate_map().atomic_rewind();
+        self.network_edition_map().atomic_rewind();
         self.fee_store().atomic_rewind();
     }
 
@@ -145,6 +154,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().abort_atomic();
         self.verifying_key_map().abort_atomic();
         self.certificate_map().abort_atomic();
+        self.network_edition_map().abort_atomic();
         self.fee_store().abort_atomic();
     }
 
@@ -157,6 +167,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         self.program_map().finish_atomic()?;
         self.verifying_key_map().finish_atomic()?;
         self.certificate_map().finish_atomic()?;
+        self.network_edition_map().finish_atomic()?;
         self.fee_store().finish_atomic()
     }
 
@@ -175,17 +186,28 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
 
         // Retrieve the edition.
-        let edition = deployment.edition();
+        let edition = deployment.program_edition();
         // Retrieve the program.
         let program = deployment.program();
         // Retrieve the program ID.
         let program_id = *program.id();
 
+        // Ensure the program edition follows the latest deployed program edition.
+        match self.get_edition(&program_id)? {
+            None => ensure!(edition == 0, "Cannot upgrade program '{program_id}', as it is not deployed"),
+            Some(latest) => ensure!(
+                latest.checked_add(1) == Some(edition),
+                "Cannot deploy edition {edition} of program '{program_id}', as the latest edition is {latest}"
+            ),
+        }
+
         atomic_batch_scope!(self, {
             // Store the program ID.
             self.id_map().insert(*transaction_id, program_id)?;
             // Store the edition.
             self.edition_map().insert(program_id, edition)?;
+            // Store the network edition.
+            self.network_edition_map().insert((program_id, edition), deployment.edition())?;
 
             // Store the reverse program ID.
             self.reverse_id_map().insert((program_id, edition), *transaction_id)?;
@@ -217,10 +239,14 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the program ID for transaction '{transaction_id}'"),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.get_program_edition(transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to locate the edition for program '{program_id}'"),
         };
+        // Ensure the edition is the latest, as the later editions upgrade it.
+        if self.get_edition(&program_id)? != Some(edition) {
+            bail!("Cannot remove edition {edition} of program '{program_id}', as it is not the latest edition")
+        }
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -230,8 +256,13 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         atomic_batch_scope!(self, {
             // Remove the program ID.
             self.id_map().remove(transaction_id)?;
-            // Remove the edition.
-            self.edition_map().remove(&program_id)?;
+            // Restore the previous edition, or remove the edition if this is the initial deployment.
+            match edition.checked_sub(1) {
+                Some(previous_edition) => self.edition_map().insert(program_id, previous_edition)?,
+                None => self.edition_map().remove(&program_id)?,
+            }
+            // Remove the network edition.
+            self.network_edition_map().remove(&(program_id, edition))?;
 
             // Remove the reverse program ID.
             self.reverse_id_map().remove(&(program_id, edition))?;
@@ -308,6 +339,29 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
     }
 
+    /// Returns the program edition deployed in the given `transaction ID`.
+    fn get_program_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        // Retrieve the program ID.
+        let program_id = match self.get_program_id(transaction_id)? {
+            Some(program_id) => program_id,
+            None => return Ok(None),
+        };
+        // Retrieve the latest edition.
+        let latest_edition = match self.get_edition(&program_id)? {
+            Some(edition) => edition,
+            None => bail!("Failed to get the edition for program '{program_id}'"),
+        };
+        // Find the edition deployed in the transaction, starting from the latest edition.
+        for edition in (0..=latest_edition).rev() {
+            if let Some(candidate) = self.reverse_id_map().get_confirmed(&(program_id, edition))? {
+                if *candidate == *transaction_id {
+                    return Ok(Some(edition));
+                }
+            }
+        }
+        bail!("Failed to find the edition of program '{program_id}' for transaction '{transaction_id}'")
+    }
+
     /// Returns the program for the given `program ID`.
     fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         // Check if the program ID is for 'credits.aleo'.
@@ -389,10 +443,15 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => return Ok(None),
         };
         // Retrieve the edition.
-        let edition = match self.get_edition(&program_id)? {
+        let edition = match self.get_program_edition(transaction_id)? {
             Some(edition) => edition,
             None => bail!("Failed to get the edition for program '{program_id}'"),
         };
+        // Retrieve the network edition.
+        let network_edition = match self.network_edition_map().get_confirmed(&(program_id, edition))? {
+            Some(network_edition) => cow_to_copied!(network_edition),
+            None => bail!("Failed to get the network edition for program '{program_id}' (edition {edition})"),
+        };
         // Retrieve the program.
         let program = match self.program_map().get_confirmed(&(program_id, edition))? {
             Some(program) => cow_to_cloned!(program),
@@ -419,7 +478,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         }
 
         // Return the deployment.
-        Ok(Some(Deployment::new(edition, program, verifying_keys)?))
+        Ok(Some(Deployment::new_with_program_edition(network_edition, edition, program, verifying_keys)?))
     }
 
     /// Returns the fee for the given `transaction ID`.
@@ -436,8 +495,7 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             return Ok(None);
         }
 
-        // TODO (raychu86): Consider program upgrades and edition changes.
-        // Retrieve the edition.
+        // Retrieve the latest edition, as upgrades are signed by the same owner.
         let edition = match self.get_edition(program_id)? {
             Some(edition) => edition,
             None => return Ok(None),
@@ -463,9 +521,9 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
             None => bail!("Failed to get the fee for transaction '{transaction_id}'"),
         };
 
-        // Retrieve the owner.
-        let owner = match self.get_owner(deployment.program_id())? {
-            Some(owner) => owner,
+        // Retrieve the owner of the deployed edition.
+        let owner = match self.owner_map().get_confirmed(&(*deployment.program_id(), deployment.program_edition()))? {
+            Some(owner) => cow_to_copied!(owner),
             None => bail!("Failed to get the owner for transaction '{transaction_id}'"),
         };
 
@@ -564,11 +622,16 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_deployment(transaction_id)
     }
 
-    /// Returns the edition for the given `program ID`.
+    /// Returns the latest program edition for the given `program ID`.
     pub fn get_edition(&self, program_id: &ProgramID<N>) -> Result<Option<u16>> {
         self.storage.get_edition(program_id)
     }
 
+    /// Returns the program edition deployed in the given `transaction ID`.
+    pub fn get_program_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
+        self.storage.get_program_edition(transaction_id)
+    }
+
     /// Returns the program ID for the given `transaction ID`.
     pub fn get_program_id(&self, transaction_id: &N::TransactionID) -> Result<Option<ProgramID<N>>> {
         self.storage.get_program_id(transaction_id)
@@ -601,10 +664,15 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
     pub fn get_fee(&self, transaction_id: &N::TransactionID) -> Result<Option<Fee<N>>> {
         self.storage.get_fee(transaction_id)
     }
+
+    /// Returns the owner for the given `program ID`.
+    pub fn get_owner(&self, program_id: &ProgramID<N>) -> Result<Option<ProgramOwner<N>>> {
+        self.storage.get_owner(program_id)
+    }
 }
 
 impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
-    /// Returns the transaction ID that deployed the given `program ID`.
+    /// Returns the transaction ID that deployed the latest edition of the given `program ID`.
     pub fn find_transaction_id_from_program_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
         self.storage.find_transaction_id_from_program_id(program_id)
     }
@@ -633,17 +701,18 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
 
     /// Returns an iterator over the program IDs, for all deployments.
     pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
-        self.storage.id_map().values_confirmed().map(|id| match id {
-            Cow::Borrowed(id) => Cow::Borrowed(id),
-            Cow::Owned(id) => Cow::Owned(id),
-        })
+        self.storage.edition_map().keys_confirmed()
     }
 
-    /// Returns an iterator over the programs, for all deployments.
+    /// Returns an iterator over the programs, at the latest edition of each deployed program.
     pub fn programs(&self) -> impl '_ + Iterator<Item = Cow<'_, Program<N>>> {
-        self.storage.program_map().values_confirmed().map(|program| match program {
-            Cow::Borrowed(program) => Cow::Borrowed(program),
-            Cow::Owned(program) => Cow::Owned(program),
+        self.storage.program_map().iter_confirmed().filter_map(|(key, program)| {
+            // Skip the editions that are upgraded by a later edition.
+            let (program_id, edition) = &*key;
+            match self.storage.edition_map().get_confirmed(program_id) {
+                Ok(Some(latest_edition)) if *latest_edition == *edition => Some(program),
+                _ => None,
+            }
         })
     }
 
@@ -706,6 +775,73 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_insert_get_remove_upgrade() {
+        let rng = &mut TestRng::default();
+
+        // Sample the initial deployment transaction.
+        let transaction = ledger_test_helpers::sample_deployment_transaction(true, rng);
+        let deployment = match &transaction {
+            Transaction::Deploy(_, _, deployment, _) => deployment.clone(),
+            _ => panic!("Incorrect transaction type"),
+        };
+        let program_id = *deployment.program_id();
+
+        // Construct an upgrade of the deployed program.
+        let upgrade = Deployment::new_with_program_edition(
+            deployment.edition(),
+            1,
+            deployment.program().clone(),
+            deployment.verifying_keys().clone(),
+        )
+        .unwrap();
+        let upgrade_id = upgrade.to_deployment_id().unwrap();
+        let private_key = console::account::PrivateKey::new(rng).unwrap();
+        let owner = ProgramOwner::new(&private_key, upgrade_id, rng).unwrap();
+        let fee = ledger_test_helpers::sample_fee_public(upgrade_id, rng);
+        let upgrade_transaction = Transaction::from_deployment(owner, upgrade, fee).unwrap();
+
+        // Initialize a new deployment store.
+        let transition_store = TransitionStore::open(None).unwrap();
+        let fee_store = FeeStore::open(transition_store).unwrap();
+        let deployment_store = DeploymentMemory::open(fee_store).unwrap();
+
+        // Ensure the upgrade is rejected before the program is deployed.
+        assert!(deployment_store.insert(&upgrade_transaction).is_err());
+
+        // Insert the initial deployment and its upgrade.
+        deployment_store.insert(&transaction).unwrap();
+        deployment_store.insert(&upgrade_transaction).unwrap();
+        // Ensure the upgrade cannot be inserted twice.
+        assert!(deployment_store.insert(&upgrade_transaction).is_err());
+        assert_eq!(deployment_store.get_edition(&program_id).unwrap(), Some(1));
+
+        // Ensure both transactions are retrieved with their own edition.
+        assert_eq!(deployment_store.get_transaction(&transaction.id()).unwrap(), Some(transaction.clone()));
+        assert_eq!(
+            deployment_store.get_transaction(&upgrade_transaction.id()).unwrap(),
+            Some(upgrade_transaction.clone())
+        );
+        assert_eq!(deployment_store.get_program_edition(&transaction.id()).unwrap(), Some(0));
+        assert_eq!(deployment_store.get_program_edition(&upgrade_transaction.id()).unwrap(), Some(1));
+        // Ensure the latest edition is found from the program ID.
+        let candidate = deployment_store.find_transaction_id_from_program_id(&program_id).unwrap();
+        assert_eq!(candidate, Some(upgrade_transaction.id()));
+
+        // Ensure the initial deployment cannot be removed before its upgrade.
+        assert!(deployment_store.remove(&transaction.id()).is_err());
+        // Remove the upgrade, which restores the initial edition.
+        deployment_store.remove(&upgrade_transaction.id()).unwrap();
+        assert_eq!(deployment_store.get_edition(&program_id).unwrap(), Some(0));
+        assert_eq!(deployment_store.get_transaction(&upgrade_transaction.id()).unwrap(), None);
+        let candidate = deployment_store.find_transaction_id_from_program_id(&program_id).unwrap();
+        assert_eq!(candidate, Some(transaction.id()));
+
+        // Remove the initial deployment.
+        deployment_store.remove(&transaction.id()).unwrap();
+        assert_eq!(deployment_store.get_edition(&program_id).unwrap(), None);
+    }
+
     #[test]
     fn test_find_transaction_id() {
         let rng = &mut TestRng::default();
@@ -354,7 +354,7 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         }
     }
 
-    /// Returns the edition for the given `transaction ID`.
+    /// Returns the program edition for the given `transaction ID`.
     pub fn get_edition(&self, transaction_id: &N::TransactionID) -> Result<Option<u16>> {
         // Retrieve the transaction type.
         let transaction_type = match self.transaction_ids.get_confirmed(transaction_id)? {
@@ -363,15 +363,10 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         };
         // Retrieve the edition.
         match transaction_type {
-            TransactionType::Deploy => {
-                // Retrieve the program ID.
-                let program_id = self.storage.deployment_store().get_program_id(transaction_id)?;
-                // Return the edition.
-                match program_id {
-                    Some(program_id) => self.storage.deployment_store().get_edition(&program_id),
-                    None => bail!("Failed to get the program ID for deployment transaction '{transaction_id}'"),
-                }
-            }
+            TransactionType::Deploy => match self.storage.deployment_store().get_program_edition(transaction_id)? {
+                Some(edition) => Ok(Some(edition)),
+                None => bail!("Failed to get the program edition for deployment transaction '{transaction_id}'"),
+            },
             // Return 'None'.
             TransactionType::Execute => Ok(None),
             // Return 'None'.
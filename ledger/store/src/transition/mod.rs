@@ -26,7 +26,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Ciphertext, Identifier, Plaintext, ProgramID, Record},
+    program::{Ciphertext, Expiry, Identifier, Plaintext, ProgramID, Record},
     types::{Field, Group},
 };
 use ledger_block::{Input, Output, Transition};
@@ -50,6 +50,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     type TCMMap: for<'a> Map<'a, N::TransitionID, Field<N>>;
     /// The mapping of `transition commitment` to `transition ID`.
     type ReverseTCMMap: for<'a> Map<'a, Field<N>, N::TransitionID>;
+    /// The transition expiries, for the transitions that are bound to a maximum block height.
+    type ExpiryMap: for<'a> Map<'a, N::TransitionID, Expiry<N>>;
 
     /// Initializes the transition storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -68,6 +70,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     fn tcm_map(&self) -> &Self::TCMMap;
     /// Returns the reverse `tcm` map.
     fn reverse_tcm_map(&self) -> &Self::ReverseTCMMap;
+    /// Returns the transition expiries map.
+    fn expiry_map(&self) -> &Self::ExpiryMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
@@ -84,6 +88,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().start_atomic();
         self.tcm_map().start_atomic();
         self.reverse_tcm_map().start_atomic();
+        self.expiry_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -95,6 +100,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             || self.reverse_tpk_map().is_atomic_in_progress()
             || self.tcm_map().is_atomic_in_progress()
             || self.reverse_tcm_map().is_atomic_in_progress()
+            || self.expiry_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -106,6 +112,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().atomic_checkpoint();
         self.tcm_map().atomic_checkpoint();
         self.reverse_tcm_map().atomic_checkpoint();
+        self.expiry_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -117,6 +124,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().clear_latest_checkpoint();
         self.tcm_map().clear_latest_checkpoint();
         self.reverse_tcm_map().clear_latest_checkpoint();
+        self.expiry_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -128,6 +136,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().atomic_rewind();
         self.tcm_map().atomic_rewind();
         self.reverse_tcm_map().atomic_rewind();
+        self.expiry_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -139,6 +148,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.reverse_tpk_map().abort_atomic();
         self.tcm_map().abort_atomic();
         self.reverse_tcm_map().abort_atomic();
+        self.expiry_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -149,7 +159,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         self.tpk_map().finish_atomic()?;
         self.reverse_tpk_map().finish_atomic()?;
         self.tcm_map().finish_atomic()?;
-        self.reverse_tcm_map().finish_atomic()
+        self.reverse_tcm_map().finish_atomic()?;
+        self.expiry_map().finish_atomic()
     }
 
     /// Stores the given `transition` into storage.
@@ -171,6 +182,10 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.tcm_map().insert(transition_id, *transition.tcm())?;
             // Store the reverse `tcm` entry.
             self.reverse_tcm_map().insert(*transition.tcm(), transition_id)?;
+            // Store the expiry, if there is one.
+            if let Some(expiry) = transition.expiry() {
+                self.expiry_map().insert(transition_id, *expiry)?;
+            }
 
             Ok(())
         })
@@ -204,6 +219,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.tcm_map().remove(transition_id)?;
             // Remove the reverse `tcm` entry.
             self.reverse_tcm_map().remove(&tcm)?;
+            // Remove the expiry.
+            self.expiry_map().remove(transition_id)?;
 
             Ok(())
         })
@@ -224,6 +241,8 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         let tpk = self.tpk_map().get_confirmed(transition_id)?;
         // Retrieve `tcm`.
         let tcm = self.tcm_map().get_confirmed(transition_id)?;
+        // Retrieve the expiry.
+        let expiry = self.expiry_map().get_confirmed(transition_id)?.map(|expiry| cow_to_copied!(expiry));
 
         match (tpk, tcm) {
             (Some(tpk), Some(tcm)) => {
@@ -235,6 +254,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
                     outputs,
                     cow_to_cloned!(tpk),
                     cow_to_cloned!(tcm),
+                    expiry,
                 )?;
                 // Ensure the transition ID matches.
                 match transition.id() == transition_id {
@@ -18,37 +18,62 @@ pub use genesis::*;
 pub mod powers;
 pub use powers::*;
 
+#[cfg(feature = "prover")]
 const REMOTE_URL: &str = "https://s3-us-west-1.amazonaws.com/testnet3.parameters";
 
 // Degrees
 impl_local!(Degree15, "resources/", "powers-of-beta-15", "usrs");
 impl_local!(Degree16, "resources/", "powers-of-beta-16", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(Degree17, REMOTE_URL, "resources/", "powers-of-beta-17", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(Degree18, REMOTE_URL, "resources/", "powers-of-beta-18", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(Degree19, REMOTE_URL, "resources/", "powers-of-beta-19", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(Degree20, REMOTE_URL, "resources/", "powers-of-beta-20", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(Degree21, REMOTE_URL, "resources/", "powers-of-beta-21", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(Degree22, REMOTE_URL, "resources/", "powers-of-beta-22", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(Degree23, REMOTE_URL, "resources/", "powers-of-beta-23", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(Degree24, REMOTE_URL, "resources/", "powers-of-beta-24", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(Degree25, REMOTE_URL, "resources/", "powers-of-beta-25", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(Degree26, REMOTE_URL, "resources/", "powers-of-beta-26", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(Degree27, REMOTE_URL, "resources/", "powers-of-beta-27", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(Degree28, REMOTE_URL, "resources/", "powers-of-beta-28", "usrs");
 
 // Shifted Degrees
 impl_local!(ShiftedDegree15, "resources/", "shifted-powers-of-beta-15", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(ShiftedDegree16, REMOTE_URL, "resources/", "shifted-powers-of-beta-16", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(ShiftedDegree17, REMOTE_URL, "resources/", "shifted-powers-of-beta-17", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(ShiftedDegree18, REMOTE_URL, "resources/", "shifted-powers-of-beta-18", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(ShiftedDegree19, REMOTE_URL, "resources/", "shifted-powers-of-beta-19", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(ShiftedDegree20, REMOTE_URL, "resources/", "shifted-powers-of-beta-20", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(ShiftedDegree21, REMOTE_URL, "resources/", "shifted-powers-of-beta-21", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(ShiftedDegree22, REMOTE_URL, "resources/", "shifted-powers-of-beta-22", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(ShiftedDegree23, REMOTE_URL, "resources/", "shifted-powers-of-beta-23", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(ShiftedDegree24, REMOTE_URL, "resources/", "shifted-powers-of-beta-24", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(ShiftedDegree25, REMOTE_URL, "resources/", "shifted-powers-of-beta-25", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(ShiftedDegree26, REMOTE_URL, "resources/", "shifted-powers-of-beta-26", "usrs");
+#[cfg(feature = "prover")]
 impl_remote!(ShiftedDegree27, REMOTE_URL, "resources/", "shifted-powers-of-beta-27", "usrs");
 
 // Powers of Beta Times Gamma * G
@@ -59,42 +84,55 @@ impl_local!(NegBeta, "resources/", "neg-powers-of-beta", "usrs");
 impl_local!(BetaH, "resources/", "beta-h", "usrs");
 
 // BondPublic
+#[cfg(feature = "prover")]
 impl_remote!(BondPublicProver, REMOTE_URL, "resources/", "bond_public", "prover");
 impl_local!(BondPublicVerifier, "resources/", "bond_public", "verifier");
 // UnbondPublic
+#[cfg(feature = "prover")]
 impl_remote!(UnbondPublicProver, REMOTE_URL, "resources/", "unbond_public", "prover");
 impl_local!(UnbondPublicVerifier, "resources/", "unbond_public", "verifier");
 // UnbondDelegatorAsValidator
+#[cfg(feature = "prover")]
 impl_remote!(UnbondDelegatorAsValidatorProver, REMOTE_URL, "resources/", "unbond_delegator_as_validator", "prover");
 impl_local!(UnbondDelegatorAsValidatorVerifier, "resources/", "unbond_delegator_as_validator", "verifier");
 // ClaimUnbondPublic
+#[cfg(feature = "prover")]
 impl_remote!(ClaimUnbondPublicProver, REMOTE_URL, "resources/", "claim_unbond_public", "prover");
 impl_local!(ClaimUnbondPublicVerifier, "resources/", "claim_unbond_public", "verifier");
 // SetValidatorState
+#[cfg(feature = "prover")]
 impl_remote!(SetValidatorStateProver, REMOTE_URL, "resources/", "set_validator_state", "prover");
 impl_local!(SetValidatorStateVerifier, "resources/", "set_validator_state", "verifier");
 // TransferPrivate
+#[cfg(feature = "prover")]
 impl_remote!(TransferPrivateProver, REMOTE_URL, "resources/", "transfer_private", "prover");
 impl_local!(TransferPrivateVerifier, "resources/", "transfer_private", "verifier");
 // TransferPublic
+#[cfg(feature = "prover")]
 impl_remote!(TransferPublicProver, REMOTE_URL, "resources/", "transfer_public", "prover");
 impl_local!(TransferPublicVerifier, "resources/", "transfer_public", "verifier");
 // TransferPrivateToPublic
+#[cfg(feature = "prover")]
 impl_remote!(TransferPrivateToPublicProver, REMOTE_URL, "resources/", "transfer_private_to_public", "prover");
 impl_local!(TransferPrivateToPublicVerifier, "resources/", "transfer_private_to_public", "verifier");
 // TransferPublicToPrivate
+#[cfg(feature = "prover")]
 impl_remote!(TransferPublicToPrivateProver, REMOTE_URL, "resources/", "transfer_public_to_private", "prover");
 impl_local!(TransferPublicToPrivateVerifier, "resources/", "transfer_public_to_private", "verifier");
 // Join
+#[cfg(feature = "prover")]
 impl_remote!(JoinProver, REMOTE_URL, "resources/", "join", "prover");
 impl_local!(JoinVerifier, "resources/", "join", "verifier");
 // Split
+#[cfg(feature = "prover")]
 impl_remote!(SplitProver, REMOTE_URL, "resources/", "split", "prover");
 impl_local!(SplitVerifier, "resources/", "split", "verifier");
 // FeePrivate
+#[cfg(feature = "prover")]
 impl_remote!(FeePrivateProver, REMOTE_URL, "resources/", "fee_private", "prover");
 impl_local!(FeePrivateVerifier, "resources/", "fee_private", "verifier");
 // FeePublic
+#[cfg(feature = "prover")]
 impl_remote!(FeePublicProver, REMOTE_URL, "resources/", "fee_public", "prover");
 impl_local!(FeePublicVerifier, "resources/", "fee_public", "verifier");
 
@@ -133,15 +171,20 @@ macro_rules! insert_key {
 }
 
 // Inclusion
+#[cfg(feature = "prover")]
 impl_remote!(InclusionProver, REMOTE_URL, "resources/", "inclusion", "prover");
 impl_local!(InclusionVerifier, "resources/", "inclusion", "verifier");
 
 /// The function name for the inclusion circuit.
 pub const TESTNET3_INCLUSION_FUNCTION_NAME: &str = "inclusion";
 
+#[cfg(feature = "prover")]
 lazy_static! {
     pub static ref INCLUSION_PROVING_KEY: Vec<u8> =
         InclusionProver::load_bytes().expect("Failed to load inclusion proving key");
+}
+
+lazy_static! {
     pub static ref INCLUSION_VERIFYING_KEY: Vec<u8> =
         InclusionVerifier::load_bytes().expect("Failed to load inclusion verifying key");
 }
@@ -155,10 +198,13 @@ mod tests {
     #[wasm_bindgen_test]
     fn test_load_bytes() {
         Degree16::load_bytes().expect("Failed to load degree 16");
-        Degree17::load_bytes().expect("Failed to load degree 17");
-        Degree18::load_bytes().expect("Failed to load degree 18");
-        Degree19::load_bytes().expect("Failed to load degree 19");
-        Degree20::load_bytes().expect("Failed to load degree 20");
+        #[cfg(feature = "prover")]
+        {
+            Degree17::load_bytes().expect("Failed to load degree 17");
+            Degree18::load_bytes().expect("Failed to load degree 18");
+            Degree19::load_bytes().expect("Failed to load degree 19");
+            Degree20::load_bytes().expect("Failed to load degree 20");
+        }
         BondPublicVerifier::load_bytes().expect("Failed to load bond_public verifier");
         UnbondPublicVerifier::load_bytes().expect("Failed to load unbond_public verifier");
         UnbondDelegatorAsValidatorVerifier::load_bytes()
@@ -169,11 +215,14 @@ mod tests {
         TransferPublicVerifier::load_bytes().expect("Failed to load transfer_public verifier");
         TransferPrivateToPublicVerifier::load_bytes().expect("Failed to load transfer_private_to_public verifier");
         TransferPublicToPrivateVerifier::load_bytes().expect("Failed to load transfer_public_to_private verifier");
-        FeePrivateProver::load_bytes().expect("Failed to load fee_private prover");
         FeePrivateVerifier::load_bytes().expect("Failed to load fee_private verifier");
-        FeePublicProver::load_bytes().expect("Failed to load fee_public prover");
         FeePublicVerifier::load_bytes().expect("Failed to load fee_public verifier");
-        InclusionProver::load_bytes().expect("Failed to load inclusion prover");
         InclusionVerifier::load_bytes().expect("Failed to load inclusion verifier");
+        #[cfg(feature = "prover")]
+        {
+            FeePrivateProver::load_bytes().expect("Failed to load fee_private prover");
+            FeePublicProver::load_bytes().expect("Failed to load fee_public prover");
+            InclusionProver::load_bytes().expect("Failed to load inclusion prover");
+        }
     }
 }
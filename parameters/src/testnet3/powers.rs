@@ -32,16 +32,27 @@ use std::{collections::BTreeMap, ops::Range, sync::Arc};
 
 const NUM_POWERS_15: usize = 1 << 15;
 const NUM_POWERS_16: usize = 1 << 16;
+#[cfg(feature = "prover")]
 const NUM_POWERS_17: usize = 1 << 17;
+#[cfg(feature = "prover")]
 const NUM_POWERS_18: usize = 1 << 18;
+#[cfg(feature = "prover")]
 const NUM_POWERS_19: usize = 1 << 19;
+#[cfg(feature = "prover")]
 const NUM_POWERS_20: usize = 1 << 20;
+#[cfg(feature = "prover")]
 const NUM_POWERS_21: usize = 1 << 21;
+#[cfg(feature = "prover")]
 const NUM_POWERS_22: usize = 1 << 22;
+#[cfg(feature = "prover")]
 const NUM_POWERS_23: usize = 1 << 23;
+#[cfg(feature = "prover")]
 const NUM_POWERS_24: usize = 1 << 24;
+#[cfg(feature = "prover")]
 const NUM_POWERS_25: usize = 1 << 25;
+#[cfg(feature = "prover")]
 const NUM_POWERS_26: usize = 1 << 26;
+#[cfg(feature = "prover")]
 const NUM_POWERS_27: usize = 1 << 27;
 const NUM_POWERS_28: usize = 1 << 28;
 
@@ -404,17 +415,29 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             // Download the universal SRS powers if they're not already on disk.
             let additional_bytes = match *num_powers {
                 NUM_POWERS_16 => Degree16::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_17 => Degree17::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_18 => Degree18::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_19 => Degree19::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_20 => Degree20::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_21 => Degree21::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_22 => Degree22::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_23 => Degree23::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_24 => Degree24::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_25 => Degree25::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_26 => Degree26::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_27 => Degree27::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_28 => Degree28::load_bytes()?,
                 _ => bail!("Cannot download an invalid degree of '{num_powers}'"),
             };
@@ -431,6 +454,8 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
     /// This method downloads the universal SRS powers from
     /// `start` up to `MAXIMUM_NUM_POWERS - self.shifted_powers_of_beta_g.len()`,
     /// and updates `Self` in place with the new powers.
+    /// Without the `prover` feature, only the bundled powers are available.
+    #[cfg_attr(not(feature = "prover"), allow(unreachable_code))]
     fn download_shifted_powers_from(&mut self, start: usize) -> Result<()> {
         // Ensure the total number of powers is less than the maximum number of powers.
         ensure!(start <= MAX_NUM_POWERS, "Requesting more powers than exist in the SRS");
@@ -481,17 +506,29 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
 
             // Download the universal SRS powers if they're not already on disk.
             let additional_bytes = match *num_powers {
+                #[cfg(feature = "prover")]
                 NUM_POWERS_16 => ShiftedDegree16::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_17 => ShiftedDegree17::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_18 => ShiftedDegree18::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_19 => ShiftedDegree19::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_20 => ShiftedDegree20::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_21 => ShiftedDegree21::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_22 => ShiftedDegree22::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_23 => ShiftedDegree23::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_24 => ShiftedDegree24::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_25 => ShiftedDegree25::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_26 => ShiftedDegree26::load_bytes()?,
+                #[cfg(feature = "prover")]
                 NUM_POWERS_27 => ShiftedDegree27::load_bytes()?,
                 _ => bail!("Cannot download an invalid degree of '{num_powers}'"),
             };
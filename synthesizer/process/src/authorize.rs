@@ -24,9 +24,30 @@ impl<N: Network> Process<N> {
         function_name: impl TryInto<Identifier<N>>,
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
         rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize_with_max_height::<A, R>(private_key, program_id, function_name, inputs, None, rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs, which cannot be included
+    /// in a block after the given maximum height, if one is given.
+    #[inline]
+    pub fn authorize_with_max_height<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        max_height: Option<u32>,
+        rng: &mut R,
     ) -> Result<Authorization<N>> {
         // Authorize the call.
-        self.get_stack(program_id)?.authorize::<A, R>(private_key, function_name, inputs, rng)
+        self.get_stack(program_id)?.authorize_with_max_height::<A, R>(
+            private_key,
+            function_name,
+            inputs,
+            max_height,
+            rng,
+        )
     }
 
     /// Authorizes the fee given the credits record, the fee amount (in microcredits),
@@ -0,0 +1,266 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use snarkvm_utilities::Directories;
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// The default endpoint of the node API.
+pub const DEFAULT_ENDPOINT: &str = "https://api.explorer.aleo.org/v1";
+
+/// The runtime settings of the VM, shared by the process, package, query client, and ledger.
+///
+/// The settings are loaded from a TOML file, where every key is optional, and can be overridden
+/// by the `SNARKVM_ENDPOINT`, `SNARKVM_NETWORK_ID`, `SNARKVM_DEV`, `SNARKVM_VERIFY_EXECUTIONS`,
+/// `SNARKVM_DATA_DIR`, `SNARKVM_ARCHIVE`, and `SNARKVM_OFFLINE` environment variables.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct VmConfig {
+    /// The endpoint of the node API, used to query the ledger state.
+    endpoint: String,
+    /// The expected network ID, or `None` to accept any network.
+    network_id: Option<u16>,
+    /// The development ID, which selects the ledger storage directory, or `None` for the production ledger.
+    dev: Option<u16>,
+    /// Whether to verify each execution after it is proven.
+    verify_executions: bool,
+    /// The root of the parameter caches and ledger storage, or `None` for the platform default.
+    data_directory: Option<PathBuf>,
+    /// Whether the ledger maintains the secondary indexes of the archive mode.
+    archive: bool,
+    /// Whether every network request is refused, so only local files and caches are used.
+    offline: bool,
+}
+
+impl Default for VmConfig {
+    /// Returns the default settings.
+    fn default() -> Self {
+        Self {
+            endpoint: DEFAULT_ENDPOINT.to_string(),
+            network_id: None,
+            dev: None,
+            verify_executions: false,
+            data_directory: None,
+            archive: false,
+            offline: false,
+        }
+    }
+}
+
+impl VmConfig {
+    /// Parses the settings from the given TOML string.
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        toml::from_str(toml).map_err(|error| anyhow!("Failed to parse the VM config: {error}"))
+    }
+
+    /// Loads the settings from the given TOML file, and applies the environment overrides.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        // Ensure the file exists.
+        ensure!(path.exists(), "The VM config does not exist at '{}'", path.display());
+        // Read the settings.
+        Self::from_toml(&std::fs::read_to_string(path)?)?.with_env()
+    }
+
+    /// Returns the default settings, with the environment overrides applied.
+    pub fn from_env() -> Result<Self> {
+        Self::default().with_env()
+    }
+
+    /// Applies the environment overrides to the settings.
+    pub fn with_env(mut self) -> Result<Self> {
+        if let Ok(endpoint) = std::env::var("SNARKVM_ENDPOINT") {
+            self.endpoint = endpoint;
+        }
+        if let Ok(network_id) = std::env::var("SNARKVM_NETWORK_ID") {
+            self.network_id =
+                Some(network_id.parse().map_err(|_| anyhow!("Invalid SNARKVM_NETWORK_ID '{network_id}'"))?);
+        }
+        if let Ok(dev) = std::env::var("SNARKVM_DEV") {
+            self.dev = Some(dev.parse().map_err(|_| anyhow!("Invalid SNARKVM_DEV '{dev}'"))?);
+        }
+        if let Ok(verify) = std::env::var("SNARKVM_VERIFY_EXECUTIONS") {
+            self.verify_executions =
+                verify.parse().map_err(|_| anyhow!("Invalid SNARKVM_VERIFY_EXECUTIONS '{verify}'"))?;
+        }
+        if let Ok(data_directory) = std::env::var("SNARKVM_DATA_DIR") {
+            self.data_directory = Some(PathBuf::from(data_directory));
+        }
+        if let Ok(archive) = std::env::var("SNARKVM_ARCHIVE") {
+            self.archive = archive.parse().map_err(|_| anyhow!("Invalid SNARKVM_ARCHIVE '{archive}'"))?;
+        }
+        if let Ok(offline) = std::env::var("SNARKVM_OFFLINE") {
+            self.offline = offline.parse().map_err(|_| anyhow!("Invalid SNARKVM_OFFLINE '{offline}'"))?;
+        }
+        Ok(self)
+    }
+
+    /// Returns the settings as a TOML string.
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string_pretty(self).map_err(|error| anyhow!("Failed to serialize the VM config: {error}"))
+    }
+
+    /// Returns the endpoint of the node API.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Returns the expected network ID, or `None` if any network is accepted.
+    pub const fn network_id(&self) -> Option<u16> {
+        self.network_id
+    }
+
+    /// Returns the development ID, or `None` for the production ledger.
+    pub const fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+
+    /// Returns `true` if each execution is verified after it is proven.
+    pub const fn verify_executions(&self) -> bool {
+        self.verify_executions
+    }
+
+    /// Returns the root of the parameter caches and ledger storage, or `None` for the platform default.
+    pub fn data_directory(&self) -> Option<&Path> {
+        self.data_directory.as_deref()
+    }
+
+    /// Returns `true` if the ledger maintains the secondary indexes of the archive mode.
+    pub const fn archive(&self) -> bool {
+        self.archive
+    }
+
+    /// Returns `true` if every network request is refused.
+    pub const fn offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Returns the directories of the parameter caches and ledger storage.
+    pub fn directories(&self) -> Directories {
+        match &self.data_directory {
+            Some(data_directory) => Directories::new(data_directory),
+            None => Directories::platform_default(),
+        }
+    }
+
+    /// Sets the configured data directory as the root of [`Directories::current`], for the whole process.
+    pub fn apply_directories(&self) {
+        Directories::set_root(self.data_directory.clone());
+    }
+
+    /// Sets the endpoint of the node API.
+    pub fn with_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = endpoint.into();
+        self
+    }
+
+    /// Sets the expected network ID.
+    pub fn with_network_id(mut self, network_id: Option<u16>) -> Self {
+        self.network_id = network_id;
+        self
+    }
+
+    /// Sets the development ID.
+    pub fn with_dev(mut self, dev: Option<u16>) -> Self {
+        self.dev = dev;
+        self
+    }
+
+    /// Sets whether each execution is verified after it is proven.
+    pub fn with_verify_executions(mut self, verify_executions: bool) -> Self {
+        self.verify_executions = verify_executions;
+        self
+    }
+
+    /// Sets the root of the parameter caches and ledger storage.
+    pub fn with_data_directory(mut self, data_directory: Option<PathBuf>) -> Self {
+        self.data_directory = data_directory;
+        self
+    }
+
+    /// Sets whether the ledger maintains the secondary indexes of the archive mode.
+    pub fn with_archive(mut self, archive: bool) -> Self {
+        self.archive = archive;
+        self
+    }
+
+    /// Sets whether every network request is refused.
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Ensures the given network matches the expected network ID.
+    pub fn ensure_network<N: Network>(&self) -> Result<()> {
+        match self.network_id {
+            Some(network_id) if network_id != N::ID => {
+                bail!("The VM config expects network {network_id}, found network {}", N::ID)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Ensures the settings allow a network request to the given endpoint.
+    pub fn ensure_online(&self, endpoint: &str) -> Result<()> {
+        match self.offline {
+            true => bail!("Cannot reach '{endpoint}' in offline mode"),
+            false => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    #[test]
+    fn test_from_toml() {
+        // Check that missing keys use the defaults.
+        assert_eq!(VmConfig::from_toml("").unwrap(), VmConfig::default());
+
+        // Check that the keys are parsed.
+        let config = VmConfig::from_toml(
+            r#"
+            endpoint = "http://localhost:3030"
+            network_id = 3
+            dev = 0
+            verify_executions = true
+            data_directory = "/tmp/aleo"
+            archive = true
+            offline = true
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.endpoint(), "http://localhost:3030");
+        assert_eq!(config.network_id(), Some(3));
+        assert_eq!(config.dev(), Some(0));
+        assert!(config.verify_executions());
+        assert!(config.archive());
+        assert!(config.offline());
+        assert!(config.ensure_online("http://localhost:3030").is_err());
+        assert_eq!(config.directories().resources_dir(), Path::new("/tmp/aleo/resources"));
+        config.ensure_network::<Testnet3>().unwrap();
+
+        // Check that the settings round trip.
+        assert_eq!(VmConfig::from_toml(&config.to_toml().unwrap()).unwrap(), config);
+
+        // Check that unknown keys and mismatched networks are rejected.
+        assert!(VmConfig::from_toml("endpont = \"http://localhost:3030\"").is_err());
+        assert!(config.clone().with_network_id(Some(0)).ensure_network::<Testnet3>().is_err());
+        config.with_offline(false).ensure_online("http://localhost:3030").unwrap();
+    }
+}
@@ -37,6 +37,37 @@ impl<N: Network> Process<N> {
         deployment
     }
 
+    /// Deploys an upgrade of the given program, which exists in the process, as the given program edition.
+    #[inline]
+    pub fn deploy_upgrade<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        program_edition: u16,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        let timer = timer!("Process::deploy_upgrade");
+
+        // Ensure the program edition is for an upgrade.
+        ensure!(program_edition > 0, "The program edition of an upgrade must be nonzero");
+        // Compute the stack.
+        let stack = Stack::new_upgrade(self, program, N::EDITION)?;
+        lap!(timer, "Compute the stack");
+
+        // Construct the deployment.
+        let deployment = stack.deploy::<A, R>(rng)?;
+        lap!(timer, "Construct the deployment");
+
+        finish!(timer);
+
+        // Return the deployment as an upgrade.
+        Deployment::new_with_program_edition(
+            deployment.edition(),
+            program_edition,
+            deployment.program().clone(),
+            deployment.verifying_keys().clone(),
+        )
+    }
+
     /// Adds the newly-deployed program.
     /// This method assumes the given deployment **is valid**.
     #[inline]
@@ -44,7 +75,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = Stack::new_with_edition(self, deployment.program(), deployment.edition())?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
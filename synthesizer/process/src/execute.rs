@@ -28,8 +28,7 @@ impl<N: Network> Process<N> {
         // Construct the locator.
         let locator = Locator::new(*request.program_id(), *request.function_name());
 
-        #[cfg(feature = "aleo-cli")]
-        println!("{}", format!(" • Executing '{locator}'...",).dimmed());
+        self.interface.info(&format!("Executing '{locator}'..."));
 
         // Initialize the trace.
         let trace = Arc::new(RwLock::new(Trace::new()));
@@ -31,7 +31,7 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::finalize_deployment");
 
         // Compute the program stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.compute_deployment_stack(deployment)?;
         lap!(timer, "Compute the stack");
 
         // Insert the verifying keys.
@@ -59,6 +59,10 @@ impl<N: Network> Process<N> {
             let program_id = deployment.program_id();
             // Iterate over the mappings.
             for mapping in deployment.program().mappings().values() {
+                // Skip the mappings of the deployed program, as an upgrade keeps them.
+                if deployment.is_upgrade() && self.get_program(program_id)?.contains_mapping(mapping.name()) {
+                    continue;
+                }
                 // Initialize the mapping.
                 finalize_operations.push(store.initialize_mapping(*program_id, *mapping.name())?);
             }
@@ -99,6 +103,12 @@ impl<N: Network> Process<N> {
         );
         lap!(timer, "Verify the number of transitions");
 
+        // Ensure the height locks of the consumed records have passed.
+        for transition in execution.transitions() {
+            self.get_stack(transition.program_id())?.check_spend_after(transition, state.block_height())?;
+        }
+        lap!(timer, "Verify the height locks");
+
         // Construct the call graph.
         let call_graph = self.construct_call_graph(execution)?;
 
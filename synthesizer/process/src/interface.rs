@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+#[cfg(feature = "aleo-cli")]
+use colored::Colorize;
+
+/// A user interface, which reports messages and progress from long-running operations.
+///
+/// Each method has a no-op default, so an implementation only overrides the messages it displays.
+pub trait UserInterface: Send + Sync {
+    /// Reports an informational message.
+    fn info(&self, _message: &str) {}
+
+    /// Reports a warning.
+    fn warn(&self, _message: &str) {}
+
+    /// Reports the start of the given task.
+    fn progress_start(&self, _task: &str) {}
+
+    /// Reports that `completed` out of `total` steps of the given task are done.
+    fn progress_update(&self, _task: &str, _completed: usize, _total: usize) {}
+
+    /// Reports the end of the given task.
+    fn progress_finish(&self, _task: &str) {}
+}
+
+/// A user interface, which does not report anything.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NoopInterface;
+
+impl UserInterface for NoopInterface {}
+
+/// A user interface, which reports to the terminal.
+#[cfg(feature = "aleo-cli")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct TerminalInterface;
+
+#[cfg(feature = "aleo-cli")]
+impl UserInterface for TerminalInterface {
+    fn info(&self, message: &str) {
+        println!("{}", format!(" • {message}").dimmed());
+    }
+
+    fn warn(&self, message: &str) {
+        eprintln!("{}", format!("⚠️  {message}").yellow());
+    }
+
+    fn progress_start(&self, task: &str) {
+        println!("⏳ {}...\n", task.bold());
+    }
+
+    fn progress_update(&self, task: &str, completed: usize, total: usize) {
+        println!("{}", format!(" • {task} ({completed}/{total})").dimmed());
+    }
+
+    fn progress_finish(&self, _task: &str) {
+        println!();
+    }
+}
+
+/// Returns the default user interface, which reports to the terminal.
+#[cfg(feature = "aleo-cli")]
+pub fn default_interface() -> Arc<dyn UserInterface> {
+    Arc::new(TerminalInterface)
+}
+
+/// Returns the default user interface, which does not report anything.
+#[cfg(not(feature = "aleo-cli"))]
+pub fn default_interface() -> Arc<dyn UserInterface> {
+    Arc::new(NoopInterface)
+}
@@ -18,6 +18,12 @@
 // TODO (howardwu): Update the return type on `execute` after stabilizing the interface.
 #![allow(clippy::type_complexity)]
 
+mod config;
+pub use config::*;
+
+mod interface;
+pub use interface::*;
+
 mod stack;
 pub use stack::*;
 
@@ -32,6 +38,7 @@ mod deploy;
 mod evaluate;
 mod execute;
 mod finalize;
+mod upgrade;
 mod verify_deployment;
 mod verify_execution;
 mod verify_fee;
@@ -67,15 +74,14 @@ use indexmap::IndexMap;
 use parking_lot::RwLock;
 use std::{collections::HashMap, sync::Arc};
 
-#[cfg(feature = "aleo-cli")]
-use colored::Colorize;
-
 #[derive(Clone)]
 pub struct Process<N: Network> {
     /// The universal SRS.
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Stack<N>>,
+    /// The user interface.
+    interface: Arc<dyn UserInterface>,
 }
 
 impl<N: Network> Process<N> {
@@ -85,7 +91,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            interface: default_interface(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -140,7 +150,11 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            interface: default_interface(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -173,7 +187,11 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            interface: default_interface(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -194,6 +212,18 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the user interface.
+    #[inline]
+    pub const fn interface(&self) -> &Arc<dyn UserInterface> {
+        &self.interface
+    }
+
+    /// Sets the user interface.
+    #[inline]
+    pub fn set_interface(&mut self, interface: Arc<dyn UserInterface>) {
+        self.interface = interface;
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -16,7 +16,15 @@ mod bytes;
 mod serialize;
 mod string;
 
-use console::{network::prelude::*, program::Request, types::Field};
+mod summary;
+pub use summary::*;
+
+use console::{
+    account::Address,
+    network::prelude::*,
+    program::{Expiry, Request},
+    types::Field,
+};
 use ledger_block::{Transaction, Transition};
 
 use indexmap::IndexMap;
@@ -183,6 +191,27 @@ impl<N: Network> Authorization<N> {
     }
 }
 
+impl<N: Network> Authorization<N> {
+    /// Returns the maximum block height of the authorization, if the caller bound a request to one.
+    ///
+    /// Note: The maximum height is signed in the request, and carried in its transition, where it is
+    /// enforced when the transaction is verified. See `Request::sign_with_max_height`.
+    pub fn max_height(&self) -> Option<u32> {
+        let requests = self.requests.read().iter().filter_map(|request| request.expiry().map(Expiry::max_height)).min();
+        let transitions =
+            self.transitions.read().values().filter_map(|transition| transition.expiry().map(Expiry::max_height)).min();
+        requests.into_iter().chain(transitions).min()
+    }
+
+    /// Ensures the authorization can be included in a block at the given height.
+    pub fn check_max_height(&self, height: u32) -> Result<()> {
+        if let Some(max_height) = self.max_height() {
+            ensure!(height <= max_height, "The authorization expired at block {max_height} (current block {height})");
+        }
+        Ok(())
+    }
+}
+
 impl<N: Network> PartialEq for Authorization<N> {
     fn eq(&self, other: &Self) -> bool {
         let self_requests = self.requests.read();
@@ -238,6 +267,11 @@ fn ensure_request_and_transition_matches<N: Network>(
         request.tcm(),
         transition.tcm(),
     );
+    // Ensure the request and transition have the same expiry.
+    ensure!(
+        request.expiry() == transition.expiry(),
+        "The request and transition at index {index} must have the same expiry in the authorization."
+    );
     Ok(())
 }
 
@@ -252,6 +286,13 @@ pub(crate) mod test_helpers {
 
     /// Returns a sample authorization.
     pub fn sample_authorization(rng: &mut TestRng) -> Authorization<CurrentNetwork> {
+        sample_authorization_with_key(rng).0
+    }
+
+    /// Returns a sample authorization, with the private key of the caller.
+    pub fn sample_authorization_with_key(
+        rng: &mut TestRng,
+    ) -> (Authorization<CurrentNetwork>, PrivateKey<CurrentNetwork>) {
         // Initialize the process.
         let process = Process::<CurrentNetwork>::load().unwrap();
 
@@ -275,6 +316,6 @@ pub(crate) mod test_helpers {
             )
             .unwrap();
         assert!(authorization.is_fee_public(), "Authorization must be for a call to 'credits.aleo/fee_public'");
-        authorization
+        (authorization, private_key)
     }
 }
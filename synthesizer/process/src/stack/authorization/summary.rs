@@ -0,0 +1,329 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::{Entry, Identifier, InputID, Literal, Plaintext, ProgramID, Record, Value};
+
+/// The visibility of an input, in an authorization summary.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InputVisibility {
+    /// A constant input.
+    Constant,
+    /// A public input.
+    Public,
+    /// A private input.
+    Private,
+    /// A record that is spent by the call.
+    Record,
+    /// A record of another program, which is passed through the call.
+    ExternalRecord,
+}
+
+impl Display for InputVisibility {
+    /// Prints the visibility as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Constant => write!(f, "constant"),
+            Self::Public => write!(f, "public"),
+            Self::Private => write!(f, "private"),
+            Self::Record => write!(f, "record"),
+            Self::ExternalRecord => write!(f, "external record"),
+        }
+    }
+}
+
+/// A record that is spent by a call, in an authorization summary.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpentRecord<N: Network> {
+    /// The serial number of the record.
+    serial_number: Field<N>,
+    /// The owner of the record.
+    owner: Address<N>,
+    /// The amount of the record in microcredits, if it is a credits record.
+    microcredits: Option<u64>,
+}
+
+impl<N: Network> SpentRecord<N> {
+    /// Returns the serial number of the record.
+    pub const fn serial_number(&self) -> &Field<N> {
+        &self.serial_number
+    }
+
+    /// Returns the owner of the record.
+    pub const fn owner(&self) -> &Address<N> {
+        &self.owner
+    }
+
+    /// Returns the amount of the record in microcredits, if it is a credits record.
+    pub const fn microcredits(&self) -> Option<u64> {
+        self.microcredits
+    }
+}
+
+/// A call to a program function, in an authorization summary.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CallSummary<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The inputs, with their visibility.
+    inputs: Vec<(InputVisibility, Value<N>)>,
+    /// The records that are spent by the call.
+    spent_records: Vec<SpentRecord<N>>,
+}
+
+impl<N: Network> CallSummary<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the inputs, with their visibility.
+    pub fn inputs(&self) -> &[(InputVisibility, Value<N>)] {
+        &self.inputs
+    }
+
+    /// Returns the records that are spent by the call.
+    pub fn spent_records(&self) -> &[SpentRecord<N>] {
+        &self.spent_records
+    }
+}
+
+/// The fee that is paid by a fee authorization, in an authorization summary.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FeeSummary {
+    /// The base fee in microcredits.
+    base_fee: u64,
+    /// The priority fee in microcredits.
+    priority_fee: u64,
+    /// Whether the fee is paid with a record, instead of a public balance.
+    is_private: bool,
+}
+
+impl FeeSummary {
+    /// Returns the base fee in microcredits.
+    pub const fn base_fee(&self) -> u64 {
+        self.base_fee
+    }
+
+    /// Returns the priority fee in microcredits.
+    pub const fn priority_fee(&self) -> u64 {
+        self.priority_fee
+    }
+
+    /// Returns the total fee in microcredits.
+    pub const fn total_fee(&self) -> u64 {
+        self.base_fee.saturating_add(self.priority_fee)
+    }
+
+    /// Returns `true` if the fee is paid with a record, instead of a public balance.
+    pub const fn is_private(&self) -> bool {
+        self.is_private
+    }
+}
+
+/// A human-reviewable description of an authorization, to show to the caller before it is proven.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuthorizationSummary<N: Network> {
+    /// The caller, who signed the authorization.
+    signer: Address<N>,
+    /// The calls, in the order they are executed.
+    calls: Vec<CallSummary<N>>,
+    /// The fee, if this is a fee authorization.
+    fee: Option<FeeSummary>,
+    /// The maximum block height, if the caller bound the authorization to one.
+    max_height: Option<u32>,
+}
+
+impl<N: Network> AuthorizationSummary<N> {
+    /// Returns the caller, who signed the authorization.
+    pub const fn signer(&self) -> &Address<N> {
+        &self.signer
+    }
+
+    /// Returns the calls, in the order they are executed.
+    pub fn calls(&self) -> &[CallSummary<N>] {
+        &self.calls
+    }
+
+    /// Returns the fee, if this is a fee authorization.
+    pub const fn fee(&self) -> Option<FeeSummary> {
+        self.fee
+    }
+
+    /// Returns the maximum block height, if the caller bound the authorization to one.
+    pub const fn max_height(&self) -> Option<u32> {
+        self.max_height
+    }
+
+    /// Returns the total amount in microcredits of the credits records spent by the authorization.
+    pub fn spent_microcredits(&self) -> u64 {
+        self.calls
+            .iter()
+            .flat_map(|call| &call.spent_records)
+            .filter_map(|record| record.microcredits)
+            .fold(0u64, |total, amount| total.saturating_add(amount))
+    }
+}
+
+impl<N: Network> Display for AuthorizationSummary<N> {
+    /// Prints the summary for the caller to review.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        writeln!(f, "Signer: {}", self.signer)?;
+        for (index, call) in self.calls.iter().enumerate() {
+            writeln!(f, "Call {}: {}/{}", index + 1, call.program_id, call.function_name)?;
+            for (visibility, input) in &call.inputs {
+                writeln!(f, "  - {visibility}: {input}")?;
+            }
+            for record in &call.spent_records {
+                match record.microcredits {
+                    Some(amount) => {
+                        writeln!(f, "  Spends a record of {amount} microcredits ({})", record.serial_number)?
+                    }
+                    None => writeln!(f, "  Spends a record ({})", record.serial_number)?,
+                }
+            }
+        }
+        if let Some(fee) = &self.fee {
+            let source = if fee.is_private { "a record" } else { "the public balance" };
+            writeln!(
+                f,
+                "Fee: {} microcredits ({} base, {} priority), paid from {source}",
+                fee.total_fee(),
+                fee.base_fee,
+                fee.priority_fee
+            )?;
+        }
+        if let Some(max_height) = self.max_height {
+            writeln!(f, "Expires after block {max_height}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Authorization<N> {
+    /// Returns a human-reviewable summary of the authorization, including the calls, their inputs,
+    /// the records they spend, and the fee of a fee authorization.
+    pub fn summary(&self) -> Result<AuthorizationSummary<N>> {
+        // Determine the kind of fee and the maximum height, before acquiring the requests.
+        let (is_fee_private, is_fee_public) = (self.is_fee_private(), self.is_fee_public());
+        let max_height = self.max_height();
+
+        let requests = self.requests.read();
+        let Some(first) = requests.front() else { bail!("Cannot summarize an empty authorization") };
+
+        // Summarize each call.
+        let calls = requests
+            .iter()
+            .map(|request| {
+                let mut inputs = Vec::with_capacity(request.inputs().len());
+                let mut spent_records = Vec::new();
+                for (input_id, input) in request.input_ids().iter().zip_eq(request.inputs()) {
+                    let visibility = match input_id {
+                        InputID::Constant(..) => InputVisibility::Constant,
+                        InputID::Public(..) => InputVisibility::Public,
+                        InputID::Private(..) => InputVisibility::Private,
+                        InputID::Record(_, _, serial_number, _) => {
+                            let Value::Record(record) = input else { bail!("Expected a record input") };
+                            spent_records.push(SpentRecord {
+                                serial_number: *serial_number,
+                                owner: **record.owner(),
+                                microcredits: microcredits(record),
+                            });
+                            InputVisibility::Record
+                        }
+                        InputID::ExternalRecord(..) => InputVisibility::ExternalRecord,
+                    };
+                    inputs.push((visibility, input.clone()));
+                }
+                Ok(CallSummary {
+                    program_id: *request.program_id(),
+                    function_name: *request.function_name(),
+                    inputs,
+                    spent_records,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Summarize the fee, which is the base and priority fee inputs, before the deployment or execution ID.
+        let fee = match is_fee_private || is_fee_public {
+            true => {
+                let amounts = first
+                    .inputs()
+                    .iter()
+                    .filter_map(|input| match input {
+                        Value::Plaintext(Plaintext::Literal(Literal::U64(amount), _)) => Some(**amount),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                let [base_fee, priority_fee] = amounts[..] else { bail!("Expected a base fee and a priority fee") };
+                Some(FeeSummary { base_fee, priority_fee, is_private: is_fee_private })
+            }
+            false => None,
+        };
+
+        Ok(AuthorizationSummary { signer: *first.signer(), calls, fee, max_height })
+    }
+}
+
+/// Returns the amount of the given record in microcredits, if it is a credits record.
+fn microcredits<N: Network>(record: &Record<N, Plaintext<N>>) -> Option<u64> {
+    match record.find(&[Identifier::from_str("microcredits").ok()?]) {
+        Ok(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => Some(*amount),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary() {
+        let rng = &mut TestRng::default();
+
+        // Summarize a public fee authorization.
+        let (authorization, private_key) =
+            crate::stack::authorization::test_helpers::sample_authorization_with_key(rng);
+        let summary = authorization.summary().unwrap();
+        assert_eq!(summary.signer(), &Address::try_from(&private_key).unwrap());
+        assert_eq!(summary.calls().len(), 1);
+
+        let call = &summary.calls()[0];
+        assert_eq!(call.program_id().to_string(), "credits.aleo");
+        assert_eq!(call.function_name().to_string(), "fee_public");
+        assert_eq!(call.inputs().len(), 3);
+        assert!(call.inputs().iter().all(|(visibility, _)| *visibility == InputVisibility::Public));
+        assert!(call.spent_records().is_empty());
+        assert_eq!(summary.spent_microcredits(), 0);
+        assert_eq!(summary.max_height(), None);
+
+        // Ensure the fee matches the inputs.
+        let fee = summary.fee().unwrap();
+        assert!(!fee.is_private());
+        assert_eq!(call.inputs()[0].1, Value::from_str(&format!("{}u64", fee.base_fee())).unwrap());
+        assert_eq!(call.inputs()[1].1, Value::from_str(&format!("{}u64", fee.priority_fee())).unwrap());
+
+        // Ensure the summary is displayed for review.
+        let display = summary.to_string();
+        assert!(display.contains("Call 1: credits.aleo/fee_public"));
+        assert!(display.contains(&format!("Fee: {} microcredits", fee.total_fee())));
+    }
+}
@@ -23,6 +23,20 @@ impl<N: Network> Stack<N> {
         function_name: impl TryInto<Identifier<N>>,
         inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
         rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize_with_max_height::<A, R>(private_key, function_name, inputs, None, rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs, which cannot be included
+    /// in a block after the given maximum height, if one is given.
+    #[inline]
+    pub fn authorize_with_max_height<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        max_height: Option<u32>,
+        rng: &mut R,
     ) -> Result<Authorization<N>> {
         let timer = timer!("Stack::authorize");
 
@@ -35,7 +49,15 @@ impl<N: Network> Stack<N> {
         lap!(timer, "Retrieve the input types");
 
         // Compute the request.
-        let request = Request::sign(private_key, program_id, function_name, inputs, &input_types, rng)?;
+        let request = Request::sign_with_max_height(
+            private_key,
+            program_id,
+            function_name,
+            inputs,
+            &input_types,
+            max_height,
+            rng,
+        )?;
         lap!(timer, "Compute the request");
         // Initialize the authorization.
         let authorization = Authorization::new(request.clone());
@@ -48,7 +48,7 @@ impl<N: Network> Stack<N> {
         finish!(timer);
 
         // Return the deployment.
-        Deployment::new(N::EDITION, self.program.clone(), verifying_keys)
+        Deployment::new(self.edition, self.program.clone(), verifying_keys)
     }
 
     /// Checks each function in the program on the given verifying key and certificate.
@@ -60,21 +60,76 @@ impl<N: Network> Stack<N> {
     ) -> Result<()> {
         let timer = timer!("Stack::verify_deployment");
 
+        // Construct the requests used to verify the certificates.
+        let requests = self.prepare_certificate_checks(deployment, rng)?;
+        lap!(timer, "Construct the requests");
+
+        // Verify the certificates, across functions in parallel.
+        cfg_into_iter!(requests).zip_eq(deployment.verifying_keys()).try_for_each(
+            |((function_name, request, burner_private_key), (_, (verifying_key, certificate)))| {
+                self.verify_certificate::<A>(function_name, request, burner_private_key, verifying_key, certificate)
+            },
+        )?;
+
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Checks each function in the program on the given verifying key and certificate,
+    /// and returns the result for every function, rather than stopping at the first invalid certificate.
+    #[inline]
+    pub fn verify_deployment_certificates<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<Vec<(Identifier<N>, Result<()>)>> {
+        // Construct the requests used to verify the certificates.
+        let requests = self.prepare_certificate_checks(deployment, rng)?;
+
+        // Verify the certificates, across functions in parallel.
+        Ok(cfg_into_iter!(requests)
+            .zip_eq(deployment.verifying_keys())
+            .map(|((function_name, request, burner_private_key), (_, (verifying_key, certificate)))| {
+                let result = self.verify_certificate::<A>(
+                    function_name,
+                    request,
+                    burner_private_key,
+                    verifying_key,
+                    certificate,
+                );
+                (*function_name, result)
+            })
+            .collect())
+    }
+
+    /// Performs the sanity checks on the given deployment, and returns the request and burner private key
+    /// used to verify the certificate of each function.
+    ///
+    /// The assignments are not constructed here, as each one is synthesized and released within the verification
+    /// of its function, so memory is bounded by the number of functions verified concurrently.
+    fn prepare_certificate_checks<'a, R: Rng + CryptoRng>(
+        &self,
+        deployment: &'a Deployment<N>,
+        rng: &mut R,
+    ) -> Result<Vec<(&'a Identifier<N>, Request<N>, PrivateKey<N>)>> {
         // Sanity Checks //
 
         // Ensure the deployment is ordered.
         deployment.check_is_ordered()?;
         // Ensure the program in the stack and deployment matches.
         ensure!(&self.program == deployment.program(), "The stack program does not match the deployment program");
+        // Ensure the stack is for the deployment edition, as the edition determines the circuits.
+        ensure!(self.edition == deployment.edition(), "The stack edition does not match the deployment edition");
 
         // Check Verifying Keys //
 
         let program_id = self.program.id();
 
-        // Construct the call stacks and assignments used to verify the certificates.
-        let mut call_stacks = Vec::with_capacity(deployment.verifying_keys().len());
+        // Construct the requests used to verify the certificates.
+        let mut requests = Vec::with_capacity(deployment.verifying_keys().len());
 
-        // Iterate through the program functions and construct the callstacks and corresponding assignments.
+        // Iterate through the program functions and construct the requests.
         for function in deployment.program().functions().values() {
             // Initialize a burner private key.
             let burner_private_key = PrivateKey::new(rng)?;
@@ -95,7 +150,6 @@ impl<N: Network> Stack<N> {
                     _ => self.sample_value(&burner_address, input_type, rng),
                 })
                 .collect::<Result<Vec<_>>>()?;
-            lap!(timer, "Sample the inputs");
 
             // Compute the request, with a burner private key.
             let request = Request::sign(
@@ -106,38 +160,44 @@ impl<N: Network> Stack<N> {
                 &input_types,
                 rng,
             )?;
-            lap!(timer, "Compute the request for {}", function.name());
-            // Initialize the assignments.
-            let assignments = Assignments::<N>::default();
-            // Initialize the call stack.
-            let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
-            // Append the function name, callstack, and assignments.
-            call_stacks.push((function.name(), call_stack, assignments));
+            // Append the function name, request, and burner private key.
+            requests.push((function.name(), request, burner_private_key));
         }
 
-        // Verify the certificates.
-        cfg_iter!(call_stacks).zip_eq(deployment.verifying_keys()).try_for_each(
-            |((function_name, call_stack, assignments), (_, (verifying_key, certificate)))| {
-                // Synthesize the circuit.
-                if let Err(err) = self.execute_function::<A>(call_stack.clone(), None) {
-                    bail!("Failed to synthesize the circuit for '{function_name}': {err}")
-                }
-                // Check the certificate.
-                match assignments.read().last() {
-                    None => bail!("The assignment for function '{function_name}' is missing in '{program_id}'"),
-                    Some((assignment, _metrics)) => {
-                        // Ensure the certificate is valid.
-                        if !certificate.verify(&function_name.to_string(), assignment, verifying_key) {
-                            bail!("The certificate for function '{function_name}' is invalid in '{program_id}'")
-                        }
-                    }
-                };
-                Ok(())
-            },
-        )?;
+        Ok(requests)
+    }
 
-        finish!(timer);
+    /// Synthesizes the circuit of the given function, and checks its assignment against the given verifying key and certificate.
+    /// The assignment is dropped on return, so it is only held while its function is verified.
+    fn verify_certificate<A: circuit::Aleo<Network = N>>(
+        &self,
+        function_name: &Identifier<N>,
+        request: Request<N>,
+        burner_private_key: PrivateKey<N>,
+        verifying_key: &VerifyingKey<N>,
+        certificate: &Certificate<N>,
+    ) -> Result<()> {
+        let program_id = self.program.id();
 
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+        // Initialize the call stack.
+        let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
+
+        // Synthesize the circuit.
+        if let Err(err) = self.execute_function::<A>(call_stack, None) {
+            bail!("Failed to synthesize the circuit for '{function_name}': {err}")
+        }
+        // Check the certificate.
+        match assignments.read().last() {
+            None => bail!("The assignment for function '{function_name}' is missing in '{program_id}'"),
+            Some((assignment, _metrics)) => {
+                // Ensure the certificate is valid.
+                if !certificate.verify(&function_name.to_string(), assignment, verifying_key) {
+                    bail!("The certificate for function '{function_name}' is invalid in '{program_id}'")
+                }
+            }
+        };
         Ok(())
     }
 }
@@ -82,6 +82,8 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a closure scope."),
+                    // If the operand is the beacon output, throw an error.
+                    Operand::BeaconOutput => bail!("Cannot retrieve the beacon output from a closure scope."),
                 }
             })
             .collect();
@@ -166,6 +168,10 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         ensure!(request.verify(&function.input_types()), "Request is invalid");
         lap!(timer, "Verify the request");
 
+        // Ensure the spending predicates of the record inputs are satisfied.
+        self.check_record_predicates(&function, inputs)?;
+        lap!(timer, "Check the record predicates");
+
         // Store the inputs.
         function.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
             // Assign the input value to the register.
@@ -213,6 +219,8 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
                     Operand::Caller => Ok(Value::Plaintext(Plaintext::from(Literal::Address(registers.caller()?)))),
                     // If the operand is the block height, throw an error.
                     Operand::BlockHeight => bail!("Cannot retrieve the block height from a function scope."),
+                    // If the operand is the beacon output, throw an error.
+                    Operand::BeaconOutput => bail!("Cannot retrieve the beacon output from a function scope."),
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -115,6 +115,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a closure scope")
                     }
+                    // If the operand is the beacon output, throw an error.
+                    Operand::BeaconOutput => {
+                        bail!("Illegal operation: cannot retrieve the beacon output in a closure scope")
+                    }
                 }
             })
             .collect();
@@ -190,6 +194,13 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         ensure!(console_request.verify(&input_types), "Request is invalid");
         lap!(timer, "Verify the console request");
 
+        // Ensure the spending predicates of the record inputs are satisfied, to fail early when authorizing.
+        // Note: The predicates are enforced by the circuit below, so this check does not secure them.
+        // The sampled inputs used to synthesize the circuit do not satisfy the predicates.
+        if matches!(call_stack, CallStack::Authorize(..) | CallStack::PackageRun(..)) {
+            self.check_record_predicates(&function, console_request.inputs())?;
+        }
+
         // Initialize the registers.
         let mut registers = Registers::new(call_stack, self.get_register_types(function.name())?.clone());
 
@@ -249,6 +260,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         })?;
         lap!(timer, "Store the inputs");
 
+        // Enforce the spending predicates of the record inputs.
+        self.enforce_record_predicates::<A>(&function, request.inputs())?;
+        lap!(timer, "Enforce the record predicates");
+
         // Initialize a tracker to determine if there are any function calls.
         let mut contains_function_call = false;
 
@@ -321,6 +336,10 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                     Operand::BlockHeight => {
                         bail!("Illegal operation: cannot retrieve the block height in a function scope")
                     }
+                    // If the operand is the beacon output, throw an error.
+                    Operand::BeaconOutput => {
+                        bail!("Illegal operation: cannot retrieve the beacon output in a function scope")
+                    }
                 }
             })
             .collect::<Result<Vec<_>>>()?;
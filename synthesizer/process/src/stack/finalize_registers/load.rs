@@ -41,6 +41,10 @@ impl<N: Network> RegistersLoad<N> for FinalizeRegisters<N> {
             Operand::BlockHeight => {
                 return Ok(Value::Plaintext(Plaintext::from(Literal::U32(U32::new(self.state.block_height())))));
             }
+            // If the operand is the beacon output, load the beacon output.
+            Operand::BeaconOutput => {
+                return Ok(Value::Plaintext(Plaintext::from(Literal::Field(self.state.beacon_output()?))));
+            }
         };
 
         // Retrieve the value.
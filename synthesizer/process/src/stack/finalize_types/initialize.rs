@@ -628,7 +628,10 @@ impl<N: Network> FinalizeTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
-            Opcode::Sign => {
+            Opcode::Encrypt => {
+                bail!("Instruction 'encrypt' is not allowed in 'finalize'");
+            }
+            Opcode::Feed | Opcode::Sign => {
                 // Ensure the instruction has one destination register.
                 ensure!(
                     instruction.destinations().len() == 1,
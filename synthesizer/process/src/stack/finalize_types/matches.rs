@@ -96,6 +96,16 @@ impl<N: Network> FinalizeTypes<N> {
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{block_height_type}' in the operand '{operand}'.",
                     )
                 }
+                // Ensure the beacon output type (field) matches the member type.
+                Operand::BeaconOutput => {
+                    // Retrieve the beacon output type.
+                    let beacon_output_type = PlaintextType::Literal(LiteralType::Field);
+                    // Ensure the beacon output type matches the member type.
+                    ensure!(
+                        &beacon_output_type == member_type,
+                        "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{beacon_output_type}' in the operand '{operand}'.",
+                    )
+                }
             }
         }
         Ok(())
@@ -177,6 +187,17 @@ impl<N: Network> FinalizeTypes<N> {
                         array_type.next_element_type()
                     )
                 }
+                // Ensure the beacon output type (field) matches the member type.
+                Operand::BeaconOutput => {
+                    // Retrieve the beacon output type.
+                    let beacon_output_type = PlaintextType::Literal(LiteralType::Field);
+                    // Ensure the beacon output type matches the member type.
+                    ensure!(
+                        &beacon_output_type == array_type.next_element_type(),
+                        "Array element expects {}, but found '{beacon_output_type}' in the operand '{operand}'.",
+                        array_type.next_element_type()
+                    )
+                }
             }
         }
         Ok(())
@@ -15,12 +15,15 @@
 use super::*;
 
 impl<N: Network> Stack<N> {
-    /// Initializes a new stack, given the process and program.
+    /// Initializes a new stack, given the process, program, and the network edition it is deployed for.
     #[inline]
-    pub(crate) fn initialize(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+    pub(crate) fn initialize(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
+        // Ensure the edition is supported by the network.
+        ensure!(edition <= N::EDITION, "Program '{}' is deployed for an unsupported edition {edition}", program.id());
         // Construct the stack for the program.
         let mut stack = Self {
             program: program.clone(),
+            edition,
             external_stacks: Default::default(),
             register_types: Default::default(),
             finalize_types: Default::default(),
@@ -53,6 +56,20 @@ impl<N: Network> Stack<N> {
         // Return the stack.
         Ok(stack)
     }
+
+    /// Re-initializes the stack from the stacks in the given process, keeping its proving and verifying keys.
+    /// This is used to refresh the external stacks, once one of the imports is upgraded.
+    /// Note: The keys remain valid, as an upgrade keeps the signature of each function in the imports.
+    #[inline]
+    pub(crate) fn reinitialize(&self, process: &Process<N>) -> Result<Self> {
+        // Construct the stack for the program.
+        let mut stack = Self::initialize(process, &self.program, self.edition)?;
+        // Share the proving and verifying keys.
+        stack.proving_keys = self.proving_keys.clone();
+        stack.verifying_keys = self.verifying_keys.clone();
+        // Return the stack.
+        Ok(stack)
+    }
 }
 
 impl<N: Network> Stack<N> {
@@ -102,6 +119,9 @@ impl<N: Network> Stack<N> {
         // Add the function name and register types to the stack.
         self.register_types.insert(*name, register_types);
 
+        // Ensure the function declares the inputs for the spending predicates of its record inputs.
+        self.check_record_predicate_inputs(function)?;
+
         // If the function contains a finalize, insert it.
         if let Some(finalize) = function.finalize_logic() {
             // Compute the finalize types.
@@ -16,5 +16,6 @@ use super::*;
 
 mod initialize;
 mod matches;
+mod predicates;
 mod sample;
 mod synthesize;
@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Returns the record inputs of the given function that declare spending predicates,
+    /// as the index of each record input and its predicates, in the order of their inputs.
+    ///
+    /// Programs deployed before `RecordPredicate::EDITION` have no predicates, as their entries
+    /// may use the reserved names for other purposes.
+    pub(crate) fn get_record_predicates(&self, function: &Function<N>) -> Result<Vec<(usize, Vec<RecordPredicate>)>> {
+        let mut record_predicates = Vec::new();
+        if self.edition < RecordPredicate::EDITION {
+            return Ok(record_predicates);
+        }
+        for (index, input_type) in function.input_types().iter().enumerate() {
+            if let ValueType::Record(record_name) = input_type {
+                let predicates = self.program.get_record(record_name)?.predicates()?;
+                if !predicates.is_empty() {
+                    record_predicates.push((index, predicates));
+                }
+            }
+        }
+        Ok(record_predicates)
+    }
+
+    /// Ensures the given function declares the public input of each predicate of its record inputs,
+    /// immediately after the record input.
+    pub(crate) fn check_record_predicate_inputs(&self, function: &Function<N>) -> Result<()> {
+        let input_types = function.input_types();
+        for (index, predicates) in self.get_record_predicates(function)? {
+            for (offset, predicate) in predicates.iter().enumerate() {
+                let expected = ValueType::Public(PlaintextType::Literal(predicate.literal_type()));
+                ensure!(
+                    input_types.get(index + 1 + offset) == Some(&expected),
+                    "Function '{}' must declare input {} as '{expected}', for the '{}' of input {index}",
+                    function.name(),
+                    index + 1 + offset,
+                    predicate.entry_name()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the given inputs satisfy the spending predicates of the record inputs of the function.
+    pub(crate) fn check_record_predicates(&self, function: &Function<N>, inputs: &[Value<N>]) -> Result<()> {
+        for (index, predicates) in self.get_record_predicates(function)? {
+            let Some(Value::Record(record)) = inputs.get(index) else { bail!("Expected input {index} to be a record") };
+            for (offset, predicate) in predicates.iter().enumerate() {
+                let entry = record.data().get(&Identifier::from_str(predicate.entry_name())?);
+                match (predicate, entry, inputs.get(index + 1 + offset)) {
+                    (
+                        RecordPredicate::HashLock,
+                        Some(Entry::Public(Plaintext::Literal(Literal::Field(hash_lock), _))),
+                        Some(Value::Plaintext(Plaintext::Literal(Literal::Field(preimage), _))),
+                    ) => ensure!(
+                        RecordPredicate::hash_lock(preimage)? == *hash_lock,
+                        "Input {} is not the preimage of the hash lock of input {index}",
+                        index + 1 + offset
+                    ),
+                    (
+                        RecordPredicate::SpendAfter,
+                        Some(Entry::Public(Plaintext::Literal(Literal::U32(spend_after), _))),
+                        Some(Value::Plaintext(Plaintext::Literal(Literal::U32(height), _))),
+                    ) => ensure!(
+                        height == spend_after,
+                        "Input {} does not match the height lock of input {index}",
+                        index + 1 + offset
+                    ),
+                    _ => bail!("Malformed '{}' predicate for input {index}", predicate.entry_name()),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Enforces the spending predicates of the record inputs of the function, in the circuit.
+    pub(crate) fn enforce_record_predicates<A: circuit::Aleo<Network = N>>(
+        &self,
+        function: &Function<N>,
+        inputs: &[circuit::Value<A>],
+    ) -> Result<()> {
+        use circuit::{
+            traits::{Equal, ToBits},
+            Inject,
+        };
+
+        for (index, predicates) in self.get_record_predicates(function)? {
+            let Some(circuit::Value::Record(record)) = inputs.get(index) else {
+                bail!("Expected input {index} to be a record")
+            };
+            for (offset, predicate) in predicates.iter().enumerate() {
+                let entry_name = circuit::Identifier::constant(Identifier::from_str(predicate.entry_name())?);
+                match (predicate, record.data().get(&entry_name), inputs.get(index + 1 + offset)) {
+                    (
+                        RecordPredicate::HashLock,
+                        Some(circuit::Entry::Public(circuit::Plaintext::Literal(
+                            circuit::Literal::Field(hash_lock),
+                            _,
+                        ))),
+                        Some(circuit::Value::Plaintext(circuit::Plaintext::Literal(
+                            circuit::Literal::Field(preimage),
+                            _,
+                        ))),
+                    ) => A::assert_eq(&A::hash_bhp256(&preimage.to_bits_le()), hash_lock),
+                    (
+                        RecordPredicate::SpendAfter,
+                        Some(circuit::Entry::Public(circuit::Plaintext::Literal(
+                            circuit::Literal::U32(spend_after),
+                            _,
+                        ))),
+                        Some(circuit::Value::Plaintext(circuit::Plaintext::Literal(circuit::Literal::U32(height), _))),
+                    ) => A::assert(height.is_equal(spend_after)),
+                    _ => bail!("Malformed '{}' predicate for input {index}", predicate.entry_name()),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the height locks of the records consumed by the given transition have passed.
+    pub fn check_spend_after(&self, transition: &Transition<N>, block_height: u32) -> Result<()> {
+        let function = self.get_function_ref(transition.function_name())?;
+        for (index, predicates) in self.get_record_predicates(function)? {
+            let Some(offset) = predicates.iter().position(|predicate| *predicate == RecordPredicate::SpendAfter) else {
+                continue;
+            };
+            // Retrieve the height lock, from the public input of the predicate.
+            let spend_after = match transition.inputs().get(index + 1 + offset) {
+                Some(ledger_block::Input::Public(_, Some(Plaintext::Literal(Literal::U32(spend_after), _)))) => {
+                    **spend_after
+                }
+                _ => bail!("Transition '{}' is missing the height lock of input {index}", transition.id()),
+            };
+            ensure!(
+                block_height >= spend_after,
+                "Transition '{}' spends a record locked until block {spend_after}",
+                transition.id()
+            );
+        }
+        Ok(())
+    }
+}
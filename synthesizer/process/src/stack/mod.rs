@@ -52,6 +52,7 @@ use console::{
         PlaintextType,
         ProgramID,
         Record,
+        RecordPredicate,
         RecordType,
         RegisterType,
         Request,
@@ -168,6 +169,8 @@ impl<N: Network> CallStack<N> {
 pub struct Stack<N: Network> {
     /// The program (record types, structs, functions).
     program: Program<N>,
+    /// The network edition the program is deployed for.
+    edition: u16,
     /// The mapping of external stacks as `(program ID, stack)`.
     external_stacks: IndexMap<ProgramID<N>, Stack<N>>,
     /// The mapping of closure and function names to their register types.
@@ -186,10 +189,47 @@ impl<N: Network> Stack<N> {
     /// Initializes a new stack, if it does not already exist, given the process and the program.
     #[inline]
     pub fn new(process: &Process<N>, program: &Program<N>) -> Result<Self> {
+        Self::new_with_edition(process, program, N::EDITION)
+    }
+
+    /// Initializes a new stack, if it does not already exist, given the process and the program,
+    /// for a program deployed for the given network edition.
+    #[inline]
+    pub fn new_with_edition(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
         // Retrieve the program ID.
         let program_id = program.id();
         // Ensure the program does not already exist in the process.
         ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+        // Compute the stack.
+        Self::new_unchecked(process, program, edition)
+    }
+
+    /// Initializes a new stack for an upgrade of a program in the process, given the process and the upgraded program,
+    /// for a program deployed for the given network edition.
+    #[inline]
+    pub fn new_upgrade(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
+        // Ensure the program exists in the process.
+        ensure!(process.contains_program(program_id), "Cannot upgrade program '{program_id}', as it does not exist");
+        // Ensure the program is compatible with the deployed program.
+        program.check_is_upgrade_of(process.get_program(program_id)?)?;
+        // Ensure the program does not import a program that imports it, as the imports would form a cycle.
+        let dependents = process.get_dependents(program_id);
+        if let Some(import) = program.imports().keys().find(|import| dependents.contains(*import)) {
+            bail!("Cannot upgrade program '{program_id}', as its import '{import}' imports it")
+        }
+        // Compute the stack.
+        Self::new_unchecked(process, program, edition)
+    }
+
+    /// Initializes a new stack, given the process and the program,
+    /// for a program deployed for the given network edition.
+    /// Note: This method does not check whether the program already exists in the process.
+    #[inline]
+    fn new_unchecked(process: &Process<N>, program: &Program<N>, edition: u16) -> Result<Self> {
+        // Retrieve the program ID.
+        let program_id = program.id();
         // Ensure the program contains functions.
         ensure!(!program.functions().is_empty(), "No functions present in the deployment for program '{program_id}'");
 
@@ -204,7 +244,13 @@ impl<N: Network> Stack<N> {
         ensure!(program == &Program::from_str(&program_string)?, "Program string serialization failed");
 
         // Return the stack.
-        Stack::initialize(process, program)
+        Stack::initialize(process, program, edition)
+    }
+
+    /// Returns the network edition the program is deployed for.
+    #[inline]
+    pub const fn edition(&self) -> u16 {
+        self.edition
     }
 }
 
@@ -393,10 +439,15 @@ impl<N: Network> Stack<N> {
         if self.program_id() == &ProgramID::from_str("credits.aleo")?
             && !self.proving_keys.read().contains_key(function_name)
         {
-            // Load the 'credits.aleo' function proving key.
-            let proving_key = N::get_credits_proving_key(function_name.to_string())?;
-            // Insert the 'credits.aleo' function proving key.
-            self.insert_proving_key(function_name, ProvingKey::new(proving_key.clone()))?;
+            // Ensure the 'credits.aleo' proving keys are available.
+            ensure!(cfg!(feature = "prover"), "The 'credits.aleo' proving keys require the 'prover' feature");
+            #[cfg(feature = "prover")]
+            {
+                // Load the 'credits.aleo' function proving key.
+                let proving_key = N::get_credits_proving_key(function_name.to_string())?;
+                // Insert the 'credits.aleo' function proving key.
+                self.insert_proving_key(function_name, ProvingKey::new(proving_key.clone()))?;
+            }
         }
         Ok(())
     }
@@ -405,6 +456,7 @@ impl<N: Network> Stack<N> {
 impl<N: Network> PartialEq for Stack<N> {
     fn eq(&self, other: &Self) -> bool {
         self.program == other.program
+            && self.edition == other.edition
             && self.external_stacks == other.external_stacks
             && self.register_types == other.register_types
             && self.finalize_types == other.finalize_types
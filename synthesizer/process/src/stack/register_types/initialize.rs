@@ -219,7 +219,17 @@ impl<N: Network> RegisterTypes<N> {
         match register {
             Register::Locator(locator) => {
                 // Ensure the registers are monotonically increasing.
-                ensure!(self.inputs.len() as u64 == locator, "Register '{register}' is out of order");
+                let expected_locator = self.inputs.len() as u64;
+                ensure!(
+                    expected_locator == locator,
+                    "Register '{register}' is out of order, expected 'r{expected_locator}'"
+                );
+                // Ensure the number of registers does not exceed the maximum.
+                ensure!(
+                    locator < N::MAX_REGISTERS as u64,
+                    "Register '{register}' exceeds the maximum of {} registers",
+                    N::MAX_REGISTERS
+                );
 
                 // Insert the input register and type.
                 match self.inputs.insert(locator, register_type) {
@@ -242,7 +252,16 @@ impl<N: Network> RegisterTypes<N> {
             Register::Locator(locator) => {
                 // Ensure the registers are monotonically increasing.
                 let expected_locator = (self.inputs.len() as u64) + self.destinations.len() as u64;
-                ensure!(expected_locator == locator, "Register '{register}' is out of order");
+                ensure!(
+                    expected_locator == locator,
+                    "Register '{register}' is out of order, expected 'r{expected_locator}'"
+                );
+                // Ensure the number of registers does not exceed the maximum.
+                ensure!(
+                    locator < N::MAX_REGISTERS as u64,
+                    "Register '{register}' exceeds the maximum of {} registers",
+                    N::MAX_REGISTERS
+                );
 
                 // Insert the destination register and type.
                 match self.destinations.insert(locator, register_type) {
@@ -371,20 +390,31 @@ impl<N: Network> RegisterTypes<N> {
         // Iterate over the operands, and retrieve the register type of each operand.
         for operand in instruction.operands() {
             // Retrieve and append the register type.
-            operand_types.push(self.get_type_from_operand(stack, operand)?);
+            match self.get_type_from_operand(stack, operand) {
+                Ok(operand_type) => operand_types.push(operand_type),
+                Err(error) => bail!("Instruction '{instruction}' in '{closure_or_function_name}' is invalid: {error}"),
+            }
         }
 
         // Compute the destination register types.
-        let destination_types = instruction.output_types(stack, &operand_types)?;
+        let destination_types = match instruction.output_types(stack, &operand_types) {
+            Ok(destination_types) => destination_types,
+            Err(error) => bail!("Instruction '{instruction}' in '{closure_or_function_name}' is invalid: {error}"),
+        };
 
         // Insert the destination register.
         for (destination, destination_type) in
             instruction.destinations().into_iter().zip_eq(destination_types.into_iter())
         {
             // Ensure the destination register is a locator (and does not reference an access).
-            ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
-            // Insert the destination register.
-            self.add_destination(destination, destination_type)?;
+            ensure!(
+                matches!(destination, Register::Locator(..)),
+                "Destination '{destination}' of '{instruction}' in '{closure_or_function_name}' must be a locator."
+            );
+            // Insert the destination register, ensuring it is written exactly once.
+            if let Err(error) = self.add_destination(destination, destination_type) {
+                bail!("Instruction '{instruction}' in '{closure_or_function_name}' is invalid: {error}")
+            }
         }
         Ok(())
     }
@@ -572,7 +602,7 @@ impl<N: Network> RegisterTypes<N> {
                 ),
                 _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
             },
-            Opcode::Sign => {
+            Opcode::Encrypt | Opcode::Feed | Opcode::Sign => {
                 // Ensure the instruction has one destination register.
                 ensure!(
                     instruction.destinations().len() == 1,
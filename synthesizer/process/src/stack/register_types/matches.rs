@@ -88,6 +88,10 @@ impl<N: Network> RegisterTypes<N> {
                 Operand::BlockHeight => bail!(
                     "Struct member '{struct_name}.{member_name}' cannot be from a block height in a non-finalize scope"
                 ),
+                // If the operand is a beacon output type, throw an error.
+                Operand::BeaconOutput => bail!(
+                    "Struct member '{struct_name}.{member_name}' cannot be from a beacon output in a non-finalize scope"
+                ),
             }
         }
         Ok(())
@@ -162,6 +166,8 @@ impl<N: Network> RegisterTypes<N> {
                 }
                 // If the operand is a block height type, throw an error.
                 Operand::BlockHeight => bail!("Array element cannot be from a block height in a non-finalize scope"),
+                // If the operand is a beacon output type, throw an error.
+                Operand::BeaconOutput => bail!("Array element cannot be from a beacon output in a non-finalize scope"),
             }
         }
         Ok(())
@@ -226,6 +232,9 @@ impl<N: Network> RegisterTypes<N> {
             Operand::BlockHeight => {
                 bail!("Forbidden operation: Cannot cast a block height as a record owner")
             }
+            Operand::BeaconOutput => {
+                bail!("Forbidden operation: Cannot cast a beacon output as a record owner")
+            }
         }
 
         // Ensure the operand types match the record entry types.
@@ -281,6 +290,12 @@ impl<N: Network> RegisterTypes<N> {
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a block height in the operand '{operand}'."
                             )
                         }
+                        // Fail if the operand is a beacon output.
+                        Operand::BeaconOutput => {
+                            bail!(
+                                "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found a beacon output in the operand '{operand}'."
+                            )
+                        }
                     }
                 }
             }
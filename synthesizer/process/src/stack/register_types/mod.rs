@@ -97,6 +97,7 @@ impl<N: Network> RegisterTypes<N> {
                 RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address))
             }
             Operand::BlockHeight => bail!("'block.height' is not a valid operand in a non-finalize context."),
+            Operand::BeaconOutput => bail!("'beacon.output' is not a valid operand in a non-finalize context."),
         })
     }
 
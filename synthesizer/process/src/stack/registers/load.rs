@@ -38,6 +38,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoad<N> for Registers<N
             Operand::Caller => return Ok(Value::Plaintext(Plaintext::from(Literal::Address(self.caller()?)))),
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is the beacon output, throw an error.
+            Operand::BeaconOutput => bail!("Cannot load the beacon output in a non-finalize context"),
         };
 
         // Retrieve the stack value.
@@ -121,6 +123,8 @@ impl<N: Network, A: circuit::Aleo<Network = N>> RegistersLoadCircuit<N, A> for R
             }
             // If the operand is the block height, throw an error.
             Operand::BlockHeight => bail!("Cannot load the block height in a non-finalize context"),
+            // If the operand is the beacon output, throw an error.
+            Operand::BeaconOutput => bail!("Cannot load the beacon output in a non-finalize context"),
         };
 
         // Retrieve the circuit value.
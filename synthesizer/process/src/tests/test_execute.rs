@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    default_interface,
     traits::{StackEvaluate, StackExecute},
     CallStack,
     Process,
@@ -22,7 +23,7 @@ use circuit::{network::AleoV0, Aleo};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, Testnet3},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
+    program::{FeedUpdate, Identifier, Literal, Plaintext, ProgramID, Record, Value},
     types::{Field, U64},
 };
 use ledger_block::Fee;
@@ -2362,8 +2363,11 @@ fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: IndexMap::new(),
+        interface: default_interface(),
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2483,3 +2487,304 @@ function {function_name}:
     assert_ne!(execution_1.peek().unwrap().id(), execution_2.peek().unwrap().id());
     assert_ne!(execution_1.to_execution_id().unwrap(), execution_2.to_execution_id().unwrap());
 }
+
+#[test]
+fn test_process_register_allocation() {
+    // Initialize a new process.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+
+    // Ensure a destination register that skips a locator is rejected.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program skipped_register.aleo;
+
+function foo:
+    input r0 as u8.public;
+    add r0 r0 into r2;
+    output r2 as u8.public;",
+    )
+    .unwrap();
+    let error = process.add_program(&program).unwrap_err().to_string();
+    assert!(error.contains("in 'foo'"), "{error}");
+    assert!(error.contains("expected 'r1'"), "{error}");
+
+    // Ensure a destination register that is written twice is rejected.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program rewritten_register.aleo;
+
+function foo:
+    input r0 as u8.public;
+    add r0 r0 into r1;
+    add r1 r1 into r1;
+    output r1 as u8.public;",
+    )
+    .unwrap();
+    let error = process.add_program(&program).unwrap_err().to_string();
+    assert!(error.contains("in 'foo'"), "{error}");
+    assert!(error.contains("expected 'r2'"), "{error}");
+
+    // Ensure a well-formed program is accepted.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program allocated_registers.aleo;
+
+function foo:
+    input r0 as u8.public;
+    add r0 r0 into r1;
+    add r1 r1 into r2;
+    output r2 as u8.public;",
+    )
+    .unwrap();
+    process.add_program(&program).unwrap();
+}
+
+#[test]
+fn test_process_execute_feed_verify() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program price_feed.aleo;
+
+function ingest:
+    input r0 as signature.private;
+    input r1 as address.public;
+    input r2 as field.public;
+    input r3 as u128.public;
+    input r4 as u64.public;
+    feed.verify r0 r1 r2 r3 r4 into r5;
+    output r5 as boolean.public;",
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("ingest").unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Sign a feed update, as the feed signer.
+    let signer_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let feed_update = FeedUpdate::sign(&signer_private_key, Field::rand(rng), 42_000_000, 1_700_000_000, rng).unwrap();
+    let inputs = feed_update.to_inputs();
+
+    // Tamper with the value of the feed update.
+    let mut tampered_inputs = inputs.clone();
+    tampered_inputs[3] = Value::from_str("43000000u128").unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    for (inputs, expected) in [(inputs, "true"), (tampered_inputs, "false")] {
+        let expected = Value::<CurrentNetwork>::from_str(expected).unwrap();
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+            .unwrap();
+        assert_eq!(authorization.len(), 1);
+
+        // Evaluate the function.
+        let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(response.outputs(), [expected.clone()]);
+
+        // Execute the function.
+        let (response, _trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+        assert_eq!(response.outputs(), [expected]);
+    }
+}
+
+#[test]
+fn test_process_execute_encrypt() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize the note, and determine the number of fields of its ciphertext.
+    let note = Plaintext::<CurrentNetwork>::from_str("{ secret: 7field, amount: 100u64 }").unwrap();
+    let num_fields = note.to_fields().unwrap().len() + 1;
+
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::from_str(&format!(
+        r"
+program secret_note.aleo;
+
+struct note:
+    secret as field;
+    amount as u64;
+
+function notify:
+    input r0 as note.private;
+    input r1 as address.public;
+    input r2 as scalar.private;
+    encrypt r0 r1 r2 into r3 as [field; {num_fields}u32];
+    output r3 as [field; {num_fields}u32].public;"
+    ))
+    .unwrap();
+    let function_name = Identifier::from_str("notify").unwrap();
+
+    // Initialize a new caller account, and the recipient of the note.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let recipient_view_key = ViewKey::try_from(&recipient_private_key).unwrap();
+    let recipient = Address::try_from(&recipient_private_key).unwrap();
+
+    // Prepare the inputs.
+    let inputs = [
+        Value::Plaintext(note.clone()),
+        Value::from(Literal::Address(recipient)),
+        Value::from(Literal::Scalar(Uniform::rand(rng))),
+    ];
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Evaluate the function.
+    let response = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let candidate = response.outputs().to_vec();
+
+    // Execute the function.
+    let (response, _trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), candidate);
+
+    // Ensure the recipient decrypts the note, and no other account does.
+    let outcome = match &candidate[0] {
+        Value::Plaintext(outcome) => outcome,
+        _ => panic!("Expected the output to be a plaintext"),
+    };
+    assert_eq!(synthesizer_program::Encrypt::decrypt(outcome, &recipient_view_key).unwrap(), note);
+    let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+    assert_ne!(synthesizer_program::Encrypt::decrypt(outcome, &caller_view_key).ok(), Some(note));
+}
+
+#[test]
+fn test_process_encrypt_in_finalize() {
+    // Initialize a new program, which encrypts in finalize.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program secret_finalize.aleo;
+
+mapping notes:
+    key as u8.public;
+    value as field.public;
+
+function notify:
+    input r0 as field.public;
+    input r1 as address.public;
+    input r2 as scalar.public;
+    async notify r0 r1 r2 into r3;
+    output r3 as secret_finalize.aleo/notify.future;
+
+finalize notify:
+    input r0 as field.public;
+    input r1 as address.public;
+    input r2 as scalar.public;
+    encrypt r0 r1 r2 into r3 as [field; 3u32];
+    set r0 into notes[0u8];",
+    )
+    .unwrap();
+
+    // Ensure the program is rejected, as `encrypt` is not allowed in finalize.
+    let mut process = Process::load().unwrap();
+    assert!(process.add_program(&program).is_err());
+}
+
+#[test]
+fn test_process_execute_record_predicates() {
+    // Initialize a new program, whose record may only be spent with a preimage, from block 4.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program hash_time_lock.aleo;
+
+record lock:
+    owner as address.private;
+    amount as u64.private;
+    hash_lock as field.public;
+    spend_after as u32.public;
+
+function claim:
+    input r0 as lock.record;
+    input r1 as field.public;
+    input r2 as u32.public;
+    output r0.amount as u64.private;",
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("claim").unwrap();
+    let input_types = program.get_function(&function_name).unwrap().input_types();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Initialize a record, which is locked by the hash of the preimage.
+    let preimage = Field::<CurrentNetwork>::rand(rng);
+    let hash_lock = console::program::RecordPredicate::hash_lock(&preimage).unwrap();
+    let record = Value::<CurrentNetwork>::from_str(&format!(
+        "{{ owner: {caller}.private, amount: 100u64.private, hash_lock: {hash_lock}.public, \
+        spend_after: 4u32.public, _nonce: {}.public }}",
+        console::types::Group::<CurrentNetwork>::zero()
+    ))
+    .unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Executes the function on the given inputs, signing the request directly, so the native checks are skipped.
+    let execute = |preimage: Field<CurrentNetwork>, spend_after: &str, rng: &mut TestRng| {
+        let inputs = [record.clone(), Value::from(Literal::Field(preimage)), Value::from_str(spend_after).unwrap()];
+        let request = console::program::Request::sign(
+            &caller_private_key,
+            *program.id(),
+            function_name,
+            inputs.iter(),
+            &input_types,
+            rng,
+        )
+        .unwrap();
+        process.execute::<CurrentAleo>(crate::Authorization::new(request))
+    };
+
+    // Ensure the circuit is not satisfied with a wrong preimage, or a wrong height lock.
+    assert!(execute(Field::rand(rng), "4u32", rng).is_err());
+    assert!(execute(preimage, "0u32", rng).is_err());
+    // Ensure the circuit is satisfied with the preimage and the height lock.
+    let (response, _trace) = execute(preimage, "4u32", rng).unwrap();
+    assert_eq!(response.outputs(), [Value::from_str("100u64").unwrap()]);
+}
+
+#[test]
+fn test_process_record_predicates_edition() {
+    // Initialize a new program, which uses a reserved entry name for another purpose.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program legacy_lock.aleo;
+
+record lock:
+    owner as address.private;
+    hash_lock as u64.private;
+
+function spend:
+    input r0 as lock.record;
+    output r0.hash_lock as u64.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let process = Process::load().unwrap();
+
+    // Ensure the program is rejected for the current edition, where the entry declares a hash lock.
+    assert!(crate::Stack::new(&process, &program).is_err());
+    // Ensure the program is accepted for the edition before the record predicates.
+    let edition = console::program::RecordPredicate::EDITION - 1;
+    let stack = crate::Stack::new_with_edition(&process, &program, edition).unwrap();
+    assert_eq!(stack.edition(), edition);
+}
@@ -87,8 +87,8 @@ impl<N: Network> Inclusion<N> {
             let transaction_leaf = TransactionLeaf::new_execution(transition_index, **transition.id());
             // Compute the transition root.
             let transition_root = transition.to_root()?;
-            // Fetch the tcm.
-            let tcm = *transition.tcm();
+            // Fetch the tcm, as bound into the transition ID.
+            let tcm = transition.to_bound_tcm()?;
 
             // Process the outputs.
             for (index, output) in transition.outputs().iter().enumerate() {
@@ -197,6 +197,9 @@ impl<N: Network> InclusionAssignment<N> {
     ///                                    |
     /// [[ serial_number ]] := Commit( commitment || Hash( COFACTOR * gamma ) )
     /// ```
+    ///
+    /// The commitment and state path are private, so the spent record is hidden among every
+    /// record commitment under the global state root, rather than among a sampled set of decoys.
     pub fn to_circuit_assignment<A: circuit::Aleo<Network = N>>(&self) -> Result<circuit::Assignment<N::Field>> {
         use circuit::Inject;
 
@@ -283,10 +283,11 @@ impl<N: Network> Trace<N> {
         }
 
         if !batch_inclusions.is_empty() {
-            // Fetch the inclusion proving key.
-            let proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
-            // Insert the inclusion proving key and assignments.
-            proving_tasks.push((proving_key, batch_inclusions));
+            // Ensure the inclusion proving key is available.
+            ensure!(cfg!(feature = "prover"), "The inclusion proving key requires the 'prover' feature");
+            // Fetch the inclusion proving key, and insert it with the assignments.
+            #[cfg(feature = "prover")]
+            proving_tasks.push((ProvingKey::<N>::new(N::inclusion_proving_key().clone()), batch_inclusions));
         }
 
         // Compute the proof.
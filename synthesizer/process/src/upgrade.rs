@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexSet;
+
+impl<N: Network> Process<N> {
+    /// Returns the IDs of the programs that import the given program, directly or through other imports.
+    #[inline]
+    pub fn get_dependents(&self, program_id: &ProgramID<N>) -> IndexSet<ProgramID<N>> {
+        let mut dependents = IndexSet::new();
+        // Add the programs that import the program or one of its dependents, until no more are found.
+        loop {
+            let num_dependents = dependents.len();
+            for (dependent_id, stack) in &self.stacks {
+                if !dependents.contains(dependent_id)
+                    && stack
+                        .program()
+                        .imports()
+                        .keys()
+                        .any(|import| import == program_id || dependents.contains(import))
+                {
+                    dependents.insert(*dependent_id);
+                }
+            }
+            if dependents.len() == num_dependents {
+                return dependents;
+            }
+        }
+    }
+
+    /// Replaces the stack of an upgraded program, and re-initializes the stacks of the programs that import it,
+    /// so their external stacks use the upgraded program.
+    /// If you intend to `execute` the upgraded program, use `finalize_deployment` to compute the stack instead.
+    #[inline]
+    pub fn upgrade_stack(&mut self, stack: Stack<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = *stack.program_id();
+        // Ensure the program exists in the process.
+        ensure!(self.contains_program(&program_id), "Cannot upgrade program '{program_id}', as it does not exist");
+
+        // Upgrade a copy of the process, so the process is unchanged if any stack fails to re-initialize.
+        let mut process = self.clone();
+        // Retrieve the dependents of the program.
+        let mut dependents = process.get_dependents(&program_id);
+        // Replace the stack of the upgraded program.
+        process.add_stack(stack);
+        // Re-initialize each dependent, once the dependents it imports are re-initialized.
+        while !dependents.is_empty() {
+            let Some(index) = dependents.iter().position(|dependent_id| {
+                process.stacks[dependent_id].program().imports().keys().all(|import| !dependents.contains(import))
+            }) else {
+                bail!("Cannot upgrade program '{program_id}', as the programs that import it form a cycle")
+            };
+            if let Some(dependent_id) = dependents.shift_remove_index(index) {
+                let stack = process.get_stack(dependent_id)?.reinitialize(&process)?;
+                process.add_stack(stack);
+            }
+        }
+
+        // Commit the upgraded stacks.
+        *self = process;
+        Ok(())
+    }
+}
@@ -23,13 +23,9 @@ impl<N: Network> Process<N> {
         rng: &mut R,
     ) -> Result<()> {
         let timer = timer!("Process::verify_deployment");
-        // Retrieve the program ID.
-        let program_id = deployment.program().id();
-        // Ensure the program does not already exist in the process.
-        ensure!(!self.contains_program(program_id), "Program '{program_id}' already exists");
 
         // Ensure the program is well-formed, by computing the stack.
-        let stack = Stack::new(self, deployment.program())?;
+        let stack = self.compute_deployment_stack(deployment)?;
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
@@ -39,6 +35,56 @@ impl<N: Network> Process<N> {
         finish!(timer);
         verification
     }
+
+    /// Verifies the given deployment of a program that is already in the process,
+    /// e.g. to retroactively verify a deployment that was added to the ledger without verification.
+    #[inline]
+    pub fn verify_existing_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = deployment.program().id();
+        // Retrieve the stack of the deployed program.
+        let stack = self.get_stack(program_id)?;
+        // If the deployment is for an edition that was since upgraded, recompute the stack of that edition.
+        if stack.program() != deployment.program() {
+            // Ensure the deployed program is an upgrade of the deployment.
+            if let Err(error) = stack.program().check_is_upgrade_of(deployment.program()) {
+                bail!("The deployment does not match program '{program_id}': {error}")
+            }
+            // Ensure the verifying keys are well-formed and the certificates are valid.
+            return Stack::initialize(self, deployment.program(), deployment.edition())?
+                .verify_deployment::<A, R>(deployment, rng);
+        }
+        // Ensure the verifying keys are well-formed and the certificates are valid.
+        stack.verify_deployment::<A, R>(deployment, rng)
+    }
+
+    /// Verifies the certificate of each function in the given deployment, and returns the result for every function.
+    /// Unlike `verify_deployment`, this does not stop at the first invalid certificate.
+    #[inline]
+    pub fn verify_deployment_certificates<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<Vec<(Identifier<N>, Result<()>)>> {
+        // Ensure the program is well-formed, by computing the stack.
+        let stack = self.compute_deployment_stack(deployment)?;
+        // Verify the certificate of each function.
+        stack.verify_deployment_certificates::<A, R>(deployment, rng)
+    }
+
+    /// Computes the stack of the given deployment,
+    /// which is either a new program, or an upgrade of a program in the process.
+    #[inline]
+    pub(crate) fn compute_deployment_stack(&self, deployment: &Deployment<N>) -> Result<Stack<N>> {
+        match deployment.is_upgrade() {
+            true => Stack::new_upgrade(self, deployment.program(), deployment.edition()),
+            false => Stack::new_with_edition(self, deployment.program(), deployment.edition()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -60,8 +60,8 @@ impl<N: Network> Process<N> {
             println!("Verifying transition for {}/{}...", transition.program_id(), transition.function_name());
             // Debug-mode only, as the `Transition` constructor recomputes the transition ID at initialization.
             debug_assert_eq!(
-                **transition.id(),
-                N::hash_bhp512(&(transition.to_root()?, *transition.tcm()).to_bits_le())?,
+                *transition.id(),
+                Transition::compute_id(transition.to_root()?, *transition.tcm(), transition.expiry())?,
                 "The transition ID is incorrect"
             );
 
@@ -194,6 +194,14 @@ impl<N: Network> Process<N> {
 
         // [Inputs] Extend the verifier inputs with the output IDs.
         inputs.extend(transition.outputs().iter().flat_map(|output| output.verifier_inputs()));
+        // Ensure the number of public inputs is within bounds.
+        ensure!(
+            inputs.len() <= N::MAX_PUBLIC_INPUTS,
+            "Transition '{}' has too many public inputs ({} > {})",
+            transition.id(),
+            inputs.len(),
+            N::MAX_PUBLIC_INPUTS
+        );
 
         #[cfg(debug_assertions)]
         println!("Transition public inputs ({} elements): {:#?}", inputs.len(), inputs);
@@ -34,8 +34,8 @@ impl<N: Network> Process<N> {
             }
             // Debug-mode only, as the `Transition` constructor recomputes the transition ID at initialization.
             debug_assert_eq!(
-                **fee.id(),
-                N::hash_bhp512(&(fee.to_root()?, *fee.tcm()).to_bits_le())?,
+                *fee.id(),
+                Transition::compute_id(fee.to_root()?, *fee.tcm(), fee.expiry())?,
                 "Transition ID of the fee is incorrect"
             );
         }
@@ -0,0 +1,258 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{CallOperator, Instruction, Program};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Locator, ProgramID},
+};
+
+/// The target of a `call` instruction.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum CallTarget<N: Network> {
+    /// A closure in the same program.
+    Closure(Identifier<N>),
+    /// A function or closure in an imported program.
+    External(Locator<N>),
+}
+
+impl<N: Network> Display for CallTarget<N> {
+    /// Prints the call target as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Closure(name) => Display::fmt(name, f),
+            Self::External(locator) => Display::fmt(locator, f),
+        }
+    }
+}
+
+/// A `call` from a function or closure to its target.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CallEdge<N: Network> {
+    /// The name of the calling function or closure.
+    caller: Identifier<N>,
+    /// The target of the call.
+    callee: CallTarget<N>,
+    /// The estimated cost of the call, as the number of instructions in the callee.
+    estimated_cost: Option<u64>,
+}
+
+impl<N: Network> CallEdge<N> {
+    /// Returns the name of the calling function or closure.
+    pub const fn caller(&self) -> &Identifier<N> {
+        &self.caller
+    }
+
+    /// Returns the target of the call.
+    pub const fn callee(&self) -> &CallTarget<N> {
+        &self.callee
+    }
+
+    /// Returns the estimated cost of the call, as the number of instructions in the callee.
+    /// Note: External callees are not available to the program, so their cost is `None`.
+    pub const fn estimated_cost(&self) -> Option<u64> {
+        self.estimated_cost
+    }
+}
+
+/// The call graph of a program, from its functions and closures to their call targets.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CallGraph<N: Network> {
+    /// The ID of the program.
+    program_id: ProgramID<N>,
+    /// The names of the closures and functions in the program.
+    nodes: Vec<Identifier<N>>,
+    /// The calls made by the closures and functions, in program order.
+    edges: Vec<CallEdge<N>>,
+}
+
+impl<N: Network> CallGraph<N> {
+    /// Returns the ID of the program.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the names of the closures and functions in the program.
+    pub fn nodes(&self) -> &[Identifier<N>] {
+        &self.nodes
+    }
+
+    /// Returns the calls made by the closures and functions, in program order.
+    pub fn edges(&self) -> &[CallEdge<N>] {
+        &self.edges
+    }
+
+    /// Returns the programs called by this program.
+    pub fn external_programs(&self) -> Vec<ProgramID<N>> {
+        let mut program_ids = Vec::new();
+        for edge in &self.edges {
+            if let CallTarget::External(locator) = edge.callee() {
+                if !program_ids.contains(locator.program_id()) {
+                    program_ids.push(*locator.program_id());
+                }
+            }
+        }
+        program_ids
+    }
+
+    /// Returns the call graph in the Graphviz DOT format.
+    pub fn to_dot(&self) -> String {
+        let mut dot = format!("digraph \"{}\" {{\n", self.program_id);
+        // Write the closures and functions.
+        for node in &self.nodes {
+            dot.push_str(&format!("    \"{node}\";\n"));
+        }
+        // Write the calls, labelled with their estimated cost.
+        for edge in &self.edges {
+            match edge.estimated_cost {
+                Some(cost) => {
+                    dot.push_str(&format!("    \"{}\" -> \"{}\" [label=\"{cost}\"];\n", edge.caller, edge.callee))
+                }
+                None => dot.push_str(&format!("    \"{}\" -> \"{}\";\n", edge.caller, edge.callee)),
+            }
+        }
+        dot.push('}');
+        dot
+    }
+}
+
+impl<N: Network> Program<N> {
+    /// Returns the call graph of the program.
+    pub fn call_graph(&self) -> CallGraph<N> {
+        // Collect the closures and functions.
+        let nodes = self.closures().keys().chain(self.functions().keys()).copied().collect();
+
+        // Collect the calls made by the closures and functions.
+        let closures = self.closures().iter().map(|(name, closure)| (name, closure.instructions()));
+        let functions = self.functions().iter().map(|(name, function)| (name, function.instructions()));
+
+        let mut edges = Vec::new();
+        for (caller, instructions) in closures.chain(functions) {
+            for instruction in instructions {
+                let Instruction::Call(call) = instruction else { continue };
+                let (callee, estimated_cost) = match call.operator() {
+                    CallOperator::Resource(name) => (
+                        CallTarget::Closure(*name),
+                        self.closures().get(name).map(|closure| closure.instructions().len() as u64),
+                    ),
+                    CallOperator::Locator(locator) => (CallTarget::External(*locator), None),
+                };
+                edges.push(CallEdge { caller: *caller, callee, estimated_cost });
+            }
+        }
+
+        CallGraph { program_id: *self.id(), nodes, edges }
+    }
+}
+
+impl<N: Network> Serialize for CallEdge<N> {
+    /// Serializes the call edge into JSON.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut edge = serializer.serialize_struct("CallEdge", 4)?;
+        edge.serialize_field("caller", &self.caller)?;
+        match &self.callee {
+            CallTarget::Closure(name) => {
+                edge.serialize_field("type", "closure")?;
+                edge.serialize_field("callee", name)?;
+            }
+            CallTarget::External(locator) => {
+                edge.serialize_field("type", "external")?;
+                edge.serialize_field("callee", locator)?;
+            }
+        }
+        edge.serialize_field("estimated_cost", &self.estimated_cost)?;
+        edge.end()
+    }
+}
+
+impl<N: Network> Serialize for CallGraph<N> {
+    /// Serializes the call graph into JSON.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut graph = serializer.serialize_struct("CallGraph", 3)?;
+        graph.serialize_field("program_id", &self.program_id)?;
+        graph.serialize_field("nodes", &self.nodes)?;
+        graph.serialize_field("edges", &self.edges)?;
+        graph.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const PROGRAM: &str = r"
+import token.aleo;
+
+program call_graph.aleo;
+
+closure double:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function transfer:
+    input r0 as token.aleo/token.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    call double r2 into r3;
+    call token.aleo/transfer r0 r1 r3 into r4 r5;
+    output r4 as token.aleo/token.record;
+    output r5 as token.aleo/token.record;";
+
+    #[test]
+    fn test_call_graph() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(PROGRAM)?;
+        let graph = program.call_graph();
+
+        assert_eq!(graph.program_id(), program.id());
+        assert_eq!(graph.nodes(), &[Identifier::from_str("double")?, Identifier::from_str("transfer")?]);
+        assert_eq!(graph.edges().len(), 2);
+
+        let edge = &graph.edges()[0];
+        assert_eq!(edge.caller(), &Identifier::from_str("transfer")?);
+        assert!(matches!(edge.callee(), CallTarget::Closure(name) if name == &Identifier::from_str("double")?));
+        assert_eq!(edge.estimated_cost(), Some(1));
+
+        let edge = &graph.edges()[1];
+        assert_eq!(edge.callee().to_string(), "token.aleo/transfer");
+        assert_eq!(edge.estimated_cost(), None);
+
+        assert_eq!(graph.external_programs(), vec![ProgramID::from_str("token.aleo")?]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_call_graph_dot_and_json() -> Result<()> {
+        let graph = Program::<CurrentNetwork>::from_str(PROGRAM)?.call_graph();
+
+        let expected_dot = r#"digraph "call_graph.aleo" {
+    "double";
+    "transfer";
+    "transfer" -> "double" [label="1"];
+    "transfer" -> "token.aleo/transfer";
+}"#;
+        assert_eq!(graph.to_dot(), expected_dot);
+
+        let json = serde_json::to_value(&graph)?;
+        assert_eq!(json["program_id"], "call_graph.aleo");
+        assert_eq!(json["edges"][0]["type"], "closure");
+        assert_eq!(json["edges"][0]["estimated_cost"], 1);
+        assert_eq!(json["edges"][1]["callee"], "token.aleo/transfer");
+        assert!(json["edges"][1]["estimated_cost"].is_null());
+        Ok(())
+    }
+}
@@ -21,6 +21,9 @@ pub type Function<N> = crate::FunctionCore<N, Instruction<N>, Command<N>>;
 pub type Finalize<N> = crate::FinalizeCore<N, Command<N>>;
 pub type Closure<N> = crate::ClosureCore<N, Instruction<N>>;
 
+mod call_graph;
+pub use call_graph::*;
+
 mod closure;
 pub use closure::*;
 
@@ -45,6 +48,7 @@ pub use traits::*;
 mod bytes;
 mod parse;
 mod serialize;
+mod upgrade;
 
 use console::{
     network::prelude::{
@@ -143,6 +147,12 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
     }
 
     /// Initializes the credits program.
+    ///
+    /// Staking is public: `bond_public` moves microcredits from `account` into the `bonded` mapping,
+    /// `unbond_public` moves them into the `unbonding` mapping with an unlock height 360 blocks ahead,
+    /// and `claim_unbond_public` enforces in finalize that the unlock height has been reached.
+    /// The proving and verifying keys of each function are pinned in `snarkvm-parameters`,
+    /// so adding a function (or a record) to this program requires regenerating those keys.
     #[inline]
     pub fn credits() -> Result<Self> {
         Self::from_str(include_str!("./resources/credits.aleo"))
@@ -213,6 +223,18 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         self.functions.contains_key(name)
     }
 
+    /// Returns the network edition required by the instructions and finalize commands of the program.
+    pub fn required_edition(&self) -> u16 {
+        let closure_editions = self.closures.values().flat_map(|closure| closure.instructions()).map(|i| i.edition());
+        let function_editions = self.functions.values().flat_map(|function| {
+            let instructions = function.instructions().iter().map(|i| i.edition());
+            let commands =
+                function.finalize_logic().into_iter().flat_map(|finalize| finalize.commands()).map(|c| c.edition());
+            instructions.chain(commands)
+        });
+        closure_editions.chain(function_editions).max().unwrap_or(0)
+    }
+
     /// Returns the mapping with the given name.
     pub fn get_mapping(&self, name: &Identifier<N>) -> Result<Mapping<N>> {
         // Attempt to retrieve the mapping.
@@ -259,6 +281,10 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         ensure!(!closure.instructions().is_empty(), "Cannot evaluate a closure without instructions");
         // Ensure the number of outputs is within the allowed range.
         ensure!(closure.outputs().len() <= N::MAX_OUTPUTS, "Closure exceeds maximum number of outputs");
+        // Ensure the instructions are supported by the network edition.
+        for instruction in closure.instructions() {
+            Self::check_edition(instruction, instruction.edition())?;
+        }
         // Return the closure.
         Ok(closure)
     }
@@ -280,6 +306,13 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         ensure!(function.instructions().len() <= N::MAX_INSTRUCTIONS, "Function exceeds maximum instructions");
         // Ensure the number of outputs is within the allowed range.
         ensure!(function.outputs().len() <= N::MAX_OUTPUTS, "Function exceeds maximum number of outputs");
+        // Ensure the instructions and finalize commands are supported by the network edition.
+        for instruction in function.instructions() {
+            Self::check_edition(instruction, instruction.edition())?;
+        }
+        for command in function.finalize_logic().into_iter().flat_map(|finalize| finalize.commands()) {
+            Self::check_edition(command, command.edition())?;
+        }
         // Return the function.
         Ok(function)
     }
@@ -549,6 +582,16 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         }
         Ok(())
     }
+
+    /// Ensures the given instruction or command, introduced in the given edition, is supported by the network edition.
+    fn check_edition(operation: &impl Display, edition: u16) -> Result<()> {
+        ensure!(
+            edition <= N::EDITION,
+            "'{operation}' requires network edition {edition}, but the network is on edition {}",
+            N::EDITION
+        );
+        Ok(())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
@@ -812,4 +855,126 @@ function swap:
 
         Ok(())
     }
+
+    #[test]
+    fn test_program_required_edition() -> Result<()> {
+        // Ensure every opcode is available in the current network edition.
+        for opcode in Instruction::<CurrentNetwork>::OPCODES {
+            assert!(opcode.edition() <= CurrentNetwork::EDITION, "'{opcode}' is not available");
+        }
+
+        // Ensure the credits program is available in the current network edition.
+        let program = Program::<CurrentNetwork>::credits()?;
+        assert!(program.required_edition() <= CurrentNetwork::EDITION);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_opcode_editions() {
+        // The opcodes available since the initial network edition.
+        // Note: New opcodes must be registered in `Opcode::EDITIONS`, not added to this list.
+        const INITIAL_OPCODES: &[&str] = &[
+            "abs",
+            "abs.w",
+            "add",
+            "add.w",
+            "and",
+            "assert.eq",
+            "assert.neq",
+            "async",
+            "call",
+            "cast",
+            "cast.lossy",
+            "commit.bhp256",
+            "commit.bhp512",
+            "commit.bhp768",
+            "commit.bhp1024",
+            "commit.ped64",
+            "commit.ped128",
+            "div",
+            "div.w",
+            "double",
+            "gt",
+            "gte",
+            "hash.bhp256",
+            "hash.bhp512",
+            "hash.bhp768",
+            "hash.bhp1024",
+            "hash.keccak256",
+            "hash.keccak384",
+            "hash.keccak512",
+            "hash.ped64",
+            "hash.ped128",
+            "hash.psd2",
+            "hash.psd4",
+            "hash.psd8",
+            "hash.sha3_256",
+            "hash.sha3_384",
+            "hash.sha3_512",
+            "hash_many.psd2",
+            "hash_many.psd4",
+            "hash_many.psd8",
+            "inv",
+            "is.eq",
+            "is.neq",
+            "lt",
+            "lte",
+            "mod",
+            "mul",
+            "mul.w",
+            "nand",
+            "neg",
+            "nor",
+            "not",
+            "or",
+            "pow",
+            "pow.w",
+            "rem",
+            "rem.w",
+            "shl",
+            "shl.w",
+            "shr",
+            "shr.w",
+            "sign.verify",
+            "square",
+            "sqrt",
+            "sub",
+            "sub.w",
+            "ternary",
+            "xor",
+        ];
+
+        // Ensure every opcode is either an initial opcode, or registered with a later edition.
+        for opcode in Instruction::<CurrentNetwork>::OPCODES {
+            let is_initial = INITIAL_OPCODES.contains(&**opcode);
+            assert_ne!(is_initial, opcode.edition() > 0, "'{opcode}' is missing from `Opcode::EDITIONS`");
+        }
+    }
+
+    #[test]
+    fn test_operand_editions() -> Result<()> {
+        // Ensure a program is gated on the edition of its finalize operands.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program beacon.aleo;
+
+mapping outputs:
+    key as u8.public;
+    value as field.public;
+
+function store:
+    input r0 as u8.public;
+    async store r0 into r1;
+    output r1 as beacon.aleo/store.future;
+
+finalize store:
+    input r0 as u8.public;
+    set beacon.output into outputs[r0];",
+        )?;
+        assert_eq!(program.required_edition(), Operand::<CurrentNetwork>::BeaconOutput.edition());
+        assert!(program.required_edition() > 0);
+
+        Ok(())
+    }
 }
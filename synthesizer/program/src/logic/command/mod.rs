@@ -53,6 +53,7 @@ use crate::{
     FinalizeOperation,
     FinalizeRegistersState,
     Instruction,
+    Operand,
 };
 use console::{
     network::prelude::*,
@@ -142,6 +143,29 @@ impl<N: Network> CommandTrait<N> for Command<N> {
     fn is_write(&self) -> bool {
         matches!(self, Command::Set(_) | Command::Remove(_))
     }
+
+    /// Returns the network edition in which the command and its operands were introduced.
+    #[inline]
+    fn edition(&self) -> u16 {
+        let (opcode, operands) = match self {
+            Command::Instruction(instruction) => return instruction.edition(),
+            Command::Await(_) => (Await::<N>::opcode(), vec![]),
+            Command::Contains(contains) => (Contains::<N>::opcode(), contains.operands()),
+            Command::Get(get) => (Get::<N>::opcode(), get.operands()),
+            Command::GetOrUse(get_or_use) => (GetOrUse::<N>::opcode(), get_or_use.operands()),
+            Command::RandChaCha(rand_chacha) => (RandChaCha::<N>::opcode(), rand_chacha.operands()),
+            Command::Remove(remove) => (Remove::<N>::opcode(), remove.operands()),
+            Command::Set(set) => (Set::<N>::opcode(), set.operands()),
+            Command::BranchEq(branch) => {
+                (BranchEq::<N>::opcode(), vec![branch.first().clone(), branch.second().clone()])
+            }
+            Command::BranchNeq(branch) => {
+                (BranchNeq::<N>::opcode(), vec![branch.first().clone(), branch.second().clone()])
+            }
+            Command::Position(_) => (Position::<N>::opcode(), vec![]),
+        };
+        operands.iter().map(Operand::edition).fold(opcode.edition(), u16::max)
+    }
 }
 
 impl<N: Network> Command<N> {
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use console::network::prelude::*;
+use console::{network::prelude::*, types::Field};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct FinalizeGlobalState {
@@ -22,6 +22,8 @@ pub struct FinalizeGlobalState {
     block_height: u32,
     /// The block-specific random seed.
     random_seed: [u8; 32],
+    /// The latest output of the randomness beacon, as a field element in little-endian bytes.
+    beacon_output: [u8; 32],
 }
 
 impl FinalizeGlobalState {
@@ -71,13 +73,24 @@ impl FinalizeGlobalState {
         let mut random_seed = [0u8; 32];
         random_seed.copy_from_slice(&seed[..32]);
 
-        Ok(Self { block_round, block_height, random_seed })
+        Ok(Self { block_round, block_height, random_seed, beacon_output: [0u8; 32] })
     }
 
     /// Initializes a new global state.
     #[inline]
     pub const fn from(block_round: u64, block_height: u32, random_seed: [u8; 32]) -> Self {
-        Self { block_round, block_height, random_seed }
+        Self { block_round, block_height, random_seed, beacon_output: [0u8; 32] }
+    }
+
+    /// Returns the global state, with the given output of the randomness beacon.
+    #[inline]
+    pub fn with_beacon_output<N: Network>(mut self, beacon_output: Field<N>) -> Result<Self> {
+        // Convert the beacon output into bytes.
+        let bytes = beacon_output.to_bytes_le()?;
+        // Ensure the beacon output is 32-bytes.
+        ensure!(bytes.len() == 32, "Invalid beacon output length for finalize global state.");
+        self.beacon_output.copy_from_slice(&bytes);
+        Ok(self)
     }
 
     /// Returns the block round.
@@ -97,4 +110,10 @@ impl FinalizeGlobalState {
     pub const fn random_seed(&self) -> &[u8; 32] {
         &self.random_seed
     }
+
+    /// Returns the latest output of the randomness beacon, or zero if the beacon has no output yet.
+    #[inline]
+    pub fn beacon_output<N: Network>(&self) -> Result<Field<N>> {
+        Field::from_bytes_le(&self.beacon_output)
+    }
 }
@@ -105,6 +105,10 @@ pub enum Instruction<N: Network> {
     DivWrapped(DivWrapped<N>),
     /// Doubles `first`, storing the outcome in `destination`.
     Double(Double<N>),
+    /// Encrypts `first` to the address `second` under the randomizer `third`, storing the outcome in `destination`.
+    Encrypt(Encrypt<N>),
+    /// Computes whether a feed update is signed by `signer`, storing the outcome in `destination`.
+    FeedVerify(FeedVerify<N>),
     /// Computes whether `first` is greater than `second` as a boolean, storing the outcome in `destination`.
     GreaterThan(GreaterThan<N>),
     /// Computes whether `first` is greater than or equal to `second` as a boolean, storing the outcome in `destination`.
@@ -300,6 +304,9 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            // New instructions are appended, to preserve the serialization index of existing instructions.
+            FeedVerify,
+            Encrypt,
         }}
     };
     // A variant **without** curly braces:
@@ -385,6 +392,12 @@ impl<N: Network> InstructionTrait<N> for Instruction<N> {
         // Check if the given name matches any opcode (in its entirety; including past the first '.' if it exists).
         Instruction::<N>::OPCODES.iter().any(|opcode| **opcode == name)
     }
+
+    /// Returns the network edition in which the instruction and its operands were introduced.
+    #[inline]
+    fn edition(&self) -> u16 {
+        self.operands().iter().map(Operand::edition).fold(self.opcode().edition(), u16::max)
+    }
 }
 
 impl<N: Network> Instruction<N> {
@@ -469,7 +482,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            68,
+            70,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
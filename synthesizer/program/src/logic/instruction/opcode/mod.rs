@@ -29,6 +29,10 @@ pub enum Opcode {
     Command(&'static str),
     /// The opcode is for a commit operation (i.e. `commit.psd4`).
     Commit(&'static str),
+    /// The opcode is for an encryption operation (i.e. `encrypt`).
+    Encrypt,
+    /// The opcode is for signed feed verification (i.e. `feed.verify`).
+    Feed,
     /// The opcode is for a hash operation (i.e. `hash.psd4`).
     Hash(&'static str),
     /// The opcode is for an 'is' operation (i.e. `is.eq`).
@@ -39,6 +43,17 @@ pub enum Opcode {
     Sign,
 }
 
+impl Opcode {
+    /// The opcodes introduced after the initial network edition, with the edition that introduced them.
+    /// Any opcode that is not listed here is available since edition 0.
+    const EDITIONS: &'static [(&'static str, u16)] = &[("encrypt", 1), ("feed.verify", 1)];
+
+    /// Returns the network edition in which the opcode was introduced.
+    pub fn edition(&self) -> u16 {
+        Self::EDITIONS.iter().find(|(opcode, _)| opcode == self.deref()).map_or(0, |(_, edition)| *edition)
+    }
+}
+
 impl Deref for Opcode {
     type Target = &'static str;
 
@@ -51,6 +66,8 @@ impl Deref for Opcode {
             Opcode::Cast(opcode) => opcode,
             Opcode::Command(opcode) => opcode,
             Opcode::Commit(opcode) => opcode,
+            Opcode::Encrypt => &"encrypt",
+            Opcode::Feed => &"feed.verify",
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
@@ -76,6 +93,8 @@ impl Display for Opcode {
             Self::Cast(opcode) => write!(f, "{opcode}"),
             Self::Command(opcode) => write!(f, "{opcode}"),
             Self::Commit(opcode) => write!(f, "{opcode}"),
+            Self::Encrypt => write!(f, "{}", self.deref()),
+            Self::Feed => write!(f, "{}", self.deref()),
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
@@ -40,6 +40,24 @@ pub enum Operand<N: Network> {
     /// The operand is the block height.
     /// Note: This variant is only accessible in the `finalize` scope.
     BlockHeight,
+    /// The operand is the latest output of the randomness beacon.
+    /// Note: This variant is only accessible in the `finalize` scope.
+    BeaconOutput,
+}
+
+impl<N: Network> Operand<N> {
+    /// Returns the network edition in which the operand was introduced.
+    pub const fn edition(&self) -> u16 {
+        match self {
+            Self::Literal(..)
+            | Self::Register(..)
+            | Self::ProgramID(..)
+            | Self::Signer
+            | Self::Caller
+            | Self::BlockHeight => 0,
+            Self::BeaconOutput => 1,
+        }
+    }
 }
 
 impl<N: Network> From<Literal<N>> for Operand<N> {
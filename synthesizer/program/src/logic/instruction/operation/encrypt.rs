@@ -0,0 +1,392 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    account::ViewKey,
+    network::prelude::*,
+    program::{ArrayType, Ciphertext, Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::Group,
+};
+
+/// The number of operands of the `encrypt` instruction.
+const NUM_OPERANDS: usize = 3;
+
+/// Returns 'true' if the destination type is valid.
+fn is_valid_destination_type<N: Network>(destination_type: &ArrayType<N>) -> bool {
+    destination_type.next_element_type() == &PlaintextType::Literal(LiteralType::Field)
+}
+
+/// Encrypts a plaintext to the recipient address under the randomizer, storing the outcome in `destination`.
+///
+/// The outcome is an array of fields, which holds the x-coordinate of the nonce `randomizer * G`, followed by
+/// the ciphertext. The ciphertext uses the same Poseidon-based stream cipher as records, so only the recipient
+/// can decrypt it, even if the outcome is a public output. The randomizer must be sampled anew for each call.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Encrypt<N: Network> {
+    /// The operands, as `(plaintext, recipient, randomizer)`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+    /// The destination register type.
+    destination_type: ArrayType<N>,
+}
+
+impl<N: Network> Encrypt<N> {
+    /// Initializes a new `encrypt` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>, destination_type: ArrayType<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == NUM_OPERANDS, "Instruction '{}' must have three operands", Self::opcode());
+        // Sanity check the destination type.
+        ensure!(is_valid_destination_type(&destination_type), "Invalid destination type for 'encrypt' instruction");
+        // Return the instruction.
+        Ok(Self { operands, destination, destination_type })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Encrypt
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly three operands.
+        debug_assert!(self.operands.len() == NUM_OPERANDS, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+
+    /// Returns the destination register type.
+    #[inline]
+    pub const fn destination_type(&self) -> &ArrayType<N> {
+        &self.destination_type
+    }
+
+    /// Decrypts the outcome of an `encrypt` instruction with the view key of the recipient.
+    pub fn decrypt(outcome: &Plaintext<N>, view_key: &ViewKey<N>) -> Result<Plaintext<N>> {
+        // Retrieve the fields of the outcome.
+        let fields = match outcome {
+            Plaintext::Array(elements, _) => elements
+                .iter()
+                .map(|element| match element {
+                    Plaintext::Literal(Literal::Field(field), _) => Ok(*field),
+                    _ => bail!("Expected the outcome of '{}' to be an array of fields", Self::opcode()),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => bail!("Expected the outcome of '{}' to be an array of fields", Self::opcode()),
+        };
+        // Split the outcome into the nonce and the ciphertext.
+        let (nonce, ciphertext) = match fields.split_first() {
+            Some((nonce, ciphertext)) => (Group::from_x_coordinate(*nonce)?, Ciphertext::from_fields(ciphertext)?),
+            None => bail!("Expected the outcome of '{}' to be non-empty", Self::opcode()),
+        };
+        // Decrypt the ciphertext.
+        ciphertext.decrypt(*view_key, nonce)
+    }
+}
+
+impl<N: Network> Encrypt<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != NUM_OPERANDS {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let plaintext = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(plaintext) => plaintext,
+            _ => bail!("Expected the first operand to be a plaintext."),
+        };
+        let recipient = match registers.load_literal(stack, &self.operands[1])? {
+            Literal::Address(address) => address,
+            _ => bail!("Expected the second operand to be an address."),
+        };
+        let randomizer = match registers.load_literal(stack, &self.operands[2])? {
+            Literal::Scalar(randomizer) => randomizer,
+            _ => bail!("Expected the third operand to be a scalar."),
+        };
+
+        // Encrypt the plaintext, and prepend the x-coordinate of the nonce.
+        let nonce = N::g_scalar_multiply(&randomizer).to_x_coordinate();
+        let ciphertext = plaintext.encrypt(&recipient, randomizer)?;
+        let fields = std::iter::once(nonce).chain(ciphertext.iter().copied()).collect::<Vec<_>>();
+        // Ensure the outcome matches the destination type.
+        ensure!(
+            fields.len() == **self.destination_type.length() as usize,
+            "Instruction '{}' produces {} fields, which does not match '{}'",
+            Self::opcode(),
+            fields.len(),
+            self.destination_type
+        );
+        let output = Plaintext::Array(
+            fields.into_iter().map(|field| Plaintext::from(Literal::Field(field))).collect(),
+            Default::default(),
+        );
+
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != NUM_OPERANDS {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let plaintext = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(plaintext) => plaintext,
+            _ => bail!("Expected the first operand to be a plaintext."),
+        };
+        let recipient = match registers.load_literal_circuit(stack, &self.operands[1])? {
+            circuit::Literal::Address(address) => address,
+            _ => bail!("Expected the second operand to be an address."),
+        };
+        let randomizer = match registers.load_literal_circuit(stack, &self.operands[2])? {
+            circuit::Literal::Scalar(randomizer) => randomizer,
+            _ => bail!("Expected the third operand to be a scalar."),
+        };
+
+        // Encrypt the plaintext, and prepend the x-coordinate of the nonce.
+        let nonce = A::g_scalar_multiply(&randomizer).to_x_coordinate();
+        let ciphertext = plaintext.encrypt(&recipient, randomizer);
+        let fields = std::iter::once(nonce).chain(ciphertext.iter().cloned()).collect::<Vec<_>>();
+        // Ensure the outcome matches the destination type.
+        ensure!(
+            fields.len() == **self.destination_type.length() as usize,
+            "Instruction '{}' produces {} fields, which does not match '{}'",
+            Self::opcode(),
+            fields.len(),
+            self.destination_type
+        );
+        let output = circuit::Plaintext::Array(
+            fields.into_iter().map(|field| circuit::Plaintext::from(circuit::Literal::Field(field))).collect(),
+            Default::default(),
+        );
+
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        _stack: &(impl StackMatches<N> + StackProgram<N>),
+        _registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        bail!("Forbidden operation: Finalize cannot invoke 'encrypt'.")
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != NUM_OPERANDS {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the destination type is valid.
+        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'encrypt' instruction");
+
+        // Ensure the operands are `(plaintext, address, scalar)`.
+        if !matches!(input_types[0], RegisterType::Plaintext(..)) {
+            bail!("Instruction '{}' expects input 0 to be a plaintext, found '{}'", Self::opcode(), input_types[0])
+        }
+        for (index, literal_type) in [(1, LiteralType::Address), (2, LiteralType::Scalar)] {
+            if input_types[index] != RegisterType::Plaintext(PlaintextType::Literal(literal_type)) {
+                bail!(
+                    "Instruction '{}' expects input {index} to be a '{literal_type}', found '{}'",
+                    Self::opcode(),
+                    input_types[index]
+                )
+            }
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Array(self.destination_type.clone()))])
+    }
+}
+
+impl<N: Network> Parser for Encrypt<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = count(
+            map(pair(Sanitizer::parse_whitespaces, Operand::parse), |(_, operand)| operand),
+            NUM_OPERANDS,
+        )(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "as" from the string.
+        let (string, _) = tag("as")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register type from the string.
+        let (string, destination_type) = ArrayType::parse(string)?;
+        // Ensure the destination type is allowed.
+        match is_valid_destination_type(&destination_type) {
+            true => Ok((string, Self { operands, destination, destination_type })),
+            false => map_res(fail, |_: ParserResult<Self>| {
+                Err(error(format!("Failed to parse 'encrypt': '{destination_type}' is invalid")))
+            })(string),
+        }
+    }
+}
+
+impl<N: Network> FromStr for Encrypt<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Encrypt<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Encrypt<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != NUM_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {} as {}", self.destination, self.destination_type)
+    }
+}
+
+impl<N: Network> FromBytes for Encrypt<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(NUM_OPERANDS);
+        // Read the operands.
+        for _ in 0..NUM_OPERANDS {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Read the destination register type.
+        let destination_type = ArrayType::read_le(&mut reader)?;
+
+        // Return the operation.
+        Self::new(operands, destination, destination_type).map_err(error)
+    }
+}
+
+impl<N: Network> ToBytes for Encrypt<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != NUM_OPERANDS {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)?;
+        // Write the destination register type.
+        self.destination_type.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, encrypt) = Encrypt::<CurrentNetwork>::parse("encrypt r0 r1 r2 into r3 as [field; 3u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(encrypt.operands.len(), NUM_OPERANDS, "The number of operands is incorrect");
+        for (index, operand) in encrypt.operands.iter().enumerate() {
+            assert_eq!(*operand, Operand::Register(Register::Locator(index as u64)), "Operand {index} is incorrect");
+        }
+        assert_eq!(encrypt.destination, Register::Locator(3), "The destination register is incorrect");
+        assert_eq!(encrypt.destination_type, ArrayType::from_str("[field; 3u32]").unwrap());
+        assert_eq!(encrypt.to_string(), "encrypt r0 r1 r2 into r3 as [field; 3u32]");
+    }
+
+    #[test]
+    fn test_parse_invalid_destination_type() {
+        assert!(Encrypt::<CurrentNetwork>::from_str("encrypt r0 r1 r2 into r3 as [u8; 3u32]").is_err());
+        assert!(Encrypt::<CurrentNetwork>::from_str("encrypt r0 r1 r2 into r3 as field").is_err());
+        assert!(Encrypt::<CurrentNetwork>::from_str("encrypt r0 r1 into r3 as [field; 3u32]").is_err());
+    }
+
+    #[test]
+    fn test_edition() {
+        // Ensure `encrypt` is gated on the network edition that introduced it.
+        assert_eq!(Encrypt::<CurrentNetwork>::opcode().edition(), 1);
+    }
+}
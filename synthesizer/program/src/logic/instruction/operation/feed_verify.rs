@@ -0,0 +1,318 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use console::{
+    network::prelude::*,
+    program::{FeedUpdate, Literal, LiteralType, PlaintextType, Register, RegisterType},
+    types::Boolean,
+};
+
+/// The number of operands of the `feed.verify` instruction.
+const NUM_OPERANDS: usize = 5;
+
+/// Computes whether the feed update `(feed_id, value, timestamp)` is signed by `signer` with `signature`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct FeedVerify<N: Network> {
+    /// The operands, as `(signature, signer, feed_id, value, timestamp)`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> FeedVerify<N> {
+    /// Initializes a new `feed.verify` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == NUM_OPERANDS, "Instruction '{}' must have five operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Feed
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that there are exactly five operands.
+        debug_assert!(self.operands.len() == NUM_OPERANDS, "Instruction '{}' must have five operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> FeedVerify<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != NUM_OPERANDS {
+            bail!("Instruction '{}' expects 5 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = match registers.load_literal(stack, &self.operands[0])? {
+            Literal::Signature(signature) => signature,
+            _ => bail!("Expected the first operand to be a signature."),
+        };
+        let signer = match registers.load_literal(stack, &self.operands[1])? {
+            Literal::Address(address) => address,
+            _ => bail!("Expected the second operand to be an address."),
+        };
+        let feed_id = match registers.load_literal(stack, &self.operands[2])? {
+            Literal::Field(feed_id) => feed_id,
+            _ => bail!("Expected the third operand to be a field."),
+        };
+        let value = match registers.load_literal(stack, &self.operands[3])? {
+            Literal::U128(value) => value,
+            _ => bail!("Expected the fourth operand to be a u128."),
+        };
+        let timestamp = match registers.load_literal(stack, &self.operands[4])? {
+            Literal::U64(timestamp) => timestamp,
+            _ => bail!("Expected the fifth operand to be a u64."),
+        };
+
+        // Verify the feed update.
+        let feed_update = FeedUpdate::from((feed_id, value, timestamp, *signature));
+        let output = Literal::Boolean(Boolean::new(feed_update.verify(&signer)));
+
+        // Store the output.
+        registers.store_literal(stack, &self.destination, output)
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != NUM_OPERANDS {
+            bail!("Instruction '{}' expects 5 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let signature = match registers.load_literal_circuit(stack, &self.operands[0])? {
+            circuit::Literal::Signature(signature) => signature,
+            _ => bail!("Expected the first operand to be a signature."),
+        };
+        let signer = match registers.load_literal_circuit(stack, &self.operands[1])? {
+            circuit::Literal::Address(address) => address,
+            _ => bail!("Expected the second operand to be an address."),
+        };
+        let feed_id = match registers.load_literal_circuit(stack, &self.operands[2])? {
+            circuit::Literal::Field(feed_id) => feed_id,
+            _ => bail!("Expected the third operand to be a field."),
+        };
+        let value = match registers.load_literal_circuit(stack, &self.operands[3])? {
+            circuit::Literal::U128(value) => value,
+            _ => bail!("Expected the fourth operand to be a u128."),
+        };
+        let timestamp = match registers.load_literal_circuit(stack, &self.operands[4])? {
+            circuit::Literal::U64(timestamp) => timestamp,
+            _ => bail!("Expected the fifth operand to be a u64."),
+        };
+
+        // Verify the feed update.
+        let feed_update = circuit::FeedUpdate::from((feed_id, value, timestamp, signature));
+        let output = circuit::Literal::Boolean(feed_update.verify(&signer));
+
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, output)
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != NUM_OPERANDS {
+            bail!("Instruction '{}' expects 5 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+
+        // Ensure the operands are `(signature, address, field, u128, u64)`.
+        let expected =
+            [LiteralType::Signature, LiteralType::Address, LiteralType::Field, LiteralType::U128, LiteralType::U64];
+        for (index, (input_type, literal_type)) in input_types.iter().zip_eq(expected).enumerate() {
+            if *input_type != RegisterType::Plaintext(PlaintextType::Literal(literal_type)) {
+                bail!(
+                    "Instruction '{}' expects input {index} to be a '{literal_type}', found '{input_type}'",
+                    Self::opcode(),
+                )
+            }
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network> Parser for FeedVerify<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the operands from the string.
+        let (string, operands) = count(
+            map(pair(Sanitizer::parse_whitespaces, Operand::parse), |(_, operand)| operand),
+            NUM_OPERANDS,
+        )(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands, destination }))
+    }
+}
+
+impl<N: Network> FromStr for FeedVerify<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for FeedVerify<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for FeedVerify<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 5.
+        if self.operands.len() != NUM_OPERANDS {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for FeedVerify<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(NUM_OPERANDS);
+        // Read the operands.
+        for _ in 0..NUM_OPERANDS {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for FeedVerify<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 5.
+        if self.operands.len() != NUM_OPERANDS {
+            return Err(error(format!("The number of operands must be 5, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, is) = FeedVerify::<CurrentNetwork>::parse("feed.verify r0 r1 r2 r3 r4 into r5").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(is.operands.len(), NUM_OPERANDS, "The number of operands is incorrect");
+        for (index, operand) in is.operands.iter().enumerate() {
+            assert_eq!(*operand, Operand::Register(Register::Locator(index as u64)), "Operand {index} is incorrect");
+        }
+        assert_eq!(is.destination, Register::Locator(5), "The destination register is incorrect");
+        assert_eq!(is.to_string(), "feed.verify r0 r1 r2 r3 r4 into r5");
+    }
+
+    #[test]
+    fn test_parse_too_few_operands() {
+        assert!(FeedVerify::<CurrentNetwork>::from_str("feed.verify r0 r1 r2 r3 into r4").is_err());
+    }
+
+    #[test]
+    fn test_edition() {
+        // Ensure `feed.verify` is gated on the network edition that introduced it.
+        assert_eq!(FeedVerify::<CurrentNetwork>::opcode().edition(), 1);
+        let instruction = crate::Instruction::<CurrentNetwork>::from_str("feed.verify r0 r1 r2 r3 r4 into r5").unwrap();
+        assert_eq!(crate::InstructionTrait::edition(&instruction), 1);
+    }
+}
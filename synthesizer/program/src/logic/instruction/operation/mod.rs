@@ -27,6 +27,12 @@ pub use cast::*;
 mod commit;
 pub use commit::*;
 
+mod encrypt;
+pub use encrypt::*;
+
+mod feed_verify;
+pub use feed_verify::*;
+
 mod hash;
 pub use hash::*;
 
@@ -23,4 +23,6 @@ pub trait InstructionTrait<N: Network>: Clone + Parser + FromBytes + ToBytes {
     fn destinations(&self) -> Vec<Register<N>>;
     /// Returns `true` if the given name is a reserved opcode.
     fn is_reserved_opcode(name: &str) -> bool;
+    /// Returns the network edition in which the instruction was introduced.
+    fn edition(&self) -> u16;
 }
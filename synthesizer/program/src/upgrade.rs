@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Ensures the program can upgrade the given deployed program.
+    ///
+    /// An upgrade keeps the structs, records, and mappings of the deployed program unchanged, so its state
+    /// remains readable, and keeps the input, output, and finalize types of each deployed function, so its
+    /// callers remain valid. New definitions may be added, and the logic of existing functions may change.
+    pub fn check_is_upgrade_of(&self, deployed: &Self) -> Result<()> {
+        let program_id = self.id();
+        // Ensure the program IDs match.
+        ensure!(program_id == deployed.id(), "Cannot upgrade '{}' with '{program_id}'", deployed.id());
+        // Ensure the program changed.
+        ensure!(self != deployed, "Cannot upgrade '{program_id}', as it is unchanged from the deployed program");
+
+        // Ensure the state definitions of the deployed program are carried forward.
+        Self::check_definitions_are_kept(program_id, "struct", deployed.structs(), self.structs())?;
+        Self::check_definitions_are_kept(program_id, "record", deployed.records(), self.records())?;
+        Self::check_definitions_are_kept(program_id, "mapping", deployed.mappings(), self.mappings())?;
+
+        // Ensure the deployed functions keep their signatures.
+        for (name, deployed) in deployed.functions() {
+            let Some(function) = self.functions().get(name) else {
+                bail!("Cannot upgrade '{program_id}', as the function '{name}' is removed")
+            };
+            ensure!(
+                deployed.input_types() == function.input_types(),
+                "Cannot upgrade '{program_id}', as the inputs of function '{name}' are changed"
+            );
+            ensure!(
+                deployed.output_types() == function.output_types(),
+                "Cannot upgrade '{program_id}', as the outputs of function '{name}' are changed"
+            );
+            ensure!(
+                deployed.finalize_logic().map(|finalize| finalize.input_types())
+                    == function.finalize_logic().map(|finalize| finalize.input_types()),
+                "Cannot upgrade '{program_id}', as the finalize inputs of function '{name}' are changed"
+            );
+        }
+        Ok(())
+    }
+
+    /// Ensures each of the deployed definitions is kept unchanged in the upgraded definitions.
+    fn check_definitions_are_kept<T: PartialEq>(
+        program_id: &ProgramID<N>,
+        kind: &str,
+        deployed: &IndexMap<Identifier<N>, T>,
+        upgraded: &IndexMap<Identifier<N>, T>,
+    ) -> Result<()> {
+        for (name, definition) in deployed {
+            match upgraded.get(name) {
+                Some(upgraded) => {
+                    ensure!(upgraded == definition, "Cannot upgrade '{program_id}', as the {kind} '{name}' is changed")
+                }
+                None => bail!("Cannot upgrade '{program_id}', as the {kind} '{name}' is removed"),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Program;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const DEPLOYED: &str = r"
+program counter.aleo;
+
+record ticket:
+    owner as address.private;
+    amount as u64.private;
+
+mapping counts:
+    key as address.public;
+    value as u64.public;
+
+function transfer:
+    input r0 as ticket.record;
+    input r1 as address.private;
+    cast r1 r0.amount into r2 as ticket.record;
+    output r2 as ticket.record;
+
+function increment:
+    input r0 as u64.public;
+    async increment self.caller r0 into r1;
+    output r1 as counter.aleo/increment.future;
+
+finalize increment:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use counts[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into counts[r0];";
+
+    /// Returns the deployed program, with the given replacement applied.
+    fn upgraded(from: &str, to: &str) -> Program<CurrentNetwork> {
+        assert!(DEPLOYED.contains(from));
+        Program::from_str(&DEPLOYED.replace(from, to)).unwrap()
+    }
+
+    #[test]
+    fn test_check_is_upgrade_of() {
+        let deployed = Program::<CurrentNetwork>::from_str(DEPLOYED).unwrap();
+
+        // Ensure an unchanged program is rejected.
+        assert!(deployed.check_is_upgrade_of(&deployed).is_err());
+
+        // Ensure a change to the finalize logic is accepted.
+        let upgrade = upgraded("add r2 r1 into r3;", "mul r2 r1 into r3;");
+        assert!(upgrade.check_is_upgrade_of(&deployed).is_ok());
+        // Ensure a new function is accepted.
+        let function = "function noop:\n    input r0 as u8.public;\n\nfunction increment:";
+        assert!(upgraded("function increment:", function).check_is_upgrade_of(&deployed).is_ok());
+
+        // Ensure a change to a record is rejected.
+        let upgrade = upgraded("amount as u64.private;", "amount as u128.private;");
+        let error = upgrade.check_is_upgrade_of(&deployed).unwrap_err();
+        assert!(error.to_string().contains("record 'ticket' is changed"), "{error}");
+        // Ensure a change to a mapping is rejected.
+        let upgrade = upgraded("value as u64.public;", "value as u128.public;");
+        let error = upgrade.check_is_upgrade_of(&deployed).unwrap_err();
+        assert!(error.to_string().contains("mapping 'counts' is changed"), "{error}");
+        // Ensure a change to the inputs of a function is rejected.
+        let upgrade = upgraded("input r1 as address.private;", "input r1 as address.public;");
+        let error = upgrade.check_is_upgrade_of(&deployed).unwrap_err();
+        assert!(error.to_string().contains("inputs of function 'transfer' are changed"), "{error}");
+
+        // Ensure a removed function is rejected.
+        let function = "function noop:\n    input r0 as u8.public;\n\nfunction increment:";
+        let error = deployed.check_is_upgrade_of(&upgraded("function increment:", function)).unwrap_err();
+        assert!(error.to_string().contains("function 'noop' is removed"), "{error}");
+    }
+}
@@ -31,6 +31,7 @@ impl<N: Network> Certificate<N> {
     }
 
     /// Returns the certificate from the proving and verifying key.
+    #[cfg(feature = "prover")]
     pub fn certify(
         function_name: &str,
         proving_key: &ProvingKey<N>,
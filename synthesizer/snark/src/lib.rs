@@ -20,6 +20,7 @@
 use console::network::{prelude::*, FiatShamir};
 use snarkvm_algorithms::{snark::varuna, traits::SNARK};
 
+#[cfg(feature = "prover")]
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
 
@@ -34,10 +35,14 @@ pub use certificate::Certificate;
 mod proof;
 pub use proof::Proof;
 
+#[cfg(feature = "prover")]
 mod proving_key;
+#[cfg(feature = "prover")]
 pub use proving_key::ProvingKey;
 
+#[cfg(feature = "prover")]
 mod universal_srs;
+#[cfg(feature = "prover")]
 pub use universal_srs::UniversalSRS;
 
 mod verifying_key;
@@ -15,6 +15,7 @@
 use super::*;
 
 mod bytes;
+mod packed;
 mod parse;
 mod serialize;
 
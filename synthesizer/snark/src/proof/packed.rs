@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Proof<N> {
+    /// Writes the given proofs to a buffer, in a packed layout, which stores the proofs
+    /// column by column, and shares identical commitments across the proofs.
+    pub fn write_packed_le<W: Write>(proofs: &[&Self], mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the packed proofs.
+        varuna::PackedProofs::new(proofs.iter().map(|proof| proof.proof.clone()).collect()).write_le(&mut writer)
+    }
+
+    /// Reads proofs from a buffer, in a packed layout.
+    pub fn read_packed_le<R: Read>(mut reader: R) -> IoResult<Vec<Self>> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid packed proofs version"));
+        }
+        // Read the packed proofs.
+        let packed = varuna::PackedProofs::<N::PairingCurve>::read_le(&mut reader)?;
+        // Return the proofs.
+        Ok(packed.into_proofs().into_iter().map(Self::new).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_packed_bytes() -> Result<()> {
+        // Sample the proof.
+        let expected = crate::test_helpers::sample_proof();
+
+        for num_proofs in 0..3 {
+            let proofs = vec![&expected; num_proofs];
+
+            // Check the packed byte representation.
+            let mut expected_bytes = Vec::new();
+            Proof::write_packed_le(&proofs, &mut expected_bytes)?;
+            let candidate = Proof::<CurrentNetwork>::read_packed_le(&expected_bytes[..])?;
+            assert_eq!(proofs, candidate.iter().collect::<Vec<_>>());
+            assert!(Proof::<CurrentNetwork>::read_packed_le(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+        }
+        Ok(())
+    }
+}
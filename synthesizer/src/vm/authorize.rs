@@ -24,6 +24,36 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         function_name: impl TryInto<Identifier<N>>,
         inputs: impl IntoIterator<IntoIter = impl ExactSizeIterator<Item = impl TryInto<Value<N>>>>,
         rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize_with_optional_max_height(private_key, program_id, function_name, inputs, None, rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs, which cannot be included
+    /// in a block after the given maximum height.
+    ///
+    /// The maximum height is signed in the request, and enforced when the transaction is verified.
+    #[inline]
+    pub fn authorize_with_max_height<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl IntoIterator<IntoIter = impl ExactSizeIterator<Item = impl TryInto<Value<N>>>>,
+        max_height: u32,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        self.authorize_with_optional_max_height(private_key, program_id, function_name, inputs, Some(max_height), rng)
+    }
+
+    /// Authorizes a call to the program function for the given inputs, with an optional maximum height.
+    fn authorize_with_optional_max_height<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl IntoIterator<IntoIter = impl ExactSizeIterator<Item = impl TryInto<Value<N>>>>,
+        max_height: Option<u32>,
+        rng: &mut R,
     ) -> Result<Authorization<N>> {
         let timer = timer!("VM::authorize");
 
@@ -44,7 +74,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         lap!(timer, "Prepare inputs");
 
         // Authorize the call.
-        let result = self.authorize_raw(private_key, program_id, function_name, inputs, rng);
+        let result = self.authorize_raw(private_key, program_id, function_name, inputs, max_height, rng);
         finish!(timer, "Authorize the call");
         result
     }
@@ -115,6 +145,43 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         finish!(timer, "Compute the authorization");
         result
     }
+
+    /// Authorizes a sponsor to pay the fee of the given execution, which was proven by another caller.
+    ///
+    /// If a `credits` record is provided, then the sponsor pays a private fee with it; otherwise,
+    /// the sponsor pays a public fee. The base fee is the minimum cost of the execution, and the
+    /// `priority_fee_in_microcredits` is an additional fee **on top** of it.
+    pub fn authorize_sponsored_fee<R: Rng + CryptoRng>(
+        &self,
+        sponsor_private_key: &PrivateKey<N>,
+        execution: &Execution<N>,
+        credits: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        rng: &mut R,
+    ) -> Result<Authorization<N>> {
+        // Compute the minimum execution cost.
+        let (minimum_execution_cost, (_, _)) = execution_cost(self, execution)?;
+        // Compute the execution ID.
+        let execution_id = execution.to_execution_id()?;
+        // Authorize the fee.
+        match credits {
+            Some(credits) => self.authorize_fee_private(
+                sponsor_private_key,
+                credits,
+                minimum_execution_cost,
+                priority_fee_in_microcredits,
+                execution_id,
+                rng,
+            ),
+            None => self.authorize_fee_public(
+                sponsor_private_key,
+                minimum_execution_cost,
+                priority_fee_in_microcredits,
+                execution_id,
+                rng,
+            ),
+        }
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -126,16 +193,18 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         program_id: ProgramID<N>,
         function_name: Identifier<N>,
         inputs: Vec<Value<N>>,
+        max_height: Option<u32>,
         rng: &mut R,
     ) -> Result<Authorization<N>> {
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Compute the authorization.
-                let authorization = $process.authorize::<$aleo, _>(
+                let authorization = $process.authorize_with_max_height::<$aleo, _>(
                     cast_ref!(&private_key as PrivateKey<$network>),
                     cast_ref!(program_id as ProgramID<$network>),
                     cast_ref!(function_name as Identifier<$network>),
                     cast_ref!(inputs as Vec<Value<$network>>).iter(),
+                    max_height,
                     rng,
                 )?;
                 // Prepare the authorization.
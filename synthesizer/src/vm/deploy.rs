@@ -32,6 +32,47 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ) -> Result<Transaction<N>> {
         // Compute the deployment.
         let deployment = self.deploy_raw(program, rng)?;
+        // Construct the deploy transaction.
+        self.deployment_transaction(private_key, deployment, fee_record, priority_fee_in_microcredits, query, rng)
+    }
+
+    /// Returns a new deploy transaction, which upgrades the deployed program to the given program,
+    /// as the next program edition. The upgrade must be signed by the owner of the deployed program.
+    ///
+    /// If a `fee_record` is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the deployment fee.
+    pub fn upgrade<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program: &Program<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Retrieve the latest edition of the deployed program.
+        let Some(edition) = self.transaction_store().deployment_store().get_edition(program.id())? else {
+            bail!("Cannot upgrade program '{}', as it is not deployed", program.id())
+        };
+        // Compute the upgrade to the next edition.
+        let program_edition = edition.checked_add(1).ok_or_else(|| anyhow!("The program edition overflowed"))?;
+        let deployment = self.deploy_upgrade_raw(program, program_edition, rng)?;
+        // Construct the deploy transaction.
+        self.deployment_transaction(private_key, deployment, fee_record, priority_fee_in_microcredits, query, rng)
+    }
+
+    /// Returns a new deploy transaction for the given deployment.
+    fn deployment_transaction<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        deployment: Deployment<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
         // Ensure the transaction is not empty.
         ensure!(!deployment.program().functions().is_empty(), "Attempted to create an empty transaction deployment");
         // Compute the deployment ID.
@@ -88,4 +129,30 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         finish!(timer, "Compute the deployment");
         result
     }
+
+    /// Returns a deployment that upgrades the deployed program to the given program, as the given program edition.
+    #[inline]
+    pub(super) fn deploy_upgrade_raw<R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        program_edition: u16,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the program.
+                let program = cast_ref!(&program as Program<$network>);
+                // Compute the deployment.
+                let deployment = $process.deploy_upgrade::<$aleo, _>(program, program_edition, rng)?;
+                // Prepare the deployment.
+                Ok(cast_ref!(deployment as Deployment<N>).clone())
+            }};
+        }
+
+        // Compute the deployment.
+        let timer = timer!("VM::deploy_upgrade_raw");
+        let result = process!(self, logic);
+        finish!(timer, "Compute the deployment");
+        result
+    }
 }
@@ -94,6 +94,97 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Transaction::from_execution(execution, fee)
     }
 
+    /// Returns a new execute transaction for the given execution, with a fee paid by a sponsor.
+    ///
+    /// The `fee_authorization` is signed by the sponsor, who may differ from the caller of the execution,
+    /// and must pay at least the minimum cost of the execution (see `VM::authorize_sponsored_fee`).
+    pub fn execute_sponsored<R: Rng + CryptoRng>(
+        &self,
+        execution: Execution<N>,
+        fee_authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Ensure the authorization is for a fee.
+        ensure!(
+            fee_authorization.is_fee_private() || fee_authorization.is_fee_public(),
+            "Expected a fee authorization for the sponsored execution"
+        );
+        // Ensure the fee is bound to the execution, before proving the fee.
+        let execution_id = execution.to_execution_id()?;
+        match fee_authorization.peek_next()?.inputs().last() {
+            Some(Value::Plaintext(Plaintext::Literal(Literal::Field(id), _))) if *id == execution_id => (),
+            _ => bail!("The fee authorization is not for the sponsored execution '{execution_id}'"),
+        }
+        // Compute the fee.
+        let fee = self.execute_fee_authorization_raw(fee_authorization, query, rng)?;
+        // Ensure the fee covers the minimum execution cost.
+        let (minimum_execution_cost, (_, _)) = execution_cost(self, &execution)?;
+        ensure!(
+            *fee.base_amount()? >= minimum_execution_cost,
+            "The sponsored fee does not cover the execution cost of {minimum_execution_cost} microcredits"
+        );
+        // Return the execute transaction.
+        Transaction::from_execution(execution, Some(fee))
+    }
+
+    /// Returns a new execute transaction for the given batch payment.
+    ///
+    /// The batch payment program must already be deployed, see `BatchPayment::program`.
+    pub fn execute_batch_payment<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        batch_payment: &BatchPayment<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Retrieve the batch payment program.
+        let program = batch_payment.program()?;
+        // Ensure the deployed program matches the batch payment program.
+        match self.process.read().get_program(program.id()) {
+            Ok(deployed) => ensure!(
+                deployed == &program,
+                "The deployed program '{}' does not match the batch payment program",
+                program.id()
+            ),
+            Err(_) => bail!("The batch payment program '{}' must be deployed first", program.id()),
+        }
+        // Execute the batch payment.
+        self.execute(
+            private_key,
+            (*program.id(), batch_payment.function_name()?),
+            batch_payment.inputs()?.into_iter(),
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )
+    }
+
+    /// Returns a new execution for the given authorization, along with a report of its proving work and cost.
+    pub fn execute_authorization_with_report<R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<(Execution<N>, ExecutionReport<N>)> {
+        // Compute the execution, while measuring the proving time.
+        let start = std::time::Instant::now();
+        let (execution, call_metrics) = self.execute_authorization_with_metrics_raw(authorization, query, rng)?;
+        let proving_time = start.elapsed();
+        // Compute the proof size in bytes.
+        let proof_size_in_bytes = match execution.proof() {
+            Some(proof) => u64::try_from(proof.to_bytes_le()?.len())?,
+            None => 0,
+        };
+        // Compute the minimum execution cost.
+        let cost = execution_cost(self, &execution)?;
+        // Return the execution and report.
+        Ok((execution, ExecutionReport::new(call_metrics, proving_time, proof_size_in_bytes, cost)))
+    }
+
     /// Returns a new fee for the given authorization.
     pub fn execute_fee_authorization<R: Rng + CryptoRng>(
         &self,
@@ -116,6 +207,18 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
     ) -> Result<Execution<N>> {
+        self.execute_authorization_with_metrics_raw(authorization, query, rng).map(|(execution, _)| execution)
+    }
+
+    /// Executes a call to the program function for the given authorization.
+    /// Returns the execution and the call metrics.
+    #[inline]
+    fn execute_authorization_with_metrics_raw<R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<(Execution<N>, Vec<CallMetrics<N>>)> {
         let timer = timer!("VM::execute_authorization_raw");
 
         // Construct the locator of the main function.
@@ -128,6 +231,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             Some(query) => query,
             None => Query::VM(self.block_store().clone()),
         };
+        // Ensure the authorization has not expired, as of the next block.
+        if authorization.max_height().is_some() {
+            authorization.check_max_height(query.get_latest_height()?.saturating_add(1))?;
+        }
         lap!(timer, "Prepare the query");
 
         macro_rules! logic {
@@ -138,6 +245,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 let (_, mut trace) = $process.execute::<$aleo>(authorization.clone())?;
                 lap!(timer, "Execute the call");
 
+                // Retrieve the call metrics.
+                let call_metrics = trace.call_metrics().to_vec();
+
                 // Prepare the assignments.
                 cast_mut_ref!(trace as Trace<N>).prepare(query)?;
                 lap!(timer, "Prepare the assignments");
@@ -146,8 +256,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 let execution = trace.prove_execution::<$aleo, _>(&locator, rng)?;
                 lap!(timer, "Compute the proof");
 
-                // Return the execution.
-                Ok(cast_ref!(execution as Execution<N>).clone())
+                // Return the execution and call metrics.
+                Ok((
+                    cast_ref!(execution as Execution<N>).clone(),
+                    cast_ref!(call_metrics as Vec<CallMetrics<N>>).clone(),
+                ))
             }};
         }
 
@@ -173,6 +286,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             Some(query) => query,
             None => Query::VM(self.block_store().clone()),
         };
+        // Ensure the authorization has not expired, as of the next block.
+        if authorization.max_height().is_some() {
+            authorization.check_max_height(query.get_latest_height()?.saturating_add(1))?;
+        }
         lap!(timer, "Prepare the query");
 
         macro_rules! logic {
@@ -209,7 +326,7 @@ mod tests {
     use console::{
         account::{Address, ViewKey},
         network::Testnet3,
-        program::{Ciphertext, Value},
+        program::{Ciphertext, Expiry, RecordPredicate, Value},
         types::Field,
     };
     use ledger_block::Transition;
@@ -350,6 +467,302 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_batch_payment() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, records) = prepare_vm(rng).unwrap();
+
+        // Fetch the unspent records.
+        let mut records = records.values();
+        let record_1 = records.next().unwrap().decrypt(&caller_view_key).unwrap();
+        let record_2 = records.next().unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Prepare the batch payment.
+        let recipients = (0..3)
+            .map(|_| ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        let mut batch_payment = BatchPayment::new().add_record(record_1).unwrap().add_record(record_2).unwrap();
+        for (i, view_key) in recipients.iter().enumerate() {
+            batch_payment = batch_payment.add_recipient(view_key.to_address(), 10 * (i as u64 + 1)).unwrap();
+        }
+
+        // Ensure the batch payment requires the program to be deployed.
+        assert!(vm.execute_batch_payment(&caller_private_key, &batch_payment, None, 0, None, rng).is_err());
+
+        // Deploy the batch payment program.
+        let program = batch_payment.program().unwrap();
+        let deployment = vm.deploy(&caller_private_key, &program, None, 0, None, rng).unwrap();
+        vm.add_next_block(
+            &crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[deployment], rng).unwrap(),
+        )
+        .unwrap();
+
+        // Execute the batch payment.
+        let transaction = vm.execute_batch_payment(&caller_private_key, &batch_payment, None, 0, None, rng).unwrap();
+
+        // Ensure there is one join, one transfer per recipient, and the batch payment transition.
+        let Transaction::Execute(_, execution, _) = &transaction else { panic!("Expected an execute transaction") };
+        assert_eq!(execution.len(), 1 + recipients.len() + 1);
+        // Ensure each recipient receives a record.
+        for view_key in &recipients {
+            assert!(execution.transitions().flat_map(Transition::records).any(|(_, record)| record.is_owner(view_key)));
+        }
+
+        // Ensure the transaction is accepted.
+        vm.add_next_block(
+            &crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_sponsored_execution() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the sponsor, and a caller without credits.
+        let sponsor_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let sponsor = Address::try_from(&sponsor_private_key).unwrap();
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Deploy a program for the caller to execute.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program sponsored.aleo;
+
+function hello:
+    input r0 as u8.private;
+    add r0 r0 into r1;
+    output r1 as u8.private;",
+        )
+        .unwrap();
+        let deployment = vm.deploy(&sponsor_private_key, &program, None, 0, None, rng).unwrap();
+        vm.add_next_block(
+            &crate::vm::test_helpers::sample_next_block(&vm, &sponsor_private_key, &[deployment], rng).unwrap(),
+        )
+        .unwrap();
+
+        // The caller proves the execution, without a fee.
+        let inputs = [Value::<CurrentNetwork>::from_str("1u8").unwrap()];
+        let authorization = vm.authorize(&caller_private_key, "sponsored.aleo", "hello", inputs, rng).unwrap();
+        let Transaction::Execute(_, execution, None) =
+            vm.execute_authorization(authorization, None, None, rng).unwrap()
+        else {
+            panic!("Expected an execute transaction without a fee")
+        };
+
+        // Ensure a fee for another execution is rejected.
+        let other_authorization =
+            vm.authorize_fee_public(&sponsor_private_key, 1_000_000, 0, Field::rand(rng), rng).unwrap();
+        assert!(vm.execute_sponsored(execution.clone(), other_authorization, None, rng).is_err());
+
+        // The sponsor authorizes the fee, and the caller attaches it.
+        let fee_authorization = vm.authorize_sponsored_fee(&sponsor_private_key, &execution, None, 0, rng).unwrap();
+        let transaction = vm.execute_sponsored(execution, fee_authorization, None, rng).unwrap();
+        assert_eq!(transaction.fee_transition().unwrap().payer(), Some(sponsor));
+        vm.check_transaction(&transaction, None).unwrap();
+
+        // Ensure the transaction is accepted.
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &sponsor_private_key, &[transaction], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert!(block.transactions().iter().all(|transaction| transaction.is_accepted()));
+    }
+
+    #[test]
+    fn test_expiring_authorization() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM, at block 0.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Authorizes a public transfer, up to the given maximum height.
+        let authorize = |max_height: u32, rng: &mut TestRng| {
+            let inputs = [
+                Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+                Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+            ];
+            vm.authorize_with_max_height(
+                &caller_private_key,
+                "credits.aleo",
+                "transfer_public",
+                inputs,
+                max_height,
+                rng,
+            )
+            .unwrap()
+        };
+
+        // Ensure an authorization that expired at block 0 cannot be proven for block 1.
+        let authorization = authorize(0, rng);
+        assert_eq!(authorization.max_height(), Some(0));
+        assert!(authorization.check_max_height(1).is_err());
+        assert!(vm.execute_authorization(authorization, None, None, rng).is_err());
+
+        // Ensure an authorization that expires at block 1 carries the maximum height into its transaction.
+        let authorization = authorize(1, rng);
+        let execution_id = authorization.to_execution_id().unwrap();
+        let fee_authorization = vm.authorize_fee_public(&caller_private_key, 10_000_000, 0, execution_id, rng).unwrap();
+        let transaction = vm.execute_authorization(authorization, Some(fee_authorization), None, rng).unwrap();
+        let transition = transaction.execution().unwrap().peek().unwrap();
+        assert_eq!(transition.expiry().map(Expiry::max_height), Some(1));
+        vm.check_transaction(&transaction, None).unwrap();
+        vm.check_transaction_expiry(&transaction, 1).unwrap();
+        assert!(vm.check_transaction_expiry(&transaction, 2).is_err());
+
+        // Ensure the transaction is rejected, once the next block is past its maximum height.
+        let block = crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[], rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert!(vm.check_transaction(&transaction, None).is_err());
+    }
+
+    #[test]
+    fn test_execution_report() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM and records.
+        let (vm, records) = prepare_vm(rng).unwrap();
+
+        // Fetch the unspent record.
+        let record = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
+
+        // Authorize a private transfer.
+        let inputs = [
+            Value::<CurrentNetwork>::Record(record),
+            Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("1u64").unwrap(),
+        ];
+        let authorization = vm.authorize(&caller_private_key, "credits.aleo", "transfer_private", inputs, rng).unwrap();
+
+        // Execute the authorization, with a report.
+        let (execution, report) = vm.execute_authorization_with_report(authorization, None, rng).unwrap();
+
+        // Ensure the report matches the execution.
+        assert_eq!(report.call_metrics().len(), execution.len());
+        assert!(report.num_constraints() > 0);
+        assert_eq!(report.proof_size_in_bytes(), execution.proof().unwrap().to_bytes_le().unwrap().len() as u64);
+        let (minimum_execution_cost, (storage_cost, finalize_cost)) = execution_cost(&vm, &execution).unwrap();
+        assert_eq!(report.minimum_execution_cost(), minimum_execution_cost);
+        assert_eq!(report.storage_cost(), storage_cost);
+        assert_eq!(report.finalize_cost(), finalize_cost);
+    }
+
+    #[test]
+    fn test_record_predicates() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Prepare the VM.
+        let (vm, _) = prepare_vm(rng).unwrap();
+
+        // Deploy a program, whose record may only be spent with a preimage, from block 4.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program hash_time_lock.aleo;
+
+record lock:
+    owner as address.private;
+    amount as u64.private;
+    hash_lock as field.public;
+    spend_after as u32.public;
+
+function open:
+    input r0 as u64.private;
+    input r1 as field.public;
+    input r2 as u32.public;
+    cast self.caller r0 r1 r2 into r3 as lock.record;
+    output r3 as lock.record;
+
+function claim:
+    input r0 as lock.record;
+    input r1 as field.public;
+    input r2 as u32.public;
+    output r0.amount as u64.private;",
+        )
+        .unwrap();
+        let deployment = vm.deploy(&caller_private_key, &program, None, 0, None, rng).unwrap();
+        vm.add_next_block(
+            &crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[deployment], rng).unwrap(),
+        )
+        .unwrap();
+
+        // Executes the given function, and returns the transaction, if it is accepted.
+        let execute = |function_name: &str, inputs: Vec<Value<CurrentNetwork>>, rng: &mut TestRng| {
+            let transaction = vm
+                .execute(
+                    &caller_private_key,
+                    ("hash_time_lock.aleo", function_name),
+                    inputs.into_iter(),
+                    None,
+                    0,
+                    None,
+                    rng,
+                )
+                .unwrap();
+            let block =
+                crate::vm::test_helpers::sample_next_block(&vm, &caller_private_key, &[transaction], rng).unwrap();
+            vm.add_next_block(&block).unwrap();
+            block
+                .transactions()
+                .iter()
+                .find(|transaction| transaction.is_accepted())
+                .map(|confirmed| confirmed.transaction().clone())
+        };
+
+        // Open a lock, in block 2.
+        let preimage = Field::rand(rng);
+        let hash_lock = RecordPredicate::hash_lock(&preimage).unwrap();
+        let inputs = vec![
+            Value::from_str("100u64").unwrap(),
+            Value::from_str(&hash_lock.to_string()).unwrap(),
+            Value::from_str("4u32").unwrap(),
+        ];
+        let transaction = execute("open", inputs, rng).unwrap();
+        let record = transaction.records().next().unwrap().1.decrypt(&caller_view_key).unwrap();
+        assert_eq!(**record.owner(), address);
+
+        // Prepares the inputs to `claim`, given the preimage.
+        let record_type = program.get_record(&Identifier::from_str("lock").unwrap()).unwrap();
+        let claim_inputs = |preimage: Field<CurrentNetwork>| {
+            let mut inputs = vec![Value::Record(record.clone())];
+            inputs.extend(record.to_predicate_inputs(record_type, Some(preimage))?);
+            Ok::<_, Error>(inputs)
+        };
+
+        // Ensure a claim with the wrong preimage is rejected.
+        assert!(claim_inputs(Field::rand(rng)).is_err());
+        let mut inputs = claim_inputs(preimage).unwrap();
+        inputs[1] = Value::from_str(&Field::<CurrentNetwork>::rand(rng).to_string()).unwrap();
+        assert!(
+            vm.execute(&caller_private_key, ("hash_time_lock.aleo", "claim"), inputs.into_iter(), None, 0, None, rng)
+                .is_err()
+        );
+
+        // Ensure a claim with the preimage is rejected in block 3, and accepted in block 4.
+        assert!(execute("claim", claim_inputs(preimage).unwrap(), rng).is_none());
+        assert!(execute("claim", claim_inputs(preimage).unwrap(), rng).is_some());
+    }
+
     #[test]
     fn test_split_transaction_size() {
         let rng = &mut TestRng::default();
@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use super::*;
+use synthesizer_program::StackProgram;
+
+use std::collections::HashSet;
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Speculates on the given list of transactions in the VM.
@@ -116,11 +119,27 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ratifications: &Ratifications<N>,
         solutions: Option<&CoinbaseSolution<N>>,
         transactions: &Transactions<N>,
+    ) -> Result<Vec<FinalizeOperation<N>>> {
+        self.finalize_with(state, ratifications, solutions, transactions, || Ok(()))
+    }
+
+    /// Finalizes the given transactions into the VM, and runs the given update in the same atomic batch.
+    ///
+    /// Returns the finalize operations from pre-ratify and post-ratify.
+    #[inline]
+    pub(crate) fn finalize_with(
+        &self,
+        state: FinalizeGlobalState,
+        ratifications: &Ratifications<N>,
+        solutions: Option<&CoinbaseSolution<N>>,
+        transactions: &Transactions<N>,
+        update: impl FnOnce() -> Result<()>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
         let timer = timer!("VM::finalize");
 
         // Performs a **real-run** of finalize over the list of ratifications, solutions, and transactions.
-        let ratified_finalize_operations = self.atomic_finalize(state, ratifications, solutions, transactions)?;
+        let ratified_finalize_operations =
+            self.atomic_finalize(state, ratifications, solutions, transactions, update)?;
 
         finish!(timer, "Finished real-run of finalize");
         Ok(ratified_finalize_operations)
@@ -171,11 +190,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let pre_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _) => true,
                 Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
+                // Note: The randomness beacon ratifications are processed by the ledger, not in finalize.
+                Ratify::BeaconCommit(..) | Ratify::BeaconReveal(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _) => false,
                 Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
+                Ratify::BeaconCommit(..) | Ratify::BeaconReveal(..) => false,
             });
 
             // Initialize a list of finalize operations.
@@ -204,6 +226,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let mut confirmed = Vec::with_capacity(num_transactions);
             // Initialize a list of the aborted transactions.
             let mut aborted = Vec::new();
+            // Initialize a set of the program IDs deployed in the block.
+            let mut deployed_program_ids = HashSet::new();
 
             // Finalize the transactions.
             'outer: for (index, transaction) in transactions.enumerate() {
@@ -211,6 +235,16 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 // Note: On failure, this will abort the entire atomic batch.
                 let index = u32::try_from(index).map_err(|_| "Failed to convert transaction index".to_string())?;
 
+                // Abort a deployment of a program that is already deployed or upgraded in the block,
+                // as each deployment is checked against the program as of the previous block.
+                if let Transaction::Deploy(_, _, deployment, _) = transaction {
+                    if !deployed_program_ids.insert(*deployment.program_id()) {
+                        let error = format!("Program '{}' is already deployed in the block", deployment.program_id());
+                        aborted.push((transaction.clone(), error));
+                        continue 'outer;
+                    }
+                }
+
                 // Process the transaction in an isolated atomic batch.
                 // - If the transaction succeeds, the finalize operations are stored.
                 // - If the transaction fails, the atomic batch is aborted and no finalize operations are stored.
@@ -330,15 +364,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         return Err("Failed to calculate the transaction fees during speculation".to_string());
                     };
 
-                    // Compute the block reward.
-                    let block_reward = ledger_block::block_reward(
-                        N::STARTING_SUPPLY,
-                        N::BLOCK_TIME,
-                        coinbase_reward,
-                        transaction_fees,
-                    );
-                    // Compute the puzzle reward.
-                    let puzzle_reward = ledger_block::puzzle_reward(coinbase_reward);
+                    // Compute the block reward and puzzle reward.
+                    let (block_reward, puzzle_reward) = block_and_puzzle_reward::<N>(coinbase_reward, transaction_fees);
 
                     // Output the reward ratifications.
                     vec![Ratify::BlockReward(block_reward), Ratify::PuzzleReward(puzzle_reward)]
@@ -373,7 +400,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         })
     }
 
-    /// Performs atomic finalization over a list of transactions.
+    /// Performs atomic finalization over a list of transactions, followed by the given update.
     ///
     /// Returns the finalize operations from pre-ratify and post-ratify.
     #[inline]
@@ -383,6 +410,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ratifications: &Ratifications<N>,
         solutions: Option<&CoinbaseSolution<N>>,
         transactions: &Transactions<N>,
+        update: impl FnOnce() -> Result<()>,
     ) -> Result<Vec<FinalizeOperation<N>>> {
         let timer = timer!("VM::atomic_finalize");
 
@@ -392,11 +420,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let pre_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _) => true,
                 Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
+                // Note: The randomness beacon ratifications are processed by the ledger, not in finalize.
+                Ratify::BeaconCommit(..) | Ratify::BeaconReveal(..) => false,
             });
             // Initialize an iterator for ratifications after finalize.
             let post_ratifications = ratifications.iter().filter(|r| match r {
                 Ratify::Genesis(_, _) => false,
                 Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
+                Ratify::BeaconCommit(..) | Ratify::BeaconReveal(..) => false,
             });
 
             // Initialize a list of finalize operations.
@@ -595,11 +626,27 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 Err(e) => return Err(format!("Failed to post-ratify - {e}")),
             }
 
+            /* Perform the update in the same atomic batch. */
+
+            if let Err(e) = update() {
+                // Note: This will abort the entire atomic batch.
+                return Err(format!("Failed to update the state derived from the block - {e}"));
+            }
+
             /* Start the commit process. */
 
             // Commit all of the stacks to the process.
-            if !stacks.is_empty() {
-                stacks.into_iter().for_each(|stack| process.add_stack(stack))
+            for stack in stacks {
+                match process.contains_program(stack.program_id()) {
+                    // Replace the stack of an upgraded program, along with the stacks that import it.
+                    true => {
+                        if let Err(error) = process.upgrade_stack(stack) {
+                            // Note: This will abort the entire atomic batch.
+                            return Err(format!("Failed to upgrade a program stack - {error}"));
+                        }
+                    }
+                    false => process.add_stack(stack),
+                }
             }
 
             finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
@@ -700,7 +747,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     // Set the genesis ratification flag.
                     is_genesis_ratified = true;
                 }
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => continue,
+                Ratify::BlockReward(..)
+                | Ratify::PuzzleReward(..)
+                | Ratify::BeaconCommit(..)
+                | Ratify::BeaconReveal(..) => continue,
             }
         }
 
@@ -736,7 +786,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Iterate over the ratifications.
         for ratify in post_ratifications {
             match ratify {
-                Ratify::Genesis(..) => continue,
+                Ratify::Genesis(..) | Ratify::BeaconCommit(..) | Ratify::BeaconReveal(..) => continue,
                 Ratify::BlockReward(block_reward) => {
                     // Ensure the block reward has not been ratified yet.
                     ensure!(!is_block_reward_ratified, "Ratify::BlockReward(..) has already been ratified");
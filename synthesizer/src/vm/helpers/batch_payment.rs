@@ -0,0 +1,528 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::Address,
+    prelude::*,
+    program::{Entry, Identifier, Literal, PaymentRequest, Plaintext, ProgramID, Record, Value},
+    types::U64,
+};
+use synthesizer_program::Program;
+
+#[cfg(feature = "address_book")]
+use console::account::{AddressBook, AddressOrAlias, AddressResolver};
+
+use std::collections::BTreeSet;
+
+/// The fee deducted by `credits.aleo/split`, in microcredits.
+const SPLIT_FEE: u64 = 10_000;
+
+/// A builder for a batch payment, which splits one or more records across multiple recipients in one transaction.
+///
+/// The payment is executed through a generated program, which joins the records and calls
+/// `credits.aleo/transfer_private` once per recipient, returning the change to the sender.
+/// The change may be split across multiple records of randomized amounts, see `BatchPayment::split_change`.
+/// The program is determined by the number of records, recipients, and change records, and must be deployed
+/// before the first batch payment of its shape is executed.
+#[derive(Clone)]
+pub struct BatchPayment<N: Network> {
+    /// The records to spend.
+    records: Vec<Record<N, Plaintext<N>>>,
+    /// The recipients and their amounts, in microcredits.
+    recipients: Vec<(Address<N>, u64)>,
+    /// The amounts split off the change, in microcredits. The last change record holds the remainder.
+    change_amounts: Vec<u64>,
+}
+
+impl<N: Network> Default for BatchPayment<N> {
+    /// Initializes a new, empty batch payment.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> BatchPayment<N> {
+    /// Initializes a new, empty batch payment.
+    pub const fn new() -> Self {
+        Self { records: Vec::new(), recipients: Vec::new(), change_amounts: Vec::new() }
+    }
+
+    /// Returns the maximum number of recipients, for the given number of records.
+    pub const fn max_recipients(num_records: usize) -> usize {
+        // Each recipient requires an address and an amount as inputs.
+        N::MAX_INPUTS.saturating_sub(num_records) / 2
+    }
+
+    /// Adds the given record to spend from.
+    pub fn add_record(mut self, record: Record<N, Plaintext<N>>) -> Result<Self> {
+        // Ensure the record contains a balance.
+        microcredits(&record)?;
+        // Ensure the number of inputs does not exceed the limit.
+        ensure!(
+            self.recipients.len() <= Self::max_recipients(self.records.len() + 1),
+            "A batch payment with {} recipients cannot spend more than {} records",
+            self.recipients.len(),
+            self.records.len()
+        );
+        self.records.push(record);
+        Ok(self)
+    }
+
+    /// Adds the given recipient and amount, in microcredits.
+    pub fn add_recipient(mut self, recipient: Address<N>, amount: u64) -> Result<Self> {
+        // Ensure the amount is nonzero.
+        ensure!(amount > 0, "A batch payment amount must be greater than zero");
+        // Ensure the number of inputs does not exceed the limit.
+        ensure!(
+            self.recipients.len() < Self::max_recipients(self.records.len().max(1)),
+            "A batch payment cannot exceed {} recipients",
+            Self::max_recipients(self.records.len().max(1))
+        );
+        self.recipients.push((recipient, amount));
+        Ok(self)
+    }
+
+    /// Adds the recipient and amount of the given payment request.
+    /// The batch payment pays each recipient with `credits.aleo/transfer_private`, so the request must be for
+    /// that function. The memo of the request is not part of the transaction.
+    pub fn add_payment_request(self, request: &PaymentRequest<N>) -> Result<Self> {
+        ensure!(
+            request.program_id().to_string() == "credits.aleo"
+                && request.function_name().to_string() == "transfer_private",
+            "A batch payment cannot pay a request for '{}/{}'",
+            request.program_id(),
+            request.function_name()
+        );
+        self.add_recipient(*request.recipient(), request.amount())
+    }
+
+    /// Adds the given recipient, as an address or an alias, and amount, in microcredits.
+    /// An alias is resolved with the given address book, followed by each of the given resolvers, in order.
+    #[cfg(feature = "address_book")]
+    pub fn add_resolved_recipient(
+        self,
+        recipient: &AddressOrAlias<N>,
+        amount: u64,
+        address_book: &AddressBook<N>,
+        resolvers: &[&dyn AddressResolver<N>],
+    ) -> Result<Self> {
+        let recipient = address_book.resolve(recipient, resolvers)?;
+        self.add_recipient(recipient, amount)
+    }
+
+    /// Splits the change into the given number of records, of randomized amounts.
+    /// Spreading the change across unrelated amounts makes it harder to tell the change apart from the payments.
+    /// Each additional change record costs the fee of one `credits.aleo/split` call, and must be set
+    /// after the records and recipients are added.
+    pub fn split_change<R: Rng + CryptoRng>(mut self, num_outputs: usize, rng: &mut R) -> Result<Self> {
+        ensure!(num_outputs > 0, "A batch payment must have at least one change record");
+        // Ensure the number of inputs does not exceed the limit.
+        let num_inputs = self.records.len() + 2 * self.recipients.len() + num_outputs - 1;
+        ensure!(num_inputs <= N::MAX_INPUTS, "A batch payment cannot exceed {} inputs", N::MAX_INPUTS);
+
+        // Compute the change, after the split fees.
+        let (balance, total) = self.balance_and_total()?;
+        let fees = (num_outputs as u64 - 1).saturating_mul(SPLIT_FEE);
+        let change = balance.saturating_sub(total).saturating_sub(fees);
+        ensure!(
+            change >= num_outputs as u64,
+            "The change ({change}) is insufficient to split into {num_outputs} records after fees ({fees})"
+        );
+
+        // Sample distinct cut points in the change, so that each change record holds a nonzero amount.
+        let mut cuts = BTreeSet::new();
+        while cuts.len() < num_outputs - 1 {
+            cuts.insert(rng.gen_range(1..change));
+        }
+        // Split off the amounts between consecutive cut points.
+        let mut previous = 0;
+        self.change_amounts = cuts
+            .into_iter()
+            .map(|cut| {
+                let amount = cut - previous;
+                previous = cut;
+                amount
+            })
+            .collect();
+        Ok(self)
+    }
+
+    /// Returns the records to spend.
+    pub fn records(&self) -> &[Record<N, Plaintext<N>>] {
+        &self.records
+    }
+
+    /// Returns the recipients and their amounts, in microcredits.
+    pub fn recipients(&self) -> &[(Address<N>, u64)] {
+        &self.recipients
+    }
+
+    /// Returns the amounts split off the change, in microcredits.
+    pub fn change_amounts(&self) -> &[u64] {
+        &self.change_amounts
+    }
+
+    /// Returns the program ID of the batch payment program, i.e. `batch_payment_2_3.aleo`,
+    /// or `batch_payment_2_3_4.aleo` if the change is split into 4 records.
+    pub fn program_id(&self) -> Result<ProgramID<N>> {
+        match self.change_amounts.len() {
+            0 => ProgramID::from_str(&format!("batch_payment_{}_{}.aleo", self.records.len(), self.recipients.len())),
+            num_splits => ProgramID::from_str(&format!(
+                "batch_payment_{}_{}_{}.aleo",
+                self.records.len(),
+                self.recipients.len(),
+                num_splits + 1
+            )),
+        }
+    }
+
+    /// Returns the function name of the batch payment program.
+    pub fn function_name(&self) -> Result<Identifier<N>> {
+        Identifier::from_str("pay")
+    }
+
+    /// Returns the batch payment program.
+    pub fn program(&self) -> Result<Program<N>> {
+        // Ensure the batch payment is well-formed.
+        self.check()?;
+
+        let num_records = self.records.len();
+        let num_recipients = self.recipients.len();
+        let num_splits = self.change_amounts.len();
+
+        let mut program = format!("import credits.aleo;\n\nprogram {};\n\nfunction pay:\n", self.program_id()?);
+        // Declare the record inputs.
+        for i in 0..num_records {
+            program.push_str(&format!("    input r{i} as credits.aleo/credits.record;\n"));
+        }
+        // Declare the recipient inputs.
+        for i in 0..num_recipients {
+            let register = num_records + 2 * i;
+            program.push_str(&format!("    input r{register} as address.private;\n"));
+            program.push_str(&format!("    input r{} as u64.private;\n", register + 1));
+        }
+        // Declare the change amount inputs.
+        for i in 0..num_splits {
+            program.push_str(&format!("    input r{} as u64.private;\n", num_records + 2 * num_recipients + i));
+        }
+
+        // Initialize the next free register, and the register holding the balance.
+        let mut next = num_records + 2 * num_recipients + num_splits;
+        let mut balance = 0;
+        // Join the records.
+        for i in 1..num_records {
+            program.push_str(&format!("    call credits.aleo/join r{balance} r{i} into r{next};\n"));
+            balance = next;
+            next += 1;
+        }
+        // Transfer to each recipient, carrying the change forward.
+        for i in 0..num_recipients {
+            let register = num_records + 2 * i;
+            program.push_str(&format!(
+                "    call credits.aleo/transfer_private r{balance} r{register} r{} into r{next} r{};\n",
+                register + 1,
+                next + 1
+            ));
+            balance = next + 1;
+            next += 2;
+        }
+        // Split the change, carrying the remainder forward.
+        let mut change = Vec::with_capacity(num_splits + 1);
+        for i in 0..num_splits {
+            let register = num_records + 2 * num_recipients + i;
+            program
+                .push_str(&format!("    call credits.aleo/split r{balance} r{register} into r{next} r{};\n", next + 1));
+            change.push(next);
+            balance = next + 1;
+            next += 2;
+        }
+        change.push(balance);
+        // Output the change.
+        for register in change {
+            program.push_str(&format!("    output r{register} as credits.aleo/credits.record;\n"));
+        }
+
+        Program::from_str(&program)
+    }
+
+    /// Returns the inputs to the batch payment program.
+    pub fn inputs(&self) -> Result<Vec<Value<N>>> {
+        // Ensure the batch payment is well-formed.
+        self.check()?;
+
+        let records = self.records.iter().map(|record| Value::Record(record.clone()));
+        let recipients = self.recipients.iter().flat_map(|(recipient, amount)| {
+            [
+                Value::Plaintext(Plaintext::from(Literal::Address(*recipient))),
+                Value::Plaintext(Plaintext::from(Literal::U64(U64::new(*amount)))),
+            ]
+        });
+        let change_amounts =
+            self.change_amounts.iter().map(|amount| Value::Plaintext(Plaintext::from(Literal::U64(U64::new(*amount)))));
+        Ok(records.chain(recipients).chain(change_amounts).collect())
+    }
+
+    /// Ensures the batch payment is well-formed, and the records cover the amounts.
+    fn check(&self) -> Result<()> {
+        ensure!(!self.records.is_empty(), "A batch payment must spend at least one record");
+        ensure!(!self.recipients.is_empty(), "A batch payment must have at least one recipient");
+        ensure!(
+            self.records.len() + 2 * self.recipients.len() + self.change_amounts.len() <= N::MAX_INPUTS,
+            "A batch payment cannot exceed {} inputs",
+            N::MAX_INPUTS
+        );
+
+        // Compute the balance of the records, and the total amount.
+        let (balance, total) = self.balance_and_total()?;
+        ensure!(balance >= total, "The records balance ({balance}) is insufficient for the batch payment ({total})");
+
+        // Ensure the change covers the split amounts and fees.
+        let mut change = balance - total;
+        for amount in &self.change_amounts {
+            change = change
+                .checked_sub(amount.saturating_add(SPLIT_FEE))
+                .ok_or(anyhow!("The batch payment change is insufficient for the change records"))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the balance of the records, and the total amount to the recipients, in microcredits.
+    fn balance_and_total(&self) -> Result<(u64, u64)> {
+        // Compute the balance of the records.
+        let mut balance = 0u64;
+        for record in &self.records {
+            balance =
+                balance.checked_add(microcredits(record)?).ok_or(anyhow!("The batch payment balance overflowed"))?;
+        }
+        // Compute the total amount.
+        let mut total = 0u64;
+        for (_, amount) in &self.recipients {
+            total = total.checked_add(*amount).ok_or(anyhow!("The batch payment amount overflowed"))?;
+        }
+        Ok((balance, total))
+    }
+}
+
+/// Returns the balance of the given credits record, in microcredits.
+fn microcredits<N: Network>(record: &Record<N, Plaintext<N>>) -> Result<u64> {
+    match record.find(&[Identifier::from_str("microcredits")?]) {
+        Ok(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => Ok(*amount),
+        _ => bail!("The record does not contain a 'microcredits' entry"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Samples a credits record with the given balance, owned by the given address.
+    fn sample_record(
+        owner: Address<CurrentNetwork>,
+        microcredits: u64,
+    ) -> Record<CurrentNetwork, Plaintext<CurrentNetwork>> {
+        Record::from_str(&format!(
+            "{{ owner: {owner}.private, microcredits: {microcredits}u64.private, _nonce: 0group.public }}"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_batch_payment_program() {
+        let rng = &mut TestRng::default();
+
+        let owner = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        let batch = BatchPayment::new()
+            .add_record(sample_record(owner, 10))
+            .unwrap()
+            .add_record(sample_record(owner, 20))
+            .unwrap()
+            .add_recipient(recipient, 5)
+            .unwrap()
+            .add_recipient(recipient, 15)
+            .unwrap();
+
+        // Ensure the program is generated.
+        let expected = Program::<CurrentNetwork>::from_str(
+            r"
+import credits.aleo;
+
+program batch_payment_2_2.aleo;
+
+function pay:
+    input r0 as credits.aleo/credits.record;
+    input r1 as credits.aleo/credits.record;
+    input r2 as address.private;
+    input r3 as u64.private;
+    input r4 as address.private;
+    input r5 as u64.private;
+    call credits.aleo/join r0 r1 into r6;
+    call credits.aleo/transfer_private r6 r2 r3 into r7 r8;
+    call credits.aleo/transfer_private r8 r4 r5 into r9 r10;
+    output r10 as credits.aleo/credits.record;",
+        )
+        .unwrap();
+        assert_eq!(batch.program().unwrap(), expected);
+        assert_eq!(batch.program_id().unwrap(), *expected.id());
+
+        // Ensure the inputs are ordered as the program expects.
+        let inputs = batch.inputs().unwrap();
+        assert_eq!(inputs.len(), 6);
+        assert_eq!(inputs[2], Value::from_str(&recipient.to_string()).unwrap());
+        assert_eq!(inputs[5], Value::from_str("15u64").unwrap());
+    }
+
+    #[test]
+    fn test_batch_payment_split_change() {
+        let rng = &mut TestRng::default();
+
+        let owner = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        let batch = BatchPayment::new()
+            .add_record(sample_record(owner, 1_000_000))
+            .unwrap()
+            .add_recipient(recipient, 100_000)
+            .unwrap()
+            .split_change(3, rng)
+            .unwrap();
+
+        // Ensure the change amounts are nonzero, and leave a nonzero remainder after the split fees.
+        let change_amounts = batch.change_amounts();
+        assert_eq!(change_amounts.len(), 2);
+        assert!(change_amounts.iter().all(|amount| *amount > 0));
+        assert!(change_amounts.iter().sum::<u64>() < 1_000_000 - 100_000 - 2 * SPLIT_FEE);
+
+        // Ensure the program is generated.
+        let expected = Program::<CurrentNetwork>::from_str(
+            r"
+import credits.aleo;
+
+program batch_payment_1_1_3.aleo;
+
+function pay:
+    input r0 as credits.aleo/credits.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    input r3 as u64.private;
+    input r4 as u64.private;
+    call credits.aleo/transfer_private r0 r1 r2 into r5 r6;
+    call credits.aleo/split r6 r3 into r7 r8;
+    call credits.aleo/split r8 r4 into r9 r10;
+    output r7 as credits.aleo/credits.record;
+    output r9 as credits.aleo/credits.record;
+    output r10 as credits.aleo/credits.record;",
+        )
+        .unwrap();
+        assert_eq!(batch.program().unwrap(), expected);
+        assert_eq!(batch.program_id().unwrap(), *expected.id());
+
+        // Ensure the change amounts are passed as the last inputs.
+        let inputs = batch.inputs().unwrap();
+        assert_eq!(inputs.len(), 5);
+        assert_eq!(inputs[3], Value::from_str(&format!("{}u64", change_amounts[0])).unwrap());
+        assert_eq!(inputs[4], Value::from_str(&format!("{}u64", change_amounts[1])).unwrap());
+
+        // Ensure a single change record keeps the original program.
+        let batch = batch.split_change(1, rng).unwrap();
+        assert!(batch.change_amounts().is_empty());
+        assert_eq!(batch.program_id().unwrap().to_string(), "batch_payment_1_1.aleo");
+
+        // Ensure the change must cover the split fees.
+        let batch =
+            BatchPayment::new().add_record(sample_record(owner, 100)).unwrap().add_recipient(recipient, 10).unwrap();
+        assert!(batch.clone().split_change(0, rng).is_err());
+        assert!(batch.split_change(2, rng).is_err());
+    }
+
+    #[test]
+    fn test_batch_payment_request() {
+        let rng = &mut TestRng::default();
+
+        let owner = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Ensure a request for `credits.aleo/transfer_private` is added as a recipient.
+        let request = PaymentRequest::from_str(&format!(
+            "aleo:{recipient}?amount=15&program=credits.aleo&function=transfer_private&memo=invoice"
+        ))
+        .unwrap();
+        let batch = BatchPayment::new().add_record(sample_record(owner, 100)).unwrap().add_payment_request(&request);
+        assert_eq!(batch.unwrap().recipients(), &[(recipient, 15)]);
+
+        // Ensure a request for another function is rejected.
+        let request = PaymentRequest::new(recipient, 15).unwrap();
+        assert!(BatchPayment::new().add_payment_request(&request).is_err());
+    }
+
+    #[cfg(feature = "address_book")]
+    #[test]
+    fn test_batch_payment_resolved_recipient() {
+        let rng = &mut TestRng::default();
+
+        let owner = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        let mut address_book = AddressBook::new();
+        address_book.insert("alice", recipient).unwrap();
+
+        // Ensure an alias and an address resolve to the same recipient.
+        let batch = BatchPayment::new()
+            .add_record(sample_record(owner, 100))
+            .unwrap()
+            .add_resolved_recipient(&AddressOrAlias::from_str("alice").unwrap(), 10, &address_book, &[])
+            .unwrap()
+            .add_resolved_recipient(&AddressOrAlias::from_str(&recipient.to_string()).unwrap(), 20, &address_book, &[])
+            .unwrap();
+        assert_eq!(batch.recipients(), &[(recipient, 10), (recipient, 20)]);
+
+        // Ensure an unknown alias is rejected.
+        let unknown = AddressOrAlias::from_str("bob").unwrap();
+        assert!(BatchPayment::new().add_resolved_recipient(&unknown, 10, &address_book, &[]).is_err());
+    }
+
+    #[test]
+    fn test_batch_payment_limits() {
+        let rng = &mut TestRng::default();
+
+        let owner = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Ensure an empty batch payment is rejected.
+        assert!(BatchPayment::<CurrentNetwork>::new().program().is_err());
+        assert!(BatchPayment::new().add_record(sample_record(owner, 10)).unwrap().program().is_err());
+
+        // Ensure a zero amount is rejected.
+        assert!(BatchPayment::<CurrentNetwork>::new().add_recipient(owner, 0).is_err());
+
+        // Ensure the number of recipients is bounded by the number of inputs.
+        let max_recipients = BatchPayment::<CurrentNetwork>::max_recipients(1);
+        assert_eq!(max_recipients, 7);
+        let mut batch = BatchPayment::new().add_record(sample_record(owner, 100)).unwrap();
+        for _ in 0..max_recipients {
+            batch = batch.add_recipient(owner, 1).unwrap();
+        }
+        assert!(batch.clone().add_recipient(owner, 1).is_err());
+        assert!(batch.clone().add_record(sample_record(owner, 100)).is_err());
+        assert!(batch.program().is_ok());
+
+        // Ensure an insufficient balance is rejected.
+        let batch = BatchPayment::new().add_record(sample_record(owner, 10)).unwrap().add_recipient(owner, 11).unwrap();
+        assert!(batch.program().is_err());
+        assert!(batch.inputs().is_err());
+    }
+}
@@ -19,42 +19,28 @@ use console::{
 };
 use ledger_block::{Deployment, Execution};
 use ledger_store::ConsensusStorage;
+use synthesizer_process::Process;
 use synthesizer_program::{Command, Finalize, Instruction};
 
 use std::collections::HashMap;
 
 /// Returns the *minimum* cost in microcredits to publish the given deployment (total cost, (storage cost, namespace cost)).
 pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (u64, u64))> {
-    // Determine the number of bytes in the deployment.
-    let size_in_bytes = deployment.size_in_bytes()?;
-    // Retrieve the program ID.
-    let program_id = deployment.program_id();
-    // Determine the number of characters in the program ID.
-    let num_characters = u32::try_from(program_id.name().to_string().len())?;
-
-    // Compute the storage cost in microcredits.
-    let storage_cost = size_in_bytes
-        .checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER)
-        .ok_or(anyhow!("The storage cost computation overflowed for a deployment"))?;
-
-    // Compute the namespace cost in credits: 10^(10 - num_characters).
-    let namespace_cost = 10u64
-        .checked_pow(10u32.saturating_sub(num_characters))
-        .ok_or(anyhow!("The namespace cost computation overflowed for a deployment"))?
-        .saturating_mul(1_000_000); // 1 microcredit = 1e-6 credits.
-
-    // Compute the total cost in microcredits.
-    let total_cost = storage_cost
-        .checked_add(namespace_cost)
-        .ok_or(anyhow!("The total cost computation overflowed for a deployment"))?;
-
-    Ok((total_cost, (storage_cost, namespace_cost)))
+    deployment.cost()
 }
 
 /// Returns the *minimum* cost in microcredits to publish the given execution (total cost, (storage cost, namespace cost)).
 pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
     vm: &VM<N, C>,
     execution: &Execution<N>,
+) -> Result<(u64, (u64, u64))> {
+    execution_cost_in_process(&vm.process().read(), execution)
+}
+
+/// Returns the *minimum* cost in microcredits to publish the given execution, using the programs in the given process.
+pub(crate) fn execution_cost_in_process<N: Network>(
+    process: &Process<N>,
+    execution: &Execution<N>,
 ) -> Result<(u64, (u64, u64))> {
     // Compute the storage cost in microcredits.
     let storage_cost = execution.size_in_bytes()?;
@@ -64,7 +50,7 @@ pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
         .transitions()
         .map(|transition| {
             let program_id = transition.program_id();
-            Ok((*program_id, vm.process().read().get_program(program_id)?.clone()))
+            Ok((*program_id, process.get_program(program_id)?.clone()))
         })
         .collect::<Result<HashMap<_, _>>>()?;
 
@@ -121,6 +107,8 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::Div(_)) => Ok(10_000),
         Command::Instruction(Instruction::DivWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Double(_)) => Ok(2_000),
+        Command::Instruction(Instruction::Encrypt(_)) => bail!("`encrypt` is not supported in finalize."),
+        Command::Instruction(Instruction::FeedVerify(_)) => Ok(250_000),
         Command::Instruction(Instruction::GreaterThan(_)) => Ok(2_000),
         Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(2_000),
         Command::Instruction(Instruction::HashBHP256(_)) => Ok(100_000),
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::process::CallMetrics;
+use console::network::prelude::*;
+
+use std::time::Duration;
+
+/// A report of the proving work and minimum cost of an execution.
+#[derive(Clone, Debug)]
+pub struct ExecutionReport<N: Network> {
+    /// The call metrics, for each transition in the execution.
+    call_metrics: Vec<CallMetrics<N>>,
+    /// The wall time spent executing and proving the execution.
+    proving_time: Duration,
+    /// The size of the proof in bytes.
+    proof_size_in_bytes: u64,
+    /// The minimum execution cost in microcredits, as (total cost, (storage cost, finalize cost)).
+    execution_cost: (u64, (u64, u64)),
+}
+
+impl<N: Network> ExecutionReport<N> {
+    /// Initializes a new execution report.
+    pub const fn new(
+        call_metrics: Vec<CallMetrics<N>>,
+        proving_time: Duration,
+        proof_size_in_bytes: u64,
+        execution_cost: (u64, (u64, u64)),
+    ) -> Self {
+        Self { call_metrics, proving_time, proof_size_in_bytes, execution_cost }
+    }
+
+    /// Returns the call metrics, for each transition in the execution.
+    pub fn call_metrics(&self) -> &[CallMetrics<N>] {
+        &self.call_metrics
+    }
+
+    /// Returns the total number of constraints proved.
+    pub fn num_constraints(&self) -> u64 {
+        self.call_metrics
+            .iter()
+            .map(|metrics| {
+                metrics
+                    .num_request_constraints
+                    .saturating_add(metrics.num_function_constraints)
+                    .saturating_add(metrics.num_response_constraints)
+            })
+            .fold(0u64, |total, num_constraints| total.saturating_add(num_constraints))
+    }
+
+    /// Returns the wall time spent executing and proving the execution.
+    pub const fn proving_time(&self) -> Duration {
+        self.proving_time
+    }
+
+    /// Returns the size of the proof in bytes.
+    pub const fn proof_size_in_bytes(&self) -> u64 {
+        self.proof_size_in_bytes
+    }
+
+    /// Returns the minimum execution cost in microcredits, which is the estimated fee without a priority fee.
+    pub const fn minimum_execution_cost(&self) -> u64 {
+        self.execution_cost.0
+    }
+
+    /// Returns the storage cost in microcredits.
+    pub const fn storage_cost(&self) -> u64 {
+        self.execution_cost.1.0
+    }
+
+    /// Returns the finalize cost in microcredits.
+    pub const fn finalize_cost(&self) -> u64 {
+        self.execution_cost.1.1
+    }
+}
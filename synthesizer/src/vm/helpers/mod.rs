@@ -12,13 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod batch_payment;
+pub use batch_payment::*;
+
 pub(crate) mod committee;
 pub use committee::*;
 
 mod cost;
 pub use cost::*;
 
+mod execution_report;
+pub use execution_report::*;
+
 mod macros;
 
+mod replay;
+pub use replay::*;
+
 mod rewards;
 pub use rewards::*;
+
+mod simulation;
+pub use simulation::*;
@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    cast_ref,
+    process,
+    vm::helpers::cost::{deployment_cost, execution_cost_in_process},
+    VM,
+};
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+    types::Field,
+};
+use ledger_block::{Deployment, Execution, Fee, Transaction};
+use ledger_store::ConsensusStorage;
+use synthesizer_process::Process;
+use synthesizer_program::Program;
+use synthesizer_snark::VerifyingKey;
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// A source of the programs and verifying keys needed to replay a transaction.
+pub trait KeyProvider<N: Network> {
+    /// Returns the program for the given program ID.
+    fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>>;
+
+    /// Returns the verifying key for the given program ID and function name.
+    fn get_verifying_key(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Result<VerifyingKey<N>>;
+}
+
+impl<N: Network, C: ConsensusStorage<N>> KeyProvider<N> for VM<N, C> {
+    /// Returns the program for the given program ID, from the process.
+    fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        self.process().read().get_program(program_id).cloned()
+    }
+
+    /// Returns the verifying key for the given program ID and function name, from the process.
+    fn get_verifying_key(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Result<VerifyingKey<N>> {
+        self.process().read().get_verifying_key(program_id, function_name)
+    }
+}
+
+/// A replay of a historical transaction, which re-verifies the transaction in a fresh process,
+/// independent of a live ledger.
+pub struct Replay<N: Network> {
+    /// The process, with the programs of the transaction.
+    process: Arc<RwLock<Process<N>>>,
+}
+
+impl<N: Network> Replay<N> {
+    /// Re-verifies the given serialized transaction against the given global state root, using the
+    /// programs and verifying keys from the given key provider, and returns the transaction.
+    ///
+    /// This verifies the transaction ID, the deployment or execution proofs, the fee proof and amount,
+    /// and that the transaction was proven against the given state root. It does not check the state of
+    /// a ledger, i.e. whether the transaction is unique, or whether a public fee payer had the balance.
+    ///
+    /// Note: A rejected transaction (i.e. `Transaction::Fee`) is bound to the ID of the rejected
+    /// deployment or execution, and is not supported.
+    pub fn verify(
+        transaction_bytes: &[u8],
+        state_root: N::StateRoot,
+        key_provider: &impl KeyProvider<N>,
+    ) -> Result<Transaction<N>> {
+        let timer = timer!("Replay::verify");
+
+        // Deserialize the transaction.
+        let transaction = Transaction::<N>::from_bytes_le(transaction_bytes)?;
+        let id = transaction.id();
+        // Ensure the transaction ID is correct.
+        ensure!(*id == transaction.to_root()?, "Incorrect transaction ID ({id})");
+
+        // Initialize a fresh process.
+        let replay = Self { process: Arc::new(RwLock::new(Process::load()?)) };
+        lap!(timer, "Initialize the process");
+
+        match &transaction {
+            Transaction::Deploy(_, owner, deployment, fee) => {
+                // Verify the owner signature.
+                let deployment_id = deployment.to_deployment_id()?;
+                ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
+                // Verify the deployment, with the imports of the program.
+                for import_id in deployment.program().imports().keys() {
+                    replay.load_program(import_id, key_provider)?;
+                }
+                replay.verify_deployment(deployment)?;
+                lap!(timer, "Verify the deployment");
+
+                // Verify the fee.
+                let (cost, _) = deployment_cost(deployment)?;
+                replay.verify_fee(fee, deployment_id, cost, state_root)?;
+            }
+            Transaction::Execute(_, execution, fee) => {
+                // Verify the execution, with the programs and verifying keys of its transitions.
+                let execution_id = execution.to_execution_id()?;
+                replay.verify_execution(execution, state_root, key_provider)?;
+                lap!(timer, "Verify the execution");
+
+                // Verify the fee, which may only be skipped for a single split.
+                let is_fee_required = !(execution.len() == 1 && transaction.contains_split());
+                match fee {
+                    Some(fee) => {
+                        let cost = match is_fee_required {
+                            true => execution_cost_in_process(&replay.process.read(), execution)?.0,
+                            false => 0,
+                        };
+                        replay.verify_fee(fee, execution_id, cost, state_root)?;
+                    }
+                    None => ensure!(!is_fee_required, "Transaction '{id}' is missing a fee (execution)"),
+                }
+            }
+            Transaction::Fee(..) => bail!("Cannot replay rejected transaction '{id}' without its rejected ID"),
+        }
+
+        finish!(timer, "Verify the fee");
+        Ok(transaction)
+    }
+
+    /// Loads the given program from the key provider, with its imports, unless it is already in the process.
+    fn load_program(&self, program_id: &ProgramID<N>, key_provider: &impl KeyProvider<N>) -> Result<()> {
+        if self.process.read().contains_program(program_id) {
+            return Ok(());
+        }
+        // Retrieve the program, and load its imports.
+        let program = key_provider.get_program(program_id)?;
+        ensure!(program.id() == program_id, "The key provider returned the wrong program for '{program_id}'");
+        for import_id in program.imports().keys() {
+            self.load_program(import_id, key_provider)?;
+        }
+        // Add the program to the process.
+        self.process.write().add_program(&program)
+    }
+
+    /// Verifies the given deployment, including its certificates.
+    fn verify_deployment(&self, deployment: &Deployment<N>) -> Result<()> {
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the deployment.
+                let deployment = cast_ref!(&deployment as Deployment<$network>);
+                // Verify the deployment.
+                $process.verify_deployment::<$aleo, _>(&deployment, &mut rand::thread_rng())
+            }};
+        }
+        process!(self, logic).map_err(|error| anyhow!("Deployment verification failed - {error}"))
+    }
+
+    /// Verifies the given execution was proven against the given state root.
+    fn verify_execution(
+        &self,
+        execution: &Execution<N>,
+        state_root: N::StateRoot,
+        key_provider: &impl KeyProvider<N>,
+    ) -> Result<()> {
+        // Ensure the execution was proven against the given state root.
+        ensure!(
+            execution.global_state_root() == state_root,
+            "Execution verification failed: global state root does not match"
+        );
+        // Load the programs and verifying keys of the transitions.
+        for transition in execution.transitions() {
+            let (program_id, function_name) = (transition.program_id(), transition.function_name());
+            self.load_program(program_id, key_provider)?;
+            let process = self.process.read();
+            if !process.get_stack(program_id)?.contains_verifying_key(function_name) {
+                process.insert_verifying_key(
+                    program_id,
+                    function_name,
+                    key_provider.get_verifying_key(program_id, function_name)?,
+                )?;
+            }
+        }
+        // Verify the execution.
+        self.process
+            .read()
+            .verify_execution(execution)
+            .map_err(|error| anyhow!("Execution verification failed: {error}"))
+    }
+
+    /// Verifies the given fee covers the given cost, and was proven against the given state root.
+    fn verify_fee(&self, fee: &Fee<N>, fee_id: Field<N>, cost: u64, state_root: N::StateRoot) -> Result<()> {
+        // Ensure the fee amount is within the limits.
+        ensure!(*fee.amount()? < N::MAX_FEE, "Fee verification failed: fee exceeds the maximum limit");
+        ensure!(*fee.base_amount()? >= cost, "Fee verification failed: requires a base fee of {cost} microcredits");
+        // Ensure the fee was proven against the given state root.
+        ensure!(fee.global_state_root() == state_root, "Fee verification failed: global state root does not match");
+        // Verify the fee.
+        self.process.read().verify_fee(fee, fee_id).map_err(|error| anyhow!("Fee verification failed: {error}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers;
+
+    type CurrentNetwork = test_helpers::CurrentNetwork;
+
+    #[test]
+    fn test_replay() {
+        let rng = &mut TestRng::default();
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let state_root = vm.block_store().current_state_root();
+
+        // Ensure an execution replays against its state root.
+        let transaction = test_helpers::sample_execution_transaction_with_private_fee(rng);
+        let bytes = transaction.to_bytes_le().unwrap();
+        assert_eq!(Replay::<CurrentNetwork>::verify(&bytes, state_root, &vm).unwrap(), transaction);
+        // Ensure the execution fails to replay against another state root.
+        assert!(Replay::<CurrentNetwork>::verify(&bytes, Uniform::rand(rng), &vm).is_err());
+        // Ensure a truncated transaction fails to replay.
+        assert!(Replay::<CurrentNetwork>::verify(&bytes[..bytes.len() - 1], state_root, &vm).is_err());
+
+        // Ensure a deployment replays against its state root.
+        let transaction = test_helpers::sample_deployment_transaction(rng);
+        let bytes = transaction.to_bytes_le().unwrap();
+        assert_eq!(Replay::<CurrentNetwork>::verify(&bytes, state_root, &vm).unwrap(), transaction);
+    }
+}
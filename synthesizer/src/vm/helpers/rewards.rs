@@ -23,6 +23,15 @@ use rayon::prelude::*;
 /// A safety bound (sanity-check) for the coinbase reward.
 const MAX_COINBASE_REWARD: u64 = ledger_block::MAX_COINBASE_REWARD; // Coinbase reward at block 1.
 
+/// Returns the block reward and puzzle reward for the given coinbase reward and transaction fees.
+/// The block reward is distributed to stakers, and the puzzle reward is distributed to provers.
+pub const fn block_and_puzzle_reward<N: Network>(coinbase_reward: u64, transaction_fees: u64) -> (u64, u64) {
+    (
+        ledger_block::block_reward(N::STARTING_SUPPLY, N::BLOCK_TIME, coinbase_reward, transaction_fees),
+        ledger_block::puzzle_reward(coinbase_reward),
+    )
+}
+
 /// Returns the updated stakers reflecting the staking rewards for the given committee and block reward.
 /// The staking reward is defined as: `block_reward * stake / total_stake`.
 ///
@@ -124,6 +133,81 @@ pub fn proving_rewards<N: Network>(
     rewards
 }
 
+/// The distribution of the rewards for a block, as applied by the ledger when the block is finalized.
+///
+/// Every amount is rounded down, so the distributed rewards never exceed the block and puzzle reward.
+/// The remainder, along with the reward of any ineligible staker, is not distributed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RewardDistribution<N: Network> {
+    /// The block reward.
+    block_reward: u64,
+    /// The puzzle reward.
+    puzzle_reward: u64,
+    /// The staking reward of each staker.
+    staking_rewards: IndexMap<Address<N>, u64>,
+    /// The proving reward of each prover.
+    proving_rewards: IndexMap<Address<N>, u64>,
+}
+
+impl<N: Network> RewardDistribution<N> {
+    /// Computes the reward distribution for the given coinbase reward, transaction fees, stakers, committee,
+    /// and prover solutions (as pairs of prover address and proof target).
+    pub fn new(
+        coinbase_reward: u64,
+        transaction_fees: u64,
+        stakers: &IndexMap<Address<N>, (Address<N>, u64)>,
+        committee: &Committee<N>,
+        proof_targets: Vec<(Address<N>, u64)>,
+    ) -> Self {
+        // Compute the block reward and puzzle reward.
+        let (block_reward, puzzle_reward) = block_and_puzzle_reward::<N>(coinbase_reward, transaction_fees);
+        // Compute the staking reward of each staker, as the increase in their stake.
+        let staking_rewards = staking_rewards(stakers, committee, block_reward)
+            .into_iter()
+            .filter_map(|(staker, (_, next_stake))| match next_stake.saturating_sub(stakers.get(&staker)?.1) {
+                0 => None,
+                reward => Some((staker, reward)),
+            })
+            .collect();
+        // Compute the proving reward of each prover.
+        let proving_rewards = proving_rewards(proof_targets, puzzle_reward);
+        Self { block_reward, puzzle_reward, staking_rewards, proving_rewards }
+    }
+
+    /// Returns the block reward.
+    pub const fn block_reward(&self) -> u64 {
+        self.block_reward
+    }
+
+    /// Returns the puzzle reward.
+    pub const fn puzzle_reward(&self) -> u64 {
+        self.puzzle_reward
+    }
+
+    /// Returns the staking reward of each staker that received one.
+    pub const fn staking_rewards(&self) -> &IndexMap<Address<N>, u64> {
+        &self.staking_rewards
+    }
+
+    /// Returns the proving reward of each prover that received one.
+    pub const fn proving_rewards(&self) -> &IndexMap<Address<N>, u64> {
+        &self.proving_rewards
+    }
+
+    /// Returns the total amount distributed to stakers and provers.
+    pub fn total_distributed(&self) -> u64 {
+        self.staking_rewards
+            .values()
+            .chain(self.proving_rewards.values())
+            .fold(0u64, |sum, reward| sum.saturating_add(*reward))
+    }
+
+    /// Returns the amount of the block and puzzle reward that is not distributed.
+    pub fn undistributed(&self) -> u64 {
+        self.block_reward.saturating_add(self.puzzle_reward).saturating_sub(self.total_distributed())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -286,6 +370,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_reward_distribution() {
+        let rng = &mut TestRng::default();
+
+        // Sample a committee.
+        let committee = ledger_committee::test_helpers::sample_committee_for_round_and_size(1, 10, rng);
+        // Convert the committee into stakers.
+        let stakers = crate::committee::test_helpers::to_stakers(committee.members());
+        // Sample the prover solutions.
+        let proof_targets = (0..5).map(|_| (Address::rand(rng), rng.gen_range(1..u64::MAX / 8))).collect::<Vec<_>>();
+
+        for _ in 0..10 {
+            // Sample a random coinbase reward and transaction fees.
+            let coinbase_reward = rng.gen_range(0..MAX_COINBASE_REWARD);
+            let transaction_fees = rng.gen_range(0..1_000_000);
+
+            // Compute the reward distribution.
+            let distribution = RewardDistribution::<CurrentNetwork>::new(
+                coinbase_reward,
+                transaction_fees,
+                &stakers,
+                &committee,
+                proof_targets.clone(),
+            );
+            // Ensure the reward distribution is deterministic.
+            let candidate =
+                RewardDistribution::new(coinbase_reward, transaction_fees, &stakers, &committee, proof_targets.clone());
+            assert_eq!(distribution, candidate);
+
+            // Ensure the rewards match the rewards applied by the ledger.
+            let (block_reward, puzzle_reward) =
+                block_and_puzzle_reward::<CurrentNetwork>(coinbase_reward, transaction_fees);
+            assert_eq!(distribution.block_reward(), block_reward);
+            assert_eq!(distribution.puzzle_reward(), puzzle_reward);
+            let next_stakers = staking_rewards(&stakers, &committee, block_reward);
+            for (staker, (_, stake)) in &stakers {
+                let reward = distribution.staking_rewards().get(staker).copied().unwrap_or_default();
+                assert_eq!(stake + reward, next_stakers[staker].1);
+            }
+            assert_eq!(distribution.proving_rewards(), &proving_rewards(proof_targets.clone(), puzzle_reward));
+
+            // Ensure the distributed rewards do not exceed the block and puzzle reward.
+            assert_eq!(distribution.total_distributed() + distribution.undistributed(), block_reward + puzzle_reward);
+        }
+    }
+
     #[test]
     fn test_proving_rewards_is_empty() {
         let rng = &mut TestRng::default();
@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, program::Value};
+use synthesizer_program::FinalizeOperation;
+
+/// The result of simulating an execution against the current state, without computing a proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Simulation<N: Network> {
+    /// The outputs of the top-level call.
+    outputs: Vec<Value<N>>,
+    /// The finalize operations that the execution would apply.
+    finalize_operations: Vec<FinalizeOperation<N>>,
+}
+
+impl<N: Network> Simulation<N> {
+    /// Initializes a new simulation.
+    pub const fn new(outputs: Vec<Value<N>>, finalize_operations: Vec<FinalizeOperation<N>>) -> Self {
+        Self { outputs, finalize_operations }
+    }
+
+    /// Returns the outputs of the top-level call.
+    pub fn outputs(&self) -> &[Value<N>] {
+        &self.outputs
+    }
+
+    /// Returns the finalize operations that the execution would apply.
+    pub fn finalize_operations(&self) -> &[FinalizeOperation<N>] {
+        &self.finalize_operations
+    }
+}
@@ -19,6 +19,7 @@ mod authorize;
 mod deploy;
 mod execute;
 mod finalize;
+mod simulate;
 mod verify;
 
 use crate::{cast_mut_ref, cast_ref, process};
@@ -55,7 +56,7 @@ use ledger_store::{
     TransactionStore,
     TransitionStore,
 };
-use synthesizer_process::{Authorization, Process, Trace};
+use synthesizer_process::{Authorization, CallMetrics, Process, Trace};
 use synthesizer_program::{FinalizeGlobalState, FinalizeOperation, FinalizeStoreTrait, Program};
 
 use aleo_std::prelude::{finish, lap, timer};
@@ -75,6 +76,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Initializes the VM from storage.
     #[inline]
     pub fn from(store: ConsensusStore<N, C>) -> Result<Self> {
+        // Roll back the latest block, if it was stored but not finalized.
+        Self::rollback_unfinalized_block(&store)?;
+
         // Initialize a new process.
         let mut process = Process::load()?;
 
@@ -135,10 +139,16 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // Retrieve the transaction store.
         let transaction_store = store.transaction_store();
-        // Load the deployments from the store.
-        for transaction_id in transaction_store.deployment_transaction_ids() {
+        // Load the latest edition of each deployed program from the store.
+        for program_id in transaction_store.program_ids() {
+            // Retrieve the transaction ID of the latest edition.
+            let Some(transaction_id) =
+                transaction_store.deployment_store().find_transaction_id_from_program_id(&program_id)?
+            else {
+                bail!("Transaction id for '{}' is not found in storage.", *program_id);
+            };
             // Load the deployment and its imports.
-            load_deployment_and_imports(&mut process, transaction_store, *transaction_id)?;
+            load_deployment_and_imports(&mut process, transaction_store, transaction_id)?;
         }
 
         // Return the new VM.
@@ -156,6 +166,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub fn process(&self) -> Arc<RwLock<Process<N>>> {
         self.process.clone()
     }
+
+    /// Returns the latest output of the randomness beacon, which is exposed to finalize scopes as `beacon.output`.
+    ///
+    /// The output is derived from the blocks, and is stored with the block that produces it,
+    /// so it is exposed to the finalize scopes of the next blocks.
+    #[inline]
+    pub fn beacon_output(&self) -> Result<Field<N>> {
+        self.finalize_store().ledger_state_store().latest_beacon_output()
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -275,6 +294,15 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Adds the given block into the VM.
     #[inline]
     pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
+        self.add_next_block_with(block, || Ok(()))
+    }
+
+    /// Adds the given block into the VM, and runs the given update in the same atomic batch as the finalize state.
+    ///
+    /// The update is for the state that the caller derives from the block, which must be written to
+    /// the finalize store (e.g. the ledger state store), so the state is only written if the block is finalized.
+    #[inline]
+    pub fn add_next_block_with(&self, block: &Block<N>, update: impl FnOnce() -> Result<()>) -> Result<()> {
         // Construct the finalize state.
         let state = FinalizeGlobalState::new::<N>(
             block.round(),
@@ -282,24 +310,62 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             block.cumulative_weight(),
             block.cumulative_proof_target(),
             block.previous_hash(),
-        )?;
+        )?
+        .with_beacon_output(self.beacon_output()?)?;
 
         // Attention: The following order is crucial because if 'finalize' fails, we can rollback the block.
         // If one first calls 'finalize', then calls 'insert(block)' and it fails, there is no way to rollback 'finalize'.
 
+        // If the process stops between the two steps, the block is rolled back when the VM is next loaded.
+
         // First, insert the block.
         self.block_store().insert(block)?;
         // Next, finalize the transactions.
-        match self.finalize(state, block.ratifications(), block.solutions(), block.transactions()) {
+        match self.finalize_with(state, block.ratifications(), block.solutions(), block.transactions(), update) {
             Ok(_ratified_finalize_operations) => Ok(()),
             Err(error) => {
                 // Rollback the block.
-                self.block_store().remove_last_n(1)?;
+                if let Err(rollback_error) = self.block_store().remove_last_n(1) {
+                    bail!("{error} (and failed to roll back block {}: {rollback_error})", block.height())
+                }
                 // Return the error.
                 Err(error)
             }
         }
     }
+
+    /// Rolls back the latest block, if it was stored but not finalized, and returns its height.
+    ///
+    /// A block is stored and then finalized in two atomic batches, so a crash between the two leaves the
+    /// latest block without its finalize state. Every finalized block records its committee in the committee
+    /// store, so the latest committee height is the height of the latest finalized block.
+    fn rollback_unfinalized_block(store: &ConsensusStore<N, C>) -> Result<Option<u32>> {
+        // Retrieve the latest block height, if there are any blocks.
+        let Some(block_height) = store.block_store().current_block_height() else {
+            return Ok(None);
+        };
+        // Retrieve the latest finalized block height, if there are any finalized blocks.
+        let finalized_height = store.finalize_store().committee_store().current_height().ok();
+
+        match finalized_height {
+            // The latest block is finalized.
+            Some(finalized_height) if finalized_height == block_height => Ok(None),
+            // The latest block is not finalized, so roll it back.
+            Some(finalized_height) if finalized_height.saturating_add(1) == block_height => {
+                store.block_store().remove_last_n(1)?;
+                Ok(Some(block_height))
+            }
+            // The genesis block is not finalized, so roll it back.
+            None if block_height == 0 => {
+                store.block_store().remove_last_n(1)?;
+                Ok(Some(block_height))
+            }
+            _ => bail!(
+                "The ledger is inconsistent - block {block_height} is stored, but the latest finalized block is {}",
+                finalized_height.map_or_else(|| "none".to_string(), |height| height.to_string())
+            ),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -901,6 +967,133 @@ function a:
         assert!(VM::from(vm.store.clone()).is_ok());
     }
 
+    #[test]
+    fn test_upgrade_program() {
+        use console::program::ProgramOwner;
+        use synthesizer_program::StackProgram;
+
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the genesis block.
+        let genesis = vm.genesis_beacon(&caller_private_key, rng).unwrap();
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Fetch the unspent records.
+        let records = genesis.transitions().cloned().flat_map(Transition::into_records).collect::<Vec<(_, _)>>();
+        let record_0 = records[0].1.decrypt(&caller_view_key).unwrap();
+        let record_1 = records[1].1.decrypt(&caller_view_key).unwrap();
+        let record_2 = records[2].1.decrypt(&caller_view_key).unwrap();
+        let record_3 = records[3].1.decrypt(&caller_view_key).unwrap();
+
+        // Deploy the first program, and a second program that imports it.
+        let program_1 = Program::<CurrentNetwork>::from_str(
+            r"
+program first_program.aleo;
+
+function c:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as u8.private;",
+        )
+        .unwrap();
+        let program_2 = Program::<CurrentNetwork>::from_str(
+            r"
+import first_program.aleo;
+
+program second_program.aleo;
+
+function b:
+    input r0 as u8.private;
+    input r1 as u8.private;
+    call first_program.aleo/c r0 r1 into r2;
+    output r2 as u8.private;",
+        )
+        .unwrap();
+        let deployment_1 = vm.deploy(&caller_private_key, &program_1, Some(record_0), 0, None, rng).unwrap();
+        let deployment_block = sample_next_block(&vm, &caller_private_key, &[deployment_1], rng).unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+        let deployment_2 = vm.deploy(&caller_private_key, &program_2, Some(record_1), 0, None, rng).unwrap();
+        let deployment_block = sample_next_block(&vm, &caller_private_key, &[deployment_2], rng).unwrap();
+        vm.add_next_block(&deployment_block).unwrap();
+
+        // Ensure the first program cannot be deployed again.
+        assert!(vm.deploy(&caller_private_key, &program_1, None, 0, None, rng).is_err());
+
+        // Upgrade the logic of the first program.
+        let upgraded =
+            Program::<CurrentNetwork>::from_str(&program_1.to_string().replace("add r0 r1", "mul r0 r1")).unwrap();
+        let upgrade = vm.upgrade(&caller_private_key, &upgraded, Some(record_2), 0, None, rng).unwrap();
+        let Transaction::Deploy(_, _, deployment, fee) = &upgrade else { panic!("Expected a deploy transaction") };
+        assert_eq!(deployment.program_edition(), 1);
+        vm.check_transaction(&upgrade, None).unwrap();
+
+        // Ensure an upgrade signed by another account is rejected.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let owner = ProgramOwner::new(&other_private_key, deployment.to_deployment_id().unwrap(), rng).unwrap();
+        let forged = Transaction::from_deployment(owner, *deployment.clone(), fee.clone()).unwrap();
+        let error = vm.check_transaction_is_unique(&forged).unwrap_err();
+        assert!(error.to_string().contains("is not signed by its owner"), "{error}");
+
+        // Add the upgrade to the ledger.
+        let upgrade_block = sample_next_block(&vm, &caller_private_key, &[upgrade.clone()], rng).unwrap();
+        vm.add_next_block(&upgrade_block).unwrap();
+        assert_eq!(vm.transaction_store().deployment_store().get_edition(upgraded.id()).unwrap(), Some(1));
+
+        // Ensure the upgrade cannot be replayed.
+        assert!(vm.check_transaction_is_unique(&upgrade).is_err());
+        // Ensure an upgrade that is incompatible with the deployed program is rejected.
+        let incompatible =
+            Program::<CurrentNetwork>::from_str(&upgraded.to_string().replace("r1 as u8", "r1 as u16")).unwrap();
+        assert!(vm.upgrade(&caller_private_key, &incompatible, Some(record_3), 0, None, rng).is_err());
+
+        // Ensure the process uses the upgraded program, including in the stack of the program that imports it.
+        let check_process = |vm: &VM<CurrentNetwork, ConsensusMemory<CurrentNetwork>>| {
+            let process = vm.process();
+            let process = process.read();
+            assert_eq!(process.get_program(upgraded.id()).unwrap(), &upgraded);
+            let stack = process.get_stack(program_2.id()).unwrap();
+            assert_eq!(stack.get_external_program(upgraded.id()).unwrap(), &upgraded);
+        };
+        check_process(&vm);
+        // Ensure the VM loads the upgraded program from storage.
+        check_process(&VM::from(vm.store.clone()).unwrap());
+    }
+
+    #[test]
+    fn test_rollback_unfinalized_block() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        // Update the VM.
+        vm.add_next_block(&genesis).unwrap();
+
+        // Store the next block without finalizing it, as if the process stopped during `add_next_block`.
+        let block = sample_next_block(&vm, &caller_private_key, &[], rng).unwrap();
+        vm.block_store().insert(&block).unwrap();
+        assert_eq!(vm.block_store().current_block_height(), Some(1));
+
+        // Check that loading the VM rolls back the block.
+        let vm = VM::from(vm.store.clone()).unwrap();
+        assert_eq!(vm.block_store().current_block_height(), Some(0));
+        // Check that the block can then be added.
+        vm.add_next_block(&block).unwrap();
+        assert_eq!(VM::<CurrentNetwork, _>::rollback_unfinalized_block(&vm.store).unwrap(), None);
+    }
+
     #[test]
     fn test_multiple_external_calls() {
         let rng = &mut TestRng::default();
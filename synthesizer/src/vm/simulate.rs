@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_block::Transition;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Simulates the given authorization against the current state, without computing a proof.
+    ///
+    /// Returns the outputs of the call and the finalize operations it would apply,
+    /// or an error if the call or its finalize logic would fail.
+    ///
+    /// Note: The finalize store is left unchanged, and the authorization is replicated,
+    /// so that it may still be executed after the simulation.
+    pub fn simulate(&self, state: FinalizeGlobalState, authorization: &Authorization<N>) -> Result<Simulation<N>> {
+        let timer = timer!("VM::simulate");
+
+        // Ensure the authorization is not for a fee.
+        ensure!(
+            !authorization.is_fee_private() && !authorization.is_fee_public(),
+            "Cannot simulate a fee authorization"
+        );
+        // Ensure the authorization has not expired, as of the given block height.
+        if authorization.max_height().is_some() {
+            authorization.check_max_height(state.block_height())?;
+        }
+
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the authorization.
+                let authorization = cast_ref!(authorization as Authorization<$network>).replicate();
+                // Execute the call, without preparing the assignments or computing the proof.
+                let (response, trace) = $process.execute::<$aleo>(authorization)?;
+                lap!(timer, "Execute the call");
+
+                // Retrieve the outputs and transitions.
+                let outputs = response.outputs().to_vec();
+                let transitions = trace.transitions().to_vec();
+
+                // Return the outputs and transitions.
+                Ok((cast_ref!(outputs as Vec<Value<N>>).clone(), cast_ref!(transitions as Vec<Transition<N>>).clone()))
+            }};
+        }
+
+        // Execute the authorization.
+        let result: Result<(Vec<Value<N>>, Vec<Transition<N>>)> = process!(self, logic);
+        let (outputs, transitions) = result?;
+        // Construct the unproven execution, as of the current state root.
+        let execution = Execution::from(transitions.into_iter(), self.block_store().current_state_root(), None)?;
+
+        // Finalize the execution on a dry run, which aborts the atomic batch on return.
+        let finalize_operations = atomic_finalize!(self.finalize_store(), FinalizeMode::DryRun, {
+            // Acquire the read lock on the process.
+            let process = self.process.read();
+            // Finalize the execution.
+            process.finalize_execution(state, self.finalize_store(), &execution, None).map_err(|e| e.to_string())
+        })?;
+        finish!(timer, "Finalize the execution");
+
+        Ok(Simulation::new(outputs, finalize_operations))
+    }
+}
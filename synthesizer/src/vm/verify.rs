@@ -32,17 +32,37 @@ macro_rules! ensure_is_unique {
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Ensures no transition in the transaction has expired, as of the block at the given height.
+    #[inline]
+    pub fn check_transaction_expiry(&self, transaction: &Transaction<N>, block_height: u32) -> Result<()> {
+        for transition in transaction.transitions() {
+            if let Some(expiry) = transition.expiry() {
+                ensure!(
+                    block_height <= expiry.max_height(),
+                    "Transition '{}' expired at block {} (block {block_height})",
+                    transition.id(),
+                    expiry.max_height()
+                );
+            }
+        }
+        Ok(())
+    }
+
     /// Verifies the transaction in the VM. On failure, returns an error.
     #[inline]
     pub fn check_transaction(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
         let timer = timer!("VM::check_transaction");
 
-        /* Transaction */
+        // Ensure the transaction does not conflict with the ledger.
+        self.check_transaction_is_unique(transaction)?;
+        lap!(timer, "Check for duplicate elements");
 
-        // Ensure the transaction ID is unique.
-        if self.block_store().contains_transaction_id(&transaction.id())? {
-            bail!("Transaction '{}' already exists in the ledger", transaction.id())
-        }
+        // Ensure no transition has expired, as of the next block.
+        let next_height = self.block_store().current_block_height().map_or(0, |height| height.saturating_add(1));
+        self.check_transaction_expiry(transaction, next_height)?;
+        lap!(timer, "Check the transition expiries");
+
+        /* Transaction */
 
         // Compute the Merkle root of the transaction.
         match transaction.to_root() {
@@ -55,6 +75,48 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         };
         lap!(timer, "Verify the transaction ID");
 
+        // First, verify the fee.
+        self.check_fee(transaction, rejected_id)?;
+
+        // Next, verify the deployment or execution.
+        match transaction {
+            Transaction::Deploy(id, owner, deployment, _) => {
+                // Compute the deployment ID.
+                let Ok(deployment_id) = deployment.to_deployment_id() else {
+                    bail!("Failed to compute the Merkle root for a deployment transaction '{id}'")
+                };
+                // Verify the signature corresponds to the transaction ID.
+                ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
+                // Ensure the edition is supported by the network.
+                if deployment.edition() > N::EDITION {
+                    bail!("Invalid deployment transaction '{id}' - expected edition at most {}", N::EDITION)
+                }
+                // Verify the deployment.
+                self.check_deployment_internal(deployment, false)?;
+            }
+            Transaction::Execute(_, execution, _) => {
+                // Verify the execution.
+                self.check_execution_internal(execution)?;
+            }
+            Transaction::Fee(..) => { /* no-op */ }
+        }
+
+        finish!(timer, "Verify the transaction");
+        Ok(())
+    }
+
+    /// Ensures the transaction does not conflict with the ledger, without verifying its proofs.
+    /// This is the part of `check_transaction` that depends on the transactions already in the ledger,
+    /// and must be rechecked for a transaction that was verified against an earlier ledger state.
+    #[inline]
+    pub fn check_transaction_is_unique(&self, transaction: &Transaction<N>) -> Result<()> {
+        /* Transaction */
+
+        // Ensure the transaction ID is unique.
+        if self.block_store().contains_transaction_id(&transaction.id())? {
+            bail!("Transaction '{}' already exists in the ledger", transaction.id())
+        }
+
         /* Transition */
 
         // Ensure the transition IDs are unique.
@@ -66,6 +128,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ensure_is_unique!("input ID", self, contains_input_id, transaction.input_ids());
         // Ensure the serial numbers are unique.
         ensure_is_unique!("serial number", self, contains_serial_number, transaction.serial_numbers());
+        // Ensure the serial numbers are nonzero, as zero is reserved for the sentinel leaf of the accumulator.
+        if transaction.serial_numbers().any(|serial_number| serial_number.is_zero()) {
+            bail!("Found a zero serial number in the transaction")
+        }
         // Ensure the tags are unique.
         ensure_is_unique!("tag", self, contains_tag, transaction.tags());
 
@@ -85,30 +151,34 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Ensure the transition commitments are unique.
         ensure_is_unique!("transition commitment", self, contains_tcm, transaction.transition_commitments());
 
-        lap!(timer, "Check for duplicate elements");
-
-        // First, verify the fee.
-        self.check_fee(transaction, rejected_id)?;
-
-        // Next, verify the deployment or execution.
         match transaction {
             Transaction::Deploy(id, owner, deployment, _) => {
-                // Compute the deployment ID.
-                let Ok(deployment_id) = deployment.to_deployment_id() else {
-                    bail!("Failed to compute the Merkle root for a deployment transaction '{id}'")
-                };
-                // Verify the signature corresponds to the transaction ID.
-                ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
-                // Ensure the edition is correct.
-                if deployment.edition() != N::EDITION {
-                    bail!("Invalid deployment transaction '{id}' - expected edition {}", N::EDITION)
-                }
-                // Ensure the program ID does not already exist..
-                if self.transaction_store().contains_program_id(deployment.program_id())? {
-                    bail!("Program ID '{}' is already deployed", deployment.program_id())
+                // Retrieve the program ID.
+                let program_id = deployment.program_id();
+                // Retrieve the deployment store.
+                let deployment_store = self.transaction_store().deployment_store();
+                // Ensure the deployment is the initial deployment, or upgrades the latest edition of the program.
+                match (deployment_store.get_edition(program_id)?, deployment.program_edition()) {
+                    (None, 0) => (),
+                    (None, _) => bail!("Transaction '{id}' upgrades program '{program_id}', which is not deployed"),
+                    (Some(_), 0) => bail!("Program ID '{program_id}' is already deployed"),
+                    (Some(latest_edition), edition) => {
+                        // Ensure the upgrade is to the next edition.
+                        ensure!(
+                            latest_edition.checked_add(1) == Some(edition),
+                            "Transaction '{id}' upgrades program '{program_id}' to edition {edition}, \
+                             but the latest edition is {latest_edition}"
+                        );
+                        // Ensure the upgrade is signed by the owner of the program.
+                        let Some(deployed_owner) = deployment_store.get_owner(program_id)? else {
+                            bail!("Failed to get the owner of program '{program_id}'")
+                        };
+                        ensure!(
+                            deployed_owner.address() == owner.address(),
+                            "Transaction '{id}' upgrades program '{program_id}', but is not signed by its owner"
+                        );
+                    }
                 }
-                // Verify the deployment.
-                self.check_deployment_internal(deployment)?;
             }
             Transaction::Execute(id, execution, _) => {
                 // Compute the execution ID.
@@ -119,13 +189,69 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if self.block_store().contains_rejected_deployment_or_execution_id(&execution_id)? {
                     bail!("Transaction '{id}' contains a previously rejected execution")
                 }
+            }
+            Transaction::Fee(..) => { /* no-op */ }
+        }
+        Ok(())
+    }
+
+    /// Verifies a transaction that is already in the ledger, e.g. to retroactively verify a block
+    /// that was added without verification. On failure, returns an error.
+    ///
+    /// Note: This omits the checks that depend on the latest state of the ledger (i.e. uniqueness and
+    /// public fee balances), and verifies a deployment against the deployed program in the process.
+    #[inline]
+    pub fn check_historical_transaction(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+    ) -> Result<()> {
+        let timer = timer!("VM::check_historical_transaction");
+
+        // Ensure the transaction ID is correct.
+        match transaction.to_root() {
+            Ok(root) if *transaction.id() != root => bail!("Incorrect transaction ID ({})", transaction.id()),
+            Ok(_) => (),
+            Err(error) => bail!("Failed to compute the Merkle root of the transaction: {error}"),
+        };
+
+        // Verify the deployment or execution, and determine the ID that the fee is bound to.
+        let (fee, fee_id) = match transaction {
+            Transaction::Deploy(id, owner, deployment, fee) => {
+                ensure!(rejected_id.is_none(), "Transaction '{id}' should not have a rejected ID (deployment)");
+                // Compute the deployment ID.
+                let Ok(deployment_id) = deployment.to_deployment_id() else {
+                    bail!("Failed to compute the Merkle root for a deployment transaction '{id}'")
+                };
+                // Verify the signature corresponds to the transaction ID.
+                ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
+                // Verify the deployment, against the deployed program.
+                self.check_deployment_internal(deployment, true)?;
+                (Some(fee), deployment_id)
+            }
+            Transaction::Execute(id, execution, fee) => {
+                ensure!(rejected_id.is_none(), "Transaction '{id}' should not have a rejected ID (execution)");
+                // Compute the execution ID.
+                let Ok(execution_id) = execution.to_execution_id() else {
+                    bail!("Failed to compute the Merkle root for an execution transaction '{id}'")
+                };
                 // Verify the execution.
                 self.check_execution_internal(execution)?;
+                (fee.as_ref(), execution_id)
             }
-            Transaction::Fee(..) => { /* no-op */ }
+            Transaction::Fee(id, fee) => match rejected_id {
+                Some(rejected_id) => (Some(fee), rejected_id),
+                None => bail!("Transaction '{id}' is missing a rejected ID (fee)"),
+            },
+        };
+        lap!(timer, "Verify the deployment or execution");
+
+        // Verify the fee.
+        if let Some(fee) = fee {
+            self.check_fee_state_root(fee, self.process.read().verify_fee(fee, fee_id))?;
         }
 
-        finish!(timer, "Verify the transaction");
+        finish!(timer, "Verify the fee");
         Ok(())
     }
 
@@ -175,6 +301,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         ensure!(*fee.base_amount()? == 0, "Transaction '{id}' has a non-zero base fee (execution)");
                     }
                     // Verify the fee.
+                    // Note: The fee proof takes the execution ID (the Merkle root of the transition IDs) as a
+                    // public input, so the signed fee binds the transitions, and a relay cannot add, remove,
+                    // or reorder a transition without invalidating the fee.
                     self.check_fee_internal(fee, execution_id)?;
                 } else {
                     // Ensure the fee can be safely skipped.
@@ -197,18 +326,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
-    /// Verifies the given deployment. On failure, returns an error.
+    /// Verifies the given deployment. If `is_deployed`, the program is already in the process
+    /// (i.e. the deployment is in the ledger), and the deployment is verified against it. On failure, returns an error.
     ///
     /// Note: This is an internal check only. To ensure all components of the deployment are checked,
     /// use `VM::check_transaction` instead.
     #[inline]
-    fn check_deployment_internal(&self, deployment: &Deployment<N>) -> Result<()> {
+    fn check_deployment_internal(&self, deployment: &Deployment<N>, is_deployed: bool) -> Result<()> {
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Prepare the deployment.
                 let deployment = cast_ref!(&deployment as Deployment<$network>);
                 // Verify the deployment.
-                $process.verify_deployment::<$aleo, _>(&deployment, &mut rand::thread_rng())
+                match is_deployed {
+                    true => $process.verify_existing_deployment::<$aleo, _>(&deployment, &mut rand::thread_rng()),
+                    false => $process.verify_deployment::<$aleo, _>(&deployment, &mut rand::thread_rng()),
+                }
             }};
         }
 
@@ -284,16 +417,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         }
 
         // Ensure the global state root exists in the block store.
-        let result = match verification {
+        let result = self.check_fee_state_root(fee, verification);
+        finish!(timer, "Check the global state root");
+        result
+    }
+
+    /// Ensures the given fee verification succeeded, and the global state root of the fee exists in the block store.
+    #[inline]
+    fn check_fee_state_root(&self, fee: &Fee<N>, verification: Result<()>) -> Result<()> {
+        match verification {
             Ok(()) => match self.block_store().contains_state_root(&fee.global_state_root()) {
                 Ok(true) => Ok(()),
                 Ok(false) => bail!("Fee verification failed: global state root not found"),
                 Err(error) => bail!("Fee verification failed: {error}"),
             },
             Err(error) => bail!("Fee verification failed: {error}"),
-        };
-        finish!(timer, "Check the global state root");
-        result
+        }
     }
 }
 
@@ -343,12 +482,12 @@ mod tests {
         let deployment = vm.deploy_raw(&program, rng).unwrap();
 
         // Ensure the deployment is valid.
-        vm.check_deployment_internal(&deployment).unwrap();
+        vm.check_deployment_internal(&deployment, false).unwrap();
 
         // Ensure that deserialization doesn't break the transaction verification.
         let serialized_deployment = deployment.to_string();
         let deployment_transaction: Deployment<CurrentNetwork> = serde_json::from_str(&serialized_deployment).unwrap();
-        vm.check_deployment_internal(&deployment_transaction).unwrap();
+        vm.check_deployment_internal(&deployment_transaction, false).unwrap();
     }
 
     #[test]
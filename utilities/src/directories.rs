@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+/// The root directory set for the whole process, which takes precedence over the platform default.
+static ROOT_OVERRIDE: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// The directories used to cache parameters and keys, and to store the ledger.
+///
+/// By default, the root is the platform data directory:
+/// - Linux: `$XDG_DATA_HOME/aleo`, or `~/.local/share/aleo`
+/// - macOS: `~/Library/Application Support/Aleo`
+/// - Windows: `%APPDATA%\Aleo`
+///
+/// If the legacy `~/.aleo` directory exists, it is used instead, so existing caches and ledgers are kept.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Directories {
+    /// The root directory.
+    root: PathBuf,
+}
+
+impl Directories {
+    /// Initializes the directories at the given root.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Returns the directories at the root set by [`Directories::set_root`], or the platform default.
+    pub fn current() -> Self {
+        match ROOT_OVERRIDE.read().ok().and_then(|root| root.clone()) {
+            Some(root) => Self::new(root),
+            None => Self::platform_default(),
+        }
+    }
+
+    /// Sets the root returned by [`Directories::current`] for the whole process, or restores the platform default.
+    pub fn set_root(root: Option<PathBuf>) {
+        if let Ok(mut root_override) = ROOT_OVERRIDE.write() {
+            *root_override = root;
+        }
+    }
+
+    /// Returns the directories at the platform default root.
+    pub fn platform_default() -> Self {
+        let home = home_dir();
+        // Prefer the legacy directory, if it exists.
+        if let Some(legacy) = home.as_ref().map(|home| home.join(".aleo")).filter(|legacy| legacy.exists()) {
+            return Self::new(legacy);
+        }
+        match platform_data_dir(home.as_deref()) {
+            Some(root) => Self::new(root),
+            None => Self::new(".aleo"),
+        }
+    }
+
+    /// Returns the root directory.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the directory of the cached parameters, including the SRS and the `credits.aleo` keys.
+    pub fn resources_dir(&self) -> PathBuf {
+        self.root.join("resources")
+    }
+
+    /// Returns the directory of the ledger storage for the given network.
+    pub fn ledger_dir(&self, network_id: u16) -> PathBuf {
+        self.root.join("storage").join(format!("ledger-{network_id}"))
+    }
+}
+
+/// Returns the home directory of the current user, if it is known.
+fn home_dir() -> Option<PathBuf> {
+    let variable = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+    std::env::var_os(variable).filter(|home| !home.is_empty()).map(PathBuf::from)
+}
+
+/// Returns the platform data directory for Aleo, if it is known.
+fn platform_data_dir(home: Option<&Path>) -> Option<PathBuf> {
+    if cfg!(target_os = "macos") {
+        home.map(|home| home.join("Library").join("Application Support").join("Aleo"))
+    } else if cfg!(windows) {
+        std::env::var_os("APPDATA")
+            .filter(|appdata| !appdata.is_empty())
+            .map(|appdata| PathBuf::from(appdata).join("Aleo"))
+    } else {
+        match std::env::var_os("XDG_DATA_HOME").filter(|data_home| !data_home.is_empty()) {
+            Some(data_home) => Some(PathBuf::from(data_home).join("aleo")),
+            None => home.map(|home| home.join(".local").join("share").join("aleo")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_directories() {
+        let directories = Directories::new("/tmp/aleo");
+        assert_eq!(directories.root(), Path::new("/tmp/aleo"));
+        assert_eq!(directories.resources_dir(), Path::new("/tmp/aleo/resources"));
+        assert_eq!(directories.ledger_dir(3), Path::new("/tmp/aleo/storage/ledger-3"));
+
+        // Check that the root override takes precedence over the platform default.
+        Directories::set_root(Some(PathBuf::from("/tmp/aleo")));
+        assert_eq!(Directories::current(), directories);
+        Directories::set_root(None);
+        assert_eq!(Directories::current(), Directories::platform_default());
+    }
+}
@@ -55,6 +55,11 @@ pub use bits::*;
 pub mod bytes;
 pub use bytes::*;
 
+#[cfg(feature = "std")]
+pub mod directories;
+#[cfg(feature = "std")]
+pub use directories::*;
+
 pub mod error;
 pub use error::*;
 
@@ -68,6 +73,9 @@ pub use parallel::*;
 pub mod rand;
 pub use self::rand::*;
 
+pub mod sampling;
+pub use sampling::*;
+
 pub mod serialize;
 pub use serialize::*;
 
@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Vec;
+
+use anyhow::{ensure, Result};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+/// A deterministic random number generator for consensus, such as shuffling or sampling a committee.
+///
+/// Every validator that seeds the generator with the same 32 bytes observes the same stream,
+/// which is the ChaCha20 keystream under the seed (with a zero nonce), read as little-endian `u64` words.
+/// All sampling in this module is defined in terms of [`ConsensusRng::next_below`], so it is portable
+/// across platforms and does not depend on the sampling algorithms of the `rand` crate.
+pub struct ConsensusRng(ChaCha20Rng);
+
+impl ConsensusRng {
+    /// Initializes the generator from the given seed.
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        Self(ChaCha20Rng::from_seed(seed))
+    }
+
+    /// Returns the next `u64` word of the stream.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    /// Returns a uniformly-sampled integer in `[0, bound)`, without modulo bias.
+    ///
+    /// Words are drawn from the stream until one is below `zone = (u64::MAX / bound) * bound`,
+    /// and the result is that word modulo `bound`. Words at or above `zone` are discarded.
+    pub fn next_below(&mut self, bound: u64) -> Result<u64> {
+        ensure!(bound > 0, "Cannot sample below a bound of zero");
+        // Compute the largest multiple of the bound that fits in a word.
+        let zone = (u64::MAX / bound) * bound;
+        loop {
+            let word = self.next_u64();
+            if word < zone {
+                return Ok(word % bound);
+            }
+        }
+    }
+}
+
+/// Shuffles the given items in place, with a Fisher-Yates shuffle.
+///
+/// For each index `i` from `len - 1` down to `1`, the item at `i` is swapped with the item at `rng.next_below(i + 1)`.
+/// The result depends on the order of the given items, so callers must provide them in a canonical order.
+pub fn shuffle<T>(items: &mut [T], rng: &mut ConsensusRng) -> Result<()> {
+    for i in (1..items.len()).rev() {
+        let j = rng.next_below(i as u64 + 1)?;
+        items.swap(i, j as usize);
+    }
+    Ok(())
+}
+
+/// Samples the given number of distinct items, with a probability proportional to their weight, such as their stake.
+///
+/// Each draw samples `r = rng.next_below(total)`, where `total` is the sum of the weights of the remaining items,
+/// and selects the first remaining item whose cumulative weight exceeds `r`. The selected item is removed
+/// (preserving the order of the remaining items), before the next draw. Items with a weight of zero are never selected.
+/// The result depends on the order of the given items, so callers must provide them in a canonical order.
+pub fn sample_weighted<T: Clone>(items: &[(T, u64)], num_samples: usize, rng: &mut ConsensusRng) -> Result<Vec<T>> {
+    // Retain the items with a nonzero weight.
+    let mut remaining = items.iter().filter(|(_, weight)| *weight > 0).collect::<Vec<_>>();
+    ensure!(
+        num_samples <= remaining.len(),
+        "Cannot sample {num_samples} items from {} items with a nonzero weight",
+        remaining.len()
+    );
+    // Compute the total weight, ensuring it does not overflow.
+    let mut total = remaining
+        .iter()
+        .try_fold(0u64, |total, (_, weight)| total.checked_add(*weight))
+        .ok_or_else(|| anyhow::anyhow!("The total weight overflowed"))?;
+
+    let mut samples = Vec::with_capacity(num_samples);
+    for _ in 0..num_samples {
+        // Sample a point in the cumulative weight.
+        let point = rng.next_below(total)?;
+        // Find the first item whose cumulative weight exceeds the point.
+        let mut cumulative = 0u64;
+        let index = remaining
+            .iter()
+            .position(|(_, weight)| {
+                cumulative += weight;
+                cumulative > point
+            })
+            .ok_or_else(|| anyhow::anyhow!("The sampled weight is out of range"))?;
+        // Select the item, and remove it from the remaining items.
+        let (item, weight) = remaining.remove(index);
+        total -= weight;
+        samples.push(item.clone());
+    }
+    Ok(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ITERATIONS: usize = 1000;
+
+    #[test]
+    fn test_next_below() {
+        let mut rng = ConsensusRng::from_seed([7u8; 32]);
+        // Ensure a bound of zero is rejected.
+        assert!(rng.next_below(0).is_err());
+        // Ensure the samples are within the bound.
+        for bound in [1, 2, 3, 10, u64::MAX / 2 + 2, u64::MAX] {
+            for _ in 0..ITERATIONS {
+                assert!(rng.next_below(bound).unwrap() < bound);
+            }
+        }
+    }
+
+    #[test]
+    fn test_shuffle_is_deterministic() {
+        let items = (0..100u32).collect::<Vec<_>>();
+
+        // Ensure the same seed yields the same permutation.
+        let mut first = items.clone();
+        shuffle(&mut first, &mut ConsensusRng::from_seed([1u8; 32])).unwrap();
+        let mut second = items.clone();
+        shuffle(&mut second, &mut ConsensusRng::from_seed([1u8; 32])).unwrap();
+        assert_eq!(first, second);
+        // Ensure a different seed yields a different permutation.
+        let mut third = items.clone();
+        shuffle(&mut third, &mut ConsensusRng::from_seed([2u8; 32])).unwrap();
+        assert_ne!(first, third);
+
+        // Ensure the shuffle is a permutation.
+        first.sort_unstable();
+        assert_eq!(first, items);
+    }
+
+    #[test]
+    fn test_shuffle_is_uniform() {
+        // Count how often each of the 6 permutations of 3 items occurs.
+        let mut rng = ConsensusRng::from_seed([3u8; 32]);
+        let mut counts = std::collections::HashMap::new();
+        for _ in 0..6 * ITERATIONS {
+            let mut items = [0u8, 1, 2];
+            shuffle(&mut items, &mut rng).unwrap();
+            *counts.entry(items).or_insert(0usize) += 1;
+        }
+        // Ensure every permutation occurs close to its expected count.
+        assert_eq!(counts.len(), 6);
+        for count in counts.values() {
+            assert!((800..1200).contains(count), "{counts:?}");
+        }
+    }
+
+    #[test]
+    fn test_sample_weighted() {
+        let items = [("a", 1u64), ("b", 0), ("c", 3), ("d", 6)];
+
+        // Ensure the same seed yields the same sample.
+        let first = sample_weighted(&items, 2, &mut ConsensusRng::from_seed([4u8; 32])).unwrap();
+        let second = sample_weighted(&items, 2, &mut ConsensusRng::from_seed([4u8; 32])).unwrap();
+        assert_eq!(first, second);
+
+        // Ensure the items are distinct, and an item with a weight of zero is never selected.
+        let all = sample_weighted(&items, 3, &mut ConsensusRng::from_seed([5u8; 32])).unwrap();
+        assert_eq!(all.len(), 3);
+        assert!(!all.contains(&"b"));
+        assert!(all.contains(&"a") && all.contains(&"c") && all.contains(&"d"));
+        // Ensure sampling more items than have a nonzero weight is rejected.
+        assert!(sample_weighted(&items, 4, &mut ConsensusRng::from_seed([5u8; 32])).is_err());
+
+        // Ensure a single draw is proportional to the weights.
+        let mut rng = ConsensusRng::from_seed([6u8; 32]);
+        let num_d = (0..10 * ITERATIONS).filter(|_| sample_weighted(&items, 1, &mut rng).unwrap() == ["d"]).count();
+        assert!((5_500..6_500).contains(&num_d), "{num_d}");
+
+        // Ensure an overflowing total weight is rejected.
+        assert!(sample_weighted(&[("a", u64::MAX), ("b", 1)], 1, &mut rng).is_err());
+    }
+}
@@ -31,19 +31,22 @@ impl Build {
         // Derive the program directory path.
         let path = std::env::current_dir()?;
 
+        // Load the settings, enabling offline mode if requested.
+        let config = match self.offline {
+            true => VmConfig::from_env()?.with_offline(true),
+            false => VmConfig::from_env()?,
+        };
+        // Use the configured HTTP client settings for the outbound requests.
+        HttpConfig::from_env()?.apply()?;
+
         // Load the package.
-        let package = Package::open(&path)?;
+        let package = Package::open(&path)?.with_config(config);
 
         println!("⚠️  Attention - This command is deprecated. Use the {} command.\n", "'run'".to_string().bold());
 
         // Build the package, if the package requires building.
         package.build::<Aleo>(self.endpoint)?;
 
-        // package.build::<Aleo>(match self.offline {
-        //     true => None,
-        //     false => Some(endpoint.unwrap_or("https://vm.aleo.org/testnet3/build".to_string())),
-        // })?;
-
         // Prepare the path string.
         let path_string = format!("(in \"{}\")", path.display());
 
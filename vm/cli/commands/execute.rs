@@ -21,9 +21,12 @@ pub struct Execute {
     function: Identifier<CurrentNetwork>,
     /// The function inputs.
     inputs: Vec<Value<CurrentNetwork>>,
-    /// Uses the specified endpoint.
-    #[clap(default_value = "https://api.explorer.aleo.org/v1", long)]
-    endpoint: String,
+    /// Uses the specified endpoint, instead of the endpoint in the settings.
+    #[clap(long)]
+    endpoint: Option<String>,
+    /// Loads the settings from the specified TOML file, instead of the environment.
+    #[clap(long)]
+    config: Option<PathBuf>,
     /// Toggles offline mode.
     #[clap(long)]
     offline: bool,
@@ -36,8 +39,25 @@ impl Execute {
         // Derive the program directory path.
         let path = std::env::current_dir()?;
 
+        // Load the settings.
+        let config = match self.config {
+            Some(config) => VmConfig::load(config)?,
+            None => VmConfig::from_env()?,
+        };
+        // Enable offline mode, if requested.
+        let config = match self.offline {
+            true => config.with_offline(true),
+            false => config,
+        };
+        // Use the configured data directory for the parameter caches.
+        config.apply_directories();
+        // Use the configured HTTP client settings for the outbound requests.
+        HttpConfig::from_env()?.apply()?;
+        // Retrieve the endpoint.
+        let endpoint = self.endpoint.unwrap_or_else(|| config.endpoint().to_string());
+
         // Load the package.
-        let package = Package::open(&path)?;
+        let package = Package::open(&path)?.with_config(config);
         // Load the private key.
         let private_key = crate::cli::helpers::dotenv_private_key()?;
 
@@ -46,7 +66,7 @@ impl Execute {
 
         // Execute the request.
         let (response, execution, metrics) =
-            package.execute::<Aleo, _>(self.endpoint, &private_key, self.function, &self.inputs, rng)?;
+            package.execute::<Aleo, _>(endpoint, &private_key, self.function, &self.inputs, rng)?;
 
         // TODO (howardwu): Include the option to execute a fee.
         let fee = None;
@@ -32,15 +32,17 @@ pub use update::*;
 
 use crate::{
     console::program::{Identifier, Locator, ProgramID, Value},
-    ledger::block::Transaction,
+    file::Template,
+    ledger::{block::Transaction, query::HttpConfig},
     package::Package,
+    synthesizer::process::VmConfig,
 };
 
 use anyhow::Result;
 use clap::Parser;
 use colored::Colorize;
 use core::str::FromStr;
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
 
 pub const LOCALE: &num_format::Locale = &num_format::Locale::en;
 
@@ -19,6 +19,9 @@ use super::*;
 pub struct New {
     /// The program name.
     name: String,
+    /// The starter program ('empty', 'example', 'token', or 'nft').
+    #[clap(default_value = "example", short, long)]
+    template: Template,
 }
 
 impl New {
@@ -32,11 +35,16 @@ impl New {
         let id = ProgramID::<CurrentNetwork>::from_str(&format!("{}.aleo", self.name))?;
 
         // Create the package.
-        Package::create(&path, &id)?;
+        Package::create_with_template(&path, &id, self.template)?;
 
         // Prepare the path string.
         let path_string = format!("(in \"{}\")", path.display());
 
-        Ok(format!("✅ Created an Aleo program '{}' {}", self.name.bold(), path_string.dimmed()))
+        Ok(format!(
+            "✅ Created an Aleo program '{}' from the '{}' template {}",
+            self.name.bold(),
+            self.template,
+            path_string.dimmed()
+        ))
     }
 }
@@ -0,0 +1,222 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A data-driven conformance suite for the protocol rules of this crate.
+//!
+//! A suite is a JSON file of vectors, each recording the expected output of a protocol rule
+//! (key derivation, literal encoding, hashes, commitments, serial numbers, and signature verification)
+//! on the given inputs. Suites are generated from this crate's own primitives with [`Suite::generate`],
+//! and alternative implementations run them with [`Suite::run`] to check that they agree on every rule.
+//! [`Suite::negative`] generates invalid and malicious inputs, which every implementation must reject.
+
+mod negative;
+mod reference;
+mod vector;
+
+pub use reference::{Implementation, Reference};
+pub use vector::{Rule, Vector};
+
+use crate::console::{
+    account::{Address, PrivateKey, Signature, ViewKey},
+    network::Network,
+    prelude::*,
+    program::{Ciphertext, Identifier, Literal, LiteralType, Plaintext, ProgramID, Record},
+    types::{Field, Group, Scalar},
+};
+
+use core::marker::PhantomData;
+use std::path::Path;
+
+/// A conformance suite, which is a list of vectors for the protocol rules of a network.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Suite {
+    /// The network ID the vectors were generated for.
+    network: u16,
+    /// The vectors.
+    vectors: Vec<Vector>,
+}
+
+impl Suite {
+    /// Initializes a new conformance suite.
+    pub const fn new(network: u16, vectors: Vec<Vector>) -> Self {
+        Self { network, vectors }
+    }
+
+    /// Generates a conformance suite with the given number of vectors per rule, using the reference implementation.
+    pub fn generate<N: Network, R: Rng + CryptoRng>(num_vectors_per_rule: usize, rng: &mut R) -> Result<Self> {
+        let reference = Reference::<N>::new();
+        let mut vectors = Vec::with_capacity(Rule::ALL.len() * num_vectors_per_rule);
+        for rule in Rule::ALL {
+            for _ in 0..num_vectors_per_rule {
+                // Sample the inputs, and record the output of the reference implementation.
+                let inputs = Reference::<N>::sample_inputs(rule, rng)?;
+                let output = reference.evaluate(rule, &inputs)?;
+                vectors.push(Vector::new(rule, inputs, output));
+            }
+        }
+        Ok(Self::new(N::ID, vectors))
+    }
+
+    /// Opens the conformance suite at the given path.
+    pub fn open(path: &Path) -> Result<Self> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Writes the conformance suite to the given path.
+    pub fn write_to(&self, path: &Path) -> Result<()> {
+        Ok(std::fs::write(path, serde_json::to_string_pretty(self)?)?)
+    }
+
+    /// Returns the network ID the vectors were generated for.
+    pub const fn network(&self) -> u16 {
+        self.network
+    }
+
+    /// Returns the vectors.
+    pub fn vectors(&self) -> &[Vector] {
+        &self.vectors
+    }
+
+    /// Runs every vector in the suite against the given implementation, in order, and returns their reports.
+    pub fn run(&self, implementation: &impl Implementation) -> Vec<ConformanceReport> {
+        self.vectors
+            .iter()
+            .enumerate()
+            .map(|(index, vector)| {
+                let error = match (implementation.evaluate(vector.rule(), vector.inputs()), vector.output()) {
+                    (Ok(output), Some(expected)) if output == expected => None,
+                    (Ok(output), Some(expected)) => Some(format!("Output is '{output}', expected '{expected}'")),
+                    (Err(error), Some(_)) => Some(error.to_string()),
+                    // Vectors without an output expect the inputs to be rejected.
+                    (Ok(output), None) => Some(format!("Output is '{output}', expected the inputs to be rejected")),
+                    (Err(_), None) => None,
+                };
+                ConformanceReport { index, rule: vector.rule(), error }
+            })
+            .collect()
+    }
+}
+
+impl Serialize for Suite {
+    /// Serializes the conformance suite into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut suite = serializer.serialize_struct("Suite", 2)?;
+        suite.serialize_field("network", &self.network)?;
+        suite.serialize_field("vectors", &self.vectors)?;
+        suite.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Suite {
+    /// Deserializes the conformance suite from a JSON object.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the suite from a string into a value.
+        let mut suite = serde_json::Value::deserialize(deserializer)?;
+        Ok(Self::new(
+            // Retrieve the network ID.
+            DeserializeExt::take_from_value::<D>(&mut suite, "network")?,
+            // Retrieve the vectors.
+            DeserializeExt::take_from_value::<D>(&mut suite, "vectors")?,
+        ))
+    }
+}
+
+/// The result of running a conformance vector against an implementation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConformanceReport {
+    /// The index of the vector in the suite.
+    index: usize,
+    /// The rule of the vector.
+    rule: Rule,
+    /// The reason the vector failed, if it did.
+    error: Option<String>,
+}
+
+impl ConformanceReport {
+    /// Returns the index of the vector in the suite.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the rule of the vector.
+    pub const fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Returns the reason the vector failed, if it did.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns `true` if the implementation agreed with the vector.
+    pub const fn is_passed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_generate_and_run() {
+        let rng = &mut TestRng::default();
+
+        // Generate a suite, and ensure it covers every rule.
+        let suite = Suite::generate::<CurrentNetwork, _>(2, rng).unwrap();
+        assert_eq!(suite.network(), CurrentNetwork::ID);
+        assert_eq!(suite.vectors().len(), 2 * Rule::ALL.len());
+
+        // Ensure the suite round-trips through a file.
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("conformance.json");
+        suite.write_to(&path).unwrap();
+        let candidate = Suite::open(&path).unwrap();
+        assert_eq!(suite, candidate);
+
+        // Ensure the reference implementation passes every vector.
+        let reports = candidate.run(&Reference::<CurrentNetwork>::new());
+        assert!(reports.iter().all(|report| report.is_passed()), "{reports:?}");
+    }
+
+    #[test]
+    fn test_run_detects_disagreement() {
+        /// An implementation that returns the wrong output for BHP256 hashes.
+        struct Faulty;
+
+        impl Implementation for Faulty {
+            fn evaluate(&self, rule: Rule, inputs: &[String]) -> Result<String> {
+                match rule {
+                    Rule::HashBHP256 => Ok(Field::<CurrentNetwork>::zero().to_string()),
+                    rule => Reference::<CurrentNetwork>::new().evaluate(rule, inputs),
+                }
+            }
+        }
+
+        let rng = &mut TestRng::default();
+        let suite = Suite::generate::<CurrentNetwork, _>(1, rng).unwrap();
+
+        // Ensure only the BHP256 vector fails.
+        let failed = suite.run(&Faulty).into_iter().filter(|report| !report.is_passed()).collect::<Vec<_>>();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].rule(), Rule::HashBHP256);
+
+        // Ensure vectors with the wrong number of inputs are rejected.
+        let suite = Suite::new(CurrentNetwork::ID, vec![Vector::new(Rule::Address, vec![], String::new())]);
+        assert!(!suite.run(&Reference::<CurrentNetwork>::new())[0].is_passed());
+    }
+}
@@ -0,0 +1,152 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl Suite {
+    /// Generates a conformance suite of invalid and malicious inputs, which a conforming implementation rejects.
+    ///
+    /// The suite covers truncated and malformed signatures and record ciphertexts, points outside the
+    /// prime-order subgroup, non-canonical (overflowing) scalar encodings, and malleated signatures.
+    /// A malleated signature still parses, so its vector expects `false` rather than a rejection.
+    pub fn negative<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<Self> {
+        let mut vectors = Vec::new();
+
+        // Signatures //
+
+        // Sample a valid signature.
+        let private_key = PrivateKey::<N>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let message = Plaintext::<N>::from(Literal::Field(Field::rand(rng)));
+        let signature = Signature::sign(&private_key, &message.to_fields()?, rng)?;
+        let signature_bytes = signature.to_bytes_le()?;
+        // Constructs the vector inputs for the given signature bytes.
+        let signature_inputs = |bytes: &[u8]| -> Result<Vec<String>> {
+            Ok(vec![address.to_string(), message.to_string(), encode_bech32m("sign", bytes)?])
+        };
+
+        // A truncated signature.
+        let bytes = &signature_bytes[..signature_bytes.len() - 1];
+        vectors.push(Vector::rejected(Rule::VerifySignature, signature_inputs(bytes)?));
+        // A signature with an unsupported variant tag, in the most significant bits of the challenge.
+        let mut bytes = signature_bytes.clone();
+        bytes[SCALAR_SIZE_IN_BYTES - 1] |= 1 << 4;
+        vectors.push(Vector::rejected(Rule::VerifySignature, signature_inputs(&bytes)?));
+        // A signature with an overflowing challenge, which exceeds the scalar field modulus.
+        let mut bytes = signature_bytes.clone();
+        bytes[..SCALAR_SIZE_IN_BYTES].copy_from_slice(&overflowing_scalar_bytes());
+        vectors.push(Vector::rejected(Rule::VerifySignature, signature_inputs(&bytes)?));
+        // A signature with an overflowing response, which exceeds the scalar field modulus.
+        let mut bytes = signature_bytes.clone();
+        bytes[SCALAR_SIZE_IN_BYTES..2 * SCALAR_SIZE_IN_BYTES].copy_from_slice(&overflowing_scalar_bytes());
+        vectors.push(Vector::rejected(Rule::VerifySignature, signature_inputs(&bytes)?));
+        // A signature with a compute key point outside the prime-order subgroup.
+        let mut bytes = signature_bytes.clone();
+        let x_coordinate = sample_non_subgroup_x_coordinate::<N, R>(rng).to_bytes_le()?;
+        bytes[2 * SCALAR_SIZE_IN_BYTES..3 * SCALAR_SIZE_IN_BYTES].copy_from_slice(&x_coordinate);
+        vectors.push(Vector::rejected(Rule::VerifySignature, signature_inputs(&bytes)?));
+        // A malleated signature, whose response is shifted by one.
+        let mut bytes = signature_bytes.clone();
+        let response = Scalar::<N>::read_le(&bytes[SCALAR_SIZE_IN_BYTES..2 * SCALAR_SIZE_IN_BYTES])? + Scalar::one();
+        bytes[SCALAR_SIZE_IN_BYTES..2 * SCALAR_SIZE_IN_BYTES].copy_from_slice(&response.to_bytes_le()?);
+        vectors.push(Vector::new(Rule::VerifySignature, signature_inputs(&bytes)?, false.to_string()));
+        // A signature for an address outside the prime-order subgroup.
+        let x_coordinate = sample_non_subgroup_x_coordinate::<N, R>(rng).to_bytes_le()?;
+        let inputs = vec![encode_bech32m("aleo", &x_coordinate)?, message.to_string(), signature.to_string()];
+        vectors.push(Vector::rejected(Rule::VerifySignature, inputs));
+
+        // Encryption //
+
+        // Sample a valid record ciphertext.
+        let inputs = Reference::<N>::sample_inputs(Rule::DecryptRecord, rng)?;
+        let (view_key, ciphertext) = (inputs[0].clone(), Record::<N, Ciphertext<N>>::from_str(&inputs[1])?);
+        let ciphertext_bytes = ciphertext.to_bytes_le()?;
+
+        // A truncated record ciphertext.
+        let bytes = &ciphertext_bytes[..ciphertext_bytes.len() - 1];
+        vectors.push(Vector::rejected(Rule::DecryptRecord, vec![view_key.clone(), encode_bech32m("record", bytes)?]));
+        // A record ciphertext with a nonce outside the prime-order subgroup, as the nonce is encoded last.
+        let mut bytes = ciphertext_bytes.clone();
+        let nonce_offset = bytes.len() - FIELD_SIZE_IN_BYTES;
+        bytes[nonce_offset..].copy_from_slice(&sample_non_subgroup_x_coordinate::<N, R>(rng).to_bytes_le()?);
+        vectors.push(Vector::rejected(Rule::DecryptRecord, vec![view_key, encode_bech32m("record", &bytes)?]));
+        // A record ciphertext decrypted with the view key of another account.
+        let view_key = ViewKey::try_from(PrivateKey::<N>::new(rng)?)?;
+        vectors.push(Vector::rejected(Rule::DecryptRecord, vec![view_key.to_string(), ciphertext.to_string()]));
+
+        // Literals //
+
+        // A group literal outside the prime-order subgroup.
+        let x_coordinate = sample_non_subgroup_x_coordinate::<N, R>(rng);
+        vectors.push(Vector::rejected(Rule::LiteralBytes, vec![format!("{}group", *x_coordinate)]));
+
+        Ok(Self::new(N::ID, vectors))
+    }
+}
+
+/// The number of bytes in an encoded scalar.
+const SCALAR_SIZE_IN_BYTES: usize = 32;
+/// The number of bytes in an encoded field element.
+const FIELD_SIZE_IN_BYTES: usize = 32;
+
+/// Returns the encoding of `2^252 - 1`, which exceeds the scalar field modulus, but leaves the variant tag bits unset.
+fn overflowing_scalar_bytes() -> [u8; SCALAR_SIZE_IN_BYTES] {
+    let mut bytes = [u8::MAX; SCALAR_SIZE_IN_BYTES];
+    bytes[SCALAR_SIZE_IN_BYTES - 1] = 0x0f;
+    bytes
+}
+
+/// Samples the x-coordinate of a point on the curve that is outside the prime-order subgroup.
+fn sample_non_subgroup_x_coordinate<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Field<N> {
+    loop {
+        let x_coordinate = Field::<N>::rand(rng);
+        // Note: A point and its negation are either both in the subgroup, or both outside it.
+        if let Some(point) = N::Affine::from_x_coordinate(*x_coordinate, true) {
+            if !point.is_in_correct_subgroup_assuming_on_curve() {
+                return x_coordinate;
+            }
+        }
+    }
+}
+
+/// Encodes the given bytes as a bech32m string with the given prefix.
+fn encode_bech32m(prefix: &str, bytes: &[u8]) -> Result<String> {
+    Ok(bech32::encode(prefix, bytes.to_base32(), bech32::Variant::Bech32m)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_negative() {
+        let rng = &mut TestRng::default();
+
+        // Generate the negative suite.
+        let suite = Suite::negative::<CurrentNetwork, _>(rng).unwrap();
+        // Ensure every vector, besides the malleated signature, expects a rejection.
+        assert_eq!(suite.vectors().iter().filter(|vector| vector.output().is_some()).count(), 1);
+
+        // Ensure the suite round-trips through JSON.
+        let candidate: Suite = serde_json::from_str(&serde_json::to_string(&suite).unwrap()).unwrap();
+        assert_eq!(suite, candidate);
+
+        // Ensure the reference implementation rejects every vector.
+        let reports = candidate.run(&Reference::<CurrentNetwork>::new());
+        assert!(reports.iter().all(|report| report.is_passed()), "{reports:?}");
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An implementation of the protocol rules, which a conformance suite is run against.
+///
+/// Alternative implementations implement this trait (or an equivalent over the JSON vectors)
+/// to check that they agree with this crate on every rule in a suite.
+pub trait Implementation {
+    /// Evaluates the given rule on the given inputs, and returns the output in its canonical string form.
+    fn evaluate(&self, rule: Rule, inputs: &[String]) -> Result<String>;
+}
+
+/// The reference implementation of the protocol rules, which evaluates each rule with this crate's primitives.
+pub struct Reference<N: Network>(PhantomData<N>);
+
+impl<N: Network> Reference<N> {
+    /// Initializes the reference implementation.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<N: Network> Default for Reference<N> {
+    /// Initializes the reference implementation.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> Implementation for Reference<N> {
+    /// Evaluates the given rule on the given inputs, and returns the output in its canonical string form.
+    fn evaluate(&self, rule: Rule, inputs: &[String]) -> Result<String> {
+        match (rule, inputs) {
+            (Rule::Address, [private_key]) => {
+                Ok(Address::try_from(PrivateKey::<N>::from_str(private_key)?)?.to_string())
+            }
+            (Rule::ViewKey, [private_key]) => {
+                Ok(ViewKey::try_from(PrivateKey::<N>::from_str(private_key)?)?.to_string())
+            }
+            (Rule::LiteralBytes, [literal]) => {
+                let bytes = Literal::<N>::from_str(literal)?.to_bytes_le()?;
+                Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+            }
+            (Rule::HashBHP256, [plaintext]) => {
+                Ok(N::hash_bhp256(&Plaintext::<N>::from_str(plaintext)?.to_bits_le())?.to_string())
+            }
+            (Rule::HashPSD2, [plaintext]) => {
+                Ok(N::hash_psd2(&Plaintext::<N>::from_str(plaintext)?.to_fields()?)?.to_string())
+            }
+            (Rule::CommitBHP256, [plaintext, randomizer]) => {
+                let bits = Plaintext::<N>::from_str(plaintext)?.to_bits_le();
+                Ok(N::commit_bhp256(&bits, &Scalar::from_str(randomizer)?)?.to_string())
+            }
+            (Rule::RecordCommitment, [record, program_id, record_name]) => {
+                let record = Record::<N, Plaintext<N>>::from_str(record)?;
+                let commitment =
+                    record.to_commitment(&ProgramID::from_str(program_id)?, &Identifier::from_str(record_name)?)?;
+                Ok(commitment.to_string())
+            }
+            (Rule::SerialNumber, [private_key, commitment]) => {
+                let private_key = PrivateKey::<N>::from_str(private_key)?;
+                Ok(Record::<N, Plaintext<N>>::serial_number(private_key, Field::from_str(commitment)?)?.to_string())
+            }
+            (Rule::VerifySignature, [address, plaintext, signature]) => {
+                let message = Plaintext::<N>::from_str(plaintext)?.to_fields()?;
+                let signature = Signature::<N>::from_str(signature)?;
+                Ok(signature.verify(&Address::from_str(address)?, &message).to_string())
+            }
+            (Rule::DecryptRecord, [view_key, ciphertext]) => {
+                let record = Record::<N, Ciphertext<N>>::from_str(ciphertext)?;
+                Ok(record.decrypt(&ViewKey::from_str(view_key)?)?.to_string())
+            }
+            (rule, inputs) => bail!("Rule '{rule}' does not take {} inputs", inputs.len()),
+        }
+    }
+}
+
+impl<N: Network> Reference<N> {
+    /// Samples inputs for the given rule.
+    pub(super) fn sample_inputs<R: Rng + CryptoRng>(rule: Rule, rng: &mut R) -> Result<Vec<String>> {
+        let inputs = match rule {
+            Rule::Address | Rule::ViewKey => vec![PrivateKey::<N>::new(rng)?.to_string()],
+            Rule::LiteralBytes | Rule::HashBHP256 | Rule::HashPSD2 => vec![sample_literal::<N, R>(rng).to_string()],
+            Rule::CommitBHP256 => vec![sample_literal::<N, R>(rng).to_string(), Scalar::<N>::rand(rng).to_string()],
+            Rule::RecordCommitment => {
+                let owner = Address::try_from(PrivateKey::<N>::new(rng)?)?;
+                let amount = rng.gen::<u64>();
+                let nonce = Group::<N>::rand(rng);
+                let record =
+                    format!("{{ owner: {owner}.private, amount: {amount}u64.private, _nonce: {nonce}.public }}");
+                vec![record, "token.aleo".to_string(), "token".to_string()]
+            }
+            Rule::SerialNumber => vec![PrivateKey::<N>::new(rng)?.to_string(), Field::<N>::rand(rng).to_string()],
+            Rule::VerifySignature => {
+                let private_key = PrivateKey::<N>::new(rng)?;
+                let plaintext = Plaintext::from(sample_literal::<N, R>(rng));
+                let signature = Signature::sign(&private_key, &plaintext.to_fields()?, rng)?;
+                // Verify against another address half of the time, so the suite covers rejected signatures.
+                let signer = match rng.gen::<bool>() {
+                    true => Address::try_from(private_key)?,
+                    false => Address::try_from(PrivateKey::<N>::new(rng)?)?,
+                };
+                vec![signer.to_string(), plaintext.to_string(), signature.to_string()]
+            }
+            Rule::DecryptRecord => {
+                let private_key = PrivateKey::<N>::new(rng)?;
+                let (owner, view_key) = (Address::try_from(&private_key)?, ViewKey::try_from(&private_key)?);
+                // Sample the randomizer, which determines the record nonce.
+                let randomizer = Scalar::<N>::rand(rng);
+                let nonce = N::g_scalar_multiply(&randomizer);
+                let amount = rng.gen::<u64>();
+                let record =
+                    format!("{{ owner: {owner}.private, amount: {amount}u64.private, _nonce: {nonce}.public }}");
+                let ciphertext = Record::<N, Plaintext<N>>::from_str(&record)?.encrypt(randomizer)?;
+                vec![view_key.to_string(), ciphertext.to_string()]
+            }
+        };
+        Ok(inputs)
+    }
+}
+
+/// Samples a literal of one of the sampled literal types.
+fn sample_literal<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Literal<N> {
+    Literal::sample(SAMPLED_LITERAL_TYPES[rng.gen_range(0..SAMPLED_LITERAL_TYPES.len())], rng)
+}
+
+/// The literal types sampled for plaintext inputs.
+const SAMPLED_LITERAL_TYPES: [LiteralType; 8] = [
+    LiteralType::Address,
+    LiteralType::Boolean,
+    LiteralType::Field,
+    LiteralType::Group,
+    LiteralType::I128,
+    LiteralType::Scalar,
+    LiteralType::U8,
+    LiteralType::U64,
+];
@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The protocol rule exercised by a conformance vector.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// `[private_key] -> address`
+    Address,
+    /// `[private_key] -> view_key`
+    ViewKey,
+    /// `[literal] -> hex(to_bytes_le(literal))`
+    LiteralBytes,
+    /// `[plaintext] -> BHP256(to_bits_le(plaintext))`
+    HashBHP256,
+    /// `[plaintext] -> Poseidon2(to_fields(plaintext))`
+    HashPSD2,
+    /// `[plaintext, randomizer] -> BHP256Commit(to_bits_le(plaintext), randomizer)`
+    CommitBHP256,
+    /// `[record, program_id, record_name] -> commitment`
+    RecordCommitment,
+    /// `[private_key, commitment] -> serial_number`
+    SerialNumber,
+    /// `[address, plaintext, signature] -> true | false`
+    VerifySignature,
+    /// `[view_key, record_ciphertext] -> record`
+    DecryptRecord,
+}
+
+impl Rule {
+    /// The rules, in the order they are generated.
+    pub const ALL: [Rule; 10] = [
+        Rule::Address,
+        Rule::ViewKey,
+        Rule::LiteralBytes,
+        Rule::HashBHP256,
+        Rule::HashPSD2,
+        Rule::CommitBHP256,
+        Rule::RecordCommitment,
+        Rule::SerialNumber,
+        Rule::VerifySignature,
+        Rule::DecryptRecord,
+    ];
+
+    /// Returns the name of the rule, as it appears in a conformance suite.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Rule::Address => "address",
+            Rule::ViewKey => "view_key",
+            Rule::LiteralBytes => "literal_bytes",
+            Rule::HashBHP256 => "hash_bhp256",
+            Rule::HashPSD2 => "hash_psd2",
+            Rule::CommitBHP256 => "commit_bhp256",
+            Rule::RecordCommitment => "record_commitment",
+            Rule::SerialNumber => "serial_number",
+            Rule::VerifySignature => "verify_signature",
+            Rule::DecryptRecord => "decrypt_record",
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    /// Returns the rule with the given name.
+    fn from_str(name: &str) -> Result<Self> {
+        match Self::ALL.iter().find(|rule| rule.name() == name) {
+            Some(rule) => Ok(*rule),
+            None => bail!("Unknown conformance rule '{name}'"),
+        }
+    }
+}
+
+impl Display for Rule {
+    /// Prints the name of the rule.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A conformance vector, which records the expected output of a protocol rule on the given inputs.
+/// Inputs and outputs are in their canonical string form, i.e. `{ "rule": "address", "inputs": ["APrivateKey1..."], "output": "aleo1..." }`.
+/// A vector without an output (`"output": null`) expects the inputs to be rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vector {
+    rule: Rule,
+    inputs: Vec<String>,
+    output: Option<String>,
+}
+
+impl Vector {
+    /// Initializes a new conformance vector.
+    pub const fn new(rule: Rule, inputs: Vec<String>, output: String) -> Self {
+        Self { rule, inputs, output: Some(output) }
+    }
+
+    /// Initializes a new conformance vector, which expects the inputs to be rejected.
+    pub const fn rejected(rule: Rule, inputs: Vec<String>) -> Self {
+        Self { rule, inputs, output: None }
+    }
+
+    /// Returns the rule.
+    pub const fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// Returns the inputs.
+    pub fn inputs(&self) -> &[String] {
+        &self.inputs
+    }
+
+    /// Returns the expected output, or `None` if the inputs are expected to be rejected.
+    pub fn output(&self) -> Option<&str> {
+        self.output.as_deref()
+    }
+}
+
+impl Serialize for Vector {
+    /// Serializes the conformance vector into a JSON object.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut vector = serializer.serialize_struct("Vector", 3)?;
+        vector.serialize_field("rule", self.rule.name())?;
+        vector.serialize_field("inputs", &self.inputs)?;
+        vector.serialize_field("output", &self.output)?;
+        vector.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Vector {
+    /// Deserializes the conformance vector from a JSON object.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the vector from a string into a value.
+        let mut vector = serde_json::Value::deserialize(deserializer)?;
+        // Retrieve the rule.
+        let rule: String = DeserializeExt::take_from_value::<D>(&mut vector, "rule")?;
+        Ok(Self {
+            rule: Rule::from_str(&rule).map_err(de::Error::custom)?,
+            // Retrieve the inputs.
+            inputs: DeserializeExt::take_from_value::<D>(&mut vector, "inputs")?,
+            // Retrieve the expected output, if the inputs are not expected to be rejected.
+            output: serde_json::from_value(vector.get_mut("output").unwrap_or(&mut serde_json::Value::Null).take())
+                .map_err(de::Error::custom)?,
+        })
+    }
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    file::Manifest,
+    file::{Manifest, Template},
     prelude::{Network, ProgramID},
     synthesizer::Program,
 };
@@ -59,23 +59,24 @@ impl<N: Network> FromStr for AleoFile<N> {
 impl<N: Network> AleoFile<N> {
     /// Creates a new Aleo program file with the given directory path, program ID, and `is_main` indicator.
     pub fn create(directory: &Path, program_id: &ProgramID<N>, is_main: bool) -> Result<Self> {
+        Self::create_with_template(directory, program_id, is_main, Template::default())
+    }
+
+    /// Creates a new Aleo program file with the given directory path, program ID, and `is_main` indicator,
+    /// using the starter program of the given template.
+    pub fn create_with_template(
+        directory: &Path,
+        program_id: &ProgramID<N>,
+        is_main: bool,
+        template: Template,
+    ) -> Result<Self> {
         // Ensure the directory path exists.
         ensure!(directory.exists(), "The program directory does not exist: '{}'", directory.display());
         // Ensure the program name is valid.
         ensure!(!Program::is_reserved_keyword(program_id.name()), "Program name is invalid (reserved): '{program_id}'");
 
         // Construct the initial program string.
-        let program_string = format!(
-            r#"// The '{program_id}' program.
-program {program_id};
-
-function hello:
-    input r0 as u32.public;
-    input r1 as u32.private;
-    add r0 r1 into r2;
-    output r2 as u32.private;
-"#
-        );
+        let program_string = template.program_string(program_id);
 
         // Create the file.
         let file_name = if is_main {
@@ -0,0 +1,201 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    console::{
+        network::prelude::{ToBits, ToBytes},
+        types::Field,
+    },
+    prelude::{Network, ProgramID},
+    synthesizer::Program,
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use core::str::FromStr;
+use indexmap::IndexMap;
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+const LOCK_FILE_NAME: &str = "program.lock";
+
+pub struct LockFile<N: Network> {
+    /// The file path.
+    path: PathBuf,
+    /// The checksum and required edition of each locked import.
+    imports: IndexMap<ProgramID<N>, (Field<N>, u16)>,
+}
+
+impl<N: Network> LockFile<N> {
+    /// Creates a new lock file with the given directory path, pinning the given imports.
+    /// Note: This method overwrites any existing lock file.
+    pub fn create(directory: &Path, imports: &[Program<N>]) -> Result<Self> {
+        // Ensure the directory path exists.
+        ensure!(directory.exists(), "The program directory does not exist: '{}'", directory.display());
+
+        // Compute the checksum and required edition of each import.
+        let imports = imports
+            .iter()
+            .map(|program| Ok((*program.id(), (Self::checksum(program)?, program.required_edition()))))
+            .collect::<Result<IndexMap<_, _>>>()?;
+
+        // Construct the lock file.
+        let lock_file = Self { path: directory.join(LOCK_FILE_NAME), imports };
+        // Write the file.
+        File::create(&lock_file.path)?.write_all(lock_file.to_json_string()?.as_bytes())?;
+
+        Ok(lock_file)
+    }
+
+    /// Opens the lock file for reading.
+    pub fn open(directory: &Path) -> Result<Self> {
+        // Ensure the directory path exists.
+        ensure!(directory.exists(), "The program directory does not exist: '{}'", directory.display());
+
+        // Construct the file path.
+        let path = directory.join(LOCK_FILE_NAME);
+        // Ensure the file path exists.
+        ensure!(path.exists(), "Lock file is missing: '{}'", path.display());
+
+        // Read the file to a string.
+        let lock_string = fs::read_to_string(&path)?;
+        let json: serde_json::Value = serde_json::from_str(&lock_string)?;
+
+        // Retrieve the locked imports.
+        let entries = json["imports"].as_object().ok_or_else(|| anyhow!("Locked imports not found."))?;
+        let mut imports = IndexMap::with_capacity(entries.len());
+        for (program_id, entry) in entries {
+            let checksum =
+                entry["checksum"].as_str().ok_or_else(|| anyhow!("Checksum of '{program_id}' not found."))?;
+            let edition = entry["edition"].as_u64().ok_or_else(|| anyhow!("Edition of '{program_id}' not found."))?;
+            imports.insert(ProgramID::from_str(program_id)?, (Field::from_str(checksum)?, u16::try_from(edition)?));
+        }
+
+        Ok(Self { path, imports })
+    }
+
+    /// Returns `true` if the lock file exists at the given path.
+    pub fn exists_at(directory: &Path) -> bool {
+        // Construct the file path.
+        let path = directory.join(LOCK_FILE_NAME);
+        // Return the result.
+        path.is_file() && path.exists()
+    }
+
+    /// Returns the lock file name.
+    pub const fn file_name() -> &'static str {
+        LOCK_FILE_NAME
+    }
+
+    /// Returns the file path.
+    pub const fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Returns the checksum and required edition of each locked import.
+    pub const fn imports(&self) -> &IndexMap<ProgramID<N>, (Field<N>, u16)> {
+        &self.imports
+    }
+
+    /// Returns the checksum of the given program, as the hash of its bytecode.
+    pub fn checksum(program: &Program<N>) -> Result<Field<N>> {
+        N::hash_bhp1024(&program.to_bytes_le()?.to_bits_le())
+    }
+
+    /// Ensures the given import matches its entry in the lock file, if it has one.
+    /// Returns `false` if the import is not locked.
+    pub fn verify(&self, program: &Program<N>) -> Result<bool> {
+        match self.imports.get(program.id()) {
+            Some((checksum, edition)) => {
+                let candidate = Self::checksum(program)?;
+                if &candidate != checksum || &program.required_edition() != edition {
+                    bail!(
+                        "Import '{}' does not match '{LOCK_FILE_NAME}' (expected checksum {checksum} for edition {edition}, found {candidate} for edition {}). Remove the lock file to re-lock the imports.",
+                        program.id(),
+                        program.required_edition()
+                    )
+                }
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the lock file as a JSON string.
+    fn to_json_string(&self) -> Result<String> {
+        let imports = self
+            .imports
+            .iter()
+            .map(|(program_id, (checksum, edition))| {
+                (program_id.to_string(), serde_json::json!({ "checksum": checksum.to_string(), "edition": edition }))
+            })
+            .collect::<serde_json::Map<_, _>>();
+        Ok(serde_json::to_string_pretty(&serde_json::json!({ "imports": imports }))? + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_lock_file() {
+        // Initialize a temporary directory.
+        let directory = tempfile::tempdir().unwrap().into_path();
+
+        // Initialize an import.
+        let program = Program::<CurrentNetwork>::from_str(
+            "
+program token.aleo;
+
+function compute:
+    input r0 as u64.private;
+    add r0 r0 into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+
+        // Create the lock file, and ensure it round trips.
+        let lock_file = LockFile::create(&directory, &[program.clone()]).unwrap();
+        assert!(LockFile::<CurrentNetwork>::exists_at(&directory));
+        let candidate = LockFile::<CurrentNetwork>::open(&directory).unwrap();
+        assert_eq!(lock_file.imports(), candidate.imports());
+
+        // Ensure the import matches its entry.
+        assert!(candidate.verify(&program).unwrap());
+
+        // Ensure a changed import fails to verify.
+        let changed = Program::<CurrentNetwork>::from_str(
+            "
+program token.aleo;
+
+function compute:
+    input r0 as u64.private;
+    mul r0 r0 into r1;
+    output r1 as u64.private;",
+        )
+        .unwrap();
+        assert!(candidate.verify(&changed).is_err());
+
+        // Ensure an unlocked import is reported as such.
+        assert!(!candidate.verify(&Program::credits().unwrap()).unwrap());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
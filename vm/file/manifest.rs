@@ -13,12 +13,13 @@
 // limitations under the License.
 
 use crate::{
-    prelude::{Network, ProgramID},
+    prelude::{Address, Network, ProgramID},
     synthesizer::Program,
 };
 
 use anyhow::{anyhow, ensure, Result};
 use core::str::FromStr;
+use indexmap::IndexMap;
 use std::{
     fs::{self, File},
     io::Write,
@@ -27,11 +28,47 @@ use std::{
 
 const MANIFEST_FILE_NAME: &str = "program.json";
 
+/// A named deployment environment in the manifest, such as `dev`, `staging`, or `prod`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Profile<N: Network> {
+    /// The address of the account used in this environment.
+    address: Address<N>,
+    /// The endpoint that requests are sent to in this environment.
+    endpoint: String,
+}
+
+impl<N: Network> Profile<N> {
+    /// Initializes a new profile with the given address and endpoint.
+    pub const fn new(address: Address<N>, endpoint: String) -> Self {
+        Self { address, endpoint }
+    }
+
+    /// Returns the address of the account used in this environment.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+
+    /// Returns the endpoint that requests are sent to in this environment.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+}
+
 pub struct Manifest<N: Network> {
     /// The file path.
     path: PathBuf,
     /// The program ID.
     program_id: ProgramID<N>,
+    /// The endpoint that requests are sent to, if one is set.
+    endpoint: Option<String>,
+    /// The network ID the package is for, if one is set.
+    network_id: Option<u16>,
+    /// The headers attached to each request, such as an authorization token.
+    headers: Vec<(String, String)>,
+    /// The named profiles, each with an address and endpoint.
+    profiles: IndexMap<String, Profile<N>>,
+    /// The program edition, which is bumped by each upgrade of the deployed program.
+    edition: u16,
 }
 
 impl<N: Network> Manifest<N> {
@@ -62,7 +99,15 @@ impl<N: Network> Manifest<N> {
         File::create(&path)?.write_all(manifest_string.as_bytes())?;
 
         // Return the manifest file.
-        Ok(Self { path, program_id: *id })
+        Ok(Self {
+            path,
+            program_id: *id,
+            endpoint: None,
+            network_id: None,
+            headers: Vec::new(),
+            profiles: IndexMap::new(),
+            edition: 0,
+        })
     }
 
     /// Opens the manifest file for reading.
@@ -85,8 +130,58 @@ impl<N: Network> Manifest<N> {
         // Ensure the program name is valid.
         ensure!(!Program::is_reserved_keyword(id.name()), "Program name is invalid (reserved): {id}");
 
+        // Retrieve the endpoint, if one is set.
+        let endpoint = match &json["endpoint"] {
+            serde_json::Value::Null => None,
+            endpoint => Some(endpoint.as_str().ok_or_else(|| anyhow!("Endpoint must be a string."))?.to_string()),
+        };
+        // Retrieve the network ID, if one is set.
+        let network_id = match &json["network"] {
+            serde_json::Value::Null => None,
+            network_id => Some(u16::try_from(network_id.as_u64().ok_or_else(|| anyhow!("Network must be an ID."))?)?),
+        };
+        // Ensure the network ID matches, if one is set.
+        if let Some(network_id) = network_id {
+            ensure!(network_id == N::ID, "The manifest expects network {network_id}, found network {}", N::ID);
+        }
+        // Retrieve the headers, if any are set.
+        let headers = match &json["headers"] {
+            serde_json::Value::Null => Vec::new(),
+            headers => headers
+                .as_object()
+                .ok_or_else(|| anyhow!("Headers must be an object."))?
+                .iter()
+                .map(|(name, value)| match value.as_str() {
+                    Some(value) => Ok((name.clone(), value.to_string())),
+                    None => Err(anyhow!("Header '{name}' must be a string.")),
+                })
+                .collect::<Result<Vec<_>>>()?,
+        };
+        // Retrieve the profiles, if any are set.
+        let profiles = match &json["profiles"] {
+            serde_json::Value::Null => IndexMap::new(),
+            profiles => profiles
+                .as_object()
+                .ok_or_else(|| anyhow!("Profiles must be an object."))?
+                .iter()
+                .map(|(name, profile)| {
+                    let address =
+                        profile["address"].as_str().ok_or_else(|| anyhow!("Profile '{name}' must have an address."))?;
+                    let endpoint = profile["endpoint"]
+                        .as_str()
+                        .ok_or_else(|| anyhow!("Profile '{name}' must have an endpoint."))?;
+                    Ok((name.clone(), Profile::new(Address::from_str(address)?, endpoint.to_string())))
+                })
+                .collect::<Result<IndexMap<_, _>>>()?,
+        };
+        // Retrieve the program edition, which is 0 until the program is upgraded.
+        let edition = match &json["edition"] {
+            serde_json::Value::Null => 0,
+            edition => u16::try_from(edition.as_u64().ok_or_else(|| anyhow!("Edition must be a number."))?)?,
+        };
+
         // Return the manifest file.
-        Ok(Self { path, program_id: id })
+        Ok(Self { path, program_id: id, endpoint, network_id, headers, profiles, edition })
     }
 
     /// Returns `true` if the manifest file exists at the given path.
@@ -111,4 +206,146 @@ impl<N: Network> Manifest<N> {
     pub const fn program_id(&self) -> &ProgramID<N> {
         &self.program_id
     }
+
+    /// Returns the endpoint that requests are sent to, if one is set.
+    pub fn endpoint(&self) -> Option<&str> {
+        self.endpoint.as_deref()
+    }
+
+    /// Returns the network ID the package is for, if one is set.
+    pub const fn network_id(&self) -> Option<u16> {
+        self.network_id
+    }
+
+    /// Returns the headers attached to each request.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Returns the named profiles.
+    pub const fn profiles(&self) -> &IndexMap<String, Profile<N>> {
+        &self.profiles
+    }
+
+    /// Returns the profile with the given name.
+    pub fn profile(&self, name: &str) -> Result<&Profile<N>> {
+        self.profiles.get(name).ok_or_else(|| anyhow!("Profile '{name}' is not in '{MANIFEST_FILE_NAME}'"))
+    }
+
+    /// Returns the program edition.
+    pub const fn edition(&self) -> u16 {
+        self.edition
+    }
+
+    /// Sets the program edition, and writes it to the manifest file.
+    pub fn set_edition(&mut self, edition: u16) -> Result<()> {
+        // Read the file, so the other fields are preserved.
+        let mut json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&self.path)?)?;
+        json.as_object_mut()
+            .ok_or_else(|| anyhow!("'{MANIFEST_FILE_NAME}' must be an object."))?
+            .insert("edition".to_string(), edition.into());
+        // Write the file.
+        fs::write(&self.path, format!("{}\n", serde_json::to_string_pretty(&json)?))?;
+        self.edition = edition;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_manifest_endpoint_and_headers() {
+        let directory = tempfile::tempdir().unwrap().into_path();
+
+        // Ensure a new manifest has no endpoint, network, or headers.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo").unwrap();
+        let manifest = Manifest::create(&directory, &program_id).unwrap();
+        assert_eq!(manifest.endpoint(), None);
+        assert_eq!(manifest.network_id(), None);
+        assert!(manifest.headers().is_empty());
+
+        // Ensure the endpoint, network, and headers are read from the manifest.
+        let manifest_string = r#"{
+    "program": "token.aleo",
+    "version": "0.0.0",
+    "endpoint": "http://localhost:3030",
+    "network": 3,
+    "headers": { "Authorization": "Bearer token" }
+}"#;
+        std::fs::write(manifest.path(), manifest_string).unwrap();
+        let manifest = Manifest::<CurrentNetwork>::open(&directory).unwrap();
+        assert_eq!(manifest.endpoint(), Some("http://localhost:3030"));
+        assert_eq!(manifest.network_id(), Some(3));
+        assert_eq!(manifest.headers(), [("Authorization".to_string(), "Bearer token".to_string())]);
+
+        // Ensure a manifest without profiles has none.
+        assert!(manifest.profiles().is_empty());
+        assert!(manifest.profile("dev").is_err());
+
+        // Ensure a manifest for a different network fails to open.
+        std::fs::write(manifest.path(), manifest_string.replace("\"network\": 3", "\"network\": 1")).unwrap();
+        assert!(Manifest::<CurrentNetwork>::open(&directory).is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_manifest_profiles() {
+        let directory = tempfile::tempdir().unwrap().into_path();
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo").unwrap();
+        let manifest = Manifest::create(&directory, &program_id).unwrap();
+
+        // Ensure the profiles are read from the manifest, in order.
+        let address = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+        let manifest_string = format!(
+            r#"{{
+    "program": "token.aleo",
+    "version": "0.0.0",
+    "profiles": {{
+        "dev": {{ "address": "{address}", "endpoint": "http://localhost:3030" }},
+        "staging": {{ "address": "{address}", "endpoint": "https://staging.example.com" }}
+    }}
+}}"#
+        );
+        std::fs::write(manifest.path(), &manifest_string).unwrap();
+        let manifest = Manifest::<CurrentNetwork>::open(&directory).unwrap();
+        assert_eq!(manifest.profiles().keys().collect::<Vec<_>>(), ["dev", "staging"]);
+        let profile = manifest.profile("staging").unwrap();
+        assert_eq!(profile.address().to_string(), address);
+        assert_eq!(profile.endpoint(), "https://staging.example.com");
+        assert!(manifest.profile("prod").is_err());
+
+        // Ensure a profile without an endpoint fails to open.
+        std::fs::write(manifest.path(), manifest_string.replace(r#", "endpoint": "http://localhost:3030""#, ""))
+            .unwrap();
+        assert!(Manifest::<CurrentNetwork>::open(&directory).is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_manifest_edition() {
+        let directory = tempfile::tempdir().unwrap().into_path();
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo").unwrap();
+        let mut manifest = Manifest::create(&directory, &program_id).unwrap();
+        assert_eq!(manifest.edition(), 0);
+
+        // Ensure the edition is written to the manifest file, and the other fields are preserved.
+        manifest.set_edition(1).unwrap();
+        assert_eq!(manifest.edition(), 1);
+        let manifest = Manifest::<CurrentNetwork>::open(&directory).unwrap();
+        assert_eq!(manifest.edition(), 1);
+        assert_eq!(manifest.program_id(), &program_id);
+        let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(manifest.path()).unwrap()).unwrap();
+        assert_eq!(json["version"], "0.0.0");
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
 }
@@ -18,8 +18,11 @@ pub use aleo::AleoFile;
 mod avm;
 pub use avm::AVMFile;
 
+mod lock;
+pub use lock::LockFile;
+
 mod manifest;
-pub use manifest::Manifest;
+pub use manifest::{Manifest, Profile};
 
 mod prover;
 pub use prover::ProverFile;
@@ -27,5 +30,18 @@ pub use prover::ProverFile;
 mod readme_file;
 pub use readme_file::README;
 
+mod template;
+pub use template::Template;
+
 mod verifier;
 pub use verifier::VerifierFile;
+
+use crate::prelude::ToBytes;
+
+use anyhow::Result;
+use sha2::{Digest, Sha256};
+
+/// Returns the SHA-256 checksum of the given key, which is stored alongside it in the build directory.
+fn checksum(key: &impl ToBytes) -> Result<[u8; 32]> {
+    Ok(Sha256::digest(key.to_bytes_le()?).into())
+}
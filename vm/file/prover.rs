@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    prelude::{FromBytes, Identifier, IoResult, Network, Read, ToBytes},
+    prelude::{error, FromBytes, Identifier, IoResult, Network, Read, ToBytes},
     synthesizer::{snark::ProvingKey, Program},
 };
 
@@ -31,6 +31,8 @@ pub struct ProverFile<N: Network> {
     function_name: Identifier<N>,
     /// The proving key.
     proving_key: ProvingKey<N>,
+    /// The checksum of the proving key.
+    checksum: [u8; 32],
 }
 
 impl<N: Network> ProverFile<N> {
@@ -42,7 +44,7 @@ impl<N: Network> ProverFile<N> {
         ensure!(!Program::is_reserved_keyword(function_name), "Function name is invalid (reserved): {}", function_name);
 
         // Create the candidate prover file.
-        let prover_file = Self { function_name: *function_name, proving_key };
+        let prover_file = Self { function_name: *function_name, checksum: super::checksum(&proving_key)?, proving_key };
 
         // Create the file name.
         let file_name = format!("{function_name}.{PROVER_FILE_EXTENSION}");
@@ -102,6 +104,11 @@ impl<N: Network> ProverFile<N> {
         &self.proving_key
     }
 
+    /// Returns the checksum of the proving key, as a hex-encoded SHA-256 digest.
+    pub fn checksum(&self) -> String {
+        hex::encode(self.checksum)
+    }
+
     /// Removes the file at the given path, if it exists.
     pub fn remove(&self, path: &Path) -> Result<()> {
         // If the path does not exist, do nothing.
@@ -178,7 +185,12 @@ impl<N: Network> FromBytes for ProverFile<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let function_name = Identifier::read_le(&mut reader)?;
         let proving_key = FromBytes::read_le(&mut reader)?;
-        Ok(Self { function_name, proving_key })
+        let checksum = <[u8; 32]>::read_le(&mut reader)?;
+        // Ensure the proving key matches its checksum.
+        if checksum != super::checksum(&proving_key).map_err(error)? {
+            return Err(error(format!("The proving key for '{function_name}' does not match its checksum")));
+        }
+        Ok(Self { function_name, proving_key, checksum })
     }
 }
 
@@ -186,7 +198,8 @@ impl<N: Network> ToBytes for ProverFile<N> {
     /// Writes the prover file to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         self.function_name.write_le(&mut writer)?;
-        self.proving_key.write_le(&mut writer)
+        self.proving_key.write_le(&mut writer)?;
+        self.checksum.write_le(&mut writer)
     }
 }
 
@@ -246,5 +259,14 @@ function compute:
         let candidate = ProverFile::open(&directory, &function_name).unwrap();
         // Ensure the prover files are equal.
         assert_eq!(expected.to_bytes_le().unwrap(), candidate.to_bytes_le().unwrap());
+        assert_eq!(expected.checksum(), candidate.checksum());
+
+        // Corrupt the checksum of the prover file.
+        let path = directory.join(format!("{function_name}.{PROVER_FILE_EXTENSION}"));
+        let mut bytes = fs::read(&path).unwrap();
+        *bytes.last_mut().unwrap() ^= 1;
+        fs::write(&path, bytes).unwrap();
+        // Ensure the prover file fails to open.
+        assert!(ProverFile::<CurrentNetwork>::open(&directory, &function_name).is_err());
     }
 }
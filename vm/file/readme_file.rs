@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    file::Template,
     prelude::{Network, ProgramID},
     synthesizer::Program,
 };
@@ -32,6 +33,11 @@ pub struct README {
 impl README {
     /// Creates a new README file with the given directory path and program ID.
     pub fn create<N: Network>(directory: &Path, id: &ProgramID<N>) -> Result<Self> {
+        Self::create_with_template(directory, id, Template::default())
+    }
+
+    /// Creates a new README file with the given directory path and program ID, describing the given template.
+    pub fn create_with_template<N: Network>(directory: &Path, id: &ProgramID<N>, template: Template) -> Result<Self> {
         // Ensure the directory path exists.
         ensure!(directory.exists(), "The program directory does not exist: {}", directory.display());
         // Ensure the program name is valid.
@@ -41,6 +47,8 @@ impl README {
         let readme_string = format!(
             r"# {id}
 
+{description}
+
 ## Build Guide
 
 To compile this Aleo program, run:
@@ -50,9 +58,11 @@ snarkvm build
 
 To execute this Aleo program, run:
 ```bash
-snarkvm run hello
+{run_command}
 ```
-"
+",
+            description = template.description(),
+            run_command = template.run_command(),
         );
 
         // Construct the file name.
@@ -0,0 +1,200 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::prelude::{Network, ProgramID};
+
+use anyhow::{bail, Error, Result};
+use core::{fmt, str::FromStr};
+
+/// The starter program of a new package.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Template {
+    /// A program with a single function, which returns its input.
+    Empty,
+    /// A program with a `hello` function, which adds two numbers.
+    #[default]
+    Example,
+    /// A program with a `token` record, which can be minted and transferred.
+    Token,
+    /// A program with an `nft` record, which can be minted once per token ID and transferred.
+    Nft,
+}
+
+impl Template {
+    /// Returns every template.
+    pub const fn all() -> [Self; 4] {
+        [Self::Empty, Self::Example, Self::Token, Self::Nft]
+    }
+
+    /// Returns a one-line description of the template.
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::Empty => "A minimal program to start from scratch.",
+            Self::Example => "An example program, which adds two numbers.",
+            Self::Token => "A token, which can be minted and transferred privately.",
+            Self::Nft => "A non-fungible token, which can be minted once per token ID and transferred privately.",
+        }
+    }
+
+    /// Returns the command to run the starter program.
+    pub const fn run_command(&self) -> &'static str {
+        match self {
+            Self::Empty => "snarkvm run main true",
+            Self::Example => "snarkvm run hello",
+            Self::Token => "snarkvm run mint <address> 100u64",
+            Self::Nft => "snarkvm run mint <address> 1field",
+        }
+    }
+
+    /// Returns the starter program for the given program ID.
+    pub fn program_string<N: Network>(&self, program_id: &ProgramID<N>) -> String {
+        match self {
+            Self::Empty => format!(
+                r"// The '{program_id}' program.
+program {program_id};
+
+function main:
+    input r0 as boolean.public;
+    output r0 as boolean.public;
+"
+            ),
+            Self::Example => format!(
+                r"// The '{program_id}' program.
+program {program_id};
+
+function hello:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+"
+            ),
+            Self::Token => format!(
+                r"// The '{program_id}' program.
+program {program_id};
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+
+function transfer:
+    input r0 as token.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    sub r0.amount r2 into r3;
+    cast r1 r2 into r4 as token.record;
+    cast r0.owner r3 into r5 as token.record;
+    output r4 as token.record;
+    output r5 as token.record;
+"
+            ),
+            Self::Nft => format!(
+                r"// The '{program_id}' program.
+program {program_id};
+
+record nft:
+    owner as address.private;
+    token_id as field.private;
+
+mapping minted:
+    key as field.public;
+    value as boolean.public;
+
+function mint:
+    input r0 as address.private;
+    input r1 as field.public;
+    cast r0 r1 into r2 as nft.record;
+    async mint r1 into r3;
+    output r2 as nft.record;
+    output r3 as {program_id}/mint.future;
+
+finalize mint:
+    input r0 as field.public;
+    contains minted[r0] into r1;
+    assert.eq r1 false;
+    set true into minted[r0];
+
+function transfer:
+    input r0 as nft.record;
+    input r1 as address.private;
+    cast r1 r0.token_id into r2 as nft.record;
+    output r2 as nft.record;
+"
+            ),
+        }
+    }
+}
+
+impl FromStr for Template {
+    type Err = Error;
+
+    /// Parses the template from its name.
+    fn from_str(template: &str) -> Result<Self> {
+        match template {
+            "empty" => Ok(Self::Empty),
+            "example" => Ok(Self::Example),
+            "token" => Ok(Self::Token),
+            "nft" => Ok(Self::Nft),
+            _ => bail!("Unknown template '{template}' (expected 'empty', 'example', 'token', or 'nft')"),
+        }
+    }
+}
+
+impl fmt::Display for Template {
+    /// Prints the template name.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty"),
+            Self::Example => write!(f, "example"),
+            Self::Token => write!(f, "token"),
+            Self::Nft => write!(f, "nft"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::synthesizer::Program;
+
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_template() {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("starter.aleo").unwrap();
+
+        for template in Template::all() {
+            // Ensure the template name round-trips.
+            assert_eq!(template, Template::from_str(&template.to_string()).unwrap());
+
+            // Ensure the starter program parses, and has the given program ID.
+            let program = Program::<CurrentNetwork>::from_str(&template.program_string(&program_id)).unwrap();
+            assert_eq!(program.id(), &program_id);
+            assert!(!program.functions().is_empty());
+
+            // Ensure the run command calls a function of the starter program.
+            let function_name = template.run_command().split_whitespace().nth(2).unwrap();
+            assert!(program.functions().keys().any(|name| name.to_string() == function_name));
+        }
+
+        // Ensure an unknown template fails.
+        assert!(Template::from_str("unknown").is_err());
+    }
+}
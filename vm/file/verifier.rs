@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    prelude::{FromBytes, Identifier, IoResult, Network, Read, ToBytes},
+    prelude::{error, FromBytes, Identifier, IoResult, Network, Read, ToBytes},
     synthesizer::{snark::VerifyingKey, Program},
 };
 
@@ -31,6 +31,8 @@ pub struct VerifierFile<N: Network> {
     function_name: Identifier<N>,
     /// The verifying key.
     verifying_key: VerifyingKey<N>,
+    /// The checksum of the verifying key.
+    checksum: [u8; 32],
 }
 
 impl<N: Network> VerifierFile<N> {
@@ -42,7 +44,8 @@ impl<N: Network> VerifierFile<N> {
         ensure!(!Program::is_reserved_keyword(function_name), "Function name is invalid (reserved): {}", function_name);
 
         // Create the candidate verifier file.
-        let verifier_file = Self { function_name: *function_name, verifying_key };
+        let verifier_file =
+            Self { function_name: *function_name, checksum: super::checksum(&verifying_key)?, verifying_key };
 
         // Create the file name.
         let file_name = format!("{function_name}.{VERIFIER_FILE_EXTENSION}");
@@ -102,6 +105,11 @@ impl<N: Network> VerifierFile<N> {
         &self.verifying_key
     }
 
+    /// Returns the checksum of the verifying key, as a hex-encoded SHA-256 digest.
+    pub fn checksum(&self) -> String {
+        hex::encode(self.checksum)
+    }
+
     /// Removes the file at the given path, if it exists.
     pub fn remove(&self, path: &Path) -> Result<()> {
         // If the path does not exist, do nothing.
@@ -178,7 +186,12 @@ impl<N: Network> FromBytes for VerifierFile<N> {
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let function_name = Identifier::read_le(&mut reader)?;
         let verifying_key = FromBytes::read_le(&mut reader)?;
-        Ok(Self { function_name, verifying_key })
+        let checksum = <[u8; 32]>::read_le(&mut reader)?;
+        // Ensure the verifying key matches its checksum.
+        if checksum != super::checksum(&verifying_key).map_err(error)? {
+            return Err(error(format!("The verifying key for '{function_name}' does not match its checksum")));
+        }
+        Ok(Self { function_name, verifying_key, checksum })
     }
 }
 
@@ -186,7 +199,8 @@ impl<N: Network> ToBytes for VerifierFile<N> {
     /// Writes the verifier file to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         self.function_name.write_le(&mut writer)?;
-        self.verifying_key.write_le(&mut writer)
+        self.verifying_key.write_le(&mut writer)?;
+        self.checksum.write_le(&mut writer)
     }
 }
 
@@ -22,8 +22,14 @@ extern crate thiserror;
 
 #[cfg(feature = "cli")]
 pub mod cli;
+#[cfg(feature = "conformance")]
+pub mod conformance;
+#[cfg(all(feature = "ledger", feature = "synthesizer"))]
 pub mod file;
+#[cfg(all(feature = "ledger", feature = "synthesizer"))]
 pub mod package;
+#[cfg(feature = "wallet")]
+pub mod wallet;
 
 #[cfg(feature = "algorithms")]
 pub use snarkvm_algorithms as algorithms;
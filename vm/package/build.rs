@@ -30,7 +30,7 @@ impl<N: Network> BuildRequest<N> {
 
     /// Sends the request to the given endpoint.
     pub fn send(&self, endpoint: &str) -> Result<BuildResponse<N>> {
-        Ok(ureq::get(endpoint).send_json(self)?.into_json()?)
+        Ok(crate::ledger::query::http_agent().get(endpoint).send_json(self)?.into_json()?)
     }
 
     /// Returns the program.
@@ -157,21 +157,19 @@ impl<N: Network> Package<N> {
         if !self.is_build_required::<A>() {
             return Ok(());
         }
+        // Ensure the endpoint can be reached, if the keys are synthesized remotely.
+        if let Some(ref endpoint) = endpoint {
+            self.config.ensure_online(endpoint)?;
+        }
 
         // Retrieve the main program.
         let program = self.program();
         // Retrieve the program ID.
         let program_id = program.id();
 
-        #[cfg(feature = "aleo-cli")]
-        println!("⏳ Compiling '{}'...\n", program_id.to_string().bold());
-
-        // Prepare the build directory.
-        let build_directory = self.build_directory();
-        // Create the build directory if it does not exist.
-        if !build_directory.exists() {
-            std::fs::create_dir_all(&build_directory)?;
-        }
+        // Report the start of the build.
+        let task = format!("Compiling '{program_id}'");
+        self.interface.progress_start(&task);
 
         // Construct the process.
         let process = self.get_process()?;
@@ -184,7 +182,8 @@ impl<N: Network> Package<N> {
             .collect::<Result<Vec<_>>>()?;
 
         // Synthesize each proving and verifying key.
-        for function_name in program.functions().keys() {
+        let num_functions = program.functions().len();
+        for (index, function_name) in program.functions().keys().enumerate() {
             match endpoint {
                 Some(ref endpoint) => {
                     // Prepare the request.
@@ -214,6 +213,37 @@ impl<N: Network> Package<N> {
                 }
                 None => process.synthesize_key::<A, _>(program_id, function_name, &mut rand::thread_rng())?,
             }
+            // Report the progress of the build.
+            self.interface.progress_update(&task, index + 1, num_functions);
+        }
+
+        // Write the keys and AVM files to the build directory.
+        self.write_build(&process, &imported_programs)?;
+
+        // Ensure the build directory exists.
+        if !self.build_directory().exists() {
+            bail!("Build directory does not exist: {}", self.build_directory().display());
+        }
+
+        // Report the end of the build.
+        self.interface.progress_finish(&task);
+
+        Ok(())
+    }
+
+    /// Writes the proving and verifying key of each function, and the AVM file of the program and its imports,
+    /// from the given process to the build directory.
+    pub(super) fn write_build(&self, process: &Process<N>, imported_programs: &[Program<N>]) -> Result<()> {
+        // Retrieve the main program.
+        let program = self.program();
+        // Retrieve the program ID.
+        let program_id = program.id();
+
+        // Prepare the build directory.
+        let build_directory = self.build_directory();
+        // Create the build directory if it does not exist.
+        if !build_directory.exists() {
+            std::fs::create_dir_all(&build_directory)?;
         }
 
         // Load each function circuit.
@@ -268,16 +298,15 @@ impl<N: Network> Package<N> {
             let _verifier = VerifierFile::create(&build_directory, function_name, verifying_key)?;
         }
 
-        // Lastly, write the AVM file.
-        let _avm_file = AVMFile::create(&build_directory, program.clone(), true)?;
-
-        // Ensure the build directory exists.
-        if !self.build_directory().exists() {
-            bail!("Build directory does not exist: {}", self.build_directory().display());
+        // Write an AVM file for each imported program, so later builds can detect changed imports.
+        for imported_program in imported_programs.iter().filter(|program| program.id().to_string() != "credits.aleo") {
+            let _avm_file = AVMFile::create(&build_directory, imported_program.clone(), false)?;
         }
 
-        #[cfg(feature = "aleo-cli")]
-        println!();
+        // Lastly, write the AVM file.
+        let _avm_file = AVMFile::create(&build_directory, program.clone(), true)?;
+        // Drop any proving keys loaded from the previous build.
+        self.proving_keys.write().clear();
 
         Ok(())
     }
@@ -12,25 +12,61 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::ledger::block::Deployment;
-use snarkvm_console::prelude::DeserializeExt;
+use crate::{
+    console::{
+        account::{Address, Signature},
+        prelude::ToFields,
+        types::Field,
+    },
+    ledger::block::Deployment,
+};
+use snarkvm_console::prelude::{de, DeserializeExt};
 
 use super::*;
 
 pub struct DeployRequest<N: Network> {
     deployment: Deployment<N>,
     program_id: ProgramID<N>,
+    signature: Option<Signature<N>>,
 }
 
 impl<N: Network> DeployRequest<N> {
     /// Sends the request to the given endpoint.
     pub fn new(deployment: Deployment<N>, program_id: ProgramID<N>) -> Self {
-        Self { deployment, program_id }
+        Self { deployment, program_id, signature: None }
+    }
+
+    /// Signs the request with the deployer's private key.
+    pub fn sign<R: Rng + CryptoRng>(mut self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Self> {
+        self.signature = Some(Signature::sign(private_key, &self.to_message()?, rng)?);
+        Ok(self)
+    }
+
+    /// Returns `true` if the request is signed by its signer, for the deployment of the given program ID.
+    pub fn verify(&self) -> bool {
+        // Ensure the program ID matches the deployment.
+        if self.deployment.program_id() != &self.program_id {
+            return false;
+        }
+        // Ensure the request is signed.
+        let Some(signature) = &self.signature else {
+            return false;
+        };
+        // Verify the signature.
+        match self.to_message() {
+            Ok(message) => signature.verify(&signature.to_address(), &message),
+            Err(_) => false,
+        }
     }
 
     /// Sends the request to the given endpoint.
     pub fn send(&self, endpoint: &str) -> Result<DeployResponse<N>> {
-        Ok(ureq::post(endpoint).send_json(self)?.into_json()?)
+        self.send_with_headers(endpoint, &[])
+    }
+
+    /// Sends the request to the given endpoint, with the given headers.
+    pub fn send_with_headers(&self, endpoint: &str, headers: &[(String, String)]) -> Result<DeployResponse<N>> {
+        stream::post_json(endpoint, headers, self)
     }
 
     /// Returns the program.
@@ -42,16 +78,38 @@ impl<N: Network> DeployRequest<N> {
     pub const fn program_id(&self) -> &ProgramID<N> {
         &self.program_id
     }
+
+    /// Returns the signature, if the request is signed.
+    pub const fn signature(&self) -> Option<&Signature<N>> {
+        self.signature.as_ref()
+    }
+
+    /// Returns the address of the signer, if the request is signed.
+    /// Note: The signer is only authenticated if `verify` returns `true`.
+    pub fn signer(&self) -> Option<Address<N>> {
+        self.signature.as_ref().map(Signature::to_address)
+    }
+
+    /// Returns the signed message, which is the deployment ID followed by the program ID.
+    fn to_message(&self) -> Result<Vec<Field<N>>> {
+        let mut message = vec![self.deployment.to_deployment_id()?];
+        message.extend(self.program_id.to_fields()?);
+        Ok(message)
+    }
 }
 
 impl<N: Network> Serialize for DeployRequest<N> {
     /// Serializes the deploy request into string or bytes.
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut request = serializer.serialize_struct("DeployRequest", 2)?;
+        let mut request = serializer.serialize_struct("DeployRequest", 2 + self.signature.is_some() as usize)?;
         // Serialize the deployment.
         request.serialize_field("deployment", &self.deployment)?;
         // Serialize the program ID.
         request.serialize_field("program_id", &self.program_id)?;
+        // Serialize the signature, if it exists.
+        if let Some(signature) = &self.signature {
+            request.serialize_field("signature", signature)?;
+        }
         request.end()
     }
 }
@@ -62,36 +120,88 @@ impl<'de, N: Network> Deserialize<'de> for DeployRequest<N> {
         // Parse the request from a string into a value.
         let mut request = serde_json::Value::deserialize(deserializer)?;
         // Recover the leaf.
-        Ok(Self::new(
+        let mut deploy_request = Self::new(
             // Retrieve the program.
             DeserializeExt::take_from_value::<D>(&mut request, "deployment")?,
             // Retrieve the program ID.
             DeserializeExt::take_from_value::<D>(&mut request, "program_id")?,
-        ))
+        );
+        // Retrieve the signature, if it exists.
+        deploy_request.signature =
+            serde_json::from_value(request.get_mut("signature").unwrap_or(&mut serde_json::Value::Null).take())
+                .map_err(de::Error::custom)?;
+        Ok(deploy_request)
     }
 }
 
 pub struct DeployResponse<N: Network> {
     deployment: Deployment<N>,
+    /// The ID of the transaction that carries the deployment, if the endpoint reports it.
+    transaction_id: Option<N::TransactionID>,
+    /// The fee charged for the deployment in microcredits, if the endpoint reports it.
+    fee: Option<u64>,
+    /// The inclusion status of the transaction, if the endpoint reports it.
+    status: Option<DeploymentStatus>,
 }
 
 impl<N: Network> DeployResponse<N> {
     /// Initializes a new deploy response.
     pub const fn new(deployment: Deployment<N>) -> Self {
-        Self { deployment }
+        Self { deployment, transaction_id: None, fee: None, status: None }
+    }
+
+    /// Sets the ID of the transaction that carries the deployment, and the fee charged for it in microcredits.
+    pub fn with_transaction(mut self, transaction_id: N::TransactionID, fee: u64) -> Self {
+        self.transaction_id = Some(transaction_id);
+        self.fee = Some(fee);
+        self
+    }
+
+    /// Sets the inclusion status of the transaction.
+    pub fn with_status(mut self, status: DeploymentStatus) -> Self {
+        self.status = Some(status);
+        self
     }
 
     /// Returns the program ID.
     pub const fn deployment(&self) -> &Deployment<N> {
         &self.deployment
     }
+
+    /// Returns the ID of the transaction that carries the deployment, if the endpoint reports it.
+    pub const fn transaction_id(&self) -> Option<&N::TransactionID> {
+        self.transaction_id.as_ref()
+    }
+
+    /// Returns the fee charged for the deployment in microcredits, if the endpoint reports it.
+    pub const fn fee(&self) -> Option<u64> {
+        self.fee
+    }
+
+    /// Returns the inclusion status of the transaction, if the endpoint reports it.
+    pub const fn status(&self) -> Option<DeploymentStatus> {
+        self.status
+    }
 }
 
 impl<N: Network> Serialize for DeployResponse<N> {
     /// Serializes the deploy response into string or bytes.
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut response = serializer.serialize_struct("DeployResponse", 1)?;
+        let num_fields = 1 + [self.transaction_id.is_some(), self.fee.is_some(), self.status.is_some()]
+            .into_iter()
+            .filter(|is_some| *is_some)
+            .count();
+        let mut response = serializer.serialize_struct("DeployResponse", num_fields)?;
         response.serialize_field("deployment", &self.deployment)?;
+        if let Some(transaction_id) = &self.transaction_id {
+            response.serialize_field("transaction_id", transaction_id)?;
+        }
+        if let Some(fee) = &self.fee {
+            response.serialize_field("fee", fee)?;
+        }
+        if let Some(status) = &self.status {
+            response.serialize_field("status", status)?;
+        }
         response.end()
     }
 }
@@ -101,72 +211,348 @@ impl<'de, N: Network> Deserialize<'de> for DeployResponse<N> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         // Parse the response from a string into a value.
         let mut response = serde_json::Value::deserialize(deserializer)?;
-        // Recover the leaf.
-        Ok(Self::new(
-            // Retrieve the program ID.
-            DeserializeExt::take_from_value::<D>(&mut response, "deployment")?,
-        ))
+        Ok(Self {
+            // Retrieve the deployment.
+            deployment: DeserializeExt::take_from_value::<D>(&mut response, "deployment")?,
+            // Retrieve the transaction ID, if it exists.
+            transaction_id: serde_json::from_value(
+                response.get_mut("transaction_id").unwrap_or(&mut serde_json::Value::Null).take(),
+            )
+            .map_err(de::Error::custom)?,
+            // Retrieve the fee, if it exists.
+            fee: serde_json::from_value(response.get_mut("fee").unwrap_or(&mut serde_json::Value::Null).take())
+                .map_err(de::Error::custom)?,
+            // Retrieve the status, if it exists.
+            status: serde_json::from_value(response.get_mut("status").unwrap_or(&mut serde_json::Value::Null).take())
+                .map_err(de::Error::custom)?,
+        })
+    }
+}
+
+/// The inclusion status of a deployment transaction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DeploymentStatus {
+    /// The transaction is waiting to be included in a block.
+    Pending,
+    /// The deployment was accepted in the block at the given height.
+    Accepted(u32),
+    /// The deployment was rejected in the block at the given height, and only its fee was charged.
+    Rejected(u32),
+}
+
+impl DeploymentStatus {
+    /// Returns the height of the block that includes the transaction, if it has been included.
+    pub const fn block_height(&self) -> Option<u32> {
+        match self {
+            Self::Pending => None,
+            Self::Accepted(height) | Self::Rejected(height) => Some(*height),
+        }
+    }
+}
+
+impl Serialize for DeploymentStatus {
+    /// Serializes the status as `{ "type": "accepted", "block_height": 10 }`, where a pending status has no height.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (status_type, block_height) = match self {
+            Self::Pending => ("pending", None),
+            Self::Accepted(height) => ("accepted", Some(height)),
+            Self::Rejected(height) => ("rejected", Some(height)),
+        };
+        let mut status = serializer.serialize_struct("DeploymentStatus", 1 + block_height.is_some() as usize)?;
+        status.serialize_field("type", status_type)?;
+        if let Some(block_height) = block_height {
+            status.serialize_field("block_height", block_height)?;
+        }
+        status.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for DeploymentStatus {
+    /// Deserializes the status from `{ "type": "accepted", "block_height": 10 }`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the status from a string into a value.
+        let mut status = serde_json::Value::deserialize(deserializer)?;
+        // Retrieve the status type.
+        let status_type: String = DeserializeExt::take_from_value::<D>(&mut status, "type")?;
+        match status_type.as_str() {
+            "pending" => Ok(Self::Pending),
+            "accepted" => Ok(Self::Accepted(DeserializeExt::take_from_value::<D>(&mut status, "block_height")?)),
+            "rejected" => Ok(Self::Rejected(DeserializeExt::take_from_value::<D>(&mut status, "block_height")?)),
+            _ => Err(de::Error::custom(format!("Unknown deployment status '{status_type}'"))),
+        }
+    }
+}
+
+/// The cost of deploying a package, and the size of the deployment it is computed from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentCost {
+    /// The total cost in microcredits.
+    total_cost: u64,
+    /// The storage cost in microcredits.
+    storage_cost: u64,
+    /// The namespace cost in microcredits.
+    namespace_cost: u64,
+    /// The number of bytes in the deployment.
+    size_in_bytes: u64,
+    /// The number of functions in the program.
+    num_functions: usize,
+    /// The number of variables, summed over the function circuits.
+    num_variables: usize,
+    /// The number of constraints, summed over the function circuits.
+    num_constraints: usize,
+}
+
+impl DeploymentCost {
+    /// Returns the total cost in microcredits, which is the minimum fee for the deployment.
+    pub const fn total_cost(&self) -> u64 {
+        self.total_cost
+    }
+
+    /// Returns the storage cost in microcredits.
+    pub const fn storage_cost(&self) -> u64 {
+        self.storage_cost
+    }
+
+    /// Returns the namespace cost in microcredits.
+    pub const fn namespace_cost(&self) -> u64 {
+        self.namespace_cost
+    }
+
+    /// Returns the number of bytes in the deployment.
+    pub const fn size_in_bytes(&self) -> u64 {
+        self.size_in_bytes
+    }
+
+    /// Returns the number of functions in the program.
+    pub const fn num_functions(&self) -> usize {
+        self.num_functions
+    }
+
+    /// Returns the number of variables, summed over the function circuits.
+    pub const fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    /// Returns the number of constraints, summed over the function circuits.
+    pub const fn num_constraints(&self) -> usize {
+        self.num_constraints
     }
 }
 
 impl<N: Network> Package<N> {
+    /// Deploys the package, sending the deployment to the given endpoint, or else to the endpoint in the manifest.
+    /// If neither is set, the deployment is returned without being sent.
     pub fn deploy<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
         &self,
         endpoint: Option<String>,
+    ) -> Result<Deployment<N>> {
+        self.deploy_with_signer::<A>(endpoint, None)
+    }
+
+    /// Deploys the package, and signs the deploy request with the given private key, if one is provided.
+    /// A signed deploy request is sent in a versioned `SignedRequest` envelope, for replay protection.
+    pub fn deploy_with_signer<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        endpoint: Option<String>,
+        private_key: Option<&PrivateKey<N>>,
+    ) -> Result<Deployment<N>> {
+        self.deploy_with_resolver::<A>(&FileResolver::new(self.imports_directory()), endpoint, private_key)
+    }
+
+    /// Deploys the package to the endpoint of the given manifest profile, such as `dev`, `staging`, or `prod`.
+    /// If a private key is provided, it must belong to the address of the profile.
+    pub fn deploy_with_profile<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        profile: &str,
+        private_key: Option<&PrivateKey<N>>,
+    ) -> Result<Deployment<N>> {
+        // Retrieve the profile.
+        let profile = self.manifest_file.profile(profile)?;
+        // Ensure the private key belongs to the profile, if one is provided.
+        if let Some(private_key) = private_key {
+            let address = Address::try_from(private_key)?;
+            ensure!(&address == profile.address(), "Expected the account {}, found {address}", profile.address());
+        }
+        self.deploy_with_signer::<A>(Some(profile.endpoint().to_string()), private_key)
+    }
+
+    /// Deploys the package, resolving its imports with the given resolver.
+    pub fn deploy_with_resolver<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        resolver: &dyn ImportResolver<N>,
+        endpoint: Option<String>,
+        private_key: Option<&PrivateKey<N>>,
     ) -> Result<Deployment<N>> {
         // Retrieve the main program.
         let program = self.program();
         // Retrieve the main program ID.
         let program_id = program.id();
 
-        #[cfg(feature = "aleo-cli")]
-        println!("⏳ Deploying '{}'...\n", program_id.to_string().bold());
-
-        // Construct the process.
-        let mut process = Process::<N>::load()?;
-
-        // Add program imports to the process.
-        let imports_directory = self.imports_directory();
-        program.imports().keys().try_for_each(|program_id| {
-            // TODO (howardwu): Add the following checks:
-            //  1) the imported program ID exists *on-chain* (for the given network)
-            //  2) the AVM bytecode of the imported program matches the AVM bytecode of the program *on-chain*
-            //  3) consensus performs the exact same checks (in `verify_deployment`)
+        // If no endpoint is given, fall back to the endpoint in the manifest, if one is set.
+        let endpoint = endpoint.or_else(|| self.manifest_file.endpoint().map(ToString::to_string));
+        // Ensure the endpoint can be reached, before computing the deployment.
+        if let Some(ref endpoint) = endpoint {
+            self.config.ensure_online(endpoint)?;
+        }
 
-            // Open the Aleo program file.
-            let import_program_file = AleoFile::open(&imports_directory, program_id, false)?;
-            // Add the import program.
-            process.add_program(import_program_file.program())?;
-            Ok::<_, Error>(())
-        })?;
+        // Report the start of the deployment.
+        let task = format!("Deploying '{program_id}'");
+        self.interface.progress_start(&task);
 
+        // Compute the deployment.
+        let deployment = self.compute_deployment::<A>(resolver)?;
         // Initialize the RNG.
         let rng = &mut rand::thread_rng();
-        // Compute the deployment.
-        let deployment = process.deploy::<A, _>(program, rng).unwrap();
 
-        match endpoint {
+        // Retrieve the headers to attach to the request.
+        let headers = self.manifest_file.headers();
+
+        let deployment = match endpoint {
             Some(ref endpoint) => {
                 // Construct the deploy request.
                 let request = DeployRequest::new(deployment, *program_id);
-                // Send the deploy request.
-                let response = request.send(endpoint)?;
+                // Send the deploy request, in a signed envelope if a private key is provided.
+                let response: DeployResponse<N> = match private_key {
+                    Some(private_key) => {
+                        let request = request.sign(private_key, rng)?;
+                        SignedRequest::sign(request, private_key, rng)?.send_with_headers(endpoint, headers)?
+                    }
+                    None => request.send_with_headers(endpoint, headers)?,
+                };
                 // Ensure the program ID matches.
                 ensure!(
                     response.deployment.program_id() == program_id,
                     "Program ID mismatch: {} != {program_id}",
                     response.deployment.program_id()
                 );
-                Ok(response.deployment)
+                // Report the transaction, so the deployment can be tracked on-chain.
+                if let Some(transaction_id) = response.transaction_id() {
+                    self.interface.info(&format!("Deployment of '{program_id}' is in transaction '{transaction_id}'"));
+                }
+                response.deployment
+            }
+            None => deployment,
+        };
+
+        // Report the end of the deployment.
+        self.interface.progress_finish(&task);
+        Ok(deployment)
+    }
+
+    /// Returns the cost in microcredits to deploy the package, without sending the deployment.
+    pub fn deployment_cost<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+    ) -> Result<DeploymentCost> {
+        // Compute the deployment.
+        let deployment = self.compute_deployment::<A>(&FileResolver::new(self.imports_directory()))?;
+        // Compute the cost of the deployment.
+        let (total_cost, (storage_cost, namespace_cost)) = deployment.cost()?;
+        // Count the variables and constraints of each function circuit.
+        let (num_variables, num_constraints) =
+            deployment.verifying_keys().iter().fold((0, 0), |(num_variables, num_constraints), (_, (key, _))| {
+                (num_variables + key.circuit_info.num_variables, num_constraints + key.circuit_info.num_constraints)
+            });
+        Ok(DeploymentCost {
+            total_cost,
+            storage_cost,
+            namespace_cost,
+            size_in_bytes: deployment.size_in_bytes()?,
+            num_functions: deployment.program().functions().len(),
+            num_variables,
+            num_constraints,
+        })
+    }
+
+    /// Computes the deployment of the package, resolving its imports with the given resolver.
+    pub(super) fn compute_deployment<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        resolver: &dyn ImportResolver<N>,
+    ) -> Result<Deployment<N>> {
+        // Retrieve the main program.
+        let program = self.program();
+        // Retrieve the main program ID.
+        let program_id = program.id();
+
+        // Construct the process with the program imports.
+        let (mut process, is_build_current) = self.process_with_imports::<A>(resolver)?;
+
+        // Initialize the RNG.
+        let rng = &mut rand::thread_rng();
+        // Add the program to the process.
+        process.add_program(program)?;
+        // If the build is up to date, reuse its keys instead of re-synthesizing each circuit.
+        if is_build_current {
+            // Load the proving and verifying key of each function from the build directory.
+            let build_directory = self.build_directory();
+            for function_name in program.functions().keys() {
+                process.insert_proving_key(program_id, function_name, self.get_proving_key(function_name)?)?;
+                let verifier = VerifierFile::open(&build_directory, function_name)?;
+                process.insert_verifying_key(program_id, function_name, verifier.verifying_key().clone())?;
             }
-            None => Ok(deployment),
         }
+        // Compute the deployment, synthesizing any keys that are not yet loaded.
+        let deployment = process.get_stack(program_id)?.deploy::<A, _>(rng)?;
+
+        // Persist the synthesized keys, so later deployments and executions load them instead.
+        if !is_build_current {
+            let imported_programs = program
+                .imports()
+                .keys()
+                .map(|program_id| process.get_program(program_id).cloned())
+                .collect::<Result<Vec<_>>>()?;
+            self.write_build(&process, &imported_programs)?;
+        }
+        Ok(deployment)
+    }
+
+    /// Returns a process with the program imports, resolved with the given resolver, and whether
+    /// the keys in the build directory were built against the resolved imports.
+    pub(super) fn process_with_imports<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        resolver: &dyn ImportResolver<N>,
+    ) -> Result<(Process<N>, bool)> {
+        // Retrieve the main program.
+        let program = self.program();
+
+        // Construct the process.
+        let mut process = Process::<N>::load()?;
+        process.set_interface(self.interface.clone());
+
+        // Only reuse the keys in the build directory if they were built against the resolved imports.
+        let mut is_build_current = !self.is_build_required::<A>();
+
+        // Resolve the program imports, and ensure they match the lock file.
+        let imports =
+            program.imports().keys().map(|program_id| resolver.resolve(program_id)).collect::<Result<Vec<_>>>()?;
+        self.lock_imports(&imports)?;
+
+        // Add program imports to the process.
+        imports.into_iter().try_for_each(|import| {
+            // TODO (howardwu): Add the following checks:
+            //  1) the imported program ID exists *on-chain* (for the given network)
+            //  2) the AVM bytecode of the imported program matches the AVM bytecode of the program *on-chain*
+            //  3) consensus performs the exact same checks (in `verify_deployment`)
+
+            // Retrieve the import program ID.
+            let program_id = import.id();
+            // Ensure the build directory holds the same import program, if the build is to be reused.
+            if is_build_current && program_id.to_string() != "credits.aleo" {
+                is_build_current = AVMFile::open(&self.build_directory(), program_id, false)
+                    .map_or(false, |avm_file| avm_file.program() == &import);
+            }
+            // Add the import program.
+            process.add_program(&import)?;
+            Ok::<_, Error>(())
+        })?;
+
+        Ok((process, is_build_current))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use snarkvm_console::prelude::{TestRng, Uniform};
 
     type CurrentNetwork = snarkvm_console::network::Testnet3;
     type CurrentAleo = snarkvm_circuit::network::AleoV0;
@@ -190,6 +576,27 @@ mod tests {
         std::fs::remove_dir_all(directory).unwrap();
     }
 
+    #[test]
+    fn test_deployment_cost() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+
+        // Compute the deployment cost.
+        let cost = package.deployment_cost::<CurrentAleo>().unwrap();
+        // Ensure the cost matches the cost of the deployment.
+        let deployment = package.deploy::<CurrentAleo>(None).unwrap();
+        let (total_cost, (storage_cost, namespace_cost)) = deployment.cost().unwrap();
+        assert_eq!(cost.total_cost(), total_cost);
+        assert_eq!(cost.storage_cost(), storage_cost);
+        assert_eq!(cost.namespace_cost(), namespace_cost);
+        assert_eq!(cost.size_in_bytes(), deployment.size_in_bytes().unwrap());
+        assert_eq!(cost.num_functions(), package.program().functions().len());
+        assert!(cost.num_constraints() > 0);
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
     #[test]
     fn test_deploy_with_import() {
         // Samples a new package at a temporary directory.
@@ -208,4 +615,177 @@ mod tests {
         // Proactively remove the temporary directory (to conserve space).
         std::fs::remove_dir_all(directory).unwrap();
     }
+
+    #[test]
+    fn test_deploy_after_build() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+
+        // Build the package, and then deploy it from the cached keys.
+        package.build::<CurrentAleo>(None).unwrap();
+        assert!(!package.is_build_required::<CurrentAleo>());
+        let deployment = package.deploy::<CurrentAleo>(None).unwrap();
+
+        // Ensure the deployment reuses the verifying keys from the build directory.
+        for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+            let verifier = VerifierFile::open(&package.build_directory(), function_name).unwrap();
+            assert_eq!(verifier.verifying_key(), verifying_key);
+        }
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_deploy_persists_keys() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+
+        // Deploy the package, without building it first.
+        assert!(package.is_build_required::<CurrentAleo>());
+        let deployment = package.deploy::<CurrentAleo>(None).unwrap();
+        // Ensure the synthesized keys were written to the build directory.
+        assert!(!package.is_build_required::<CurrentAleo>());
+
+        for (function_name, (verifying_key, _)) in deployment.verifying_keys() {
+            // Ensure the verifier file holds the deployed verifying key.
+            let verifier = VerifierFile::open(&package.build_directory(), function_name).unwrap();
+            assert_eq!(verifier.verifying_key(), verifying_key);
+            // Ensure the proving key loads from the prover file, and is cached for later executions.
+            let prover = ProverFile::open(&package.build_directory(), function_name).unwrap();
+            assert_eq!(&package.get_proving_key(function_name).unwrap(), prover.proving_key());
+            std::fs::remove_file(package.build_directory().join(format!("{function_name}.prover"))).unwrap();
+            assert_eq!(&package.get_proving_key(function_name).unwrap(), prover.proving_key());
+        }
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_deploy_with_resolver() {
+        // Samples a new package with an import at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_wallet_package();
+
+        // Resolve the import from memory, instead of from the imports directory.
+        let import_id = ProgramID::<CurrentNetwork>::from_str("token.aleo").unwrap();
+        let import = package.get_import(&import_id, None).unwrap();
+        std::fs::remove_dir_all(package.imports_directory()).unwrap();
+        let resolver = MemoryResolver::new([import]);
+
+        // Deploy the package.
+        let deployment = package.deploy_with_resolver::<CurrentAleo>(&resolver, None, None).unwrap();
+        assert_eq!(package.program(), deployment.program());
+
+        // Ensure the deployment fails if the import cannot be resolved.
+        let resolver = MemoryResolver::<CurrentNetwork>::default();
+        assert!(package.deploy_with_resolver::<CurrentAleo>(&resolver, None, None).is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_deploy_with_profile() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+        let rng = &mut TestRng::default();
+
+        // Add a profile to the manifest.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let manifest_string = format!(
+            r#"{{
+    "program": "{}",
+    "version": "0.0.0",
+    "profiles": {{ "staging": {{ "address": "{address}", "endpoint": "http://localhost:3030" }} }}
+}}"#,
+            package.program_id()
+        );
+        std::fs::write(package.manifest_file().path(), manifest_string).unwrap();
+        let package = Package::<CurrentNetwork>::open(&directory).unwrap();
+
+        // Ensure an unknown profile fails.
+        assert!(package.deploy_with_profile::<CurrentAleo>("prod", None).is_err());
+        // Ensure a private key that does not belong to the profile fails.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let error = package.deploy_with_profile::<CurrentAleo>("staging", Some(&other_private_key)).unwrap_err();
+        assert!(error.to_string().contains(&address.to_string()));
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_deploy_response_serialization() {
+        let rng = &mut TestRng::default();
+
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+
+        // Deploy the package.
+        let deployment = package.deploy::<CurrentAleo>(None).unwrap();
+
+        // Ensure a response with only the deployment is deserialized, as sent by endpoints that omit the transaction.
+        let response = DeployResponse::new(deployment.clone());
+        let candidate: DeployResponse<CurrentNetwork> =
+            serde_json::from_str(&serde_json::to_string(&response).unwrap()).unwrap();
+        assert_eq!(candidate.deployment(), &deployment);
+        assert!(candidate.transaction_id().is_none());
+        assert!(candidate.fee().is_none());
+        assert!(candidate.status().is_none());
+
+        // Ensure the transaction ID, fee, and status are preserved through serialization.
+        let transaction_id = Field::<CurrentNetwork>::rand(rng).into();
+        for status in [DeploymentStatus::Pending, DeploymentStatus::Accepted(10), DeploymentStatus::Rejected(11)] {
+            let response =
+                DeployResponse::new(deployment.clone()).with_transaction(transaction_id, 1_000).with_status(status);
+            let candidate: DeployResponse<CurrentNetwork> =
+                serde_json::from_str(&serde_json::to_string(&response).unwrap()).unwrap();
+            assert_eq!(candidate.transaction_id(), Some(&transaction_id));
+            assert_eq!(candidate.fee(), Some(1_000));
+            assert_eq!(candidate.status(), Some(status));
+        }
+        assert_eq!(DeploymentStatus::Pending.block_height(), None);
+        assert_eq!(DeploymentStatus::Accepted(10).block_height(), Some(10));
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_deploy_request_signature() {
+        let rng = &mut TestRng::default();
+
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+
+        // Deploy the package.
+        let deployment = package.deploy::<CurrentAleo>(None).unwrap();
+
+        // Ensure an unsigned request does not verify.
+        let request = DeployRequest::new(deployment.clone(), *package.program().id());
+        assert!(request.signer().is_none());
+        assert!(!request.verify());
+
+        // Sign the request.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let request = request.sign(&private_key, rng).unwrap();
+        assert_eq!(request.signer(), Some(Address::try_from(&private_key).unwrap()));
+        assert!(request.verify());
+
+        // Ensure the signature is preserved through serialization.
+        let candidate: DeployRequest<CurrentNetwork> =
+            serde_json::from_str(&serde_json::to_string(&request).unwrap()).unwrap();
+        assert_eq!(candidate.signer(), request.signer());
+        assert!(candidate.verify());
+
+        // Ensure a request with a different program ID does not verify.
+        let mut request = request;
+        request.program_id = ProgramID::from_str("other.aleo").unwrap();
+        assert!(!request.verify());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
 }
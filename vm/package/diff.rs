@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::fmt::{self, Display, Formatter};
+
+/// The changes to one kind of definition in a program, such as its functions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DefinitionDiff<N: Network> {
+    /// The names of the definitions that are only in the local program.
+    added: Vec<Identifier<N>>,
+    /// The names of the definitions that are only in the deployed program.
+    removed: Vec<Identifier<N>>,
+    /// The names of the definitions that are in both programs, but differ.
+    changed: Vec<Identifier<N>>,
+}
+
+impl<N: Network> DefinitionDiff<N> {
+    /// Initializes a new definition diff, from the deployed definitions to the local definitions.
+    fn new<T: PartialEq>(deployed: &IndexMap<Identifier<N>, T>, local: &IndexMap<Identifier<N>, T>) -> Self {
+        Self {
+            added: local.keys().filter(|name| !deployed.contains_key(*name)).copied().collect(),
+            removed: deployed.keys().filter(|name| !local.contains_key(*name)).copied().collect(),
+            changed: local
+                .iter()
+                .filter(|(name, definition)| deployed.get(*name).map_or(false, |deployed| deployed != *definition))
+                .map(|(name, _)| *name)
+                .collect(),
+        }
+    }
+
+    /// Returns the names of the definitions that are only in the local program.
+    pub fn added(&self) -> &[Identifier<N>] {
+        &self.added
+    }
+
+    /// Returns the names of the definitions that are only in the deployed program.
+    pub fn removed(&self) -> &[Identifier<N>] {
+        &self.removed
+    }
+
+    /// Returns the names of the definitions that are in both programs, but differ.
+    pub fn changed(&self) -> &[Identifier<N>] {
+        &self.changed
+    }
+
+    /// Returns `true` if the definitions are the same in both programs.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// The semantic diff of the local program of a package against its deployed version.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgramDiff<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The changes to the struct definitions.
+    structs: DefinitionDiff<N>,
+    /// The changes to the record definitions.
+    records: DefinitionDiff<N>,
+    /// The changes to the mappings.
+    mappings: DefinitionDiff<N>,
+    /// The changes to the closures.
+    closures: DefinitionDiff<N>,
+    /// The changes to the functions.
+    functions: DefinitionDiff<N>,
+}
+
+impl<N: Network> ProgramDiff<N> {
+    /// Initializes a new program diff, from the deployed program to the local program.
+    pub fn new(deployed: &Program<N>, local: &Program<N>) -> Result<Self> {
+        // Ensure the programs have the same program ID.
+        ensure!(
+            deployed.id() == local.id(),
+            "Cannot diff '{}' against the deployed program '{}'",
+            local.id(),
+            deployed.id()
+        );
+        Ok(Self {
+            program_id: *local.id(),
+            structs: DefinitionDiff::new(deployed.structs(), local.structs()),
+            records: DefinitionDiff::new(deployed.records(), local.records()),
+            mappings: DefinitionDiff::new(deployed.mappings(), local.mappings()),
+            closures: DefinitionDiff::new(deployed.closures(), local.closures()),
+            functions: DefinitionDiff::new(deployed.functions(), local.functions()),
+        })
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the changes to the struct definitions.
+    pub const fn structs(&self) -> &DefinitionDiff<N> {
+        &self.structs
+    }
+
+    /// Returns the changes to the record definitions.
+    pub const fn records(&self) -> &DefinitionDiff<N> {
+        &self.records
+    }
+
+    /// Returns the changes to the mappings.
+    pub const fn mappings(&self) -> &DefinitionDiff<N> {
+        &self.mappings
+    }
+
+    /// Returns the changes to the closures.
+    pub const fn closures(&self) -> &DefinitionDiff<N> {
+        &self.closures
+    }
+
+    /// Returns the changes to the functions.
+    pub const fn functions(&self) -> &DefinitionDiff<N> {
+        &self.functions
+    }
+
+    /// Returns `true` if the local program is semantically the same as the deployed program.
+    pub fn is_empty(&self) -> bool {
+        self.kinds().all(|(_, diff)| diff.is_empty())
+    }
+
+    /// Returns each kind of definition, with its changes.
+    fn kinds(&self) -> impl '_ + Iterator<Item = (&'static str, &DefinitionDiff<N>)> {
+        [
+            ("struct", &self.structs),
+            ("record", &self.records),
+            ("mapping", &self.mappings),
+            ("closure", &self.closures),
+            ("function", &self.functions),
+        ]
+        .into_iter()
+    }
+}
+
+impl<N: Network> Display for ProgramDiff<N> {
+    /// Prints the diff, with one line per added (`+`), removed (`-`), or changed (`~`) definition.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "'{}' is unchanged from the deployed program", self.program_id);
+        }
+        write!(f, "'{}' differs from the deployed program:", self.program_id)?;
+        for (kind, diff) in self.kinds() {
+            for (marker, names) in [('+', &diff.added), ('-', &diff.removed), ('~', &diff.changed)] {
+                for name in names {
+                    write!(f, "\n  {marker} {kind} {name}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Package<N> {
+    /// Fetches the deployed version of the program from the given endpoint, or else from the endpoint in the manifest,
+    /// and returns the semantic diff of the local program against it.
+    pub fn diff_onchain(&self, endpoint: Option<&str>) -> Result<ProgramDiff<N>> {
+        match endpoint.or_else(|| self.manifest_file.endpoint()) {
+            Some(endpoint) => {
+                self.config.ensure_online(endpoint)?;
+                self.diff_with_resolver(&EndpointResolver::new(endpoint))
+            }
+            None => bail!("No endpoint is given, and none is set in '{}'", Manifest::<N>::file_name()),
+        }
+    }
+
+    /// Fetches the deployed version of the program with the given resolver,
+    /// and returns the semantic diff of the local program against it.
+    pub fn diff_with_resolver(&self, resolver: &dyn ImportResolver<N>) -> Result<ProgramDiff<N>> {
+        // Fetch the deployed program.
+        let deployed = resolver.resolve(&self.program_id)?;
+        // Compute the diff of the local program against the deployed program.
+        let diff = ProgramDiff::new(&deployed, self.program())?;
+        self.interface.info(&diff.to_string());
+        Ok(diff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_diff_onchain() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+        let program = package.program().clone();
+
+        // Ensure diffing without an endpoint is rejected.
+        assert!(package.diff_onchain(None).is_err());
+        // Ensure a program that is not deployed is rejected.
+        assert!(package.diff_with_resolver(&MemoryResolver::<CurrentNetwork>::new([])).is_err());
+
+        // Ensure the deployed program is reported as unchanged.
+        let diff = package.diff_with_resolver(&MemoryResolver::new([program.clone()])).unwrap();
+        assert!(diff.is_empty());
+
+        // Deploy a version with a `mint` function, without a `transfer` function, and with another `initialize`.
+        let deployed = Program::<CurrentNetwork>::from_str(
+            "
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function initialize:
+    input r0 as address.private;
+    input r1 as u64.public;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;",
+        )
+        .unwrap();
+        let diff = package.diff_with_resolver(&MemoryResolver::new([deployed])).unwrap();
+        // Ensure the diff reports the functions, from the deployed program to the local program.
+        assert!(!diff.is_empty());
+        assert_eq!(diff.functions().added(), &[Identifier::<CurrentNetwork>::from_str("transfer").unwrap()]);
+        assert_eq!(diff.functions().removed(), &[Identifier::<CurrentNetwork>::from_str("mint").unwrap()]);
+        assert_eq!(diff.functions().changed(), &[Identifier::<CurrentNetwork>::from_str("initialize").unwrap()]);
+        assert!(diff.records().is_empty());
+        assert!(diff.to_string().contains("+ function transfer"));
+
+        // Ensure a program with a different program ID is rejected.
+        let other =
+            Program::<CurrentNetwork>::from_str(&program.to_string().replace("token.aleo", "other.aleo")).unwrap();
+        assert!(ProgramDiff::new(&other, &program).is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
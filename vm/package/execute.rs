@@ -14,8 +14,125 @@
 
 use super::*;
 
+use crate::console::{account::Address, prelude::de};
+use snarkvm_utilities::DeserializeExt;
+
+pub struct ExecuteRequest<N: Network> {
+    execution: Execution<N>,
+    program_id: ProgramID<N>,
+    function_name: Identifier<N>,
+    address: Address<N>,
+}
+
+impl<N: Network> ExecuteRequest<N> {
+    /// Initializes a new execute request.
+    pub const fn new(
+        execution: Execution<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        address: Address<N>,
+    ) -> Self {
+        Self { execution, program_id, function_name, address }
+    }
+
+    /// Sends the request to the given endpoint.
+    pub fn send(&self, endpoint: &str) -> Result<ExecuteResponse<N>> {
+        stream::post_json(endpoint, &[], self)
+    }
+
+    /// Returns the execution.
+    pub const fn execution(&self) -> &Execution<N> {
+        &self.execution
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the address of the caller.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+}
+
+impl<N: Network> Serialize for ExecuteRequest<N> {
+    /// Serializes the execute request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut request = serializer.serialize_struct("ExecuteRequest", 4)?;
+        request.serialize_field("execution", &self.execution)?;
+        request.serialize_field("program_id", &self.program_id)?;
+        request.serialize_field("function_name", &self.function_name)?;
+        request.serialize_field("address", &self.address)?;
+        request.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ExecuteRequest<N> {
+    /// Deserializes the execute request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the request from a string into a value.
+        let mut request = serde_json::Value::deserialize(deserializer)?;
+        // Recover the request.
+        Ok(Self::new(
+            // Retrieve the execution.
+            DeserializeExt::take_from_value::<D>(&mut request, "execution")?,
+            // Retrieve the program ID.
+            DeserializeExt::take_from_value::<D>(&mut request, "program_id")?,
+            // Retrieve the function name.
+            DeserializeExt::take_from_value::<D>(&mut request, "function_name")?,
+            // Retrieve the address.
+            DeserializeExt::take_from_value::<D>(&mut request, "address")?,
+        ))
+    }
+}
+
+pub struct ExecuteResponse<N: Network> {
+    execution: Execution<N>,
+}
+
+impl<N: Network> ExecuteResponse<N> {
+    /// Initializes a new execute response.
+    pub const fn new(execution: Execution<N>) -> Self {
+        Self { execution }
+    }
+
+    /// Returns the execution.
+    pub const fn execution(&self) -> &Execution<N> {
+        &self.execution
+    }
+}
+
+impl<N: Network> Serialize for ExecuteResponse<N> {
+    /// Serializes the execute response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("ExecuteResponse", 1)?;
+        response.serialize_field("execution", &self.execution)?;
+        response.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ExecuteResponse<N> {
+    /// Deserializes the execute response from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the response from a string into a value.
+        let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Recover the response.
+        Ok(Self::new(
+            // Retrieve the execution.
+            DeserializeExt::take_from_value::<D>(&mut response, "execution")?,
+        ))
+    }
+}
+
 impl<N: Network> Package<N> {
-    /// Executes a program function with the given inputs.
+    /// Executes a program function with the given inputs, returning the response and the proven `Execution`.
+    /// The outputs of the response are in plaintext, including any records the function outputs.
     #[allow(clippy::type_complexity)]
     pub fn execute<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
         &self,
@@ -33,6 +150,8 @@ impl<N: Network> Package<N> {
         if !program.contains_function(&function_name) {
             bail!("Function '{function_name}' does not exist.")
         }
+        // Ensure the endpoint can be reached, as it is queried for the state root.
+        self.config.ensure_online(&endpoint)?;
 
         // Build the package, if the package requires building.
         // TODO (howardwu): We currently choose only to support local synthesis of keys due to performance.
@@ -42,8 +161,8 @@ impl<N: Network> Package<N> {
         // Prepare the locator (even if logging is disabled, to sanity check the locator is well-formed).
         let locator = Locator::<N>::from_str(&format!("{program_id}/{function_name}"))?;
 
-        #[cfg(feature = "aleo-cli")]
-        println!("🚀 Executing '{}'...\n", locator.to_string().bold());
+        // Report the execution.
+        self.interface.info(&format!("Executing '{locator}'..."));
 
         // Construct the process.
         let process = self.get_process()?;
@@ -85,13 +204,13 @@ impl<N: Network> Package<N> {
 
         // Prepare the build directory.
         let build_directory = self.build_directory();
-        // Load the prover.
-        let prover = ProverFile::open(&build_directory, &function_name)?;
+        // Load the proving key.
+        let proving_key = self.get_proving_key(&function_name)?;
         // Load the verifier.
         let verifier = VerifierFile::open(&build_directory, &function_name)?;
 
         // Adds the proving key to the process.
-        process.insert_proving_key(program_id, &function_name, prover.proving_key().clone())?;
+        process.insert_proving_key(program_id, &function_name, proving_key)?;
         // Adds the verifying key to the process.
         process.insert_verifying_key(program_id, &function_name, verifier.verifying_key().clone())?;
 
@@ -105,9 +224,48 @@ impl<N: Network> Package<N> {
         trace.prepare(Query::<_, BlockMemory<_>>::from(endpoint))?;
         // Prove the execution.
         let execution = trace.prove_execution::<A, R>(&locator.to_string(), rng)?;
+        // Verify the execution, if the settings require it.
+        if self.config.verify_executions() {
+            process.verify_execution(&execution)?;
+        }
         // Return the response, execution, and call metrics.
         Ok((response, execution, call_metrics))
     }
+
+    /// Executes a program function with the given inputs, and sends the proven `Execution` to the given
+    /// `send_endpoint` in an `ExecuteRequest`, in the same way that `deploy` sends a `DeployRequest`.
+    /// The `endpoint` is the node that is queried for the state root and state paths, as in `execute`.
+    #[allow(clippy::type_complexity)]
+    pub fn execute_and_send<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        endpoint: String,
+        send_endpoint: &str,
+        private_key: &PrivateKey<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        rng: &mut R,
+    ) -> Result<(Response<N>, Execution<N>, Vec<CallMetrics<N>>)> {
+        // Ensure the endpoint can be reached, before proving the execution.
+        self.config.ensure_online(send_endpoint)?;
+        // Execute the program function.
+        let (response, execution, call_metrics) =
+            self.execute::<A, R>(endpoint, private_key, function_name, inputs, rng)?;
+
+        // Construct the execute request.
+        let request =
+            ExecuteRequest::new(execution, *self.program_id(), function_name, Address::try_from(private_key)?);
+        // Send the execute request.
+        let execute_response = request.send(send_endpoint)?;
+        // Ensure the execution ID matches.
+        let execution_id = request.execution().to_execution_id()?;
+        ensure!(
+            execute_response.execution().to_execution_id()? == execution_id,
+            "Execution ID mismatch: {} != {execution_id}",
+            execute_response.execution().to_execution_id()?
+        );
+
+        Ok((response, execute_response.execution, call_metrics))
+    }
 }
 
 #[cfg(test)]
@@ -137,7 +295,7 @@ mod tests {
         let (private_key, function_name, inputs) =
             crate::package::test_helpers::sample_package_run(package.program_id());
         // Construct the endpoint.
-        let endpoint = "https://api.explorer.aleo.org/v1".to_string();
+        let endpoint = crate::synthesizer::process::DEFAULT_ENDPOINT.to_string();
         // Run the program function.
         let (_response, _execution, _metrics) =
             package.execute::<CurrentAleo, _>(endpoint, &private_key, function_name, &inputs, rng).unwrap();
@@ -166,7 +324,7 @@ mod tests {
         let (private_key, function_name, inputs) =
             crate::package::test_helpers::sample_package_run(package.program_id());
         // Construct the endpoint.
-        let endpoint = "https://api.explorer.aleo.org/v1".to_string();
+        let endpoint = crate::synthesizer::process::DEFAULT_ENDPOINT.to_string();
         // Run the program function.
         let (_response, _execution, _metrics) =
             package.execute::<CurrentAleo, _>(endpoint, &private_key, function_name, &inputs, rng).unwrap();
@@ -175,6 +333,27 @@ mod tests {
         std::fs::remove_dir_all(directory).unwrap();
     }
 
+    #[test]
+    fn test_execute_request_serialization() {
+        type CurrentNetwork = snarkvm_console::network::Testnet3;
+
+        let rng = &mut TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Construct the execute request.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("mint").unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let request = ExecuteRequest::new(Execution::new(), program_id, function_name, address);
+
+        // Ensure the request fields are serialized.
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["program_id"], serde_json::to_value(program_id).unwrap());
+        assert_eq!(json["function_name"], serde_json::to_value(function_name).unwrap());
+        assert_eq!(json["address"], serde_json::to_value(address).unwrap());
+        assert!(json.get("execution").is_some());
+    }
+
     /// Use `cargo test profiler --features timer` to run this test.
     #[ignore]
     #[test]
@@ -195,7 +374,7 @@ mod tests {
         let (private_key, function_name, inputs) =
             crate::package::test_helpers::sample_package_run(package.program_id());
         // Construct the endpoint.
-        let endpoint = "https://api.explorer.aleo.org/v1".to_string();
+        let endpoint = crate::synthesizer::process::DEFAULT_ENDPOINT.to_string();
         // Run the program function.
         let (_response, _execution, _metrics) =
             package.execute::<CurrentAleo, _>(endpoint, &private_key, function_name, &inputs, rng).unwrap();
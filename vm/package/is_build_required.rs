@@ -43,6 +43,28 @@ impl<N: Network> Package<N> {
             return true;
         }
 
+        // Check if each imported program matches the one it was built against.
+        let imports_directory = self.imports_directory();
+        for program_id in program.imports().keys() {
+            // Skip `credits.aleo`, as it is loaded by the process and is not in the imports directory.
+            if program_id.to_string() == "credits.aleo" {
+                continue;
+            }
+            // Open the imported program, and the AVM file it was built against.
+            let (aleo_file, avm_file) = match (
+                AleoFile::open(&imports_directory, program_id, false),
+                AVMFile::open(&build_directory, program_id, false),
+            ) {
+                (Ok(aleo_file), Ok(avm_file)) => (aleo_file, avm_file),
+                // If either file fails to open, then a build is required.
+                _ => return true,
+            };
+            // If the imported program has changed, then a build is required.
+            if aleo_file.program() != avm_file.program() {
+                return true;
+            }
+        }
+
         // Next, check if the prover and verifier exist for each function.
         for function_name in program.functions().keys() {
             // Check if the prover file exists.
@@ -175,4 +197,18 @@ function compute:
         package.build::<Aleo>(None).unwrap();
         assert!(!package.is_build_required::<Aleo>());
     }
+
+    #[test]
+    fn test_changed_import_requires_rebuild() {
+        let (_, package) = crate::package::test_helpers::sample_wallet_package();
+        package.build::<Aleo>(None).unwrap();
+        assert!(!package.is_build_required::<Aleo>());
+
+        // Append a closure to the imported program.
+        let import_path = package.imports_directory().join("token.aleo");
+        let mut file = std::fs::OpenOptions::new().append(true).open(import_path).unwrap();
+        file.write_all(b"\nclosure noop:\n    input r0 as u64;\n    output r0 as u64;\n").unwrap();
+
+        assert!(package.is_build_required::<Aleo>());
+    }
 }
@@ -0,0 +1,397 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    console::{
+        account::ViewKey,
+        program::{Plaintext, Record},
+    },
+    ledger::{
+        block::Transaction,
+        store::{helpers::memory::ConsensusMemory, ConsensusStore},
+        Ledger,
+        RecordsFilter,
+    },
+    synthesizer::VM,
+};
+use snarkvm_console::prelude::{de, DeserializeExt, DeserializeOwned};
+
+use super::*;
+
+use anyhow::anyhow;
+
+/// The in-memory ledger that a ledger test runs against.
+type TestLedger<N> = Ledger<N, ConsensusMemory<N>>;
+
+/// A call of a ledger test, which executes a function of the package program or one of its imports.
+pub struct LedgerCall<N: Network> {
+    program_id: Option<ProgramID<N>>,
+    function_name: Identifier<N>,
+    inputs: Vec<Value<N>>,
+}
+
+impl<N: Network> LedgerCall<N> {
+    /// Initializes a new call. If the program ID is `None`, the call targets the package program.
+    pub const fn new(program_id: Option<ProgramID<N>>, function_name: Identifier<N>, inputs: Vec<Value<N>>) -> Self {
+        Self { program_id, function_name, inputs }
+    }
+
+    /// Returns the program ID, if it is declared.
+    pub const fn program_id(&self) -> &Option<ProgramID<N>> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the inputs.
+    pub fn inputs(&self) -> &[Value<N>] {
+        &self.inputs
+    }
+}
+
+/// An expected mapping entry of a ledger test.
+pub struct MappingAssertion<N: Network> {
+    program_id: Option<ProgramID<N>>,
+    mapping_name: Identifier<N>,
+    key: Plaintext<N>,
+    value: Option<Value<N>>,
+}
+
+impl<N: Network> MappingAssertion<N> {
+    /// Initializes a new mapping assertion. If the program ID is `None`, the assertion targets the package program,
+    /// and if the value is `None`, the key is expected to be absent.
+    pub const fn new(
+        program_id: Option<ProgramID<N>>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        value: Option<Value<N>>,
+    ) -> Self {
+        Self { program_id, mapping_name, key, value }
+    }
+
+    /// Returns the program ID, if it is declared.
+    pub const fn program_id(&self) -> &Option<ProgramID<N>> {
+        &self.program_id
+    }
+
+    /// Returns the mapping name.
+    pub const fn mapping_name(&self) -> &Identifier<N> {
+        &self.mapping_name
+    }
+
+    /// Returns the key.
+    pub const fn key(&self) -> &Plaintext<N> {
+        &self.key
+    }
+
+    /// Returns the expected value, or `None` if the key is expected to be absent.
+    pub const fn value(&self) -> &Option<Value<N>> {
+        &self.value
+    }
+}
+
+/// A ledger test of a package, which executes a sequence of calls against an in-memory ledger,
+/// and checks the resulting mapping and record state.
+///
+/// Ledger tests are JSON files, i.e.
+/// `{ "calls": [{ "function": "mint", "inputs": ["aleo1...", "100u64"] }],
+///    "mappings": [{ "mapping": "account", "key": "aleo1...", "value": "100u64" }], "records": ["{ owner: ... }"] }`.
+/// Calls and mappings may declare a `program`, which defaults to the package program, and a `null` mapping value
+/// expects the key to be absent. The `mappings`, `records`, and `private_key` fields are optional.
+/// Records are expected among the unspent records of the caller, and are compared without their nonce.
+pub struct LedgerTest<N: Network> {
+    calls: Vec<LedgerCall<N>>,
+    mappings: Vec<MappingAssertion<N>>,
+    records: Vec<Record<N, Plaintext<N>>>,
+    private_key: Option<PrivateKey<N>>,
+}
+
+impl<N: Network> LedgerTest<N> {
+    /// Initializes a new ledger test.
+    pub const fn new(
+        calls: Vec<LedgerCall<N>>,
+        mappings: Vec<MappingAssertion<N>>,
+        records: Vec<Record<N, Plaintext<N>>>,
+        private_key: Option<PrivateKey<N>>,
+    ) -> Self {
+        Self { calls, mappings, records, private_key }
+    }
+
+    /// Opens the ledger test at the given path.
+    pub fn open(path: &Path) -> Result<Self> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Returns the calls.
+    pub fn calls(&self) -> &[LedgerCall<N>] {
+        &self.calls
+    }
+
+    /// Returns the expected mapping entries.
+    pub fn mappings(&self) -> &[MappingAssertion<N>] {
+        &self.mappings
+    }
+
+    /// Returns the expected unspent records.
+    pub fn records(&self) -> &[Record<N, Plaintext<N>>] {
+        &self.records
+    }
+
+    /// Returns the private key of the caller, if it is declared.
+    pub const fn private_key(&self) -> &Option<PrivateKey<N>> {
+        &self.private_key
+    }
+}
+
+/// Takes the optional field with the given name from the value, returning `None` if it is absent or `null`.
+fn take_optional<'de, D: Deserializer<'de>, T: DeserializeOwned>(
+    value: &mut serde_json::Value,
+    name: &str,
+) -> Result<Option<T>, D::Error> {
+    serde_json::from_value(value.get_mut(name).unwrap_or(&mut serde_json::Value::Null).take())
+        .map_err(de::Error::custom)
+}
+
+impl<'de, N: Network> Deserialize<'de> for LedgerCall<N> {
+    /// Deserializes the call from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the call from a string into a value.
+        let mut call = serde_json::Value::deserialize(deserializer)?;
+        Ok(Self::new(
+            // Retrieve the program ID, if it exists.
+            take_optional::<D, _>(&mut call, "program")?,
+            // Retrieve the function name.
+            DeserializeExt::take_from_value::<D>(&mut call, "function")?,
+            // Retrieve the inputs.
+            DeserializeExt::take_from_value::<D>(&mut call, "inputs")?,
+        ))
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for MappingAssertion<N> {
+    /// Deserializes the mapping assertion from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the mapping assertion from a string into a value.
+        let mut assertion = serde_json::Value::deserialize(deserializer)?;
+        Ok(Self::new(
+            // Retrieve the program ID, if it exists.
+            take_optional::<D, _>(&mut assertion, "program")?,
+            // Retrieve the mapping name.
+            DeserializeExt::take_from_value::<D>(&mut assertion, "mapping")?,
+            // Retrieve the key.
+            DeserializeExt::take_from_value::<D>(&mut assertion, "key")?,
+            // Retrieve the expected value, if it exists.
+            take_optional::<D, _>(&mut assertion, "value")?,
+        ))
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for LedgerTest<N> {
+    /// Deserializes the ledger test from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the ledger test from a string into a value.
+        let mut test = serde_json::Value::deserialize(deserializer)?;
+        Ok(Self::new(
+            // Retrieve the calls.
+            DeserializeExt::take_from_value::<D>(&mut test, "calls")?,
+            // Retrieve the expected mapping entries, if they exist.
+            take_optional::<D, _>(&mut test, "mappings")?.unwrap_or_default(),
+            // Retrieve the expected records, if they exist.
+            take_optional::<D, _>(&mut test, "records")?.unwrap_or_default(),
+            // Retrieve the private key, if it exists.
+            take_optional::<D, _>(&mut test, "private_key")?,
+        ))
+    }
+}
+
+impl<N: Network> Package<N> {
+    /// Runs the given ledger test against a fresh in-memory ledger, whose genesis block is created with
+    /// the private key of the test, or else a sampled one. The package imports and program are deployed,
+    /// the calls are executed in order with one block each, and the final mapping and record state is checked.
+    pub fn test_against_ledger<R: Rng + CryptoRng>(&self, test: &LedgerTest<N>, rng: &mut R) -> Result<()> {
+        // Retrieve the private key, or sample one.
+        let private_key = match test.private_key() {
+            Some(private_key) => *private_key,
+            None => PrivateKey::new(rng)?,
+        };
+
+        // Initialize the ledger.
+        let genesis =
+            VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?.genesis_beacon(&private_key, rng)?;
+        let ledger = TestLedger::<N>::load(genesis, None)?;
+
+        // Deploy the imports that are not yet on the ledger, and then the program.
+        let resolver = FileResolver::new(self.imports_directory());
+        for import_id in self.program().imports().keys() {
+            if !ledger.vm().contains_program(import_id) {
+                let import = resolver.resolve(import_id)?;
+                let transaction = ledger.vm().deploy(&private_key, &import, None, 0, None, rng)?;
+                add_transaction(&ledger, &private_key, transaction, rng)
+                    .map_err(|error| anyhow!("Failed to deploy '{import_id}': {error}"))?;
+            }
+        }
+        let transaction = ledger.vm().deploy(&private_key, self.program(), None, 0, None, rng)?;
+        add_transaction(&ledger, &private_key, transaction, rng)
+            .map_err(|error| anyhow!("Failed to deploy '{}': {error}", self.program_id))?;
+        self.interface.info(&format!("Deployed '{}' to the test ledger", self.program_id));
+
+        // Execute the calls.
+        for (index, call) in test.calls().iter().enumerate() {
+            let program_id = call.program_id().as_ref().unwrap_or(&self.program_id);
+            let function_name = call.function_name();
+            ledger
+                .vm()
+                .execute(&private_key, (*program_id, *function_name), call.inputs().iter().cloned(), None, 0, None, rng)
+                .and_then(|transaction| add_transaction(&ledger, &private_key, transaction, rng))
+                .map_err(|error| anyhow!("Call {index} to '{program_id}/{function_name}' failed: {error}"))?;
+        }
+
+        // Ensure the mappings hold the expected entries.
+        for assertion in test.mappings() {
+            let program_id = assertion.program_id().as_ref().unwrap_or(&self.program_id);
+            let (mapping_name, key) = (assertion.mapping_name(), assertion.key());
+            let value = ledger.vm().finalize_store().get_value_confirmed(*program_id, *mapping_name, key)?;
+            ensure!(
+                &value == assertion.value(),
+                "Mapping entry '{program_id}/{mapping_name}[{key}]' is {}, expected {}",
+                value.map_or("absent".to_string(), |value| format!("'{value}'")),
+                assertion.value().as_ref().map_or("absent".to_string(), |value| format!("'{value}'")),
+            );
+        }
+
+        // Ensure the caller holds the expected records.
+        if !test.records().is_empty() {
+            let view_key = ViewKey::try_from(&private_key)?;
+            let records = ledger
+                .find_records(&view_key, RecordsFilter::SlowUnspent(private_key))?
+                .map(|(_, record)| record)
+                .collect::<Vec<_>>();
+            for expected in test.records() {
+                // Records are compared without their nonce, as it is sampled when the record is created.
+                ensure!(
+                    records.iter().any(|record| record.owner() == expected.owner() && record.data() == expected.data()),
+                    "No unspent record matches '{expected}'"
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Adds the given transaction to the next block of the ledger, and ensures it is accepted.
+fn add_transaction<N: Network, R: Rng + CryptoRng>(
+    ledger: &TestLedger<N>,
+    private_key: &PrivateKey<N>,
+    transaction: Transaction<N>,
+    rng: &mut R,
+) -> Result<()> {
+    // Construct the next block.
+    let block = ledger.prepare_advance_to_next_beacon_block(private_key, vec![], vec![], vec![transaction], rng)?;
+    // Ensure the transaction is accepted.
+    ensure!(block.aborted_transaction_ids().is_empty(), "The transaction was aborted");
+    ensure!(block.transactions().iter().all(|transaction| transaction.is_accepted()), "The transaction was rejected");
+    // Advance to the next block.
+    ledger.advance_to_next_block(&block)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::account::Address;
+    use snarkvm_utilities::TestRng;
+
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_package_test_against_ledger() {
+        // Samples a new package, which imports `token.aleo`, at a temporary directory.
+        let (_, token_package) = crate::package::test_helpers::sample_token_package();
+        let program = Program::<CurrentNetwork>::from_str(
+            "
+import token.aleo;
+
+program counter.aleo;
+
+mapping counts:
+    key as address.public;
+    value as u64.public;
+
+function increment:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async increment r0 r1 into r2;
+    output r2 as counter.aleo/increment.future;
+
+finalize increment:
+    input r0 as address.public;
+    input r1 as u64.public;
+    assert.neq r1 0u64;
+    get.or_use counts[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into counts[r0];",
+        )
+        .unwrap();
+        let (directory, package) =
+            crate::package::test_helpers::sample_package_with_program_and_imports(&program, &[token_package
+                .program()
+                .clone()]);
+        std::fs::remove_dir_all(token_package.directory()).unwrap();
+
+        let rng = &mut TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&private_key).unwrap();
+        let stranger = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Run a passing ledger test, which calls both the program and its import.
+        let test = serde_json::from_value::<LedgerTest<CurrentNetwork>>(serde_json::json!({
+            "calls": [
+                { "function": "increment", "inputs": [caller.to_string(), "2u64"] },
+                { "function": "increment", "inputs": [caller.to_string(), "3u64"] },
+                { "program": "token.aleo", "function": "initialize", "inputs": [caller.to_string(), "100u64"] },
+            ],
+            "mappings": [
+                { "mapping": "counts", "key": caller.to_string(), "value": "5u64" },
+                { "mapping": "counts", "key": stranger.to_string() },
+            ],
+            "records": [format!("{{ owner: {caller}.private, amount: 100u64.private, _nonce: 0group.public }}")],
+            "private_key": private_key.to_string(),
+        }))
+        .unwrap();
+        package.test_against_ledger(&test, rng).unwrap();
+
+        // Ensure a rejected call fails the ledger test.
+        let test = serde_json::from_value::<LedgerTest<CurrentNetwork>>(serde_json::json!({
+            "calls": [{ "function": "increment", "inputs": [caller.to_string(), "0u64"] }],
+        }))
+        .unwrap();
+        let error = package.test_against_ledger(&test, rng).unwrap_err();
+        assert!(error.to_string().starts_with("Call 0 to 'counter.aleo/increment' failed"), "{error}");
+
+        // Ensure a mismatched mapping entry fails the ledger test.
+        let test = serde_json::from_value::<LedgerTest<CurrentNetwork>>(serde_json::json!({
+            "calls": [{ "function": "increment", "inputs": [caller.to_string(), "2u64"] }],
+            "mappings": [{ "mapping": "counts", "key": caller.to_string(), "value": "3u64" }],
+            "private_key": private_key.to_string(),
+        }))
+        .unwrap();
+        let error = package.test_against_ledger(&test, rng).unwrap_err();
+        assert!(error.to_string().contains("is '2u64', expected '3u64'"), "{error}");
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
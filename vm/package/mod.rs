@@ -15,12 +15,38 @@
 mod build;
 mod clean;
 mod deploy;
+mod diff;
 mod execute;
 mod is_build_required;
+mod ledger_test;
+mod publish;
+mod resolver;
 mod run;
+mod sign_build;
+mod signed;
+mod stream;
+#[cfg(feature = "async")]
+mod submit;
+mod test_case;
+mod update_imports;
+mod upgrade;
+mod verify;
+mod workspace;
 
 pub use build::{BuildRequest, BuildResponse};
-pub use deploy::{DeployRequest, DeployResponse};
+pub use deploy::{DeployRequest, DeployResponse, DeploymentCost, DeploymentStatus};
+pub use diff::{DefinitionDiff, ProgramDiff};
+pub use execute::{ExecuteRequest, ExecuteResponse};
+pub use ledger_test::{LedgerCall, LedgerTest, MappingAssertion};
+pub use publish::{PublishRequest, PublishResponse};
+pub use resolver::{EndpointResolver, FileResolver, ImportResolver, MemoryResolver};
+pub use signed::{ReplayGuard, SignedRequest};
+#[cfg(feature = "async")]
+pub use submit::{RetryConfig, SubmitError};
+pub use test_case::{TestCase, TestReport};
+pub use update_imports::ImportUpdate;
+pub use verify::CertificateReport;
+pub use workspace::Workspace;
 
 use crate::{
     console::{
@@ -28,11 +54,20 @@ use crate::{
         network::Network,
         program::{Identifier, Locator, ProgramID, Response, Value},
     },
-    file::{AVMFile, AleoFile, Manifest, ProverFile, VerifierFile, README},
+    file::{AVMFile, AleoFile, LockFile, Manifest, ProverFile, Template, VerifierFile, README},
     ledger::{block::Execution, query::Query, store::helpers::memory::BlockMemory},
     prelude::{Deserialize, Deserializer, Serialize, SerializeStruct, Serializer},
     synthesizer::{
-        process::{Assignments, CallMetrics, CallStack, Process, StackExecute},
+        process::{
+            default_interface,
+            Assignments,
+            CallMetrics,
+            CallStack,
+            Process,
+            StackExecute,
+            UserInterface,
+            VmConfig,
+        },
         program::{CallOperator, Instruction, Program},
         snark::{ProvingKey, VerifyingKey},
     },
@@ -40,11 +75,13 @@ use crate::{
 
 use anyhow::{bail, ensure, Error, Result};
 use core::str::FromStr;
+use indexmap::IndexMap;
+use parking_lot::RwLock;
 use rand::{CryptoRng, Rng};
-use std::path::{Path, PathBuf};
-
-#[cfg(feature = "aleo-cli")]
-use colored::Colorize;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 pub struct Package<N: Network> {
     /// The program ID.
@@ -55,11 +92,23 @@ pub struct Package<N: Network> {
     manifest_file: Manifest<N>,
     /// The program file.
     program_file: AleoFile<N>,
+    /// The user interface.
+    interface: Arc<dyn UserInterface>,
+    /// The runtime settings.
+    config: VmConfig,
+    /// The proving keys loaded from the build directory, by function name.
+    proving_keys: RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>,
 }
 
 impl<N: Network> Package<N> {
     /// Creates a new package, at the given directory with the given program name.
     pub fn create(directory: &Path, program_id: &ProgramID<N>) -> Result<Self> {
+        Self::create_with_template(directory, program_id, Template::default())
+    }
+
+    /// Creates a new package, at the given directory with the given program name,
+    /// using the starter program and README of the given template.
+    pub fn create_with_template(directory: &Path, program_id: &ProgramID<N>, template: Template) -> Result<Self> {
         // Ensure the directory path does not exist.
         ensure!(!directory.exists(), "The program directory already exists: {}", directory.display());
         // Ensure the program name is valid.
@@ -73,11 +122,19 @@ impl<N: Network> Package<N> {
         // Create the manifest file.
         let manifest_file = Manifest::create(directory, program_id)?;
         // Create the program file.
-        let program_file = AleoFile::create(directory, program_id, true)?;
+        let program_file = AleoFile::create_with_template(directory, program_id, true, template)?;
         // Create the README file.
-        let _readme_file = README::create::<N>(directory, program_id)?;
-
-        Ok(Self { program_id: *program_id, directory: directory.to_path_buf(), manifest_file, program_file })
+        let _readme_file = README::create_with_template::<N>(directory, program_id, template)?;
+
+        Ok(Self {
+            program_id: *program_id,
+            directory: directory.to_path_buf(),
+            manifest_file,
+            program_file,
+            interface: default_interface(),
+            config: VmConfig::default(),
+            proving_keys: Default::default(),
+        })
     }
 
     /// Opens the package at the given directory with the given program name.
@@ -109,7 +166,15 @@ impl<N: Network> Package<N> {
         // Open the program file.
         let program_file = AleoFile::open(directory, &program_id, true)?;
 
-        Ok(Self { program_id, directory: directory.to_path_buf(), manifest_file, program_file })
+        Ok(Self {
+            program_id,
+            directory: directory.to_path_buf(),
+            manifest_file,
+            program_file,
+            interface: default_interface(),
+            config: VmConfig::default(),
+            proving_keys: Default::default(),
+        })
     }
 
     /// Returns the program ID.
@@ -137,6 +202,28 @@ impl<N: Network> Package<N> {
         self.program_file.program()
     }
 
+    /// Returns the user interface.
+    pub const fn interface(&self) -> &Arc<dyn UserInterface> {
+        &self.interface
+    }
+
+    /// Sets the user interface, which reports the progress of the package operations.
+    pub fn with_interface(mut self, interface: Arc<dyn UserInterface>) -> Self {
+        self.interface = interface;
+        self
+    }
+
+    /// Returns the runtime settings.
+    pub const fn config(&self) -> &VmConfig {
+        &self.config
+    }
+
+    /// Sets the runtime settings.
+    pub fn with_config(mut self, config: VmConfig) -> Self {
+        self.config = config;
+        self
+    }
+
     /// Returns the build directory.
     pub fn build_directory(&self) -> PathBuf {
         self.directory.join("build")
@@ -147,28 +234,104 @@ impl<N: Network> Package<N> {
         self.directory.join("imports")
     }
 
+    /// Returns the proving key for the given function, loading it from the build directory on first use.
+    pub fn get_proving_key(&self, function_name: &Identifier<N>) -> Result<ProvingKey<N>> {
+        // Return the proving key, if it was already loaded.
+        if let Some(proving_key) = self.proving_keys.read().get(function_name) {
+            return Ok(proving_key.clone());
+        }
+        // Ensure the function exists in the program.
+        ensure!(self.program().contains_function(function_name), "Function '{function_name}' does not exist.");
+        // Load the prover file, which ensures the proving key matches its checksum.
+        let build_directory = self.build_directory();
+        ensure!(
+            ProverFile::exists_at(&build_directory, function_name),
+            "The proving key for '{function_name}' is missing. Build or deploy the package to synthesize it."
+        );
+        let proving_key = ProverFile::open(&build_directory, function_name)?.proving_key().clone();
+        // Cache the proving key for later executions.
+        self.proving_keys.write().insert(*function_name, proving_key.clone());
+        Ok(proving_key)
+    }
+
+    /// Returns the imported program for the given program ID.
+    /// The imports directory is checked first, and the program is only fetched from the given endpoint
+    /// if it is not found locally, so multi-program packages can be developed without a running node.
+    pub fn get_import(&self, program_id: &ProgramID<N>, endpoint: Option<&str>) -> Result<Program<N>> {
+        match FileResolver::new(self.imports_directory()).resolve(program_id) {
+            Ok(program) => Ok(program),
+            Err(error) => match endpoint {
+                Some(endpoint) => {
+                    // Ensure the import can be fetched, as it is missing from the imports directory.
+                    ensure!(
+                        !self.config.offline(),
+                        "'{program_id}' is missing from the imports directory, and cannot be fetched in offline mode"
+                    );
+                    EndpointResolver::new(endpoint).resolve(program_id)
+                }
+                None => Err(error),
+            },
+        }
+    }
+
+    /// Ensures the given imports match the package lock file, and pins any import that is not yet locked.
+    /// Imports are locked by the checksum of their bytecode and their required edition, so an import that
+    /// changes after it was first resolved is reported instead of being silently built or deployed against.
+    pub fn lock_imports(&self, imports: &[Program<N>]) -> Result<()> {
+        // Initialize the 'credits.aleo' program ID.
+        let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
+        // Don't lock `credits.aleo` as the process is already loaded with it.
+        let imports = imports.iter().filter(|program| program.id() != &credits_program_id).cloned().collect::<Vec<_>>();
+
+        // If the lock file exists, ensure each locked import is unchanged.
+        if LockFile::<N>::exists_at(&self.directory) {
+            let lock_file = LockFile::<N>::open(&self.directory)?;
+            let mut is_locked = true;
+            for program in &imports {
+                is_locked &= lock_file.verify(program)?;
+            }
+            // If every import is locked, the lock file is up to date.
+            if is_locked && lock_file.imports().len() == imports.len() {
+                return Ok(());
+            }
+        }
+        // Write the lock file, pinning each import.
+        let _lock_file = LockFile::create(&self.directory, &imports)?;
+        Ok(())
+    }
+
     /// Returns a new process for the package.
     pub fn get_process(&self) -> Result<Process<N>> {
+        self.get_process_with_endpoint(None)
+    }
+
+    /// Returns a new process for the package, fetching any import missing from the imports directory
+    /// from the given endpoint.
+    pub fn get_process_with_endpoint(&self, endpoint: Option<&str>) -> Result<Process<N>> {
+        // Ensure the package network matches the settings.
+        self.config.ensure_network::<N>()?;
         // Create the process.
         let mut process = Process::load()?;
-
-        // Prepare the imports directory.
-        let imports_directory = self.imports_directory();
+        process.set_interface(self.interface.clone());
 
         // Initialize the 'credits.aleo' program ID.
         let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
 
-        // Add all import programs (in order) to the process.
-        self.program().imports().keys().try_for_each(|program_id| {
+        // Resolve all import programs (in order).
+        let imports = self
+            .program()
+            .imports()
+            .keys()
             // Don't add `credits.aleo` as the process is already loaded with it.
-            if program_id != &credits_program_id {
-                // Open the Aleo program file.
-                let import_program_file = AleoFile::open(&imports_directory, program_id, false)?;
-                // Add the import program.
-                process.add_program(import_program_file.program())?;
-            }
-            Ok::<_, Error>(())
-        })?;
+            .filter(|program_id| *program_id != &credits_program_id)
+            .map(|program_id| self.get_import(program_id, endpoint))
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure the imports match the lock file.
+        self.lock_imports(&imports)?;
+        // Add all import programs (in order) to the process.
+        for import in &imports {
+            process.add_program(import)?;
+        }
 
         // Add the program to the process.
         process.add_program(self.program())?;
@@ -428,6 +591,73 @@ mod tests {
         std::fs::remove_dir_all(directory).unwrap();
     }
 
+    #[test]
+    fn test_get_import() {
+        // Samples a new package with an import at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_wallet_package();
+
+        // Ensure the import is resolved from the imports directory, without an endpoint.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo").unwrap();
+        let import = package.get_import(&program_id, None).unwrap();
+        assert_eq!(import.id(), &program_id);
+
+        // Ensure a missing import fails to resolve, without an endpoint.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("missing.aleo").unwrap();
+        assert!(package.get_import(&program_id, None).is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_offline() {
+        // Samples a new package with an import at a temporary directory, in offline mode.
+        let (directory, package) = crate::package::test_helpers::sample_wallet_package();
+        let package = package.with_config(VmConfig::default().with_offline(true));
+        let endpoint = "http://localhost:3030";
+
+        // Ensure a cached import is still resolved.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo").unwrap();
+        assert!(package.get_import(&program_id, Some(endpoint)).is_ok());
+        // Ensure a missing import is not fetched.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("missing.aleo").unwrap();
+        let error = package.get_import(&program_id, Some(endpoint)).unwrap_err();
+        assert!(error.to_string().contains("offline mode"));
+
+        // Ensure every request to the endpoint fails fast.
+        assert!(package.update_imports(Some(endpoint)).is_err());
+        assert!(package.diff_onchain(Some(endpoint)).is_err());
+        assert!(package.build::<CurrentAleo>(Some(endpoint.to_string())).is_err());
+        assert!(!package.build_directory().exists());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_lock_imports() {
+        // Samples a new package with an import at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_wallet_package();
+
+        // Ensure the imports are locked when the process is first constructed.
+        assert!(!LockFile::<CurrentNetwork>::exists_at(&directory));
+        assert!(package.get_process().is_ok());
+        assert!(LockFile::<CurrentNetwork>::exists_at(&directory));
+        assert!(package.get_process().is_ok());
+
+        // Change the imported program.
+        let import_path = package.imports_directory().join("token.aleo");
+        let mut import_string = std::fs::read_to_string(&import_path).unwrap();
+        import_string.push_str("\nclosure noop:\n    input r0 as u64;\n    output r0 as u64;\n");
+        std::fs::write(&import_path, import_string).unwrap();
+
+        // Ensure the changed import no longer matches the lock file.
+        assert!(package.get_process().is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
     #[test]
     fn test_build_directory() {
         // Samples a new package at a temporary directory.
@@ -454,6 +684,33 @@ mod tests {
         std::fs::remove_dir_all(directory).unwrap();
     }
 
+    #[test]
+    fn test_create_with_template() {
+        // Initialize a temporary directory.
+        let directory = tempfile::tempdir().expect("Failed to open temporary directory").into_path();
+
+        for template in Template::all() {
+            // Create a package with the template.
+            let program_id = ProgramID::<CurrentNetwork>::from_str(&format!("{template}_starter.aleo")).unwrap();
+            let package_directory = directory.join(template.to_string());
+            Package::create_with_template(&package_directory, &program_id, template).unwrap();
+
+            // Ensure the package opens with the starter program.
+            let package = Package::<CurrentNetwork>::open(&package_directory).unwrap();
+            assert_eq!(package.program(), &Program::from_str(&template.program_string(&program_id)).unwrap());
+            // Get the program process and check all instructions.
+            assert!(package.get_process().is_ok());
+
+            // Ensure the README describes the template.
+            let readme = std::fs::read_to_string(package_directory.join("README.md")).unwrap();
+            assert!(readme.contains(template.description()));
+            assert!(readme.contains(template.run_command()));
+        }
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
     #[test]
     fn test_package_run_and_execute_match() {
         // Initialize the program.
@@ -485,7 +742,7 @@ function bar:
         let inputs = vec![Value::from_str("true").unwrap()];
 
         // Construct the endpoint.
-        let endpoint = "https://api.explorer.aleo.org/v1".to_string();
+        let endpoint = crate::synthesizer::process::DEFAULT_ENDPOINT.to_string();
 
         // Run the program function.
         let run_result = package.run::<CurrentAleo, _>(&private_key, function_name, &inputs, rng).ok();
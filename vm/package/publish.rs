@@ -0,0 +1,228 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use crate::console::{account::Address, prelude::de, types::Field};
+use snarkvm_utilities::DeserializeExt;
+
+/// A request to publish a program, with its README and manifest, to a program registry.
+pub struct PublishRequest<N: Network> {
+    /// The program.
+    program: Program<N>,
+    /// The manifest, as the contents of `program.json`.
+    manifest: String,
+    /// The README, if the package has one.
+    readme: Option<String>,
+}
+
+impl<N: Network> PublishRequest<N> {
+    /// Initializes a new publish request.
+    pub const fn new(program: Program<N>, manifest: String, readme: Option<String>) -> Self {
+        Self { program, manifest, readme }
+    }
+
+    /// Returns the program.
+    pub const fn program(&self) -> &Program<N> {
+        &self.program
+    }
+
+    /// Returns the manifest.
+    pub fn manifest(&self) -> &str {
+        &self.manifest
+    }
+
+    /// Returns the README, if the package has one.
+    pub fn readme(&self) -> Option<&str> {
+        self.readme.as_deref()
+    }
+}
+
+impl<N: Network> Serialize for PublishRequest<N> {
+    /// Serializes the publish request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut request = serializer.serialize_struct("PublishRequest", 2 + self.readme.is_some() as usize)?;
+        // Serialize the program.
+        request.serialize_field("program", &self.program)?;
+        // Serialize the manifest.
+        request.serialize_field("manifest", &self.manifest)?;
+        // Serialize the README, if it exists.
+        if let Some(readme) = &self.readme {
+            request.serialize_field("readme", readme)?;
+        }
+        request.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for PublishRequest<N> {
+    /// Deserializes the publish request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the request from a string into a value.
+        let mut request = serde_json::Value::deserialize(deserializer)?;
+        // Recover the request.
+        Ok(Self::new(
+            // Retrieve the program.
+            DeserializeExt::take_from_value::<D>(&mut request, "program")?,
+            // Retrieve the manifest.
+            DeserializeExt::take_from_value::<D>(&mut request, "manifest")?,
+            // Retrieve the README, if it exists.
+            serde_json::from_value(request.get_mut("readme").unwrap_or(&mut serde_json::Value::Null).take())
+                .map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+/// The registry record of a published program.
+pub struct PublishResponse<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The checksum of the published program bytecode.
+    checksum: Field<N>,
+    /// The address of the publisher.
+    publisher: Address<N>,
+}
+
+impl<N: Network> PublishResponse<N> {
+    /// Initializes a new publish response.
+    pub const fn new(program_id: ProgramID<N>, checksum: Field<N>, publisher: Address<N>) -> Self {
+        Self { program_id, checksum, publisher }
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the checksum of the published program bytecode.
+    pub const fn checksum(&self) -> &Field<N> {
+        &self.checksum
+    }
+
+    /// Returns the address of the publisher.
+    pub const fn publisher(&self) -> &Address<N> {
+        &self.publisher
+    }
+}
+
+impl<N: Network> Serialize for PublishResponse<N> {
+    /// Serializes the publish response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("PublishResponse", 3)?;
+        response.serialize_field("program_id", &self.program_id)?;
+        response.serialize_field("checksum", &self.checksum)?;
+        response.serialize_field("publisher", &self.publisher)?;
+        response.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for PublishResponse<N> {
+    /// Deserializes the publish response from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the response from a string into a value.
+        let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Recover the response.
+        Ok(Self::new(
+            // Retrieve the program ID.
+            DeserializeExt::take_from_value::<D>(&mut response, "program_id")?,
+            // Retrieve the checksum.
+            DeserializeExt::take_from_value::<D>(&mut response, "checksum")?,
+            // Retrieve the publisher.
+            DeserializeExt::take_from_value::<D>(&mut response, "publisher")?,
+        ))
+    }
+}
+
+impl<N: Network> Package<N> {
+    /// Returns the publish request for the package.
+    pub fn publish_request(&self) -> Result<PublishRequest<N>> {
+        // Read the manifest.
+        let manifest = std::fs::read_to_string(self.manifest_file.path())?;
+        // Read the README, if it exists.
+        let readme_path = self.directory.join("README.md");
+        let readme = match readme_path.exists() {
+            true => Some(std::fs::read_to_string(readme_path)?),
+            false => None,
+        };
+        Ok(PublishRequest::new(self.program().clone(), manifest, readme))
+    }
+
+    /// Publishes the package to the registry at the given endpoint.
+    /// The request is sent in a `SignedRequest` envelope, signed by the developer's private key.
+    pub fn publish<R: Rng + CryptoRng>(
+        &self,
+        endpoint: &str,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<PublishResponse<N>> {
+        // Ensure the registry can be reached.
+        self.config.ensure_online(endpoint)?;
+        // Retrieve the program ID.
+        let program_id = self.program().id();
+
+        // Report the start of the publication.
+        let task = format!("Publishing '{program_id}'");
+        self.interface.progress_start(&task);
+
+        // Send the publish request, in a signed envelope.
+        let request = SignedRequest::sign(self.publish_request()?, private_key, rng)?;
+        let response: PublishResponse<N> = request.send(endpoint)?;
+
+        // Ensure the registry recorded this program, as published by the signer.
+        ensure!(response.program_id() == program_id, "Program ID mismatch: {} != {program_id}", response.program_id());
+        ensure!(
+            *response.checksum() == LockFile::checksum(self.program())?,
+            "Program checksum mismatch for '{program_id}'"
+        );
+        ensure!(*response.publisher() == Address::try_from(private_key)?, "Publisher mismatch for '{program_id}'");
+
+        // Report the end of the publication.
+        self.interface.progress_finish(&task);
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::prelude::TestRng;
+
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_publish_request() {
+        let rng = &mut TestRng::default();
+
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+
+        // Construct the publish request.
+        let request = package.publish_request().unwrap();
+        assert_eq!(request.program(), package.program());
+        assert!(request.manifest().contains(&package.program().id().to_string()));
+
+        // Ensure the request is preserved through serialization.
+        let candidate: PublishRequest<CurrentNetwork> =
+            serde_json::from_str(&serde_json::to_string(&request).unwrap()).unwrap();
+        assert_eq!(candidate.program(), request.program());
+        assert_eq!(candidate.manifest(), request.manifest());
+        assert_eq!(candidate.readme(), request.readme());
+
+        // Ensure the signed request verifies.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        assert!(SignedRequest::sign(request, &private_key, rng).unwrap().verify());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::collections::HashMap;
+
+/// A source of imported programs, used to resolve the imports of a package.
+pub trait ImportResolver<N: Network> {
+    /// Returns the program for the given program ID.
+    fn resolve(&self, program_id: &ProgramID<N>) -> Result<Program<N>>;
+}
+
+/// Resolves imports from the Aleo files in a directory, such as the `imports` directory of a package.
+pub struct FileResolver {
+    /// The directory containing the imported programs.
+    directory: PathBuf,
+}
+
+impl FileResolver {
+    /// Initializes a new file resolver for the given directory.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self { directory: directory.into() }
+    }
+}
+
+impl<N: Network> ImportResolver<N> for FileResolver {
+    /// Returns the program for the given program ID, from its Aleo file in the directory.
+    fn resolve(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        Ok(AleoFile::open(&self.directory, program_id, false)?.program().clone())
+    }
+}
+
+/// Resolves imports from the deployed programs of a node.
+pub struct EndpointResolver {
+    /// The endpoint of the node.
+    endpoint: String,
+}
+
+impl EndpointResolver {
+    /// Initializes a new endpoint resolver for the given endpoint.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self { endpoint: endpoint.into() }
+    }
+}
+
+impl<N: Network> ImportResolver<N> for EndpointResolver {
+    /// Returns the program for the given program ID, as deployed on the node.
+    fn resolve(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        Query::<N, BlockMemory<N>>::from(self.endpoint.as_str()).get_program(program_id)
+    }
+}
+
+/// Resolves imports from a set of programs held in memory.
+pub struct MemoryResolver<N: Network> {
+    /// The programs, keyed by program ID.
+    programs: HashMap<ProgramID<N>, Program<N>>,
+}
+
+impl<N: Network> MemoryResolver<N> {
+    /// Initializes a new memory resolver for the given programs.
+    pub fn new(programs: impl IntoIterator<Item = Program<N>>) -> Self {
+        Self { programs: programs.into_iter().map(|program| (*program.id(), program)).collect() }
+    }
+
+    /// Adds the given program to the resolver, replacing any program with the same program ID.
+    pub fn insert(&mut self, program: Program<N>) {
+        self.programs.insert(*program.id(), program);
+    }
+}
+
+impl<N: Network> Default for MemoryResolver<N> {
+    /// Initializes a new memory resolver, without any programs.
+    fn default() -> Self {
+        Self { programs: HashMap::new() }
+    }
+}
+
+impl<N: Network> ImportResolver<N> for MemoryResolver<N> {
+    /// Returns the program for the given program ID, if it is held by the resolver.
+    fn resolve(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        match self.programs.get(program_id) {
+            Some(program) => Ok(program.clone()),
+            None => bail!("Program '{program_id}' is not held by the resolver"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_file_and_memory_resolvers() {
+        // Samples a new package with an import at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_wallet_package();
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo").unwrap();
+        let missing_id = ProgramID::<CurrentNetwork>::from_str("missing.aleo").unwrap();
+
+        // Ensure the file resolver reads the import from the imports directory.
+        let resolver = FileResolver::new(package.imports_directory());
+        let program: Program<CurrentNetwork> = resolver.resolve(&program_id).unwrap();
+        assert_eq!(program.id(), &program_id);
+        assert!(ImportResolver::<CurrentNetwork>::resolve(&resolver, &missing_id).is_err());
+
+        // Ensure the memory resolver returns the programs it holds.
+        let resolver = MemoryResolver::new([program.clone()]);
+        assert_eq!(resolver.resolve(&program_id).unwrap(), program);
+        assert!(resolver.resolve(&missing_id).is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -15,7 +15,9 @@
 use super::*;
 
 impl<N: Network> Package<N> {
-    /// Runs a program function with the given inputs.
+    /// Runs a program function with the given inputs, without producing a proof.
+    /// The outputs of the response are in plaintext, including any records the function outputs.
+    /// To obtain an `Execution` of the function, use `Package::execute` instead.
     pub fn run<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -33,10 +35,10 @@ impl<N: Network> Package<N> {
         }
 
         // Prepare the locator (even if logging is disabled, to sanity check the locator is well-formed).
-        let _locator = Locator::<N>::from_str(&format!("{program_id}/{function_name}"))?;
+        let locator = Locator::<N>::from_str(&format!("{program_id}/{function_name}"))?;
 
-        #[cfg(feature = "aleo-cli")]
-        println!("🚀 Running '{}'...\n", _locator.to_string().bold());
+        // Report the run.
+        self.interface.info(&format!("Running '{locator}'..."));
 
         // Construct the process.
         let process = self.get_process()?;
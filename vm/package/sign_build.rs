@@ -0,0 +1,145 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use crate::console::{
+    account::{Address, Signature},
+    prelude::ToBytes,
+};
+
+use sha2::{Digest, Sha256};
+
+/// The file name of the build signature, in the build directory.
+const SIGNATURE_FILE_NAME: &str = "signature";
+
+impl<N: Network> Package<N> {
+    /// Signs the build of the package with the given private key, and writes the signature to `build/signature`.
+    /// The signature covers the program bytecode and the verifying key of each function in the build directory.
+    pub fn sign_build<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Address<N>> {
+        // Compute the digest of the build.
+        let digest = self.build_digest()?;
+        // Derive the signer.
+        let signer = Address::try_from(private_key)?;
+        // Sign the digest.
+        let signature = Signature::sign_bytes(private_key, &digest, rng)?;
+
+        // Write the signature file.
+        let signature_file = serde_json::json!({
+            "signer": signer.to_string(),
+            "digest": hex::encode(digest),
+            "signature": signature.to_string(),
+        });
+        std::fs::write(self.signature_file_path(), serde_json::to_string_pretty(&signature_file)?)?;
+
+        self.interface.info(&format!("Signed the build of '{}' as '{signer}'", self.program_id));
+        Ok(signer)
+    }
+
+    /// Checks that `build/signature` is a valid signature from the given developer address,
+    /// over the program bytecode and the verifying key of each function in the build directory.
+    pub fn verify_build(&self, developer: &Address<N>) -> Result<()> {
+        // Ensure the signature file exists.
+        let path = self.signature_file_path();
+        ensure!(path.exists(), "The build of '{}' is not signed (missing '{}')", self.program_id, path.display());
+
+        // Read the signature file.
+        let signature_file: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+        let get_string = |key: &str| match signature_file.get(key).and_then(|value| value.as_str()) {
+            Some(value) => Ok(value.to_string()),
+            None => bail!("Missing '{key}' in '{}'", path.display()),
+        };
+        let signer = Address::<N>::from_str(&get_string("signer")?)?;
+        let digest = get_string("digest")?;
+        let signature = Signature::<N>::from_str(&get_string("signature")?)?;
+
+        // Ensure the build is signed by the developer.
+        ensure!(&signer == developer, "The build is signed by '{signer}', not the developer '{developer}'");
+        // Ensure the build is unchanged since it was signed.
+        let expected_digest = self.build_digest()?;
+        ensure!(
+            digest == hex::encode(expected_digest),
+            "The build of '{}' changed since it was signed",
+            self.program_id
+        );
+        // Ensure the signature is valid.
+        ensure!(signature.verify_bytes(&signer, &expected_digest), "The build signature is invalid");
+        Ok(())
+    }
+
+    /// Returns the path of the build signature.
+    fn signature_file_path(&self) -> PathBuf {
+        self.build_directory().join(SIGNATURE_FILE_NAME)
+    }
+
+    /// Returns the SHA-256 digest of the program bytecode and the verifying key of each function.
+    fn build_digest(&self) -> Result<[u8; 32]> {
+        let mut hasher = Sha256::new();
+        // Hash the program bytecode.
+        hasher.update(self.program().to_bytes_le()?);
+        // Hash the verifying key of each function, in order.
+        for function_name in self.program().functions().keys() {
+            let verifier = VerifierFile::open(&self.build_directory(), function_name)?;
+            hasher.update(function_name.to_bytes_le()?);
+            hasher.update(verifier.verifying_key().to_bytes_le()?);
+        }
+        Ok(hasher.finalize().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_utilities::TestRng;
+
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_sign_build() {
+        let rng = &mut TestRng::default();
+
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+        // Sample the developer.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let developer = Address::try_from(&private_key).unwrap();
+
+        // Ensure a package without a build cannot be signed.
+        assert!(package.sign_build(&private_key, rng).is_err());
+
+        // Build the package.
+        package.build::<CurrentAleo>(None).unwrap();
+        // Ensure an unsigned build does not verify.
+        assert!(package.verify_build(&developer).is_err());
+
+        // Sign the build.
+        assert_eq!(package.sign_build(&private_key, rng).unwrap(), developer);
+        assert!(package.build_directory().join(SIGNATURE_FILE_NAME).exists());
+        // Ensure the build verifies for the developer.
+        package.verify_build(&developer).unwrap();
+
+        // Ensure the build does not verify for another developer.
+        let other = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(package.verify_build(&other).is_err());
+
+        // Ensure a build signed by another key does not verify for the developer.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        package.sign_build(&other_private_key, rng).unwrap();
+        assert!(package.verify_build(&developer).is_err());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,299 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use crate::console::{
+    account::{Address, Signature},
+    prelude::{de, DeserializeOwned, ToBytes},
+};
+use snarkvm_utilities::DeserializeExt;
+
+use std::{
+    collections::HashMap,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// A request body, wrapped in an envelope that is signed by the sender.
+///
+/// The signature covers the version, timestamp, nonce, signer, and the canonical JSON encoding of the body.
+/// The envelope carries a `version` field, so a server can tell it apart from an unsigned request body
+/// sent to the same endpoint, and reject envelopes of an unknown version.
+/// A server checks the envelope with a [`ReplayGuard`], which rejects stale and replayed requests.
+pub struct SignedRequest<N: Network, T> {
+    /// The request body.
+    body: T,
+    /// The UNIX timestamp (in seconds) of when the request was signed.
+    timestamp: i64,
+    /// The random nonce.
+    nonce: u64,
+    /// The address of the signer.
+    signer: Address<N>,
+    /// The signature.
+    signature: Signature<N>,
+}
+
+impl<N: Network, T> SignedRequest<N, T> {
+    /// The version of the signed request envelope.
+    pub const VERSION: u8 = 1;
+}
+
+impl<N: Network, T: Serialize> SignedRequest<N, T> {
+    /// Signs the given request body with the given private key, at the current time.
+    pub fn sign<R: Rng + CryptoRng>(body: T, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Self> {
+        Self::sign_at(body, private_key, current_timestamp()?, rng)
+    }
+
+    /// Signs the given request body with the given private key, at the given UNIX timestamp (in seconds).
+    pub fn sign_at<R: Rng + CryptoRng>(
+        body: T,
+        private_key: &PrivateKey<N>,
+        timestamp: i64,
+        rng: &mut R,
+    ) -> Result<Self> {
+        // Sample the nonce.
+        let nonce = rng.gen();
+        // Derive the signer.
+        let signer = Address::try_from(private_key)?;
+        // Sign the message.
+        let signature = Signature::sign_bytes(private_key, &to_message(&body, timestamp, nonce, &signer)?, rng)?;
+        Ok(Self { body, timestamp, nonce, signer, signature })
+    }
+
+    /// Returns `true` if the signature is valid for the request.
+    /// Note: This method does not check the timestamp or nonce, see [`ReplayGuard::check`].
+    pub fn verify(&self) -> bool {
+        match to_message(&self.body, self.timestamp, self.nonce, &self.signer) {
+            Ok(message) => self.signature.verify_bytes(&self.signer, &message),
+            Err(_) => false,
+        }
+    }
+
+    /// Sends the request to the given endpoint.
+    pub fn send<Response: DeserializeOwned>(&self, endpoint: &str) -> Result<Response>
+    where
+        T: Sync,
+    {
+        self.send_with_headers(endpoint, &[])
+    }
+
+    /// Sends the request to the given endpoint, with the given headers.
+    pub fn send_with_headers<Response: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        headers: &[(String, String)],
+    ) -> Result<Response>
+    where
+        T: Sync,
+    {
+        stream::post_json(endpoint, headers, self)
+    }
+}
+
+impl<N: Network, T> SignedRequest<N, T> {
+    /// Returns the request body.
+    pub const fn body(&self) -> &T {
+        &self.body
+    }
+
+    /// Returns the UNIX timestamp (in seconds) of when the request was signed.
+    pub const fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// Returns the nonce.
+    pub const fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    /// Returns the address of the signer.
+    pub const fn signer(&self) -> &Address<N> {
+        &self.signer
+    }
+
+    /// Returns the signature.
+    pub const fn signature(&self) -> &Signature<N> {
+        &self.signature
+    }
+
+    /// Returns the request body.
+    pub fn into_body(self) -> T {
+        self.body
+    }
+}
+
+impl<N: Network, T: Serialize> Serialize for SignedRequest<N, T> {
+    /// Serializes the signed request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut request = serializer.serialize_struct("SignedRequest", 6)?;
+        request.serialize_field("version", &Self::VERSION)?;
+        request.serialize_field("body", &self.body)?;
+        request.serialize_field("timestamp", &self.timestamp)?;
+        request.serialize_field("nonce", &self.nonce)?;
+        request.serialize_field("signer", &self.signer)?;
+        request.serialize_field("signature", &self.signature)?;
+        request.end()
+    }
+}
+
+impl<'de, N: Network, T: DeserializeOwned> Deserialize<'de> for SignedRequest<N, T> {
+    /// Deserializes the signed request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the request from a string into a value.
+        let mut request = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the version is supported.
+        let version: u8 = DeserializeExt::take_from_value::<D>(&mut request, "version")?;
+        if version != Self::VERSION {
+            return Err(de::Error::custom(format!("Unsupported signed request version {version}")));
+        }
+        // Recover the request.
+        Ok(Self {
+            // Retrieve the body.
+            body: serde_json::from_value(
+                request.get_mut("body").ok_or_else(|| de::Error::custom("The \"body\" field is missing"))?.take(),
+            )
+            .map_err(de::Error::custom)?,
+            // Retrieve the timestamp.
+            timestamp: DeserializeExt::take_from_value::<D>(&mut request, "timestamp")?,
+            // Retrieve the nonce.
+            nonce: DeserializeExt::take_from_value::<D>(&mut request, "nonce")?,
+            // Retrieve the signer.
+            signer: DeserializeExt::take_from_value::<D>(&mut request, "signer")?,
+            // Retrieve the signature.
+            signature: DeserializeExt::take_from_value::<D>(&mut request, "signature")?,
+        })
+    }
+}
+
+/// A server-side guard, which rejects signed requests that are invalid, stale, or replayed.
+pub struct ReplayGuard<N: Network> {
+    /// The maximum difference (in seconds) between the request timestamp and the current time.
+    max_age_in_secs: i64,
+    /// The (signer, nonce) pairs seen within the window, with their timestamps.
+    seen: HashMap<(Address<N>, u64), i64>,
+}
+
+impl<N: Network> ReplayGuard<N> {
+    /// Initializes a new replay guard, which accepts requests signed within `max_age_in_secs` of the current time.
+    pub fn new(max_age_in_secs: i64) -> Self {
+        Self { max_age_in_secs, seen: Default::default() }
+    }
+
+    /// Checks the signed request at the current time, and records its nonce.
+    pub fn check<T: Serialize>(&mut self, request: &SignedRequest<N, T>) -> Result<()> {
+        self.check_at(request, current_timestamp()?)
+    }
+
+    /// Checks the signed request at the given UNIX timestamp (in seconds), and records its nonce.
+    pub fn check_at<T: Serialize>(&mut self, request: &SignedRequest<N, T>, now: i64) -> Result<()> {
+        // Ensure the timestamp is within the window, before spending time on the signature.
+        ensure!(
+            now.abs_diff(request.timestamp) <= self.max_age_in_secs.unsigned_abs(),
+            "The request from '{}' has an expired timestamp ({})",
+            request.signer,
+            request.timestamp
+        );
+        // Ensure the signature is valid.
+        ensure!(request.verify(), "Invalid signature for the request from '{}'", request.signer);
+        // Forget the nonces that have left the window, as their requests are now rejected as expired.
+        let max_age_in_secs = self.max_age_in_secs.unsigned_abs();
+        self.seen.retain(|_, timestamp| now.abs_diff(*timestamp) <= max_age_in_secs);
+        // Ensure the nonce has not been seen.
+        ensure!(
+            self.seen.insert((request.signer, request.nonce), request.timestamp).is_none(),
+            "The request from '{}' is a replay (nonce {})",
+            request.signer,
+            request.nonce
+        );
+        Ok(())
+    }
+}
+
+/// Returns the message to sign, which is the version, timestamp, nonce, signer, and canonical JSON body.
+fn to_message<N: Network, T: Serialize>(body: &T, timestamp: i64, nonce: u64, signer: &Address<N>) -> Result<Vec<u8>> {
+    let mut message = Vec::new();
+    SignedRequest::<N, T>::VERSION.write_le(&mut message)?;
+    timestamp.write_le(&mut message)?;
+    nonce.write_le(&mut message)?;
+    signer.write_le(&mut message)?;
+    message.extend(serde_json::to_vec(body)?);
+    Ok(message)
+}
+
+/// Returns the current UNIX timestamp (in seconds).
+fn current_timestamp() -> Result<i64> {
+    Ok(i64::try_from(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::{network::Testnet3, prelude::TestRng};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_signed_request() {
+        let rng = &mut TestRng::default();
+
+        // Sign a request body.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let request = SignedRequest::sign_at("credits.aleo".to_string(), &private_key, 1_000, rng).unwrap();
+        assert_eq!(request.signer(), &Address::try_from(&private_key).unwrap());
+        assert!(request.verify());
+
+        // Ensure the request is preserved through serialization.
+        let candidate: SignedRequest<CurrentNetwork, String> =
+            serde_json::from_str(&serde_json::to_string(&request).unwrap()).unwrap();
+        assert_eq!(candidate.body(), request.body());
+        assert!(candidate.verify());
+
+        // Ensure an envelope of an unknown version, or an unsigned body, is rejected.
+        let mut value = serde_json::to_value(&request).unwrap();
+        value["version"] = serde_json::json!(SignedRequest::<CurrentNetwork, String>::VERSION + 1);
+        assert!(serde_json::from_value::<SignedRequest<CurrentNetwork, String>>(value).is_err());
+        let mut value = serde_json::to_value(&request).unwrap();
+        value.as_object_mut().unwrap().remove("version");
+        assert!(serde_json::from_value::<SignedRequest<CurrentNetwork, String>>(value).is_err());
+
+        // Ensure a tampered body does not verify.
+        let mut tampered = candidate;
+        tampered.body = "other.aleo".to_string();
+        assert!(!tampered.verify());
+    }
+
+    #[test]
+    fn test_replay_guard() {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let mut guard = ReplayGuard::new(60);
+
+        // Ensure a fresh request is accepted once.
+        let request = SignedRequest::sign_at(1u64, &private_key, 1_000, rng).unwrap();
+        assert!(guard.check_at(&request, 1_030).is_ok());
+        assert!(guard.check_at(&request, 1_030).is_err());
+
+        // Ensure a stale or future request is rejected.
+        let request = SignedRequest::sign_at(2u64, &private_key, 1_000, rng).unwrap();
+        assert!(guard.check_at(&request, 1_061).is_err());
+        assert!(guard.check_at(&request, 939).is_err());
+        assert!(guard.check_at(&request, 1_060).is_ok());
+
+        // Ensure a tampered request is rejected.
+        let mut request = SignedRequest::sign_at(3u64, &private_key, 1_000, rng).unwrap();
+        request.nonce = request.nonce.wrapping_add(1);
+        assert!(guard.check_at(&request, 1_000).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use crate::{console::prelude::DeserializeOwned, ledger::query::http_agent};
+
+use std::{
+    io::{self, BufWriter, Read, Write},
+    sync::mpsc::{sync_channel, Receiver, SyncSender},
+};
+
+/// The size of each chunk of a streamed request body.
+const CHUNK_SIZE: usize = 64 * 1024;
+/// The number of chunks that may be buffered ahead of the connection.
+const CHANNEL_CAPACITY: usize = 4;
+
+/// Posts the given request body to the given endpoint as JSON, and returns the parsed response.
+///
+/// The body is serialized on a separate thread and sent with chunked transfer encoding as it is produced,
+/// so a large request (such as a deployment) is not buffered in full alongside the value it encodes.
+/// The response is parsed from the connection as it is read. The given headers are attached to the request,
+/// which is sent with the HTTP client settings of the process.
+pub(super) fn post_json<T: Serialize + Sync, Response: DeserializeOwned>(
+    endpoint: &str,
+    headers: &[(String, String)],
+    body: &T,
+) -> Result<Response> {
+    let response = stream_json(body, |reader| {
+        let request = http_agent().post(endpoint).set("Content-Type", "application/json");
+        headers.iter().fold(request, |request, (name, value)| request.set(name, value)).send(reader)
+    })??;
+    Ok(serde_json::from_reader(response.into_reader())?)
+}
+
+/// Serializes the given value as JSON on a separate thread, while `consume` reads it from the returned stream.
+fn stream_json<T: Serialize + Sync, O>(value: &T, consume: impl FnOnce(ChunkReader) -> O) -> Result<O> {
+    // Initialize a bounded channel, so the serializer never runs far ahead of the reader.
+    let (sender, receiver) = sync_channel(CHANNEL_CAPACITY);
+    std::thread::scope(|scope| {
+        // Serialize the value in chunks.
+        let writer = scope.spawn(move || -> Result<()> {
+            let mut writer = BufWriter::with_capacity(CHUNK_SIZE, ChunkWriter(sender));
+            serde_json::to_writer(&mut writer, value)?;
+            Ok(writer.flush()?)
+        });
+        // Consume the stream. The stream ends when the writer is dropped.
+        let output = consume(ChunkReader { receiver, chunk: Vec::new(), position: 0 });
+        // Ensure the value was serialized in full.
+        match writer.join() {
+            Ok(result) => result.map(|()| output),
+            Err(_) => bail!("Failed to serialize the request body"),
+        }
+    })
+}
+
+/// The sending half of a streamed request body.
+struct ChunkWriter(SyncSender<Vec<u8>>);
+
+impl Write for ChunkWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // If the reader was dropped, the request has ended, so there is nothing left to write.
+        self.0.send(buf.to_vec()).map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The receiving half of a streamed request body.
+struct ChunkReader {
+    /// The channel of chunks.
+    receiver: Receiver<Vec<u8>>,
+    /// The current chunk.
+    chunk: Vec<u8>,
+    /// The position in the current chunk.
+    position: usize,
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // If the current chunk is exhausted, wait for the next chunk.
+        while self.position == self.chunk.len() {
+            match self.receiver.recv() {
+                Ok(chunk) => (self.chunk, self.position) = (chunk, 0),
+                // The writer has finished.
+                Err(_) => return Ok(0),
+            }
+        }
+        // Copy as much of the current chunk as fits.
+        let num_bytes = buf.len().min(self.chunk.len() - self.position);
+        buf[..num_bytes].copy_from_slice(&self.chunk[self.position..self.position + num_bytes]);
+        self.position += num_bytes;
+        Ok(num_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_json() {
+        // Initialize a value that spans many chunks.
+        let expected = (0..100_000u32).map(|i| i.to_string()).collect::<Vec<_>>();
+        assert!(serde_json::to_vec(&expected).unwrap().len() > 4 * CHUNK_SIZE);
+
+        // Ensure the value is recovered from the stream.
+        let candidate: Vec<String> = stream_json(&expected, |reader| serde_json::from_reader(reader).unwrap()).unwrap();
+        assert_eq!(expected, candidate);
+
+        // Ensure the writer stops if the reader ends early.
+        let result = stream_json(&expected, |mut reader| {
+            let mut buf = [0u8; 16];
+            reader.read_exact(&mut buf).unwrap();
+        });
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use crate::console::prelude::DeserializeOwned;
+
+use core::{fmt, time::Duration};
+
+/// The retry policy for submitting requests asynchronously.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// The number of retries after the first attempt.
+    max_retries: u32,
+    /// The backoff before the first retry, which doubles on each subsequent retry.
+    initial_backoff: Duration,
+    /// The timeout of each attempt.
+    timeout: Duration,
+}
+
+impl RetryConfig {
+    /// Initializes a new retry policy.
+    pub const fn new(max_retries: u32, initial_backoff: Duration, timeout: Duration) -> Self {
+        Self { max_retries, initial_backoff, timeout }
+    }
+
+    /// Returns the number of retries after the first attempt.
+    pub const fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns the backoff before the first retry.
+    pub const fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    /// Returns the timeout of each attempt.
+    pub const fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the backoff before the given retry (starting from `0`).
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff.saturating_mul(2u32.saturating_pow(retry))
+    }
+}
+
+impl Default for RetryConfig {
+    /// Returns the default retry policy, of 3 retries from a 500ms backoff, with a 30s timeout.
+    fn default() -> Self {
+        Self::new(3, Duration::from_millis(500), Duration::from_secs(30))
+    }
+}
+
+/// An error from submitting a request asynchronously.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmitError {
+    /// The request did not reach the node, or the node failed to respond, after every retry.
+    Network(String),
+    /// The node rejected the request, with the given HTTP status and response body.
+    Rejected(u16, String),
+    /// The node accepted the request, but its response could not be parsed.
+    InvalidResponse(String),
+}
+
+impl SubmitError {
+    /// Returns `true` if the request may succeed if it is retried.
+    pub const fn is_retryable(&self) -> bool {
+        match self {
+            Self::Network(..) => true,
+            // A server error may be transient, while a client error will not change on retry.
+            Self::Rejected(status, _) => *status >= 500,
+            Self::InvalidResponse(..) => false,
+        }
+    }
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Network(error) => write!(f, "Failed to reach the node: {error}"),
+            Self::Rejected(status, message) => write!(f, "The node rejected the request ({status}): {message}"),
+            Self::InvalidResponse(error) => write!(f, "The node returned an invalid response: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+/// Posts the given request body to the given endpoint, retrying with exponential backoff on retryable errors.
+pub(super) async fn send_async<T: Serialize, Response: DeserializeOwned>(
+    endpoint: &str,
+    body: &T,
+    config: &RetryConfig,
+) -> Result<Response, SubmitError> {
+    // Initialize the client.
+    let client = reqwest::Client::builder()
+        .timeout(config.timeout())
+        .build()
+        .map_err(|error| SubmitError::Network(error.to_string()))?;
+
+    let mut retry = 0;
+    loop {
+        match send_once(&client, endpoint, body).await {
+            Ok(response) => return Ok(response),
+            Err(error) if error.is_retryable() && retry < config.max_retries() => {
+                // Wait before the next attempt.
+                tokio::time::sleep(config.backoff(retry)).await;
+                retry += 1;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/// Posts the given request body to the given endpoint once.
+async fn send_once<T: Serialize, Response: DeserializeOwned>(
+    client: &reqwest::Client,
+    endpoint: &str,
+    body: &T,
+) -> Result<Response, SubmitError> {
+    // Send the request.
+    let response =
+        client.post(endpoint).json(body).send().await.map_err(|error| SubmitError::Network(error.to_string()))?;
+    // Ensure the node accepted the request.
+    let status = response.status();
+    if !status.is_success() {
+        let message = response.text().await.unwrap_or_default();
+        return Err(SubmitError::Rejected(status.as_u16(), message));
+    }
+    // Parse the response.
+    response.json().await.map_err(|error| SubmitError::InvalidResponse(error.to_string()))
+}
+
+impl<N: Network> DeployRequest<N> {
+    /// Sends the request to the given endpoint asynchronously, retrying according to the given policy.
+    pub async fn send_async(&self, endpoint: &str, config: &RetryConfig) -> Result<DeployResponse<N>, SubmitError> {
+        send_async(endpoint, self, config).await
+    }
+}
+
+impl<N: Network> ExecuteRequest<N> {
+    /// Sends the request to the given endpoint asynchronously, retrying according to the given policy.
+    pub async fn send_async(&self, endpoint: &str, config: &RetryConfig) -> Result<ExecuteResponse<N>, SubmitError> {
+        send_async(endpoint, self, config).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let config = RetryConfig::new(3, Duration::from_millis(100), Duration::from_secs(1));
+        assert_eq!(config.backoff(0), Duration::from_millis(100));
+        assert_eq!(config.backoff(1), Duration::from_millis(200));
+        assert_eq!(config.backoff(2), Duration::from_millis(400));
+        // Ensure the backoff saturates instead of overflowing.
+        let config = RetryConfig::new(3, Duration::MAX, Duration::from_secs(1));
+        assert_eq!(config.backoff(1), Duration::MAX);
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(SubmitError::Network("connection refused".to_string()).is_retryable());
+        assert!(SubmitError::Rejected(503, String::new()).is_retryable());
+        assert!(!SubmitError::Rejected(400, "invalid deployment".to_string()).is_retryable());
+        assert!(!SubmitError::InvalidResponse(String::new()).is_retryable());
+    }
+}
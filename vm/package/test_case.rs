@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_console::prelude::{de, DeserializeExt};
+
+use super::*;
+
+/// A test case of a package, which runs a function of the program with the given inputs.
+///
+/// Test cases are JSON files in the `tests` directory of the package, i.e.
+/// `{ "function": "mint", "inputs": ["aleo1...", "100u64"], "outputs": ["100u64"] }`.
+/// The `outputs` and `private_key` fields are optional. Records are compared without their nonce.
+pub struct TestCase<N: Network> {
+    function_name: Identifier<N>,
+    inputs: Vec<Value<N>>,
+    outputs: Option<Vec<Value<N>>>,
+    private_key: Option<PrivateKey<N>>,
+}
+
+impl<N: Network> TestCase<N> {
+    /// Initializes a new test case.
+    pub const fn new(
+        function_name: Identifier<N>,
+        inputs: Vec<Value<N>>,
+        outputs: Option<Vec<Value<N>>>,
+        private_key: Option<PrivateKey<N>>,
+    ) -> Self {
+        Self { function_name, inputs, outputs, private_key }
+    }
+
+    /// Opens the test case at the given path.
+    pub fn open(path: &Path) -> Result<Self> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the inputs.
+    pub fn inputs(&self) -> &[Value<N>] {
+        &self.inputs
+    }
+
+    /// Returns the expected outputs, if they are declared.
+    pub const fn outputs(&self) -> &Option<Vec<Value<N>>> {
+        &self.outputs
+    }
+
+    /// Returns the private key of the caller, if it is declared.
+    pub const fn private_key(&self) -> &Option<PrivateKey<N>> {
+        &self.private_key
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for TestCase<N> {
+    /// Deserializes the test case from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the test case from a string into a value.
+        let mut test_case = serde_json::Value::deserialize(deserializer)?;
+        Ok(Self::new(
+            // Retrieve the function name.
+            DeserializeExt::take_from_value::<D>(&mut test_case, "function")?,
+            // Retrieve the inputs.
+            DeserializeExt::take_from_value::<D>(&mut test_case, "inputs")?,
+            // Retrieve the expected outputs, if they exist.
+            serde_json::from_value(test_case.get_mut("outputs").unwrap_or(&mut serde_json::Value::Null).take())
+                .map_err(de::Error::custom)?,
+            // Retrieve the private key, if it exists.
+            serde_json::from_value(test_case.get_mut("private_key").unwrap_or(&mut serde_json::Value::Null).take())
+                .map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+/// The result of running a test case of a package.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestReport {
+    /// The name of the test case file.
+    name: String,
+    /// The reason the test case failed, if it did.
+    error: Option<String>,
+}
+
+impl TestReport {
+    /// Returns the name of the test case file.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the reason the test case failed, if it did.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns `true` if the test case passed.
+    pub const fn is_passed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+impl<N: Network> Package<N> {
+    /// Returns the tests directory.
+    pub fn tests_directory(&self) -> PathBuf {
+        self.directory.join("tests")
+    }
+
+    /// Runs the test cases in the tests directory, in lexicographic order, and returns their reports.
+    /// Each test case is run against a fresh process.
+    pub fn test<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        rng: &mut R,
+    ) -> Result<Vec<TestReport>> {
+        // Retrieve the tests directory.
+        let tests_directory = self.tests_directory();
+        // Ensure the tests directory exists.
+        ensure!(tests_directory.exists(), "The tests directory does not exist: {}", tests_directory.display());
+
+        // Collect the test case files.
+        let mut paths = std::fs::read_dir(&tests_directory)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        paths.retain(|path| path.is_file() && path.extension().map_or(false, |extension| extension == "json"));
+        paths.sort();
+
+        let mut reports = Vec::with_capacity(paths.len());
+        for path in paths {
+            // Retrieve the test case name.
+            let name = path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+            // Run the test case.
+            let result = TestCase::open(&path).and_then(|test_case| self.run_test_case::<A, R>(&test_case, rng));
+            // Report the result.
+            match &result {
+                Ok(()) => self.interface.info(&format!("Test '{name}' passed")),
+                Err(error) => self.interface.warn(&format!("Test '{name}' failed: {error}")),
+            }
+            reports.push(TestReport { name, error: result.err().map(|error| error.to_string()) });
+        }
+        Ok(reports)
+    }
+
+    /// Runs the given test case, and ensures the outputs match the expected outputs, if they are declared.
+    fn run_test_case<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>, R: Rng + CryptoRng>(
+        &self,
+        test_case: &TestCase<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Retrieve the private key, or sample one.
+        let private_key = match test_case.private_key() {
+            Some(private_key) => *private_key,
+            None => PrivateKey::new(rng)?,
+        };
+        // Run the function.
+        let (response, _) = self.run::<A, R>(&private_key, *test_case.function_name(), test_case.inputs(), rng)?;
+
+        // Ensure the outputs match the expected outputs.
+        if let Some(expected) = test_case.outputs() {
+            let outputs = response.outputs();
+            ensure!(outputs.len() == expected.len(), "Expected {} outputs, found {}", expected.len(), outputs.len());
+            for (index, (output, expected)) in outputs.iter().zip(expected).enumerate() {
+                let is_match = match (output, expected) {
+                    // Records are compared without their nonce, as it is sampled when the record is created.
+                    (Value::Record(output), Value::Record(expected)) => {
+                        output.owner() == expected.owner() && output.data() == expected.data()
+                    }
+                    (output, expected) => output == expected,
+                };
+                ensure!(is_match, "Output {index} is '{output}', expected '{expected}'");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console::account::Address;
+    use snarkvm_utilities::TestRng;
+
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+
+    #[test]
+    fn test_package_test() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+
+        // Ensure a package without a tests directory is rejected.
+        let rng = &mut TestRng::default();
+        assert!(package.test::<CurrentAleo, _>(rng).is_err());
+
+        // Write the test cases.
+        let private_key = crate::cli::helpers::dotenv_private_key().unwrap();
+        let caller = Address::try_from(&private_key).unwrap();
+        let record = format!("{{ owner: {caller}.private, amount: 100u64.private, _nonce: 0group.public }}");
+        std::fs::create_dir_all(package.tests_directory()).unwrap();
+        let passing = serde_json::json!({
+            "function": "initialize",
+            "inputs": [caller.to_string(), "100u64"],
+            "outputs": [record],
+            "private_key": private_key.to_string(),
+        });
+        std::fs::write(package.tests_directory().join("a_initialize.json"), passing.to_string()).unwrap();
+        let failing = serde_json::json!({
+            "function": "initialize",
+            "inputs": [caller.to_string(), "99u64"],
+            "outputs": [record],
+        });
+        std::fs::write(package.tests_directory().join("b_initialize.json"), failing.to_string()).unwrap();
+
+        // Run the test cases.
+        let reports = package.test::<CurrentAleo, _>(rng).unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].name(), "a_initialize");
+        assert!(reports[0].is_passed(), "{:?}", reports[0].error());
+        assert_eq!(reports[1].name(), "b_initialize");
+        assert!(!reports[1].is_passed());
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use crate::console::types::Field;
+
+/// The result of updating an import of a package, against its locally cached version.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportUpdate<N: Network> {
+    /// The program ID of the import.
+    program_id: ProgramID<N>,
+    /// The checksum of the cached import, if it was cached.
+    previous_checksum: Option<Field<N>>,
+    /// The checksum of the fetched import.
+    checksum: Field<N>,
+    /// The lines of the cached import that are not in the fetched import.
+    removed_lines: Vec<String>,
+    /// The lines of the fetched import that are not in the cached import.
+    added_lines: Vec<String>,
+}
+
+impl<N: Network> ImportUpdate<N> {
+    /// Initializes a new import update, from the cached import (if it was cached) to the fetched import.
+    fn new(previous: Option<&Program<N>>, program: &Program<N>) -> Result<Self> {
+        // Collects the lines of the given program string that are not in the other program string.
+        let difference = |program: &str, other: &str| -> Vec<String> {
+            let other = other.lines().collect::<std::collections::HashSet<_>>();
+            program.lines().filter(|line| !other.contains(line)).map(ToString::to_string).collect()
+        };
+
+        let (previous_string, program_string) =
+            (previous.map(ToString::to_string).unwrap_or_default(), program.to_string());
+        Ok(Self {
+            program_id: *program.id(),
+            previous_checksum: previous.map(LockFile::checksum).transpose()?,
+            checksum: LockFile::checksum(program)?,
+            removed_lines: difference(&previous_string, &program_string),
+            added_lines: difference(&program_string, &previous_string),
+        })
+    }
+
+    /// Returns the program ID of the import.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the checksum of the cached import, if it was cached.
+    pub const fn previous_checksum(&self) -> Option<&Field<N>> {
+        self.previous_checksum.as_ref()
+    }
+
+    /// Returns the checksum of the fetched import.
+    pub const fn checksum(&self) -> &Field<N> {
+        &self.checksum
+    }
+
+    /// Returns the lines of the cached import that are not in the fetched import.
+    pub fn removed_lines(&self) -> &[String] {
+        &self.removed_lines
+    }
+
+    /// Returns the lines of the fetched import that are not in the cached import.
+    pub fn added_lines(&self) -> &[String] {
+        &self.added_lines
+    }
+
+    /// Returns `true` if the import was not cached.
+    pub const fn is_new(&self) -> bool {
+        self.previous_checksum.is_none()
+    }
+
+    /// Returns `true` if the fetched import differs from the cached import.
+    pub fn is_changed(&self) -> bool {
+        self.previous_checksum.as_ref() != Some(&self.checksum)
+    }
+}
+
+impl<N: Network> Package<N> {
+    /// Re-fetches each import of the package from the given endpoint, or else from the endpoint in the manifest,
+    /// and updates the imports directory and the lock file. Returns an update for each import.
+    pub fn update_imports(&self, endpoint: Option<&str>) -> Result<Vec<ImportUpdate<N>>> {
+        match endpoint.or_else(|| self.manifest_file.endpoint()) {
+            Some(endpoint) => {
+                self.config.ensure_online(endpoint)?;
+                self.update_imports_with_resolver(&EndpointResolver::new(endpoint))
+            }
+            None => bail!("No endpoint is given, and none is set in '{}'", Manifest::<N>::file_name()),
+        }
+    }
+
+    /// Re-fetches each import of the package with the given resolver, and updates the imports directory
+    /// and the lock file. Returns an update for each import, which reports its diff against the cached version.
+    pub fn update_imports_with_resolver(&self, resolver: &dyn ImportResolver<N>) -> Result<Vec<ImportUpdate<N>>> {
+        // Initialize the 'credits.aleo' program ID.
+        let credits_program_id = ProgramID::<N>::from_str("credits.aleo")?;
+
+        // Fetch every import before writing any, so a failed fetch leaves the imports directory unchanged.
+        let programs = self
+            .program()
+            .imports()
+            .keys()
+            // Don't update `credits.aleo` as the process is already loaded with it.
+            .filter(|program_id| *program_id != &credits_program_id)
+            .map(|program_id| {
+                let program = resolver.resolve(program_id)?;
+                ensure!(program.id() == program_id, "Fetched '{}' for the import '{program_id}'", program.id());
+                Ok(program)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the imports directory.
+        let imports_directory = self.imports_directory();
+        if !programs.is_empty() {
+            std::fs::create_dir_all(&imports_directory)?;
+        }
+
+        let mut updates = Vec::with_capacity(programs.len());
+        for program in &programs {
+            // Compare the fetched import against the cached import.
+            let previous = FileResolver::new(&imports_directory).resolve(program.id()).ok();
+            let update = ImportUpdate::new(previous.as_ref(), program)?;
+            // Write the fetched import, if it changed.
+            if update.is_changed() {
+                std::fs::write(imports_directory.join(program.id().to_string()), program.to_string())?;
+                self.interface.info(&format!(
+                    "Updated '{}' (+{} -{} lines)",
+                    program.id(),
+                    update.added_lines().len(),
+                    update.removed_lines().len()
+                ));
+            }
+            updates.push(update);
+        }
+
+        // Pin the fetched imports in the lock file.
+        LockFile::create(&self.directory, &programs)?;
+        Ok(updates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+
+    #[test]
+    fn test_update_imports() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_wallet_package();
+
+        // Ensure updating without an endpoint is rejected.
+        assert!(package.update_imports(None).is_err());
+
+        // Ensure an unchanged import is reported as unchanged.
+        let token_id = ProgramID::<CurrentNetwork>::from_str("token.aleo").unwrap();
+        let token = package.get_import(&token_id, None).unwrap();
+        let updates = package.update_imports_with_resolver(&MemoryResolver::new([token.clone()])).unwrap();
+        assert_eq!(updates.len(), 1);
+        assert!(!updates[0].is_new());
+        assert!(!updates[0].is_changed());
+
+        // Upgrade the import with a new function.
+        let upgraded = Program::<CurrentNetwork>::from_str(&format!(
+            "{token}\nfunction burn:\n    input r0 as token.record;\n    output r0.amount as u64.private;\n"
+        ))
+        .unwrap();
+        let updates = package.update_imports_with_resolver(&MemoryResolver::new([upgraded.clone()])).unwrap();
+        // Ensure the diff reports the new function.
+        assert!(updates[0].is_changed());
+        assert_eq!(updates[0].previous_checksum(), Some(&LockFile::checksum(&token).unwrap()));
+        assert!(updates[0].added_lines().iter().any(|line| line.contains("function burn")));
+        assert!(updates[0].removed_lines().is_empty());
+        // Ensure the imports directory and the lock file hold the upgraded import.
+        assert_eq!(package.get_import(&token_id, None).unwrap(), upgraded);
+        assert!(LockFile::<CurrentNetwork>::open(package.directory()).unwrap().verify(&upgraded).unwrap());
+
+        // Ensure a resolver that cannot fetch the import leaves the imports directory unchanged.
+        assert!(package.update_imports_with_resolver(&MemoryResolver::<CurrentNetwork>::new([])).is_err());
+        assert_eq!(package.get_import(&token_id, None).unwrap(), upgraded);
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::ledger::block::Deployment;
+
+use super::*;
+
+use anyhow::anyhow;
+
+impl<N: Network> Package<N> {
+    /// Upgrades the program deployed at the given endpoint, or else at the endpoint in the manifest,
+    /// to the local program, and sends the upgrade to the endpoint.
+    /// The program edition in the manifest is bumped once the endpoint reports the upgrade is accepted;
+    /// otherwise, use `confirm_upgrade` once the upgrade is included in a block.
+    pub fn upgrade<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &mut self,
+        endpoint: Option<&str>,
+    ) -> Result<Deployment<N>> {
+        let Some(endpoint) = endpoint.or_else(|| self.manifest_file.endpoint()).map(ToString::to_string) else {
+            bail!("No endpoint is given, and none is set in '{}'", Manifest::<N>::file_name())
+        };
+        self.config.ensure_online(&endpoint)?;
+
+        // Compute the upgrade of the deployed program.
+        let deployment = self.upgrade_with_resolver::<A>(&EndpointResolver::new(endpoint.clone()))?;
+
+        // Send the upgrade, which is deployed like any other program.
+        let request = DeployRequest::new(deployment, self.program_id);
+        let response = request.send_with_headers(&endpoint, self.manifest_file.headers())?;
+        // Ensure the response is for the upgrade.
+        ensure!(
+            response.deployment() == request.deployment(),
+            "The endpoint responded with a different deployment of '{}'",
+            self.program_id
+        );
+
+        // Confirm the upgrade, if the endpoint reports it is included in a block.
+        match response.status() {
+            Some(status) if status.block_height().is_some() => self.confirm_upgrade(response.deployment(), status)?,
+            _ => self.interface.info(&format!("The upgrade of '{}' is pending", self.program_id)),
+        }
+        Ok(response.deployment().clone())
+    }
+
+    /// Returns the deployment that upgrades the deployed program, fetched with the given resolver,
+    /// to the local program, as the next program edition.
+    ///
+    /// An upgrade keeps the structs, records, and mappings of the deployed program unchanged, and keeps
+    /// the input, output, and finalize types of each deployed function, as checked by `Program::check_is_upgrade_of`.
+    pub fn upgrade_with_resolver<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        resolver: &dyn ImportResolver<N>,
+    ) -> Result<Deployment<N>> {
+        // Fetch the deployed program.
+        let deployed = resolver.resolve(&self.program_id)?;
+        // Ensure the local program is compatible with the deployed program.
+        self.program().check_is_upgrade_of(&deployed)?;
+        self.interface.info(&ProgramDiff::new(&deployed, self.program())?.to_string());
+
+        // Compute the deployment of the local program.
+        let deployment = self.compute_deployment::<A>(&FileResolver::new(self.imports_directory()))?;
+
+        // Construct the upgrade to the next program edition.
+        let program_edition =
+            self.manifest_file.edition().checked_add(1).ok_or_else(|| anyhow!("The edition overflowed"))?;
+        Deployment::new_with_program_edition(
+            deployment.edition(),
+            program_edition,
+            deployment.program().clone(),
+            deployment.verifying_keys().clone(),
+        )
+    }
+
+    /// Confirms the given upgrade with its inclusion status, and bumps the program edition in the manifest
+    /// if the upgrade is accepted.
+    pub fn confirm_upgrade(&mut self, deployment: &Deployment<N>, status: DeploymentStatus) -> Result<()> {
+        // Ensure the deployment is an upgrade of the package program.
+        ensure!(
+            deployment.program_id() == &self.program_id && deployment.is_upgrade(),
+            "The deployment is not an upgrade of '{}'",
+            self.program_id
+        );
+        // Ensure the upgrade is to the next program edition, so it is only confirmed once.
+        let edition = deployment.program_edition();
+        ensure!(
+            self.manifest_file.edition().checked_add(1) == Some(edition),
+            "Cannot confirm edition {edition} of '{}', as the manifest is at edition {}",
+            self.program_id,
+            self.manifest_file.edition()
+        );
+
+        match status {
+            DeploymentStatus::Pending => bail!("The upgrade of '{}' is still pending", self.program_id),
+            DeploymentStatus::Rejected(height) => {
+                bail!("The upgrade of '{}' was rejected in block {height}", self.program_id)
+            }
+            DeploymentStatus::Accepted(height) => {
+                // Bump the program edition.
+                self.manifest_file.set_edition(edition)?;
+                self.interface.info(&format!("Upgraded '{}' to edition {edition} in block {height}", self.program_id));
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+
+    /// Returns the local `token.aleo` program, with the given replacement applied.
+    fn deployed_token(program: &Program<CurrentNetwork>, from: &str, to: &str) -> Program<CurrentNetwork> {
+        let deployed = program.to_string().replace(from, to);
+        assert_ne!(deployed, program.to_string());
+        Program::from_str(&deployed).unwrap()
+    }
+
+    #[test]
+    fn test_upgrade() {
+        // Samples a new package at a temporary directory.
+        let (directory, mut package) = crate::package::test_helpers::sample_token_package();
+        let program = package.program().clone();
+        assert_eq!(package.manifest_file().edition(), 0);
+
+        // Ensure upgrading without an endpoint is rejected.
+        assert!(package.upgrade::<CurrentAleo>(None).is_err());
+        // Ensure an unchanged program is rejected.
+        assert!(package.upgrade_with_resolver::<CurrentAleo>(&MemoryResolver::new([program.clone()])).is_err());
+
+        // Ensure a change to the inputs of a deployed function is rejected.
+        let deployed = deployed_token(
+            &program,
+            "input r1 as u64.private;\n    cast r0 r1",
+            "input r1 as u64.public;\n    cast r0 r1",
+        );
+        let error = package.upgrade_with_resolver::<CurrentAleo>(&MemoryResolver::new([deployed])).unwrap_err();
+        assert!(error.to_string().contains("inputs of function 'initialize'"), "{error}");
+        // Ensure a change to a deployed record is rejected.
+        let deployed = deployed_token(&program, "amount as u64.private;", "amount as u128.private;");
+        let error = package.upgrade_with_resolver::<CurrentAleo>(&MemoryResolver::new([deployed])).unwrap_err();
+        assert!(error.to_string().contains("record 'token' is changed"), "{error}");
+        // Ensure a removed function is rejected.
+        let deployed =
+            Program::from_str(&format!("{program}\nfunction burn:\n    input r0 as token.record;\n")).unwrap();
+        let error = package.upgrade_with_resolver::<CurrentAleo>(&MemoryResolver::new([deployed])).unwrap_err();
+        assert!(error.to_string().contains("function 'burn' is removed"), "{error}");
+
+        // Upgrade a deployed program whose `transfer` function has different logic but the same signature.
+        let deployed =
+            deployed_token(&program, "sub r0.amount r2 into r3;", "sub r0.amount r2 into r3;\n    add r3 r2 into r6;");
+        let upgrade = package.upgrade_with_resolver::<CurrentAleo>(&MemoryResolver::new([deployed])).unwrap();
+        assert!(upgrade.is_upgrade());
+        assert_eq!(upgrade.program_edition(), 1);
+        assert_eq!(upgrade.program(), &program);
+        // Ensure the upgrade round-trips through JSON with its program edition.
+        assert_eq!(upgrade, Deployment::from_str(&upgrade.to_string()).unwrap());
+        // Ensure the edition is not bumped before the upgrade is confirmed.
+        assert_eq!(package.manifest_file().edition(), 0);
+
+        // Ensure a pending or rejected upgrade does not bump the edition.
+        assert!(package.confirm_upgrade(&upgrade, DeploymentStatus::Pending).is_err());
+        assert!(package.confirm_upgrade(&upgrade, DeploymentStatus::Rejected(5)).is_err());
+        assert_eq!(package.manifest_file().edition(), 0);
+        // Ensure an initial deployment cannot be confirmed as an upgrade.
+        let deployment = package.compute_deployment::<CurrentAleo>(&FileResolver::new(package.imports_directory()));
+        assert!(package.confirm_upgrade(&deployment.unwrap(), DeploymentStatus::Accepted(5)).is_err());
+
+        // Confirm the accepted upgrade, which bumps the edition in the manifest.
+        package.confirm_upgrade(&upgrade, DeploymentStatus::Accepted(5)).unwrap();
+        assert_eq!(package.manifest_file().edition(), 1);
+        assert_eq!(Manifest::<CurrentNetwork>::open(&directory).unwrap().edition(), 1);
+        // Ensure the upgrade cannot be confirmed twice.
+        assert!(package.confirm_upgrade(&upgrade, DeploymentStatus::Accepted(5)).is_err());
+        assert_eq!(package.manifest_file().edition(), 1);
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The result of verifying the certificate of a function in a deployment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CertificateReport<N: Network> {
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The number of variables in the function circuit.
+    num_variables: usize,
+    /// The number of constraints in the function circuit.
+    num_constraints: usize,
+    /// The reason the certificate failed to verify, if it did.
+    error: Option<String>,
+}
+
+impl<N: Network> CertificateReport<N> {
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the number of variables in the function circuit.
+    pub const fn num_variables(&self) -> usize {
+        self.num_variables
+    }
+
+    /// Returns the number of constraints in the function circuit.
+    pub const fn num_constraints(&self) -> usize {
+        self.num_constraints
+    }
+
+    /// Returns the reason the certificate failed to verify, if it did.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Returns `true` if the certificate verified.
+    pub const fn is_verified(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+impl<N: Network> Package<N> {
+    /// Verifies the deployment of the package locally, with the same checks consensus runs in `verify_deployment`,
+    /// and returns a report for the certificate of each function. The deployment is not sent to any endpoint.
+    pub fn verify_deployment<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+    ) -> Result<Vec<CertificateReport<N>>> {
+        // Retrieve the imports resolver.
+        let resolver = FileResolver::new(self.imports_directory());
+        // Compute the deployment.
+        let deployment = self.compute_deployment::<A>(&resolver)?;
+        // Construct a process with the program imports, as consensus would see it before the deployment.
+        let (process, _) = self.process_with_imports::<A>(&resolver)?;
+
+        // Verify the certificate of each function.
+        let results = process.verify_deployment_certificates::<A, _>(&deployment, &mut rand::thread_rng())?;
+        let reports = results
+            .into_iter()
+            .zip(deployment.verifying_keys())
+            .map(|((function_name, result), (_, (verifying_key, _)))| {
+                // Report the result.
+                match &result {
+                    Ok(()) => self.interface.info(&format!("Certificate for '{function_name}' verified")),
+                    Err(error) => self.interface.warn(&format!("Certificate for '{function_name}' failed: {error}")),
+                }
+                CertificateReport {
+                    function_name,
+                    num_variables: verifying_key.circuit_info.num_variables,
+                    num_constraints: verifying_key.circuit_info.num_constraints,
+                    error: result.err().map(|error| error.to_string()),
+                }
+            })
+            .collect();
+        Ok(reports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+
+    #[test]
+    fn test_verify_deployment() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_token_package();
+
+        // Verify the deployment.
+        let reports = package.verify_deployment::<CurrentAleo>().unwrap();
+        // Ensure there is a verified report for each function.
+        assert_eq!(reports.len(), package.program().functions().len());
+        for (report, function_name) in reports.iter().zip(package.program().functions().keys()) {
+            assert_eq!(report.function_name(), function_name);
+            assert!(report.is_verified(), "{:?}", report.error());
+            assert!(report.num_constraints() > 0);
+        }
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The file name of the workspace manifest.
+const WORKSPACE_FILE_NAME: &str = "workspace.json";
+
+/// A set of packages in a common directory, which may import one another.
+pub struct Workspace<N: Network> {
+    /// The directory path.
+    directory: PathBuf,
+    /// The member packages, in dependency order.
+    members: IndexMap<ProgramID<N>, Package<N>>,
+}
+
+impl<N: Network> Package<N> {
+    /// Opens the workspace at the given directory, ordering its members so that each member
+    /// follows the members it imports, and wiring those imports into each member's imports directory.
+    pub fn open_workspace(directory: &Path) -> Result<Workspace<N>> {
+        Workspace::open(directory)
+    }
+}
+
+impl<N: Network> Workspace<N> {
+    /// Opens the workspace at the given directory.
+    pub fn open(directory: &Path) -> Result<Self> {
+        // Ensure the directory path exists.
+        ensure!(directory.exists(), "The workspace directory does not exist: {}", directory.display());
+        // Ensure the workspace manifest exists.
+        ensure!(Self::exists_at(directory), "Missing '{}' at '{}'", Self::file_name(), directory.display());
+
+        // Read the workspace manifest.
+        let manifest: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(directory.join(WORKSPACE_FILE_NAME))?)?;
+        // Retrieve the member directories.
+        let member_directories = match manifest.get("members").and_then(|members| members.as_array()) {
+            Some(members) => members
+                .iter()
+                .map(|member| match member.as_str() {
+                    Some(member) => Ok(directory.join(member)),
+                    None => bail!("Workspace member '{member}' is not a path"),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => bail!("Missing the 'members' list in '{WORKSPACE_FILE_NAME}'"),
+        };
+
+        // Open each member package.
+        let mut packages = IndexMap::with_capacity(member_directories.len());
+        for member_directory in &member_directories {
+            let package = Package::<N>::open(member_directory)?;
+            let program_id = *package.program_id();
+            ensure!(
+                packages.insert(program_id, package).is_none(),
+                "Found more than one workspace member for '{program_id}'"
+            );
+        }
+
+        // Order the members, so that each member follows the members it imports.
+        let mut members = IndexMap::with_capacity(packages.len());
+        while !packages.is_empty() {
+            // Find the next member whose imported members are all ordered.
+            let next = packages.iter().position(|(_, package)| {
+                package
+                    .program()
+                    .imports()
+                    .keys()
+                    .all(|import_id| !packages.contains_key(import_id) || members.contains_key(import_id))
+            });
+            match next {
+                Some(index) => {
+                    let (program_id, package) = packages.shift_remove_index(index).unwrap();
+                    members.insert(program_id, package);
+                }
+                None => bail!(
+                    "Found an import cycle among the workspace members: {}",
+                    packages.keys().map(|program_id| program_id.to_string()).collect::<Vec<_>>().join(", ")
+                ),
+            }
+        }
+
+        let workspace = Self { directory: directory.to_path_buf(), members };
+        // Wire the imports between the members.
+        workspace.wire_imports()?;
+        Ok(workspace)
+    }
+
+    /// Returns `true` if the workspace manifest exists at the given directory.
+    pub fn exists_at(directory: &Path) -> bool {
+        // Construct the file path.
+        let path = directory.join(WORKSPACE_FILE_NAME);
+        // Return the result.
+        path.is_file() && path.exists()
+    }
+
+    /// Returns the workspace manifest file name.
+    pub const fn file_name() -> &'static str {
+        WORKSPACE_FILE_NAME
+    }
+
+    /// Returns the directory path.
+    pub const fn directory(&self) -> &PathBuf {
+        &self.directory
+    }
+
+    /// Returns the member packages, in dependency order.
+    pub fn members(&self) -> impl '_ + ExactSizeIterator<Item = &Package<N>> {
+        self.members.values()
+    }
+
+    /// Returns the member package for the given program ID.
+    pub fn get_member(&self, program_id: &ProgramID<N>) -> Result<&Package<N>> {
+        match self.members.get(program_id) {
+            Some(package) => Ok(package),
+            None => bail!("'{program_id}' is not a member of the workspace"),
+        }
+    }
+
+    /// Sets the runtime settings of every member.
+    pub fn with_config(mut self, config: VmConfig) -> Self {
+        self.members = self
+            .members
+            .into_iter()
+            .map(|(program_id, package)| (program_id, package.with_config(config.clone())))
+            .collect();
+        self
+    }
+
+    /// Builds each member, in dependency order.
+    pub fn build<A: crate::circuit::Aleo<Network = N, BaseField = N::Field>>(
+        &self,
+        endpoint: Option<String>,
+    ) -> Result<()> {
+        // Rewire the imports, in case a member program changed since the workspace was opened.
+        self.wire_imports()?;
+        // Build each member.
+        for package in self.members.values() {
+            package.build::<A>(endpoint.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Writes the program of each member into the imports directory of every member that imports it.
+    fn wire_imports(&self) -> Result<()> {
+        for package in self.members.values() {
+            // Retrieve the imported members.
+            let imports = package
+                .program()
+                .imports()
+                .keys()
+                .filter_map(|import_id| self.members.get(import_id))
+                .collect::<Vec<_>>();
+            if imports.is_empty() {
+                continue;
+            }
+
+            // Retrieve the imports directory.
+            let imports_directory = package.imports_directory();
+            std::fs::create_dir_all(&imports_directory)?;
+
+            for import in imports {
+                // Write the imported member, if it changed.
+                let path = imports_directory.join(import.program_id().to_string());
+                let program = import.program().to_string();
+                if std::fs::read_to_string(&path).ok().as_deref() != Some(program.as_str()) {
+                    std::fs::write(&path, program)?;
+                    package.interface().info(&format!(
+                        "Wired '{}' into '{}'",
+                        import.program_id(),
+                        package.program_id()
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+
+    /// Moves the given package directories into a new (temporary) workspace with the given member names.
+    fn sample_workspace(members: &[(&str, &PathBuf)]) -> PathBuf {
+        let directory = tempfile::tempdir().expect("Failed to open temporary directory").into_path();
+        for (name, member_directory) in members {
+            std::fs::rename(member_directory, directory.join(name)).unwrap();
+        }
+        let names = members.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        std::fs::write(directory.join(WORKSPACE_FILE_NAME), serde_json::json!({ "members": names }).to_string())
+            .unwrap();
+        directory
+    }
+
+    #[test]
+    fn test_open_workspace() {
+        // Samples the packages, and remove the cached `token.aleo` import from the wallet.
+        let (token_directory, _) = crate::package::test_helpers::sample_token_package();
+        let (wallet_directory, _) = crate::package::test_helpers::sample_wallet_package();
+        std::fs::remove_dir_all(wallet_directory.join("imports")).unwrap();
+
+        // List the wallet before the token it imports.
+        let directory = sample_workspace(&[("wallet", &wallet_directory), ("token", &token_directory)]);
+
+        // Open the workspace.
+        let workspace = Package::<CurrentNetwork>::open_workspace(&directory).unwrap();
+        assert_eq!(workspace.members().len(), 2);
+
+        // Ensure the token is ordered before the wallet.
+        let program_ids = workspace.members().map(|package| package.program_id().to_string()).collect::<Vec<_>>();
+        assert_eq!(program_ids, ["token.aleo", "wallet.aleo"]);
+
+        // Ensure the token is wired into the wallet's imports.
+        let wallet = workspace.get_member(&ProgramID::from_str("wallet.aleo").unwrap()).unwrap();
+        let token = workspace.get_member(&ProgramID::from_str("token.aleo").unwrap()).unwrap();
+        let import = std::fs::read_to_string(wallet.imports_directory().join("token.aleo")).unwrap();
+        assert_eq!(import, token.program().to_string());
+
+        // Build the workspace.
+        workspace.build::<CurrentAleo>(None).unwrap();
+        for package in workspace.members() {
+            assert!(package.build_directory().exists());
+            assert!(!package.is_build_required::<CurrentAleo>());
+        }
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_open_workspace_fails() {
+        // Ensure a missing workspace manifest fails.
+        let (token_directory, _) = crate::package::test_helpers::sample_token_package();
+        assert!(Package::<CurrentNetwork>::open_workspace(&token_directory).is_err());
+
+        // Ensure a duplicate member fails.
+        let (other_directory, _) = crate::package::test_helpers::sample_token_package();
+        let directory = sample_workspace(&[("token", &token_directory), ("other", &other_directory)]);
+        assert!(Package::<CurrentNetwork>::open_workspace(&directory).is_err());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The console types for wallets, which manage accounts and records,
+//! without compiling the circuit, ledger, and synthesizer crates.
+//!
+//! To depend on this surface alone, use `default-features = false` with the `wallet` feature.
+//! Note: The console network still depends on the algorithms, curves, fields, and parameters crates,
+//! as the network parameters, including the universal SRS and the credits keys, are defined in terms of them.
+
+pub use crate::console::{
+    account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey},
+    network::{Network, Testnet3},
+    program::{
+        Ciphertext,
+        Entry,
+        Identifier,
+        Literal,
+        Locator,
+        Owner,
+        PaymentRequest,
+        Plaintext,
+        ProgramID,
+        Record,
+        RecordBundle,
+        Value,
+    },
+    types::{Boolean, Field, Group, Scalar, U64},
+};